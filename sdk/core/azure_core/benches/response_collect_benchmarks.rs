@@ -2,7 +2,10 @@
 // Licensed under the MIT License.
 
 use azure_core::{
-    http::{headers::Headers, AsyncRawResponse, AsyncResponseBody, StatusCode},
+    http::{
+        headers::{Headers, CONTENT_LENGTH},
+        AsyncRawResponse, AsyncResponseBody, StatusCode,
+    },
     Bytes,
 };
 use bytes::BytesMut;
@@ -26,16 +29,20 @@ async fn collect_two_pass(mut body: AsyncResponseBody) -> Bytes {
     result.into()
 }
 
-/// The current single-pass approach: reserve+extend directly into a growing BytesMut.
+/// The current single-pass approach: reserve+extend into a BytesMut preallocated from `content-length`, if present.
 async fn collect_single_pass(body: AsyncResponseBody) -> Bytes {
     body.collect().await.unwrap()
 }
 
-fn make_body(chunk_size: usize, chunk_count: usize) -> AsyncResponseBody {
+fn make_body(chunk_size: usize, chunk_count: usize, content_length: bool) -> AsyncResponseBody {
     let chunks: Vec<azure_core::Result<Bytes>> = (0..chunk_count)
         .map(|_| Ok(Bytes::from(vec![0u8; chunk_size])))
         .collect();
-    AsyncRawResponse::new(StatusCode::Ok, Headers::new(), stream::iter(chunks).boxed()).into_body()
+    let mut headers = Headers::new();
+    if content_length {
+        headers.insert(CONTENT_LENGTH, (chunk_size * chunk_count).to_string());
+    }
+    AsyncRawResponse::new(StatusCode::Ok, headers, stream::iter(chunks).boxed()).into_body()
 }
 
 fn response_collect_benchmarks(c: &mut Criterion) {
@@ -56,7 +63,7 @@ fn response_collect_benchmarks(c: &mut Criterion) {
             &(chunk_size, chunk_count),
             |b, &(cs, cc)| {
                 b.to_async(&rt).iter(|| async move {
-                    let body = make_body(cs, cc);
+                    let body = make_body(cs, cc, false);
                     std::hint::black_box(collect_two_pass(body).await);
                 });
             },
@@ -67,7 +74,18 @@ fn response_collect_benchmarks(c: &mut Criterion) {
             &(chunk_size, chunk_count),
             |b, &(cs, cc)| {
                 b.to_async(&rt).iter(|| async move {
-                    let body = make_body(cs, cc);
+                    let body = make_body(cs, cc, false);
+                    std::hint::black_box(collect_single_pass(body).await);
+                });
+            },
+        );
+
+        group.bench_with_input(
+            BenchmarkId::new("single_pass_content_length", label),
+            &(chunk_size, chunk_count),
+            |b, &(cs, cc)| {
+                b.to_async(&rt).iter(|| async move {
+                    let body = make_body(cs, cc, true);
                     std::hint::black_box(collect_single_pass(body).await);
                 });
             },
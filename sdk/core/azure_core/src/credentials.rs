@@ -213,6 +213,11 @@ impl AccessToken {
 pub struct TokenRequestOptions<'a> {
     /// Method options to be used when requesting a token.
     pub method_options: ClientMethodOptions<'a>,
+
+    /// The tenant to request the token from instead of the credential's configured tenant.
+    ///
+    /// Credentials may return an error for tenants they aren't configured to allow.
+    pub tenant_id: Option<String>,
 }
 
 /// Represents a credential that can acquire an Entra ID access token.
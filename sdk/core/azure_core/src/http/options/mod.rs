@@ -9,7 +9,7 @@ use std::{borrow::Cow, collections::HashSet, sync::Arc};
 use typespec_client_core::http::{policies::Policy, DEFAULT_ALLOWED_QUERY_PARAMETERS};
 pub use typespec_client_core::http::{
     ClientMethodOptions, ExponentialRetryOptions, FixedRetryOptions, LoggingOptions,
-    PipelineOptions, RetryOptions, Transport,
+    PipelineOptions, ResponseBodyOptions, RetryOptions, Transport,
 };
 pub use user_agent::*;
 
@@ -44,6 +44,9 @@ pub struct ClientOptions {
     /// Specifies which headers and query parameters should be logged. All headers and query parameters not in the allow list will be redacted.
     pub logging: LoggingOptions,
 
+    /// Limits on buffering response bodies.
+    pub response_body: ResponseBodyOptions,
+
     /// Cloud configuration for the client. If None, the client will default to Azure Public Cloud.
     pub cloud: Option<Arc<CloudConfiguration>>,
 }
@@ -73,6 +76,7 @@ impl ClientOptions {
             retry: self.retry,
             transport: self.transport,
             logging: self.logging,
+            response_body: self.response_body,
         };
 
         (
@@ -12,6 +12,7 @@ use crate::{
     tracing::{Span, SpanStatus},
 };
 use async_trait::async_trait;
+pub use azure_core_macros::pageable;
 use futures::{stream::FusedStream, FutureExt, Stream};
 use pin_project::pin_project;
 use std::{fmt, future::Future, pin::Pin, sync::Arc, task};
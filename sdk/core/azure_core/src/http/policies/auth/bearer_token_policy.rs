@@ -9,7 +9,7 @@ use crate::{
         policies::{Policy, PolicyResult, ERROR_TYPE_ATTRIBUTE},
         ClientMethodOptions, Context, Request, StatusCode,
     },
-    time::{Duration, OffsetDateTime},
+    time::{self, Duration, OffsetDateTime},
     tracing::Span,
     Error, Result,
 };
@@ -81,32 +81,91 @@ impl Policy for BearerTokenAuthorizationPolicy {
 
         let mut response = next[0].send(&ctx, request, &next[1..]).await?;
 
-        if response.status() == StatusCode::Unauthorized {
+        let decision = evaluate_challenge(
+            response.status(),
+            response.headers(),
+            self.on_challenge.is_some(),
+        );
+        if decision != ChallengeDecision::Return {
             self.authorizer.invalidate_cache().await;
-            if let Some(ref callback) = self.on_challenge {
-                if response.headers().get_str(&WWW_AUTHENTICATE).is_ok() {
-                    callback
-                        .on_challenge(&ctx, request, self.authorizer.as_ref(), response.headers())
-                        .await?;
-                    request.body_mut().reset().await?;
-                    if let Some(span) = ctx.value::<Arc<dyn Span>>() {
-                        // this span covers the request which received the 401 response
-                        if span.is_recording() {
-                            span.set_attribute(
-                                ERROR_TYPE_ATTRIBUTE,
-                                response.status().to_string().into(),
-                            );
-                        }
-                    }
-                    response = next[0].send(&ctx, request, &next[1..]).await?
+        }
+        if let (ChallengeDecision::Challenge, Some(callback)) = (decision, &self.on_challenge) {
+            callback
+                .on_challenge(&ctx, request, self.authorizer.as_ref(), response.headers())
+                .await?;
+            request.body_mut().reset().await?;
+            if let Some(span) = ctx.value::<Arc<dyn Span>>() {
+                // this span covers the request which received the 401 response
+                if span.is_recording() {
+                    span.set_attribute(ERROR_TYPE_ATTRIBUTE, response.status().to_string().into());
                 }
             }
+            response = next[0].send(&ctx, request, &next[1..]).await?
         }
 
         Ok(response)
     }
 }
 
+/// What a [`BearerTokenAuthorizationPolicy`] does with its cached token before authorizing a request.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TokenDecision {
+    /// Use the cached token.
+    Use,
+    /// Request a new token, but use the cached token if the request fails; it is within its refresh window.
+    Refresh,
+    /// Request a new token and fail if the request fails; there is no cached token or it has expired.
+    Acquire,
+}
+
+/// Decides whether a cached token can authorize a request.
+///
+/// This function performs no I/O, so token caching can be tested deterministically without a credential.
+///
+/// # Arguments
+/// * `expires_on` - When the cached token expires, or `None` if no token is cached.
+/// * `now` - The current time.
+pub fn evaluate_token(expires_on: Option<OffsetDateTime>, now: OffsetDateTime) -> TokenDecision {
+    match expires_on {
+        Some(expires_on) if expires_on > now + TOKEN_REFRESH_WINDOW => TokenDecision::Use,
+        Some(expires_on) if expires_on > now => TokenDecision::Refresh,
+        _ => TokenDecision::Acquire,
+    }
+}
+
+/// What a [`BearerTokenAuthorizationPolicy`] does after receiving a response.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ChallengeDecision {
+    /// Return the response to the caller.
+    Return,
+    /// Discard the cached token and return the response to the caller.
+    Invalidate,
+    /// Discard the cached token, pass the challenge to the [`OnChallenge`] callback, and send the request again.
+    Challenge,
+}
+
+/// Decides how to handle a response to an authorized request.
+///
+/// This function performs no I/O, so challenge handling can be tested deterministically without a transport.
+///
+/// # Arguments
+/// * `status` - The status code of the response.
+/// * `headers` - The headers of the response.
+/// * `handles_challenges` - Whether an [`OnChallenge`] callback is set.
+pub fn evaluate_challenge(
+    status: StatusCode,
+    headers: &Headers,
+    handles_challenges: bool,
+) -> ChallengeDecision {
+    if status != StatusCode::Unauthorized {
+        ChallengeDecision::Return
+    } else if handles_challenges && headers.get_str(&WWW_AUTHENTICATE).is_ok() {
+        ChallengeDecision::Challenge
+    } else {
+        ChallengeDecision::Invalidate
+    }
+}
+
 /// Callback [`BearerTokenAuthorizationPolicy`] invokes when it receives a 401 Unauthorized response with an authentication challenge (WWW-Authenticate header).
 #[async_trait]
 pub trait OnChallenge: std::fmt::Debug + Send + Sync {
@@ -198,45 +257,31 @@ impl Authorizer for BearerTokenAuthorizer {
         options: TokenRequestOptions<'_>,
     ) -> Result<()> {
         let access_token = self.access_token.read().await;
-        match access_token.as_ref() {
-            None => {
-                // cache is empty. Upgrade the lock and acquire a token, provided another thread hasn't already done so
-                drop(access_token);
-                let mut access_token = self.access_token.write().await;
-                if access_token.is_none() {
-                    *access_token = Some(self.credential.get_token(scopes, Some(options)).await?);
-                }
-            }
-            Some(token) if should_refresh(&token.expires_on) => {
-                // token is expired or within its refresh window. Upgrade the lock and
-                // acquire a new token, provided another thread hasn't already done so
-                let expires_on = token.expires_on;
-                drop(access_token);
-                let mut access_token = self.access_token.write().await;
-                // access_token shouldn't be None here, but check anyway to guarantee unwrap won't panic
-                if access_token.is_none() || access_token.as_ref().unwrap().expires_on == expires_on
-                {
-                    match self.credential.get_token(scopes, Some(options)).await {
-                        Ok(new_token) => {
-                            *access_token = Some(new_token);
-                        }
-                        Err(e)
-                            if access_token.is_none()
-                                || expires_on <= OffsetDateTime::now_utc() =>
-                        {
-                            // propagate this error because we can't proceed without a new token
-                            return Err(e);
-                        }
-                        Err(_) => {
-                            // ignore this error because the cached token is still valid
-                        }
+        let expires_on = access_token.as_ref().map(|token| token.expires_on);
+        // release the read lock so we don't try to acquire it while still holding it
+        drop(access_token);
+        if evaluate_token(expires_on, time::now_utc()) != TokenDecision::Use {
+            // cache is empty, or the token is expired or within its refresh window. Upgrade the lock and
+            // acquire a new token, provided another thread hasn't already done so
+            let mut access_token = self.access_token.write().await;
+            let current = access_token.as_ref().map(|token| token.expires_on);
+            if current.is_none() || current == expires_on {
+                match self.credential.get_token(scopes, Some(options)).await {
+                    Ok(new_token) => {
+                        *access_token = Some(new_token);
+                    }
+                    Err(e)
+                        if evaluate_token(expires_on, time::now_utc())
+                            == TokenDecision::Acquire =>
+                    {
+                        // propagate this error because we can't proceed without a new token
+                        return Err(e);
+                    }
+                    Err(_) => {
+                        // ignore this error because the cached token is still valid
                     }
                 }
             }
-            Some(_) => {
-                // do nothing; cached token is valid and not within its refresh window
-                drop(access_token); // release the read lock so we don't try to acquire it while still holding it
-            }
         }
 
         let access_token = self.access_token.read().await;
@@ -256,9 +301,8 @@ impl Authorizer for BearerTokenAuthorizer {
     }
 }
 
-fn should_refresh(expires_on: &OffsetDateTime) -> bool {
-    *expires_on <= OffsetDateTime::now_utc() + Duration::minutes(5)
-}
+/// How long before a cached token expires that [`BearerTokenAuthorizationPolicy`] requests a new one.
+const TOKEN_REFRESH_WINDOW: Duration = Duration::minutes(5);
 
 #[derive(Debug, Default)]
 struct DefaultOnRequest {
@@ -277,6 +321,7 @@ impl OnRequest for DefaultOnRequest {
             method_options: ClientMethodOptions {
                 context: context.clone(),
             },
+            ..Default::default()
         };
         let scopes: Vec<&str> = self.scopes.iter().map(String::as_str).collect();
         authorizer.authorize(request, &scopes, options).await
@@ -355,6 +400,44 @@ mod tests {
         }
     }
 
+    #[test]
+    fn evaluate_token_without_credential() {
+        let now = OffsetDateTime::UNIX_EPOCH;
+        assert_eq!(TokenDecision::Acquire, evaluate_token(None, now));
+        assert_eq!(TokenDecision::Acquire, evaluate_token(Some(now), now));
+        assert_eq!(
+            TokenDecision::Refresh,
+            evaluate_token(Some(now + Duration::minutes(5)), now)
+        );
+        assert_eq!(
+            TokenDecision::Use,
+            evaluate_token(Some(now + Duration::minutes(6)), now)
+        );
+    }
+
+    #[test]
+    fn evaluate_challenge_without_transport() {
+        let mut challenge = Headers::new();
+        challenge.insert(WWW_AUTHENTICATE, "Bearer claims=\"...\"");
+
+        assert_eq!(
+            ChallengeDecision::Return,
+            evaluate_challenge(StatusCode::Ok, &challenge, true)
+        );
+        assert_eq!(
+            ChallengeDecision::Challenge,
+            evaluate_challenge(StatusCode::Unauthorized, &challenge, true)
+        );
+        assert_eq!(
+            ChallengeDecision::Invalidate,
+            evaluate_challenge(StatusCode::Unauthorized, &challenge, false)
+        );
+        assert_eq!(
+            ChallengeDecision::Invalidate,
+            evaluate_challenge(StatusCode::Unauthorized, &Headers::new(), true)
+        );
+    }
+
     #[tokio::test]
     async fn authn_error() {
         // this mock's get_token() will return an error because it has no tokens
@@ -471,6 +554,66 @@ mod tests {
         .await;
     }
 
+    #[derive(Debug)]
+    struct MockClock(std::sync::Mutex<OffsetDateTime>);
+
+    impl time::Clock for MockClock {
+        fn now_utc(&self) -> OffsetDateTime {
+            *self.0.lock().unwrap()
+        }
+    }
+
+    #[tokio::test]
+    async fn refresh_window_uses_current_clock() {
+        // these tokens expired long ago by the system clock, so only the mock clock keeps them valid
+        let start = OffsetDateTime::UNIX_EPOCH;
+        let credential = Arc::new(MockCredential::new(&[
+            AccessToken {
+                token: Secret::new("1".to_string()),
+                expires_on: start + Duration::hours(1),
+            },
+            AccessToken {
+                token: Secret::new("2".to_string()),
+                expires_on: start + Duration::hours(2),
+            },
+        ]));
+        let policy = BearerTokenAuthorizationPolicy::new(credential.clone(), ["scope"]);
+        let client = MockHttpClient::new(|_| {
+            async {
+                Ok(AsyncRawResponse::from_bytes(
+                    StatusCode::Ok,
+                    Headers::new(),
+                    Bytes::new(),
+                ))
+            }
+            .boxed()
+        });
+        let transport: Arc<dyn Policy> =
+            Arc::new(TransportPolicy::new(Transport::new(Arc::new(client))));
+        let clock = Arc::new(MockClock(std::sync::Mutex::new(start)));
+
+        time::with_clock(clock.clone(), async {
+            for (now, expected_calls) in [
+                (start, 1),
+                (start + Duration::minutes(50), 1),
+                (start + Duration::minutes(56), 2),
+            ] {
+                *clock.0.lock().unwrap() = now;
+                let mut req = Request::new("https://localhost".parse().unwrap(), Method::Get);
+                policy
+                    .send(
+                        &Context::default(),
+                        &mut req,
+                        std::slice::from_ref(&transport),
+                    )
+                    .await
+                    .expect("successful request");
+                assert_eq!(expected_calls, credential.get_token_calls());
+            }
+        })
+        .await;
+    }
+
     #[derive(Debug)]
     struct TestOnChallenge {
         calls: Arc<AtomicUsize>,
@@ -494,6 +637,7 @@ mod tests {
                 method_options: ClientMethodOptions {
                     context: context.clone(),
                 },
+                ..Default::default()
             };
             authorizer.authorize(request, &["scope"], options).await?;
             Ok(())
@@ -697,6 +841,7 @@ mod tests {
                             method_options: ClientMethodOptions {
                                 context: Context::default(),
                             },
+                            ..Default::default()
                         },
                     )
                     .await?;
@@ -4,5 +4,14 @@
 //! Authentication pipeline policies.
 
 mod bearer_token_policy;
+#[cfg(any(feature = "hmac_rust", feature = "hmac_openssl"))]
+mod shared_key_policy;
 
-pub use bearer_token_policy::{Authorizer, BearerTokenAuthorizationPolicy, OnChallenge, OnRequest};
+pub use bearer_token_policy::{
+    evaluate_challenge, evaluate_token, Authorizer, BearerTokenAuthorizationPolicy,
+    ChallengeDecision, OnChallenge, OnRequest, TokenDecision,
+};
+#[cfg(any(feature = "hmac_rust", feature = "hmac_openssl"))]
+pub use shared_key_policy::{
+    canonicalized_headers, SharedKeyAuthorizationPolicy, SharedKeyCanonicalizer,
+};
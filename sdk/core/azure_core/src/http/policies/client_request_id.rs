@@ -8,7 +8,7 @@ use crate::{
         request::options::ClientRequestId,
         Context, Request,
     },
-    Uuid,
+    random,
 };
 use std::sync::Arc;
 
@@ -32,6 +32,22 @@ impl ClientRequestIdPolicy {
     pub const fn with_header_name(header: &'static str) -> Self {
         ClientRequestIdPolicy(headers::HeaderName::from_static(header))
     }
+
+    /// Adds the header to `request` without sending it.
+    ///
+    /// An existing header is left unchanged. Otherwise, the value is the [`ClientRequestId`] in `ctx`, if any,
+    /// or a new UUID.
+    pub fn apply(&self, ctx: &Context, request: &mut Request) {
+        if request.headers().get_optional_str(&self.0).is_some() {
+            return;
+        }
+        if let Some(request_id) = ctx.value::<ClientRequestId>() {
+            request.insert_header(self.0.clone(), request_id.value());
+        } else {
+            let request_id: String = random::new_uuid().into();
+            request.insert_header(self.0.clone(), request_id);
+        }
+    }
 }
 
 impl Default for ClientRequestIdPolicy {
@@ -54,15 +70,7 @@ impl Policy for ClientRequestIdPolicy {
         request: &mut Request,
         next: &[Arc<dyn Policy>],
     ) -> PolicyResult {
-        if request.headers().get_optional_str(&self.0).is_none() {
-            if let Some(request_id) = ctx.value::<ClientRequestId>() {
-                request.insert_header(self.0.clone(), request_id.value());
-            } else {
-                let request_id: String = Uuid::new_v4().into();
-                request.insert_header(self.0.clone(), request_id);
-            }
-        }
-
+        self.apply(ctx, request);
         next[0].send(ctx, request, &next[1..]).await
     }
 }
@@ -117,6 +125,30 @@ mod tests {
             .expect("Policy execution failed");
     }
 
+    #[test]
+    fn apply_without_transport() {
+        let policy = ClientRequestIdPolicy::default();
+        let ctx = Context::new().with_value(ClientRequestId::new("from-context".to_string()));
+
+        let mut request = Request::new("https://example.com".parse().unwrap(), Method::Get);
+        policy.apply(&ctx, &mut request);
+        assert_eq!(
+            request
+                .headers()
+                .get_optional_str(&headers::CLIENT_REQUEST_ID),
+            Some("from-context")
+        );
+
+        request.insert_header(headers::CLIENT_REQUEST_ID, "existing");
+        policy.apply(&ctx, &mut request);
+        assert_eq!(
+            request
+                .headers()
+                .get_optional_str(&headers::CLIENT_REQUEST_ID),
+            Some("existing")
+        );
+    }
+
     #[tokio::test]
     async fn header_not_present() {
         // Arrange
@@ -150,6 +182,49 @@ mod tests {
             .expect("Policy execution failed");
     }
 
+    #[tokio::test]
+    async fn generated_header_uses_current_random_source() {
+        #[derive(Debug)]
+        struct ZeroSource;
+
+        impl random::RandomSource for ZeroSource {
+            fn next_u64(&self) -> u64 {
+                0
+            }
+        }
+
+        // Arrange
+        let mut request = Request::new("https://example.com".parse().unwrap(), Method::Get);
+
+        let policy = ClientRequestIdPolicy::default();
+        let transport = Arc::new(MockHttpClient::new(|req| {
+            async move {
+                // Assert
+                assert_eq!(
+                    req.headers().get_optional_str(&headers::CLIENT_REQUEST_ID),
+                    Some("00000000-0000-4000-8000-000000000000")
+                );
+
+                Ok(AsyncRawResponse::from_bytes(
+                    StatusCode::Ok,
+                    headers::Headers::new(),
+                    Bytes::new(),
+                ))
+            }
+            .boxed()
+        }));
+        let transport = Arc::new(TransportPolicy::new(Transport::new(transport)));
+        let ctx = Context::new();
+
+        // Act
+        random::with_random_source(
+            Arc::new(ZeroSource),
+            policy.send(&ctx, &mut request, &[transport]),
+        )
+        .await
+        .expect("Policy execution failed");
+    }
+
     #[tokio::test]
     async fn custom_header_name_with_existing_value() {
         // Arrange
@@ -105,6 +105,11 @@ impl<'a> UserAgentPolicy {
             header: HeaderValue::from(header_str),
         }
     }
+
+    /// Sets the `User-Agent` header on `request` without sending it.
+    pub fn apply(&self, request: &mut Request) {
+        request.insert_header(USER_AGENT, self.header.clone());
+    }
 }
 
 #[async_trait::async_trait]
@@ -115,7 +120,7 @@ impl Policy for UserAgentPolicy {
         request: &mut Request,
         next: &[Arc<dyn Policy>],
     ) -> PolicyResult {
-        request.insert_header(USER_AGENT, self.header.clone());
+        self.apply(request);
         next[0].send(ctx, request, &next[1..]).await
     }
 }
@@ -155,6 +160,25 @@ mod tests {
         );
     }
 
+    #[test]
+    fn apply_without_transport() {
+        let policy = UserAgentPolicy::new_with_rustc_version(
+            Some("test"),
+            Some("1.2.3"),
+            Some("4.5.6"),
+            &UserAgentOptions::default(),
+        );
+        let mut request = Request::new(
+            "https://example.com".parse().unwrap(),
+            crate::http::Method::Get,
+        );
+        policy.apply(&mut request);
+        assert_eq!(
+            request.headers().get_optional_str(&USER_AGENT),
+            Some(format!("azsdk-rust-test/1.2.3 (4.5.6; {OS}; {ARCH})").as_str())
+        );
+    }
+
     #[test]
     fn missing_env() {
         // Would simulate if option_env!("CARGO_PKG_NAME"), for example, returned None.
@@ -11,7 +11,7 @@ use crate::{
         Context, Format, JsonFormat, Response, StatusCode, Url,
     },
     sleep,
-    time::{Duration, OffsetDateTime},
+    time::{self, Duration},
     tracing::{Span, SpanStatus},
 };
 use futures::{channel::oneshot, stream::unfold, Stream, StreamExt};
@@ -753,9 +753,8 @@ pub fn get_retry_after(
     options: &PollerOptions,
 ) -> Duration {
     #[cfg_attr(feature = "test", allow(unused_mut))]
-    let duration =
-        crate::http::policies::get_retry_after(headers, OffsetDateTime::now_utc, retry_headers)
-            .unwrap_or(options.frequency);
+    let duration = crate::http::policies::get_retry_after(headers, time::now_utc, retry_headers)
+        .unwrap_or(options.frequency);
 
     #[cfg(feature = "test")]
     {
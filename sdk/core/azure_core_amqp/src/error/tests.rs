@@ -262,6 +262,22 @@ fn test_amqp_error_condition_clone() {
     assert_eq!(unknown, cloned_unknown);
 }
 
+#[test]
+fn test_amqp_error_condition_serde() {
+    assert_eq!(
+        serde_json::to_string(&AmqpErrorCondition::SessionLockLost).unwrap(),
+        r#""com.microsoft:session-lock-lost""#
+    );
+    assert_eq!(
+        serde_json::from_str::<AmqpErrorCondition>(r#""amqp:link:stolen""#).unwrap(),
+        AmqpErrorCondition::LinkStolen
+    );
+    assert_eq!(
+        serde_json::from_str::<AmqpErrorCondition>(r#""unknown:condition""#).unwrap(),
+        AmqpErrorCondition::UnknownValue("unknown:condition".to_string())
+    );
+}
+
 #[test]
 fn test_amqp_error_condition_from_symbol() {
     let symbol = AmqpSymbol("amqp:decode-error".to_string());
@@ -2,16 +2,19 @@
 // Licensed under the MIT license.
 
 use crate::{
-    connection::{AmqpConnectionApis, AmqpConnectionOptions},
+    connection::{AmqpConnectionApis, AmqpConnectionOptions, AmqpTransportType},
     error::{AmqpErrorKind, Result},
-    fe2o3::error::{Fe2o3ConnectionError, Fe2o3ConnectionOpenError, Fe2o3TransportError},
+    fe2o3::{
+        error::{Fe2o3ConnectionError, Fe2o3ConnectionOpenError, Fe2o3TransportError},
+        websocket,
+    },
     value::{AmqpOrderedMap, AmqpSymbol, AmqpValue},
     AmqpError,
 };
 use azure_core::http::Url;
 use fe2o3_amqp::connection::ConnectionHandle;
 use std::{borrow::BorrowMut, sync::OnceLock};
-use tokio::sync::Mutex;
+use tokio::{net::TcpStream, sync::Mutex};
 use tracing::{debug, warn};
 
 #[derive(Debug, Default)]
@@ -116,13 +119,45 @@ impl AmqpConnectionApis for Fe2o3AmqpConnection {
                 builder = builder.hostname(url.host_str());
             }
 
-            self.connection
-                .set(Mutex::new(
+            let connection = match options.transport_type.unwrap_or_default() {
+                AmqpTransportType::AmqpTcp => match options.network_endpoint {
+                    // Connect to the network endpoint, but keep the URL's host as the TLS server
+                    // name and the AMQP hostname.
+                    Some(network_endpoint) => {
+                        async {
+                            let default_port = match url.scheme() {
+                                "amqps" => 5671,
+                                _ => 5672,
+                            };
+                            let addrs = network_endpoint.socket_addrs(|| Some(default_port))?;
+                            let stream = TcpStream::connect(&*addrs).await?;
+                            builder
+                                .scheme(url.scheme())
+                                .hostname(url.host_str())
+                                .sasl_hostname(url.host_str())
+                                .domain(url.domain())
+                                .open_with_stream(stream)
+                                .await
+                        }
+                        .await
+                    }
+                    None => builder.open(endpoint).await,
+                },
+                AmqpTransportType::AmqpWebSockets => {
+                    // TLS is provided by the WebSocket, so AMQP itself runs unencrypted over it.
+                    let websocket_url = websocket::websocket_url(&endpoint)?;
+                    let stream = websocket::connect(&websocket_url, options.proxy.as_ref()).await?;
                     builder
-                        .open(endpoint)
+                        .hostname(url.host_str())
+                        .scheme("amqp")
+                        .open_with_stream(stream)
                         .await
-                        .map_err(|e| AmqpError::from(Fe2o3ConnectionOpenError(e)))?,
-                ))
+                }
+            }
+            .map_err(|e| AmqpError::from(Fe2o3ConnectionOpenError(e)))?;
+
+            self.connection
+                .set(Mutex::new(connection))
                 .map_err(|_| Self::connection_already_set())?;
             Ok(())
         }
@@ -107,6 +107,32 @@ impl AmqpManagementApis for Fe2o3AmqpManagement {
         let response = management.call(request).await.map_err(AmqpError::from)?;
         Ok((&response.entity_attributes).into())
     }
+
+    async fn call_with_body(
+        &self,
+        operation_type: String,
+        application_properties: AmqpOrderedMap<String, AmqpSimpleValue>,
+        body: AmqpOrderedMap<String, AmqpValue>,
+    ) -> Result<AmqpOrderedMap<String, AmqpValue>> {
+        let mut management = self
+            .management
+            .get()
+            .ok_or_else(Self::amqp_management_not_attached)?
+            .lock()
+            .await;
+
+        let request = WithBodyRequest {
+            request: WithApplicationPropertiesRequest::new(
+                operation_type,
+                &self.access_token,
+                application_properties,
+            ),
+            body,
+        };
+
+        let response = management.call(request).await.map_err(AmqpError::from)?;
+        Ok((&response.entity_attributes).into())
+    }
 }
 
 impl From<fe2o3_amqp_management::error::Error> for AmqpError {
@@ -196,3 +222,83 @@ impl fe2o3_amqp_management::Request for WithApplicationPropertiesRequest<'_> {
     }
     fn encode_body(self) -> Self::Body {}
 }
+
+/// A management request that also carries a map in its AMQP value body.
+struct WithBodyRequest<'a> {
+    request: WithApplicationPropertiesRequest<'a>,
+    body: AmqpOrderedMap<String, AmqpValue>,
+}
+
+impl fe2o3_amqp_management::Request for WithBodyRequest<'_> {
+    const OPERATION: &'static str = "READ";
+    type Response = ReadResponse;
+    type Body = fe2o3_amqp_types::primitives::Value;
+
+    fn manageable_entity_type(&mut self) -> Option<String> {
+        self.request.manageable_entity_type()
+    }
+    fn locales(&mut self) -> Option<String> {
+        self.request.locales()
+    }
+    fn encode_application_properties(
+        &mut self,
+    ) -> Option<fe2o3_amqp_types::messaging::ApplicationProperties> {
+        self.request.encode_application_properties()
+    }
+    fn encode_body(self) -> Self::Body {
+        fe2o3_amqp_types::primitives::Value::Map(
+            self.body
+                .into_iter()
+                .map(|(key, value)| {
+                    (
+                        fe2o3_amqp_types::primitives::Value::String(key),
+                        value.into(),
+                    )
+                })
+                .collect(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fe2o3_amqp_management::Request;
+    use fe2o3_amqp_types::primitives::Value;
+
+    #[test]
+    fn with_body_request_encodes_body_map() {
+        let access_token = AccessToken::new(
+            azure_core::credentials::Secret::new("token"),
+            azure_core::time::OffsetDateTime::now_utc(),
+        );
+        let mut application_properties = AmqpOrderedMap::new();
+        application_properties.insert("timeout".to_string(), AmqpSimpleValue::UInt(60));
+        let mut body = AmqpOrderedMap::new();
+        body.insert(
+            "session-id".to_string(),
+            AmqpValue::String("session-1".to_string()),
+        );
+
+        let message = WithBodyRequest {
+            request: WithApplicationPropertiesRequest::new(
+                "com.microsoft:get-session-state".to_string(),
+                &access_token,
+                application_properties,
+            ),
+            body,
+        }
+        .into_message();
+
+        let application_properties = message.application_properties.unwrap();
+        assert!(application_properties.0.contains_key("timeout"));
+        assert!(!application_properties.0.contains_key("session-id"));
+        let Value::Map(body) = message.body else {
+            panic!("expected a map body");
+        };
+        assert_eq!(
+            body.get(&Value::String("session-id".to_string())),
+            Some(&Value::String("session-1".to_string()))
+        );
+    }
+}
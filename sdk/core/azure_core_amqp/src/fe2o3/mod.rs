@@ -9,3 +9,4 @@ pub(crate) mod receiver;
 pub(crate) mod sender;
 pub(crate) mod session;
 pub(crate) mod value;
+pub(crate) mod websocket;
@@ -111,6 +111,16 @@ impl AmqpReceiverApis for Fe2o3AmqpReceiver {
         Ok(receiver.lock().await.credit_mode().into())
     }
 
+    async fn set_credit(&self, credit: u32) -> Result<()> {
+        let receiver = self.receiver.get().ok_or_else(Self::receiver_not_set)?;
+        receiver
+            .lock()
+            .await
+            .set_credit(credit)
+            .await
+            .map_err(AmqpError::from)
+    }
+
     async fn receive_delivery(&self) -> Result<AmqpDelivery> {
         let mut receiver = self
             .receiver
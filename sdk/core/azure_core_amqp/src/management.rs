@@ -36,6 +36,22 @@ pub trait AmqpManagementApis {
         operation_type: String,
         application_properties: AmqpOrderedMap<String, AmqpSimpleValue>,
     ) -> Result<AmqpOrderedMap<String, AmqpValue>>;
+
+    /// Calls a management operation with the specified type, application properties, and body.
+    ///
+    /// # Arguments
+    /// - `operation_type`: A string representing the type of management operation to perform.
+    /// - `application_properties`: An ordered map of application properties to include in the management request.
+    /// - `body`: An ordered map sent as the AMQP value body of the management request.
+    ///
+    /// # Returns
+    /// A result containing an ordered map of application properties from the management response, or an error if the operation fails.
+    async fn call_with_body(
+        &self,
+        operation_type: String,
+        application_properties: AmqpOrderedMap<String, AmqpSimpleValue>,
+        body: AmqpOrderedMap<String, AmqpValue>,
+    ) -> Result<AmqpOrderedMap<String, AmqpValue>>;
 }
 
 /// Struct representing the AMQP management functionality.
@@ -60,6 +76,16 @@ impl AmqpManagementApis for AmqpManagement {
             .call(operation_type, application_properties)
             .await
     }
+    async fn call_with_body(
+        &self,
+        operation_type: String,
+        application_properties: AmqpOrderedMap<String, AmqpSimpleValue>,
+        body: AmqpOrderedMap<String, AmqpValue>,
+    ) -> Result<AmqpOrderedMap<String, AmqpValue>> {
+        self.implementation
+            .call_with_body(operation_type, application_properties, body)
+            .await
+    }
 }
 
 impl AmqpManagement {
@@ -142,6 +142,15 @@ impl AmqpManagementApis for NoopAmqpManagement {
     ) -> Result<AmqpOrderedMap<String, AmqpValue>> {
         unimplemented!();
     }
+
+    async fn call_with_body(
+        &self,
+        operation_type: String,
+        application_properties: AmqpOrderedMap<String, AmqpSimpleValue>,
+        body: AmqpOrderedMap<String, AmqpValue>,
+    ) -> Result<AmqpOrderedMap<String, AmqpValue>> {
+        unimplemented!();
+    }
 }
 
 impl NoopAmqpSender {
@@ -217,6 +226,11 @@ impl AmqpReceiverApis for NoopAmqpReceiver {
         unimplemented!();
     }
 
+    #[allow(unused_variables)]
+    async fn set_credit(&self, credit: u32) -> Result<()> {
+        unimplemented!();
+    }
+
     #[allow(unused_variables)]
     async fn receive_delivery(&self) -> Result<AmqpDelivery> {
         unimplemented!();
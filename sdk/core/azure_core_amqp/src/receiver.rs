@@ -97,6 +97,16 @@ pub trait AmqpReceiverApis {
     /// Gets the current credit mode of the AMQP receiver.
     async fn credit_mode(&self) -> Result<ReceiverCreditMode>;
 
+    /// Sets the link credit of the AMQP receiver, allowing the sender to send up to `credit` more
+    /// deliveries.
+    ///
+    /// This is how a receiver in [`ReceiverCreditMode::Manual`] requests deliveries. Link credit is
+    /// absolute: setting it replaces any credit which has not yet been used.
+    ///
+    /// # Arguments
+    /// * `credit` - The number of deliveries the receiver is ready to receive.
+    async fn set_credit(&self, credit: u32) -> Result<()>;
+
     /// Receives a delivery from the AMQP receiver.
     async fn receive_delivery(&self) -> Result<AmqpDelivery>;
 
@@ -138,6 +148,10 @@ impl AmqpReceiverApis for AmqpReceiver {
         self.implementation.credit_mode().await
     }
 
+    async fn set_credit(&self, credit: u32) -> Result<()> {
+        self.implementation.set_credit(credit).await
+    }
+
     /// Receives a delivery from the AMQP receiver.
     ///
     /// This method returns a single [`AmqpDelivery`] that can be used to receive a message from the AMQP receiver.
@@ -72,6 +72,34 @@ pub enum AmqpSimpleValue {
     Described(Box<AmqpDescribed>),
 }
 
+impl AmqpSimpleValue {
+    /// Returns the value as a `T`, or `None` if the value holds a different AMQP type.
+    ///
+    /// Unlike the `From<AmqpSimpleValue>` conversions, this does not panic on a type mismatch, which
+    /// makes it suitable for values received from the wire.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use azure_core_amqp::AmqpSimpleValue;
+    ///
+    /// let value = AmqpSimpleValue::from(42i32);
+    /// assert_eq!(value.get::<i32>(), Some(42));
+    /// assert_eq!(value.get::<String>(), None);
+    /// ```
+    pub fn get<T: FromAmqpSimpleValue>(&self) -> Option<T> {
+        T::from_amqp_simple_value(self)
+    }
+}
+
+/// A type which can be extracted from an [`AmqpSimpleValue`] without panicking.
+///
+/// See [`AmqpSimpleValue::get`].
+pub trait FromAmqpSimpleValue: Sized {
+    /// Returns the value as `Self`, or `None` if the value holds a different AMQP type.
+    fn from_amqp_simple_value(value: &AmqpSimpleValue) -> Option<Self>;
+}
+
 // Note: There is intentionally no conversion from AmqpValue to AmqpSimpleValue. This is because we want a compile time error if you attempt to pass an AmqpValue into something expecting an AmqpSimpleValue.
 // This is to prevent accidental misuse of the API. If you need to convert an AmqpValue to an AmqpSimpleValue, you should do so explicitly.
 
@@ -101,6 +129,15 @@ macro_rules! conversions_for_amqp_simple_types {
                 }
             }
 
+            impl FromAmqpSimpleValue for $t {
+                fn from_amqp_simple_value(v: &AmqpSimpleValue) -> Option<Self> {
+                    match v {
+                        AmqpSimpleValue::$field(v) => Some(v.clone()),
+                        _ => None,
+                    }
+                }
+            }
+
             impl PartialEq<$t> for AmqpSimpleValue {
                 fn eq(&self, other: &$t) -> bool {
                     match self {
@@ -149,3 +186,27 @@ impl From<&str> for AmqpSimpleValue {
         AmqpSimpleValue::String(value.to_string())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn get_matching_type() {
+        assert_eq!(AmqpSimpleValue::from(true).get::<bool>(), Some(true));
+        assert_eq!(AmqpSimpleValue::from(-7i64).get::<i64>(), Some(-7));
+        assert_eq!(
+            AmqpSimpleValue::from("value").get::<String>(),
+            Some("value".to_string())
+        );
+        let id = Uuid::new_v4();
+        assert_eq!(AmqpSimpleValue::from(id).get::<Uuid>(), Some(id));
+    }
+
+    #[test]
+    fn get_mismatched_type() {
+        assert_eq!(AmqpSimpleValue::from(7i32).get::<i64>(), None);
+        assert_eq!(AmqpSimpleValue::from("7").get::<i32>(), None);
+        assert_eq!(AmqpSimpleValue::Null.get::<bool>(), None);
+    }
+}
@@ -14,11 +14,10 @@ use azure_core::{
     error::{CheckSuccessOptions, ErrorKind},
     fmt::SafeDebug,
     http::{
-        pager::{PagerContinuation, PagerResult, PagerState},
-        ClientOptions, Method, Pager, Pipeline, PipelineSendOptions, RawResponse, Request,
+        pager::pageable, ClientOptions, Method, Pager, Pipeline, PipelineSendOptions, Request,
         RequestContent, Response, Url,
     },
-    json, Result,
+    Result,
 };
 use std::sync::Arc;
 
@@ -176,53 +175,15 @@ impl SecretClient {
     }
 
     /// Lists secret properties (paginated).
+    #[pageable(next_link = "nextLink")]
     pub fn list_secret_properties(
         &self,
-        _options: Option<SecretClientListSecretPropertiesOptions<'_>>,
+        options: Option<SecretClientListSecretPropertiesOptions<'_>>,
     ) -> Result<Pager<ListSecretPropertiesResult>> {
-        let pipeline = self.pipeline.clone();
-        let mut first_url = self.endpoint.clone();
-        first_url.set_path("secrets");
-        Ok(Pager::new(
-            move |state: PagerState, pager_options| {
-                let url = match state {
-                    PagerState::More(continuation) => {
-                        continuation.try_into().expect("expected Url")
-                    }
-                    PagerState::Initial => first_url.clone(),
-                };
-                let mut request = Request::new(url, Method::Get);
-                request.insert_header("accept", "application/json");
-                let pipeline = pipeline.clone();
-                let first_url = first_url.clone();
-                Box::pin(async move {
-                    let rsp = pipeline
-                        .send(
-                            &pager_options.context,
-                            &mut request,
-                            Some(PipelineSendOptions {
-                                check_success: CheckSuccessOptions {
-                                    success_codes: &[200],
-                                },
-                                ..Default::default()
-                            }),
-                        )
-                        .await?;
-                    let (status, headers, body) = rsp.deconstruct();
-                    let res: ListSecretPropertiesResult = json::from_json(&body)?;
-                    let rsp = RawResponse::from_bytes(status, headers, body).into();
-                    Ok(match res.next_link {
-                        Some(next_link) if !next_link.is_empty() => PagerResult::More {
-                            response: rsp,
-                            continuation: PagerContinuation::Link(
-                                first_url.join(next_link.as_ref())?,
-                            ),
-                        },
-                        _ => PagerResult::Done { response: rsp },
-                    })
-                })
-            },
-            None,
-        ))
+        let mut url = self.endpoint.clone();
+        url.set_path("secrets");
+        let mut request = Request::new(url, Method::Get);
+        request.insert_header("accept", "application/json");
+        request
     }
 }
@@ -5,6 +5,7 @@
 #![cfg_attr(docsrs, feature(doc_cfg))]
 #![warn(missing_docs)]
 
+mod pageable;
 mod tracing;
 mod tracing_client;
 mod tracing_function;
@@ -72,3 +73,46 @@ pub fn function(attr: TokenStream, item: TokenStream) -> TokenStream {
     tracing_function::parse_function(attr.into(), item.into())
         .map_or_else(|e| e.into_compile_error().into(), |v| v.into())
 }
+
+/// Attribute a client method that returns a `Pager` to generate the paging glue.
+///
+/// Use the `#[pageable]` attribute exported from `azure_core::http::pager`.
+///
+/// The body of the method should evaluate to the `Request` for the first page, and may use `?`.
+/// The method must take `&self` and a parameter named `options` of type `Option<T>` where `T: Default` has a
+/// `method_options: PagerOptions` field, and must return `Result<Pager<P>>` or `Result<Pager<P, F>>`.
+/// Before the body runs, `options` is unwrapped using `Default`; the body should read any query parameters or headers from it.
+///
+/// The generated method sends the request through `self.pipeline`, then reads the continuation for the next page from each response.
+/// Exactly one continuation source is required:
+///
+/// * `next_link = "nextLink"` - A field in the page model containing the absolute URL of the next page.
+/// * `continuation = "NextMarker"` - A field in the page model containing a continuation token.
+/// * `continuation_header = "x-ms-continuation"` - A response header containing a continuation token.
+///
+/// Continuation tokens also require where to send them in the next request, either `query = "marker"` or `header = "x-ms-continuation"`.
+/// Use `pipeline = "field"` if the client's `Pipeline` field is not named `pipeline`.
+///
+/// Paging stops when the continuation is absent or empty. When combined with `#[tracing::function]`, place `#[pageable]` first.
+///
+/// ```ignore
+/// #[azure_core::http::pager::pageable(next_link = "nextLink")]
+/// #[tracing::function("KeyVault.getSecrets")]
+/// pub fn list_secret_properties(
+///     &self,
+///     options: Option<SecretClientListSecretPropertiesOptions<'_>>,
+/// ) -> Result<Pager<ListSecretPropertiesResult>> {
+///     let mut url = self.endpoint.clone();
+///     url.append_path("secrets");
+///     url.query_pairs_mut().append_pair("api-version", &self.api_version);
+///     if let Some(maxresults) = options.maxresults {
+///         url.query_pairs_mut().append_pair("maxresults", &maxresults.to_string());
+///     }
+///     Request::new(url, Method::Get)
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn pageable(attr: TokenStream, item: TokenStream) -> TokenStream {
+    pageable::parse_pageable(attr.into(), item.into())
+        .map_or_else(|e| e.into_compile_error().into(), |v| v.into())
+}
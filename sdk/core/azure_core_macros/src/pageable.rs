@@ -0,0 +1,365 @@
+// Copyright (c) Microsoft Corporation. All rights reserved.
+// Licensed under the MIT License.
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{
+    parse::Parser, spanned::Spanned, FnArg, GenericArgument, Ident, ItemFn, LitStr, Pat,
+    PathArguments, Result, ReturnType, Type,
+};
+
+const INVALID_PAGEABLE_FUNCTION_MESSAGE: &str =
+    "pageable attribute must be applied to a method taking `&self` and `options` and returning `Result<Pager<T>>` or `Result<Pager<T, F>>`";
+
+const INVALID_CONTINUATION_MESSAGE: &str =
+    "pageable attribute requires exactly one of `next_link`, `continuation`, or `continuation_header`";
+
+const MISSING_DESTINATION_MESSAGE: &str =
+    "`continuation` and `continuation_header` require exactly one of `query` or `header`";
+
+const UNEXPECTED_DESTINATION_MESSAGE: &str =
+    "`next_link` cannot be combined with `query` or `header`";
+
+/// Where the continuation for the next page is read from in a response.
+enum Source {
+    /// A field in the page model containing the absolute URL of the next page.
+    NextLink(LitStr),
+    /// A field in the page model containing a continuation token.
+    Field(LitStr),
+    /// A response header containing a continuation token.
+    Header(LitStr),
+}
+
+/// Where a continuation token is sent in the request for the next page.
+enum Destination {
+    Query(LitStr),
+    Header(LitStr),
+}
+
+struct PageableArgs {
+    source: Source,
+    destination: Option<Destination>,
+    pipeline: Ident,
+}
+
+impl PageableArgs {
+    fn parse(attr: TokenStream) -> Result<Self> {
+        let mut next_link = None;
+        let mut continuation = None;
+        let mut continuation_header = None;
+        let mut query = None;
+        let mut header = None;
+        let mut pipeline = None;
+
+        let parser = syn::meta::parser(|meta| {
+            let value = || -> Result<LitStr> { meta.value()?.parse() };
+            if meta.path.is_ident("next_link") {
+                next_link = Some(value()?);
+            } else if meta.path.is_ident("continuation") {
+                continuation = Some(value()?);
+            } else if meta.path.is_ident("continuation_header") {
+                continuation_header = Some(value()?);
+            } else if meta.path.is_ident("query") {
+                query = Some(value()?);
+            } else if meta.path.is_ident("header") {
+                header = Some(value()?);
+            } else if meta.path.is_ident("pipeline") {
+                pipeline = Some(value()?.parse::<Ident>()?);
+            } else {
+                return Err(meta.error(
+                    "unsupported pageable attribute, expected one of: next_link, continuation, continuation_header, query, header, pipeline",
+                ));
+            }
+            Ok(())
+        });
+        let span = attr.span();
+        parser.parse2(attr)?;
+
+        let source = match (next_link, continuation, continuation_header) {
+            (Some(name), None, None) => Source::NextLink(name),
+            (None, Some(name), None) => Source::Field(name),
+            (None, None, Some(name)) => Source::Header(name),
+            _ => return Err(syn::Error::new(span, INVALID_CONTINUATION_MESSAGE)),
+        };
+        let destination = match (&source, query, header) {
+            (Source::NextLink(_), None, None) => None,
+            (Source::NextLink(_), ..) => {
+                return Err(syn::Error::new(span, UNEXPECTED_DESTINATION_MESSAGE))
+            }
+            (_, Some(name), None) => Some(Destination::Query(name)),
+            (_, None, Some(name)) => Some(Destination::Header(name)),
+            _ => return Err(syn::Error::new(span, MISSING_DESTINATION_MESSAGE)),
+        };
+
+        Ok(Self {
+            source,
+            destination,
+            pipeline: pipeline.unwrap_or_else(|| Ident::new("pipeline", span)),
+        })
+    }
+}
+
+/// Parse the token stream for a pageable client method.
+///
+/// The body of the method must evaluate to the `Request` for the first page. It may refer to
+/// `options`, which has already been unwrapped to the options type, and may use `?`.
+/// The method body is replaced by one that sends that request through `self.pipeline`, reads
+/// the continuation from each response, and returns a `Pager`.
+pub fn parse_pageable(attr: TokenStream, item: TokenStream) -> Result<TokenStream> {
+    let args = PageableArgs::parse(attr)?;
+    let ItemFn {
+        attrs,
+        vis,
+        sig,
+        block,
+    } = syn::parse2(item)?;
+
+    if !has_options_parameter(&sig) {
+        return Err(syn::Error::new(
+            sig.span(),
+            INVALID_PAGEABLE_FUNCTION_MESSAGE,
+        ));
+    }
+    let (page, format) = pager_types(&sig.output)
+        .ok_or_else(|| syn::Error::new(sig.output.span(), INVALID_PAGEABLE_FUNCTION_MESSAGE))?;
+
+    let pipeline = &args.pipeline;
+    let apply = generate_apply(&args);
+    let extract = generate_extract(&args, &format);
+
+    Ok(quote! {
+        #(#attrs)*
+        #vis #sig {
+            let options = options.unwrap_or_default();
+            let first_request: ::azure_core::http::Request = #block;
+            let pipeline = self.#pipeline.clone();
+            let method_options = ::azure_core::http::pager::PagerOptions {
+                context: options.method_options.context.into_owned(),
+                ..options.method_options
+            };
+            ::std::result::Result::Ok(::azure_core::http::Pager::new(
+                move |state: ::azure_core::http::pager::PagerState,
+                      pager_options: ::azure_core::http::pager::PagerOptions<'static>| {
+                    let mut request = first_request.clone();
+                    if let ::azure_core::http::pager::PagerState::More(continuation) = state {
+                        #apply
+                    }
+                    let pipeline = pipeline.clone();
+                    ::std::boxed::Box::pin(async move {
+                        let rsp: ::azure_core::http::Response<#page, #format> = pipeline
+                            .send(&pager_options.context, &mut request, ::std::option::Option::None)
+                            .await?
+                            .into();
+                        #extract
+                    })
+                },
+                ::std::option::Option::Some(method_options),
+            ))
+        }
+    })
+}
+
+fn has_options_parameter(sig: &syn::Signature) -> bool {
+    let mut inputs = sig.inputs.iter();
+    if !matches!(inputs.next(), Some(FnArg::Receiver(r)) if r.reference.is_some()) {
+        return false;
+    }
+    inputs.any(|arg| {
+        matches!(arg, FnArg::Typed(pat) if matches!(pat.pat.as_ref(), Pat::Ident(ident) if ident.ident == "options"))
+    })
+}
+
+/// Gets the page type and format from a return type of `Result<Pager<P>>` or `Result<Pager<P, F>>`.
+fn pager_types(output: &ReturnType) -> Option<(Type, Type)> {
+    let ReturnType::Type(_, ty) = output else {
+        return None;
+    };
+    let result = last_segment(ty)?;
+    if result.ident != "Result" {
+        return None;
+    }
+    let pager = match generic_args(result)?.first()? {
+        GenericArgument::Type(ty) => last_segment(ty)?,
+        _ => return None,
+    };
+    if pager.ident != "Pager" {
+        return None;
+    }
+    let mut args = generic_args(pager)?.iter().filter_map(|arg| match arg {
+        GenericArgument::Type(ty) => Some(ty.clone()),
+        _ => None,
+    });
+    let page = args.next()?;
+    let format = args
+        .next()
+        .unwrap_or_else(|| syn::parse_quote!(::azure_core::http::JsonFormat));
+    Some((page, format))
+}
+
+fn last_segment(ty: &Type) -> Option<&syn::PathSegment> {
+    match ty {
+        Type::Path(path) => path.path.segments.last(),
+        _ => None,
+    }
+}
+
+fn generic_args(
+    segment: &syn::PathSegment,
+) -> Option<&syn::punctuated::Punctuated<GenericArgument, syn::token::Comma>> {
+    match &segment.arguments {
+        PathArguments::AngleBracketed(args) => Some(&args.args),
+        _ => None,
+    }
+}
+
+/// Generates code to apply `continuation` to `request` for the next page.
+fn generate_apply(args: &PageableArgs) -> TokenStream {
+    match &args.destination {
+        None => quote! {
+            if let ::std::result::Result::Ok(url) = ::azure_core::http::Url::try_from(continuation) {
+                *request.url_mut() = url;
+            }
+        },
+        Some(Destination::Query(name)) => quote! {
+            let mut query_builder = ::azure_core::http::UrlExt::query_builder(request.url_mut());
+            query_builder.set_pair(#name, continuation.as_ref());
+            query_builder.build();
+        },
+        Some(Destination::Header(name)) => quote! {
+            request.insert_header(#name, ::std::string::String::from(continuation));
+        },
+    }
+}
+
+/// Generates code to read the continuation from `rsp` and return a `PagerResult`.
+fn generate_extract(args: &PageableArgs, format: &Type) -> TokenStream {
+    let more = |continuation: TokenStream| {
+        quote! {
+            ::std::result::Result::Ok(::azure_core::http::pager::PagerResult::More {
+                response: rsp,
+                continuation: #continuation,
+            })
+        }
+    };
+    let done = quote! {
+        ::std::result::Result::Ok(::azure_core::http::pager::PagerResult::Done { response: rsp })
+    };
+
+    let (field, continuation) = match &args.source {
+        Source::Header(name) => {
+            let more = more(quote! { ::azure_core::http::pager::PagerContinuation::Token(token) });
+            return quote! {
+                match rsp.headers().get_optional_string(&::azure_core::http::headers::HeaderName::from_static(#name)) {
+                    ::std::option::Option::Some(token) if !token.is_empty() => #more,
+                    _ => #done,
+                }
+            };
+        }
+        Source::NextLink(name) => (
+            name,
+            quote! {
+                ::azure_core::http::pager::PagerContinuation::Link(
+                    ::azure_core::http::Url::parse(&next).map_err(|err| {
+                        ::azure_core::Error::new(::azure_core::error::ErrorKind::DataConversion, err)
+                    })?,
+                )
+            },
+        ),
+        Source::Field(name) => (
+            name,
+            quote! { ::azure_core::http::pager::PagerContinuation::Token(next) },
+        ),
+    };
+    let more = more(continuation);
+
+    quote! {
+        #[derive(::azure_core::__private::serde::Deserialize)]
+        #[serde(crate = "::azure_core::__private::serde")]
+        struct Continuation {
+            #[serde(rename = #field)]
+            next: ::std::option::Option<::std::string::String>,
+        }
+
+        let page: Continuation =
+            <Continuation as ::azure_core::http::DeserializeWith<#format>>::deserialize_with(rsp.body().clone())?;
+        match page.next {
+            ::std::option::Option::Some(next) if !next.is_empty() => #more,
+            _ => #done,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn requires_continuation() {
+        let err = parse_pageable(
+            quote! { query = "marker" },
+            quote! {
+                pub fn list(&self, options: Option<ListOptions<'_>>) -> Result<Pager<ListResult>> {
+                    Request::new(self.endpoint.clone(), Method::Get)
+                }
+            },
+        )
+        .unwrap_err();
+        assert_eq!(INVALID_CONTINUATION_MESSAGE, err.to_string());
+    }
+
+    #[test]
+    fn token_requires_destination() {
+        let err = parse_pageable(
+            quote! { continuation = "NextMarker" },
+            quote! {
+                pub fn list(&self, options: Option<ListOptions<'_>>) -> Result<Pager<ListResult>> {
+                    Request::new(self.endpoint.clone(), Method::Get)
+                }
+            },
+        )
+        .unwrap_err();
+        assert_eq!(MISSING_DESTINATION_MESSAGE, err.to_string());
+    }
+
+    #[test]
+    fn requires_pager_return() {
+        let err = parse_pageable(
+            quote! { next_link = "nextLink" },
+            quote! {
+                pub fn list(&self, options: Option<ListOptions<'_>>) -> Result<Response<ListResult>> {
+                    Request::new(self.endpoint.clone(), Method::Get)
+                }
+            },
+        )
+        .unwrap_err();
+        assert_eq!(INVALID_PAGEABLE_FUNCTION_MESSAGE, err.to_string());
+    }
+
+    #[test]
+    fn requires_options() {
+        let err = parse_pageable(
+            quote! { next_link = "nextLink" },
+            quote! {
+                pub fn list(&self) -> Result<Pager<ListResult>> {
+                    Request::new(self.endpoint.clone(), Method::Get)
+                }
+            },
+        )
+        .unwrap_err();
+        assert_eq!(INVALID_PAGEABLE_FUNCTION_MESSAGE, err.to_string());
+    }
+
+    #[test]
+    fn format_from_return_type() {
+        let output: ReturnType = syn::parse_quote! { -> Result<Pager<ListBlobsResult, XmlFormat>> };
+        let (page, format) = pager_types(&output).unwrap();
+        assert_eq!(
+            quote! { ListBlobsResult }.to_string(),
+            quote! { #page }.to_string()
+        );
+        assert_eq!(
+            quote! { XmlFormat }.to_string(),
+            quote! { #format }.to_string()
+        );
+    }
+}
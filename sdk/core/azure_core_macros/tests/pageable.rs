@@ -0,0 +1,194 @@
+// Copyright (c) Microsoft Corporation. All rights reserved.
+// Licensed under the MIT License.
+
+//! Integration tests for `#[pageable]`.
+
+use async_trait::async_trait;
+use azure_core::{
+    http::{
+        headers::{HeaderName, Headers},
+        pager::{pageable, Page, PagerOptions},
+        AsyncRawResponse, ClientOptions, HttpClient, Method, Pager, Pipeline, Request, StatusCode,
+        Transport, Url,
+    },
+    Result,
+};
+use azure_core_test::http::MockHttpClient;
+use futures::{FutureExt as _, TryStreamExt as _};
+use serde::Deserialize;
+use std::sync::{Arc, Mutex};
+
+#[derive(Deserialize)]
+struct ListItemsResult {
+    value: Vec<String>,
+}
+
+#[async_trait]
+impl Page for ListItemsResult {
+    type Item = String;
+    type IntoIter = std::vec::IntoIter<String>;
+
+    async fn into_items(self) -> Result<Self::IntoIter> {
+        Ok(self.value.into_iter())
+    }
+}
+
+#[derive(Default)]
+struct ListItemsOptions<'a> {
+    filter: Option<String>,
+    method_options: PagerOptions<'a>,
+}
+
+struct TestClient {
+    endpoint: Url,
+    pipeline: Pipeline,
+}
+
+impl TestClient {
+    fn new(transport: Arc<dyn HttpClient>) -> Self {
+        Self {
+            endpoint: "https://example.com/items".parse().unwrap(),
+            pipeline: Pipeline::new(
+                Some("test"),
+                Some("1.0.0"),
+                ClientOptions {
+                    transport: Some(Transport::new(transport)),
+                    ..Default::default()
+                },
+                Vec::new(),
+                Vec::new(),
+                None,
+            ),
+        }
+    }
+
+    #[pageable(next_link = "nextLink")]
+    fn list_items(&self, options: Option<ListItemsOptions<'_>>) -> Result<Pager<ListItemsResult>> {
+        let mut url = self.endpoint.clone();
+        if let Some(filter) = options.filter.as_deref() {
+            url.query_pairs_mut().append_pair("filter", filter);
+        }
+        Request::new(url, Method::Get)
+    }
+
+    #[pageable(
+        continuation_header = "x-ms-continuation",
+        header = "x-ms-continuation"
+    )]
+    fn list_items_by_header(
+        &self,
+        options: Option<ListItemsOptions<'_>>,
+    ) -> Result<Pager<ListItemsResult>> {
+        Request::new(self.endpoint.clone(), Method::Get)
+    }
+
+    #[pageable(continuation = "nextMarker", query = "marker")]
+    fn list_items_by_marker(
+        &self,
+        options: Option<ListItemsOptions<'_>>,
+    ) -> Result<Pager<ListItemsResult>> {
+        Request::new(self.endpoint.clone(), Method::Get)
+    }
+}
+
+type Requests = Arc<Mutex<Vec<(String, Option<String>)>>>;
+
+/// Creates a transport that returns `pages` in order and records each request URL and continuation header.
+fn transport(pages: Vec<(Headers, &'static str)>) -> (Arc<dyn HttpClient>, Requests) {
+    const CONTINUATION: HeaderName = HeaderName::from_static("x-ms-continuation");
+    let requests = Arc::new(Mutex::new(Vec::new()));
+    let pages = Arc::new(Mutex::new(pages.into_iter()));
+    let client = {
+        let requests = requests.clone();
+        MockHttpClient::new(move |req: &Request| {
+            requests.lock().unwrap().push((
+                req.url().to_string(),
+                req.headers().get_optional_string(&CONTINUATION),
+            ));
+            let (headers, body) = pages.lock().unwrap().next().expect("unexpected request");
+            async move { Ok(AsyncRawResponse::from_bytes(StatusCode::Ok, headers, body)) }.boxed()
+        })
+    };
+    (Arc::new(client), requests)
+}
+
+#[tokio::test]
+async fn follows_next_link() {
+    let (transport, requests) = transport(vec![
+        (
+            Headers::new(),
+            r#"{"value":["a","b"],"nextLink":"https://example.com/items?page=2"}"#,
+        ),
+        (Headers::new(), r#"{"value":["c"],"nextLink":""}"#),
+    ]);
+    let client = TestClient::new(transport);
+
+    let items: Vec<String> = client
+        .list_items(Some(ListItemsOptions {
+            filter: Some("x".into()),
+            ..Default::default()
+        }))
+        .unwrap()
+        .try_collect()
+        .await
+        .unwrap();
+
+    assert_eq!(vec!["a", "b", "c"], items);
+    let urls: Vec<String> = requests
+        .lock()
+        .unwrap()
+        .iter()
+        .map(|r| r.0.clone())
+        .collect();
+    assert_eq!(
+        vec![
+            "https://example.com/items?filter=x",
+            "https://example.com/items?page=2",
+        ],
+        urls
+    );
+}
+
+#[tokio::test]
+async fn sends_header_continuation() {
+    let mut headers = Headers::new();
+    headers.insert("x-ms-continuation", "token");
+    let (transport, requests) = transport(vec![
+        (headers, r#"{"value":["a"]}"#),
+        (Headers::new(), r#"{"value":["b"]}"#),
+    ]);
+    let client = TestClient::new(transport);
+
+    let items: Vec<String> = client
+        .list_items_by_header(None)
+        .unwrap()
+        .try_collect()
+        .await
+        .unwrap();
+
+    assert_eq!(vec!["a", "b"], items);
+    let requests = requests.lock().unwrap();
+    assert_eq!(None, requests[0].1);
+    assert_eq!(Some("token"), requests[1].1.as_deref());
+}
+
+#[tokio::test]
+async fn sends_query_continuation() {
+    let (transport, requests) = transport(vec![
+        (Headers::new(), r#"{"value":["a"],"nextMarker":"m1"}"#),
+        (Headers::new(), r#"{"value":["b"]}"#),
+    ]);
+    let client = TestClient::new(transport);
+
+    let items: Vec<String> = client
+        .list_items_by_marker(None)
+        .unwrap()
+        .try_collect()
+        .await
+        .unwrap();
+
+    assert_eq!(vec!["a", "b"], items);
+    let requests = requests.lock().unwrap();
+    assert_eq!("https://example.com/items", requests[0].0);
+    assert_eq!("https://example.com/items?marker=m1", requests[1].0);
+}
@@ -5,7 +5,7 @@
 use azure_core::{
     credentials::{AccessToken, Secret, TokenCredential, TokenRequestOptions},
     http::ClientOptions,
-    time::{Duration, OffsetDateTime},
+    time::{self, Duration},
 };
 use azure_identity::DeveloperToolsCredential;
 use azure_identity::{
@@ -32,7 +32,7 @@ impl TokenCredential for MockCredential {
         _: Option<TokenRequestOptions<'_>>,
     ) -> azure_core::Result<AccessToken> {
         let token: Secret = format!("TEST TOKEN {}", scopes.join(" ")).into();
-        let expires_on = OffsetDateTime::now_utc().saturating_add(Duration::minutes(5));
+        let expires_on = time::now_utc().saturating_add(Duration::minutes(5));
 
         Ok(AccessToken { token, expires_on })
     }
@@ -65,6 +65,7 @@ pub fn from_env(options: Option<ClientOptions>) -> azure_core::Result<Arc<dyn To
                 Some(AzurePipelinesCredentialOptions {
                     credential_options: ClientAssertionCredentialOptions {
                         client_options: options.unwrap_or_default(),
+                        ..Default::default()
                     },
                 }),
             )? as Arc<dyn TokenCredential>);
@@ -3,5 +3,7 @@
 
 //! HTTP testing utilities.
 mod clients;
+mod recording;
 
 pub use clients::*;
+pub use recording::*;
@@ -0,0 +1,384 @@
+// Copyright (c) Microsoft Corporation. All rights reserved.
+// Licensed under the MIT License.
+
+use async_trait::async_trait;
+use azure_core::{
+    base64,
+    error::ErrorKind,
+    http::{headers::Headers, request::Request, AsyncRawResponse, HttpClient, Method},
+    test::TestMode,
+    Bytes, Error, Result,
+};
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::{BTreeMap, VecDeque},
+    fmt, fs,
+    path::{Path, PathBuf},
+    sync::{Arc, Mutex},
+};
+
+/// An [`HttpClient`] that records requests and responses to a JSON file, or plays them back from that file,
+/// without the [Test Proxy](https://github.com/Azure/azure-sdk-tools/blob/main/tools/test-proxy/Azure.Sdk.Tools.TestProxy/README.md).
+///
+/// Use this for tests that must run offline or where the Test Proxy is not available.
+/// Tests attributed with `#[recorded::test]` should use [`Recording::instrument()`](crate::Recording::instrument) instead,
+/// which supports sanitizers and matchers.
+///
+/// Requests are played back in the order they were recorded and must match the recorded method and URL.
+/// Request headers and bodies are never recorded, since they often carry credentials like client secrets,
+/// but URLs and response bodies are recorded as received, so do not record secrets like SAS tokens in query parameters.
+///
+/// # Examples
+///
+/// ```no_run
+/// use azure_core::{http::{ClientOptions, Transport}, test::TestMode};
+/// use azure_core_test::http::LocalRecordingClient;
+/// use std::sync::Arc;
+///
+/// # fn main() -> azure_core::Result<()> {
+/// # let http_client = azure_core::http::new_http_client(None);
+/// let test_mode = TestMode::current()?;
+/// let client = LocalRecordingClient::new(test_mode, http_client, "tests/recordings/get_secret.json")?;
+/// let options = ClientOptions {
+///     transport: Some(Transport::new(Arc::new(client))),
+///     ..Default::default()
+/// };
+/// # Ok(())
+/// # }
+/// ```
+pub struct LocalRecordingClient {
+    path: PathBuf,
+    mode: Mode,
+}
+
+enum Mode {
+    Live(Arc<dyn HttpClient>),
+    Record {
+        client: Arc<dyn HttpClient>,
+        entries: Mutex<Vec<Entry>>,
+    },
+    Playback(Mutex<VecDeque<Entry>>),
+}
+
+impl LocalRecordingClient {
+    /// Creates a new `LocalRecordingClient` for the given [`TestMode`].
+    ///
+    /// * [`TestMode::Live`] sends requests using `client` without recording them.
+    /// * [`TestMode::Record`] sends requests using `client` and records them to `path`, replacing any existing recording.
+    /// * [`TestMode::Playback`] plays back responses recorded to `path` without sending requests.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `test_mode` is [`TestMode::Playback`] and the recording cannot be read.
+    pub fn new(
+        test_mode: TestMode,
+        client: Arc<dyn HttpClient>,
+        path: impl Into<PathBuf>,
+    ) -> Result<Self> {
+        let path = path.into();
+        let mode = match test_mode {
+            TestMode::Live => Mode::Live(client),
+            TestMode::Record => Mode::Record {
+                client,
+                entries: Mutex::new(Vec::new()),
+            },
+            TestMode::Playback => {
+                let recording = fs::read(&path).map_err(|err| {
+                    Error::with_error(
+                        ErrorKind::Io,
+                        err,
+                        format!("failed to read recording {}", path.display()),
+                    )
+                })?;
+                let recording: RecordingFile = serde_json::from_slice(&recording)?;
+                Mode::Playback(Mutex::new(recording.entries.into()))
+            }
+        };
+
+        Ok(Self { path, mode })
+    }
+
+    /// Gets the path to the recording file.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    async fn record(
+        &self,
+        client: &dyn HttpClient,
+        entries: &Mutex<Vec<Entry>>,
+        req: &Request,
+    ) -> Result<AsyncRawResponse> {
+        let response = client
+            .execute_request(req)
+            .await?
+            .try_into_raw_response()
+            .await?;
+        let (status, headers, body) = response.deconstruct();
+        let body: Bytes = body.into();
+
+        let entry = Entry {
+            method: req.method(),
+            uri: req.url().to_string(),
+            status_code: status.into(),
+            response_headers: headers
+                .iter()
+                .map(|(name, value)| (name.as_str().to_string(), value.as_str().to_string()))
+                .collect(),
+            response_body: RecordedBody::new(&body),
+        };
+
+        let mut entries = entries.lock().map_err(lock_error)?;
+        entries.push(entry);
+        self.save(&entries)?;
+
+        Ok(AsyncRawResponse::from_bytes(status, headers, body))
+    }
+
+    fn play_back(
+        &self,
+        entries: &Mutex<VecDeque<Entry>>,
+        req: &Request,
+    ) -> Result<AsyncRawResponse> {
+        let mut entries = entries.lock().map_err(lock_error)?;
+        let Some(entry) = entries.pop_front() else {
+            return Err(Error::with_message(
+                ErrorKind::Other,
+                format!(
+                    "no recorded response for {} {} in {}",
+                    req.method(),
+                    req.url(),
+                    self.path.display()
+                ),
+            ));
+        };
+
+        if entry.method != req.method() || entry.uri != req.url().as_str() {
+            return Err(Error::with_message(
+                ErrorKind::Other,
+                format!(
+                    "request {} {} does not match recorded request {} {} in {}",
+                    req.method(),
+                    req.url(),
+                    entry.method,
+                    entry.uri,
+                    self.path.display()
+                ),
+            ));
+        }
+
+        let mut headers = Headers::new();
+        for (name, value) in entry.response_headers {
+            headers.insert(name, value);
+        }
+        let body = entry
+            .response_body
+            .map(RecordedBody::into_bytes)
+            .transpose()?
+            .unwrap_or_default();
+
+        Ok(AsyncRawResponse::from_bytes(
+            entry.status_code.into(),
+            headers,
+            body,
+        ))
+    }
+
+    fn save(&self, entries: &[Entry]) -> Result<()> {
+        if let Some(dir) = self.path.parent().filter(|dir| !dir.as_os_str().is_empty()) {
+            fs::create_dir_all(dir)?;
+        }
+        let recording = serde_json::to_vec_pretty(&RecordingFileRef { entries })?;
+        fs::write(&self.path, recording).map_err(|err| {
+            Error::with_error(
+                ErrorKind::Io,
+                err,
+                format!("failed to write recording {}", self.path.display()),
+            )
+        })
+    }
+}
+
+impl fmt::Debug for LocalRecordingClient {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mode = match self.mode {
+            Mode::Live(_) => TestMode::Live,
+            Mode::Record { .. } => TestMode::Record,
+            Mode::Playback(_) => TestMode::Playback,
+        };
+        f.debug_struct("LocalRecordingClient")
+            .field("path", &self.path)
+            .field("mode", &mode)
+            .finish()
+    }
+}
+
+#[async_trait]
+impl HttpClient for LocalRecordingClient {
+    async fn execute_request(&self, req: &Request) -> Result<AsyncRawResponse> {
+        match &self.mode {
+            Mode::Live(client) => client.execute_request(req).await,
+            Mode::Record { client, entries } => self.record(client.as_ref(), entries, req).await,
+            Mode::Playback(entries) => self.play_back(entries, req),
+        }
+    }
+}
+
+fn lock_error(_: impl std::error::Error) -> Error {
+    Error::with_message(ErrorKind::Other, "failed to lock recorded entries")
+}
+
+#[derive(Deserialize)]
+struct RecordingFile {
+    entries: Vec<Entry>,
+}
+
+#[derive(Serialize)]
+struct RecordingFileRef<'a> {
+    entries: &'a [Entry],
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Entry {
+    method: Method,
+    uri: String,
+    status_code: u16,
+    #[serde(default)]
+    response_headers: BTreeMap<String, String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    response_body: Option<RecordedBody>,
+}
+
+/// A body recorded as text if valid UTF-8, or base64-encoded otherwise.
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+enum RecordedBody {
+    Text(String),
+    Base64(String),
+}
+
+impl RecordedBody {
+    fn new(bytes: &[u8]) -> Option<Self> {
+        if bytes.is_empty() {
+            return None;
+        }
+        Some(match std::str::from_utf8(bytes) {
+            Ok(text) => Self::Text(text.to_string()),
+            Err(_) => Self::Base64(base64::encode(bytes)),
+        })
+    }
+
+    fn into_bytes(self) -> Result<Bytes> {
+        Ok(match self {
+            Self::Text(text) => Bytes::from(text),
+            Self::Base64(encoded) => Bytes::from(base64::decode(encoded)?),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::http::MockHttpClient;
+    use azure_core::http::{headers::HeaderName, StatusCode};
+    use futures::FutureExt as _;
+
+    const REQUEST_ID: HeaderName = HeaderName::from_static("x-ms-request-id");
+
+    fn recording_path(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!("{name}-{}.json", std::process::id()))
+    }
+
+    #[tokio::test]
+    async fn record_and_play_back() {
+        let path = recording_path("record_and_play_back");
+        let mock_client = Arc::new(MockHttpClient::new(|req| {
+            async move {
+                let mut headers = Headers::new();
+                headers.insert(REQUEST_ID, "1");
+                let body = match req.url().path() {
+                    "/text" => Bytes::from_static(br#"{"value":"secret"}"#),
+                    _ => Bytes::from_static(&[0xff, 0x00, 0xfe]),
+                };
+                Ok(AsyncRawResponse::from_bytes(StatusCode::Ok, headers, body))
+            }
+            .boxed()
+        }));
+
+        let mut text_req = Request::new("https://localhost/text".parse().unwrap(), Method::Post);
+        text_req.set_body(Bytes::from_static(b"client_secret=request-secret"));
+        let binary_req = Request::new("https://localhost/binary".parse().unwrap(), Method::Get);
+
+        let client = LocalRecordingClient::new(TestMode::Record, mock_client, &path).unwrap();
+        for req in [&text_req, &binary_req] {
+            client.execute_request(req).await.unwrap();
+        }
+        let recording = fs::read_to_string(&path).unwrap();
+        assert!(!recording.contains("request-secret"), "{recording}");
+
+        let client = LocalRecordingClient::new(
+            TestMode::Playback,
+            Arc::new(MockHttpClient::new(|_| {
+                async { panic!("playback should not send requests") }.boxed()
+            })),
+            &path,
+        )
+        .unwrap();
+
+        let response = client
+            .execute_request(&text_req)
+            .await
+            .unwrap()
+            .try_into_raw_response()
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::Ok);
+        assert_eq!(response.headers().get_str(&REQUEST_ID).unwrap(), "1");
+        assert_eq!(response.body().as_ref(), br#"{"value":"secret"}"#);
+
+        let response = client
+            .execute_request(&binary_req)
+            .await
+            .unwrap()
+            .try_into_raw_response()
+            .await
+            .unwrap();
+        assert_eq!(response.body().as_ref(), &[0xff, 0x00, 0xfe]);
+
+        client.execute_request(&binary_req).await.unwrap_err();
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[tokio::test]
+    async fn play_back_mismatch() {
+        let path = recording_path("play_back_mismatch");
+        fs::write(
+            &path,
+            r#"{"entries":[{"method":"GET","uri":"https://localhost/a","statusCode":204}]}"#,
+        )
+        .unwrap();
+
+        let client = LocalRecordingClient::new(
+            TestMode::Playback,
+            Arc::new(MockHttpClient::new(|_| async { unreachable!() }.boxed())),
+            &path,
+        )
+        .unwrap();
+        fs::remove_file(&path).unwrap();
+
+        let req = Request::new("https://localhost/b".parse().unwrap(), Method::Get);
+        let err = client.execute_request(&req).await.unwrap_err();
+        assert!(err.to_string().contains("does not match"), "{err}");
+    }
+
+    #[test]
+    fn play_back_missing_recording() {
+        LocalRecordingClient::new(
+            TestMode::Playback,
+            Arc::new(MockHttpClient::new(|_| async { unreachable!() }.boxed())),
+            recording_path("play_back_missing_recording"),
+        )
+        .unwrap_err();
+    }
+}
@@ -23,10 +23,18 @@ pub const DEFAULT_IGNORED_HEADERS: &[&str; 6] = &[
 ];
 
 /// Matchers to use for a recording or playback.
+///
+/// A matcher determines which recorded request matches a request made during playback.
+/// Use a [`CustomDefaultMatcher`] to ignore volatile values like dates, client request IDs, or session tokens.
 #[derive(Debug, Serialize)]
 pub enum Matcher {
+    /// Matches requests without comparing bodies.
     BodilessMatcher,
+
+    /// Matches requests without comparing headers.
     HeaderlessMatcher,
+
+    /// Matches requests using the default matcher with custom settings.
     #[serde(untagged)]
     CustomDefaultMatcher(CustomDefaultMatcher),
 }
@@ -57,18 +65,35 @@ impl TryFrom<Matcher> for Bytes {
 }
 
 /// A custom matcher.
+///
+/// # Examples
+///
+/// Ignore a session token header and a volatile query parameter in addition to the [`DEFAULT_IGNORED_HEADERS`].
+///
+/// ```
+/// use azure_core_test::{CustomDefaultMatcher, Matcher, DEFAULT_IGNORED_HEADERS};
+///
+/// let matcher: Matcher = CustomDefaultMatcher {
+///     ignored_headers: [DEFAULT_IGNORED_HEADERS.as_slice(), &["x-ms-session-token"]].concat(),
+///     ignored_query_parameters: vec!["timestamp"],
+///     ..Default::default()
+/// }
+/// .into();
+/// ```
 #[derive(Debug, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct CustomDefaultMatcher {
-    /// Whether to compare bodies during playback.
+    /// Whether to compare bodies during playback. The default is `true`.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub compare_bodies: Option<bool>,
 
-    /// Header to exclude from the recording.
+    /// Headers to exclude from matching entirely.
+    ///
+    /// Unlike [`CustomDefaultMatcher::ignored_headers`], these headers may also be missing from either request.
     #[serde(serialize_with = "join", skip_serializing_if = "Vec::is_empty")]
     pub excluded_headers: Vec<&'static str>,
 
-    /// Headers to ignore during playback.
+    /// Headers whose values are ignored during playback. The headers must still be present in both requests.
     ///
     /// The default is [`DEFAULT_IGNORED_HEADERS`].
     #[serde(serialize_with = "join", skip_serializing_if = "Vec::is_empty")]
@@ -114,3 +139,19 @@ fn serialize_custom_default_matcher() {
         r#"{"compareBodies":false,"ignoredHeaders":"foo,bar"}"#
     )
 }
+
+#[test]
+fn serialize_custom_default_matcher_query_parameters() {
+    let v: Matcher = CustomDefaultMatcher {
+        excluded_headers: vec!["x-ms-session-token"],
+        ignored_headers: Vec::new(),
+        ignore_query_ordering: Some(true),
+        ignored_query_parameters: vec!["timestamp", "nonce"],
+        ..Default::default()
+    }
+    .into();
+    assert_eq!(
+        serde_json::to_string(&v).unwrap(),
+        r#"{"excludedHeaders":"x-ms-session-token","ignoreQueryOrdering":true,"ignoredQueryParameters":"timestamp,nonce"}"#
+    )
+}
@@ -7,9 +7,19 @@ use crate::{
     recording::Recording,
     TestContext,
 };
-use azure_core::{test::TestMode, Result};
+use azure_core::{
+    random::{self, RandomSource},
+    test::TestMode,
+    time::{self, Clock, OffsetDateTime},
+    Result,
+};
 pub use azure_core_test_macros::test;
-use std::sync::Arc;
+use rand::{Rng as _, SeedableRng as _};
+use rand_chacha::ChaCha20Rng;
+use std::{
+    future::Future,
+    sync::{Arc, Mutex, PoisonError},
+};
 use tokio::sync::OnceCell;
 
 static ONLY_TRACE: std::sync::OnceLock<()> = std::sync::OnceLock::new();
@@ -92,6 +102,56 @@ pub async fn start(
     Ok(ctx)
 }
 
+/// Runs a recorded test started with [`start`].
+///
+/// When playing back, `test` runs with a [`Clock`] fixed at [`PLAYBACK_TIME`] and a [`RandomSource`] with a fixed seed,
+/// so times and random data client libraries compute, like retry jitter, client request IDs, and token refresh
+/// windows, are the same on every run. Every `#[recorded::test]` will call this automatically.
+pub async fn run<F, Fut>(ctx: TestContext, test: F) -> Fut::Output
+where
+    F: FnOnce(TestContext) -> Fut,
+    Fut: Future,
+{
+    let playback = ctx
+        .recording
+        .as_ref()
+        .is_some_and(|recording| recording.test_mode() == TestMode::Playback);
+    if !playback {
+        return test(ctx).await;
+    }
+
+    let clock = Arc::new(PlaybackClock);
+    let random_source = Arc::new(PlaybackRandomSource(Mutex::new(ChaCha20Rng::from_seed(
+        [0; 32],
+    ))));
+    time::with_clock(clock, random::with_random_source(random_source, test(ctx))).await
+}
+
+/// The current time while playing back a recorded test: 2025-01-01T00:00:00Z.
+pub const PLAYBACK_TIME: OffsetDateTime =
+    OffsetDateTime::UNIX_EPOCH.saturating_add(azure_core::time::Duration::seconds(1_735_689_600));
+
+#[derive(Debug)]
+struct PlaybackClock;
+
+impl Clock for PlaybackClock {
+    fn now_utc(&self) -> OffsetDateTime {
+        PLAYBACK_TIME
+    }
+}
+
+#[derive(Debug)]
+struct PlaybackRandomSource(Mutex<ChaCha20Rng>);
+
+impl RandomSource for PlaybackRandomSource {
+    fn next_u64(&self) -> u64 {
+        self.0
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .next_u64()
+    }
+}
+
 fn init_tracing() {
     #[cfg(feature = "tracing")]
     {
@@ -104,3 +164,39 @@ fn init_tracing() {
             .init();
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn playback_context() -> TestContext {
+        let mut ctx = TestContext::new(env!("CARGO_MANIFEST_DIR"), file!(), "run")
+            .expect("valid test context");
+        ctx.recording = Some(Recording::with_seed());
+        ctx
+    }
+
+    #[tokio::test]
+    async fn run_fixes_clock_and_random_source_in_playback() {
+        let first = run(playback_context(), |_| async {
+            (time::now_utc(), random::new_uuid(), random::new_uuid())
+        })
+        .await;
+        assert_eq!(PLAYBACK_TIME, first.0);
+        assert_ne!(first.1, first.2);
+
+        let second = run(playback_context(), |_| async {
+            (time::now_utc(), random::new_uuid(), random::new_uuid())
+        })
+        .await;
+        assert_eq!(first, second);
+    }
+
+    #[tokio::test]
+    async fn run_uses_system_clock_without_recording() {
+        let ctx = TestContext::new(env!("CARGO_MANIFEST_DIR"), file!(), "run")
+            .expect("valid test context");
+        let now = run(ctx, |_| async { time::now_utc() }).await;
+        assert!(now > PLAYBACK_TIME);
+    }
+}
@@ -17,7 +17,7 @@ use crate::{
         Proxy, ProxyExt, RecordingId,
     },
     recording::policy::RecordingModePolicy,
-    Matcher, Sanitizer,
+    GeneralStringSanitizer, Matcher, Sanitizer,
 };
 use azure_core::{
     base64,
@@ -58,6 +58,8 @@ pub struct Recording {
     recording_assets_file: Option<String>,
     id: Option<RecordingId>,
     variables: RwLock<HashMap<String, String>>,
+    // Sanitizers for the values of variables retrieved with `VarOptions::sanitize`, added when the recording is stopped.
+    variable_sanitizers: Mutex<Vec<GeneralStringSanitizer>>,
     rand: OnceLock<Mutex<ChaCha20Rng>>,
 }
 
@@ -67,6 +69,29 @@ unsafe impl Send for Recording {}
 
 impl Recording {
     /// Adds a [`Sanitizer`] to sanitize PII for the current test.
+    ///
+    /// Sanitizers are applied before the recording is saved, so they can be added any time before the test ends.
+    ///
+    /// # Examples
+    ///
+    /// Replace a generated resource ID in request URIs.
+    ///
+    /// ```no_run
+    /// use azure_core_test::{recorded, TestContext, UriRegexSanitizer};
+    ///
+    /// #[recorded::test]
+    /// async fn test_create(ctx: TestContext) -> azure_core::Result<()> {
+    ///     let recording = ctx.recording();
+    ///     recording
+    ///         .add_sanitizer(UriRegexSanitizer {
+    ///             regex: Some(r"/items/(?<id>[0-9a-f-]{36})".into()),
+    ///             group_for_replace: Some("id".into()),
+    ///             ..Default::default()
+    ///         })
+    ///         .await?;
+    ///     Ok(())
+    /// }
+    /// ```
     pub async fn add_sanitizer<S>(&self, sanitizer: S) -> azure_core::Result<()>
     where
         S: Sanitizer,
@@ -359,6 +384,9 @@ impl Recording {
     }
 
     /// Gets a required variable from the environment or recording.
+    ///
+    /// If [`VarOptions::sanitize`] is `true`, the value is recorded as [`VarOptions::sanitize_value`]
+    /// and replaced with it wherever it appears in recorded requests and responses.
     pub fn var<K>(&self, key: K, options: Option<VarOptions>) -> String
     where
         K: AsRef<str>,
@@ -389,12 +417,81 @@ impl Recording {
 
         // Do not record unset (None) environment variables.
         if let Some(sanitized) = sanitized {
+            if let Some(target) = value
+                .as_deref()
+                .filter(|v| !v.is_empty() && *v != sanitized)
+            {
+                let mut sanitizers = self.variable_sanitizers.lock().ok()?;
+                sanitizers.push(GeneralStringSanitizer {
+                    target: target.into(),
+                    value: Some(sanitized.clone()),
+                    condition: None,
+                });
+            }
+
             let mut variables = self.variables.write().map_err(write_lock_error).ok()?;
             variables.insert(key.into(), sanitized);
         }
 
         value
     }
+
+    /// Gets a variable from the recording, or inserts the value returned by `f` into the recording.
+    ///
+    /// Use this to persist values generated by a test, like resource names, so that playback uses the exact same values.
+    /// When running live, `f` is always called. When recording, `f` is called once per `key` and its value is saved to the recording.
+    /// When playing back, the value saved to the recording is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use azure_core_test::{recorded, TestContext};
+    /// use std::time::{SystemTime, UNIX_EPOCH};
+    ///
+    /// #[recorded::test]
+    /// async fn test_create_database(ctx: TestContext) -> azure_core::Result<()> {
+    ///     let recording = ctx.recording();
+    ///     let database_name = recording.var_or_insert_with("databaseName", || {
+    ///         let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap();
+    ///         format!("db{}", now.as_secs())
+    ///     });
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the recording variables cannot be locked for reading or writing,
+    /// or if playing back and `key` was not saved to the recording.
+    pub fn var_or_insert_with<K, F>(&self, key: K, f: F) -> String
+    where
+        K: AsRef<str>,
+        F: FnOnce() -> String,
+    {
+        let key = key.as_ref();
+        match self.test_mode {
+            TestMode::Live => f(),
+            TestMode::Playback => {
+                let variables = self
+                    .variables
+                    .read()
+                    .map_err(read_lock_error)
+                    .unwrap_or_else(|err| panic!("{err}"));
+                variables
+                    .get(key)
+                    .cloned()
+                    .unwrap_or_else(|| panic!("{key} is not recorded"))
+            }
+            TestMode::Record => {
+                let mut variables = self
+                    .variables
+                    .write()
+                    .map_err(write_lock_error)
+                    .unwrap_or_else(|err| panic!("{err}"));
+                variables.entry(key.into()).or_insert_with(f).clone()
+            }
+        }
+    }
 }
 
 const RANDOM_SEED_NAME: &str = "RandomSeed";
@@ -419,6 +516,7 @@ impl Recording {
             recording_assets_file,
             id: None,
             variables: RwLock::new(HashMap::new()),
+            variable_sanitizers: Mutex::new(Vec::new()),
             rand: OnceLock::new(),
         }
     }
@@ -441,6 +539,7 @@ impl Recording {
                 RANDOM_SEED_NAME.into(),
                 (&"test8S9UCR2yV8LU01tq+VNEwGssAXVUbL0Hd488GAYVosM="[4..]).into(), // Prefix but then drop is to avoid CredScan false positives
             )])),
+            variable_sanitizers: Mutex::new(Vec::new()),
             rand: OnceLock::new(),
         }
     }
@@ -576,6 +675,18 @@ impl Recording {
         match self.test_mode {
             TestMode::Playback => client.playback_stop(recording_id.as_ref(), None).await,
             TestMode::Record => {
+                let sanitizers =
+                    std::mem::take(&mut *self.variable_sanitizers.lock().map_err(|err| {
+                        azure_core::Error::with_message(ErrorKind::Other, err.to_string())
+                    })?);
+                for sanitizer in sanitizers {
+                    let options = ClientAddSanitizerOptions {
+                        recording_id: Some(recording_id),
+                        ..Default::default()
+                    };
+                    client.add_sanitizer(sanitizer, Some(options)).await?;
+                }
+
                 let payload = {
                     let variables = self.variables.read().map_err(read_lock_error)?;
                     VariablePayload {
@@ -816,3 +927,72 @@ fn test_var_options_apply_sanitized() {
     assert_eq!(value, Some("test".to_string()));
     assert_eq!(sanitized, Some("*****".into()));
 }
+
+#[test]
+fn test_var_sanitized_in_recording() {
+    let recording = Recording::new(
+        TestMode::Record,
+        tracing::trace_span!("test_var_sanitized_in_recording").entered(),
+        None,
+        "sdk/core",
+        String::from("none"),
+        None,
+    );
+
+    let options = |value: &'static str| VarOptions {
+        default_value: Some(value.into()),
+        sanitize: true,
+        ..Default::default()
+    };
+    assert_eq!(
+        recording.var("TEST_ACCOUNT_NAME_NOT_SET", Some(options("myaccount"))),
+        "myaccount"
+    );
+    assert_eq!(recording.var("TEST_EMPTY_NOT_SET", Some(options(""))), "");
+    assert_eq!(
+        recording.var(
+            "TEST_UNSANITIZED_NOT_SET",
+            Some(VarOptions {
+                default_value: Some("unsanitized".into()),
+                ..Default::default()
+            })
+        ),
+        "unsanitized"
+    );
+
+    let sanitizers = recording.variable_sanitizers.lock().unwrap();
+    assert_eq!(sanitizers.len(), 1);
+    assert_eq!(sanitizers[0].target, "myaccount");
+    assert_eq!(sanitizers[0].value.as_deref(), Some("Sanitized"));
+}
+
+#[test]
+fn test_var_or_insert_with() {
+    let recording = Recording::new(
+        TestMode::Record,
+        tracing::trace_span!("test_var_or_insert_with").entered(),
+        None,
+        "sdk/core",
+        String::from("none"),
+        None,
+    );
+    assert_eq!(
+        recording.var_or_insert_with("databaseName", || "db1".into()),
+        "db1"
+    );
+    assert_eq!(
+        recording.var_or_insert_with("databaseName", || panic!("already recorded")),
+        "db1"
+    );
+
+    let playback = Recording::with_seed();
+    playback
+        .variables
+        .write()
+        .unwrap()
+        .extend(recording.variables.read().unwrap().clone());
+    assert_eq!(
+        playback.var_or_insert_with("databaseName", || panic!("playing back")),
+        "db1"
+    );
+}
@@ -14,6 +14,8 @@ use proc_macro::TokenStream;
 /// * `live` - Run the test only in live mode. The test will be ignored unless `AZURE_TEST_MODE=live`.
 /// * `playback` - Run the test only in playback mode. The test will be ignored unless `AZURE_TEST_MODE=playback`.
 ///   Note: Only use this for tests that validate playback-specific behavior. Most tests should not use this option.
+/// * `no_record` - Run the test live without recording when `AZURE_TEST_MODE` is `record` or `live`. The test will be ignored when playing back.
+///   Use this for tests that require resources not available in every environment, or whose traffic cannot be played back.
 ///
 /// # Examples
 ///
@@ -39,6 +41,17 @@ use proc_macro::TokenStream;
 /// }
 /// ```
 ///
+/// For tests that should never be recorded, you must declare an async function that may accept a `TestContext` and must return a `Result<T, E>`.
+///
+/// ```no_run
+/// use azure_core_test::{recorded, TestContext};
+///
+/// #[recorded::test(no_record)]
+/// async fn test(ctx: TestContext) -> Result<(), Box<dyn std::error::Error>> {
+///     todo!()
+/// }
+/// ```
+///
 /// Read documentation for `azure_core_test` for more information and examples.
 #[proc_macro_attribute]
 pub fn test(attr: TokenStream, item: TokenStream) -> TokenStream {
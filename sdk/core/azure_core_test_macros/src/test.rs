@@ -9,7 +9,9 @@ use syn::{
 };
 
 const INVALID_RECORDED_ATTRIBUTE_MESSAGE: &str =
-    "expected `#[recorded::test]`, `#[recorded::test(live)]`, or `#[recorded::test(playback)]`";
+    "expected `#[recorded::test]`, `#[recorded::test(live)]`, `#[recorded::test(playback)]`, or `#[recorded::test(no_record)]`";
+const CONFLICTING_RECORDED_ATTRIBUTE_MESSAGE: &str =
+    "`playback` cannot be combined with `live` or `no_record`";
 const INVALID_RECORDED_FUNCTION_MESSAGE: &str =
     "expected `async fn(TestContext)` function signature with `Result<T, E>` return";
 const INVALID_TEST_MODE_MESSAGE: &str = "expected 'playback', 'record', or 'live'";
@@ -81,10 +83,22 @@ pub fn parse_test(attr: TokenStream, item: TokenStream) -> Result<TokenStream> {
         });
     }
 
+    // Ignore tests that are never recorded if playing back, and run them live otherwise.
+    let test_mode = if recorded_attrs.no_record {
+        if test_mode == TestMode::Playback {
+            test_attr.extend(quote! {
+                #[ignore = "skipping tests without recordings"]
+            });
+        }
+        TestMode::Live
+    } else {
+        test_mode
+    };
+
     let fn_name = &original_sig.ident;
     let mut inputs = original_sig.inputs.iter();
     let setup = match inputs.next() {
-        None if recorded_attrs.live => quote! {
+        None if recorded_attrs.live || recorded_attrs.no_record => quote! {
             #fn_name().await
         },
         Some(FnArg::Typed(PatType { ty, .. })) if is_test_context(ty.as_ref()) => {
@@ -98,7 +112,7 @@ pub fn parse_test(attr: TokenStream, item: TokenStream) -> Result<TokenStream> {
                     stringify!(#fn_name),
                     ::std::option::Option::None,
                 ).await?;
-                #fn_name(ctx).await
+                ::azure_core_test::recorded::run(ctx, #fn_name).await
             }
         }
         _ => {
@@ -141,6 +155,7 @@ static TEST_MODE: LazyLock<TestMode> = LazyLock::new(|| {
 struct Attributes {
     live: bool,
     playback: bool,
+    no_record: bool,
 }
 
 impl Parse for Attributes {
@@ -155,6 +170,7 @@ impl Parse for Attributes {
                     match ident.to_string().as_str() {
                         "live" => attrs.live = true,
                         "playback" => attrs.playback = true,
+                        "no_record" => attrs.no_record = true,
                         _ => {
                             return Err(syn::Error::new(
                                 arg.span(),
@@ -171,6 +187,12 @@ impl Parse for Attributes {
                 }
             }
         }
+        if attrs.playback && (attrs.live || attrs.no_record) {
+            return Err(syn::Error::new(
+                input.span(),
+                CONFLICTING_RECORDED_ATTRIBUTE_MESSAGE,
+            ));
+        }
         Ok(attrs)
     }
 }
@@ -327,6 +349,51 @@ mod tests {
         assert!(!attrs.live);
     }
 
+    #[test]
+    fn attributes_parse_no_record() {
+        let attr: Attribute = syn::parse_quote! {
+            #[recorded(no_record)]
+        };
+        let attrs: Attributes = attr.parse_args().unwrap();
+        assert!(attrs.no_record);
+        assert!(!attrs.live);
+        assert!(!attrs.playback);
+    }
+
+    #[test]
+    fn attributes_parse_playback_conflicts() {
+        for attr in [
+            syn::parse_quote! { #[recorded(playback, live)] },
+            syn::parse_quote! { #[recorded(no_record, playback)] },
+        ] {
+            let attr: Attribute = attr;
+            attr.parse_args::<Attributes>().unwrap_err();
+        }
+    }
+
+    #[test]
+    fn parse_recorded_no_record() {
+        let attr = quote! { no_record };
+        let item = quote! {
+            async fn no_record() -> azure_core::Result<()> {
+                todo!()
+            }
+        };
+        parse_test(attr, item).unwrap();
+    }
+
+    #[test]
+    fn parse_recorded_no_record_with_context() {
+        let attr = quote! { no_record };
+        let item = quote! {
+            async fn no_record(ctx: TestContext) -> azure_core::Result<()> {
+                todo!()
+            }
+        };
+        let tokens = parse_test(attr, item).unwrap().to_string();
+        assert!(tokens.contains(":: azure_core_test :: TestMode :: Live"));
+    }
+
     #[test]
     fn parse_recorded_playback_only() {
         let attr = quote! { playback };
@@ -6,7 +6,7 @@
 use std::borrow::Cow;
 
 #[cfg(feature = "derive")]
-pub use typespec_macros::SafeDebug;
+pub use typespec_macros::{ExtensibleEnum, SafeDebug};
 
 /// Converts ASCII characters in `value` to lowercase if required; otherwise, returns the original slice.
 ///
@@ -1,9 +1,15 @@
 // Copyright (c) Microsoft Corporation. All rights reserved.
 // Licensed under the MIT License.
 
+use crate::{
+    error::{Error, ErrorKind},
+    time::{self, Duration, OffsetDateTime},
+};
+use futures::future::{self, Either};
 use std::any::{Any, TypeId};
 use std::borrow::{Borrow, Cow};
 use std::collections::HashMap;
+use std::future::Future;
 use std::sync::Arc;
 
 /// Pipeline execution context.
@@ -98,6 +104,33 @@ impl<'a> Context<'a> {
             .and_then(|item| item.downcast_ref())
     }
 
+    /// Sets the [`Deadline`] by which the request must complete and returns `Self` to allow chaining.
+    ///
+    /// A deadline can only be shortened: if the context already carries an earlier deadline, that one is kept.
+    /// Pipeline policies check the deadline before and while they wait, so the request fails as soon as it
+    /// can no longer complete in time.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use typespec_client_core::{http::{Context, Deadline}, time::Duration};
+    ///
+    /// let context = Context::new().with_deadline(Deadline::after(Duration::seconds(30)));
+    /// assert!(context.deadline().is_some());
+    /// ```
+    #[must_use]
+    pub fn with_deadline(mut self, deadline: Deadline) -> Self {
+        if self.deadline().is_none_or(|current| deadline < current) {
+            self.insert(deadline);
+        }
+        self
+    }
+
+    /// Returns the [`Deadline`] by which the request must complete, if one was set.
+    pub fn deadline(&self) -> Option<Deadline> {
+        self.value::<Deadline>().copied()
+    }
+
     /// Returns `true` if the type map is empty; otherwise, `false`.
     pub fn is_empty(&self) -> bool {
         self.type_map.is_empty()
@@ -158,6 +191,68 @@ impl Default for Context<'_> {
     }
 }
 
+/// A point in time by which a request must complete.
+///
+/// Set a deadline with [`Context::with_deadline`]. Pipeline policies that wait — retry delays, token acquisition,
+/// and the transport — stop and return an error once the deadline passes.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Deadline(OffsetDateTime);
+
+impl Deadline {
+    /// Creates a deadline at the given point in time.
+    pub fn at(expires_on: OffsetDateTime) -> Self {
+        Self(expires_on)
+    }
+
+    /// Creates a deadline `timeout` from the current time.
+    pub fn after(timeout: Duration) -> Self {
+        Self(time::now_utc() + timeout)
+    }
+
+    /// Returns the point in time at which the deadline expires.
+    pub fn expires_on(&self) -> OffsetDateTime {
+        self.0
+    }
+
+    /// Returns the time left before the deadline expires, or zero if it has already expired.
+    pub fn remaining(&self) -> Duration {
+        (self.0 - time::now_utc()).max(Duration::ZERO)
+    }
+
+    /// Returns `true` if the deadline has expired.
+    pub fn is_expired(&self) -> bool {
+        self.remaining().is_zero()
+    }
+
+    /// Runs `future` to completion unless the deadline expires first.
+    ///
+    /// Returns the future's result, or a "deadline exceeded" error if the deadline expired before the future
+    /// completed. An already expired deadline fails without polling `future`.
+    pub async fn run<F, T>(&self, future: F) -> crate::Result<T>
+    where
+        F: Future<Output = crate::Result<T>>,
+    {
+        let remaining = self.remaining();
+        if remaining.is_zero() {
+            return Err(self.exceeded_error());
+        }
+
+        let sleep = crate::sleep::sleep(remaining);
+        futures::pin_mut!(future, sleep);
+        match future::select(future, sleep).await {
+            Either::Left((result, _)) => result,
+            Either::Right(((), _)) => Err(self.exceeded_error()),
+        }
+    }
+
+    /// Returns the error reported when work is cut short by this deadline.
+    pub fn exceeded_error(&self) -> Error {
+        Error::with_message_fn(ErrorKind::Other, || {
+            format!("deadline {} exceeded", time::to_rfc3339(&self.0))
+        })
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -264,4 +359,47 @@ mod tests {
         let b_ptr = std::ptr::addr_of!(*b.type_map);
         assert_ne!(a_ptr, b_ptr);
     }
+
+    #[test]
+    fn with_deadline_keeps_earliest() {
+        let early = Deadline::at(OffsetDateTime::UNIX_EPOCH + Duration::seconds(10));
+        let late = Deadline::at(OffsetDateTime::UNIX_EPOCH + Duration::seconds(20));
+
+        let context = Context::new().with_deadline(late).with_deadline(early);
+        assert_eq!(context.deadline(), Some(early));
+
+        let context = context.with_deadline(late);
+        assert_eq!(context.deadline(), Some(early));
+    }
+
+    #[test]
+    fn expired_deadline_has_no_remaining_time() {
+        let deadline = Deadline::at(OffsetDateTime::UNIX_EPOCH);
+        assert!(deadline.is_expired());
+        assert_eq!(deadline.remaining(), Duration::ZERO);
+
+        let deadline = Deadline::after(Duration::hours(1));
+        assert!(!deadline.is_expired());
+        assert!(deadline.remaining() > Duration::minutes(59));
+    }
+
+    #[tokio::test]
+    async fn deadline_run() {
+        let deadline = Deadline::after(Duration::hours(1));
+        assert_eq!(deadline.run(async { Ok(42) }).await.unwrap(), 42);
+
+        let deadline = Deadline::at(OffsetDateTime::UNIX_EPOCH);
+        let error = deadline
+            .run(async { Ok::<_, Error>(42) })
+            .await
+            .expect_err("expired deadline");
+        assert_eq!(error.kind(), &ErrorKind::Other);
+
+        let deadline = Deadline::after(Duration::milliseconds(10));
+        let error = deadline
+            .run(futures::future::pending::<crate::Result<()>>())
+            .await
+            .expect_err("pending future should not outlive the deadline");
+        assert_eq!(error.kind(), &ErrorKind::Other);
+    }
 }
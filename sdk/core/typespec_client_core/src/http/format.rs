@@ -90,6 +90,14 @@ impl<D: DeserializeOwned> DeserializeWith<XmlFormat> for D {
     }
 }
 
+/// A format indicating that the response body is deserialized by a custom deserializer.
+///
+/// [`Response::with_deserializer`](crate::http::Response::with_deserializer) attaches the deserializer and returns a
+/// `Response<T, CustomFormat>`, whose [`into_model`](crate::http::Response::into_model) invokes it. Use this for formats
+/// other than JSON or XML, such as CSV, Avro, or protobuf.
+#[derive(Debug, Clone)]
+pub struct CustomFormat;
+
 /// A [`Format`] indicating that the response has no structured format.
 /// This includes responses that return raw data and that don't return a response body.
 ///
@@ -7,3 +7,5 @@ mod common;
 
 pub use common::*;
 pub use typespec::http::headers::*;
+#[cfg(feature = "derive")]
+pub use typespec_macros::AsHeaders;
@@ -32,6 +32,8 @@ use std::collections::BTreeMap;
 
 // Re-export important types.
 pub use typespec::http::StatusCode;
+#[cfg(feature = "derive")]
+pub use typespec_macros::QueryParams;
 pub use url::Url;
 
 /// Add a new query pair into the target [`Url`]'s query string.
@@ -9,10 +9,13 @@ mod transport;
 pub use retry::*;
 pub use transport::*;
 
-use crate::http::{
-    headers::RETRY_AFTER,
-    policies::{Policy, RetryHeaders},
-    Context, StatusCode,
+use crate::{
+    http::{
+        headers::RETRY_AFTER,
+        policies::{Policy, RetryHeaders},
+        Context, StatusCode,
+    },
+    time::Duration,
 };
 use std::borrow::Cow;
 use std::fmt::Debug;
@@ -50,6 +53,29 @@ pub struct ClientOptions {
 
     /// Logging options.
     pub logging: LoggingOptions,
+
+    /// Limits on buffering response bodies.
+    pub response_body: ResponseBodyOptions,
+}
+
+/// Limits on collecting a response body into memory.
+///
+/// A [`Pipeline`](crate::http::Pipeline) applies these limits when it buffers a response before it is deserialized.
+/// Streamed responses are not limited unless collected with
+/// [`AsyncResponseBody::collect_with_options`](crate::http::AsyncResponseBody::collect_with_options).
+///
+/// Exceeding a limit fails with a [`ResponseBodyLimitError`](crate::http::response::ResponseBodyLimitError).
+#[derive(Clone, Debug, Default)]
+pub struct ResponseBodyOptions {
+    /// The maximum number of bytes to collect.
+    ///
+    /// If `None`, bodies of any size are collected.
+    pub max_size: Option<usize>,
+
+    /// The maximum time to wait for the entire body to be read.
+    ///
+    /// If `None`, reading the body waits indefinitely.
+    pub read_timeout: Option<Duration>,
 }
 
 /// Method options allow customization of client method calls.
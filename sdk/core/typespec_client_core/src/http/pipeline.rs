@@ -5,7 +5,7 @@ use typespec::http::RawResponse;
 
 use crate::http::{
     policies::{Buffer, LoggingPolicy, Policy, TransportPolicy},
-    AsyncRawResponse, ClientOptions, Context, PipelineOptions, Request,
+    AsyncRawResponse, ClientOptions, Context, PipelineOptions, Request, ResponseBodyOptions,
 };
 use std::sync::Arc;
 
@@ -30,6 +30,7 @@ use std::sync::Arc;
 #[derive(Debug, Clone)]
 pub struct Pipeline {
     pipeline: Vec<Arc<dyn Policy>>,
+    response_body: ResponseBodyOptions,
 }
 
 /// Options for the [`Pipeline::send`] function.
@@ -92,7 +93,10 @@ impl Pipeline {
         #[cfg(debug_assertions)]
         debug_assert_eq!(pipeline.len(), initial_capacity);
 
-        Self { pipeline }
+        Self {
+            pipeline,
+            response_body: options.response_body,
+        }
     }
 
     /// Gets the policies in the order a [`Request`] is processed.
@@ -101,6 +105,8 @@ impl Pipeline {
     }
 
     /// Sends a [`Request`] through each configured [`Policy`] and gets a [`RawResponse`] that is processed by each policy in reverse.
+    ///
+    /// The response body is buffered within the limits of [`ClientOptions::response_body`].
     pub async fn send(
         &self,
         ctx: &Context<'_>,
@@ -109,7 +115,7 @@ impl Pipeline {
     ) -> crate::Result<RawResponse> {
         // Signal the TransportPolicy to buffer the entire response.
         let mut ctx = ctx.to_borrowed();
-        ctx.insert(Buffer);
+        ctx.insert(Buffer(self.response_body.clone()));
 
         self.pipeline[0]
             .send(&ctx, request, &self.pipeline[1..])
@@ -137,8 +143,10 @@ mod tests {
     use crate::{
         error::{Error, ErrorKind},
         http::{
-            headers::Headers, policies::PolicyResult, AsyncRawResponse, FixedRetryOptions,
-            JsonFormat, Method, Response, RetryOptions, StatusCode, Transport,
+            headers::{Headers, CONTENT_LENGTH},
+            policies::PolicyResult,
+            AsyncRawResponse, FixedRetryOptions, JsonFormat, Method, Response, RetryOptions,
+            StatusCode, Transport,
         },
         stream::BytesStream,
         Bytes,
@@ -195,6 +203,111 @@ mod tests {
         assert_eq!("baz", &model.bar);
     }
 
+    #[tokio::test]
+    async fn send_limits_response_body() {
+        #[derive(Debug, Default)]
+        struct Responder {
+            count: Mutex<usize>,
+        }
+
+        #[async_trait::async_trait]
+        impl Policy for Responder {
+            async fn send(
+                &self,
+                _ctx: &Context,
+                _request: &mut Request,
+                _next: &[Arc<dyn Policy>],
+            ) -> PolicyResult {
+                *self.count.lock().await += 1;
+                let buffer = Bytes::from_static(br#"{"foo":1,"bar":"baz"}"#);
+                let stream: BytesStream = buffer.into();
+                Ok(AsyncRawResponse::new(
+                    StatusCode::Ok,
+                    Headers::new(),
+                    Box::pin(stream),
+                ))
+            }
+        }
+
+        let responder = Arc::new(Responder::default());
+        let options = ClientOptions {
+            transport: Some(Transport::with_policy(responder.clone())),
+            response_body: ResponseBodyOptions {
+                max_size: Some(8),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let pipeline = Pipeline::new(options, Vec::new(), Vec::new(), None);
+        let mut request = Request::new("http://localhost".parse().unwrap(), Method::Get);
+        let err = pipeline
+            .send(&Context::default(), &mut request, None)
+            .await
+            .unwrap_err();
+
+        // The retry policy adds context to errors it does not retry.
+        let mut source: Option<&(dyn std::error::Error + 'static)> = Some(&err);
+        let limit_error = std::iter::from_fn(|| {
+            let error = source?;
+            source = error.source();
+            Some(error)
+        })
+        .find_map(|error| error.downcast_ref::<crate::http::response::ResponseBodyLimitError>());
+        assert_eq!(
+            Some(&crate::http::response::ResponseBodyLimitError::TooLarge { max_size: 8 }),
+            limit_error
+        );
+        // An oversized body is not retried.
+        assert_eq!(1, *responder.count.lock().await);
+    }
+
+    #[tokio::test]
+    async fn send_limits_response_body_ignores_head_content_length() {
+        #[derive(Debug)]
+        struct Responder;
+
+        #[async_trait::async_trait]
+        impl Policy for Responder {
+            async fn send(
+                &self,
+                _ctx: &Context,
+                _request: &mut Request,
+                _next: &[Arc<dyn Policy>],
+            ) -> PolicyResult {
+                // A HEAD response describes the body a GET would return but has none.
+                let mut headers = Headers::new();
+                headers.insert(CONTENT_LENGTH, "1073741824");
+                let stream: BytesStream = Bytes::new().into();
+                Ok(AsyncRawResponse::new(
+                    StatusCode::Ok,
+                    headers,
+                    Box::pin(stream),
+                ))
+            }
+        }
+
+        let options = ClientOptions {
+            transport: Some(Transport::with_policy(Arc::new(Responder))),
+            response_body: ResponseBodyOptions {
+                max_size: Some(8),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let pipeline = Pipeline::new(options, Vec::new(), Vec::new(), None);
+        let mut request = Request::new("http://localhost".parse().unwrap(), Method::Head);
+        let response = pipeline
+            .send(&Context::default(), &mut request, None)
+            .await
+            .unwrap();
+
+        assert_eq!(
+            Some("1073741824"),
+            response.headers().get_optional_str(&CONTENT_LENGTH)
+        );
+        assert!(response.body().is_empty());
+    }
+
     #[derive(Debug, Default)]
     struct Counter {
         count: Mutex<usize>,
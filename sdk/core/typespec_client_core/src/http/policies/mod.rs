@@ -9,10 +9,12 @@ use std::sync::Arc;
 
 mod logging;
 mod retry;
+mod routing;
 mod transport;
 
 pub(crate) use logging::*;
 pub use retry::*;
+pub use routing::*;
 pub use transport::*;
 
 /// A specialized `Result` type for policies.
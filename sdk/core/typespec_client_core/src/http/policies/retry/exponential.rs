@@ -59,7 +59,7 @@ impl RetryPolicy for ExponentialRetryPolicy {
 
     fn sleep_duration(&self, retry_count: u32) -> Duration {
         let sleep_ms = self.initial_delay.whole_milliseconds() as u64 * 2u64.pow(retry_count)
-            + u64::from(rand::random::<u8>());
+            + (crate::random::next_u64() % 256);
         let sleep_ms = sleep_ms.min(
             self.max_delay
                 .whole_milliseconds()
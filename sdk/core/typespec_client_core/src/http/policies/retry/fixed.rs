@@ -53,7 +53,7 @@ impl super::RetryPolicy for FixedRetryPolicy {
     }
 
     fn sleep_duration(&self, _retry_count: u32) -> Duration {
-        let sleep_ms = self.delay.whole_milliseconds() as u64 + u64::from(rand::random::<u8>());
+        let sleep_ms = self.delay.whole_milliseconds() as u64 + (crate::random::next_u64() % 256);
         Duration::milliseconds(sleep_ms as i64)
     }
 }
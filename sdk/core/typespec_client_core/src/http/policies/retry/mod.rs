@@ -30,7 +30,7 @@ fn try_parse_retry_after_http_date(http_date: &str) -> Option<OffsetDateTime> {
 }
 
 /// A function that returns an `OffsetDateTime`.
-type DateTimeFn = fn() -> OffsetDateTime;
+pub type DateTimeFn = fn() -> OffsetDateTime;
 
 /// Get the duration to delay between retry attempts, provided by the headers from the response.
 ///
@@ -70,7 +70,7 @@ pub fn get_retry_after(
 /// A wrapper around a retry count to be used in the context of a retry policy.
 ///
 /// This allows a post-retry policy to access the retry count
-pub struct RetryPolicyCount(u32);
+pub struct RetryPolicyCount(pub(crate) u32);
 
 impl Deref for RetryPolicyCount {
     type Target = u32;
@@ -148,6 +148,94 @@ const DEFAULT_RETRY_STATUS_CODES: &[StatusCode] = &[
     StatusCode::GatewayTimeout,
 ];
 
+/// What a [`RetryPolicy`] observed from a single attempt, independent of how the attempt was sent.
+#[derive(Clone, Copy, Debug)]
+pub enum RetryAttempt<'a> {
+    /// The transport returned a response.
+    Response {
+        /// The status code of the response.
+        status: StatusCode,
+        /// The headers of the response.
+        headers: &'a Headers,
+    },
+    /// The attempt failed with an error of the given kind.
+    Error(&'a ErrorKind),
+}
+
+/// What a [`RetryPolicy`] decided to do after a [`RetryAttempt`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum RetryDecision {
+    /// Return the response to the caller; the status is not retriable.
+    Return,
+    /// Return the error to the caller; the error did not originate in the transport.
+    Fail,
+    /// Return the last response or error to the caller; the policy has expired.
+    Expired,
+    /// Wait and then retry the request.
+    Retry {
+        /// The duration to wait requested by the service, if any.
+        retry_after: Option<Duration>,
+    },
+}
+
+/// Decides whether a request should be retried after an attempt.
+///
+/// This function performs no I/O and does not consult the clock except through `now`,
+/// so retry behavior can be tested deterministically without sending requests or sleeping.
+///
+/// # Arguments
+/// * `policy` - The retry policy to consult.
+/// * `attempt` - The outcome of the most recent attempt.
+/// * `elapsed` - The time elapsed since the first attempt completed.
+/// * `retry_count` - The number of retries performed so far.
+/// * `now` - A function returning the current time, used to evaluate HTTP-date `Retry-After` headers.
+pub fn evaluate_retry<P>(
+    policy: &P,
+    attempt: RetryAttempt<'_>,
+    elapsed: Duration,
+    retry_count: u32,
+    now: DateTimeFn,
+) -> RetryDecision
+where
+    P: RetryPolicy + ?Sized,
+{
+    let retry_after = match attempt {
+        RetryAttempt::Response { status, headers } => {
+            let mut retry_status_codes = policy.retry_status_codes();
+            if retry_status_codes.is_empty() {
+                retry_status_codes = DEFAULT_RETRY_STATUS_CODES;
+            }
+            if !retry_status_codes.contains(&status) {
+                return RetryDecision::Return;
+            }
+
+            // For a 429 response (TooManyRequests) or 503 (ServiceUnavailable),
+            // use any "retry-after" headers returned by the server to determine how long to wait before retrying.
+            // https://learn.microsoft.com/en-us/azure/architecture/best-practices/retry-service-specific#retry-usage-guidance
+            match status {
+                StatusCode::TooManyRequests | StatusCode::ServiceUnavailable => {
+                    policy.retry_headers().and_then(|retry_headers| {
+                        get_retry_after(headers, now, &retry_headers.retry_headers)
+                    })
+                }
+                _ => None,
+            }
+        }
+        RetryAttempt::Error(kind) => {
+            if !matches!(kind, ErrorKind::Io | ErrorKind::Connection) {
+                return RetryDecision::Fail;
+            }
+            // Transport error so no Retry-After headers - leave the retry period up to the policy
+            None
+        }
+    };
+
+    if policy.is_expired(elapsed, retry_count) {
+        return RetryDecision::Expired;
+    }
+    RetryDecision::Retry { retry_after }
+}
+
 #[async_trait::async_trait]
 impl<T> Policy for T
 where
@@ -170,17 +258,29 @@ where
                 )?;
             }
             let ctx = ctx.clone().with_value(RetryPolicyCount(retry_count));
-            let result = next[0].send(&ctx, request, &next[1..]).await;
+            // Bound each attempt, including any per-try policies such as token acquisition, by the deadline.
+            let result = match ctx.deadline() {
+                Some(deadline) => deadline.run(next[0].send(&ctx, request, &next[1..])).await,
+                None => next[0].send(&ctx, request, &next[1..]).await,
+            };
             // only start keeping track of time after the first request is made
-            let start = start.get_or_insert_with(OffsetDateTime::now_utc);
-            let (last_result, retry_after) = match result {
-                Ok(response) => {
-                    let status = response.status();
-                    let mut retry_status_codes = self.retry_status_codes();
-                    if retry_status_codes.is_empty() {
-                        retry_status_codes = DEFAULT_RETRY_STATUS_CODES;
-                    }
-                    if !retry_status_codes.contains(&status) {
+            let start = start.get_or_insert_with(time::now_utc);
+            let time_since_start = time::now_utc() - *start;
+
+            let attempt = match &result {
+                Ok(response) => RetryAttempt::Response {
+                    status: response.status(),
+                    headers: response.headers(),
+                },
+                Err(error) => RetryAttempt::Error(error.kind()),
+            };
+            let decision =
+                evaluate_retry(self, attempt, time_since_start, retry_count, time::now_utc);
+
+            let retry_after = match decision {
+                RetryDecision::Return => {
+                    if let Ok(response) = &result {
+                        let status = response.status();
                         if status.is_success() {
                             trace!("server returned success status {}", status,);
                         } else {
@@ -189,61 +289,64 @@ where
                                 status
                             );
                         }
-                        return Ok(response);
                     }
-
-                    let retry_headers = self.retry_headers();
-                    // For a 429 response (TooManyRequests) or 503 (ServiceUnavailable),
-                    // use any "retry-after" headers returned by the server to determine how long to wait before retrying.
-                    // https://learn.microsoft.com/en-us/azure/architecture/best-practices/retry-service-specific#retry-usage-guidance
-                    let retry_after = match status {
-                        StatusCode::TooManyRequests | StatusCode::ServiceUnavailable => {
-                            retry_headers.and_then(|headers| {
-                                get_retry_after(
-                                    response.headers(),
-                                    OffsetDateTime::now_utc,
-                                    &headers.retry_headers,
-                                )
-                            })
-                        }
-                        _ => None,
-                    };
-
-                    debug!(
-                        "server returned error status which requires retry: {}",
-                        status
-                    );
-                    (Ok(response), retry_after)
+                    return result;
                 }
-                Err(error) => {
-                    if matches!(error.kind(), &ErrorKind::Io | &ErrorKind::Connection) {
-                        debug!(
-                            "transport error occurred when making request which will be retried: {}",
-                            error
-                        );
-                        // Transport error so no Retry-After headers - leave the retry period up to the policy
-                        let retry_after = None;
-                        (Err(error), retry_after)
-                    } else {
-                        return Err(error.with_context(
-                            "non-transport error occurred which will not be retried",
-                        ));
-                    }
+                RetryDecision::Fail => {
+                    return result.map_err(|error| {
+                        error.with_context("non-transport error occurred which will not be retried")
+                    });
                 }
+                RetryDecision::Expired => {
+                    return result.map_err(|error| {
+                        error.with_context(
+                            "retry policy expired and the request will no longer be retried",
+                        )
+                    });
+                }
+                RetryDecision::Retry { retry_after } => retry_after,
             };
 
-            let time_since_start = OffsetDateTime::now_utc() - *start;
-            if self.is_expired(time_since_start, retry_count) {
-                return match last_result {
-                    Ok(result) => Ok(result),
-                    Err(last_error) => Err(last_error.with_context(
-                        "retry policy expired and the request will no longer be retried",
-                    )),
-                };
+            match &result {
+                Ok(response) => debug!(
+                    "server returned error status which requires retry: {}",
+                    response.status()
+                ),
+                Err(error) => debug!(
+                    "transport error occurred when making request which will be retried: {}",
+                    error
+                ),
             }
             retry_count += 1;
 
-            self.wait(retry_count, retry_after).await;
+            let waited = match ctx.deadline() {
+                // Don't wait for a retry the service says can't start before the deadline.
+                Some(deadline)
+                    if retry_after
+                        .is_some_and(|retry_after| retry_after >= deadline.remaining()) =>
+                {
+                    Err(deadline.exceeded_error())
+                }
+                Some(deadline) => {
+                    deadline
+                        .run(async {
+                            self.wait(retry_count, retry_after).await;
+                            Ok(())
+                        })
+                        .await
+                }
+                None => {
+                    self.wait(retry_count, retry_after).await;
+                    Ok(())
+                }
+            };
+            if waited.is_err() {
+                return result.map_err(|error| {
+                    error.with_context(
+                        "the request deadline expired before the request could be retried",
+                    )
+                });
+            }
         }
     }
 }
@@ -254,8 +357,8 @@ mod test {
     use super::*;
     use crate::http::{
         headers::{Headers, RETRY_AFTER},
-        AsyncRawResponse, Context, ExponentialRetryOptions, FixedRetryOptions, Method, Request,
-        RetryOptions, Url,
+        AsyncRawResponse, Context, Deadline, ExponentialRetryOptions, FixedRetryOptions, Method,
+        Request, RetryOptions, Url,
     };
     use ::time::macros::datetime;
     use std::sync::{Arc, Mutex};
@@ -344,6 +447,78 @@ mod test {
         assert_eq!(retry_after, Some(Duration::seconds(123)));
     }
 
+    #[test]
+    fn evaluate_retry_without_transport() {
+        let retry_headers = RetryHeaders {
+            retry_headers: vec![RETRY_AFTER],
+        };
+        let policy = FixedRetryPolicy::new(
+            Duration::seconds(1),
+            2,
+            Duration::MAX,
+            retry_headers,
+            Vec::new(),
+        );
+
+        let mut headers = Headers::new();
+        headers.insert(RETRY_AFTER, "Fri, 01 Jan 2021 00:00:05 GMT");
+        let throttled = RetryAttempt::Response {
+            status: StatusCode::TooManyRequests,
+            headers: &headers,
+        };
+
+        assert_eq!(
+            RetryDecision::Retry {
+                retry_after: Some(Duration::seconds(5))
+            },
+            evaluate_retry(&policy, throttled, Duration::ZERO, 0, datetime_now)
+        );
+        assert_eq!(
+            RetryDecision::Expired,
+            evaluate_retry(&policy, throttled, Duration::ZERO, 2, datetime_now)
+        );
+
+        let ok = RetryAttempt::Response {
+            status: StatusCode::Ok,
+            headers: &headers,
+        };
+        assert_eq!(
+            RetryDecision::Return,
+            evaluate_retry(&policy, ok, Duration::ZERO, 0, datetime_now)
+        );
+
+        let internal = RetryAttempt::Response {
+            status: StatusCode::InternalServerError,
+            headers: &headers,
+        };
+        assert_eq!(
+            RetryDecision::Retry { retry_after: None },
+            evaluate_retry(&policy, internal, Duration::ZERO, 0, datetime_now),
+            "retry-after is only honored for 429 and 503"
+        );
+
+        assert_eq!(
+            RetryDecision::Retry { retry_after: None },
+            evaluate_retry(
+                &policy,
+                RetryAttempt::Error(&ErrorKind::Connection),
+                Duration::ZERO,
+                0,
+                datetime_now
+            )
+        );
+        assert_eq!(
+            RetryDecision::Fail,
+            evaluate_retry(
+                &policy,
+                RetryAttempt::Error(&ErrorKind::DataConversion),
+                Duration::ZERO,
+                0,
+                datetime_now
+            )
+        );
+    }
+
     #[tokio::test]
     async fn test_retry_statuses() {
         let retries = 2u32;
@@ -511,4 +686,52 @@ mod test {
         .to_policy(RetryHeaders::default(), empty);
         test_retries_for_default_statuses(retry_policy).await;
     }
+
+    #[tokio::test]
+    async fn expired_deadline_skips_request() {
+        let retry_policy = RetryOptions::fixed(FixedRetryOptions::default())
+            .to_policy(RetryHeaders::default(), DEFAULT_RETRY_STATUS_CODES);
+        let ctx = Context::new().with_deadline(Deadline::at(OffsetDateTime::UNIX_EPOCH));
+        let mut request = Request::new(Url::parse("http://localhost").unwrap(), Method::Get);
+        let count = Arc::new(Mutex::new(0));
+        let next = vec![Arc::new(StatusResponder {
+            request_count: count.clone(),
+            status: StatusCode::Ok,
+        }) as Arc<dyn Policy>];
+
+        let error = retry_policy
+            .send(&ctx, &mut request, &next)
+            .await
+            .expect_err("expired deadline should fail the request");
+
+        assert_eq!(error.kind(), &ErrorKind::Other);
+        assert_eq!(0, *count.lock().unwrap());
+    }
+
+    #[tokio::test]
+    async fn deadline_cuts_retry_wait_short() {
+        let retry_policy = RetryOptions::fixed(FixedRetryOptions {
+            delay: Duration::minutes(10),
+            max_retries: 3,
+            ..Default::default()
+        })
+        .to_policy(RetryHeaders::default(), DEFAULT_RETRY_STATUS_CODES);
+        let ctx = Context::new().with_deadline(Deadline::after(Duration::milliseconds(50)));
+        let mut request = Request::new(Url::parse("http://localhost").unwrap(), Method::Get);
+        let count = Arc::new(Mutex::new(0));
+        let next = vec![Arc::new(StatusResponder {
+            request_count: count.clone(),
+            status: StatusCode::ServiceUnavailable,
+        }) as Arc<dyn Policy>];
+
+        let started = std::time::Instant::now();
+        let response = retry_policy
+            .send(&ctx, &mut request, &next)
+            .await
+            .expect("policy should return the last response when the deadline expires");
+
+        assert_eq!(response.status(), StatusCode::ServiceUnavailable);
+        assert_eq!(1, *count.lock().unwrap());
+        assert!(started.elapsed() < std::time::Duration::from_secs(60));
+    }
 }
@@ -0,0 +1,290 @@
+// Copyright (c) Microsoft Corporation. All rights reserved.
+// Licensed under the MIT License.
+
+use crate::http::{
+    policies::{Policy, PolicyResult, RetryPolicyCount},
+    Context, Method, Request, Url,
+};
+use async_trait::async_trait;
+use std::sync::Arc;
+
+/// A set of equivalent service endpoints: a primary and zero or more secondaries in order of preference.
+///
+/// Endpoints are compared by origin (scheme, host, and port). The path of each endpoint is ignored
+/// when a request is routed; only the origin of the request URL is replaced.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EndpointSet {
+    primary: Url,
+    secondaries: Vec<Url>,
+}
+
+impl EndpointSet {
+    /// Creates a new `EndpointSet` with only a primary endpoint.
+    pub fn new(primary: Url) -> Self {
+        Self {
+            primary,
+            secondaries: Vec::new(),
+        }
+    }
+
+    /// Adds a secondary endpoint after any previously added secondaries.
+    #[must_use]
+    pub fn with_secondary(mut self, secondary: Url) -> Self {
+        self.secondaries.push(secondary);
+        self
+    }
+
+    /// Gets the primary endpoint.
+    pub fn primary(&self) -> &Url {
+        &self.primary
+    }
+
+    /// Gets the secondary endpoints in order of preference.
+    pub fn secondaries(&self) -> &[Url] {
+        &self.secondaries
+    }
+
+    /// Iterates over all endpoints, starting with the primary.
+    pub fn iter(&self) -> impl Iterator<Item = &Url> {
+        std::iter::once(&self.primary).chain(self.secondaries.iter())
+    }
+
+    /// Gets the number of endpoints, including the primary.
+    pub fn len(&self) -> usize {
+        self.secondaries.len() + 1
+    }
+
+    /// Always returns `false` since an `EndpointSet` contains at least a primary endpoint.
+    pub fn is_empty(&self) -> bool {
+        false
+    }
+
+    /// Returns `true` if `url` has the same origin as any endpoint in the set.
+    pub fn contains(&self, url: &Url) -> bool {
+        self.iter()
+            .any(|endpoint| endpoint.origin() == url.origin())
+    }
+}
+
+/// Selects which endpoint of an [`EndpointSet`] a request should be sent to.
+///
+/// A resolver is consulted before every attempt, including retries, so it can fail over to another endpoint.
+pub trait EndpointResolver: Send + Sync + std::fmt::Debug {
+    /// Returns the endpoint to send `request` to, or `None` to leave the request URL unchanged.
+    ///
+    /// # Arguments
+    /// * `endpoints` - The endpoints configured for the client.
+    /// * `request` - The request about to be sent.
+    /// * `attempt` - The zero-based attempt number; `0` for the initial attempt and incremented for each retry.
+    fn resolve(&self, endpoints: &EndpointSet, request: &Request, attempt: u32) -> Option<Url>;
+}
+
+/// Sends writes to the primary endpoint and rotates reads through all endpoints on each retry.
+///
+/// Reads are `GET` and `HEAD` requests. The initial attempt always uses the primary endpoint.
+#[derive(Clone, Debug, Default)]
+pub struct FailoverEndpointResolver;
+
+impl EndpointResolver for FailoverEndpointResolver {
+    fn resolve(&self, endpoints: &EndpointSet, request: &Request, attempt: u32) -> Option<Url> {
+        if !matches!(request.method(), Method::Get | Method::Head) {
+            return Some(endpoints.primary().clone());
+        }
+        endpoints
+            .iter()
+            .nth(attempt as usize % endpoints.len())
+            .cloned()
+    }
+}
+
+/// Overrides the [`EndpointResolver`] of an [`EndpointRoutingPolicy`] for a single request when added to the [`Context`].
+#[derive(Clone, Debug)]
+pub struct EndpointResolverOverride(pub Arc<dyn EndpointResolver>);
+
+/// Routes each attempt to the endpoint chosen by an [`EndpointResolver`].
+///
+/// Add this policy to the per-try policies so it runs after the retry policy and can select a different endpoint for each retry.
+/// Only requests whose URL origin matches an endpoint in the [`EndpointSet`] are rewritten; the path and query are preserved.
+#[derive(Debug)]
+pub struct EndpointRoutingPolicy {
+    endpoints: EndpointSet,
+    resolver: Arc<dyn EndpointResolver>,
+}
+
+impl EndpointRoutingPolicy {
+    /// Creates a new `EndpointRoutingPolicy` using the [`FailoverEndpointResolver`].
+    pub fn new(endpoints: EndpointSet) -> Self {
+        Self::with_resolver(endpoints, Arc::new(FailoverEndpointResolver))
+    }
+
+    /// Creates a new `EndpointRoutingPolicy` using a custom [`EndpointResolver`].
+    pub fn with_resolver(endpoints: EndpointSet, resolver: Arc<dyn EndpointResolver>) -> Self {
+        Self {
+            endpoints,
+            resolver,
+        }
+    }
+
+    /// Gets the configured endpoints.
+    pub fn endpoints(&self) -> &EndpointSet {
+        &self.endpoints
+    }
+}
+
+#[async_trait]
+impl Policy for EndpointRoutingPolicy {
+    async fn send(
+        &self,
+        ctx: &Context,
+        request: &mut Request,
+        next: &[Arc<dyn Policy>],
+    ) -> PolicyResult {
+        if self.endpoints.contains(request.url()) {
+            let attempt = ctx.value::<RetryPolicyCount>().map_or(0, |count| **count);
+            let resolver = ctx
+                .value::<EndpointResolverOverride>()
+                .map_or(&self.resolver, |r| &r.0);
+            if let Some(endpoint) = resolver.resolve(&self.endpoints, request, attempt) {
+                rebase(request.url_mut(), &endpoint);
+            }
+        }
+
+        next[0].send(ctx, request, &next[1..]).await
+    }
+}
+
+/// Replaces the scheme, host, and port of `url` with those of `endpoint`.
+fn rebase(url: &mut Url, endpoint: &Url) {
+    if url.origin() == endpoint.origin() {
+        return;
+    }
+    // These setters only fail for URLs that cannot be a base, which cannot be endpoints.
+    let _ = url.set_scheme(endpoint.scheme());
+    let _ = url.set_host(endpoint.host_str());
+    let _ = url.set_port(endpoint.port());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::http::{headers::Headers, AsyncRawResponse, StatusCode};
+    use std::sync::Mutex;
+
+    #[derive(Debug, Default)]
+    struct Recorder(Mutex<Vec<String>>);
+
+    #[async_trait]
+    impl Policy for Recorder {
+        async fn send(
+            &self,
+            _ctx: &Context,
+            request: &mut Request,
+            _next: &[Arc<dyn Policy>],
+        ) -> PolicyResult {
+            self.0.lock().unwrap().push(request.url().to_string());
+            Ok(AsyncRawResponse::from_bytes(
+                StatusCode::Ok,
+                Headers::new(),
+                "",
+            ))
+        }
+    }
+
+    fn endpoints() -> EndpointSet {
+        EndpointSet::new("https://account.blob.core.windows.net".parse().unwrap()).with_secondary(
+            "https://account-secondary.blob.core.windows.net"
+                .parse()
+                .unwrap(),
+        )
+    }
+
+    async fn send(
+        policy: &EndpointRoutingPolicy,
+        ctx: &Context<'_>,
+        request: &mut Request,
+    ) -> String {
+        let recorder = Arc::new(Recorder::default());
+        let next: [Arc<dyn Policy>; 1] = [recorder.clone()];
+        policy.send(ctx, request, &next).await.unwrap();
+        let urls = recorder.0.lock().unwrap();
+        urls[0].clone()
+    }
+
+    #[tokio::test]
+    async fn reads_fail_over_on_retry() {
+        let policy = EndpointRoutingPolicy::new(endpoints());
+        let url: Url = "https://account.blob.core.windows.net/container/blob?comp=list"
+            .parse()
+            .unwrap();
+
+        let mut request = Request::new(url.clone(), Method::Get);
+        assert_eq!(
+            "https://account.blob.core.windows.net/container/blob?comp=list",
+            send(&policy, &Context::new(), &mut request).await
+        );
+
+        let ctx = Context::new().with_value(RetryPolicyCount(1));
+        assert_eq!(
+            "https://account-secondary.blob.core.windows.net/container/blob?comp=list",
+            send(&policy, &ctx, &mut request).await
+        );
+
+        let ctx = Context::new().with_value(RetryPolicyCount(2));
+        assert_eq!(
+            "https://account.blob.core.windows.net/container/blob?comp=list",
+            send(&policy, &ctx, &mut request).await
+        );
+    }
+
+    #[tokio::test]
+    async fn writes_use_primary() {
+        let policy = EndpointRoutingPolicy::new(endpoints());
+        let mut request = Request::new(
+            "https://account.blob.core.windows.net/container/blob"
+                .parse()
+                .unwrap(),
+            Method::Put,
+        );
+        let ctx = Context::new().with_value(RetryPolicyCount(1));
+        assert_eq!(
+            "https://account.blob.core.windows.net/container/blob",
+            send(&policy, &ctx, &mut request).await
+        );
+    }
+
+    #[tokio::test]
+    async fn unknown_origin_unchanged() {
+        let policy = EndpointRoutingPolicy::new(endpoints());
+        let mut request = Request::new("https://example.com/path".parse().unwrap(), Method::Get);
+        let ctx = Context::new().with_value(RetryPolicyCount(1));
+        assert_eq!(
+            "https://example.com/path",
+            send(&policy, &ctx, &mut request).await
+        );
+    }
+
+    #[tokio::test]
+    async fn context_overrides_resolver() {
+        #[derive(Debug)]
+        struct AlwaysSecondary;
+
+        impl EndpointResolver for AlwaysSecondary {
+            fn resolve(&self, endpoints: &EndpointSet, _: &Request, _: u32) -> Option<Url> {
+                endpoints.secondaries().first().cloned()
+            }
+        }
+
+        let policy = EndpointRoutingPolicy::new(endpoints());
+        let mut request = Request::new(
+            "https://account.blob.core.windows.net/container"
+                .parse()
+                .unwrap(),
+            Method::Put,
+        );
+        let ctx = Context::new().with_value(EndpointResolverOverride(Arc::new(AlwaysSecondary)));
+        assert_eq!(
+            "https://account-secondary.blob.core.windows.net/container",
+            send(&policy, &ctx, &mut request).await
+        );
+    }
+}
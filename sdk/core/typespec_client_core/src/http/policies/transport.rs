@@ -3,7 +3,7 @@
 
 use crate::http::{
     headers::{Header, HeaderValue, CONTENT_LENGTH},
-    options::Transport,
+    options::{ResponseBodyOptions, Transport},
     policies::{Policy, PolicyResult},
     Context, Method, Request, Sanitizer, DEFAULT_ALLOWED_QUERY_PARAMETERS,
 };
@@ -27,8 +27,9 @@ impl TransportPolicy {
     }
 }
 
-/// When present in [`Context`], signals to the `TransportPolicy` to buffer the entire [`AsyncRawResponse`](crate::http::AsyncRawResponse).
-pub(crate) struct Buffer;
+/// When present in [`Context`], signals to the `TransportPolicy` to buffer the entire [`AsyncRawResponse`](crate::http::AsyncRawResponse)
+/// within the given limits.
+pub(crate) struct Buffer(pub(crate) ResponseBodyOptions);
 
 #[async_trait]
 impl Policy for TransportPolicy {
@@ -51,13 +52,21 @@ impl Policy for TransportPolicy {
             "sending request '{}'",
             request.url.sanitize(&DEFAULT_ALLOWED_QUERY_PARAMETERS)
         );
-        let response = { self.transport.send(ctx, request) };
-
-        if ctx.value::<Buffer>().is_some() {
-            return response.await?.buffer().await;
+        let response = async {
+            let mut response = self.transport.send(ctx, request).await?;
+            if request.method() == Method::Head {
+                response = response.without_content_length();
+            }
+            if let Some(Buffer(options)) = ctx.value::<Buffer>() {
+                return response.buffer(options).await;
+            }
+            Ok(response)
+        };
+
+        match ctx.deadline() {
+            Some(deadline) => deadline.run(response).await,
+            None => response.await,
         }
-
-        response.await
     }
 }
 
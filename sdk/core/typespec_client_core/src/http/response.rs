@@ -7,11 +7,18 @@
 use crate::http::JsonFormat;
 use crate::{
     error::ErrorKind,
-    http::{headers::Headers, DeserializeWith, Format, StatusCode},
+    http::{
+        headers::{Headers, CONTENT_LENGTH},
+        CustomFormat, DeserializeWith, Format, ResponseBodyOptions, StatusCode,
+    },
+    time::Duration,
     Bytes,
 };
 use bytes::BytesMut;
-use futures::{Stream, StreamExt};
+use futures::{
+    future::{self, Either},
+    Stream, StreamExt,
+};
 use std::{fmt, marker::PhantomData, pin::Pin, task::Poll};
 use typespec::error::ResultExt as _;
 pub use typespec::http::response::*;
@@ -19,6 +26,12 @@ pub use typespec::http::response::*;
 /// A pinned stream of bytes that can be sent as a response body.
 pub type PinnedStream = Pin<Box<dyn Stream<Item = crate::Result<Bytes>> + Send>>;
 
+/// The maximum number of bytes [`AsyncResponseBody::collect`] will preallocate based on a size hint.
+///
+/// Larger bodies still collect correctly, but the buffer grows as chunks arrive so that an incorrect
+/// or malicious `content-length` cannot force a large allocation up front.
+const MAX_PREALLOCATED_CAPACITY: usize = 64 * 1024 * 1024;
+
 /// A raw HTTP response with status, headers, and body.
 #[derive(Debug)]
 pub struct AsyncRawResponse {
@@ -29,11 +42,21 @@ pub struct AsyncRawResponse {
 
 impl AsyncRawResponse {
     /// Create a raw HTTP response from an asynchronous stream of bytes.
+    ///
+    /// If `headers` contains a `content-length`, it is used as a hint to preallocate the buffer when the body is collected.
+    /// It is ignored for `204 No Content` and `304 Not Modified` responses, which never have a body.
     pub fn new(status: StatusCode, headers: Headers, stream: PinnedStream) -> Self {
+        let content_length = match status {
+            StatusCode::NoContent | StatusCode::NotModified => None,
+            _ => headers
+                .get_optional_as::<usize, _>(&CONTENT_LENGTH)
+                .ok()
+                .flatten(),
+        };
         Self {
             status,
             headers,
-            body: AsyncResponseBody::new(stream),
+            body: AsyncResponseBody::new(stream).with_content_length(content_length),
         }
     }
 
@@ -72,15 +95,28 @@ impl AsyncRawResponse {
         Ok(RawResponse::from_bytes(self.status, self.headers, body))
     }
 
-    /// Collect the stream into an internal [`Bytes`] collection.
-    pub(crate) async fn buffer(self) -> crate::Result<Self> {
+    /// Clears the expected length of the body.
+    ///
+    /// A response to a `HEAD` request has no body, but its `content-length` describes the body a `GET` would return.
+    pub(crate) fn without_content_length(mut self) -> Self {
+        self.body.content_length = None;
+        self
+    }
+
+    /// Collect the stream into an internal [`Bytes`] collection within the limits of `options`.
+    pub(crate) async fn buffer(self, options: &ResponseBodyOptions) -> crate::Result<Self> {
         Ok(Self {
-            body: self.body.buffer().await?,
+            body: self.body.buffer(options).await?,
             ..self
         })
     }
 }
 
+/// A boxed function that deserializes a [`ResponseBody`] into a model `T`.
+///
+/// Attach one to a [`Response`] with [`Response::with_deserializer`].
+pub type BoxedDeserializer<T> = Box<dyn FnOnce(ResponseBody) -> crate::Result<T> + Send + Sync>;
+
 /// A typed fully-buffered HTTP response.
 ///
 /// The type parameter `T` is a marker type that indicates what the caller should expect to be able to deserialize the body into.
@@ -95,6 +131,7 @@ impl AsyncRawResponse {
 #[cfg(feature = "json")]
 pub struct Response<T, F = JsonFormat> {
     raw: RawResponse,
+    deserializer: Option<BoxedDeserializer<T>>,
     phantom: PhantomData<(T, F)>,
 }
 
@@ -112,6 +149,7 @@ pub struct Response<T, F = JsonFormat> {
 #[cfg(not(feature = "json"))]
 pub struct Response<T, F> {
     raw: RawResponse,
+    deserializer: Option<BoxedDeserializer<T>>,
     phantom: PhantomData<(T, F)>,
 }
 
@@ -145,6 +183,55 @@ impl<T, F> Response<T, F> {
     pub fn to_raw_response(&self) -> RawResponse {
         self.raw.clone()
     }
+
+    /// Attaches a custom deserializer that [`Response::into_model`] uses to convert the body into `T`.
+    ///
+    /// Use this to deserialize formats other than JSON or XML, such as CSV, Avro, or protobuf,
+    /// without giving up the typed [`Response`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typespec_client_core::http::{headers::Headers, RawResponse, Response, StatusCode};
+    ///
+    /// struct Csv(Vec<String>);
+    ///
+    /// let response: Response<Csv> =
+    ///     RawResponse::from_bytes(StatusCode::Ok, Headers::new(), "a,b,c").into();
+    /// let model = response
+    ///     .with_deserializer(|body| {
+    ///         let text = body.into_string()?;
+    ///         Ok(Csv(text.split(',').map(ToOwned::to_owned).collect()))
+    ///     })
+    ///     .into_model()
+    ///     .unwrap();
+    /// assert_eq!(model.0, ["a", "b", "c"]);
+    /// ```
+    pub fn with_deserializer<D>(self, deserializer: D) -> Response<T, CustomFormat>
+    where
+        D: FnOnce(ResponseBody) -> crate::Result<T> + Send + Sync + 'static,
+    {
+        Response {
+            raw: self.raw,
+            deserializer: Some(Box::new(deserializer)),
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<T> Response<T, CustomFormat> {
+    /// Fetches the entire body and converts it into type `T` using the deserializer attached with [`Response::with_deserializer`].
+    ///
+    /// Returns a [`DataConversion`](ErrorKind::DataConversion) error if no deserializer was attached.
+    pub fn into_model(self) -> crate::Result<T> {
+        let deserializer = self.deserializer.ok_or_else(|| {
+            crate::Error::with_message(
+                ErrorKind::DataConversion,
+                "no custom deserializer was attached to the response",
+            )
+        })?;
+        deserializer(self.raw.into_body())
+    }
 }
 
 impl<T: DeserializeWith<F>, F: Format> Response<T, F> {
@@ -205,6 +292,7 @@ impl<T, F> From<RawResponse> for Response<T, F> {
     fn from(raw: RawResponse) -> Self {
         Self {
             raw,
+            deserializer: None,
             phantom: PhantomData,
         }
     }
@@ -292,7 +380,11 @@ impl<T> From<AsyncResponse<T>> for AsyncRawResponse {
 ///
 /// This body can either be streamed or collected into [`Bytes`].
 #[pin_project::pin_project]
-pub struct AsyncResponseBody(#[pin] Body);
+pub struct AsyncResponseBody {
+    #[pin]
+    body: Body,
+    content_length: Option<usize>,
+}
 
 #[pin_project::pin_project(project = BodyProj)]
 enum Body {
@@ -303,26 +395,116 @@ enum Body {
 impl AsyncResponseBody {
     /// Create a new [`AsyncResponseBody`] from an async stream of bytes.
     fn new(stream: PinnedStream) -> Self {
-        Self(Body::Stream(stream))
+        Self {
+            body: Body::Stream(stream),
+            content_length: None,
+        }
     }
 
     /// Create a new [`AsyncResponseBody`] from a byte slice.
     fn from_bytes(bytes: impl Into<Bytes>) -> Self {
-        Self(Body::Bytes(Some(bytes.into())))
+        let bytes = bytes.into();
+        Self {
+            content_length: Some(bytes.len()),
+            body: Body::Bytes(Some(bytes)),
+        }
+    }
+
+    /// Sets the expected length of the body, typically taken from the `content-length` header.
+    fn with_content_length(mut self, content_length: Option<usize>) -> Self {
+        self.content_length = content_length;
+        self
+    }
+
+    /// Gets the expected length of the body in bytes, if known.
+    pub fn content_length(&self) -> Option<usize> {
+        self.content_length
     }
 
     /// Collect the stream into a [`Bytes`] collection.
-    pub async fn collect(mut self) -> crate::Result<Bytes> {
-        // Append each chunk directly into a growing BytesMut using reserve(),
-        // This avoids the intermediate Vec<Bytes> of the previous implementation,
-        // which was held in memory simultaneously with the final buffer.
-        let mut result = BytesMut::new();
+    ///
+    /// A body consisting of a single chunk is returned without copying. Otherwise, chunks are appended into a single
+    /// buffer preallocated from the [`content_length`](Self::content_length), if known.
+    pub async fn collect(self) -> crate::Result<Bytes> {
+        self.collect_up_to(None).await
+    }
+
+    /// Collect the stream into a [`Bytes`] collection within the limits of `options`.
+    ///
+    /// Fails with a [`ResponseBodyLimitError`] if the body is larger than [`ResponseBodyOptions::max_size`] or is not
+    /// read within [`ResponseBodyOptions::read_timeout`]. A body whose [`content_length`](Self::content_length) exceeds
+    /// the maximum size fails without being read.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use typespec_client_core::{
+    ///     http::{response::AsyncResponse, ResponseBodyOptions},
+    ///     time::Duration,
+    /// };
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let response: AsyncResponse = unimplemented!();
+    /// let options = ResponseBodyOptions {
+    ///     max_size: Some(16 * 1024 * 1024),
+    ///     read_timeout: Some(Duration::seconds(30)),
+    /// };
+    /// let body = response.into_body().collect_with_options(&options).await?;
+    /// # Ok(()) }
+    /// ```
+    pub async fn collect_with_options(self, options: &ResponseBodyOptions) -> crate::Result<Bytes> {
+        let collect = self.collect_up_to(options.max_size);
+        let Some(read_timeout) = options.read_timeout else {
+            return collect.await;
+        };
+
+        let sleep = crate::sleep::sleep(read_timeout);
+        futures::pin_mut!(collect, sleep);
+        match future::select(collect, sleep).await {
+            Either::Left((result, _)) => result,
+            Either::Right(((), _)) => Err(ResponseBodyLimitError::TimedOut { read_timeout }.into()),
+        }
+    }
+
+    /// Collect the stream into a [`Bytes`] collection, failing if it holds more than `max_size` bytes.
+    async fn collect_up_to(mut self, max_size: Option<usize>) -> crate::Result<Bytes> {
+        let max_size = max_size.unwrap_or(usize::MAX);
+        let check_size = |size: usize| {
+            if size > max_size {
+                return Err(ResponseBodyLimitError::TooLarge { max_size });
+            }
+            Ok(())
+        };
+        if let Some(content_length) = self.content_length {
+            check_size(content_length)?;
+        }
+
+        let Some(first) = self.next().await.transpose()? else {
+            return Ok(Bytes::new());
+        };
+        check_size(first.len())?;
+        let Some(second) = self.next().await.transpose()? else {
+            return Ok(first);
+        };
+        let mut len = first.len() + second.len();
+        check_size(len)?;
+
+        let capacity = self
+            .content_length
+            .unwrap_or_default()
+            .min(MAX_PREALLOCATED_CAPACITY)
+            .max(len);
+        let mut result = BytesMut::with_capacity(capacity);
+        result.extend_from_slice(&first);
+        result.extend_from_slice(&second);
         while let Some(res) = self.next().await {
             let chunk = res?;
+            len += chunk.len();
+            check_size(len)?;
             result.reserve(chunk.len());
             result.extend_from_slice(&chunk);
         }
-        Ok(result.into())
+        Ok(result.freeze())
     }
 
     /// Collect the stream into a caller supplied buffer.
@@ -364,13 +546,62 @@ impl AsyncResponseBody {
             .map(ToOwned::to_owned)
     }
 
-    /// Collect the stream into an internal [`Bytes`] collection.
-    async fn buffer(self) -> crate::Result<Self> {
-        let bytes = self.collect().await?;
+    /// Collect the stream into an internal [`Bytes`] collection within the limits of `options`.
+    async fn buffer(self, options: &ResponseBodyOptions) -> crate::Result<Self> {
+        let bytes = self.collect_with_options(options).await?;
         Ok(Self::from_bytes(bytes))
     }
 }
 
+/// The error returned when collecting a response body exceeds a limit in [`ResponseBodyOptions`].
+///
+/// Returned as the source of an [`Error`](crate::Error); use [`Error::downcast_ref`](crate::Error::downcast_ref) to
+/// inspect it. A timeout has [`ErrorKind::Io`] and is retried like other failures reading the body. A body that is too
+/// large has [`ErrorKind::Other`] and is not retried, since the service would likely return the same body again.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ResponseBodyLimitError {
+    /// The body is larger than [`ResponseBodyOptions::max_size`].
+    TooLarge {
+        /// The maximum number of bytes that could be collected.
+        max_size: usize,
+    },
+
+    /// The body was not read within [`ResponseBodyOptions::read_timeout`].
+    TimedOut {
+        /// The time allowed to read the body.
+        read_timeout: Duration,
+    },
+}
+
+impl fmt::Display for ResponseBodyLimitError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::TooLarge { max_size } => {
+                write!(
+                    f,
+                    "response body exceeded the maximum size of {max_size} bytes"
+                )
+            }
+            Self::TimedOut { read_timeout } => {
+                write!(f, "response body was not read within {read_timeout}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for ResponseBodyLimitError {}
+
+impl From<ResponseBodyLimitError> for crate::Error {
+    fn from(error: ResponseBodyLimitError) -> Self {
+        let kind = match error {
+            ResponseBodyLimitError::TooLarge { .. } => ErrorKind::Other,
+            ResponseBodyLimitError::TimedOut { .. } => ErrorKind::Io,
+        };
+        crate::Error::new(kind, error)
+    }
+}
+
 impl Stream for AsyncResponseBody {
     type Item = crate::Result<Bytes>;
     fn poll_next(
@@ -378,7 +609,7 @@ impl Stream for AsyncResponseBody {
         cx: &mut std::task::Context<'_>,
     ) -> Poll<Option<Self::Item>> {
         let this = self.project();
-        match this.0.project() {
+        match this.body.project() {
             BodyProj::Bytes(b) => {
                 if let Some(b) = b.take() {
                     return Poll::Ready(Some(Ok(b)));
@@ -433,6 +664,39 @@ mod tests {
         assert_eq!(b"Hello World", &*body);
     }
 
+    #[test]
+    fn custom_deserializer_converts_body() {
+        #[derive(Debug, PartialEq)]
+        struct Pair(u8, u8);
+
+        let response: Response<Pair, crate::http::NoFormat> =
+            RawResponse::from_bytes(StatusCode::Ok, Headers::new(), [1u8, 2].as_slice()).into();
+        let pair = response
+            .with_deserializer(|body| match *body {
+                [a, b] => Ok(Pair(a, b)),
+                _ => Err(crate::Error::with_message(
+                    ErrorKind::DataConversion,
+                    "expected two bytes",
+                )),
+            })
+            .into_model()
+            .unwrap();
+        assert_eq!(pair, Pair(1, 2));
+    }
+
+    #[test]
+    fn custom_format_without_deserializer_fails() {
+        #[derive(Debug)]
+        pub struct MyModel;
+
+        let response: Response<MyModel, CustomFormat> =
+            RawResponse::from_bytes(StatusCode::Ok, Headers::new(), b"Hello".as_slice()).into();
+        let error = response
+            .into_model()
+            .expect_err("no deserializer was attached");
+        assert_eq!(error.kind(), &ErrorKind::DataConversion);
+    }
+
     #[tokio::test]
     async fn can_convert_buf_response_to_raw_response() {
         let buf_response =
@@ -464,6 +728,155 @@ mod tests {
         assert_eq!(buffer, vec![0xde, 0xad, 0xbe, 0xef]);
     }
 
+    #[tokio::test]
+    async fn collect_single_chunk_without_copy() {
+        let chunk = Bytes::from(vec![0xde, 0xad, 0xbe, 0xef]);
+        let ptr = chunk.as_ptr();
+        let body = AsyncRawResponse::new(
+            StatusCode::Ok,
+            Headers::new(),
+            stream::iter(vec![Ok(chunk)]).boxed(),
+        )
+        .into_body();
+        let collected = body.collect().await.unwrap();
+        assert_eq!(ptr, collected.as_ptr());
+    }
+
+    #[tokio::test]
+    async fn collect_uses_content_length_hint() {
+        let mut headers = Headers::new();
+        headers.insert(CONTENT_LENGTH, "4");
+        let body = AsyncRawResponse::new(
+            StatusCode::Ok,
+            headers,
+            stream::iter(vec![
+                Ok(Bytes::from_static(&[0xde, 0xad])),
+                Ok(Bytes::from_static(&[0xbe, 0xef])),
+            ])
+            .boxed(),
+        )
+        .into_body();
+        assert_eq!(Some(4), body.content_length());
+        let buffer = body.collect().await.unwrap();
+        assert_eq!(&[0xde, 0xad, 0xbe, 0xef], &buffer[..]);
+    }
+
+    #[tokio::test]
+    async fn collect_ignores_invalid_content_length() {
+        let mut headers = Headers::new();
+        headers.insert(CONTENT_LENGTH, "not-a-number");
+        let body = AsyncRawResponse::new(
+            StatusCode::Ok,
+            headers,
+            stream::iter(Vec::<crate::Result<Bytes>>::new()).boxed(),
+        )
+        .into_body();
+        assert_eq!(None, body.content_length());
+        assert!(body.collect().await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn collect_with_options_limits_size() {
+        let body = || {
+            AsyncRawResponse::new(
+                StatusCode::Ok,
+                Headers::new(),
+                stream::iter(vec![
+                    Ok(Bytes::from_static(&[0xde, 0xad])),
+                    Ok(Bytes::from_static(&[0xbe, 0xef])),
+                    Ok(Bytes::from_static(&[0xca, 0xfe])),
+                ])
+                .boxed(),
+            )
+            .into_body()
+        };
+
+        let options = ResponseBodyOptions {
+            max_size: Some(6),
+            ..Default::default()
+        };
+        let buffer = body().collect_with_options(&options).await.unwrap();
+        assert_eq!(6, buffer.len());
+
+        let options = ResponseBodyOptions {
+            max_size: Some(5),
+            ..Default::default()
+        };
+        let err = body().collect_with_options(&options).await.unwrap_err();
+        assert_eq!(&ErrorKind::Other, err.kind());
+        assert_eq!(
+            Some(&ResponseBodyLimitError::TooLarge { max_size: 5 }),
+            err.downcast_ref::<ResponseBodyLimitError>()
+        );
+    }
+
+    #[tokio::test]
+    async fn collect_with_options_rejects_large_content_length() {
+        let mut headers = Headers::new();
+        headers.insert(CONTENT_LENGTH, "1073741824");
+        let body = AsyncRawResponse::new(
+            StatusCode::Ok,
+            headers,
+            stream::once(async { panic!("body should not be read") }).boxed(),
+        )
+        .into_body();
+        let options = ResponseBodyOptions {
+            max_size: Some(1024),
+            ..Default::default()
+        };
+        let err = body.collect_with_options(&options).await.unwrap_err();
+        assert_eq!(
+            Some(&ResponseBodyLimitError::TooLarge { max_size: 1024 }),
+            err.downcast_ref::<ResponseBodyLimitError>()
+        );
+    }
+
+    #[tokio::test]
+    async fn collect_with_options_ignores_content_length_without_body() {
+        for status in [StatusCode::NoContent, StatusCode::NotModified] {
+            let mut headers = Headers::new();
+            headers.insert(CONTENT_LENGTH, "1073741824");
+            let body = AsyncRawResponse::new(
+                status,
+                headers,
+                stream::iter(Vec::<crate::Result<Bytes>>::new()).boxed(),
+            )
+            .into_body();
+            assert_eq!(None, body.content_length());
+            let options = ResponseBodyOptions {
+                max_size: Some(1024),
+                ..Default::default()
+            };
+            assert!(body
+                .collect_with_options(&options)
+                .await
+                .unwrap()
+                .is_empty());
+        }
+    }
+
+    #[tokio::test]
+    async fn collect_with_options_times_out() {
+        let body = AsyncRawResponse::new(
+            StatusCode::Ok,
+            Headers::new(),
+            stream::pending::<crate::Result<Bytes>>().boxed(),
+        )
+        .into_body();
+        let options = ResponseBodyOptions {
+            read_timeout: Some(Duration::milliseconds(10)),
+            ..Default::default()
+        };
+        let err = body.collect_with_options(&options).await.unwrap_err();
+        assert_eq!(&ErrorKind::Io, err.kind());
+        assert_eq!(
+            Some(&ResponseBodyLimitError::TimedOut {
+                read_timeout: Duration::milliseconds(10)
+            }),
+            err.downcast_ref::<ResponseBodyLimitError>()
+        );
+    }
+
     #[tokio::test]
     async fn into_body_collect_into_simple() {
         let response: AsyncResponse = AsyncRawResponse::new(
@@ -13,6 +13,8 @@ pub mod fmt;
 pub mod http;
 #[cfg(feature = "json")]
 pub use typespec::json;
+pub mod random;
+mod scoped;
 pub mod sleep;
 pub mod stream;
 pub mod time;
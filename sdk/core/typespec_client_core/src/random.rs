@@ -0,0 +1,142 @@
+// Copyright (c) Microsoft Corporation. All rights reserved.
+// Licensed under the MIT License.
+
+//! Random data used by client libraries.
+//!
+//! Client libraries get random data, like retry jitter and client request IDs, from the [`RandomSource`]
+//! returned by [`get_random_source`]. Tests can replace it for a single future with [`with_random_source`]
+//! so recorded interactions are deterministic.
+
+use crate::scoped::{Scoped, Slot};
+use std::{fmt, future::Future, sync::Arc};
+use uuid::Uuid;
+
+/// A source of random data.
+pub trait RandomSource: fmt::Debug + Send + Sync {
+    /// Returns a random `u64`.
+    fn next_u64(&self) -> u64;
+
+    /// Fills `dest` with random bytes.
+    fn fill_bytes(&self, dest: &mut [u8]) {
+        for chunk in dest.chunks_mut(8) {
+            let bytes = self.next_u64().to_le_bytes();
+            chunk.copy_from_slice(&bytes[..chunk.len()]);
+        }
+    }
+}
+
+/// A [`RandomSource`] backed by the thread-local random number generator.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct ThreadRandomSource;
+
+impl RandomSource for ThreadRandomSource {
+    fn next_u64(&self) -> u64 {
+        rand::random()
+    }
+}
+
+thread_local! {
+    static RANDOM_SOURCE: Slot<Arc<dyn RandomSource>> = const { Slot::new(None) };
+}
+
+/// Returns the current [`RandomSource`].
+///
+/// This is the [`RandomSource`] passed to the innermost [`with_random_source`] being polled, or a
+/// [`ThreadRandomSource`] otherwise.
+pub fn get_random_source() -> Arc<dyn RandomSource> {
+    RANDOM_SOURCE
+        .with(|source| source.borrow().clone())
+        .unwrap_or_else(|| Arc::new(ThreadRandomSource))
+}
+
+/// Runs `future` with `source` as the current [`RandomSource`].
+///
+/// The source is only used while `future` is polled, so other tasks, including other tests running in parallel,
+/// keep their own random data. Tasks spawned by `future` do not inherit it.
+///
+/// # Arguments
+/// * `source` - An instance of a type that implements the [`RandomSource`] trait.
+/// * `future` - The future to run with `source`.
+///
+/// # Examples
+///
+/// ```
+/// use typespec_client_core::random::{self, with_random_source, RandomSource};
+/// use std::sync::{atomic::{AtomicU64, Ordering}, Arc};
+///
+/// #[derive(Debug, Default)]
+/// struct CountingSource(AtomicU64);
+///
+/// impl RandomSource for CountingSource {
+///     fn next_u64(&self) -> u64 {
+///         self.0.fetch_add(1, Ordering::Relaxed)
+///     }
+/// }
+///
+/// # futures::executor::block_on(async {
+/// let uuid = with_random_source(Arc::new(CountingSource::default()), async { random::new_uuid() }).await;
+/// assert_eq!(uuid.to_string(), "00000000-0000-4000-8100-000000000000");
+/// # });
+/// ```
+pub fn with_random_source<F: Future>(
+    source: Arc<dyn RandomSource>,
+    future: F,
+) -> impl Future<Output = F::Output> {
+    Scoped::new(&RANDOM_SOURCE, source, future)
+}
+
+/// Returns a random `u64` from the current [`RandomSource`].
+pub fn next_u64() -> u64 {
+    get_random_source().next_u64()
+}
+
+/// Returns a new version 4 (random) [`Uuid`] from the current [`RandomSource`].
+pub fn new_uuid() -> Uuid {
+    let mut bytes = [0u8; 16];
+    get_random_source().fill_bytes(&mut bytes);
+    uuid::Builder::from_random_bytes(bytes).into_uuid()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug)]
+    struct FixedSource(u64);
+
+    impl RandomSource for FixedSource {
+        fn next_u64(&self) -> u64 {
+            self.0
+        }
+    }
+
+    #[test]
+    fn fill_bytes_partial_chunk() {
+        let mut bytes = [0u8; 10];
+        FixedSource(0x0807_0605_0403_0201).fill_bytes(&mut bytes);
+        assert_eq!(bytes, [1, 2, 3, 4, 5, 6, 7, 8, 1, 2]);
+    }
+
+    #[test]
+    fn new_uuid_is_v4() {
+        let uuid = new_uuid();
+        assert_eq!(uuid.get_version(), Some(uuid::Version::Random));
+        assert_ne!(uuid, new_uuid());
+    }
+
+    #[tokio::test]
+    async fn with_random_source_is_scoped_to_the_future() {
+        let (scoped, other) = futures::join!(
+            with_random_source(Arc::new(FixedSource(7)), async {
+                tokio::task::yield_now().await;
+                next_u64()
+            }),
+            async {
+                tokio::task::yield_now().await;
+                (next_u64(), next_u64())
+            },
+        );
+        assert_eq!(scoped, 7);
+        assert_ne!(other.0, other.1);
+    }
+}
@@ -0,0 +1,66 @@
+// Copyright (c) Microsoft Corporation. All rights reserved.
+// Licensed under the MIT License.
+
+//! Values that are only visible while a future is polled.
+
+use pin_project::pin_project;
+use std::{
+    cell::RefCell,
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+    thread::LocalKey,
+};
+
+/// The thread-local slot holding the value of the innermost [`Scoped`] future being polled.
+pub(crate) type Slot<T> = RefCell<Option<T>>;
+
+/// A future that makes `value` visible in `key` while `future` is polled.
+///
+/// The previous value of `key` is restored after each poll, so scopes can be nested and
+/// other tasks polled on the same thread are not affected.
+#[pin_project]
+pub(crate) struct Scoped<T: 'static, F> {
+    key: &'static LocalKey<Slot<T>>,
+    value: Option<T>,
+    #[pin]
+    future: F,
+}
+
+impl<T: 'static, F> Scoped<T, F> {
+    pub(crate) fn new(key: &'static LocalKey<Slot<T>>, value: T, future: F) -> Self {
+        Self {
+            key,
+            value: Some(value),
+            future,
+        }
+    }
+}
+
+impl<T: 'static, F: Future> Future for Scoped<T, F> {
+    type Output = F::Output;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.project();
+        let previous = this.key.with(|slot| slot.replace(this.value.take()));
+        // Restore the previous value even if the future panics.
+        let _restore = Restore {
+            key: this.key,
+            value: this.value,
+            previous,
+        };
+        this.future.poll(cx)
+    }
+}
+
+struct Restore<'a, T: 'static> {
+    key: &'static LocalKey<Slot<T>>,
+    value: &'a mut Option<T>,
+    previous: Option<T>,
+}
+
+impl<T: 'static> Drop for Restore<'_, T> {
+    fn drop(&mut self) {
+        *self.value = self.key.with(|slot| slot.replace(self.previous.take()));
+    }
+}
@@ -0,0 +1,132 @@
+// Copyright (c) Microsoft Corporation. All rights reserved.
+// Licensed under the MIT License.
+
+//! The current time used by client libraries.
+
+use super::OffsetDateTime;
+use crate::scoped::{Scoped, Slot};
+use std::{fmt, future::Future, sync::Arc};
+
+/// A source of the current time.
+///
+/// Client libraries get the current time from the [`Clock`] returned by [`get_clock`], for example to compute
+/// how long a request has been retried or whether an access token needs to be refreshed.
+/// Tests can replace it for a single future with [`with_clock`] so those computations are deterministic.
+pub trait Clock: fmt::Debug + Send + Sync {
+    /// Returns the current date and time in UTC.
+    fn now_utc(&self) -> OffsetDateTime;
+}
+
+/// A [`Clock`] that returns the system time.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now_utc(&self) -> OffsetDateTime {
+        OffsetDateTime::now_utc()
+    }
+}
+
+thread_local! {
+    static CLOCK: Slot<Arc<dyn Clock>> = const { Slot::new(None) };
+}
+
+/// Returns the current [`Clock`].
+///
+/// This is the [`Clock`] passed to the innermost [`with_clock`] being polled, or a [`SystemClock`] otherwise.
+pub fn get_clock() -> Arc<dyn Clock> {
+    CLOCK
+        .with(|clock| clock.borrow().clone())
+        .unwrap_or_else(|| Arc::new(SystemClock))
+}
+
+/// Runs `future` with `clock` as the current [`Clock`].
+///
+/// The clock is only used while `future` is polled, so other tasks, including other tests running in parallel,
+/// keep their own clock. Tasks spawned by `future` do not inherit it.
+///
+/// # Arguments
+/// * `clock` - An instance of a type that implements the [`Clock`] trait.
+/// * `future` - The future to run with `clock`.
+///
+/// # Examples
+///
+/// ```
+/// use typespec_client_core::time::{self, with_clock, Clock, OffsetDateTime};
+/// use std::sync::Arc;
+///
+/// #[derive(Debug)]
+/// struct FixedClock(OffsetDateTime);
+///
+/// impl Clock for FixedClock {
+///     fn now_utc(&self) -> OffsetDateTime {
+///         self.0
+///     }
+/// }
+///
+/// # futures::executor::block_on(async {
+/// let now = with_clock(Arc::new(FixedClock(OffsetDateTime::UNIX_EPOCH)), async {
+///     time::now_utc()
+/// })
+/// .await;
+/// assert_eq!(now, OffsetDateTime::UNIX_EPOCH);
+/// # });
+/// ```
+pub fn with_clock<F: Future>(clock: Arc<dyn Clock>, future: F) -> impl Future<Output = F::Output> {
+    Scoped::new(&CLOCK, clock, future)
+}
+
+/// Returns the current date and time in UTC from the current [`Clock`].
+pub fn now_utc() -> OffsetDateTime {
+    get_clock().now_utc()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::time::Duration;
+
+    #[test]
+    fn system_clock_now_utc() {
+        let before = OffsetDateTime::now_utc();
+        let now = SystemClock.now_utc();
+        assert!(now >= before);
+        assert!(now - before < Duration::minutes(1));
+    }
+
+    #[derive(Debug)]
+    struct FixedClock(OffsetDateTime);
+
+    impl Clock for FixedClock {
+        fn now_utc(&self) -> OffsetDateTime {
+            self.0
+        }
+    }
+
+    #[tokio::test]
+    async fn with_clock_is_scoped_to_the_future() {
+        let outer = OffsetDateTime::UNIX_EPOCH;
+        let inner = outer + Duration::days(1);
+        with_clock(Arc::new(FixedClock(outer)), async {
+            assert_eq!(now_utc(), outer);
+            let nested = with_clock(Arc::new(FixedClock(inner)), async { now_utc() }).await;
+            assert_eq!(nested, inner);
+            assert_eq!(now_utc(), outer);
+        })
+        .await;
+        assert_ne!(now_utc(), outer);
+    }
+
+    #[tokio::test]
+    async fn with_clock_is_restored_across_yields() {
+        let epoch = OffsetDateTime::UNIX_EPOCH;
+        let mut scoped = Box::pin(with_clock(Arc::new(FixedClock(epoch)), async {
+            tokio::task::yield_now().await;
+            now_utc()
+        }));
+        // Poll once so the future yields, then check the clock is not visible outside of it.
+        assert!(futures::poll!(scoped.as_mut()).is_pending());
+        assert_ne!(now_utc(), epoch);
+        assert_eq!(scoped.await, epoch);
+    }
+}
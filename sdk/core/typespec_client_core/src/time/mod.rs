@@ -15,11 +15,13 @@ use typespec::error::{ErrorKind, ResultExt};
 pub use time::serde::rfc3339;
 pub use time::serde::timestamp;
 
+mod clock;
 // RFC 3339 vs ISO 8601: <https://ijmacd.github.io/rfc3339-iso8601/>
 pub mod iso8601;
 pub mod rfc7231;
 pub mod unix_time;
 
+pub use clock::{get_clock, now_utc, with_clock, Clock, SystemClock};
 pub use unix_time::parse_unix_time;
 
 /// RFC 3339: Date and Time on the Internet: Timestamps.
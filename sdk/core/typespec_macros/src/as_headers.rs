@@ -0,0 +1,226 @@
+// Copyright (c) Microsoft Corporation. All rights reserved.
+// Licensed under the MIT License.
+
+use crate::Result;
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{
+    spanned::Spanned, Attribute, Data, DataStruct, DeriveInput, Error, Expr, Field, Fields,
+    GenericArgument, Lit, LitStr, Path, PathArguments, Type,
+};
+
+pub fn derive_as_headers_impl(ast: DeriveInput) -> Result<TokenStream> {
+    let body = generate_body(ast)?;
+
+    // We wrap the generated code in a const block to give it a unique scope.
+    let gen = quote! {
+        #[doc(hidden)]
+        const _: () = {
+            #body
+        };
+    };
+    Ok(gen)
+}
+
+fn generate_body(ast: DeriveInput) -> Result<TokenStream> {
+    let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
+    let name = &ast.ident;
+
+    let type_attrs = TypeAttrs::from_attrs(&ast.attrs)?;
+    let krate = type_attrs
+        .krate
+        .unwrap_or_else(|| syn::parse_quote!(::typespec_client_core));
+
+    let fields = match &ast.data {
+        Data::Struct(DataStruct {
+            fields: Fields::Named(fields),
+            ..
+        }) => &fields.named,
+        _ => {
+            return Err(Error::new(
+                ast.span(),
+                "only structs with named fields are supported for `AsHeaders`",
+            ))
+        }
+    };
+
+    let inserts = fields
+        .iter()
+        .map(|f| generate_field(&krate, f))
+        .collect::<Result<Vec<_>>>()?;
+
+    Ok(quote! {
+        #[automatically_derived]
+        impl #impl_generics #krate::http::headers::AsHeaders for #name #ty_generics #where_clause {
+            type Error = ::std::convert::Infallible;
+            type Iter = ::std::vec::IntoIter<(
+                #krate::http::headers::HeaderName,
+                #krate::http::headers::HeaderValue,
+            )>;
+
+            fn as_headers(&self) -> ::std::result::Result<Self::Iter, Self::Error> {
+                let mut headers = ::std::vec::Vec::new();
+                #(#inserts)*
+                ::std::result::Result::Ok(headers.into_iter())
+            }
+        }
+    })
+}
+
+fn generate_field(krate: &Path, field: &Field) -> Result<TokenStream> {
+    let Some(header_name) = FieldAttrs::from_attrs(&field.attrs)?.name else {
+        return Ok(TokenStream::new());
+    };
+    let ident = field.ident.as_ref().expect("expected named field");
+
+    let header_name = match header_name {
+        HeaderNameAttr::Literal(name) => {
+            quote! { #krate::http::headers::HeaderName::from_static(#name) }
+        }
+        HeaderNameAttr::Path(path) => quote! { #path },
+    };
+    let push = quote! {
+        headers.push((
+            #header_name,
+            #krate::http::headers::HeaderValue::from(::std::string::ToString::to_string(value)),
+        ));
+    };
+
+    if is_option(&field.ty) {
+        Ok(quote! {
+            if let ::std::option::Option::Some(value) = &self.#ident {
+                #push
+            }
+        })
+    } else {
+        Ok(quote! {
+            {
+                let value = &self.#ident;
+                #push
+            }
+        })
+    }
+}
+
+fn is_option(ty: &Type) -> bool {
+    let Type::Path(path) = ty else {
+        return false;
+    };
+    let Some(segment) = path.path.segments.last() else {
+        return false;
+    };
+    segment.ident == "Option"
+        && matches!(
+            &segment.arguments,
+            PathArguments::AngleBracketed(args) if matches!(args.args.first(), Some(GenericArgument::Type(_)))
+        )
+}
+
+const INVALID_HEADER_ATTRIBUTE_MESSAGE: &str =
+    "invalid header attribute, expected attribute in form #[header(\"name\")] or #[header(NAME)]";
+
+const UPPERCASE_HEADER_NAME_MESSAGE: &str = "header names must be lowercase";
+
+#[derive(Default)]
+struct TypeAttrs {
+    krate: Option<Path>,
+}
+
+impl TypeAttrs {
+    fn from_attrs(attributes: &[Attribute]) -> Result<Self> {
+        let mut attrs = Self::default();
+        for attribute in attributes.iter().filter(|a| a.path().is_ident("header")) {
+            attribute.parse_nested_meta(|meta| {
+                if meta.path.is_ident("crate") {
+                    let path: LitStr = meta.value()?.parse()?;
+                    attrs.krate = Some(path.parse()?);
+                    return Ok(());
+                }
+                Err(meta.error("unsupported header attribute, expected `crate = \"...\"`"))
+            })?;
+        }
+        Ok(attrs)
+    }
+}
+
+enum HeaderNameAttr {
+    /// A literal header name, e.g., `#[header("x-ms-lease-id")]`.
+    Literal(LitStr),
+    /// A path to a `HeaderName` constant, e.g., `#[header(LEASE_ID)]`.
+    Path(Path),
+}
+
+#[derive(Default)]
+struct FieldAttrs {
+    name: Option<HeaderNameAttr>,
+}
+
+impl FieldAttrs {
+    fn from_attrs(attributes: &[Attribute]) -> Result<Self> {
+        let mut attrs = Self::default();
+        for attribute in attributes.iter().filter(|a| a.path().is_ident("header")) {
+            let meta_list = attribute
+                .meta
+                .require_list()
+                .map_err(|_| Error::new(attribute.span(), INVALID_HEADER_ATTRIBUTE_MESSAGE))?;
+            let expr: Expr = meta_list
+                .parse_args()
+                .map_err(|_| Error::new(meta_list.span(), INVALID_HEADER_ATTRIBUTE_MESSAGE))?;
+            attrs.name = Some(match expr {
+                Expr::Lit(syn::ExprLit {
+                    lit: Lit::Str(name),
+                    ..
+                }) => {
+                    if name.value().chars().any(char::is_uppercase) {
+                        return Err(Error::new(name.span(), UPPERCASE_HEADER_NAME_MESSAGE));
+                    }
+                    HeaderNameAttr::Literal(name)
+                }
+                Expr::Path(path) => HeaderNameAttr::Path(path.path),
+                _ => {
+                    return Err(Error::new(
+                        meta_list.span(),
+                        INVALID_HEADER_ATTRIBUTE_MESSAGE,
+                    ))
+                }
+            });
+        }
+        Ok(attrs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn field_attrs_requires_name() {
+        let attr: Attribute = syn::parse_quote! {
+            #[header]
+        };
+        assert!(
+            matches!(FieldAttrs::from_attrs(&[attr]), Err(err) if err.to_string() == INVALID_HEADER_ATTRIBUTE_MESSAGE)
+        );
+    }
+
+    #[test]
+    fn field_attrs_rejects_uppercase() {
+        let attr: Attribute = syn::parse_quote! {
+            #[header("X-Ms-Lease-Id")]
+        };
+        assert!(
+            matches!(FieldAttrs::from_attrs(&[attr]), Err(err) if err.to_string() == UPPERCASE_HEADER_NAME_MESSAGE)
+        );
+    }
+
+    #[test]
+    fn field_attrs_accepts_path() {
+        let attr: Attribute = syn::parse_quote! {
+            #[header(headers::IF_MATCH)]
+        };
+        assert!(matches!(
+            FieldAttrs::from_attrs(&[attr]).unwrap().name,
+            Some(HeaderNameAttr::Path(_))
+        ));
+    }
+}
@@ -0,0 +1,228 @@
+// Copyright (c) Microsoft Corporation. All rights reserved.
+// Licensed under the MIT License.
+
+use crate::Result;
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{
+    spanned::Spanned, Attribute, Data, DataEnum, DeriveInput, Error, Fields, Ident, LitStr, Type,
+    Variant,
+};
+
+const UNKNOWN_VALUE: &str = "UnknownValue";
+
+const UNSUPPORTED_VARIANT_MESSAGE: &str =
+    "`ExtensibleEnum` variants must be unit variants, except for `UnknownValue(String)`";
+
+const MISSING_UNKNOWN_VALUE_MESSAGE: &str =
+    "`ExtensibleEnum` requires an `UnknownValue(String)` variant";
+
+pub fn derive_extensible_enum_impl(ast: DeriveInput) -> Result<TokenStream> {
+    let body = generate_body(ast)?;
+
+    // We wrap the generated code in a const block to give it a unique scope.
+    let gen = quote! {
+        #[doc(hidden)]
+        const _: () = {
+            #body
+        };
+    };
+    Ok(gen)
+}
+
+fn generate_body(ast: DeriveInput) -> Result<TokenStream> {
+    let name = &ast.ident;
+    if !ast.generics.params.is_empty() {
+        return Err(Error::new(
+            ast.generics.span(),
+            "generic types are not supported for `ExtensibleEnum`",
+        ));
+    }
+    let Data::Enum(DataEnum { variants, .. }) = &ast.data else {
+        return Err(Error::new(
+            ast.span(),
+            "only enums are supported for `ExtensibleEnum`",
+        ));
+    };
+
+    let mut known = Vec::new();
+    let mut has_unknown = false;
+    for variant in variants {
+        if variant.ident == UNKNOWN_VALUE {
+            check_unknown_value(variant)?;
+            has_unknown = true;
+            continue;
+        }
+        if !matches!(variant.fields, Fields::Unit) {
+            return Err(Error::new(variant.span(), UNSUPPORTED_VARIANT_MESSAGE));
+        }
+        let value = VariantAttrs::from_attrs(&variant.attrs)?
+            .rename
+            .unwrap_or_else(|| LitStr::new(&variant.ident.to_string(), variant.ident.span()));
+        known.push((&variant.ident, value));
+    }
+    if !has_unknown {
+        return Err(Error::new(ast.span(), MISSING_UNKNOWN_VALUE_MESSAGE));
+    }
+
+    let unknown = Ident::new(UNKNOWN_VALUE, name.span());
+    let as_str_arms = known.iter().map(|(ident, value)| {
+        quote! { #name::#ident => #value, }
+    });
+    let from_str_arms = known.iter().map(|(ident, value)| {
+        quote! { #value => #name::#ident, }
+    });
+    let from_string_arms = from_str_arms.clone();
+
+    Ok(quote! {
+        #[automatically_derived]
+        impl ::std::convert::AsRef<str> for #name {
+            fn as_ref(&self) -> &str {
+                match self {
+                    #(#as_str_arms)*
+                    #name::#unknown(value) => value.as_str(),
+                }
+            }
+        }
+
+        #[automatically_derived]
+        impl ::std::fmt::Display for #name {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                f.write_str(::std::convert::AsRef::<str>::as_ref(self))
+            }
+        }
+
+        #[automatically_derived]
+        impl ::std::str::FromStr for #name {
+            type Err = ::std::convert::Infallible;
+
+            fn from_str(s: &str) -> ::std::result::Result<Self, Self::Err> {
+                ::std::result::Result::Ok(match s {
+                    #(#from_str_arms)*
+                    _ => #name::#unknown(::std::borrow::ToOwned::to_owned(s)),
+                })
+            }
+        }
+
+        #[automatically_derived]
+        impl ::std::convert::From<::std::string::String> for #name {
+            fn from(s: ::std::string::String) -> Self {
+                match s.as_str() {
+                    #(#from_string_arms)*
+                    _ => #name::#unknown(s),
+                }
+            }
+        }
+
+        #[automatically_derived]
+        impl ::serde::Serialize for #name {
+            fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+            where
+                S: ::serde::Serializer,
+            {
+                serializer.serialize_str(::std::convert::AsRef::<str>::as_ref(self))
+            }
+        }
+
+        #[automatically_derived]
+        impl<'de> ::serde::Deserialize<'de> for #name {
+            fn deserialize<D>(deserializer: D) -> ::std::result::Result<Self, D::Error>
+            where
+                D: ::serde::Deserializer<'de>,
+            {
+                let s = <::std::string::String as ::serde::Deserialize>::deserialize(deserializer)?;
+                ::std::result::Result::Ok(::std::convert::From::from(s))
+            }
+        }
+    })
+}
+
+fn check_unknown_value(variant: &Variant) -> Result<()> {
+    let Fields::Unnamed(fields) = &variant.fields else {
+        return Err(Error::new(variant.span(), UNSUPPORTED_VARIANT_MESSAGE));
+    };
+    let is_string = fields.unnamed.len() == 1
+        && matches!(&fields.unnamed[0].ty, Type::Path(path) if path.path.is_ident("String"));
+    if !is_string {
+        return Err(Error::new(variant.span(), UNSUPPORTED_VARIANT_MESSAGE));
+    }
+    Ok(())
+}
+
+#[derive(Default)]
+struct VariantAttrs {
+    rename: Option<LitStr>,
+}
+
+impl VariantAttrs {
+    fn from_attrs(attributes: &[Attribute]) -> Result<Self> {
+        let mut attrs = Self::default();
+        for attribute in attributes
+            .iter()
+            .filter(|a| a.path().is_ident("extensible"))
+        {
+            attribute.parse_nested_meta(|meta| {
+                if meta.path.is_ident("rename") {
+                    attrs.rename = Some(meta.value()?.parse()?);
+                    return Ok(());
+                }
+                Err(meta.error("unsupported extensible attribute, expected `rename = \"...\"`"))
+            })?;
+        }
+        Ok(attrs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn requires_unknown_value() {
+        let ast: DeriveInput = syn::parse_quote! {
+            enum Color {
+                Red,
+            }
+        };
+        assert!(
+            matches!(generate_body(ast), Err(err) if err.to_string() == MISSING_UNKNOWN_VALUE_MESSAGE)
+        );
+    }
+
+    #[test]
+    fn rejects_data_variants() {
+        let ast: DeriveInput = syn::parse_quote! {
+            enum Color {
+                Rgb(u8, u8, u8),
+                UnknownValue(String),
+            }
+        };
+        assert!(
+            matches!(generate_body(ast), Err(err) if err.to_string() == UNSUPPORTED_VARIANT_MESSAGE)
+        );
+
+        let ast: DeriveInput = syn::parse_quote! {
+            enum Color {
+                Red,
+                UnknownValue(u32),
+            }
+        };
+        assert!(
+            matches!(generate_body(ast), Err(err) if err.to_string() == UNSUPPORTED_VARIANT_MESSAGE)
+        );
+    }
+
+    #[test]
+    fn variant_attrs_rename() {
+        let attr: Attribute = syn::parse_quote! {
+            #[extensible(rename = "red")]
+        };
+        assert_eq!(
+            VariantAttrs::from_attrs(&[attr])
+                .unwrap()
+                .rename
+                .map(|r| r.value()),
+            Some("red".to_string())
+        );
+    }
+}
@@ -11,6 +11,9 @@ use syn::{parse_macro_input, DeriveInput};
 
 extern crate proc_macro;
 
+mod as_headers;
+mod extensible_enum;
+mod query;
 mod safe_debug;
 
 type Result<T> = ::std::result::Result<T, syn::Error>;
@@ -95,7 +98,174 @@ fn run_derive_macro(input: proc_macro::TokenStream, imp: DeriveImpl) -> proc_mac
 ///     assert_eq!(format!("{employee:?}"), r#"Employee { position: Position { id: 12, title: "Staff Engineer", .. }, .. }"#);
 /// }
 /// ```
+///
+/// Attributes on enum variants override the attribute on the enum, and attributes on fields override both.
+///
+/// ```
+/// # use typespec_macros::SafeDebug;
+/// #[derive(SafeDebug)]
+/// #[safe(true)]
+/// enum Principal {
+///     Service(String),
+///     #[safe(false)]
+///     User {
+///         #[safe(true)]
+///         id: u32,
+///         email: String,
+///     },
+/// }
+///
+/// let user = Principal::User {
+///     id: 12,
+///     email: "kelly@contoso.com".to_string(),
+/// };
+/// if cfg!(feature = "debug") {
+///     assert_eq!(format!("{user:?}"), r#"User { id: 12, email: "kelly@contoso.com" }"#);
+/// } else {
+///     assert_eq!(format!("{user:?}"), "User { id: 12, .. }");
+/// }
+/// ```
 #[proc_macro_derive(SafeDebug, attributes(safe))]
 pub fn derive_safe_debug(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     run_derive_macro(input, safe_debug::derive_safe_debug_impl)
 }
+
+/// Derive to append the fields of an options struct to a URL as query parameters.
+///
+/// Implements `typespec_client_core::http::AppendToUrlQuery`. Each field value is converted to a string using its
+/// [`Display`](std::fmt::Display) implementation and appended using its field name as the parameter name.
+/// Fields of type `Option<T>` are skipped when `None`. Fields of type `Vec<T>` are appended as a single comma-separated parameter,
+/// and are skipped when empty.
+///
+/// Fields support the following `#[query(..)]` attributes:
+///
+/// * `rename = "name"` - Use `name` as the parameter name instead of the field name.
+/// * `style = "csv" | "ssv" | "pipes" | "multi"` - Encode a collection as comma-, space-, or pipe-separated values,
+///   or as a separate parameter for each value. Any field whose reference implements `IntoIterator` can be given a style.
+/// * `flatten` - Append a field that itself implements `AppendToUrlQuery`.
+/// * `skip` - Do not append the field, e.g., for `ClientMethodOptions`.
+///
+/// The generated code refers to `::typespec_client_core` by default. Use `#[query(crate = "path")]` on the type
+/// to refer to a crate that re-exports it, e.g., `#[query(crate = "azure_core")]`.
+///
+/// # Examples
+///
+/// ```
+/// # use typespec_macros::QueryParams;
+/// use typespec_client_core::http::{AppendToUrlQuery, Url};
+///
+/// #[derive(Default, QueryParams)]
+/// struct ListBlobsOptions {
+///     prefix: Option<String>,
+///     #[query(rename = "maxresults")]
+///     max_results: Option<u32>,
+///     include: Option<Vec<String>>,
+///     #[query(style = "multi")]
+///     tag: Vec<String>,
+///     #[query(skip)]
+///     marker: Option<String>,
+/// }
+///
+/// let options = ListBlobsOptions {
+///     max_results: Some(10),
+///     include: Some(vec!["metadata".into(), "snapshots".into()]),
+///     tag: vec!["a".into(), "b".into()],
+///     marker: Some("ignored".into()),
+///     ..Default::default()
+/// };
+///
+/// let mut url: Url = "https://example.com/container?restype=container".parse().unwrap();
+/// options.append_to_url_query(&mut url);
+/// assert_eq!(
+///     url.as_str(),
+///     "https://example.com/container?restype=container&maxresults=10&include=metadata%2Csnapshots&tag=a&tag=b",
+/// );
+/// ```
+#[proc_macro_derive(QueryParams, attributes(query))]
+pub fn derive_query_params(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    run_derive_macro(input, query::derive_query_params_impl)
+}
+
+/// Derive to insert the fields of an options struct into a request as headers.
+///
+/// Implements `typespec_client_core::http::headers::AsHeaders` so the type can be passed to `Request::insert_headers`.
+/// Only fields attributed with `#[header(..)]` are inserted; the value is converted using its [`Display`](std::fmt::Display) implementation.
+/// Fields of type `Option<T>` are skipped when `None`.
+///
+/// The header name can be a lowercase string literal, e.g., `#[header("x-ms-lease-id")]`,
+/// or a path to a `HeaderName` constant, e.g., `#[header(headers::IF_MATCH)]`.
+///
+/// The generated code refers to `::typespec_client_core` by default. Use `#[header(crate = "path")]` on the type
+/// to refer to a crate that re-exports it, e.g., `#[header(crate = "azure_core")]`.
+///
+/// # Examples
+///
+/// ```
+/// # use typespec_macros::AsHeaders;
+/// use typespec_client_core::http::{headers::{self, HeaderName}, Method, Request};
+///
+/// #[derive(Default, AsHeaders)]
+/// struct DeleteBlobOptions {
+///     #[header("x-ms-lease-id")]
+///     lease_id: Option<String>,
+///     #[header(headers::IF_MATCH)]
+///     if_match: Option<String>,
+///     #[header("x-ms-version")]
+///     version: &'static str,
+///     timeout: Option<u32>,
+/// }
+///
+/// let options = DeleteBlobOptions {
+///     lease_id: Some("a9e8d2ac".into()),
+///     version: "2025-01-05",
+///     timeout: Some(30),
+///     ..Default::default()
+/// };
+///
+/// let mut request = Request::new("https://example.com/container/blob".parse().unwrap(), Method::Delete);
+/// request.insert_headers(&options).unwrap();
+///
+/// let headers = request.headers();
+/// assert_eq!(headers.get_optional_str(&HeaderName::from_static("x-ms-lease-id")), Some("a9e8d2ac"));
+/// assert_eq!(headers.get_optional_str(&HeaderName::from_static("x-ms-version")), Some("2025-01-05"));
+/// assert_eq!(headers.get_optional_str(&headers::IF_MATCH), None);
+/// assert_eq!(headers.iter().count(), 2);
+/// ```
+#[proc_macro_derive(AsHeaders, attributes(header))]
+pub fn derive_as_headers(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    run_derive_macro(input, as_headers::derive_as_headers_impl)
+}
+
+/// Derive for an "extensible" string enum: known values as unit variants plus an `UnknownValue(String)` catch-all,
+/// so models keep working when a service adds new values.
+///
+/// Implements [`AsRef<str>`], [`Display`](std::fmt::Display), [`FromStr`](std::str::FromStr), `From<String>`,
+/// and serde's `Serialize` and `Deserialize` using the string value. Parsing never fails: values that don't match
+/// a known variant become `UnknownValue`. The type's crate must depend on `serde`.
+///
+/// Each variant's value is its name unless renamed with `#[extensible(rename = "value")]`. Values are case-sensitive.
+///
+/// # Examples
+///
+/// ```
+/// # use typespec_macros::ExtensibleEnum;
+/// #[derive(Clone, Debug, PartialEq, ExtensibleEnum)]
+/// enum AccessTier {
+///     Hot,
+///     Cool,
+///     #[extensible(rename = "Archive")]
+///     Archived,
+///     UnknownValue(String),
+/// }
+///
+/// assert_eq!(AccessTier::Archived.to_string(), "Archive");
+/// assert_eq!("Cool".parse::<AccessTier>().unwrap(), AccessTier::Cool);
+///
+/// let tier: AccessTier = serde_json::from_str(r#""Cold""#).unwrap();
+/// assert_eq!(tier, AccessTier::UnknownValue("Cold".to_string()));
+/// assert_eq!(serde_json::to_string(&tier).unwrap(), r#""Cold""#);
+/// ```
+#[proc_macro_derive(ExtensibleEnum, attributes(extensible))]
+pub fn derive_extensible_enum(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    run_derive_macro(input, extensible_enum::derive_extensible_enum_impl)
+}
@@ -0,0 +1,277 @@
+// Copyright (c) Microsoft Corporation. All rights reserved.
+// Licensed under the MIT License.
+
+use crate::Result;
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{
+    meta::ParseNestedMeta, spanned::Spanned, Attribute, Data, DataStruct, DeriveInput, Error,
+    Field, Fields, GenericArgument, LitStr, Path, PathArguments, Type,
+};
+
+pub fn derive_query_params_impl(ast: DeriveInput) -> Result<TokenStream> {
+    let body = generate_body(ast)?;
+
+    // We wrap the generated code in a const block to give it a unique scope.
+    let gen = quote! {
+        #[doc(hidden)]
+        const _: () = {
+            #body
+        };
+    };
+    Ok(gen)
+}
+
+fn generate_body(ast: DeriveInput) -> Result<TokenStream> {
+    let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
+    let name = &ast.ident;
+
+    let type_attrs = TypeAttrs::from_attrs(&ast.attrs)?;
+    let krate = type_attrs
+        .krate
+        .unwrap_or_else(|| syn::parse_quote!(::typespec_client_core));
+
+    let fields = match &ast.data {
+        Data::Struct(DataStruct {
+            fields: Fields::Named(fields),
+            ..
+        }) => &fields.named,
+        _ => {
+            return Err(Error::new(
+                ast.span(),
+                "only structs with named fields are supported for `QueryParams`",
+            ))
+        }
+    };
+
+    let appends = fields
+        .iter()
+        .map(|f| generate_field(&krate, f))
+        .collect::<Result<Vec<_>>>()?;
+
+    Ok(quote! {
+        #[automatically_derived]
+        impl #impl_generics #krate::http::AppendToUrlQuery for #name #ty_generics #where_clause {
+            fn append_to_url_query(&self, url: &mut #krate::http::Url) {
+                #(#appends)*
+            }
+        }
+    })
+}
+
+fn generate_field(krate: &Path, field: &Field) -> Result<TokenStream> {
+    let attrs = FieldAttrs::from_attrs(&field.attrs)?;
+    let ident = field.ident.as_ref().expect("expected named field");
+
+    if attrs.skip {
+        return Ok(TokenStream::new());
+    }
+    if attrs.flatten {
+        return Ok(quote! {
+            #krate::http::AppendToUrlQuery::append_to_url_query(&self.#ident, url);
+        });
+    }
+
+    let name = attrs
+        .rename
+        .unwrap_or_else(|| ident.to_string().trim_start_matches("r#").to_string());
+    let (optional, ty) = match option_inner(&field.ty) {
+        Some(ty) => (true, ty),
+        None => (false, &field.ty),
+    };
+    let style = match attrs.style {
+        Some(style) => Some(style),
+        None if is_vec(ty) => Some(ListStyle::Csv),
+        None => None,
+    };
+
+    let append = match style {
+        None => quote! {
+            url.query_pairs_mut()
+                .append_pair(#name, &::std::string::ToString::to_string(value));
+        },
+        Some(ListStyle::Multi) => quote! {
+            for item in value {
+                url.query_pairs_mut()
+                    .append_pair(#name, &::std::string::ToString::to_string(item));
+            }
+        },
+        Some(style) => {
+            let separator = style.separator();
+            quote! {
+                let items: ::std::vec::Vec<::std::string::String> = ::std::iter::IntoIterator::into_iter(value)
+                    .map(|item| ::std::string::ToString::to_string(item))
+                    .collect();
+                if !items.is_empty() {
+                    url.query_pairs_mut().append_pair(#name, &items.join(#separator));
+                }
+            }
+        }
+    };
+
+    if optional {
+        Ok(quote! {
+            if let ::std::option::Option::Some(value) = &self.#ident {
+                #append
+            }
+        })
+    } else {
+        Ok(quote! {
+            {
+                let value = &self.#ident;
+                #append
+            }
+        })
+    }
+}
+
+/// Gets the `T` in `Option<T>`, if `ty` is an `Option`.
+fn option_inner(ty: &Type) -> Option<&Type> {
+    generic_arg(ty, "Option")
+}
+
+fn is_vec(ty: &Type) -> bool {
+    generic_arg(ty, "Vec").is_some()
+}
+
+fn generic_arg<'a>(ty: &'a Type, ident: &str) -> Option<&'a Type> {
+    let Type::Path(path) = ty else {
+        return None;
+    };
+    let segment = path.path.segments.last()?;
+    if segment.ident != ident {
+        return None;
+    }
+    let PathArguments::AngleBracketed(args) = &segment.arguments else {
+        return None;
+    };
+    match args.args.first()? {
+        GenericArgument::Type(ty) => Some(ty),
+        _ => None,
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum ListStyle {
+    /// Comma-separated values in a single parameter.
+    Csv,
+    /// Space-separated values in a single parameter.
+    Ssv,
+    /// Pipe-separated values in a single parameter.
+    Pipes,
+    /// A separate parameter for each value.
+    Multi,
+}
+
+impl ListStyle {
+    fn separator(&self) -> &'static str {
+        match self {
+            ListStyle::Csv => ",",
+            ListStyle::Ssv => " ",
+            ListStyle::Pipes => "|",
+            ListStyle::Multi => unreachable!("multi style has no separator"),
+        }
+    }
+}
+
+const INVALID_STYLE_MESSAGE: &str =
+    "invalid style, expected one of \"csv\", \"ssv\", \"pipes\", or \"multi\"";
+
+#[derive(Default)]
+struct TypeAttrs {
+    krate: Option<Path>,
+}
+
+impl TypeAttrs {
+    fn from_attrs(attributes: &[Attribute]) -> Result<Self> {
+        let mut attrs = Self::default();
+        for attribute in attributes.iter().filter(|a| a.path().is_ident("query")) {
+            attribute.parse_nested_meta(|meta| {
+                if meta.path.is_ident("crate") {
+                    let path: LitStr = meta.value()?.parse()?;
+                    attrs.krate = Some(path.parse()?);
+                    return Ok(());
+                }
+                Err(meta.error("unsupported query attribute, expected `crate = \"...\"`"))
+            })?;
+        }
+        Ok(attrs)
+    }
+}
+
+#[derive(Default)]
+struct FieldAttrs {
+    rename: Option<String>,
+    style: Option<ListStyle>,
+    skip: bool,
+    flatten: bool,
+}
+
+impl FieldAttrs {
+    fn from_attrs(attributes: &[Attribute]) -> Result<Self> {
+        let mut attrs = Self::default();
+        for attribute in attributes.iter().filter(|a| a.path().is_ident("query")) {
+            attribute.parse_nested_meta(|meta| attrs.parse_meta(meta))?;
+        }
+        Ok(attrs)
+    }
+
+    fn parse_meta(&mut self, meta: ParseNestedMeta) -> Result<()> {
+        if meta.path.is_ident("rename") {
+            let name: LitStr = meta.value()?.parse()?;
+            self.rename = Some(name.value());
+        } else if meta.path.is_ident("style") {
+            let style: LitStr = meta.value()?.parse()?;
+            self.style = Some(match style.value().as_str() {
+                "csv" => ListStyle::Csv,
+                "ssv" => ListStyle::Ssv,
+                "pipes" => ListStyle::Pipes,
+                "multi" => ListStyle::Multi,
+                _ => return Err(Error::new(style.span(), INVALID_STYLE_MESSAGE)),
+            });
+        } else if meta.path.is_ident("skip") {
+            self.skip = true;
+        } else if meta.path.is_ident("flatten") {
+            self.flatten = true;
+        } else {
+            return Err(meta.error(
+                "unsupported query attribute, expected `rename`, `style`, `skip`, or `flatten`",
+            ));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn field_attrs_invalid_style() {
+        let attr: Attribute = syn::parse_quote! {
+            #[query(style = "tsv")]
+        };
+        assert!(
+            matches!(FieldAttrs::from_attrs(&[attr]), Err(err) if err.to_string() == INVALID_STYLE_MESSAGE)
+        );
+    }
+
+    #[test]
+    fn vec_defaults_to_csv() {
+        let ast: DeriveInput = syn::parse_quote! {
+            struct Options {
+                include: Option<Vec<String>>,
+            }
+        };
+        let tokens = derive_query_params_impl(ast).unwrap().to_string();
+        assert!(tokens.contains("join (\",\")"), "{tokens}");
+    }
+
+    #[test]
+    fn tuple_structs_unsupported() {
+        let ast: DeriveInput = syn::parse_quote! {
+            struct Options(Option<String>);
+        };
+        assert!(derive_query_params_impl(ast).is_err());
+    }
+}
@@ -35,8 +35,9 @@ fn generate_body(ast: DeriveInput) -> Result<TokenStream> {
                     let variant_name = &v.ident;
                     let path = to_path(&[name, variant_name]);
 
-                    let mut enum_attrs = Attrs::from_attrs(&v.attrs)?;
-                    enum_attrs.update(&type_attrs);
+                    // The variant attribute, if present, overrides the type attribute.
+                    let mut enum_attrs = type_attrs.clone();
+                    enum_attrs.update(&Attrs::from_attrs(&v.attrs)?);
 
                     generate_fields(&path, &enum_attrs, &v.fields)
                 })
@@ -188,7 +189,7 @@ fn to_path(idents: &[&Ident]) -> Path {
     }
 }
 
-#[derive(Debug, Default)]
+#[derive(Clone, Debug, Default)]
 struct Attrs {
     safe: Option<bool>,
 }
@@ -3,7 +3,7 @@
 
 //! Account reference types for Azure Cosmos DB.
 
-use crate::{AccountEndpoint, CosmosCredential};
+use crate::{models::ResourceTokens, AccountEndpoint, CosmosCredential};
 
 #[cfg(feature = "key_auth")]
 use azure_core::credentials::Secret;
@@ -13,7 +13,8 @@ use std::sync::Arc;
 /// A reference to a Cosmos DB account, combining an endpoint with a credential.
 ///
 /// This type bundles together the account endpoint and the credential needed to
-/// authenticate with it. Use convenience constructors [`with_credential()`](Self::with_credential)
+/// authenticate with it. Use convenience constructors [`with_credential()`](Self::with_credential),
+/// [`with_resource_tokens()`](Self::with_resource_tokens),
 /// or [`with_authentication_key()`](Self::with_authentication_key) (requires the `key_auth` feature) to create instances.
 ///
 /// # Examples
@@ -77,6 +78,35 @@ impl AccountReference {
         }
     }
 
+    /// Creates a new account reference that authenticates with resource tokens.
+    ///
+    /// Resource tokens grant scoped, time-limited access to specific resources,
+    /// so a client that must not hold the account key can still reach the data
+    /// it is permitted to use. See [`ResourceTokens`] for how a token is chosen
+    /// for each request.
+    ///
+    /// # Arguments
+    ///
+    /// * `endpoint` - The Cosmos DB account endpoint.
+    /// * `tokens` - The resource tokens, keyed by the link of the resource each one permits.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use azure_data_cosmos::{models::ResourceTokens, AccountEndpoint, AccountReference};
+    ///
+    /// let endpoint: AccountEndpoint = "https://myaccount.documents.azure.com/".parse().unwrap();
+    /// let tokens = ResourceTokens::new()
+    ///     .with_token("dbs/mydb/colls/orders", "type=resource&ver=1.0&sig=...");
+    /// let account = AccountReference::with_resource_tokens(endpoint, tokens);
+    /// ```
+    pub fn with_resource_tokens(endpoint: AccountEndpoint, tokens: ResourceTokens) -> Self {
+        Self {
+            endpoint,
+            credential: CosmosCredential::from(tokens),
+        }
+    }
+
     /// Returns the endpoint and credential as a tuple.
     ///
     /// This is used internally by the builder to extract the components.
@@ -0,0 +1,881 @@
+// Copyright (c) Microsoft Corporation. All rights reserved.
+// Licensed under the MIT License.
+
+//! Provides the [`BulkExecutor`] type for executing many item operations as batch requests.
+//!
+//! Operations are grouped by the partition key range that owns their partition key, resolved from
+//! the driver's partition key range cache, and packed into non-atomic batch requests of up to
+//! [`MAX_OPERATIONS_PER_BATCH`] operations, so a large ingestion costs a fraction of the round
+//! trips of one point operation per item. A batch request is sent as soon as its batch is full,
+//! while the ranges of later operations are still being resolved. Batch requests for different
+//! ranges (and successive batches for the same range) run concurrently up to
+//! [`BulkOptions::max_concurrency`].
+//! Operations the service throttles individually are resubmitted after the service's retry-after
+//! delay, and operations whose range split or merged are regrouped against the refreshed ranges.
+
+use crate::{
+    error::CosmosStatus,
+    feed::FeedRange,
+    models::{
+        BatchResponse, BulkOperation, BulkOperationResult, TransactionalBatchOperation,
+        TransactionalBatchOperationResult,
+    },
+    options::BulkOptions,
+    PartitionKey,
+};
+use async_trait::async_trait;
+use azure_core::{http::headers::AsHeaders, time::Duration};
+use azure_data_cosmos_driver::{
+    models::{ContainerReference, CosmosOperation},
+    CosmosDriver,
+};
+use futures::{
+    stream::{self, BoxStream},
+    Stream, StreamExt,
+};
+use serde::Serialize;
+use std::{
+    collections::{HashMap, VecDeque},
+    pin::Pin,
+    sync::Arc,
+    task,
+};
+
+/// The maximum number of operations the service accepts in one batch request.
+const MAX_OPERATIONS_PER_BATCH: usize = 100;
+
+/// The maximum size of a batch request body the service accepts.
+const MAX_BATCH_BODY_BYTES: usize = 2 * 1024 * 1024;
+
+/// The per-operation status the service reports for a throttled operation.
+const TOO_MANY_REQUESTS: u16 = 429;
+
+/// The delay before resubmitting throttled operations when the service does not specify one.
+const DEFAULT_THROTTLE_DELAY: Duration = Duration::milliseconds(100);
+
+/// The number of times operations are regrouped after their partition key range splits or merges.
+const MAX_REGROUP_RETRIES: usize = 3;
+
+/// A stream of the results of a bulk execution.
+///
+/// Returned by [`ContainerClient::execute_bulk()`](crate::clients::ContainerClient::execute_bulk()).
+/// Yields one [`BulkOperationResult`] per operation, in completion order. Batch requests
+/// are only sent while the stream is polled, and dropping the stream stops sending new ones.
+///
+/// # Usage
+///
+/// ```rust,no_run
+/// # use azure_data_cosmos::models::BulkOperation;
+/// # async fn example(container_client: azure_data_cosmos::clients::ContainerClient) -> azure_data_cosmos::Result<()> {
+/// use futures::StreamExt;
+///
+/// let operations = (0..10_000).map(|i| {
+///     let item = serde_json::json!({ "id": i.to_string(), "category": format!("category{}", i % 100) });
+///     BulkOperation::upsert_item(format!("category{}", i % 100), item, None)
+/// });
+/// let operations = operations.collect::<azure_data_cosmos::Result<Vec<_>>>()?;
+///
+/// let mut results = container_client.execute_bulk(operations, None);
+/// while let Some(result) = results.next().await {
+///     match result.result() {
+///         Ok(item) if item.is_success() => {}
+///         Ok(item) => println!("operation {} failed with {}", result.index(), item.status_code()),
+///         Err(error) => println!("operation {} failed: {error}", result.index()),
+///     }
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub struct BulkExecutor {
+    stream: BoxStream<'static, BulkOperationResult>,
+}
+
+impl BulkExecutor {
+    pub(crate) fn new(
+        driver: Arc<CosmosDriver>,
+        container: ContainerReference,
+        operations: Vec<BulkOperation>,
+        options: BulkOptions,
+    ) -> Self {
+        Self::with_container(
+            Arc::new(DriverContainer { driver, container }),
+            operations,
+            options,
+        )
+    }
+
+    fn with_container<C: BatchContainer + 'static>(
+        container: Arc<C>,
+        operations: Vec<BulkOperation>,
+        options: BulkOptions,
+    ) -> Self {
+        let max_concurrency = options.max_concurrency.get();
+        let mut pending = Vec::with_capacity(operations.len());
+        let mut failed = Vec::new();
+        for (index, operation) in operations.into_iter().enumerate() {
+            match PendingOperation::new(index, operation) {
+                Ok(operation) => pending.push(operation),
+                Err(error) => failed.push(PlanStep::Failed(BulkOperationResult::new(
+                    index,
+                    Err(error),
+                ))),
+            }
+        }
+
+        let steps = stream::iter(failed).chain(plan_batches(container.clone(), pending, false));
+        let stream = steps
+            .map(move |step| {
+                let container = container.clone();
+                let options = options.clone();
+                async move {
+                    match step {
+                        PlanStep::Batch(batch) => execute_batch(container, batch, &options).await,
+                        PlanStep::Failed(result) => vec![result],
+                    }
+                }
+            })
+            .buffer_unordered(max_concurrency)
+            .flat_map(stream::iter);
+        Self {
+            stream: Box::pin(stream),
+        }
+    }
+}
+
+impl Stream for BulkExecutor {
+    type Item = BulkOperationResult;
+
+    fn poll_next(
+        mut self: Pin<&mut Self>,
+        cx: &mut task::Context<'_>,
+    ) -> task::Poll<Option<Self::Item>> {
+        self.stream.poll_next_unpin(cx)
+    }
+}
+
+/// The container requests made by a [`BulkExecutor`].
+#[async_trait]
+trait BatchContainer: Send + Sync {
+    /// Returns the feed range of the single partition key range that owns `partition_key`.
+    ///
+    /// With `force_refresh`, the partition key ranges are refreshed before the lookup.
+    async fn partition_key_range(
+        &self,
+        partition_key: &PartitionKey,
+        force_refresh: bool,
+    ) -> crate::Result<FeedRange>;
+
+    /// Sends one non-atomic batch request with `body` to `range` and returns its per-operation
+    /// results, in order.
+    async fn send_batch(
+        &self,
+        range: &FeedRange,
+        body: Vec<u8>,
+        options: &BulkOptions,
+    ) -> crate::Result<Vec<TransactionalBatchOperationResult>>;
+}
+
+/// A [`BatchContainer`] that sends requests through the driver.
+struct DriverContainer {
+    driver: Arc<CosmosDriver>,
+    container: ContainerReference,
+}
+
+#[async_trait]
+impl BatchContainer for DriverContainer {
+    async fn partition_key_range(
+        &self,
+        partition_key: &PartitionKey,
+        force_refresh: bool,
+    ) -> crate::Result<FeedRange> {
+        let ranges = self
+            .driver
+            .resolve_partition_key_ranges_for_key(&self.container, partition_key, force_refresh)
+            .await;
+        match ranges.as_deref() {
+            Some([range]) => Ok(FeedRange::try_from(range)?),
+            _ => Err(crate::DriverCosmosError::builder()
+                .with_status(CosmosStatus::CLIENT_TOPOLOGY_RESOLUTION_FAILED)
+                .with_message(
+                    "could not resolve a single partition key range for the operation's partition key",
+                )
+                .build()
+                .into()),
+        }
+    }
+
+    async fn send_batch(
+        &self,
+        range: &FeedRange,
+        body: Vec<u8>,
+        options: &BulkOptions,
+    ) -> crate::Result<Vec<TransactionalBatchOperationResult>> {
+        let operation =
+            CosmosOperation::bulk(self.container.clone(), range.clone()).with_body(body);
+        let driver_response = self
+            .driver
+            .execute_singleton_operation(operation, options.operation.clone())
+            .await?;
+        Ok(
+            BatchResponse::new(crate::driver_bridge::driver_response_to_cosmos_response(
+                driver_response,
+            ))
+            .into_model()?
+            .into_results(),
+        )
+    }
+}
+
+/// An operation waiting to be sent, with its position in the submitted sequence.
+#[derive(Debug)]
+struct PendingOperation {
+    index: usize,
+    partition_key: PartitionKey,
+    /// The partition key as the JSON array the service expects in each batch body element.
+    partition_key_json: String,
+    operation: TransactionalBatchOperation,
+}
+
+impl PendingOperation {
+    fn new(index: usize, operation: BulkOperation) -> crate::Result<Self> {
+        let (partition_key, operation) = operation.into_parts();
+        let partition_key_json = partition_key
+            .as_headers()
+            .ok()
+            .and_then(|mut headers| headers.next())
+            .map(|(_, value)| value.as_str().to_owned())
+            .ok_or_else(|| {
+                crate::DriverCosmosError::builder()
+                    .with_status(CosmosStatus::new(azure_core::http::StatusCode::BadRequest))
+                    .with_message("bulk operations require a non-empty partition key")
+                    .build()
+            })?;
+        Ok(Self {
+            index,
+            partition_key,
+            partition_key_json,
+            operation,
+        })
+    }
+
+    fn body_item(&self) -> BulkItem<'_> {
+        BulkItem {
+            partition_key: &self.partition_key_json,
+            operation: &self.operation,
+        }
+    }
+}
+
+/// One element of a bulk batch request body: the operation and the partition key it targets.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct BulkItem<'a> {
+    partition_key: &'a str,
+    #[serde(flatten)]
+    operation: &'a TransactionalBatchOperation,
+}
+
+/// Operations sent together in one batch request to the partition key range that owns them.
+#[derive(Debug)]
+struct PlannedBatch {
+    range: FeedRange,
+    operations: Vec<PendingOperation>,
+}
+
+/// A step of a bulk execution: a batch to send, or the result of an operation that can't be sent.
+enum PlanStep {
+    Batch(PlannedBatch),
+    Failed(BulkOperationResult),
+}
+
+/// Returns the batches of `operations`, resolving the partition key range of each operation only
+/// as it is grouped.
+///
+/// A batch is yielded as soon as it reaches the service's operation count or body size limit, so
+/// batch requests are sent while the ranges of later operations are still being resolved. The
+/// remaining, partially filled batches are yielded once every operation is grouped. An operation
+/// whose range can't be resolved yields a failed result. With `force_refresh`, the partition key
+/// ranges are refreshed before the first lookup.
+fn plan_batches<C: BatchContainer + 'static>(
+    container: Arc<C>,
+    operations: Vec<PendingOperation>,
+    force_refresh: bool,
+) -> impl Stream<Item = PlanStep> + Send {
+    let planner = BatchPlanner {
+        container,
+        operations: operations.into_iter(),
+        ranges: HashMap::new(),
+        batches: BatchGroups::default(),
+        force_refresh,
+    };
+    stream::unfold(planner, |mut planner| async move {
+        let step = planner.next_step().await?;
+        Some((step, planner))
+    })
+}
+
+/// The state of [`plan_batches`].
+struct BatchPlanner<C> {
+    container: Arc<C>,
+    operations: std::vec::IntoIter<PendingOperation>,
+    /// The resolved range of each partition key seen so far.
+    ranges: HashMap<PartitionKey, crate::Result<FeedRange>>,
+    batches: BatchGroups,
+    force_refresh: bool,
+}
+
+impl<C: BatchContainer> BatchPlanner<C> {
+    async fn next_step(&mut self) -> Option<PlanStep> {
+        while let Some(operation) = self.operations.next() {
+            match self.range(&operation.partition_key).await {
+                Ok(range) => {
+                    if let Some(batch) = self.batches.push(range, operation) {
+                        return Some(PlanStep::Batch(batch));
+                    }
+                }
+                Err(error) => {
+                    return Some(PlanStep::Failed(BulkOperationResult::new(
+                        operation.index,
+                        Err(error),
+                    )))
+                }
+            }
+        }
+        self.batches.pop().map(PlanStep::Batch)
+    }
+
+    async fn range(&mut self, partition_key: &PartitionKey) -> crate::Result<FeedRange> {
+        if let Some(range) = self.ranges.get(partition_key) {
+            return range.clone();
+        }
+        let range = self
+            .container
+            .partition_key_range(partition_key, self.force_refresh)
+            .await;
+        self.force_refresh = false;
+        self.ranges.insert(partition_key.clone(), range.clone());
+        range
+    }
+}
+
+/// The batch being filled for each partition key range, in the order the ranges were first seen.
+#[derive(Default)]
+struct BatchGroups {
+    groups: VecDeque<BatchGroup>,
+    indices: HashMap<FeedRange, usize>,
+}
+
+struct BatchGroup {
+    batch: PlannedBatch,
+    body_len: usize,
+}
+
+impl BatchGroups {
+    /// Adds `operation` to the batch for `range`, returning that batch if it was full without
+    /// `operation`.
+    fn push(&mut self, range: FeedRange, operation: PendingOperation) -> Option<PlannedBatch> {
+        let index = *self.indices.entry(range.clone()).or_insert_with(|| {
+            self.groups.push_back(BatchGroup {
+                batch: PlannedBatch {
+                    range,
+                    operations: Vec::new(),
+                },
+                // Account for the enclosing `[` and `]`.
+                body_len: 2,
+            });
+            self.groups.len() - 1
+        });
+        let group = &mut self.groups[index];
+
+        // Account for the separating `,`.
+        let operation_len =
+            serde_json::to_vec(&operation.body_item()).map_or(0, |body| body.len()) + 1;
+        let full = if !group.batch.operations.is_empty()
+            && (group.batch.operations.len() == MAX_OPERATIONS_PER_BATCH
+                || group.body_len + operation_len > MAX_BATCH_BODY_BYTES)
+        {
+            group.body_len = 2;
+            Some(PlannedBatch {
+                range: group.batch.range.clone(),
+                operations: std::mem::take(&mut group.batch.operations),
+            })
+        } else {
+            None
+        };
+        group.body_len += operation_len;
+        group.batch.operations.push(operation);
+        full
+    }
+
+    /// Removes the batch of the first range seen. Only called once no more operations are pushed.
+    fn pop(&mut self) -> Option<PlannedBatch> {
+        self.indices.clear();
+        self.groups.pop_front().map(|group| group.batch)
+    }
+}
+
+/// Returns whether a batch request failed because its target range no longer matches the
+/// container's partition key ranges.
+fn is_topology_change(error: &crate::CosmosError) -> bool {
+    let status = error.status();
+    status.is_partition_key_range_gone()
+        || status == CosmosStatus::COMPLETING_SPLIT
+        || status == CosmosStatus::COMPLETING_PARTITION_MIGRATION
+}
+
+/// Sends `batch`, resubmitting throttled operations and regrouping operations whose range
+/// changed, and returns a result for each operation.
+async fn execute_batch<C: BatchContainer + 'static>(
+    container: Arc<C>,
+    batch: PlannedBatch,
+    options: &BulkOptions,
+) -> Vec<BulkOperationResult> {
+    let mut results = Vec::with_capacity(batch.operations.len());
+    let mut queue = vec![(batch, 0)];
+    while let Some((batch, regroup_retries)) = queue.pop() {
+        let PlannedBatch {
+            range,
+            operations: mut pending,
+        } = batch;
+        let mut throttle_retries = 0;
+        loop {
+            let response = send_batch(&*container, &range, &pending, options).await;
+            let operation_results = match response {
+                Ok(operation_results) => operation_results,
+                Err(error)
+                    if regroup_retries < MAX_REGROUP_RETRIES && is_topology_change(&error) =>
+                {
+                    let steps: Vec<_> = plan_batches(container.clone(), pending, true)
+                        .collect()
+                        .await;
+                    for step in steps {
+                        match step {
+                            PlanStep::Batch(batch) => queue.push((batch, regroup_retries + 1)),
+                            PlanStep::Failed(result) => results.push(result),
+                        }
+                    }
+                    break;
+                }
+                Err(error) => {
+                    results.extend(pending.into_iter().map(|operation| {
+                        BulkOperationResult::new(operation.index, Err(error.clone()))
+                    }));
+                    break;
+                }
+            };
+
+            let retry_throttled = throttle_retries < options.max_throttle_retries;
+            let mut throttled = Vec::new();
+            let mut delay: Option<u64> = None;
+            for (operation, result) in pending.into_iter().zip(operation_results) {
+                if retry_throttled && result.status_code() == TOO_MANY_REQUESTS {
+                    delay = delay.max(result.retry_after_milliseconds());
+                    throttled.push(operation);
+                } else {
+                    results.push(BulkOperationResult::new(operation.index, Ok(result)));
+                }
+            }
+            if throttled.is_empty() {
+                break;
+            }
+
+            throttle_retries += 1;
+            let delay = delay
+                .and_then(|delay| i64::try_from(delay).ok())
+                .map_or(DEFAULT_THROTTLE_DELAY, Duration::milliseconds);
+            azure_core::sleep::sleep(delay).await;
+            pending = throttled;
+        }
+    }
+    results
+}
+
+/// Sends one non-atomic batch request to `range` and returns its per-operation results, in order.
+async fn send_batch(
+    container: &impl BatchContainer,
+    range: &FeedRange,
+    operations: &[PendingOperation],
+    options: &BulkOptions,
+) -> crate::Result<Vec<TransactionalBatchOperationResult>> {
+    let body = serde_json::to_vec(
+        &operations
+            .iter()
+            .map(PendingOperation::body_item)
+            .collect::<Vec<_>>(),
+    )?;
+    let results = container.send_batch(range, body, options).await?;
+
+    if results.len() != operations.len() {
+        return Err(crate::DriverCosmosError::builder()
+            .with_status(CosmosStatus::SERIALIZATION_RESPONSE_BODY_INVALID)
+            .with_message(format!(
+                "batch response has {} results for {} operations",
+                results.len(),
+                operations.len()
+            ))
+            .build()
+            .into());
+    }
+    Ok(results)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+
+    fn range(min: &str, max: &str) -> FeedRange {
+        FeedRange::new(min.into(), max.into()).unwrap()
+    }
+
+    fn targeted(
+        operations: impl IntoIterator<Item = BulkOperation>,
+        range_of: impl Fn(usize) -> FeedRange,
+    ) -> Vec<(FeedRange, PendingOperation)> {
+        operations
+            .into_iter()
+            .enumerate()
+            .map(|(index, operation)| {
+                (
+                    range_of(index),
+                    PendingOperation::new(index, operation).unwrap(),
+                )
+            })
+            .collect()
+    }
+
+    fn group_batches(operations: Vec<(FeedRange, PendingOperation)>) -> Vec<PlannedBatch> {
+        let mut groups = BatchGroups::default();
+        let mut batches: Vec<_> = operations
+            .into_iter()
+            .filter_map(|(range, operation)| groups.push(range, operation))
+            .collect();
+        batches.extend(std::iter::from_fn(|| groups.pop()));
+        batches
+    }
+
+    fn indices(batch: &PlannedBatch) -> Vec<usize> {
+        batch.operations.iter().map(|op| op.index).collect()
+    }
+
+    #[test]
+    fn group_batches_groups_by_partition_key_range() {
+        // Partition keys pk0..pk3 alternate between two ranges, so keys pk0 and pk2 share a batch.
+        let operations =
+            (0..8).map(|i| BulkOperation::read_item(format!("pk{}", i % 4), i.to_string(), None));
+        let operations = targeted(operations, |i| {
+            if i % 2 == 0 {
+                range("", "80")
+            } else {
+                range("80", "FF")
+            }
+        });
+
+        let batches = group_batches(operations);
+
+        assert_eq!(2, batches.len());
+        assert_eq!(range("", "80"), batches[0].range);
+        assert_eq!(vec![0, 2, 4, 6], indices(&batches[0]));
+        assert_eq!(range("80", "FF"), batches[1].range);
+        assert_eq!(vec![1, 3, 5, 7], indices(&batches[1]));
+    }
+
+    #[test]
+    fn group_batches_limits_operation_count() {
+        let operations =
+            (0..250).map(|i| BulkOperation::delete_item(format!("pk{i}"), i.to_string(), None));
+        let operations = targeted(operations, |_| FeedRange::full());
+
+        let batches = group_batches(operations);
+
+        let sizes: Vec<_> = batches.iter().map(|b| b.operations.len()).collect();
+        assert_eq!(vec![100, 100, 50], sizes);
+        assert_eq!((200..250).collect::<Vec<_>>(), indices(&batches[2]));
+    }
+
+    #[test]
+    fn group_batches_limits_body_size() {
+        let payload = "x".repeat(700 * 1024);
+        let operations = (0..5).map(|i| {
+            BulkOperation::upsert_item(
+                "pk",
+                serde_json::json!({ "id": i.to_string(), "payload": payload }),
+                None,
+            )
+            .unwrap()
+        });
+        let operations = targeted(operations, |_| FeedRange::full());
+
+        let batches = group_batches(operations);
+
+        let sizes: Vec<_> = batches.iter().map(|b| b.operations.len()).collect();
+        assert_eq!(vec![2, 2, 1], sizes);
+    }
+
+    #[test]
+    fn group_batches_empty() {
+        assert!(group_batches(Vec::new()).is_empty());
+    }
+
+    #[test]
+    fn body_item_includes_partition_key() {
+        let operation =
+            PendingOperation::new(0, BulkOperation::read_item("pk1", "item1", None)).unwrap();
+
+        let body = serde_json::to_value(operation.body_item()).unwrap();
+
+        assert_eq!(
+            serde_json::json!({
+                "partitionKey": "[\"pk1\"]",
+                "operationType": "Read",
+                "id": "item1",
+            }),
+            body
+        );
+    }
+
+    /// A request a [`MemoryContainer`] received.
+    #[derive(Debug, PartialEq)]
+    enum Request {
+        /// A partition key range lookup, and whether it forced a refresh.
+        Lookup(bool),
+        /// A batch request to a range, with the ids of its operations.
+        Batch(FeedRange, Vec<String>),
+    }
+
+    /// An in-memory container that, like the service, rejects batch requests to a range that
+    /// split after the client resolved it.
+    #[derive(Default)]
+    struct MemoryContainer {
+        /// The partition key range of each partition key, as the service has it.
+        ranges: Mutex<HashMap<PartitionKey, FeedRange>>,
+        /// The partition key ranges the client resolves, refreshed from `ranges` on a forced
+        /// refresh.
+        cached_ranges: Mutex<HashMap<PartitionKey, FeedRange>>,
+        /// The statuses of the operations of the next batch requests, in order. Operations
+        /// without a status succeed.
+        statuses: Mutex<VecDeque<Vec<u16>>>,
+        requests: Mutex<Vec<Request>>,
+    }
+
+    impl MemoryContainer {
+        fn with_ranges<P: Into<PartitionKey>>(
+            ranges: impl IntoIterator<Item = (P, FeedRange)>,
+        ) -> Self {
+            let ranges: HashMap<_, _> = ranges
+                .into_iter()
+                .map(|(partition_key, range)| (partition_key.into(), range))
+                .collect();
+            Self {
+                cached_ranges: Mutex::new(ranges.clone()),
+                ranges: Mutex::new(ranges),
+                ..Default::default()
+            }
+        }
+
+        /// Moves `partition_key` to `range` without refreshing the client's ranges, as a split
+        /// would.
+        fn split(&self, partition_key: impl Into<PartitionKey>, range: FeedRange) {
+            self.ranges
+                .lock()
+                .unwrap()
+                .insert(partition_key.into(), range);
+        }
+
+        fn batches(&self) -> Vec<(FeedRange, Vec<String>)> {
+            self.requests
+                .lock()
+                .unwrap()
+                .iter()
+                .filter_map(|request| match request {
+                    Request::Batch(range, ids) => Some((range.clone(), ids.clone())),
+                    Request::Lookup(_) => None,
+                })
+                .collect()
+        }
+    }
+
+    #[async_trait]
+    impl BatchContainer for MemoryContainer {
+        async fn partition_key_range(
+            &self,
+            partition_key: &PartitionKey,
+            force_refresh: bool,
+        ) -> crate::Result<FeedRange> {
+            self.requests
+                .lock()
+                .unwrap()
+                .push(Request::Lookup(force_refresh));
+            let mut cached_ranges = self.cached_ranges.lock().unwrap();
+            if force_refresh {
+                *cached_ranges = self.ranges.lock().unwrap().clone();
+            }
+            Ok(cached_ranges[partition_key].clone())
+        }
+
+        async fn send_batch(
+            &self,
+            range: &FeedRange,
+            body: Vec<u8>,
+            _options: &BulkOptions,
+        ) -> crate::Result<Vec<TransactionalBatchOperationResult>> {
+            let body: Vec<serde_json::Value> = serde_json::from_slice(&body)?;
+            let ids = body
+                .iter()
+                .map(|item| item["id"].as_str().unwrap().to_string())
+                .collect();
+            self.requests
+                .lock()
+                .unwrap()
+                .push(Request::Batch(range.clone(), ids));
+            if !self.ranges.lock().unwrap().values().any(|r| r == range) {
+                return Err(crate::DriverCosmosError::builder()
+                    .with_status(CosmosStatus::PARTITION_KEY_RANGE_GONE)
+                    .with_message("partition key range is gone")
+                    .build()
+                    .into());
+            }
+
+            let statuses = self
+                .statuses
+                .lock()
+                .unwrap()
+                .pop_front()
+                .unwrap_or_default();
+            let results = (0..body.len()).map(|i| match statuses.get(i) {
+                Some(&TOO_MANY_REQUESTS) => serde_json::json!({
+                    "statusCode": TOO_MANY_REQUESTS,
+                    "retryAfterMilliseconds": 10,
+                }),
+                status => serde_json::json!({ "statusCode": status.copied().unwrap_or(200) }),
+            });
+            Ok(serde_json::from_value(serde_json::Value::Array(
+                results.collect(),
+            ))?)
+        }
+    }
+
+    async fn execute(
+        container: &Arc<MemoryContainer>,
+        operations: impl IntoIterator<Item = BulkOperation>,
+        options: BulkOptions,
+    ) -> Vec<(usize, u16)> {
+        let mut results: Vec<_> = BulkExecutor::with_container(
+            container.clone(),
+            operations.into_iter().collect(),
+            options,
+        )
+        .map(|result| (result.index(), result.result().unwrap().status_code()))
+        .collect()
+        .await;
+        results.sort();
+        results
+    }
+
+    fn ids(ids: &[&str]) -> Vec<String> {
+        ids.iter().map(ToString::to_string).collect()
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn execute_resubmits_throttled_operations() {
+        let container = Arc::new(MemoryContainer::with_ranges([("pk", FeedRange::full())]));
+        *container.statuses.lock().unwrap() = VecDeque::from([vec![200, 429, 429], vec![200, 429]]);
+        let operations = (0..3).map(|i| BulkOperation::read_item("pk", i.to_string(), None));
+
+        let results = execute(&container, operations, BulkOptions::default()).await;
+
+        assert_eq!(vec![(0, 200), (1, 200), (2, 200)], results);
+        assert_eq!(
+            vec![
+                (FeedRange::full(), ids(&["0", "1", "2"])),
+                (FeedRange::full(), ids(&["1", "2"])),
+                (FeedRange::full(), ids(&["2"])),
+            ],
+            container.batches()
+        );
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn execute_returns_throttled_result_after_max_throttle_retries() {
+        let container = Arc::new(MemoryContainer::with_ranges([("pk", FeedRange::full())]));
+        *container.statuses.lock().unwrap() = VecDeque::from([vec![429], vec![429]]);
+        let operations = [BulkOperation::read_item("pk", "0", None)];
+        let options = BulkOptions::default().with_max_throttle_retries(1);
+
+        let results = execute(&container, operations, options).await;
+
+        assert_eq!(vec![(0, 429)], results);
+        assert_eq!(2, container.batches().len());
+    }
+
+    #[tokio::test]
+    async fn execute_regroups_operations_after_a_split() {
+        let container = Arc::new(MemoryContainer::with_ranges([
+            ("pk0", FeedRange::full()),
+            ("pk1", FeedRange::full()),
+        ]));
+        container.split("pk0", range("", "80"));
+        container.split("pk1", range("80", "FF"));
+        let operations =
+            (0..4).map(|i| BulkOperation::read_item(format!("pk{}", i % 2), i.to_string(), None));
+
+        let results = execute(&container, operations, BulkOptions::default()).await;
+
+        assert_eq!(vec![(0, 200), (1, 200), (2, 200), (3, 200)], results);
+        let mut batches = container.batches();
+        assert_eq!(
+            (FeedRange::full(), ids(&["0", "1", "2", "3"])),
+            batches.remove(0)
+        );
+        batches.sort_by_key(|(_, ids)| ids.clone());
+        assert_eq!(
+            vec![
+                (range("", "80"), ids(&["0", "2"])),
+                (range("80", "FF"), ids(&["1", "3"])),
+            ],
+            batches
+        );
+        let lookups: Vec<_> = container
+            .requests
+            .lock()
+            .unwrap()
+            .iter()
+            .filter_map(|request| match request {
+                Request::Lookup(force_refresh) => Some(*force_refresh),
+                Request::Batch(..) => None,
+            })
+            .collect();
+        assert_eq!(vec![false, false, true, false], lookups);
+    }
+
+    #[tokio::test]
+    async fn execute_sends_full_batches_before_resolving_later_ranges() {
+        let partition_keys: Vec<_> = (0..150).map(|i| format!("pk{i}")).collect();
+        let container = Arc::new(MemoryContainer::with_ranges(
+            partition_keys
+                .iter()
+                .map(|pk| (pk.clone(), FeedRange::full())),
+        ));
+        let operations = partition_keys
+            .iter()
+            .enumerate()
+            .map(|(i, pk)| BulkOperation::read_item(pk.clone(), i.to_string(), None));
+        let options = BulkOptions::default().with_max_concurrency(1.try_into().unwrap());
+
+        let results = execute(&container, operations, options).await;
+
+        assert_eq!(150, results.len());
+        let requests = container.requests.lock().unwrap();
+        let batches: Vec<_> = requests
+            .iter()
+            .enumerate()
+            .filter_map(|(i, request)| match request {
+                Request::Batch(_, ids) => Some((i, ids.len())),
+                Request::Lookup(_) => None,
+            })
+            .collect();
+        // The first batch is sent once the 101st operation overflows it, before the remaining
+        // 49 ranges are resolved.
+        assert_eq!(vec![(101, 100), (151, 50)], batches);
+    }
+}
@@ -0,0 +1,1001 @@
+// Copyright (c) Microsoft Corporation. All rights reserved.
+// Licensed under the MIT License.
+
+//! Provides the [`ChangeFeedProcessor`] type for distributing change feed processing across hosts.
+//!
+//! Progress is tracked in a separate lease container, one lease item per feed range of the
+//! monitored container. Each lease records the instance that owns it and the continuation token
+//! of the last batch its handler completed. Instances that share a processor name balance the
+//! leases between them: an instance acquires unowned and expired leases until it owns its fair
+//! share, and takes one lease at a time from the busiest instance when none are free. Every
+//! lease update is conditioned on the lease's ETag, so a lease taken by another instance is
+//! noticed on its previous owner's next checkpoint or renewal.
+//!
+//! A lease is renewed on a timer while its changes are read and handled, and expires once its
+//! ETag has stayed the same for the expiration interval. Each instance measures that interval on
+//! its own monotonic clock from when it first saw the current ETag, so expiry does not depend on
+//! clock skew between hosts.
+
+use crate::{
+    clients::ContainerClient,
+    feed::{FeedRange, FeedScope},
+    models::ChangeFeedItem,
+    options::{ChangeFeedOptions, ChangeFeedProcessorOptions, ItemWriteOptions, Precondition},
+    Query,
+};
+use async_trait::async_trait;
+use azure_core::{http::Etag, time::Duration};
+use azure_data_cosmos_driver::models::ContinuationToken;
+use futures::{future::BoxFuture, stream::FuturesUnordered, FutureExt, StreamExt, TryStreamExt};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use std::{
+    collections::{HashMap, HashSet},
+    future::Future,
+    sync::Arc,
+    time::Instant,
+};
+
+type Handler<T> = dyn Fn(
+        ChangeFeedProcessorContext,
+        Vec<ChangeFeedItem<T>>,
+    ) -> BoxFuture<'static, azure_core::Result<()>>
+    + Send
+    + Sync;
+
+type LeaseWorker = dyn Fn(Arc<LeaseStore>, Lease) -> BoxFuture<'static, (String, azure_core::Result<()>)>
+    + Send
+    + Sync;
+
+/// Processes the change feed of a container, distributing its feed ranges across every
+/// instance that runs a processor with the same name.
+///
+/// Returned by [`ContainerClient::change_feed_processor()`](crate::clients::ContainerClient::change_feed_processor()).
+/// Changes are delivered to the handler at least once: a batch is checkpointed only after the
+/// handler succeeds, and a batch whose handler fails is delivered again once the lease is
+/// reacquired.
+///
+/// # Lease container
+///
+/// The lease container must use `/id` as its partition key path. It may be shared by several
+/// processors with different names.
+///
+/// # Usage
+///
+/// ```rust,no_run
+/// # async fn example(
+/// #     container_client: azure_data_cosmos::clients::ContainerClient,
+/// #     lease_container_client: azure_data_cosmos::clients::ContainerClient,
+/// # ) -> azure_data_cosmos::Result<()> {
+/// use azure_data_cosmos::models::ChangeFeedItem;
+///
+/// let processor = container_client.change_feed_processor(
+///     "inventory",
+///     "host-1",
+///     lease_container_client,
+///     |context, changes: Vec<ChangeFeedItem<serde_json::Value>>| async move {
+///         println!("{} changes in {:?}", changes.len(), context.feed_range());
+///         Ok(())
+///     },
+///     None,
+/// );
+/// let shutdown = async { /* wait for a shutdown signal */ };
+/// processor.run_until(shutdown).await?;
+/// # Ok(())
+/// # }
+/// ```
+pub struct ChangeFeedProcessor {
+    store: Arc<LeaseStore>,
+    monitored: ContainerClient,
+    worker: Arc<LeaseWorker>,
+}
+
+impl ChangeFeedProcessor {
+    pub(crate) fn new<T, F, Fut>(
+        monitored: ContainerClient,
+        lease_container: ContainerClient,
+        processor_name: String,
+        instance_name: String,
+        handler: F,
+        options: ChangeFeedProcessorOptions,
+    ) -> Self
+    where
+        T: DeserializeOwned + Send + 'static,
+        F: Fn(ChangeFeedProcessorContext, Vec<ChangeFeedItem<T>>) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = azure_core::Result<()>> + Send + 'static,
+    {
+        let handler: Arc<Handler<T>> =
+            Arc::new(move |context, changes| handler(context, changes).boxed());
+        let reader = monitored.clone();
+        let worker: Arc<LeaseWorker> = Arc::new(move |store, lease| {
+            let id = lease.id.clone();
+            process_lease(reader.clone(), store, handler.clone(), lease)
+                .map(|result| (id, result))
+                .boxed()
+        });
+        Self {
+            store: Arc::new(LeaseStore {
+                container: Box::new(lease_container),
+                processor_name,
+                instance_name,
+                options,
+            }),
+            monitored,
+            worker,
+        }
+    }
+
+    /// Runs the processor until `shutdown` completes.
+    ///
+    /// On shutdown the processor stops its handlers and releases its leases so other instances
+    /// can acquire them immediately.
+    ///
+    /// Transient failures to read the feed ranges of the monitored container or to read or
+    /// update the leases in the lease container are logged and retried after the lease acquire
+    /// interval.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if reading the feed ranges or the leases fails with an error that is not
+    /// transient. Leases owned by the processor when it fails are acquired by other instances
+    /// once they expire.
+    pub async fn run_until(&self, shutdown: impl Future<Output = ()>) -> crate::Result<()> {
+        let balancer = Balancer::new(self.store.clone(), self.worker.clone());
+        Box::pin(balancer.run_until(|| self.monitored.read_feed_ranges(None), shutdown)).await
+    }
+
+    /// Runs the processor until it fails.
+    ///
+    /// Dropping the returned future stops the processor without releasing its leases; they are
+    /// acquired by other instances once they expire. Use [`run_until`](Self::run_until) to
+    /// release them on shutdown.
+    pub async fn run(&self) -> crate::Result<()> {
+        Box::pin(self.run_until(futures::future::pending())).await
+    }
+}
+
+/// The leases one run of a processor is processing, and the workers processing them.
+struct Balancer {
+    store: Arc<LeaseStore>,
+    worker: Arc<LeaseWorker>,
+    workers: FuturesUnordered<BoxFuture<'static, (String, azure_core::Result<()>)>>,
+    /// The ids of the leases that have a running worker.
+    running: HashSet<String>,
+    ages: LeaseAges,
+}
+
+impl Balancer {
+    fn new(store: Arc<LeaseStore>, worker: Arc<LeaseWorker>) -> Self {
+        Self {
+            store,
+            worker,
+            workers: FuturesUnordered::new(),
+            running: HashSet::new(),
+            ages: LeaseAges::default(),
+        }
+    }
+
+    /// Creates leases for the feed ranges in `ranges` that have none, acquires this instance's
+    /// share of the leases, and starts processing every owned lease that is not already being
+    /// processed.
+    async fn balance(&mut self, ranges: Vec<FeedRange>) -> crate::Result<()> {
+        let mut leases = self.store.list().await?;
+        for range in uncovered_ranges(&leases, ranges) {
+            if let Some(lease) = self.store.create(range).await? {
+                leases.push(lease);
+            }
+        }
+
+        let now = Instant::now();
+        self.ages.observe(&leases, now);
+        let instance = self.store.instance_name.as_str();
+        let expiration = self.store.options.lease_expiration_interval;
+        let mut candidates = leases_to_acquire(&leases, instance, |lease| {
+            self.ages.is_expired(lease, now, expiration)
+        });
+        // Leases this instance owns are never candidates above. A running worker renews its own
+        // lease, so only leases whose worker has stopped are acquired again; acquiring a running
+        // one would start a second worker for it.
+        candidates.extend(leases.iter().filter(|lease| {
+            lease.owner.as_deref() == Some(instance) && !self.running.contains(&lease.id)
+        }));
+
+        for lease in candidates {
+            if let Some(lease) = self.store.acquire(lease.clone()).await? {
+                self.running.insert(lease.id.clone());
+                self.workers.push((self.worker)(self.store.clone(), lease));
+            }
+        }
+        Ok(())
+    }
+
+    /// Balances the leases of the feed ranges `read_ranges` returns every lease acquire interval,
+    /// and drives the workers, until `shutdown` completes or balancing fails with an error that
+    /// is not transient.
+    async fn run_until<R, Fut>(
+        mut self,
+        read_ranges: R,
+        shutdown: impl Future<Output = ()>,
+    ) -> crate::Result<()>
+    where
+        R: Fn() -> Fut,
+        Fut: Future<Output = crate::Result<Vec<FeedRange>>>,
+    {
+        let mut shutdown = std::pin::pin!(shutdown.fuse());
+        loop {
+            // Boxed to keep the feed range and lease store requests out of this future's own size.
+            let balanced = Box::pin(async {
+                let ranges = read_ranges().await?;
+                self.balance(ranges).await
+            })
+            .await;
+            if let Err(error) = balanced {
+                if !error.status().is_transient() {
+                    self.stop().await;
+                    return Err(error);
+                }
+                tracing::warn!(%error, "failed to balance change feed leases; retrying after the lease acquire interval");
+            }
+
+            let mut tick = std::pin::pin!(azure_core::sleep::sleep(
+                self.store.options.lease_acquire_interval
+            )
+            .fuse());
+            loop {
+                futures::select! {
+                    _ = shutdown => {
+                        self.stop().await;
+                        return Ok(());
+                    }
+                    (id, result) = self.workers.select_next_some() => {
+                        self.running.remove(&id);
+                        if let Err(error) = result {
+                            tracing::warn!(lease = %id, %error, "change feed processing stopped; the lease will be retried");
+                        }
+                    }
+                    _ = tick => break,
+                }
+            }
+        }
+    }
+
+    /// Stops every worker and releases their leases.
+    async fn stop(self) {
+        drop(self.workers);
+        self.store.release_all(&self.running).await;
+    }
+}
+
+/// Context passed to a [`ChangeFeedProcessor`] handler with each batch of changes.
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub struct ChangeFeedProcessorContext {
+    lease_token: String,
+    feed_range: FeedRange,
+}
+
+impl ChangeFeedProcessorContext {
+    /// Returns the id of the lease the changes were read under.
+    pub fn lease_token(&self) -> &str {
+        &self.lease_token
+    }
+
+    /// Returns the feed range the changes were read from.
+    pub fn feed_range(&self) -> &FeedRange {
+        &self.feed_range
+    }
+}
+
+/// A lease item stored in the lease container.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Lease {
+    id: String,
+    processor_name: String,
+    feed_range: FeedRange,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    owner: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    continuation_token: Option<ContinuationToken>,
+    /// The server-assigned ETag, which changes on every acquisition, renewal, and checkpoint.
+    #[serde(rename = "_etag", default, skip_serializing)]
+    etag: Option<Etag>,
+}
+
+/// How long each lease has gone without a write, measured on this host's monotonic clock.
+///
+/// Lease writes are recognized by their new ETag rather than by a timestamp, so no two clocks
+/// are ever compared.
+#[derive(Default)]
+struct LeaseAges(HashMap<String, (Option<Etag>, Instant)>);
+
+impl LeaseAges {
+    /// Records the ETags of `leases` as seen at `now`, forgetting leases that no longer exist.
+    fn observe(&mut self, leases: &[Lease], now: Instant) {
+        self.0
+            .retain(|id, _| leases.iter().any(|lease| &lease.id == id));
+        for lease in leases {
+            match self.0.get_mut(&lease.id) {
+                Some((etag, _)) if *etag == lease.etag => {}
+                _ => {
+                    self.0.insert(lease.id.clone(), (lease.etag.clone(), now));
+                }
+            }
+        }
+    }
+
+    /// Returns whether `lease` has kept its ETag for at least `expiration` as of `now`.
+    fn is_expired(&self, lease: &Lease, now: Instant, expiration: Duration) -> bool {
+        self.0.get(&lease.id).is_some_and(|(etag, since)| {
+            *etag == lease.etag && expiration <= now.saturating_duration_since(*since)
+        })
+    }
+}
+
+/// The lease container requests made by a [`LeaseStore`].
+#[async_trait]
+trait LeaseContainer: Send + Sync {
+    /// Returns the leases of the processor named `processor_name`.
+    async fn query_leases(&self, processor_name: &str) -> crate::Result<Vec<Lease>>;
+
+    /// Creates `lease`, returning it with its ETag, or `None` if a lease with its id already
+    /// exists.
+    async fn create_lease(&self, lease: Lease) -> crate::Result<Option<Lease>>;
+
+    /// Replaces `lease` if its ETag still matches, returning it with its new ETag, or `None` if
+    /// the lease was updated or deleted.
+    async fn replace_lease(&self, lease: Lease) -> crate::Result<Option<Lease>>;
+}
+
+#[async_trait]
+impl LeaseContainer for ContainerClient {
+    async fn query_leases(&self, processor_name: &str) -> crate::Result<Vec<Lease>> {
+        let query = Query::from("SELECT * FROM c WHERE c.processorName = @processorName")
+            .with_parameter("@processorName", processor_name)?;
+        self.query_items::<Lease>(query, FeedScope::full_container(), None)
+            .await?
+            .try_collect()
+            .await
+    }
+
+    async fn create_lease(&self, lease: Lease) -> crate::Result<Option<Lease>> {
+        match self
+            .create_item(lease.id.clone(), &lease.id, &lease, None)
+            .await
+        {
+            Ok(response) => Ok(Some(Lease {
+                etag: response.headers().etag().cloned(),
+                ..lease
+            })),
+            Err(error) if error.status().is_conflict() => Ok(None),
+            Err(error) => Err(error),
+        }
+    }
+
+    async fn replace_lease(&self, mut lease: Lease) -> crate::Result<Option<Lease>> {
+        let mut options = ItemWriteOptions::default();
+        if let Some(etag) = lease.etag.take() {
+            options = options.with_precondition(Precondition::IfMatch(etag));
+        }
+        match self
+            .replace_item(lease.id.clone(), &lease.id, &lease, Some(options))
+            .await
+        {
+            Ok(response) => Ok(Some(Lease {
+                etag: response.headers().etag().cloned(),
+                ..lease
+            })),
+            Err(error)
+                if error.status().is_precondition_failed() || error.status().is_not_found() =>
+            {
+                Ok(None)
+            }
+            Err(error) => Err(error),
+        }
+    }
+}
+
+/// Reads and updates the leases of one processor instance.
+struct LeaseStore {
+    container: Box<dyn LeaseContainer>,
+    processor_name: String,
+    instance_name: String,
+    options: ChangeFeedProcessorOptions,
+}
+
+impl LeaseStore {
+    async fn list(&self) -> crate::Result<Vec<Lease>> {
+        self.container.query_leases(&self.processor_name).await
+    }
+
+    /// Creates an unowned lease for `feed_range`, or returns `None` if another instance created it first.
+    async fn create(&self, feed_range: FeedRange) -> crate::Result<Option<Lease>> {
+        let lease = Lease {
+            id: format!(
+                "{}.{}-{}",
+                self.processor_name,
+                feed_range.min_inclusive(),
+                feed_range.max_exclusive()
+            ),
+            processor_name: self.processor_name.clone(),
+            feed_range,
+            owner: None,
+            continuation_token: None,
+            etag: None,
+        };
+        self.container.create_lease(lease).await
+    }
+
+    /// Takes ownership of `lease`, or returns `None` if it was updated by another instance.
+    async fn acquire(&self, mut lease: Lease) -> crate::Result<Option<Lease>> {
+        lease.owner = Some(self.instance_name.clone());
+        self.update(lease).await
+    }
+
+    /// Writes `lease` if it has not changed since it was read, returning the updated lease, or
+    /// `None` if another instance updated or deleted it.
+    async fn update(&self, lease: Lease) -> crate::Result<Option<Lease>> {
+        self.container.replace_lease(lease).await
+    }
+
+    /// Gives up this instance's ownership of the leases in `ids`, ignoring failures.
+    async fn release_all(&self, ids: &HashSet<String>) {
+        if ids.is_empty() {
+            return;
+        }
+        let leases = match self.list().await {
+            Ok(leases) => leases,
+            Err(error) => {
+                tracing::warn!(%error, "failed to release change feed leases");
+                return;
+            }
+        };
+        for mut lease in leases {
+            if !ids.contains(&lease.id)
+                || lease.owner.as_deref() != Some(self.instance_name.as_str())
+            {
+                continue;
+            }
+            lease.owner = None;
+            if let Err(error) = self.update(lease).await {
+                tracing::warn!(%error, "failed to release change feed lease");
+            }
+        }
+    }
+}
+
+/// A lease held by a worker, and when this host last wrote it.
+struct HeldLease {
+    lease: Lease,
+    written: Instant,
+}
+
+impl HeldLease {
+    /// Wraps a lease that was just acquired.
+    fn acquired(lease: Lease) -> Self {
+        Self {
+            lease,
+            written: Instant::now(),
+        }
+    }
+
+    /// Writes the lease, renewing it, and returns `false` if another instance has taken it.
+    async fn write(&mut self, store: &LeaseStore) -> crate::Result<bool> {
+        let Some(lease) = store.update(self.lease.clone()).await? else {
+            return Ok(false);
+        };
+        self.lease = lease;
+        self.written = Instant::now();
+        Ok(true)
+    }
+
+    /// Runs `work` to completion, renewing the lease whenever the renew interval passes without
+    /// a write, and returns its output, or `None` if the lease is lost first.
+    ///
+    /// `work` is not polled while a renewal is in flight, and is dropped if the lease is lost.
+    async fn renew_during<F: Future>(
+        &mut self,
+        store: &LeaseStore,
+        work: F,
+    ) -> crate::Result<Option<F::Output>> {
+        let mut work = std::pin::pin!(work.fuse());
+        loop {
+            let due = store.options.lease_renew_interval - self.written.elapsed();
+            let mut renewal =
+                std::pin::pin!(azure_core::sleep::sleep(due.max(Duration::ZERO)).fuse());
+            futures::select! {
+                output = work => return Ok(Some(output)),
+                _ = renewal => {
+                    if !self.write(store).await? {
+                        return Ok(None);
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Reads the change feed of `lease`'s feed range, passing each batch of changes to `handler` and
+/// checkpointing after it succeeds, until the lease is lost or an error occurs.
+async fn process_lease<T: DeserializeOwned + Send + 'static>(
+    monitored: ContainerClient,
+    store: Arc<LeaseStore>,
+    handler: Arc<Handler<T>>,
+    lease: Lease,
+) -> azure_core::Result<()> {
+    let options = &store.options;
+    let mut feed_options = ChangeFeedOptions::default();
+    if let Some(token) = lease.continuation_token.clone() {
+        feed_options = feed_options.with_continuation_token(token);
+    }
+    if let Some(max_item_count) = options.max_item_count {
+        feed_options = feed_options.with_max_item_count(max_item_count);
+    }
+    let mut pages = monitored
+        .query_change_feed::<T>(
+            FeedScope::range(lease.feed_range.clone()),
+            options.start_from.clone(),
+            Some(feed_options),
+        )
+        .await?;
+    let context = ChangeFeedProcessorContext {
+        lease_token: lease.id.clone(),
+        feed_range: lease.feed_range.clone(),
+    };
+    let mut held = HeldLease::acquired(lease);
+
+    while let Some(page) = pages.next().await {
+        let changes = page?.into_items();
+        if changes.is_empty() {
+            let idle = azure_core::sleep::sleep(options.poll_interval);
+            if held.renew_during(&store, idle).await?.is_none() {
+                return Ok(());
+            }
+            continue;
+        }
+
+        let handled = held
+            .renew_during(&store, handler(context.clone(), changes))
+            .await?;
+        let Some(handled) = handled else {
+            return Ok(());
+        };
+        handled?;
+        held.lease.continuation_token = Some(pages.to_continuation_token()?);
+        if !held.write(&store).await? {
+            return Ok(());
+        }
+    }
+    Ok(())
+}
+
+/// Returns the feed ranges not covered by an existing lease.
+///
+/// A lease keeps covering its original feed range after the range splits, because the change
+/// feed of a range follows the split.
+fn uncovered_ranges(leases: &[Lease], ranges: Vec<FeedRange>) -> Vec<FeedRange> {
+    ranges
+        .into_iter()
+        .filter(|range| !leases.iter().any(|lease| lease.feed_range.overlaps(range)))
+        .collect()
+}
+
+/// Returns the leases owned by other instances (or no instance) that `instance` should acquire
+/// to own its fair share of `leases`.
+///
+/// Unowned and expired leases are taken first. When none are left and `instance` owns fewer than
+/// its share, one lease is taken from the instance that owns the most, provided that instance
+/// owns more than its share. Leases owned by `instance` itself always count as its own, even when
+/// `is_expired` holds for them.
+fn leases_to_acquire<'a>(
+    leases: &'a [Lease],
+    instance: &str,
+    is_expired: impl Fn(&Lease) -> bool,
+) -> Vec<&'a Lease> {
+    let mut owned: HashMap<&str, Vec<&Lease>> = HashMap::new();
+    let mut available = Vec::new();
+    for lease in leases {
+        match lease.owner.as_deref() {
+            Some(owner) if owner == instance || !is_expired(lease) => {
+                owned.entry(owner).or_default().push(lease)
+            }
+            _ => available.push(lease),
+        }
+    }
+    owned.entry(instance).or_default();
+
+    let target = leases.len().div_ceil(owned.len());
+    let mine = owned[instance].len();
+    if mine >= target {
+        return Vec::new();
+    }
+    if !available.is_empty() {
+        available.truncate(target - mine);
+        return available;
+    }
+
+    owned
+        .into_iter()
+        .filter(|(owner, owned)| *owner != instance && owned.len() > target)
+        .max_by_key(|(owner, owned)| (owned.len(), *owner))
+        .and_then(|(_, owned)| owned.first().copied())
+        .into_iter()
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use azure_core::http::StatusCode;
+    use std::{
+        collections::BTreeMap,
+        sync::{
+            atomic::{AtomicUsize, Ordering},
+            Mutex,
+        },
+    };
+
+    fn lease(index: u32, owner: Option<&str>) -> Lease {
+        Lease {
+            id: format!("processor.{index}"),
+            processor_name: "processor".to_string(),
+            feed_range: FeedRange::full(),
+            owner: owner.map(str::to_string),
+            continuation_token: None,
+            etag: None,
+        }
+    }
+
+    fn ids(leases: Vec<&Lease>) -> Vec<&str> {
+        leases.into_iter().map(|lease| lease.id.as_str()).collect()
+    }
+
+    /// An in-memory lease container that, like the service, gives a lease a new ETag on every
+    /// write and rejects writes whose ETag is stale.
+    #[derive(Default)]
+    struct MemoryLeases {
+        leases: Mutex<BTreeMap<String, Lease>>,
+        writes: AtomicUsize,
+        /// Errors returned by the next lease queries, in order.
+        query_errors: Mutex<Vec<crate::CosmosError>>,
+        queries: AtomicUsize,
+    }
+
+    impl MemoryLeases {
+        fn write(&self, mut lease: Lease) -> Lease {
+            let version = self.writes.fetch_add(1, Ordering::SeqCst);
+            lease.etag = Some(Etag::from(version.to_string()));
+            self.leases
+                .lock()
+                .unwrap()
+                .insert(lease.id.clone(), lease.clone());
+            lease
+        }
+
+        fn writes(&self) -> usize {
+            self.writes.load(Ordering::SeqCst)
+        }
+
+        /// Gives lease `id` to `owner`, as another instance would.
+        fn transfer(&self, id: &str, owner: &str) {
+            let mut lease = self.leases.lock().unwrap()[id].clone();
+            lease.owner = Some(owner.to_string());
+            self.write(lease);
+        }
+    }
+
+    #[async_trait]
+    impl LeaseContainer for Arc<MemoryLeases> {
+        async fn query_leases(&self, processor_name: &str) -> crate::Result<Vec<Lease>> {
+            self.queries.fetch_add(1, Ordering::SeqCst);
+            if let Some(error) = self.query_errors.lock().unwrap().pop() {
+                return Err(error);
+            }
+            let leases = self.leases.lock().unwrap();
+            Ok(leases
+                .values()
+                .filter(|lease| lease.processor_name == processor_name)
+                .cloned()
+                .collect())
+        }
+
+        async fn create_lease(&self, lease: Lease) -> crate::Result<Option<Lease>> {
+            if self.leases.lock().unwrap().contains_key(&lease.id) {
+                return Ok(None);
+            }
+            Ok(Some(self.write(lease)))
+        }
+
+        async fn replace_lease(&self, lease: Lease) -> crate::Result<Option<Lease>> {
+            let current = self.leases.lock().unwrap().get(&lease.id).cloned();
+            match current {
+                Some(current) if lease.etag.is_none() || current.etag == lease.etag => {
+                    Ok(Some(self.write(lease)))
+                }
+                _ => Ok(None),
+            }
+        }
+    }
+
+    fn store(
+        leases: &Arc<MemoryLeases>,
+        instance: &str,
+        options: ChangeFeedProcessorOptions,
+    ) -> Arc<LeaseStore> {
+        Arc::new(LeaseStore {
+            container: Box::new(leases.clone()),
+            processor_name: "processor".to_string(),
+            instance_name: instance.to_string(),
+            options,
+        })
+    }
+
+    /// Returns a worker that counts the workers it starts and never finishes.
+    fn counting_worker(started: Arc<AtomicUsize>) -> Arc<LeaseWorker> {
+        Arc::new(move |_, lease| {
+            started.fetch_add(1, Ordering::SeqCst);
+            futures::future::pending()
+                .map(move |()| (lease.id, Ok(())))
+                .boxed()
+        })
+    }
+
+    #[test]
+    fn acquires_all_unowned_leases_when_alone() {
+        let leases: Vec<_> = (0..4).map(|i| lease(i, None)).collect();
+
+        let acquired = leases_to_acquire(&leases, "a", |_| false);
+
+        assert_eq!(
+            vec!["processor.0", "processor.1", "processor.2", "processor.3"],
+            ids(acquired)
+        );
+    }
+
+    #[test]
+    fn acquires_fair_share_of_expired_leases() {
+        let leases = vec![
+            lease(0, Some("b")),
+            lease(1, Some("b")),
+            lease(2, Some("c")),
+            lease(3, None),
+        ];
+
+        // "c" has expired, so "a" and "b" share the four leases.
+        let acquired = leases_to_acquire(&leases, "a", |lease| lease.id == "processor.2");
+
+        assert_eq!(vec!["processor.2", "processor.3"], ids(acquired));
+    }
+
+    #[test]
+    fn never_acquires_own_expired_leases() {
+        let leases = vec![lease(0, Some("a")), lease(1, Some("b"))];
+
+        assert!(leases_to_acquire(&leases, "a", |lease| lease.id == "processor.0").is_empty());
+    }
+
+    #[test]
+    fn steals_one_lease_from_busiest_instance() {
+        let leases = vec![
+            lease(0, Some("b")),
+            lease(1, Some("b")),
+            lease(2, Some("b")),
+            lease(3, Some("c")),
+            lease(4, Some("c")),
+        ];
+
+        let acquired = leases_to_acquire(&leases, "a", |_| false);
+
+        assert_eq!(vec!["processor.0"], ids(acquired));
+    }
+
+    #[test]
+    fn acquires_nothing_when_balanced() {
+        let leases = vec![
+            lease(0, Some("a")),
+            lease(1, Some("b")),
+            lease(2, Some("b")),
+        ];
+
+        assert!(leases_to_acquire(&leases, "a", |_| false).is_empty());
+        assert!(leases_to_acquire(&leases, "b", |_| false).is_empty());
+    }
+
+    #[test]
+    fn leases_expire_once_their_etag_stops_changing() {
+        let expiration = Duration::seconds(60);
+        let start = Instant::now();
+        let mut current = lease(0, Some("b"));
+        current.etag = Some(Etag::from("1"));
+        let mut ages = LeaseAges::default();
+
+        ages.observe(std::slice::from_ref(&current), start);
+        let later = start + std::time::Duration::from_secs(40);
+        assert!(!ages.is_expired(&current, later, expiration));
+
+        // A renewal restarts the interval.
+        current.etag = Some(Etag::from("2"));
+        ages.observe(std::slice::from_ref(&current), later);
+        let expired_at = later + std::time::Duration::from_secs(60);
+        assert!(!ages.is_expired(
+            &current,
+            expired_at - std::time::Duration::from_secs(1),
+            expiration
+        ));
+        assert!(ages.is_expired(&current, expired_at, expiration));
+
+        ages.observe(&[], expired_at);
+        assert!(!ages.is_expired(&current, expired_at, expiration));
+    }
+
+    #[tokio::test]
+    async fn balance_does_not_restart_a_running_lease_after_it_expires() {
+        let leases = Arc::new(MemoryLeases::default());
+        let started = Arc::new(AtomicUsize::new(0));
+        // The worker never writes its lease, so it expires as soon as it is seen again.
+        let options =
+            ChangeFeedProcessorOptions::default().with_lease_expiration_interval(Duration::ZERO);
+        let mut balancer = Balancer::new(
+            store(&leases, "a", options),
+            counting_worker(started.clone()),
+        );
+
+        balancer.balance(vec![FeedRange::full()]).await.unwrap();
+        balancer.balance(vec![FeedRange::full()]).await.unwrap();
+
+        assert_eq!(1, started.load(Ordering::SeqCst));
+        assert_eq!(1, balancer.workers.len());
+    }
+
+    #[tokio::test]
+    async fn balance_restarts_an_owned_lease_whose_worker_stopped() {
+        let leases = Arc::new(MemoryLeases::default());
+        let started = Arc::new(AtomicUsize::new(0));
+        let options = ChangeFeedProcessorOptions::default();
+        let mut balancer = Balancer::new(
+            store(&leases, "a", options),
+            counting_worker(started.clone()),
+        );
+
+        balancer.balance(vec![FeedRange::full()]).await.unwrap();
+        balancer.workers.clear();
+        balancer.running.clear();
+        balancer.balance(vec![FeedRange::full()]).await.unwrap();
+
+        assert_eq!(2, started.load(Ordering::SeqCst));
+    }
+
+    fn error(status: StatusCode) -> crate::CosmosError {
+        crate::DriverCosmosError::builder()
+            .with_status(crate::CosmosStatus::new(status))
+            .with_message("lease query failed")
+            .build()
+            .into()
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn run_retries_balance_after_a_transient_lease_query_failure() {
+        let leases = Arc::new(MemoryLeases::default());
+        leases
+            .query_errors
+            .lock()
+            .unwrap()
+            .push(error(StatusCode::ServiceUnavailable));
+        let started = Arc::new(AtomicUsize::new(0));
+        let balancer = Balancer::new(
+            store(&leases, "a", ChangeFeedProcessorOptions::default()),
+            counting_worker(started.clone()),
+        );
+
+        let shutdown = async {
+            while started.load(Ordering::SeqCst) == 0 {
+                azure_core::sleep::sleep(Duration::seconds(1)).await;
+            }
+        };
+        balancer
+            .run_until(|| async { Ok(vec![FeedRange::full()]) }, shutdown)
+            .await
+            .unwrap();
+
+        assert_eq!(1, started.load(Ordering::SeqCst));
+        // The failed query, the retried balance, and releasing the lease on shutdown.
+        assert_eq!(3, leases.queries.load(Ordering::SeqCst));
+        assert!(leases
+            .leases
+            .lock()
+            .unwrap()
+            .values()
+            .all(|lease| lease.owner.is_none()));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn run_stops_when_a_lease_query_fails_with_a_non_transient_error() {
+        let leases = Arc::new(MemoryLeases::default());
+        leases
+            .query_errors
+            .lock()
+            .unwrap()
+            .push(error(StatusCode::Forbidden));
+        let started = Arc::new(AtomicUsize::new(0));
+        let balancer = Balancer::new(
+            store(&leases, "a", ChangeFeedProcessorOptions::default()),
+            counting_worker(started.clone()),
+        );
+
+        let error = balancer
+            .run_until(
+                || async { Ok(vec![FeedRange::full()]) },
+                futures::future::pending(),
+            )
+            .await
+            .unwrap_err();
+
+        assert!(error.status().is_forbidden());
+        assert_eq!(0, started.load(Ordering::SeqCst));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn renews_the_lease_while_a_slow_handler_runs() {
+        let leases = Arc::new(MemoryLeases::default());
+        let store = store(&leases, "a", ChangeFeedProcessorOptions::default());
+        let created = store.create(FeedRange::full()).await.unwrap().unwrap();
+        let mut held = HeldLease::acquired(store.acquire(created).await.unwrap().unwrap());
+        let writes = leases.writes();
+
+        // Renewals are due every 17 seconds.
+        let handler = async {
+            azure_core::sleep::sleep(Duration::seconds(60)).await;
+            "done"
+        };
+        let output = held.renew_during(&store, handler).await.unwrap();
+
+        assert_eq!(Some("done"), output);
+        assert_eq!(writes + 3, leases.writes());
+        assert_eq!(Some("a"), held.lease.owner.as_deref());
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn renewal_stops_the_handler_when_the_lease_is_lost() {
+        let leases = Arc::new(MemoryLeases::default());
+        let store = store(&leases, "a", ChangeFeedProcessorOptions::default());
+        let created = store.create(FeedRange::full()).await.unwrap().unwrap();
+        let mut held = HeldLease::acquired(store.acquire(created).await.unwrap().unwrap());
+
+        leases.transfer(&held.lease.id, "b");
+        let output = held
+            .renew_during(&store, futures::future::pending::<()>())
+            .await
+            .unwrap();
+
+        assert_eq!(None, output);
+    }
+
+    #[test]
+    fn covered_ranges_do_not_get_new_leases() {
+        let low = FeedRange::new("".into(), "7F".into()).unwrap();
+        let high = FeedRange::new("7F".into(), "FF".into()).unwrap();
+        let mut existing = lease(0, None);
+        existing.feed_range = low.clone();
+
+        let uncovered = uncovered_ranges(&[existing], vec![low, high.clone()]);
+
+        assert_eq!(vec![high], uncovered);
+    }
+
+    #[test]
+    fn lease_round_trips_through_json() {
+        let mut original = lease(0, Some("a"));
+        original.continuation_token = Some(ContinuationToken::from_string("token".to_string()));
+
+        let json = serde_json::to_value(&original).unwrap();
+        assert_eq!("processor", json["processorName"]);
+        assert_eq!("token", json["continuationToken"]);
+        assert!(json.get("_etag").is_none());
+
+        let mut json = json;
+        json["_etag"] = "\"0000\"".into();
+        let parsed: Lease = serde_json::from_value(json).unwrap();
+        assert_eq!(Some("a"), parsed.owner.as_deref());
+        assert_eq!(Some(Etag::from("\"0000\"")), parsed.etag);
+        assert_eq!(original.feed_range, parsed.feed_range);
+    }
+}
@@ -3,22 +3,28 @@
 
 use crate::{
     clients::{offers_client, ClientContext},
-    feed::{ChangeFeedPageIterator, FeedRange, FeedScope, QueryItemIterator},
-    models::TransactionalBatch,
+    feed::{ChangeFeedPageIterator, FeedRange, FeedScope, QueryItemIterator, ReadManyItemIterator},
     models::{BatchResponse, ChangeFeedItem, ItemResponse, ResourceResponse},
-    models::{ContainerProperties, PatchInstructions, ThroughputProperties},
+    models::{BulkOperation, TransactionalBatch},
+    models::{ContainerProperties, PatchInstructions, ThroughputProperties, TriggerProperties},
     options::{
-        BatchOptions, ChangeFeedOptions, ChangeFeedStartFrom, DeleteContainerOptions,
+        BatchOptions, BulkOptions, ChangeFeedOptions, ChangeFeedProcessorOptions,
+        ChangeFeedStartFrom, DeleteContainerOptions, ExecuteStoredProcedureOptions,
         ItemReadOptions, ItemWriteOptions, PatchItemOptions, Precondition, QueryOptions,
-        ReadContainerOptions, ReadFeedRangesOptions, ReplaceContainerOptions, SessionToken,
-        ThroughputOptions,
+        ReadContainerOptions, ReadFeedRangesOptions, ReadManyOptions, ReplaceContainerOptions,
+        SessionToken, ThroughputOptions,
+    },
+    options::{
+        CreateTriggerOptions, DeleteTriggerOptions, QueryTriggersOptions, ReadTriggerOptions,
+        ReplaceTriggerOptions,
     },
     PartitionKey, Query,
 };
 
-use super::ThroughputPoller;
+use super::{BulkExecutor, ChangeFeedProcessor, ChangeFeedProcessorContext, ThroughputPoller};
 use azure_data_cosmos_driver::models::{
-    ContainerReference, CosmosOperation, ItemReference, PartitionKeyKind,
+    ContainerReference, CosmosOperation, ItemReference, OfferMigration, PartitionKeyKind,
+    StoredProcedureReference, TriggerReference,
 };
 use serde::{de::DeserializeOwned, Serialize};
 
@@ -96,7 +102,11 @@ impl ContainerClient {
         ))
     }
 
-    /// Updates the indexing policy of the container.
+    /// Replaces the properties of the container.
+    ///
+    /// Use this to change mutable settings such as the indexing policy, default time-to-live,
+    /// and conflict resolution policy. Properties left unset are reset to their defaults, so
+    /// start from the properties returned by [`read()`](Self::read()).
     ///
     /// **NOTE**: The [`ContainerProperties::id`] and [`ContainerProperties::partition_key`] must be the same as the existing container, they cannot be changed.
     ///
@@ -122,6 +132,22 @@ impl ContainerClient {
     /// # Ok(())
     /// # }
     /// ```
+    ///
+    /// To change a single setting while keeping the others, read the current properties first:
+    ///
+    /// ```rust,no_run
+    /// # async fn doc() -> Result<(), Box<dyn std::error::Error>> {
+    /// use azure_data_cosmos::models::TimeToLive;
+    /// # let container_client: azure_data_cosmos::clients::ContainerClient = panic!("this is a non-running example");
+    /// let properties = container_client
+    ///     .read(None)
+    ///     .await?
+    ///     .into_model()?
+    ///     .with_default_ttl(TimeToLive::Seconds(3600));
+    /// container_client.replace(properties, None).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
     pub async fn replace(
         &self,
         properties: ContainerProperties,
@@ -211,18 +237,101 @@ impl ContainerClient {
         .await
     }
 
+    /// Begins migrating the container throughput from manual to autoscale.
+    ///
+    /// The service chooses the autoscale maximum from the current manual throughput. Use
+    /// [`begin_replace_throughput()`](Self::begin_replace_throughput()) afterwards to pick a
+    /// different maximum. The returned [`ThroughputPoller`] tracks the migration like a replace.
+    ///
+    /// # Arguments
+    /// * `options` - Optional parameters for the request.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # async fn example(client: azure_data_cosmos::clients::ContainerClient) -> azure_data_cosmos::Result<()> {
+    /// let throughput = client
+    ///     .begin_migrate_throughput_to_autoscale(None)
+    ///     .await? // start the migration
+    ///     .await? // wait for completion (polls if async)
+    ///     .into_model()?;
+    /// println!("autoscale maximum: {:?}", throughput.autoscale_maximum());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn begin_migrate_throughput_to_autoscale(
+        &self,
+        options: Option<ThroughputOptions>,
+    ) -> crate::Result<ThroughputPoller> {
+        self.begin_migrate_throughput(OfferMigration::ToAutoscale, options)
+            .await
+    }
+
+    /// Begins migrating the container throughput from autoscale to manual.
+    ///
+    /// The service sets the manual throughput from the current autoscale maximum. The returned
+    /// [`ThroughputPoller`] tracks the migration like a replace.
+    ///
+    /// # Arguments
+    /// * `options` - Optional parameters for the request.
+    pub async fn begin_migrate_throughput_to_manual(
+        &self,
+        options: Option<ThroughputOptions>,
+    ) -> crate::Result<ThroughputPoller> {
+        self.begin_migrate_throughput(OfferMigration::ToManual, options)
+            .await
+    }
+
+    async fn begin_migrate_throughput(
+        &self,
+        migration: OfferMigration,
+        options: Option<ThroughputOptions>,
+    ) -> crate::Result<ThroughputPoller> {
+        let options = options.unwrap_or_default();
+
+        offers_client::begin_migrate(
+            self.context.driver.clone(),
+            self.container_ref.account().clone(),
+            self.container_ref.rid(),
+            migration,
+            options.operation,
+        )
+        .await
+    }
+
     /// Deletes this container.
     ///
     #[doc = include_str!("../../docs/control-plane-warning.md")]
     ///
     /// # Arguments
     /// * `options` - Optional parameters for the request.
+    ///
+    /// # Examples
+    ///
+    /// Delete the container only if it has not been replaced since it was read:
+    ///
+    /// ```rust,no_run
+    /// # async fn doc() -> Result<(), Box<dyn std::error::Error>> {
+    /// use azure_data_cosmos::options::{DeleteContainerOptions, Precondition};
+    /// # let container_client: azure_data_cosmos::clients::ContainerClient = panic!("this is a non-running example");
+    /// let properties = container_client.read(None).await?.into_model()?;
+    /// let mut options = DeleteContainerOptions::default();
+    /// if let Some(etag) = properties.system_properties.etag {
+    ///     options = options.with_precondition(Precondition::if_match(etag));
+    /// }
+    /// container_client.delete(Some(options)).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
     pub async fn delete(
         &self,
         options: Option<DeleteContainerOptions>,
     ) -> crate::Result<ResourceResponse<()>> {
         let options = options.unwrap_or_default();
-        let operation = CosmosOperation::delete_container(self.container_ref.clone());
+        let mut operation = CosmosOperation::delete_container(self.container_ref.clone());
+        if let Some(precondition) = options.precondition {
+            operation = operation.with_precondition(precondition);
+        }
 
         let driver_response = self
             .context
@@ -319,7 +428,9 @@ impl ContainerClient {
 
         // Create the driver operation and apply ItemWriteOptions fields.
         let operation = CosmosOperation::create_item(item_ref).with_body(body);
-        let operation = apply_item_options(operation, options.session_token, options.precondition);
+        let operation = apply_item_options(operation, options.session_token, options.precondition)
+            .with_pre_triggers(options.pre_triggers)
+            .with_post_triggers(options.post_triggers);
 
         // Execute through the driver.
         let driver_response = self
@@ -417,7 +528,9 @@ impl ContainerClient {
 
         // Create the driver operation and apply ItemWriteOptions fields.
         let operation = CosmosOperation::replace_item(item_ref).with_body(body);
-        let operation = apply_item_options(operation, options.session_token, options.precondition);
+        let operation = apply_item_options(operation, options.session_token, options.precondition)
+            .with_pre_triggers(options.pre_triggers)
+            .with_post_triggers(options.post_triggers);
 
         // Execute through the driver.
         let driver_response = self
@@ -625,7 +738,9 @@ impl ContainerClient {
 
         // Create the driver operation and apply ItemWriteOptions fields.
         let operation = CosmosOperation::upsert_item(item_ref).with_body(body);
-        let operation = apply_item_options(operation, options.session_token, options.precondition);
+        let operation = apply_item_options(operation, options.session_token, options.precondition)
+            .with_pre_triggers(options.pre_triggers)
+            .with_post_triggers(options.post_triggers);
 
         // Execute through the driver.
         let driver_response = self
@@ -640,6 +755,110 @@ impl ContainerClient {
         ))
     }
 
+    /// Creates a new item in the container, computing its partition key from the item body.
+    ///
+    /// This behaves like [`create_item`](Self::create_item), but the partition key is read from the
+    /// serialized item using the container's partition key paths (including hierarchical paths),
+    /// so it always matches the document. A property missing from the item is sent as an undefined
+    /// partition key component, and a JSON `null` as a null component.
+    ///
+    /// # Arguments
+    /// * `item_id` - The id of the new item.
+    /// * `item` - The item to create. It must serialize to a JSON object.
+    /// * `options` - Optional parameters for the request
+    ///
+    /// # Errors
+    ///
+    /// In addition to the errors returned by [`create_item`](Self::create_item), returns an error if the
+    /// item does not serialize to a JSON object or a partition key path resolves to an object or array.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use serde::{Deserialize, Serialize};
+    /// # async fn doc() -> Result<(), Box<dyn std::error::Error>> {
+    /// #[derive(Debug, Deserialize, Serialize)]
+    /// pub struct Product {
+    ///     #[serde(rename = "id")]
+    ///     product_id: String,
+    ///     category_id: String,
+    ///     product_name: String,
+    /// }
+    /// let p = Product {
+    ///     product_id: "product1".to_string(),
+    ///     category_id: "category1".to_string(),
+    ///     product_name: "Product #1".to_string(),
+    /// };
+    /// # let container_client: azure_data_cosmos::clients::ContainerClient = panic!("this is a non-running example");
+    /// // The container is partitioned on "/category_id", so the partition key is "category1".
+    /// container_client
+    ///     .create_item_infer_partition_key("product1", p, None)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn create_item_infer_partition_key<T: Serialize>(
+        &self,
+        item_id: &str,
+        item: T,
+        options: Option<ItemWriteOptions>,
+    ) -> crate::Result<ItemResponse> {
+        let (partition_key, item) = self.partition_key_from_item(item)?;
+        self.create_item(partition_key, item_id, item, options)
+            .await
+    }
+
+    /// Replaces an existing item in the container, computing its partition key from the item body.
+    ///
+    /// This behaves like [`replace_item`](Self::replace_item), but the partition key is computed as
+    /// described in [`create_item_infer_partition_key`](Self::create_item_infer_partition_key).
+    ///
+    /// # Arguments
+    /// * `item_id` - The id of the item to replace.
+    /// * `item` - The new item. It must serialize to a JSON object.
+    /// * `options` - Optional parameters for the request
+    pub async fn replace_item_infer_partition_key<T: Serialize>(
+        &self,
+        item_id: &str,
+        item: T,
+        options: Option<ItemWriteOptions>,
+    ) -> crate::Result<ItemResponse> {
+        let (partition_key, item) = self.partition_key_from_item(item)?;
+        self.replace_item(partition_key, item_id, item, options)
+            .await
+    }
+
+    /// Creates or replaces an item in the container, computing its partition key from the item body.
+    ///
+    /// This behaves like [`upsert_item`](Self::upsert_item), but the partition key is computed as
+    /// described in [`create_item_infer_partition_key`](Self::create_item_infer_partition_key).
+    ///
+    /// # Arguments
+    /// * `item_id` - The id of the item to create or replace.
+    /// * `item` - The item to upsert. It must serialize to a JSON object.
+    /// * `options` - Optional parameters for the request
+    pub async fn upsert_item_infer_partition_key<T: Serialize>(
+        &self,
+        item_id: &str,
+        item: T,
+        options: Option<ItemWriteOptions>,
+    ) -> crate::Result<ItemResponse> {
+        let (partition_key, item) = self.partition_key_from_item(item)?;
+        self.upsert_item(partition_key, item_id, item, options)
+            .await
+    }
+
+    /// Serializes `item` and extracts its partition key using the cached container partition key paths.
+    fn partition_key_from_item<T: Serialize>(
+        &self,
+        item: T,
+    ) -> crate::Result<(PartitionKey, serde_json::Value)> {
+        let item = serde_json::to_value(item)?;
+        let partition_key =
+            PartitionKey::from_item(self.container_ref.partition_key_definition(), &item)?;
+        Ok((partition_key, item))
+    }
+
     /// Reads a specific item from the container.
     ///
     /// # Arguments
@@ -668,6 +887,21 @@ impl ContainerClient {
     /// # Ok(())
     /// # }
     /// ```
+    ///
+    /// To pass an item through without deserializing it, take the body's raw bytes instead.
+    /// They hold the exact JSON the service returned.
+    ///
+    /// ```rust,no_run
+    /// # async fn doc() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let container_client: azure_data_cosmos::clients::ContainerClient = panic!("this is a non-running example");
+    /// let json: azure_core::Bytes = container_client
+    ///     .read_item("partition1", "item1", None)
+    ///     .await?
+    ///     .into_body()
+    ///     .single()?;
+    /// # Ok(())
+    /// # }
+    /// ```
     pub async fn read_item(
         &self,
         partition_key: impl Into<PartitionKey>,
@@ -700,6 +934,53 @@ impl ContainerClient {
         ))
     }
 
+    /// Reads many items, given their ids and partition keys.
+    ///
+    /// The items are grouped by partition key and read with single-partition queries, which costs
+    /// far fewer request units and round trips than one [`read_item`](Self::read_item) call per
+    /// item. Items are yielded in completion order; items that do not exist are omitted.
+    ///
+    /// # Arguments
+    /// * `items` - The id and partition key of each item to read.
+    /// * `options` - Optional parameters for the request.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # async fn doc() -> Result<(), Box<dyn std::error::Error>> {
+    /// use futures::TryStreamExt;
+    /// # let container_client: azure_data_cosmos::clients::ContainerClient = panic!("this is a non-running example");
+    /// #[derive(serde::Deserialize)]
+    /// struct Product {
+    ///     id: String,
+    /// }
+    /// let products: Vec<Product> = container_client
+    ///     .read_many_items([("product1", "category1"), ("product2", "category2")], None)
+    ///     .try_collect()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn read_many_items<T, I, P>(
+        &self,
+        items: impl IntoIterator<Item = (I, P)>,
+        options: Option<ReadManyOptions>,
+    ) -> ReadManyItemIterator<T>
+    where
+        T: DeserializeOwned + Send + 'static,
+        I: Into<String>,
+        P: Into<PartitionKey>,
+    {
+        ReadManyItemIterator::new(
+            self.clone(),
+            items
+                .into_iter()
+                .map(|(id, partition_key)| (id.into(), partition_key.into()))
+                .collect(),
+            options.unwrap_or_default(),
+        )
+    }
+
     /// Deletes an item from the container.
     ///
     /// # Arguments
@@ -737,7 +1018,9 @@ impl ContainerClient {
 
         // Create the driver operation (no body for delete).
         let operation = CosmosOperation::delete_item(item_ref);
-        let operation = apply_item_options(operation, options.session_token, options.precondition);
+        let operation = apply_item_options(operation, options.session_token, options.precondition)
+            .with_pre_triggers(options.pre_triggers)
+            .with_post_triggers(options.post_triggers);
 
         // Execute through the driver.
         let driver_response = self
@@ -752,6 +1035,261 @@ impl ContainerClient {
         ))
     }
 
+    /// Executes a stored procedure in the scope of a single partition key.
+    ///
+    /// The response body holds the value the stored procedure passed to `setBody`, and its
+    /// headers carry the request charge (see [`ResponseHeaders::request_charge`](crate::models::ResponseHeaders::request_charge)).
+    ///
+    /// # Arguments
+    /// * `stored_procedure_id` - The id of the stored procedure.
+    /// * `partition_key` - The partition key the stored procedure runs against.
+    /// * `parameters` - The stored procedure's parameters. A value that serializes to a JSON
+    ///   array (such as a tuple, slice, or `Vec`) supplies one parameter per element; any other
+    ///   value is passed as the only parameter.
+    /// * `options` - Optional parameters for the request.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # async fn doc() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let container_client: azure_data_cosmos::clients::ContainerClient = panic!("this is a non-running example");
+    /// let response = container_client
+    ///     .execute_stored_procedure::<u32>("incrementCounter", "category1", ("counter1", 5), None)
+    ///     .await?;
+    /// println!("request charge: {:?}", response.headers().request_charge());
+    /// let counter = response.into_model()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn execute_stored_procedure<T>(
+        &self,
+        stored_procedure_id: &str,
+        partition_key: impl Into<PartitionKey>,
+        parameters: impl Serialize,
+        options: Option<ExecuteStoredProcedureOptions>,
+    ) -> crate::Result<ResourceResponse<T>> {
+        let options = options.unwrap_or_default();
+        let body = serde_json::to_vec(&stored_procedure_parameters(parameters)?)?;
+
+        let stored_procedure_ref = StoredProcedureReference::from_name(
+            &self.container_ref,
+            stored_procedure_id.to_owned(),
+        );
+        let operation =
+            CosmosOperation::execute_stored_procedure(stored_procedure_ref, partition_key.into())
+                .with_body(body);
+        let operation = apply_item_options(operation, options.session_token, None);
+
+        let driver_response = self
+            .context
+            .driver
+            .execute_singleton_operation(operation, options.operation)
+            .await?;
+
+        Ok(ResourceResponse::new(
+            crate::driver_bridge::driver_response_to_cosmos_response(driver_response),
+        ))
+    }
+
+    /// Creates a new trigger in the container.
+    ///
+    #[doc = include_str!("../../docs/control-plane-always-returns-body.md")]
+    ///
+    /// # Arguments
+    /// * `properties` - A [`TriggerProperties`] describing the new trigger.
+    /// * `options` - Optional parameters for the request.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # async fn doc() -> Result<(), Box<dyn std::error::Error>> {
+    /// use azure_data_cosmos::models::{TriggerOperation, TriggerProperties, TriggerType};
+    /// use azure_data_cosmos::options::ItemWriteOptions;
+    /// # let container_client: azure_data_cosmos::clients::ContainerClient = panic!("this is a non-running example");
+    /// let trigger = TriggerProperties::new(
+    ///     "stampCreated",
+    ///     r#"function stampCreated() {
+    ///         var request = getContext().getRequest();
+    ///         var item = request.getBody();
+    ///         item.created = new Date().toISOString();
+    ///         request.setBody(item);
+    ///     }"#,
+    ///     TriggerType::Pre,
+    ///     TriggerOperation::Create,
+    /// );
+    /// container_client.create_trigger(trigger, None).await?;
+    ///
+    /// let options = ItemWriteOptions::default().with_pre_triggers(["stampCreated".to_string()]);
+    /// let item = serde_json::json!({ "id": "item1", "category": "category1" });
+    /// container_client
+    ///     .create_item("category1", "item1", item, Some(options))
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn create_trigger(
+        &self,
+        properties: TriggerProperties,
+        options: Option<CreateTriggerOptions>,
+    ) -> crate::Result<ResourceResponse<TriggerProperties>> {
+        let options = options.unwrap_or_default();
+        let body = serde_json::to_vec(&properties)?;
+        let operation = CosmosOperation::create_trigger(self.container_ref.clone()).with_body(body);
+
+        // Control-plane writes always need the full response body so the
+        // caller can inspect the stored trigger.
+        let mut operation_options = options.operation;
+        operation_options.content_response_on_write =
+            Some(azure_data_cosmos_driver::options::ContentResponseOnWrite::Enabled);
+
+        let driver_response = self
+            .context
+            .driver
+            .execute_singleton_operation(operation, operation_options)
+            .await?;
+
+        Ok(ResourceResponse::new(
+            crate::driver_bridge::driver_response_to_cosmos_response(driver_response),
+        ))
+    }
+
+    /// Reads the properties of a trigger.
+    ///
+    /// # Arguments
+    /// * `trigger_id` - The id of the trigger.
+    /// * `options` - Optional parameters for the request.
+    pub async fn read_trigger(
+        &self,
+        trigger_id: &str,
+        options: Option<ReadTriggerOptions>,
+    ) -> crate::Result<ResourceResponse<TriggerProperties>> {
+        let options = options.unwrap_or_default();
+        let trigger_ref = TriggerReference::from_name(&self.container_ref, trigger_id.to_owned());
+        let operation = CosmosOperation::read_trigger(trigger_ref);
+
+        let driver_response = self
+            .context
+            .driver
+            .execute_singleton_operation(operation, options.operation)
+            .await?;
+
+        Ok(ResourceResponse::new(
+            crate::driver_bridge::driver_response_to_cosmos_response(driver_response),
+        ))
+    }
+
+    /// Replaces the definition of a trigger.
+    ///
+    /// The trigger to replace is identified by [`TriggerProperties::id`].
+    ///
+    #[doc = include_str!("../../docs/control-plane-always-returns-body.md")]
+    ///
+    /// # Arguments
+    /// * `properties` - The new [`TriggerProperties`] for the trigger.
+    /// * `options` - Optional parameters for the request.
+    pub async fn replace_trigger(
+        &self,
+        properties: TriggerProperties,
+        options: Option<ReplaceTriggerOptions>,
+    ) -> crate::Result<ResourceResponse<TriggerProperties>> {
+        let options = options.unwrap_or_default();
+        let body = serde_json::to_vec(&properties)?;
+        let trigger_ref =
+            TriggerReference::from_name(&self.container_ref, properties.id.into_owned());
+        let operation = CosmosOperation::replace_trigger(trigger_ref).with_body(body);
+
+        let mut operation_options = options.operation;
+        operation_options.content_response_on_write =
+            Some(azure_data_cosmos_driver::options::ContentResponseOnWrite::Enabled);
+
+        let driver_response = self
+            .context
+            .driver
+            .execute_singleton_operation(operation, operation_options)
+            .await?;
+
+        Ok(ResourceResponse::new(
+            crate::driver_bridge::driver_response_to_cosmos_response(driver_response),
+        ))
+    }
+
+    /// Deletes a trigger.
+    ///
+    /// # Arguments
+    /// * `trigger_id` - The id of the trigger.
+    /// * `options` - Optional parameters for the request.
+    pub async fn delete_trigger(
+        &self,
+        trigger_id: &str,
+        options: Option<DeleteTriggerOptions>,
+    ) -> crate::Result<ResourceResponse<()>> {
+        let options = options.unwrap_or_default();
+        let trigger_ref = TriggerReference::from_name(&self.container_ref, trigger_id.to_owned());
+        let operation = CosmosOperation::delete_trigger(trigger_ref);
+
+        let driver_response = self
+            .context
+            .driver
+            .execute_singleton_operation(operation, options.operation)
+            .await?;
+
+        Ok(ResourceResponse::new(
+            crate::driver_bridge::driver_response_to_cosmos_response(driver_response),
+        ))
+    }
+
+    /// Executes a query against the triggers in the container.
+    ///
+    /// # Arguments
+    /// * `query` - The query to execute.
+    /// * `options` - Optional parameters for the request.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # async fn doc() -> Result<(), Box<dyn std::error::Error>> {
+    /// use futures::TryStreamExt;
+    /// # let container_client: azure_data_cosmos::clients::ContainerClient = panic!("this is a non-running example");
+    /// let triggers: Vec<_> = container_client
+    ///     .query_triggers("SELECT * FROM t", None)
+    ///     .await?
+    ///     .try_collect()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn query_triggers(
+        &self,
+        query: impl Into<Query>,
+        options: Option<QueryTriggersOptions>,
+    ) -> crate::Result<QueryItemIterator<TriggerProperties>> {
+        let options = options.unwrap_or_default();
+        let query = query.into();
+        let mut initial_operation = CosmosOperation::query_triggers(self.container_ref.clone())
+            .with_body(serde_json::to_vec(&query)?);
+        if let Some(hint) = options.feed.max_item_count {
+            initial_operation = initial_operation.with_max_item_count(hint);
+        }
+        let operation_options = options.operation;
+
+        let plan = self
+            .context
+            .driver
+            .plan_operation(
+                initial_operation,
+                &operation_options,
+                options.feed.continuation_token.as_ref(),
+            )
+            .await?;
+
+        Ok(QueryItemIterator::new(
+            self.context.driver.clone(),
+            None,
+            plan,
+            operation_options,
+        ))
+    }
+
     /// Executes a single-partition query against items in the container.
     ///
     /// The resulting document will be deserialized into the type provided as `T`.
@@ -1019,6 +1557,92 @@ impl ContainerClient {
         ))
     }
 
+    /// Executes many item operations as batch requests.
+    ///
+    /// Operations are grouped by partition key and sent as non-atomic batch requests of
+    /// up to 100 operations each, with up to [`BulkOptions::max_concurrency`] requests in
+    /// flight. Unlike [`execute_transactional_batch`](Self::execute_transactional_batch),
+    /// operations may target any partition key and succeed or fail independently.
+    /// Operations throttled by the service are resubmitted after the delay it requests,
+    /// up to [`BulkOptions::max_throttle_retries`] times.
+    ///
+    /// # Arguments
+    /// * `operations` - The operations to execute.
+    /// * `options` - Optional parameters for the request.
+    ///
+    /// Returns a [`BulkExecutor`] stream that yields one result per operation as its batch
+    /// completes. Requests are sent only while the stream is polled.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use azure_data_cosmos::models::BulkOperation;
+    /// use futures::StreamExt;
+    ///
+    /// # async fn doc() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let container_client: azure_data_cosmos::clients::ContainerClient = panic!("this is a non-running example");
+    /// let operations = vec![
+    ///     BulkOperation::create_item("category1", serde_json::json!({ "id": "a", "category": "category1" }))?,
+    ///     BulkOperation::create_item("category2", serde_json::json!({ "id": "b", "category": "category2" }))?,
+    /// ];
+    /// let failures = container_client
+    ///     .execute_bulk(operations, None)
+    ///     .filter(|result| futures::future::ready(!result.result().is_ok_and(|r| r.is_success())))
+    ///     .count()
+    ///     .await;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn execute_bulk(
+        &self,
+        operations: impl IntoIterator<Item = BulkOperation>,
+        options: Option<BulkOptions>,
+    ) -> BulkExecutor {
+        BulkExecutor::new(
+            self.context.driver.clone(),
+            self.container_ref.clone(),
+            operations.into_iter().collect(),
+            options.unwrap_or_default(),
+        )
+    }
+
+    /// Creates a [`ChangeFeedProcessor`] that passes each batch of changes in this container to `handler`.
+    ///
+    /// Instances that use the same `processor_name` and lease container share the work: each
+    /// feed range of this container is processed by one instance at a time, and progress is
+    /// checkpointed to the lease container after `handler` completes a batch. The processor
+    /// does nothing until it is run; see [`ChangeFeedProcessor::run_until()`].
+    ///
+    /// # Arguments
+    /// * `processor_name` - The name shared by every instance of this processor.
+    /// * `instance_name` - A name unique to this instance, recorded as the owner of its leases.
+    /// * `lease_container` - The container that stores leases. Its partition key path must be `/id`.
+    /// * `handler` - Called with each batch of changes. An error stops processing of the feed range
+    ///   until its lease is next acquired, and the batch is delivered again.
+    /// * `options` - Optional parameters for polling and lease management.
+    pub fn change_feed_processor<T, F, Fut>(
+        &self,
+        processor_name: impl Into<String>,
+        instance_name: impl Into<String>,
+        lease_container: ContainerClient,
+        handler: F,
+        options: Option<ChangeFeedProcessorOptions>,
+    ) -> ChangeFeedProcessor
+    where
+        T: DeserializeOwned + Send + 'static,
+        F: Fn(ChangeFeedProcessorContext, Vec<ChangeFeedItem<T>>) -> Fut + Send + Sync + 'static,
+        Fut: std::future::Future<Output = azure_core::Result<()>> + Send + 'static,
+    {
+        ChangeFeedProcessor::new(
+            self.clone(),
+            lease_container,
+            processor_name.into(),
+            instance_name.into(),
+            handler,
+            options.unwrap_or_default(),
+        )
+    }
+
     /// Gets the feed ranges for this container.
     pub async fn read_feed_ranges(
         &self,
@@ -1233,6 +1857,16 @@ impl ContainerClient {
     }
 }
 
+/// Returns the JSON array of stored procedure parameters for `parameters`.
+///
+/// Values that do not serialize to an array become the only parameter.
+fn stored_procedure_parameters(parameters: impl Serialize) -> crate::Result<serde_json::Value> {
+    Ok(match serde_json::to_value(parameters)? {
+        parameters @ serde_json::Value::Array(_) => parameters,
+        parameter => serde_json::Value::Array(vec![parameter]),
+    })
+}
+
 /// Applies optional `session_token` and `precondition` to a [`CosmosOperation`].
 ///
 /// Both [`ItemReadOptions`] and [`ItemWriteOptions`] carry these fields;
@@ -173,14 +173,21 @@ impl CosmosClient {
         let options = options.unwrap_or_default();
         let query = query.into();
         let account = self.context.driver.account().clone();
-        let initial_operation =
+        let mut initial_operation =
             CosmosOperation::query_databases(account).with_body(serde_json::to_vec(&query)?);
+        if let Some(hint) = options.feed.max_item_count {
+            initial_operation = initial_operation.with_max_item_count(hint);
+        }
         let operation_options = options.operation;
 
         let plan = self
             .context
             .driver
-            .plan_operation(initial_operation, &operation_options, None)
+            .plan_operation(
+                initial_operation,
+                &operation_options,
+                options.feed.continuation_token.as_ref(),
+            )
             .await?;
 
         Ok(QueryItemIterator::new(
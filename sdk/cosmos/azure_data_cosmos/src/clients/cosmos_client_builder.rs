@@ -3,6 +3,7 @@
 
 //! Builder for creating [`CosmosClient`] instances.
 
+use std::num::NonZeroUsize;
 #[cfg(feature = "fault_injection")]
 use std::sync::Arc;
 
@@ -99,6 +100,10 @@ pub struct CosmosClientBuilder {
     backup_endpoints: Vec<azure_core::http::Url>,
     /// Options to use for per-partition failover (PPAF, PPCB)
     partition_failover_options: Option<PartitionFailoverOptions>,
+    /// Whether reads prefer the lowest-latency preferred region.
+    latency_based_read_routing: bool,
+    /// Maximum number of containers whose session tokens are cached.
+    max_session_containers: Option<NonZeroUsize>,
 }
 
 impl CosmosClientBuilder {
@@ -155,6 +160,34 @@ impl CosmosClientBuilder {
         self
     }
 
+    /// Enables or disables latency-based read routing (disabled by default).
+    ///
+    /// When enabled, the client tracks a rolling average of successful read
+    /// latency per region and sends the first attempt of each read to the
+    /// available preferred region with the lowest latency, rather than strictly
+    /// following preferred-region order. A region must be noticeably faster
+    /// than the current choice before reads move to it. A small share of reads
+    /// is sent to the other available preferred regions so their latency stays
+    /// known. Retries and writes are unaffected.
+    pub fn with_latency_based_read_routing(mut self, enabled: bool) -> Self {
+        self.latency_based_read_routing = enabled;
+        self
+    }
+
+    /// Limits the number of containers whose session tokens the client caches
+    /// (unbounded by default).
+    ///
+    /// The client remembers the latest session token of every container it
+    /// talks to. Long-running processes that touch many containers, such as
+    /// multi-tenant services with a container per tenant, can set this to
+    /// bound that memory. When the limit is reached, the least recently used
+    /// container's tokens are evicted; its next request is sent without a
+    /// cached session token.
+    pub fn with_max_session_containers(mut self, max_containers: NonZeroUsize) -> Self {
+        self.max_session_containers = Some(max_containers);
+        self
+    }
+
     /// Sets a per-client suffix to append to the User-Agent header for
     /// telemetry, overriding any runtime-wide default suffix.
     ///
@@ -282,6 +315,8 @@ impl CosmosClientBuilder {
             self.options.operation,
             self.options.user_agent_suffix,
             self.partition_failover_options,
+            self.latency_based_read_routing,
+            self.max_session_containers,
             #[cfg(feature = "fault_injection")]
             self.fault_injection_rules,
             self.throughput_control_groups,
@@ -304,12 +339,15 @@ impl CosmosClientBuilder {
 ///   a warning and falls back to an empty list, which causes the driver to use
 ///   the account's own region order.
 /// - [`RoutingStrategy::PreferredRegions`] passes the caller's list through unchanged.
+#[allow(clippy::too_many_arguments)]
 fn build_driver_options(
     account: azure_data_cosmos_driver::models::AccountReference,
     strategy: RoutingStrategy,
     operation_options: OperationOptions,
     user_agent_suffix: Option<UserAgentSuffix>,
     partition_failover_options: Option<PartitionFailoverOptions>,
+    latency_based_read_routing: bool,
+    max_session_containers: Option<NonZeroUsize>,
     #[cfg(feature = "fault_injection")] fault_injection_rules: Vec<
         Arc<azure_data_cosmos_driver::fault_injection::FaultInjectionRule>,
     >,
@@ -330,13 +368,17 @@ fn build_driver_options(
     };
     let mut builder = azure_data_cosmos_driver::options::DriverOptions::builder(account)
         .with_preferred_regions(preferred_regions)
-        .with_operation_options(operation_options);
+        .with_operation_options(operation_options)
+        .with_latency_based_read_routing(latency_based_read_routing);
     if let Some(suffix) = user_agent_suffix {
         builder = builder.with_user_agent_suffix(suffix);
     }
     if let Some(pfo) = partition_failover_options {
         builder = builder.with_partition_failover_options(pfo);
     }
+    if let Some(max_containers) = max_session_containers {
+        builder = builder.with_max_session_containers(max_containers);
+    }
     #[cfg(feature = "fault_injection")]
     if !fault_injection_rules.is_empty() {
         builder = builder
@@ -366,6 +408,11 @@ fn build_driver_account(
         CosmosCredential::MasterKey(key) => {
             azure_data_cosmos_driver::models::AccountReference::with_master_key(endpoint, key)
         }
+        CosmosCredential::ResourceTokens(tokens) => {
+            azure_data_cosmos_driver::models::AccountReference::with_resource_tokens(
+                endpoint, tokens,
+            )
+        }
     };
     base.with_backup_endpoints(backup_endpoints)
 }
@@ -469,6 +516,8 @@ mod tests {
             OperationOptions::default(),
             None,
             None,
+            false,
+            None,
             #[cfg(feature = "fault_injection")]
             Vec::new(),
             Vec::new(),
@@ -492,6 +541,8 @@ mod tests {
             OperationOptions::default(),
             None,
             None,
+            false,
+            None,
             #[cfg(feature = "fault_injection")]
             Vec::new(),
             Vec::new(),
@@ -513,6 +564,8 @@ mod tests {
             OperationOptions::default(),
             None,
             None,
+            false,
+            None,
             #[cfg(feature = "fault_injection")]
             Vec::new(),
             Vec::new(),
@@ -532,6 +585,8 @@ mod tests {
             OperationOptions::default(),
             Some(suffix.clone()),
             None,
+            false,
+            None,
             #[cfg(feature = "fault_injection")]
             Vec::new(),
             Vec::new(),
@@ -556,6 +611,8 @@ mod tests {
             OperationOptions::default(),
             None,
             Some(pfo),
+            false,
+            None,
             #[cfg(feature = "fault_injection")]
             Vec::new(),
             Vec::new(),
@@ -583,6 +640,8 @@ mod tests {
             OperationOptions::default(),
             None,
             None,
+            false,
+            None,
             #[cfg(feature = "fault_injection")]
             Vec::new(),
             Vec::new(),
@@ -594,4 +653,42 @@ mod tests {
             PartitionFailoverOptions::default().circuit_breaker_enabled(),
         );
     }
+
+    #[test]
+    fn latency_based_read_routing_flows_to_driver_options() {
+        let opts = build_driver_options(
+            test_account(),
+            RoutingStrategy::PreferredRegions(Vec::new()),
+            OperationOptions::default(),
+            None,
+            None,
+            true,
+            None,
+            #[cfg(feature = "fault_injection")]
+            Vec::new(),
+            Vec::new(),
+        )
+        .expect("build_driver_options should succeed");
+
+        assert!(opts.latency_based_read_routing_enabled());
+    }
+
+    #[test]
+    fn max_session_containers_flows_to_driver_options() {
+        let opts = build_driver_options(
+            test_account(),
+            RoutingStrategy::PreferredRegions(Vec::new()),
+            OperationOptions::default(),
+            None,
+            None,
+            false,
+            NonZeroUsize::new(50),
+            #[cfg(feature = "fault_injection")]
+            Vec::new(),
+            Vec::new(),
+        )
+        .expect("build_driver_options should succeed");
+
+        assert_eq!(opts.max_session_containers(), NonZeroUsize::new(50));
+    }
 }
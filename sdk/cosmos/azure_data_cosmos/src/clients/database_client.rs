@@ -12,7 +12,7 @@ use crate::{
     },
     Query,
 };
-use azure_data_cosmos_driver::models::{CosmosOperation, DatabaseReference};
+use azure_data_cosmos_driver::models::{CosmosOperation, DatabaseReference, OfferMigration};
 
 use super::ThroughputPoller;
 
@@ -124,14 +124,21 @@ impl DatabaseClient {
     ) -> crate::Result<QueryItemIterator<ContainerProperties>> {
         let options = options.unwrap_or_default();
         let query = query.into();
-        let initial_operation = CosmosOperation::query_containers(self.database_ref.clone())
+        let mut initial_operation = CosmosOperation::query_containers(self.database_ref.clone())
             .with_body(serde_json::to_vec(&query)?);
+        if let Some(hint) = options.feed.max_item_count {
+            initial_operation = initial_operation.with_max_item_count(hint);
+        }
         let operation_options = options.operation;
 
         let plan = self
             .context
             .driver
-            .plan_operation(initial_operation, &operation_options, None)
+            .plan_operation(
+                initial_operation,
+                &operation_options,
+                options.feed.continuation_token.as_ref(),
+            )
             .await?;
 
         Ok(QueryItemIterator::new(
@@ -276,6 +283,71 @@ impl DatabaseClient {
         )
         .await
     }
+
+    /// Begins migrating the database throughput from manual to autoscale.
+    ///
+    /// The service chooses the autoscale maximum from the current manual throughput. Use
+    /// [`begin_replace_throughput()`](Self::begin_replace_throughput()) afterwards to pick a
+    /// different maximum. The returned [`ThroughputPoller`] tracks the migration like a replace.
+    ///
+    /// # Arguments
+    /// * `options` - Optional parameters for the request.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # async fn example(client: azure_data_cosmos::clients::DatabaseClient) -> azure_data_cosmos::Result<()> {
+    /// let throughput = client
+    ///     .begin_migrate_throughput_to_autoscale(None)
+    ///     .await? // start the migration
+    ///     .await? // wait for completion (polls if async)
+    ///     .into_model()?;
+    /// println!("autoscale maximum: {:?}", throughput.autoscale_maximum());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn begin_migrate_throughput_to_autoscale(
+        &self,
+        options: Option<ThroughputOptions>,
+    ) -> crate::Result<ThroughputPoller> {
+        self.begin_migrate_throughput(OfferMigration::ToAutoscale, options)
+            .await
+    }
+
+    /// Begins migrating the database throughput from autoscale to manual.
+    ///
+    /// The service sets the manual throughput from the current autoscale maximum. The returned
+    /// [`ThroughputPoller`] tracks the migration like a replace.
+    ///
+    /// # Arguments
+    /// * `options` - Optional parameters for the request.
+    pub async fn begin_migrate_throughput_to_manual(
+        &self,
+        options: Option<ThroughputOptions>,
+    ) -> crate::Result<ThroughputPoller> {
+        self.begin_migrate_throughput(OfferMigration::ToManual, options)
+            .await
+    }
+
+    async fn begin_migrate_throughput(
+        &self,
+        migration: OfferMigration,
+        options: Option<ThroughputOptions>,
+    ) -> crate::Result<ThroughputPoller> {
+        let options = options.unwrap_or_default();
+        // We need to get the RID for the database.
+        let db = self.read(None).await?.into_model()?;
+        let resource_id = resource_id_or_error(db.system_properties.resource_id, "database")?;
+
+        offers_client::begin_migrate(
+            self.context.driver.clone(),
+            self.context.driver.account().clone(),
+            &resource_id,
+            migration,
+            options.operation,
+        )
+        .await
+    }
 }
 
 /// Unwraps the `_rid` from a system-properties response. The Cosmos service
@@ -7,6 +7,8 @@
 // Public API
 // =========================================================================
 
+pub use bulk_executor::BulkExecutor;
+pub use change_feed_processor::{ChangeFeedProcessor, ChangeFeedProcessorContext};
 pub use container_client::ContainerClient;
 pub use cosmos_client::CosmosClient;
 pub use cosmos_client_builder::CosmosClientBuilder;
@@ -23,6 +25,8 @@ pub use throughput_poller::ThroughputPoller;
 // Internal modules
 // =========================================================================
 
+mod bulk_executor;
+mod change_feed_processor;
 mod container_client;
 mod cosmos_client;
 mod cosmos_client_builder;
@@ -7,7 +7,7 @@
 //! replace throughput offers. All operations go through the Cosmos driver.
 
 use crate::{feed::FeedBody, models::CosmosResponse, models::ThroughputProperties, Query};
-use azure_data_cosmos_driver::models::{AccountReference, CosmosOperation};
+use azure_data_cosmos_driver::models::{AccountReference, CosmosOperation, OfferMigration};
 use azure_data_cosmos_driver::options::OperationOptions;
 use azure_data_cosmos_driver::CosmosDriver;
 use std::sync::Arc;
@@ -70,19 +70,54 @@ pub(crate) async fn begin_replace(
     operation_options: OperationOptions,
 ) -> crate::Result<crate::clients::ThroughputPoller> {
     let mut current_throughput =
-        find_offer(&driver, &account, resource_id, operation_options.clone())
-            .await?
-            .ok_or_else(|| {
-                // No offer exists for the resource — typically the caller
-                // pointed at a resource that doesn't support throughput
-                // (e.g. a serverless or shared-throughput container).
-                crate::DriverCosmosError::builder()
-                    .with_status(
-                        crate::error::CosmosStatus::CLIENT_NO_THROUGHPUT_OFFER_FOR_RESOURCE,
-                    )
-                    .with_message("no throughput offer found for this resource")
-                    .build()
-            })?;
+        find_offer_to_replace(&driver, &account, resource_id, operation_options.clone()).await?;
+    current_throughput.offer = throughput.offer;
+
+    send_replace(driver, account, current_throughput, None, operation_options).await
+}
+
+/// Migrates the offer for a resource between manual and autoscale throughput and returns a
+/// [`ThroughputPoller`](crate::clients::ThroughputPoller) to track the operation.
+///
+/// The current offer is sent back unchanged; the service derives the new throughput.
+pub(crate) async fn begin_migrate(
+    driver: Arc<CosmosDriver>,
+    account: AccountReference,
+    resource_id: &str,
+    migration: OfferMigration,
+    operation_options: OperationOptions,
+) -> crate::Result<crate::clients::ThroughputPoller> {
+    let current_throughput =
+        find_offer_to_replace(&driver, &account, resource_id, operation_options.clone()).await?;
+
+    send_replace(
+        driver,
+        account,
+        current_throughput,
+        Some(migration),
+        operation_options,
+    )
+    .await
+}
+
+/// Finds the offer for a resource, failing if there is none or it has no id.
+async fn find_offer_to_replace(
+    driver: &CosmosDriver,
+    account: &AccountReference,
+    resource_id: &str,
+    operation_options: OperationOptions,
+) -> crate::Result<ThroughputProperties> {
+    let current_throughput = find_offer(driver, account, resource_id, operation_options)
+        .await?
+        .ok_or_else(|| {
+            // No offer exists for the resource — typically the caller
+            // pointed at a resource that doesn't support throughput
+            // (e.g. a serverless or shared-throughput container).
+            crate::DriverCosmosError::builder()
+                .with_status(crate::error::CosmosStatus::CLIENT_NO_THROUGHPUT_OFFER_FOR_RESOURCE)
+                .with_message("no throughput offer found for this resource")
+                .build()
+        })?;
 
     if current_throughput.offer_id.is_empty() {
         // Service contract violation: an offer was returned but it has
@@ -94,13 +129,24 @@ pub(crate) async fn begin_replace(
             .build()
             .into());
     }
+    Ok(current_throughput)
+}
 
-    let offer_id = current_throughput.offer_id.clone();
-    current_throughput.offer = throughput.offer;
-
-    let body = serde_json::to_vec(&current_throughput)?;
-    let operation =
+/// Replaces an offer with `throughput`, optionally migrating it, and returns a poller.
+async fn send_replace(
+    driver: Arc<CosmosDriver>,
+    account: AccountReference,
+    throughput: ThroughputProperties,
+    migration: Option<OfferMigration>,
+    operation_options: OperationOptions,
+) -> crate::Result<crate::clients::ThroughputPoller> {
+    let offer_id = throughput.offer_id.clone();
+    let body = serde_json::to_vec(&throughput)?;
+    let mut operation =
         CosmosOperation::replace_offer(account.clone(), offer_id.clone()).with_body(body);
+    if let Some(migration) = migration {
+        operation = operation.with_offer_migration(migration);
+    }
 
     // The Offers API always requires the full response body (the service does not
     // support Prefer: return=minimal for offers), so explicitly enable content response.
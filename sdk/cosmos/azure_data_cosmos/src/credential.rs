@@ -5,6 +5,7 @@
 
 use azure_core::credentials::TokenCredential;
 use azure_core::fmt::SafeDebug;
+use azure_data_cosmos_driver::models::ResourceTokens;
 use std::sync::Arc;
 
 #[cfg(feature = "key_auth")]
@@ -12,8 +13,9 @@ use azure_core::credentials::Secret;
 
 /// Authentication credential for connecting to a Cosmos DB account.
 ///
-/// Either key-based authentication using a master key, or token-based
-/// authentication using an Azure credential (e.g., managed identity, service principal).
+/// Either key-based authentication using a master key, token-based
+/// authentication using an Azure credential (e.g., managed identity, service principal),
+/// or scoped access using [`ResourceTokens`].
 ///
 /// # Examples
 ///
@@ -44,6 +46,8 @@ pub enum CosmosCredential {
     /// Primary or secondary account key.
     #[cfg(feature = "key_auth")]
     MasterKey(Secret),
+    /// Resource tokens minted from permissions, granting access to specific resources.
+    ResourceTokens(ResourceTokens),
 }
 
 impl From<Arc<dyn TokenCredential>> for CosmosCredential {
@@ -52,6 +56,12 @@ impl From<Arc<dyn TokenCredential>> for CosmosCredential {
     }
 }
 
+impl From<ResourceTokens> for CosmosCredential {
+    fn from(tokens: ResourceTokens) -> Self {
+        Self::ResourceTokens(tokens)
+    }
+}
+
 #[cfg(feature = "key_auth")]
 impl From<Secret> for CosmosCredential {
     fn from(key: Secret) -> Self {
@@ -104,6 +114,16 @@ mod tests {
         assert_safe_debug_render(&format!("{cosmos:?}"), "TokenCredential");
     }
 
+    #[test]
+    fn debug_resource_tokens_redacts_tokens() {
+        let cosmos = CosmosCredential::from(
+            ResourceTokens::new().with_token("dbs/db1", "type=resource&ver=1.0&sig=secret"),
+        );
+        let rendered = format!("{cosmos:?}");
+        assert_safe_debug_render(&rendered, "ResourceTokens");
+        assert!(!rendered.contains("sig=secret"));
+    }
+
     #[cfg(feature = "key_auth")]
     #[test]
     fn debug_master_key_redacts_secret() {
@@ -16,7 +16,7 @@ use std::fmt;
 use std::sync::Arc;
 
 use azure_data_cosmos_driver::error::CosmosError as DriverCosmosError;
-use azure_data_cosmos_driver::models::CosmosResponse;
+use azure_data_cosmos_driver::models::{ActivityId, CosmosResponse, RequestCharge};
 
 use crate::diagnostics::DiagnosticsContext;
 
@@ -64,6 +64,92 @@ impl CosmosError {
     pub fn diagnostics(&self) -> Option<Arc<DiagnosticsContext>> {
         self.0.diagnostics()
     }
+
+    /// Returns the sub-status code, if any. Shorthand for
+    /// `self.status().sub_status()`.
+    pub fn sub_status(&self) -> Option<SubStatusCode> {
+        self.status().sub_status()
+    }
+
+    /// Returns the request charge (`x-ms-request-charge`) of the failed
+    /// response. Returns `None` for synthetic errors.
+    pub fn request_charge(&self) -> Option<RequestCharge> {
+        self.response()?.headers().request_charge
+    }
+
+    /// Returns the activity ID (`x-ms-activity-id`) of the failed response,
+    /// for correlating the failure with service-side logs. Returns `None`
+    /// for synthetic errors.
+    pub fn activity_id(&self) -> Option<&ActivityId> {
+        self.response()?.headers().activity_id.as_ref()
+    }
+}
+
+/// Cosmos-specific accessors on an [`azure_core::Error`].
+///
+/// A [`CosmosError`] converted into an [`azure_core::Error`] (for example by
+/// `?` in a function returning [`azure_core::Result`]) is kept as the error's
+/// source. This trait recovers its typed status and response headers without
+/// string matching. Every accessor returns `None` (or `false`) for errors that
+/// did not originate from a [`CosmosError`].
+///
+/// # Examples
+///
+/// ```
+/// use azure_data_cosmos::{CosmosErrorExt as _, ContainerClient};
+///
+/// async fn read(container: &ContainerClient) -> azure_core::Result<()> {
+///     match container.read_item("pk", "id", None).await {
+///         Ok(_) => Ok(()),
+///         Err(err) => {
+///             let err = azure_core::Error::from(err);
+///             if err.is_throttled() {
+///                 println!("throttled; charged {:?} RU", err.request_charge());
+///             }
+///             Err(err)
+///         }
+///     }
+/// }
+/// ```
+pub trait CosmosErrorExt {
+    /// Returns the [`CosmosError`] this error was converted from, if any.
+    fn cosmos_error(&self) -> Option<&CosmosError>;
+
+    /// Returns the typed Cosmos status (HTTP status code + optional sub-status).
+    fn cosmos_status(&self) -> Option<CosmosStatus> {
+        self.cosmos_error().map(CosmosError::status)
+    }
+
+    /// Returns the Cosmos sub-status code.
+    fn cosmos_sub_status(&self) -> Option<SubStatusCode> {
+        self.cosmos_error().and_then(CosmosError::sub_status)
+    }
+
+    /// Returns the request charge of the failed response.
+    fn request_charge(&self) -> Option<RequestCharge> {
+        self.cosmos_error().and_then(CosmosError::request_charge)
+    }
+
+    /// Returns the activity ID of the failed response.
+    fn activity_id(&self) -> Option<&ActivityId> {
+        self.cosmos_error().and_then(CosmosError::activity_id)
+    }
+
+    /// Returns `true` if the request was throttled (HTTP 429).
+    fn is_throttled(&self) -> bool {
+        self.cosmos_status().is_some_and(|s| s.is_throttled())
+    }
+
+    /// Returns `true` if the targeted resource or partition is gone (HTTP 410).
+    fn is_gone(&self) -> bool {
+        self.cosmos_status().is_some_and(|s| s.is_gone())
+    }
+}
+
+impl CosmosErrorExt for azure_core::Error {
+    fn cosmos_error(&self) -> Option<&CosmosError> {
+        self.downcast_ref::<CosmosError>()
+    }
 }
 
 impl fmt::Display for CosmosError {
@@ -319,6 +405,45 @@ mod tests {
         assert!(matches!(core_err.kind(), CoreErrorKind::Other));
     }
 
+    #[test]
+    fn cosmos_error_ext_on_azure_core_error() {
+        let cosmos: CosmosError = DriverCosmosError::builder()
+            .with_status(CosmosStatus::new(
+                azure_core::http::StatusCode::TooManyRequests,
+            ))
+            .with_message("throttled")
+            .build()
+            .into();
+        assert_eq!(cosmos.sub_status(), None);
+        assert_eq!(cosmos.request_charge(), None);
+        assert!(cosmos.activity_id().is_none());
+
+        let core_err: azure_core::Error = cosmos.into();
+        assert!(core_err.cosmos_error().is_some());
+        assert!(core_err.is_throttled());
+        assert!(!core_err.is_gone());
+        assert_eq!(
+            core_err.cosmos_status(),
+            Some(CosmosStatus::new(
+                azure_core::http::StatusCode::TooManyRequests
+            ))
+        );
+
+        let gone: azure_core::Error = CosmosError::from(
+            DriverCosmosError::builder()
+                .with_status(CosmosStatus::new(azure_core::http::StatusCode::Gone))
+                .with_message("gone")
+                .build(),
+        )
+        .into();
+        assert!(gone.is_gone());
+
+        let other = azure_core::Error::with_message(CoreErrorKind::Other, "not cosmos");
+        assert!(other.cosmos_error().is_none());
+        assert!(other.cosmos_sub_status().is_none());
+        assert!(!other.is_throttled());
+    }
+
     #[test]
     fn from_cosmos_error_for_azure_core_error_downcast_recovers_cosmos_error() {
         let cosmos: CosmosError = DriverCosmosError::builder()
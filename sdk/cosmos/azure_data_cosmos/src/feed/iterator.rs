@@ -17,7 +17,7 @@ use futures::future::BoxFuture;
 use futures::Stream;
 use serde::de::DeserializeOwned;
 
-use crate::{driver_bridge, feed::query_page::QueryFeedPage};
+use crate::{driver_bridge, feed::query_page::QueryFeedPage, models::QueryMetrics};
 
 type DriverPageFuture = BoxFuture<'static, (OperationPlan, crate::Result<Option<DriverResponse>>)>;
 
@@ -185,6 +185,7 @@ pub struct QueryItemIterator<T: Send> {
     #[pin]
     source: PageSource<T>,
     current: Option<std::vec::IntoIter<T>>,
+    query_metrics: Option<QueryMetrics>,
     _marker: PhantomData<fn() -> T>,
 }
 
@@ -199,10 +200,21 @@ impl<T: Send + DeserializeOwned + 'static> QueryItemIterator<T> {
         Self {
             source: PageSource::Live(Box::pin(LiveState::new(driver, container, plan, options))),
             current: None,
+            query_metrics: None,
             _marker: PhantomData,
         }
     }
 
+    /// Returns the query execution metrics aggregated over every page fetched so far.
+    ///
+    /// For a cross-partition query, each page is served by one partition; the returned
+    /// metrics combine all of them. Returns `None` until a page carrying metrics has been
+    /// fetched, which requires
+    /// [`QueryOptions::with_populate_query_metrics`](crate::options::QueryOptions::with_populate_query_metrics).
+    pub fn query_metrics(&self) -> Option<&QueryMetrics> {
+        self.query_metrics.as_ref()
+    }
+
     /// Converts this item iterator into a page iterator, yielding full pages
     /// instead of individual items.
     ///
@@ -212,6 +224,7 @@ impl<T: Send + DeserializeOwned + 'static> QueryItemIterator<T> {
     pub fn into_pages(self) -> QueryPageIterator<T> {
         QueryPageIterator {
             source: self.source,
+            query_metrics: self.query_metrics,
             _marker: PhantomData,
         }
     }
@@ -235,6 +248,7 @@ impl<T: Send + DeserializeOwned + 'static> Stream for QueryItemIterator<T> {
 
             match this.source.as_mut().poll_next_page(cx) {
                 task::Poll::Ready(Some(Ok(page))) => {
+                    accumulate_query_metrics(this.query_metrics, &page);
                     *this.current = Some(page.into_items().into_iter());
                     continue;
                 }
@@ -256,10 +270,22 @@ impl<T: Send + DeserializeOwned + 'static> Stream for QueryItemIterator<T> {
 pub struct QueryPageIterator<T: Send> {
     #[pin]
     source: PageSource<T>,
+    query_metrics: Option<QueryMetrics>,
     _marker: PhantomData<fn() -> T>,
 }
 
 impl<T: Send + DeserializeOwned + 'static> QueryPageIterator<T> {
+    /// Returns the query execution metrics aggregated over every page yielded so far.
+    ///
+    /// For a cross-partition query, each page is served by one partition; the returned
+    /// metrics combine all of them. Per-page metrics are available from
+    /// [`QueryFeedPage::execution_metrics()`]. Returns `None` until a page carrying metrics
+    /// has been yielded, which requires
+    /// [`QueryOptions::with_populate_query_metrics`](crate::options::QueryOptions::with_populate_query_metrics).
+    pub fn query_metrics(&self) -> Option<&QueryMetrics> {
+        self.query_metrics.as_ref()
+    }
+
     /// Captures the current iterator position as a [`ContinuationToken`].
     ///
     /// Pass the returned token to a subsequent
@@ -301,7 +327,18 @@ impl<T: Send + DeserializeOwned + 'static> Stream for QueryPageIterator<T> {
     ) -> task::Poll<Option<Self::Item>> {
         // Safety: we never move source out via Pin.
         let this = self.project();
-        this.source.poll_next_page(cx)
+        let poll = this.source.poll_next_page(cx);
+        if let task::Poll::Ready(Some(Ok(page))) = &poll {
+            accumulate_query_metrics(this.query_metrics, page);
+        }
+        poll
+    }
+}
+
+/// Adds the execution metrics of `page`, if any, to the running `total`.
+fn accumulate_query_metrics<T>(total: &mut Option<QueryMetrics>, page: &QueryFeedPage<T>) {
+    if let Some(metrics) = page.execution_metrics() {
+        *total.get_or_insert_with(QueryMetrics::default) += *metrics;
     }
 }
 
@@ -327,6 +364,7 @@ mod tests {
         QueryItemIterator {
             source: PageSource::Synthetic(pages.into()),
             current: None,
+            query_metrics: None,
             _marker: PhantomData,
         }
     }
@@ -389,6 +427,26 @@ mod tests {
         assert!(item_iter.next().await.unwrap().is_err());
     }
 
+    #[tokio::test]
+    async fn page_iterator_aggregates_query_metrics() {
+        let metrics =
+            |count: u64| QueryMetrics::parse(&format!("retrievedDocumentCount={count}")).unwrap();
+        let pages = vec![
+            Ok(create_test_page(vec![1]).with_execution_metrics_for_testing(metrics(10))),
+            Ok(create_test_page(vec![2])),
+            Ok(create_test_page(vec![3]).with_execution_metrics_for_testing(metrics(5))),
+        ];
+
+        let mut page_iter = synthetic_item_iter(pages).into_pages();
+        assert!(page_iter.query_metrics().is_none());
+        while page_iter.next().await.is_some() {}
+
+        assert_eq!(
+            15,
+            page_iter.query_metrics().unwrap().retrieved_document_count
+        );
+    }
+
     #[tokio::test]
     async fn item_iterator_handles_empty_pages() {
         let pages = vec![
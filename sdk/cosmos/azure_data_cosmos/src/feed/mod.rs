@@ -15,6 +15,7 @@ pub use iterator::{QueryItemIterator, QueryPageIterator};
 pub use page::FeedPage;
 pub use query::{FeedScope, Query};
 pub use query_page::QueryFeedPage;
+pub use read_many_iterator::ReadManyItemIterator;
 
 // =========================================================================
 // Crate-internal re-exports
@@ -31,3 +32,4 @@ mod iterator;
 mod page;
 mod query;
 mod query_page;
+mod read_many_iterator;
@@ -124,6 +124,21 @@ impl FeedScope {
 ///     .with_parameter("@customer_info", CustomerInfo { id: 42, name: "Contoso".into() }).unwrap();
 /// # assert_eq!(serde_json::to_string(&query).unwrap(), "{\"query\":\"\\n    SELECT * FROM c\\n    WHERE c.id = @customer_info.id\\n    AND c.name = @customer_info.name\",\"parameters\":[{\"name\":\"@customer_info\",\"value\":{\"id\":42,\"name\":\"Contoso\"}}]}");
 /// ```
+///
+/// To add several parameters at once, use [`Query::with_parameters()`] with a struct or map whose
+/// fields are the parameter names:
+///
+/// ```rust
+/// # use azure_data_cosmos::Query;
+/// #[derive(serde::Serialize)]
+/// struct Filter<'a> {
+///     category: &'a str,
+///     min_price: f64,
+/// }
+/// let query = Query::from("SELECT * FROM c WHERE c.category = @category AND c.price >= @min_price")
+///     .with_parameters(&Filter { category: "gear", min_price: 9.99 }).unwrap();
+/// # assert_eq!(serde_json::to_string(&query).unwrap(), "{\"query\":\"SELECT * FROM c WHERE c.category = @category AND c.price >= @min_price\",\"parameters\":[{\"name\":\"@category\",\"value\":\"gear\"},{\"name\":\"@min_price\",\"value\":9.99}]}");
+/// ```
 #[derive(Clone, Debug, Serialize)]
 pub struct Query {
     /// The query text itself.
@@ -153,6 +168,39 @@ impl Query {
         Ok(self)
     }
 
+    /// Consumes this [`Query`] instance, adds a parameter for each field of `parameters`, and returns it.
+    ///
+    /// `parameters` must serialize to a JSON object, such as a struct or a map with string keys.
+    /// Each key becomes a parameter name, with a leading `@` added if it does not already have one.
+    ///
+    /// Returns an error, without adding any parameters, if `parameters` cannot be serialized, does
+    /// not serialize to an object, or has a key that is not a valid parameter name.
+    pub fn with_parameters(mut self, parameters: &impl Serialize) -> crate::Result<Self> {
+        let serde_json::Value::Object(fields) = serde_json::to_value(parameters)? else {
+            return Err(invalid_parameters(
+                "query parameters must serialize to a JSON object".to_string(),
+            ));
+        };
+
+        let mut new_parameters = Vec::with_capacity(fields.len());
+        for (key, value) in fields {
+            let name = if key.starts_with('@') {
+                key
+            } else {
+                format!("@{key}")
+            };
+            if !is_valid_parameter_name(&name) {
+                return Err(invalid_parameters(format!(
+                    "'{name}' is not a valid query parameter name"
+                )));
+            }
+            new_parameters.push(QueryParameter { name, value });
+        }
+        self.parameters.extend(new_parameters);
+
+        Ok(self)
+    }
+
     /// Consumes this [`Query`] instance, replaces its text with the provided value, and returns it.
     pub fn with_text(mut self, text: impl Into<String>) -> Self {
         self.text = text.into();
@@ -176,6 +224,24 @@ impl<T: Into<String>> From<T> for Query {
     }
 }
 
+/// Returns `true` if `name` is `@` followed by an identifier.
+fn is_valid_parameter_name(name: &str) -> bool {
+    let mut chars = name.chars();
+    chars.next() == Some('@')
+        && chars
+            .next()
+            .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+fn invalid_parameters(message: String) -> crate::CosmosError {
+    crate::DriverCosmosError::builder()
+        .with_status(crate::error::CosmosStatus::CLIENT_BAD_REQUEST)
+        .with_message(message)
+        .build()
+        .into()
+}
+
 /// Represents a single parameter in a Cosmos DB query.
 #[derive(Clone, Debug, Serialize)]
 struct QueryParameter {
@@ -242,6 +308,50 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    pub fn with_parameters_adds_object_fields() -> Result<(), Box<dyn Error>> {
+        #[derive(Serialize)]
+        struct Parameters {
+            category: &'static str,
+            max_price: Option<f64>,
+        }
+
+        let query = Query::from("SELECT * FROM c")
+            .with_parameter("@id", 42)?
+            .with_parameters(&Parameters {
+                category: "gear",
+                max_price: None,
+            })?
+            .with_parameters(&std::collections::BTreeMap::from([("@tag", ["a", "b"])]))?;
+
+        let serialized = serde_json::to_string(&query).unwrap();
+        assert_eq!(
+            serialized,
+            r#"{"query":"SELECT * FROM c","parameters":[{"name":"@id","value":42},{"name":"@category","value":"gear"},{"name":"@max_price","value":null},{"name":"@tag","value":["a","b"]}]}"#
+        );
+        Ok(())
+    }
+
+    #[test]
+    pub fn with_parameters_rejects_invalid_input() {
+        let not_an_object = Query::from("SELECT * FROM c").with_parameters(&[1, 2]);
+        assert!(not_an_object.is_err());
+
+        let invalid_name =
+            Query::from("SELECT * FROM c").with_parameters(&std::collections::BTreeMap::from([
+                ("ok", 1),
+                ("not valid", 2),
+            ]));
+        assert!(invalid_name
+            .unwrap_err()
+            .to_string()
+            .contains("'@not valid' is not a valid query parameter name"));
+
+        let empty_name = Query::from("SELECT * FROM c")
+            .with_parameters(&std::collections::BTreeMap::from([("@", 1)]));
+        assert!(empty_name.is_err());
+    }
+
     #[test]
     pub fn with_text_replaces_query_text() {
         let query = Query::from("SELECT * FROM c").with_text("SELECT c.id FROM c".to_string());
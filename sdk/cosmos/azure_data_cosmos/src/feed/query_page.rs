@@ -11,13 +11,13 @@ use serde::de::DeserializeOwned;
 use crate::{
     diagnostics::DiagnosticsContext,
     feed::page::{FeedBody, FeedPage},
-    models::{CosmosResponse, ResponseHeaders},
+    models::{CosmosResponse, IndexMetrics, QueryMetrics, ResponseHeaders},
 };
 
 /// Represents a single page of results from a Cosmos DB query.
 ///
 /// Wraps a [`FeedPage`] and adds query-specific metadata such as
-/// [`index_metrics()`](Self::index_metrics) and [`query_metrics()`](Self::query_metrics).
+/// [`index_utilization()`](Self::index_utilization) and [`query_metrics()`](Self::query_metrics).
 ///
 /// This type is yielded by [`QueryItemIterator`](crate::feed::QueryItemIterator)
 /// and [`QueryPageIterator`](crate::feed::QueryPageIterator) for query operations.
@@ -29,8 +29,14 @@ pub struct QueryFeedPage<T> {
     /// Index utilization metrics (decoded from base64 JSON).
     index_metrics: Option<String>,
 
+    /// [`index_metrics`](Self::index_metrics) parsed into the typed model.
+    index_utilization: Option<IndexMetrics>,
+
     /// Query execution metrics (semicolon-delimited key=value pairs).
     query_metrics: Option<String>,
+
+    /// [`query_metrics`](Self::query_metrics) parsed into the typed model.
+    execution_metrics: Option<QueryMetrics>,
 }
 
 impl<T> QueryFeedPage<T> {
@@ -78,6 +84,14 @@ impl<T> QueryFeedPage<T> {
         self.index_metrics.as_deref()
     }
 
+    /// Returns the index utilization metrics, if available.
+    ///
+    /// This is [`index_metrics()`](Self::index_metrics) parsed into an [`IndexMetrics`].
+    /// Returns `None` if the metrics were not requested or could not be parsed.
+    pub fn index_utilization(&self) -> Option<&IndexMetrics> {
+        self.index_utilization.as_ref()
+    }
+
     /// Returns the query execution metrics, if available.
     ///
     /// The value is a semicolon-delimited string of key=value pairs.
@@ -85,6 +99,14 @@ impl<T> QueryFeedPage<T> {
     pub fn query_metrics(&self) -> Option<&str> {
         self.query_metrics.as_deref()
     }
+
+    /// Returns the query execution metrics for this page, if available.
+    ///
+    /// This is [`query_metrics()`](Self::query_metrics) parsed into a [`QueryMetrics`].
+    /// Returns `None` if the metrics were not requested or could not be parsed.
+    pub fn execution_metrics(&self) -> Option<&QueryMetrics> {
+        self.execution_metrics.as_ref()
+    }
 }
 
 impl<T: DeserializeOwned> QueryFeedPage<T> {
@@ -95,7 +117,9 @@ impl<T: DeserializeOwned> QueryFeedPage<T> {
         let cosmos_headers: CosmosResponseHeaders =
             crate::models::into_driver_headers(response.cosmos_headers().clone());
         let index_metrics = cosmos_headers.index_metrics.clone();
+        let index_utilization = index_metrics.as_deref().and_then(parse_index_metrics);
         let query_metrics = cosmos_headers.query_metrics.clone();
+        let execution_metrics = query_metrics.as_deref().and_then(parse_query_metrics);
         let diagnostics = response.diagnostics();
         let body: FeedBody<T> = response.into_model()?;
 
@@ -106,11 +130,29 @@ impl<T: DeserializeOwned> QueryFeedPage<T> {
                 diagnostics,
             ),
             index_metrics,
+            index_utilization,
             query_metrics,
+            execution_metrics,
         })
     }
 }
 
+/// Parses decoded index utilization JSON, logging rather than failing the page on error.
+fn parse_index_metrics(json: &str) -> Option<IndexMetrics> {
+    serde_json::from_str(json)
+        .inspect_err(|error| tracing::debug!(%error, "ignoring unparseable index metrics"))
+        .ok()
+}
+
+/// Parses a query metrics header value, logging rather than failing the page on error.
+fn parse_query_metrics(value: &str) -> Option<QueryMetrics> {
+    let metrics = QueryMetrics::parse(value);
+    if metrics.is_none() {
+        tracing::debug!("ignoring unparseable query metrics");
+    }
+    metrics
+}
+
 #[cfg(test)]
 impl<T> QueryFeedPage<T> {
     /// Test-only constructor used by the iterator unit tests in this crate.
@@ -122,7 +164,15 @@ impl<T> QueryFeedPage<T> {
         Self {
             page: FeedPage::new(items, headers, diagnostics),
             index_metrics: None,
+            index_utilization: None,
             query_metrics: None,
+            execution_metrics: None,
         }
     }
+
+    /// Test-only: attaches parsed query metrics to this page.
+    pub(crate) fn with_execution_metrics_for_testing(mut self, metrics: QueryMetrics) -> Self {
+        self.execution_metrics = Some(metrics);
+        self
+    }
 }
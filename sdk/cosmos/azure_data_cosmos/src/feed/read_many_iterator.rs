@@ -0,0 +1,199 @@
+// Copyright (c) Microsoft Corporation. All rights reserved.
+// Licensed under the MIT License.
+
+//! [`ReadManyItemIterator`] — async item stream for reading many items by id and partition key.
+//!
+//! Requested items are grouped by partition key and each group is read with one single-partition
+//! query per [`MAX_IDS_PER_QUERY`] ids, so reading N items costs a handful of queries instead of
+//! N point reads. Queries run concurrently up to
+//! [`ReadManyOptions::max_concurrency`](crate::options::ReadManyOptions::max_concurrency).
+
+use std::{collections::HashMap, marker::PhantomData, pin::Pin, task};
+
+use futures::{
+    stream::{self, BoxStream},
+    Stream, StreamExt,
+};
+use serde::de::DeserializeOwned;
+
+use crate::{
+    clients::ContainerClient,
+    feed::{FeedScope, Query},
+    options::{QueryOptions, ReadManyOptions},
+    PartitionKey,
+};
+
+/// The maximum number of ids read by one query.
+const MAX_IDS_PER_QUERY: usize = 256;
+
+/// A stream of the items read by
+/// [`ContainerClient::read_many_items()`](crate::clients::ContainerClient::read_many_items()).
+///
+/// Items are yielded in completion order, not request order. Items that do not exist are
+/// omitted rather than reported as errors. A failed query yields one `Err` in place of the
+/// items it would have returned. Queries are only sent while the stream is polled.
+pub struct ReadManyItemIterator<T> {
+    stream: BoxStream<'static, crate::Result<T>>,
+    _marker: PhantomData<fn() -> T>,
+}
+
+impl<T: DeserializeOwned + Send + 'static> ReadManyItemIterator<T> {
+    pub(crate) fn new(
+        container: ContainerClient,
+        items: Vec<(String, PartitionKey)>,
+        options: ReadManyOptions,
+    ) -> Self {
+        let max_concurrency = options.max_concurrency.get();
+        let stream = stream::iter(plan_queries(items))
+            .map(move |(partition_key, ids)| {
+                read_group::<T>(container.clone(), partition_key, ids, options.clone())
+            })
+            .buffer_unordered(max_concurrency)
+            .flat_map(stream::iter);
+        Self {
+            stream: Box::pin(stream),
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<T> Stream for ReadManyItemIterator<T> {
+    type Item = crate::Result<T>;
+
+    fn poll_next(
+        mut self: Pin<&mut Self>,
+        cx: &mut task::Context<'_>,
+    ) -> task::Poll<Option<Self::Item>> {
+        self.stream.poll_next_unpin(cx)
+    }
+}
+
+/// Groups the requested ids by partition key, splitting each group at [`MAX_IDS_PER_QUERY`].
+fn plan_queries(items: Vec<(String, PartitionKey)>) -> Vec<(PartitionKey, Vec<String>)> {
+    let mut groups: Vec<(PartitionKey, Vec<String>)> = Vec::new();
+    let mut group_indices = HashMap::new();
+    for (id, partition_key) in items {
+        let group = *group_indices
+            .entry(partition_key.clone())
+            .or_insert_with(|| {
+                groups.push((partition_key, Vec::new()));
+                groups.len() - 1
+            });
+        groups[group].1.push(id);
+    }
+
+    groups
+        .into_iter()
+        .flat_map(|(partition_key, ids)| {
+            ids.chunks(MAX_IDS_PER_QUERY)
+                .map(|chunk| (partition_key.clone(), chunk.to_vec()))
+                .collect::<Vec<_>>()
+        })
+        .collect()
+}
+
+/// Builds the query that selects the items with the given `ids`.
+fn build_query(ids: Vec<String>) -> crate::Result<Query> {
+    let names: Vec<_> = (0..ids.len()).map(|i| format!("@id{i}")).collect();
+    let mut query = Query::from(format!(
+        "SELECT * FROM c WHERE c.id IN ({})",
+        names.join(", ")
+    ));
+    for (name, id) in names.into_iter().zip(ids) {
+        query = query.with_parameter(name, id)?;
+    }
+    Ok(query)
+}
+
+/// Reads the items with the given `ids` from one logical partition.
+async fn read_group<T: DeserializeOwned + Send + 'static>(
+    container: ContainerClient,
+    partition_key: PartitionKey,
+    ids: Vec<String>,
+    options: ReadManyOptions,
+) -> Vec<crate::Result<T>> {
+    let mut query_options = QueryOptions::default().with_operation_options(options.operation);
+    if let Some(session_token) = options.session_token {
+        query_options = query_options.with_session_token(session_token);
+    }
+    let query = match build_query(ids) {
+        Ok(query) => query,
+        Err(error) => return vec![Err(error)],
+    };
+    let mut items = match container
+        .query_items::<T>(
+            query,
+            FeedScope::partition(partition_key),
+            Some(query_options),
+        )
+        .await
+    {
+        Ok(items) => items,
+        Err(error) => return vec![Err(error)],
+    };
+
+    let mut results = Vec::new();
+    while let Some(item) = items.next().await {
+        let failed = item.is_err();
+        results.push(item);
+        if failed {
+            break;
+        }
+    }
+    results
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn plan_queries_groups_by_partition_key() {
+        let items = (0..6)
+            .map(|i| (i.to_string(), PartitionKey::from(format!("pk{}", i % 2))))
+            .collect();
+
+        let queries = plan_queries(items);
+
+        assert_eq!(
+            vec![
+                (PartitionKey::from("pk0"), vec!["0", "2", "4"]),
+                (PartitionKey::from("pk1"), vec!["1", "3", "5"]),
+            ],
+            queries
+                .iter()
+                .map(|(pk, ids)| (pk.clone(), ids.iter().map(String::as_str).collect()))
+                .collect::<Vec<(PartitionKey, Vec<&str>)>>()
+        );
+    }
+
+    #[test]
+    fn plan_queries_limits_ids_per_query() {
+        let items = (0..600)
+            .map(|i| (i.to_string(), PartitionKey::from("pk")))
+            .collect();
+
+        let sizes: Vec<_> = plan_queries(items)
+            .iter()
+            .map(|(_, ids)| ids.len())
+            .collect();
+
+        assert_eq!(vec![256, 256, 88], sizes);
+    }
+
+    #[test]
+    fn build_query_parameterizes_ids() {
+        let query = build_query(vec!["a".to_string(), "b".to_string()]).unwrap();
+
+        assert_eq!(
+            serde_json::json!({
+                "query": "SELECT * FROM c WHERE c.id IN (@id0, @id1)",
+                "parameters": [
+                    { "name": "@id0", "value": "a" },
+                    { "name": "@id1", "value": "b" },
+                ],
+            }),
+            serde_json::to_value(&query).unwrap()
+        );
+    }
+}
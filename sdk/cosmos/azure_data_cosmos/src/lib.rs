@@ -15,7 +15,7 @@ pub use clients::{ContainerClient, CosmosClient, CosmosClientBuilder, DatabaseCl
 #[cfg(feature = "preview_dtx")]
 pub use clients::{DistributedReadTransaction, DistributedWriteTransaction};
 pub use credential::CosmosCredential;
-pub use error::{CosmosError, CosmosStatus, Result, SubStatusCode};
+pub use error::{CosmosError, CosmosErrorExt, CosmosStatus, Result, SubStatusCode};
 pub use feed::{FeedScope, Query};
 pub use models::{PartitionKey, TransactionalBatch};
 pub use options::RoutingStrategy;
@@ -0,0 +1,192 @@
+// Copyright (c) Microsoft Corporation. All rights reserved.
+// Licensed under the MIT License.
+
+//! [`BulkOperation`] and the [`BulkOperationResult`] its execution returns.
+
+use crate::models::{TransactionalBatchOperation, TransactionalBatchOperationResult};
+use crate::options::{
+    BatchDeleteOptions, BatchReadOptions, BatchReplaceOptions, BatchUpsertOptions,
+};
+use crate::PartitionKey;
+use azure_core::fmt::SafeDebug;
+use serde::Serialize;
+use std::borrow::Cow;
+
+/// A single item operation submitted to
+/// [`ContainerClient::execute_bulk()`](crate::clients::ContainerClient::execute_bulk()).
+///
+/// Unlike the operations of a [`TransactionalBatch`](crate::TransactionalBatch), bulk
+/// operations may target different partition keys and succeed or fail independently.
+///
+/// # Examples
+///
+/// ```rust
+/// use azure_data_cosmos::models::BulkOperation;
+/// use serde::Serialize;
+///
+/// #[derive(Serialize)]
+/// struct Product {
+///     id: String,
+///     category: String,
+/// }
+///
+/// # fn doc() -> Result<(), Box<dyn std::error::Error>> {
+/// let product = Product {
+///     id: "product1".to_string(),
+///     category: "category1".to_string(),
+/// };
+/// let operation = BulkOperation::upsert_item("category1", product, None)?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, SafeDebug)]
+#[safe(true)]
+pub struct BulkOperation {
+    partition_key: PartitionKey,
+    operation: TransactionalBatchOperation,
+}
+
+impl BulkOperation {
+    /// Creates an operation that creates an item.
+    ///
+    /// # Arguments
+    /// * `partition_key` - The partition key of the item.
+    /// * `item` - The item to create. Must implement [`Serialize`].
+    pub fn create_item<T: Serialize>(
+        partition_key: impl Into<PartitionKey>,
+        item: T,
+    ) -> crate::Result<Self> {
+        Ok(Self::new(
+            partition_key,
+            TransactionalBatchOperation::create(item)?,
+        ))
+    }
+
+    /// Creates an operation that upserts an item.
+    ///
+    /// # Arguments
+    /// * `partition_key` - The partition key of the item.
+    /// * `item` - The item to upsert. Must implement [`Serialize`].
+    /// * `options` - Optional conditional options for the operation.
+    pub fn upsert_item<T: Serialize>(
+        partition_key: impl Into<PartitionKey>,
+        item: T,
+        options: Option<BatchUpsertOptions>,
+    ) -> crate::Result<Self> {
+        Ok(Self::new(
+            partition_key,
+            TransactionalBatchOperation::upsert(item, options)?,
+        ))
+    }
+
+    /// Creates an operation that replaces an item.
+    ///
+    /// # Arguments
+    /// * `partition_key` - The partition key of the item.
+    /// * `item_id` - The id of the item to replace.
+    /// * `item` - The new item data. Must implement [`Serialize`].
+    /// * `options` - Optional conditional options for the operation.
+    pub fn replace_item<T: Serialize>(
+        partition_key: impl Into<PartitionKey>,
+        item_id: impl Into<Cow<'static, str>>,
+        item: T,
+        options: Option<BatchReplaceOptions>,
+    ) -> crate::Result<Self> {
+        Ok(Self::new(
+            partition_key,
+            TransactionalBatchOperation::replace(item_id, item, options)?,
+        ))
+    }
+
+    /// Creates an operation that reads an item.
+    ///
+    /// # Arguments
+    /// * `partition_key` - The partition key of the item.
+    /// * `item_id` - The id of the item to read.
+    /// * `options` - Optional conditional options for the operation.
+    pub fn read_item(
+        partition_key: impl Into<PartitionKey>,
+        item_id: impl Into<Cow<'static, str>>,
+        options: Option<BatchReadOptions>,
+    ) -> Self {
+        Self::new(
+            partition_key,
+            TransactionalBatchOperation::read(item_id, options),
+        )
+    }
+
+    /// Creates an operation that deletes an item.
+    ///
+    /// # Arguments
+    /// * `partition_key` - The partition key of the item.
+    /// * `item_id` - The id of the item to delete.
+    /// * `options` - Optional conditional options for the operation.
+    pub fn delete_item(
+        partition_key: impl Into<PartitionKey>,
+        item_id: impl Into<Cow<'static, str>>,
+        options: Option<BatchDeleteOptions>,
+    ) -> Self {
+        Self::new(
+            partition_key,
+            TransactionalBatchOperation::delete(item_id, options),
+        )
+    }
+
+    /// Returns the partition key this operation targets.
+    pub fn partition_key(&self) -> &PartitionKey {
+        &self.partition_key
+    }
+
+    fn new(partition_key: impl Into<PartitionKey>, operation: TransactionalBatchOperation) -> Self {
+        Self {
+            partition_key: partition_key.into(),
+            operation,
+        }
+    }
+
+    pub(crate) fn into_parts(self) -> (PartitionKey, TransactionalBatchOperation) {
+        (self.partition_key, self.operation)
+    }
+}
+
+/// The result of a single [`BulkOperation`].
+///
+/// Results are returned in completion order, not submission order. Use
+/// [`index`](Self::index) to match a result to its operation.
+#[derive(Clone, SafeDebug)]
+#[safe(true)]
+#[non_exhaustive]
+pub struct BulkOperationResult {
+    index: usize,
+    result: crate::Result<TransactionalBatchOperationResult>,
+}
+
+impl BulkOperationResult {
+    pub(crate) fn new(
+        index: usize,
+        result: crate::Result<TransactionalBatchOperationResult>,
+    ) -> Self {
+        Self { index, result }
+    }
+
+    /// Returns the position of the operation in the sequence passed to
+    /// [`ContainerClient::execute_bulk()`](crate::clients::ContainerClient::execute_bulk()).
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Returns the outcome of the operation.
+    ///
+    /// `Ok` holds the service's per-operation result, which may itself report a
+    /// failure such as a conflict or a precondition failure; check
+    /// [`is_success`](TransactionalBatchOperationResult::is_success). `Err` means
+    /// the batch request carrying the operation failed as a whole.
+    pub fn result(&self) -> Result<&TransactionalBatchOperationResult, &crate::CosmosError> {
+        self.result.as_ref()
+    }
+
+    /// Consumes this result and returns the outcome of the operation.
+    pub fn into_result(self) -> crate::Result<TransactionalBatchOperationResult> {
+        self.result
+    }
+}
@@ -135,6 +135,12 @@ pub struct ContainerProperties {
     #[serde(skip_serializing_if = "TimeToLive::is_forever")]
     pub analytical_storage_ttl: TimeToLive,
 
+    /// The geospatial configuration for the container.
+    ///
+    /// When not set, the service treats spatial data as [`Geography`](GeospatialType::Geography).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub geospatial_config: Option<GeospatialConfig>,
+
     /// A [`SystemProperties`] object containing common system properties for the container.
     #[serde(flatten)]
     pub system_properties: SystemProperties,
@@ -151,6 +157,7 @@ impl ContainerProperties {
             vector_embedding_policy: None,
             default_ttl: TimeToLive::Forever,
             analytical_storage_ttl: TimeToLive::Forever,
+            geospatial_config: None,
             system_properties: SystemProperties::default(),
         }
     }
@@ -193,6 +200,47 @@ impl ContainerProperties {
         self.analytical_storage_ttl = analytical_storage_ttl.into();
         self
     }
+
+    pub fn with_geospatial_config(mut self, geospatial_config: GeospatialConfig) -> Self {
+        self.geospatial_config = Some(geospatial_config);
+        self
+    }
+}
+
+/// Represents the geospatial configuration for a container.
+///
+/// For more information see <https://learn.microsoft.com/azure/cosmos-db/nosql/query/geospatial-index>
+#[derive(Clone, SafeDebug, Deserialize, Serialize, PartialEq, Eq)]
+#[safe(true)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct GeospatialConfig {
+    /// The coordinate system in which the container's spatial data is interpreted.
+    #[serde(rename = "type")] // "type" is a reserved word in Rust.
+    pub geospatial_type: GeospatialType,
+}
+
+impl GeospatialConfig {
+    /// Creates a new [`GeospatialConfig`] with the given geospatial type.
+    pub fn new(geospatial_type: GeospatialType) -> Self {
+        Self { geospatial_type }
+    }
+}
+
+/// Defines the coordinate systems in which spatial data can be interpreted.
+#[derive(Clone, SafeDebug, Deserialize, Serialize, PartialEq, Eq)]
+#[safe(true)]
+#[serde(rename_all = "PascalCase")]
+#[non_exhaustive]
+pub enum GeospatialType {
+    /// Data is interpreted as round-earth latitude and longitude coordinates.
+    Geography,
+
+    /// Data is interpreted as coordinates on a flat Euclidean plane.
+    ///
+    /// Every [`SpatialIndex`](crate::models::SpatialIndex) in a geometry container must set a
+    /// [`BoundingBox`](crate::models::BoundingBox).
+    Geometry,
 }
 
 /// Represents the vector embedding policy for a container.
@@ -413,7 +461,10 @@ mod tests {
     use serde::{Deserialize, Serialize};
 
     use super::TimeToLive;
-    use crate::models::ContainerProperties;
+    use crate::models::{
+        BoundingBox, ContainerProperties, GeospatialConfig, GeospatialType, IndexingPolicy,
+        SpatialIndex, SpatialType,
+    };
 
     #[derive(Debug, Deserialize, Serialize)]
     struct TtlHolder {
@@ -534,6 +585,37 @@ mod tests {
         assert_eq!(TimeToLive::NoDefault, props.analytical_storage_ttl);
     }
 
+    #[test]
+    fn serialize_geometry_container_properties() {
+        let properties = ContainerProperties::new("MyContainer", "/partitionKey".into())
+            .with_geospatial_config(GeospatialConfig::new(GeospatialType::Geometry))
+            .with_indexing_policy(
+                IndexingPolicy::default().with_spatial_index(
+                    SpatialIndex::new("/shape/*")
+                        .with_type(SpatialType::Polygon)
+                        .with_bounding_box(BoundingBox::new(0.0, 0.0, 100.0, 100.0)),
+                ),
+            );
+        let json: serde_json::Value = serde_json::to_value(&properties).unwrap();
+
+        assert_eq!(
+            serde_json::json!({ "type": "Geometry" }),
+            json["geospatialConfig"]
+        );
+        assert_eq!(
+            serde_json::json!([{
+                "path": "/shape/*",
+                "types": ["Polygon"],
+                "boundingBox": { "xmin": 0.0, "ymin": 0.0, "xmax": 100.0, "ymax": 100.0 },
+            }]),
+            json["indexingPolicy"]["spatialIndexes"]
+        );
+        assert_eq!(
+            properties,
+            serde_json::from_value::<ContainerProperties>(json).unwrap()
+        );
+    }
+
     #[test]
     pub fn container_properties_default_serialization() {
         // This test asserts that the default value serializes the same way across SDK versions.
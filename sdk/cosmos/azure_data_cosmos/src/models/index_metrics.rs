@@ -0,0 +1,134 @@
+// Copyright (c) Microsoft Corporation. All rights reserved.
+// Licensed under the MIT License.
+
+//! [`IndexMetrics`] — index utilization reported for a query page.
+
+use azure_core::fmt::SafeDebug;
+use serde::{Deserialize, Serialize};
+
+/// Index utilization metrics for a page of query results.
+///
+/// Returned by [`QueryFeedPage::index_utilization()`](crate::feed::QueryFeedPage::index_utilization())
+/// when the query was run with
+/// [`QueryOptions::with_populate_index_metrics`](crate::options::QueryOptions::with_populate_index_metrics).
+/// Lists the indexes the query used and the indexes that, if added to the indexing
+/// policy, could make it cheaper.
+#[derive(Clone, Default, SafeDebug, Deserialize, Serialize, PartialEq, Eq)]
+#[safe(true)]
+#[serde(rename_all = "PascalCase")]
+#[non_exhaustive]
+pub struct IndexMetrics {
+    /// Single-path indexes the query used.
+    #[serde(default)]
+    pub utilized_single_indexes: Vec<SingleIndexMetrics>,
+
+    /// Single-path indexes that the query could have used had they been indexed.
+    #[serde(default)]
+    pub potential_single_indexes: Vec<SingleIndexMetrics>,
+
+    /// Composite indexes the query used.
+    #[serde(default)]
+    pub utilized_composite_indexes: Vec<CompositeIndexMetrics>,
+
+    /// Composite indexes that the query could have used had they been defined.
+    #[serde(default)]
+    pub potential_composite_indexes: Vec<CompositeIndexMetrics>,
+}
+
+/// Utilization of a single-path index.
+#[derive(Clone, Default, SafeDebug, Deserialize, Serialize, PartialEq, Eq)]
+#[safe(true)]
+#[serde(rename_all = "PascalCase")]
+#[non_exhaustive]
+pub struct SingleIndexMetrics {
+    /// The query filter expression the index applies to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub filter_expression: Option<String>,
+
+    /// The index path, such as `/name/?`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub index_spec: Option<String>,
+
+    /// Whether the filter expression is evaluated precisely.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub filter_precise_set: Option<bool>,
+
+    /// Whether the index is precise for the filter.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub index_precise_set: Option<bool>,
+
+    /// The service's estimate of the index's impact on the query, such as `High` or `Low`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub index_impact_score: Option<String>,
+}
+
+/// Utilization of a composite index.
+#[derive(Clone, Default, SafeDebug, Deserialize, Serialize, PartialEq, Eq)]
+#[safe(true)]
+#[serde(rename_all = "PascalCase")]
+#[non_exhaustive]
+pub struct CompositeIndexMetrics {
+    /// The index paths and their order, such as `/name ASC`.
+    #[serde(default)]
+    pub index_specs: Vec<String>,
+
+    /// Whether the index is precise for the query.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub index_precise_set: Option<bool>,
+
+    /// The service's estimate of the index's impact on the query, such as `High` or `Low`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub index_impact_score: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deserializes_service_payload() {
+        let metrics: IndexMetrics = serde_json::from_str(
+            r#"{
+                "UtilizedSingleIndexes": [{
+                    "FilterExpression": "",
+                    "IndexSpec": "/category/?",
+                    "FilterPreciseSet": true,
+                    "IndexPreciseSet": true,
+                    "IndexImpactScore": "High"
+                }],
+                "PotentialSingleIndexes": [],
+                "UtilizedCompositeIndexes": [],
+                "PotentialCompositeIndexes": [{
+                    "IndexSpecs": ["/category ASC", "/price DESC"],
+                    "IndexPreciseSet": false,
+                    "IndexImpactScore": "Low"
+                }]
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(1, metrics.utilized_single_indexes.len());
+        assert_eq!(
+            Some("/category/?"),
+            metrics.utilized_single_indexes[0].index_spec.as_deref()
+        );
+        assert_eq!(
+            Some("High"),
+            metrics.utilized_single_indexes[0]
+                .index_impact_score
+                .as_deref()
+        );
+        assert!(metrics.potential_single_indexes.is_empty());
+        assert_eq!(
+            vec!["/category ASC", "/price DESC"],
+            metrics.potential_composite_indexes[0].index_specs
+        );
+    }
+
+    #[test]
+    fn missing_lists_default_to_empty() {
+        let metrics: IndexMetrics = serde_json::from_str("{}").unwrap();
+
+        assert_eq!(IndexMetrics::default(), metrics);
+    }
+}
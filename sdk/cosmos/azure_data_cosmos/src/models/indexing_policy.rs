@@ -124,6 +124,15 @@ pub struct SpatialIndex {
 
     /// The spatial types used in this index
     pub types: Vec<SpatialType>,
+
+    /// The extent of the coordinate space covered by this index.
+    ///
+    /// Required for containers whose [`GeospatialType`](crate::models::GeospatialType) is
+    /// [`Geometry`](crate::models::GeospatialType::Geometry), and must be omitted for
+    /// [`Geography`](crate::models::GeospatialType::Geography) containers.
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bounding_box: Option<BoundingBox>,
 }
 
 impl SpatialIndex {
@@ -132,6 +141,7 @@ impl SpatialIndex {
         Self {
             path: path.into(),
             types: Vec::new(),
+            bounding_box: None,
         }
     }
 
@@ -140,8 +150,49 @@ impl SpatialIndex {
         self.types.push(spatial_type);
         self
     }
+
+    /// Sets the bounding box of this index.
+    pub fn with_bounding_box(mut self, bounding_box: BoundingBox) -> Self {
+        self.bounding_box = Some(bounding_box);
+        self
+    }
+}
+
+/// The rectangular extent of the coordinate space covered by a geometry [`SpatialIndex`].
+///
+/// Geometry data outside the bounding box is still stored, but is not indexed.
+#[derive(Clone, Copy, SafeDebug, Deserialize, Serialize, PartialEq)]
+#[safe(true)]
+#[non_exhaustive]
+pub struct BoundingBox {
+    /// The minimum x coordinate.
+    pub xmin: f64,
+
+    /// The minimum y coordinate.
+    pub ymin: f64,
+
+    /// The maximum x coordinate.
+    pub xmax: f64,
+
+    /// The maximum y coordinate.
+    pub ymax: f64,
+}
+
+impl BoundingBox {
+    /// Creates a new [`BoundingBox`] from its minimum and maximum coordinates.
+    pub fn new(xmin: f64, ymin: f64, xmax: f64, ymax: f64) -> Self {
+        Self {
+            xmin,
+            ymin,
+            xmax,
+            ymax,
+        }
+    }
 }
 
+// Coordinates come from JSON numbers, which cannot be NaN, so equality is total.
+impl Eq for BoundingBox {}
+
 /// Defines the types of spatial data that can be indexed.
 #[derive(Clone, SafeDebug, Deserialize, Serialize, PartialEq, Eq)]
 #[safe(true)]
@@ -277,8 +328,8 @@ pub enum VectorIndexType {
 #[cfg(test)]
 mod tests {
     use crate::models::{
-        CompositeIndex, CompositeIndexOrder, CompositeIndexProperty, IndexingMode, IndexingPolicy,
-        PropertyPath, SpatialIndex, SpatialType, VectorIndex, VectorIndexType,
+        BoundingBox, CompositeIndex, CompositeIndexOrder, CompositeIndexProperty, IndexingMode,
+        IndexingPolicy, PropertyPath, SpatialIndex, SpatialType, VectorIndex, VectorIndexType,
     };
 
     #[test]
@@ -373,7 +424,8 @@ mod tests {
                         SpatialType::Polygon,
                         SpatialType::MultiPolygon,
                         SpatialType::LineString,
-                    ]
+                    ],
+                    bounding_box: None,
                 }],
                 composite_indexes: vec![
                     CompositeIndex {
@@ -441,10 +493,12 @@ mod tests {
                         SpatialType::MultiPolygon,
                         SpatialType::LineString,
                     ],
+                    bounding_box: None,
                 },
                 SpatialIndex {
                     path: "/path/to/geojson/property2/?".to_string(),
                     types: vec![],
+                    bounding_box: None,
                 },
             ],
             composite_indexes: vec![
@@ -480,4 +534,18 @@ mod tests {
             json
         );
     }
+
+    #[test]
+    pub fn spatial_index_bounding_box_round_trip() {
+        let index = SpatialIndex::new("/location/?")
+            .with_type(SpatialType::Polygon)
+            .with_bounding_box(BoundingBox::new(-10.0, -20.0, 10.5, 20.0));
+
+        let json = serde_json::to_string(&index).unwrap();
+        assert_eq!(
+            r#"{"path":"/location/?","types":["Polygon"],"boundingBox":{"xmin":-10.0,"ymin":-20.0,"xmax":10.5,"ymax":20.0}}"#,
+            json
+        );
+        assert_eq!(index, serde_json::from_str::<SpatialIndex>(&json).unwrap());
+    }
 }
@@ -11,23 +11,26 @@
 pub use azure_data_cosmos_driver::models::{
     ContainerReference, CosmosNumber, CosmosStatus, EffectivePartitionKey, PartitionKey,
     PartitionKeyDefinition, PartitionKeyKind, PartitionKeyValue, PartitionKeyVersion,
-    PatchInstructions, PatchOperation,
+    PatchInstructions, PatchOperation, ResourceTokens,
 };
 pub use batch_response::BatchResponse;
+pub use bulk::{BulkOperation, BulkOperationResult};
 pub use change_feed_item::{
     ChangeFeedItem, ChangeFeedMetadata, ChangeFeedOperationType, LogicalSequenceNumber,
 };
 pub use container_properties::{
-    ConflictResolutionMode, ConflictResolutionPolicy, ContainerProperties, TimeToLive, UniqueKey,
-    UniqueKeyPolicy, VectorDataType, VectorDistanceFunction, VectorEmbedding,
-    VectorEmbeddingPolicy,
+    ConflictResolutionMode, ConflictResolutionPolicy, ContainerProperties, GeospatialConfig,
+    GeospatialType, TimeToLive, UniqueKey, UniqueKeyPolicy, VectorDataType, VectorDistanceFunction,
+    VectorEmbedding, VectorEmbeddingPolicy,
 };
 pub use database_properties::DatabaseProperties;
+pub use index_metrics::{CompositeIndexMetrics, IndexMetrics, SingleIndexMetrics};
 pub use indexing_policy::{
-    CompositeIndex, CompositeIndexOrder, CompositeIndexProperty, IndexingMode, IndexingPolicy,
-    PropertyPath, SpatialIndex, SpatialType, VectorIndex, VectorIndexType,
+    BoundingBox, CompositeIndex, CompositeIndexOrder, CompositeIndexProperty, IndexingMode,
+    IndexingPolicy, PropertyPath, SpatialIndex, SpatialType, VectorIndex, VectorIndexType,
 };
 pub use item_response::ItemResponse;
+pub use query_metrics::QueryMetrics;
 pub use resource_response::ResourceResponse;
 pub use response_body::ResponseBody;
 pub use response_headers::ResponseHeaders;
@@ -36,6 +39,7 @@ pub use throughput_properties::ThroughputProperties;
 pub use transactional_batch::{
     TransactionalBatch, TransactionalBatchOperationResult, TransactionalBatchResponse,
 };
+pub use trigger_properties::{TriggerOperation, TriggerProperties, TriggerType};
 
 // =========================================================================
 // Crate-internal exports
@@ -43,21 +47,26 @@ pub use transactional_batch::{
 
 pub(crate) use cosmos_response::CosmosResponse;
 pub(crate) use response_headers::into_driver_headers;
+pub(crate) use transactional_batch::TransactionalBatchOperation;
 
 // =========================================================================
 // Internal modules
 // =========================================================================
 
 mod batch_response;
+mod bulk;
 mod change_feed_item;
 mod container_properties;
 mod cosmos_response;
 mod database_properties;
+mod index_metrics;
 mod indexing_policy;
 mod item_response;
+mod query_metrics;
 mod resource_response;
 mod response_body;
 mod response_headers;
 mod system_properties;
 mod throughput_properties;
 mod transactional_batch;
+mod trigger_properties;
@@ -0,0 +1,184 @@
+// Copyright (c) Microsoft Corporation. All rights reserved.
+// Licensed under the MIT License.
+
+//! [`QueryMetrics`] — execution metrics reported for a query.
+
+use azure_core::fmt::SafeDebug;
+use std::{ops::AddAssign, time::Duration};
+
+/// Execution metrics for a query.
+///
+/// Returned per page by
+/// [`QueryFeedPage::execution_metrics()`](crate::feed::QueryFeedPage::execution_metrics())
+/// and aggregated across pages by
+/// [`QueryPageIterator::query_metrics()`](crate::feed::QueryPageIterator::query_metrics())
+/// when the query was run with
+/// [`QueryOptions::with_populate_query_metrics`](crate::options::QueryOptions::with_populate_query_metrics).
+///
+/// Metrics from several pages or partitions are combined with `+=`: counts, sizes and
+/// times are summed, and the index hit ratio is weighted by retrieved document count.
+#[derive(Clone, Copy, Default, SafeDebug, PartialEq)]
+#[safe(true)]
+#[non_exhaustive]
+pub struct QueryMetrics {
+    /// The number of documents the query engine loaded.
+    pub retrieved_document_count: u64,
+
+    /// The total size, in bytes, of the documents the query engine loaded.
+    pub retrieved_document_size: u64,
+
+    /// The number of documents the query returned.
+    pub output_document_count: u64,
+
+    /// The total size, in bytes, of the documents the query returned.
+    pub output_document_size: u64,
+
+    /// The total time the service spent executing the query.
+    pub total_execution_time: Duration,
+
+    /// The time spent compiling, planning and optimizing the query.
+    pub query_preparation_time: Duration,
+
+    /// The time spent searching the index.
+    pub index_lookup_time: Duration,
+
+    /// The time spent loading documents.
+    pub document_load_time: Duration,
+
+    /// The time spent running the query in the query engine.
+    pub vm_execution_time: Duration,
+
+    /// The time spent in system and user-defined functions.
+    pub runtime_execution_time: Duration,
+
+    /// The time spent writing the query results.
+    pub document_write_time: Duration,
+
+    /// The number of retrieved documents that the index matched exactly.
+    index_hit_document_count: f64,
+}
+
+impl QueryMetrics {
+    /// Returns the fraction of retrieved documents that the index matched exactly, from `0.0` to `1.0`.
+    ///
+    /// A low ratio means the query loaded many documents only to filter them out; adding
+    /// an index for its filters usually makes it cheaper. Returns `1.0` when no documents
+    /// were retrieved.
+    pub fn index_hit_ratio(&self) -> f64 {
+        if self.retrieved_document_count == 0 {
+            1.0
+        } else {
+            (self.index_hit_document_count / self.retrieved_document_count as f64).clamp(0.0, 1.0)
+        }
+    }
+
+    /// Parses the `x-ms-documentdb-query-metrics` header value.
+    ///
+    /// The value is a semicolon-delimited list of `key=value` pairs. Unknown keys are ignored;
+    /// returns `None` if a value cannot be parsed.
+    pub(crate) fn parse(value: &str) -> Option<Self> {
+        let mut metrics = Self::default();
+        let mut index_utilization_ratio = 0.0;
+        for pair in value.split(';').filter(|pair| !pair.trim().is_empty()) {
+            let (key, value) = pair.split_once('=')?;
+            let value: f64 = value.trim().parse().ok()?;
+            if !value.is_finite() || value < 0.0 {
+                return None;
+            }
+            let time = || Duration::from_secs_f64(value / 1000.0);
+            match key.trim() {
+                "retrievedDocumentCount" => metrics.retrieved_document_count = value as u64,
+                "retrievedDocumentSize" => metrics.retrieved_document_size = value as u64,
+                "outputDocumentCount" => metrics.output_document_count = value as u64,
+                "outputDocumentSize" => metrics.output_document_size = value as u64,
+                "indexUtilizationRatio" => index_utilization_ratio = value,
+                "totalExecutionTimeInMs" => metrics.total_execution_time = time(),
+                "queryCompileTimeInMs"
+                | "queryLogicalPlanBuildTimeInMs"
+                | "queryPhysicalPlanBuildTimeInMs"
+                | "queryOptimizationTimeInMs" => metrics.query_preparation_time += time(),
+                "indexLookupTimeInMs" => metrics.index_lookup_time = time(),
+                "documentLoadTimeInMs" => metrics.document_load_time = time(),
+                "VMExecutionTimeInMs" => metrics.vm_execution_time = time(),
+                "systemFunctionExecuteTimeInMs" | "userFunctionExecuteTimeInMs" => {
+                    metrics.runtime_execution_time += time()
+                }
+                "writeOutputTimeInMs" => metrics.document_write_time = time(),
+                _ => {}
+            }
+        }
+        metrics.index_hit_document_count =
+            index_utilization_ratio * metrics.retrieved_document_count as f64;
+        Some(metrics)
+    }
+}
+
+impl AddAssign for QueryMetrics {
+    fn add_assign(&mut self, other: Self) {
+        self.retrieved_document_count += other.retrieved_document_count;
+        self.retrieved_document_size += other.retrieved_document_size;
+        self.output_document_count += other.output_document_count;
+        self.output_document_size += other.output_document_size;
+        self.total_execution_time += other.total_execution_time;
+        self.query_preparation_time += other.query_preparation_time;
+        self.index_lookup_time += other.index_lookup_time;
+        self.document_load_time += other.document_load_time;
+        self.vm_execution_time += other.vm_execution_time;
+        self.runtime_execution_time += other.runtime_execution_time;
+        self.document_write_time += other.document_write_time;
+        self.index_hit_document_count += other.index_hit_document_count;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PAGE: &str = "totalExecutionTimeInMs=2.50;queryCompileTimeInMs=0.10;\
+        queryLogicalPlanBuildTimeInMs=0.05;queryPhysicalPlanBuildTimeInMs=0.05;\
+        queryOptimizationTimeInMs=0.00;VMExecutionTimeInMs=1.50;indexLookupTimeInMs=0.50;\
+        documentLoadTimeInMs=0.75;systemFunctionExecuteTimeInMs=0.00;\
+        userFunctionExecuteTimeInMs=0.00;retrievedDocumentCount=100;\
+        retrievedDocumentSize=20000;outputDocumentCount=50;outputDocumentSize=10000;\
+        writeOutputTimeInMs=0.25;indexUtilizationRatio=0.50";
+
+    #[test]
+    fn parse_reads_known_keys() {
+        let metrics = QueryMetrics::parse(PAGE).unwrap();
+
+        assert_eq!(100, metrics.retrieved_document_count);
+        assert_eq!(20000, metrics.retrieved_document_size);
+        assert_eq!(50, metrics.output_document_count);
+        assert_eq!(10000, metrics.output_document_size);
+        assert_eq!(Duration::from_micros(2500), metrics.total_execution_time);
+        assert_eq!(Duration::from_micros(200), metrics.query_preparation_time);
+        assert_eq!(Duration::from_micros(500), metrics.index_lookup_time);
+        assert_eq!(Duration::from_micros(750), metrics.document_load_time);
+        assert_eq!(Duration::from_micros(1500), metrics.vm_execution_time);
+        assert_eq!(Duration::from_micros(250), metrics.document_write_time);
+        assert_eq!(0.5, metrics.index_hit_ratio());
+    }
+
+    #[test]
+    fn parse_rejects_malformed_values() {
+        assert_eq!(None, QueryMetrics::parse("retrievedDocumentCount=abc"));
+        assert_eq!(None, QueryMetrics::parse("retrievedDocumentCount"));
+        assert_eq!(
+            Some(QueryMetrics::default()),
+            QueryMetrics::parse("unknownKey=1;")
+        );
+    }
+
+    #[test]
+    fn add_assign_weights_index_hit_ratio() {
+        let mut metrics = QueryMetrics::parse(PAGE).unwrap();
+        metrics += QueryMetrics::parse(
+            "retrievedDocumentCount=300;indexUtilizationRatio=1.00;totalExecutionTimeInMs=1.5",
+        )
+        .unwrap();
+
+        assert_eq!(400, metrics.retrieved_document_count);
+        assert_eq!(Duration::from_millis(4), metrics.total_execution_time);
+        assert_eq!(0.875, metrics.index_hit_ratio());
+    }
+}
@@ -11,7 +11,8 @@ use crate::models::{CosmosResponse, ResponseBody, ResponseHeaders};
 use azure_core::fmt::SafeDebug;
 use serde::de::DeserializeOwned;
 
-/// A response from a resource management operation (databases, containers, throughput).
+/// A response from a resource management operation (databases, containers, throughput) or a
+/// stored procedure execution.
 ///
 /// Carries common Cosmos response metadata plus a type parameter `T` that names
 /// the model the body deserializes into. Unlike [`ItemResponse`](crate::models::ItemResponse)
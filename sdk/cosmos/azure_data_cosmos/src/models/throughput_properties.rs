@@ -10,6 +10,15 @@ use crate::models::SystemProperties;
 
 const OFFER_VERSION_2: &str = "V2";
 
+/// The throughput offer provisioned for a database or container.
+///
+/// Construct one with [`manual()`](Self::manual()) or [`autoscale()`](Self::autoscale()) to
+/// provision or replace throughput. Values read from the service report the provisioned
+/// throughput through [`throughput()`](Self::throughput()) for manual offers and
+/// [`autoscale_maximum()`](Self::autoscale_maximum()) for autoscale offers.
+///
+/// Throughput limits (such as minimum RU/s) are enforced by the service, not by these
+/// constructors, so that limit changes do not require an SDK update.
 #[derive(Clone, SafeDebug, Deserialize, Serialize)]
 #[safe(true)]
 #[serde(rename_all = "camelCase")]
@@ -28,6 +37,7 @@ pub struct ThroughputProperties {
 }
 
 impl ThroughputProperties {
+    /// Creates manual (fixed) throughput of `throughput` RU/s.
     pub fn manual(throughput: usize) -> ThroughputProperties {
         ThroughputProperties {
             resource: String::new(),
@@ -43,6 +53,11 @@ impl ThroughputProperties {
         }
     }
 
+    /// Creates autoscale throughput that scales up to `starting_maximum_throughput` RU/s.
+    ///
+    /// The service scales between 10% of the maximum and the maximum. When
+    /// `increment_percent` is set, the service raises the maximum by that percentage
+    /// whenever the stored data outgrows it.
     pub fn autoscale(
         starting_maximum_throughput: usize,
         increment_percent: Option<usize>,
@@ -68,14 +83,25 @@ impl ThroughputProperties {
         }
     }
 
+    /// Returns the provisioned manual throughput in RU/s.
+    ///
+    /// For an offer read from the service this is also set for autoscale offers, where it
+    /// reports the throughput the offer is currently scaled to.
     pub fn throughput(&self) -> Option<usize> {
         self.offer.offer_throughput
     }
 
+    /// Returns `true` if this is an autoscale offer.
+    pub fn is_autoscale(&self) -> bool {
+        self.offer.offer_autopilot_settings.is_some()
+    }
+
+    /// Returns the maximum throughput in RU/s of an autoscale offer.
     pub fn autoscale_maximum(&self) -> Option<usize> {
         Some(self.offer.offer_autopilot_settings.as_ref()?.max_throughput)
     }
 
+    /// Returns the auto-upgrade increment percentage of an autoscale offer, if any.
     pub fn autoscale_increment(&self) -> Option<usize> {
         Some(
             self.offer
@@ -181,4 +207,38 @@ mod tests {
             .expect("should have autopilot settings");
         assert_eq!(settings.max_throughput, 4000);
     }
+
+    #[test]
+    fn accessors_report_offer_kind() {
+        let manual = ThroughputProperties::manual(400);
+        assert!(!manual.is_autoscale());
+        assert_eq!(manual.throughput(), Some(400));
+        assert_eq!(manual.autoscale_maximum(), None);
+
+        let autoscale = ThroughputProperties::autoscale(4000, Some(10));
+        assert!(autoscale.is_autoscale());
+        assert_eq!(autoscale.throughput(), None);
+        assert_eq!(autoscale.autoscale_maximum(), Some(4000));
+        assert_eq!(autoscale.autoscale_increment(), Some(10));
+    }
+
+    #[test]
+    fn deserialized_autoscale_offer_reports_both_values() {
+        let offer: ThroughputProperties = serde_json::from_value(serde_json::json!({
+            "resource": "dbs/abc=/colls/def=/",
+            "content": {
+                "offerThroughput": 400,
+                "offerAutopilotSettings": { "maxThroughput": 4000 },
+            },
+            "id": "xyz1",
+            "offerResourceId": "def=",
+            "offerType": "Invalid",
+            "offerVersion": "V2",
+        }))
+        .unwrap();
+
+        assert!(offer.is_autoscale());
+        assert_eq!(offer.throughput(), Some(400));
+        assert_eq!(offer.autoscale_maximum(), Some(4000));
+    }
 }
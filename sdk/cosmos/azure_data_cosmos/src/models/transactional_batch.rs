@@ -109,11 +109,8 @@ impl TransactionalBatch {
     /// # }
     /// ```
     pub fn create_item<T: Serialize>(mut self, item: T) -> crate::Result<Self> {
-        let resource_body = serde_json::to_value(item)?;
-        self.operations.push(TransactionalBatchOperation::Create {
-            resource_body,
-            id: None,
-        });
+        self.operations
+            .push(TransactionalBatchOperation::create(item)?);
         Ok(self)
     }
 
@@ -127,19 +124,8 @@ impl TransactionalBatch {
         item: T,
         options: Option<BatchUpsertOptions>,
     ) -> crate::Result<Self> {
-        let resource_body = serde_json::to_value(item)?;
-        let (if_match, if_none_match) = match options.as_ref().and_then(|o| o.precondition.as_ref())
-        {
-            Some(Precondition::IfMatch(etag)) => (Some(etag.to_string()), None),
-            Some(Precondition::IfNoneMatch(etag)) => (None, Some(etag.to_string())),
-            None | Some(_) => (None, None),
-        };
-        self.operations.push(TransactionalBatchOperation::Upsert {
-            resource_body,
-            id: None,
-            if_match,
-            if_none_match,
-        });
+        self.operations
+            .push(TransactionalBatchOperation::upsert(item, options)?);
         Ok(self)
     }
 
@@ -155,16 +141,9 @@ impl TransactionalBatch {
         item: T,
         options: Option<BatchReplaceOptions>,
     ) -> crate::Result<Self> {
-        let resource_body = serde_json::to_value(item)?;
-        let if_match = match options.as_ref().and_then(|o| o.precondition.as_ref()) {
-            Some(Precondition::IfMatch(etag)) => Some(etag.to_string()),
-            _ => None,
-        };
-        self.operations.push(TransactionalBatchOperation::Replace {
-            id: item_id.into(),
-            resource_body,
-            if_match,
-        });
+        self.operations.push(TransactionalBatchOperation::replace(
+            item_id, item, options,
+        )?);
         Ok(self)
     }
 
@@ -178,17 +157,8 @@ impl TransactionalBatch {
         item_id: impl Into<Cow<'static, str>>,
         options: Option<BatchReadOptions>,
     ) -> Self {
-        let (if_match, if_none_match) = match options.as_ref().and_then(|o| o.precondition.as_ref())
-        {
-            Some(Precondition::IfMatch(etag)) => (Some(etag.to_string()), None),
-            Some(Precondition::IfNoneMatch(etag)) => (None, Some(etag.to_string())),
-            None | Some(_) => (None, None),
-        };
-        self.operations.push(TransactionalBatchOperation::Read {
-            id: item_id.into(),
-            if_match,
-            if_none_match,
-        });
+        self.operations
+            .push(TransactionalBatchOperation::read(item_id, options));
         self
     }
 
@@ -202,14 +172,8 @@ impl TransactionalBatch {
         item_id: impl Into<Cow<'static, str>>,
         options: Option<BatchDeleteOptions>,
     ) -> Self {
-        let if_match = match options.as_ref().and_then(|o| o.precondition.as_ref()) {
-            Some(Precondition::IfMatch(etag)) => Some(etag.to_string()),
-            _ => None,
-        };
-        self.operations.push(TransactionalBatchOperation::Delete {
-            id: item_id.into(),
-            if_match,
-        });
+        self.operations
+            .push(TransactionalBatchOperation::delete(item_id, options));
         self
     }
 }
@@ -282,6 +246,82 @@ pub(crate) enum TransactionalBatchOperation {
     },
 }
 
+impl TransactionalBatchOperation {
+    pub(crate) fn create<T: Serialize>(item: T) -> crate::Result<Self> {
+        Ok(Self::Create {
+            resource_body: serde_json::to_value(item)?,
+            id: None,
+        })
+    }
+
+    pub(crate) fn upsert<T: Serialize>(
+        item: T,
+        options: Option<BatchUpsertOptions>,
+    ) -> crate::Result<Self> {
+        let resource_body = serde_json::to_value(item)?;
+        let (if_match, if_none_match) = match options.as_ref().and_then(|o| o.precondition.as_ref())
+        {
+            Some(Precondition::IfMatch(etag)) => (Some(etag.to_string()), None),
+            Some(Precondition::IfNoneMatch(etag)) => (None, Some(etag.to_string())),
+            None | Some(_) => (None, None),
+        };
+        Ok(Self::Upsert {
+            resource_body,
+            id: None,
+            if_match,
+            if_none_match,
+        })
+    }
+
+    pub(crate) fn replace<T: Serialize>(
+        item_id: impl Into<Cow<'static, str>>,
+        item: T,
+        options: Option<BatchReplaceOptions>,
+    ) -> crate::Result<Self> {
+        let resource_body = serde_json::to_value(item)?;
+        let if_match = match options.as_ref().and_then(|o| o.precondition.as_ref()) {
+            Some(Precondition::IfMatch(etag)) => Some(etag.to_string()),
+            _ => None,
+        };
+        Ok(Self::Replace {
+            id: item_id.into(),
+            resource_body,
+            if_match,
+        })
+    }
+
+    pub(crate) fn read(
+        item_id: impl Into<Cow<'static, str>>,
+        options: Option<BatchReadOptions>,
+    ) -> Self {
+        let (if_match, if_none_match) = match options.as_ref().and_then(|o| o.precondition.as_ref())
+        {
+            Some(Precondition::IfMatch(etag)) => (Some(etag.to_string()), None),
+            Some(Precondition::IfNoneMatch(etag)) => (None, Some(etag.to_string())),
+            None | Some(_) => (None, None),
+        };
+        Self::Read {
+            id: item_id.into(),
+            if_match,
+            if_none_match,
+        }
+    }
+
+    pub(crate) fn delete(
+        item_id: impl Into<Cow<'static, str>>,
+        options: Option<BatchDeleteOptions>,
+    ) -> Self {
+        let if_match = match options.as_ref().and_then(|o| o.precondition.as_ref()) {
+            Some(Precondition::IfMatch(etag)) => Some(etag.to_string()),
+            _ => None,
+        };
+        Self::Delete {
+            id: item_id.into(),
+            if_match,
+        }
+    }
+}
+
 /// Response from executing a transactional batch.
 ///
 /// The Cosmos DB batch API returns a raw JSON array of operation results,
@@ -299,6 +339,11 @@ impl TransactionalBatchResponse {
     pub fn results(&self) -> &[TransactionalBatchOperationResult] {
         &self.results
     }
+
+    /// Consumes the response and returns the results of each operation in the batch.
+    pub(crate) fn into_results(self) -> Vec<TransactionalBatchOperationResult> {
+        self.results
+    }
 }
 
 impl<'de> Deserialize<'de> for TransactionalBatchResponse {
@@ -0,0 +1,137 @@
+// Copyright (c) Microsoft Corporation. All rights reserved.
+// Licensed under the MIT License.
+
+//! [`TriggerProperties`] — properties of a Cosmos DB trigger.
+
+use std::borrow::Cow;
+
+use azure_core::fmt::SafeDebug;
+use serde::{Deserialize, Serialize};
+
+use crate::models::SystemProperties;
+
+/// Properties of a Cosmos DB trigger.
+///
+/// Returned by [`ContainerClient::read_trigger()`](crate::clients::ContainerClient::read_trigger())
+/// and the other trigger operations on [`ContainerClient`](crate::clients::ContainerClient).
+///
+/// When constructing this type, use [`TriggerProperties::new()`] with the required values, for example:
+///
+/// ```rust
+/// # use azure_data_cosmos::models::{TriggerOperation, TriggerProperties, TriggerType};
+/// let properties = TriggerProperties::new(
+///     "validateItem",
+///     "function validateItem() { /* ... */ }",
+///     TriggerType::Pre,
+///     TriggerOperation::Create,
+/// );
+/// ```
+///
+/// A trigger only runs for item writes that name it in
+/// [`ItemWriteOptions::pre_triggers`](crate::options::ItemWriteOptions::pre_triggers) or
+/// [`ItemWriteOptions::post_triggers`](crate::options::ItemWriteOptions::post_triggers).
+#[derive(Clone, SafeDebug, Deserialize, Serialize, PartialEq, Eq)]
+#[safe(true)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct TriggerProperties {
+    /// The ID of the trigger.
+    pub id: Cow<'static, str>,
+
+    /// The JavaScript source of the trigger.
+    pub body: String,
+
+    /// Whether the trigger runs before or after the item write.
+    pub trigger_type: TriggerType,
+
+    /// The item write operations the trigger may run for.
+    pub trigger_operation: TriggerOperation,
+
+    /// A [`SystemProperties`] object containing common system properties for the trigger.
+    #[serde(flatten)]
+    pub system_properties: SystemProperties,
+}
+
+impl TriggerProperties {
+    /// Creates a new [`TriggerProperties`] with the required values.
+    pub fn new(
+        id: impl Into<Cow<'static, str>>,
+        body: impl Into<String>,
+        trigger_type: TriggerType,
+        trigger_operation: TriggerOperation,
+    ) -> Self {
+        Self {
+            id: id.into(),
+            body: body.into(),
+            trigger_type,
+            trigger_operation,
+            system_properties: SystemProperties::default(),
+        }
+    }
+}
+
+/// Defines when a trigger runs relative to the item write it is included in.
+#[derive(Clone, SafeDebug, Deserialize, Serialize, PartialEq, Eq)]
+#[safe(true)]
+#[non_exhaustive]
+pub enum TriggerType {
+    /// The trigger runs before the item is written and may modify it.
+    Pre,
+    /// The trigger runs after the item is written, in the same transaction.
+    Post,
+}
+
+/// Defines the item write operations a trigger may run for.
+#[derive(Clone, SafeDebug, Deserialize, Serialize, PartialEq, Eq)]
+#[safe(true)]
+#[non_exhaustive]
+pub enum TriggerOperation {
+    All,
+    Create,
+    Replace,
+    Delete,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serializes_trigger_definition() {
+        let properties = TriggerProperties::new(
+            "stamp",
+            "function stamp() {}",
+            TriggerType::Pre,
+            TriggerOperation::All,
+        );
+
+        assert_eq!(
+            serde_json::json!({
+                "id": "stamp",
+                "body": "function stamp() {}",
+                "triggerType": "Pre",
+                "triggerOperation": "All",
+            }),
+            serde_json::to_value(&properties).unwrap()
+        );
+    }
+
+    #[test]
+    fn deserializes_trigger_with_system_properties() {
+        let properties: TriggerProperties = serde_json::from_value(serde_json::json!({
+            "id": "audit",
+            "body": "function audit() {}",
+            "triggerType": "Post",
+            "triggerOperation": "Delete",
+            "_rid": "abc=",
+            "_etag": "\"0000\"",
+            "_ts": 1700000000,
+        }))
+        .unwrap();
+
+        assert_eq!("audit", properties.id);
+        assert_eq!(TriggerType::Post, properties.trigger_type);
+        assert_eq!(TriggerOperation::Delete, properties.trigger_operation);
+        assert!(properties.system_properties.etag.is_some());
+    }
+}
@@ -0,0 +1,68 @@
+// Copyright (c) Microsoft Corporation. All rights reserved.
+// Licensed under the MIT License.
+
+//! Request-level [`BulkOptions`] passed to
+//! [`ContainerClient::execute_bulk()`](crate::clients::ContainerClient::execute_bulk()).
+
+use azure_data_cosmos_driver::options::OperationOptions;
+use std::num::NonZeroUsize;
+
+/// The default number of batch requests a bulk execution keeps in flight.
+const DEFAULT_MAX_CONCURRENCY: NonZeroUsize = NonZeroUsize::new(10).unwrap();
+
+/// The default number of times a throttled operation is resubmitted.
+const DEFAULT_MAX_THROTTLE_RETRIES: u32 = 9;
+
+/// Options for bulk execution.
+///
+/// Used by [`ContainerClient::execute_bulk()`](crate::clients::ContainerClient::execute_bulk()).
+/// Conditional options are specified per-operation within each
+/// [`BulkOperation`](crate::models::BulkOperation).
+#[derive(Clone)]
+#[non_exhaustive]
+pub struct BulkOptions {
+    /// General-purpose options that apply to each batch request.
+    /// See [`OperationOptions`] for available settings and layered resolution behavior.
+    pub operation: OperationOptions,
+
+    /// The maximum number of batch requests in flight at once.
+    ///
+    /// Defaults to 10.
+    pub max_concurrency: NonZeroUsize,
+
+    /// The maximum number of times an operation throttled by the service (HTTP 429)
+    /// is resubmitted before its 429 result is returned.
+    ///
+    /// Defaults to 9.
+    pub max_throttle_retries: u32,
+}
+
+impl Default for BulkOptions {
+    fn default() -> Self {
+        Self {
+            operation: OperationOptions::default(),
+            max_concurrency: DEFAULT_MAX_CONCURRENCY,
+            max_throttle_retries: DEFAULT_MAX_THROTTLE_RETRIES,
+        }
+    }
+}
+
+impl BulkOptions {
+    /// Sets the [`OperationOptions`] for each batch request.
+    pub fn with_operation_options(mut self, operation: OperationOptions) -> Self {
+        self.operation = operation;
+        self
+    }
+
+    /// Sets the maximum number of batch requests in flight at once.
+    pub fn with_max_concurrency(mut self, max_concurrency: NonZeroUsize) -> Self {
+        self.max_concurrency = max_concurrency;
+        self
+    }
+
+    /// Sets the maximum number of times a throttled operation is resubmitted.
+    pub fn with_max_throttle_retries(mut self, max_throttle_retries: u32) -> Self {
+        self.max_throttle_retries = max_throttle_retries;
+        self
+    }
+}
@@ -0,0 +1,111 @@
+// Copyright (c) Microsoft Corporation. All rights reserved.
+// Licensed under the MIT License.
+
+//! [`ChangeFeedProcessorOptions`] passed to
+//! [`ContainerClient::change_feed_processor()`](crate::clients::ContainerClient::change_feed_processor()).
+
+use azure_core::time::Duration;
+use azure_data_cosmos_driver::models::{ChangeFeedStartFrom, MaxItemCountHint};
+
+/// The default delay between change feed polls of a feed range with no new changes.
+const DEFAULT_POLL_INTERVAL: Duration = Duration::seconds(5);
+
+/// The default interval at which the processor looks for leases to acquire.
+const DEFAULT_LEASE_ACQUIRE_INTERVAL: Duration = Duration::seconds(13);
+
+/// The default interval at which a held lease is renewed.
+const DEFAULT_LEASE_RENEW_INTERVAL: Duration = Duration::seconds(17);
+
+/// The default time after which a lease that has not been renewed may be taken by another instance.
+const DEFAULT_LEASE_EXPIRATION_INTERVAL: Duration = Duration::seconds(60);
+
+/// Options for a [`ChangeFeedProcessor`](crate::clients::ChangeFeedProcessor).
+///
+/// Every instance sharing a processor name should use the same lease intervals.
+#[derive(Clone)]
+#[non_exhaustive]
+pub struct ChangeFeedProcessorOptions {
+    /// Where to begin reading a feed range that has no checkpoint yet.
+    ///
+    /// Defaults to [`ChangeFeedStartFrom::Now`].
+    pub start_from: ChangeFeedStartFrom,
+
+    /// The maximum number of changes passed to the handler in one call.
+    pub max_item_count: Option<MaxItemCountHint>,
+
+    /// The delay before polling a feed range again after it reported no new changes.
+    ///
+    /// Defaults to 5 seconds.
+    pub poll_interval: Duration,
+
+    /// The interval at which the processor balances leases across instances.
+    ///
+    /// Defaults to 13 seconds.
+    pub lease_acquire_interval: Duration,
+
+    /// The interval at which a lease is renewed while its changes are read and handled.
+    ///
+    /// Leases are also renewed by every checkpoint. Must be shorter than
+    /// [`lease_expiration_interval`](Self::lease_expiration_interval). Defaults to 17 seconds.
+    pub lease_renew_interval: Duration,
+
+    /// The time after which a lease that has not been renewed is considered abandoned
+    /// and may be acquired by another instance.
+    ///
+    /// Each instance measures this on its own clock from when it first sees the lease's
+    /// current version, so the clocks of different hosts need not agree.
+    ///
+    /// Defaults to 60 seconds.
+    pub lease_expiration_interval: Duration,
+}
+
+impl Default for ChangeFeedProcessorOptions {
+    fn default() -> Self {
+        Self {
+            start_from: ChangeFeedStartFrom::Now,
+            max_item_count: None,
+            poll_interval: DEFAULT_POLL_INTERVAL,
+            lease_acquire_interval: DEFAULT_LEASE_ACQUIRE_INTERVAL,
+            lease_renew_interval: DEFAULT_LEASE_RENEW_INTERVAL,
+            lease_expiration_interval: DEFAULT_LEASE_EXPIRATION_INTERVAL,
+        }
+    }
+}
+
+impl ChangeFeedProcessorOptions {
+    /// Sets where to begin reading a feed range that has no checkpoint yet.
+    pub fn with_start_from(mut self, start_from: ChangeFeedStartFrom) -> Self {
+        self.start_from = start_from;
+        self
+    }
+
+    /// Sets the maximum number of changes passed to the handler in one call.
+    pub fn with_max_item_count(mut self, max_item_count: MaxItemCountHint) -> Self {
+        self.max_item_count = Some(max_item_count);
+        self
+    }
+
+    /// Sets the delay before polling a feed range again after it reported no new changes.
+    pub fn with_poll_interval(mut self, poll_interval: Duration) -> Self {
+        self.poll_interval = poll_interval;
+        self
+    }
+
+    /// Sets the interval at which the processor balances leases across instances.
+    pub fn with_lease_acquire_interval(mut self, lease_acquire_interval: Duration) -> Self {
+        self.lease_acquire_interval = lease_acquire_interval;
+        self
+    }
+
+    /// Sets the interval at which a lease with no new changes is renewed.
+    pub fn with_lease_renew_interval(mut self, lease_renew_interval: Duration) -> Self {
+        self.lease_renew_interval = lease_renew_interval;
+        self
+    }
+
+    /// Sets the time after which a lease that has not been renewed may be acquired by another instance.
+    pub fn with_lease_expiration_interval(mut self, lease_expiration_interval: Duration) -> Self {
+        self.lease_expiration_interval = lease_expiration_interval;
+        self
+    }
+}
@@ -3,99 +3,108 @@
 
 //! Options for container-level CRUD and query operations.
 
+use azure_data_cosmos_driver::models::{MaxItemCountHint, Precondition};
 use azure_data_cosmos_driver::options::OperationOptions;
+use azure_data_cosmos_macros::CosmosSetters;
 
-use crate::models::ThroughputProperties;
+use crate::{feed::ContinuationToken, models::ThroughputProperties, options::FeedOptions};
 
 /// Options to be passed to [`DatabaseClient::create_container()`](crate::clients::DatabaseClient::create_container()).
-#[derive(Clone, Default)]
+#[derive(Clone, Default, CosmosSetters)]
 #[non_exhaustive]
 pub struct CreateContainerOptions {
     /// General-purpose options that apply to this request.
     /// See [`OperationOptions`] for available settings and layered resolution behavior.
+    #[setter(
+        name = "with_operation_options",
+        doc = "Sets the [`OperationOptions`] for this request."
+    )]
     pub operation: OperationOptions,
 
+    #[setter(doc = "Sets the throughput properties for the new container.")]
     pub(crate) throughput: Option<ThroughputProperties>,
 }
 
-impl CreateContainerOptions {
-    /// Sets the throughput properties for the new container.
-    pub fn with_throughput(mut self, throughput: ThroughputProperties) -> Self {
-        self.throughput = Some(throughput);
-        self
-    }
-
-    /// Sets the [`OperationOptions`] for this request.
-    pub fn with_operation_options(mut self, operation: OperationOptions) -> Self {
-        self.operation = operation;
-        self
-    }
-}
-
 /// Options to be passed to [`ContainerClient::replace()`](crate::clients::ContainerClient::replace()).
-#[derive(Clone, Default)]
+#[derive(Clone, Default, CosmosSetters)]
 #[non_exhaustive]
 pub struct ReplaceContainerOptions {
     /// General-purpose options that apply to this request.
     /// See [`OperationOptions`] for available settings and layered resolution behavior.
+    #[setter(
+        name = "with_operation_options",
+        doc = "Sets the [`OperationOptions`] for this request."
+    )]
     pub operation: OperationOptions,
 }
 
-impl ReplaceContainerOptions {
-    /// Sets the [`OperationOptions`] for this request.
-    pub fn with_operation_options(mut self, operation: OperationOptions) -> Self {
-        self.operation = operation;
-        self
-    }
-}
-
 /// Options to be passed to [`ContainerClient::delete()`](crate::clients::ContainerClient::delete()).
-#[derive(Clone, Default)]
+#[derive(Clone, Default, CosmosSetters)]
 #[non_exhaustive]
 pub struct DeleteContainerOptions {
     /// General-purpose options that apply to this request.
     /// See [`OperationOptions`] for available settings and layered resolution behavior.
+    #[setter(
+        name = "with_operation_options",
+        doc = "Sets the [`OperationOptions`] for this request."
+    )]
     pub operation: OperationOptions,
-}
 
-impl DeleteContainerOptions {
-    /// Sets the [`OperationOptions`] for this request.
-    pub fn with_operation_options(mut self, operation: OperationOptions) -> Self {
-        self.operation = operation;
-        self
-    }
+    /// Conditional ETag check. Typically [`Precondition::IfMatch`], so the container is only
+    /// deleted if it has not changed since its properties were read.
+    #[setter(doc = "Sets a conditional ETag check for this request.")]
+    pub precondition: Option<Precondition>,
 }
 
 /// Options to be passed to [`ContainerClient::read()`](crate::clients::ContainerClient::read()).
-#[derive(Clone, Default)]
+#[derive(Clone, Default, CosmosSetters)]
 #[non_exhaustive]
 pub struct ReadContainerOptions {
     /// General-purpose options that apply to this request.
     /// See [`OperationOptions`] for available settings and layered resolution behavior.
+    #[setter(
+        name = "with_operation_options",
+        doc = "Sets the [`OperationOptions`] for this request."
+    )]
     pub operation: OperationOptions,
 }
 
-impl ReadContainerOptions {
-    /// Sets the [`OperationOptions`] for this request.
-    pub fn with_operation_options(mut self, operation: OperationOptions) -> Self {
-        self.operation = operation;
-        self
-    }
-}
-
 /// Options to be passed to [`DatabaseClient::query_containers()`](crate::clients::DatabaseClient::query_containers()).
-#[derive(Clone, Default)]
+#[derive(Clone, Default, CosmosSetters)]
 #[non_exhaustive]
 pub struct QueryContainersOptions {
     /// General-purpose options that apply to this request.
     /// See [`OperationOptions`] for available settings and layered resolution behavior.
+    #[setter(
+        name = "with_operation_options",
+        doc = "Sets the [`OperationOptions`] for this request."
+    )]
     pub operation: OperationOptions,
+
+    /// Paging options (max item count, continuation token) for this query.
+    #[setter(
+        name = "with_feed_options",
+        doc = "Sets the [`FeedOptions`] (paging) for this query."
+    )]
+    pub feed: FeedOptions,
 }
 
 impl QueryContainersOptions {
-    /// Sets the [`OperationOptions`] for this request.
-    pub fn with_operation_options(mut self, operation: OperationOptions) -> Self {
-        self.operation = operation;
+    /// Sets the maximum number of items the service should return per page.
+    ///
+    /// Delegates to [`FeedOptions::with_max_item_count`] on the inner
+    /// [`feed`](Self::feed) options.
+    pub fn with_max_item_count(mut self, max_item_count: MaxItemCountHint) -> Self {
+        self.feed = self.feed.with_max_item_count(max_item_count);
+        self
+    }
+
+    /// Sets a continuation token to resume the query at a previous position.
+    ///
+    /// Delegates to [`FeedOptions::with_continuation_token`] on the inner
+    /// [`feed`](Self::feed) options.
+    pub fn with_continuation_token(mut self, continuation_token: ContinuationToken) -> Self {
+        self.feed = self.feed.with_continuation_token(continuation_token);
         self
     }
 }
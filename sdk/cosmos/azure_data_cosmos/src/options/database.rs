@@ -3,72 +3,87 @@
 
 //! Options for database-level CRUD and query operations.
 
+use azure_data_cosmos_driver::models::MaxItemCountHint;
 use azure_data_cosmos_driver::options::OperationOptions;
+use azure_data_cosmos_macros::CosmosSetters;
+
+use crate::{feed::ContinuationToken, options::FeedOptions};
 
 /// Options to be passed to [`CosmosClient::create_database()`](crate::CosmosClient::create_database()).
-#[derive(Clone, Default)]
+#[derive(Clone, Default, CosmosSetters)]
 #[non_exhaustive]
 pub struct CreateDatabaseOptions {
     /// General-purpose options that apply to this request.
     /// See [`OperationOptions`] for available settings and layered resolution behavior.
+    #[setter(
+        name = "with_operation_options",
+        doc = "Sets the [`OperationOptions`] for this request."
+    )]
     pub operation: OperationOptions,
 }
 
-impl CreateDatabaseOptions {
-    /// Sets the [`OperationOptions`] for this request.
-    pub fn with_operation_options(mut self, operation: OperationOptions) -> Self {
-        self.operation = operation;
-        self
-    }
-}
-
 /// Options to be passed to [`DatabaseClient::delete()`](crate::clients::DatabaseClient::delete()).
-#[derive(Clone, Default)]
+#[derive(Clone, Default, CosmosSetters)]
 #[non_exhaustive]
 pub struct DeleteDatabaseOptions {
     /// General-purpose options that apply to this request.
     /// See [`OperationOptions`] for available settings and layered resolution behavior.
+    #[setter(
+        name = "with_operation_options",
+        doc = "Sets the [`OperationOptions`] for this request."
+    )]
     pub operation: OperationOptions,
 }
 
-impl DeleteDatabaseOptions {
-    /// Sets the [`OperationOptions`] for this request.
-    pub fn with_operation_options(mut self, operation: OperationOptions) -> Self {
-        self.operation = operation;
-        self
-    }
-}
-
 /// Options to be passed to [`DatabaseClient::read()`](crate::clients::DatabaseClient::read()).
-#[derive(Clone, Default)]
+#[derive(Clone, Default, CosmosSetters)]
 #[non_exhaustive]
 pub struct ReadDatabaseOptions {
     /// General-purpose options that apply to this request.
     /// See [`OperationOptions`] for available settings and layered resolution behavior.
+    #[setter(
+        name = "with_operation_options",
+        doc = "Sets the [`OperationOptions`] for this request."
+    )]
     pub operation: OperationOptions,
 }
 
-impl ReadDatabaseOptions {
-    /// Sets the [`OperationOptions`] for this request.
-    pub fn with_operation_options(mut self, operation: OperationOptions) -> Self {
-        self.operation = operation;
-        self
-    }
-}
-
 /// Options to be passed to [`CosmosClient::query_databases()`](crate::CosmosClient::query_databases()).
-#[derive(Clone, Default)]
+#[derive(Clone, Default, CosmosSetters)]
 #[non_exhaustive]
 pub struct QueryDatabasesOptions {
     /// General-purpose options that apply to this request.
     /// See [`OperationOptions`] for available settings and layered resolution behavior.
+    #[setter(
+        name = "with_operation_options",
+        doc = "Sets the [`OperationOptions`] for this request."
+    )]
     pub operation: OperationOptions,
+
+    /// Paging options (max item count, continuation token) for this query.
+    #[setter(
+        name = "with_feed_options",
+        doc = "Sets the [`FeedOptions`] (paging) for this query."
+    )]
+    pub feed: FeedOptions,
 }
 
 impl QueryDatabasesOptions {
-    /// Sets the [`OperationOptions`] for this request.
-    pub fn with_operation_options(mut self, operation: OperationOptions) -> Self {
-        self.operation = operation;
+    /// Sets the maximum number of items the service should return per page.
+    ///
+    /// Delegates to [`FeedOptions::with_max_item_count`] on the inner
+    /// [`feed`](Self::feed) options.
+    pub fn with_max_item_count(mut self, max_item_count: MaxItemCountHint) -> Self {
+        self.feed = self.feed.with_max_item_count(max_item_count);
+        self
+    }
+
+    /// Sets a continuation token to resume the query at a previous position.
+    ///
+    /// Delegates to [`FeedOptions::with_continuation_token`] on the inner
+    /// [`feed`](Self::feed) options.
+    pub fn with_continuation_token(mut self, continuation_token: ContinuationToken) -> Self {
+        self.feed = self.feed.with_continuation_token(continuation_token);
         self
     }
 }
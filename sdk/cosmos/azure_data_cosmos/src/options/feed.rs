@@ -15,11 +15,12 @@ use crate::feed::ContinuationToken;
 /// struct so other feed-style APIs can adopt them without re-declaring the
 /// same fields.
 ///
-/// Today, `FeedOptions` is composed into [`QueryOptions`] via its
+/// `FeedOptions` is composed into [`QueryOptions`] via its
 /// [`feed`](QueryOptions::feed) field; [`QueryOptions`] also exposes
 /// [`with_max_item_count`](QueryOptions::with_max_item_count) and
 /// [`with_continuation_token`](QueryOptions::with_continuation_token)
-/// shortcuts that delegate to the inner [`FeedOptions`].
+/// shortcuts that delegate to the inner [`FeedOptions`]. The options for
+/// querying databases, containers, and triggers compose it the same way.
 #[derive(Clone, Default)]
 #[non_exhaustive]
 pub struct FeedOptions {
@@ -91,7 +92,8 @@ pub struct QueryOptions {
 
     /// When `true`, request that the service include per-query metrics in the
     /// response (`x-ms-documentdb-populatequerymetrics`). Surfaced via
-    /// `QueryFeedPage::query_metrics()`.
+    /// `QueryFeedPage::query_metrics()`, `QueryFeedPage::execution_metrics()` and,
+    /// aggregated across pages, `QueryPageIterator::query_metrics()`.
     pub populate_query_metrics: Option<bool>,
 }
 
@@ -70,6 +70,18 @@ pub struct ItemWriteOptions {
     /// Conditional ETag check. For writes, typically [`Precondition::IfMatch`]
     /// (optimistic concurrency).
     pub precondition: Option<Precondition>,
+
+    /// Names of the pre-triggers to run before the item is written.
+    ///
+    /// Each trigger must exist in the container and be a
+    /// [`TriggerType::Pre`](crate::models::TriggerType::Pre) trigger for this operation.
+    pub pre_triggers: Vec<String>,
+
+    /// Names of the post-triggers to run after the item is written.
+    ///
+    /// Each trigger must exist in the container and be a
+    /// [`TriggerType::Post`](crate::models::TriggerType::Post) trigger for this operation.
+    pub post_triggers: Vec<String>,
 }
 
 impl ItemWriteOptions {
@@ -85,6 +97,18 @@ impl ItemWriteOptions {
         self
     }
 
+    /// Sets the names of the pre-triggers to run before the item is written.
+    pub fn with_pre_triggers(mut self, triggers: impl IntoIterator<Item = String>) -> Self {
+        self.pre_triggers = triggers.into_iter().collect();
+        self
+    }
+
+    /// Sets the names of the post-triggers to run after the item is written.
+    pub fn with_post_triggers(mut self, triggers: impl IntoIterator<Item = String>) -> Self {
+        self.post_triggers = triggers.into_iter().collect();
+        self
+    }
+
     /// Sets the [`OperationOptions`] for this request.
     pub fn with_operation_options(mut self, operation: OperationOptions) -> Self {
         self.operation = operation;
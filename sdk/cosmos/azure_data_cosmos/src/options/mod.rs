@@ -14,18 +14,22 @@ pub use azure_data_cosmos_driver::models::{
 #[doc(inline)]
 pub use azure_data_cosmos_driver::options::{
     AvailabilityStrategy, ConnectionPoolOptions, ConnectionPoolOptionsBuilder,
+    ConnectivityRetryOptions, ConnectivityRetryOptionsBuilder, ConnectivityRetryOptionsView,
     ContentResponseOnWrite, DiagnosticsOptions, DiagnosticsOptionsBuilder, DiagnosticsVerbosity,
-    EndToEndOperationLatencyPolicy, ExcludedRegions, HedgeThreshold, HedgingStrategy,
-    OperationOptions, OperationOptionsBuilder, OperationOptionsView, PartitionFailoverOptions,
-    PartitionFailoverOptionsBuilder, PriorityLevel, ReadConsistencyStrategy, Region,
-    ServerCertificateValidation, ThrottlingRetryOptions, ThrottlingRetryOptionsBuilder,
-    ThrottlingRetryOptionsView, ThroughputControlGroupOptions, ThroughputControlOptions,
-    ThroughputControlOptionsBuilder, ThroughputControlOptionsView, TlsBackend, UserAgentSuffix,
+    EndToEndOperationLatencyPolicy, ExcludedRegions, GlobalThroughputControlOptions,
+    HedgeThreshold, HedgingStrategy, OperationOptions, OperationOptionsBuilder,
+    OperationOptionsView, PartitionFailoverOptions, PartitionFailoverOptionsBuilder, PriorityLevel,
+    ReadConsistencyStrategy, Region, ServerCertificateValidation, ThrottlingRetryOptions,
+    ThrottlingRetryOptionsBuilder, ThrottlingRetryOptionsView, ThroughputControlGroupOptions,
+    ThroughputControlOptions, ThroughputControlOptionsBuilder, ThroughputControlOptionsView,
+    TlsBackend, UserAgentSuffix,
 };
 pub use batch::{
     BatchDeleteOptions, BatchOptions, BatchReadOptions, BatchReplaceOptions, BatchUpsertOptions,
 };
+pub use bulk::BulkOptions;
 pub use change_feed::{ChangeFeedMode, ChangeFeedOptions, ChangeFeedStartFrom};
+pub use change_feed_processor::ChangeFeedProcessorOptions;
 pub use client::CosmosClientOptions;
 pub use consistency::ConsistencyLevel;
 pub use container::{
@@ -38,15 +42,23 @@ pub use database::{
 pub use feed::{FeedOptions, QueryOptions};
 pub use feed_ranges::ReadFeedRangesOptions;
 pub use item::{ItemReadOptions, ItemWriteOptions, PatchItemOptions};
+pub use read_many::ReadManyOptions;
 pub use routing_strategy::RoutingStrategy;
+pub use stored_procedure::ExecuteStoredProcedureOptions;
 pub use throughput::ThroughputOptions;
+pub use trigger::{
+    CreateTriggerOptions, DeleteTriggerOptions, QueryTriggersOptions, ReadTriggerOptions,
+    ReplaceTriggerOptions,
+};
 
 // =========================================================================
 // Internal modules
 // =========================================================================
 
 mod batch;
+mod bulk;
 mod change_feed;
+mod change_feed_processor;
 mod client;
 mod consistency;
 mod container;
@@ -54,5 +66,8 @@ mod database;
 mod feed;
 mod feed_ranges;
 mod item;
+mod read_many;
 mod routing_strategy;
+mod stored_procedure;
 mod throughput;
+mod trigger;
@@ -0,0 +1,60 @@
+// Copyright (c) Microsoft Corporation. All rights reserved.
+// Licensed under the MIT License.
+
+//! Request-level [`ReadManyOptions`] passed to
+//! [`ContainerClient::read_many_items()`](crate::clients::ContainerClient::read_many_items()).
+
+use azure_data_cosmos_driver::{models::SessionToken, options::OperationOptions};
+use std::num::NonZeroUsize;
+
+/// The default number of queries a read-many operation keeps in flight.
+const DEFAULT_MAX_CONCURRENCY: NonZeroUsize = NonZeroUsize::new(10).unwrap();
+
+/// Options for reading many items at once.
+///
+/// Used by [`ContainerClient::read_many_items()`](crate::clients::ContainerClient::read_many_items()).
+#[derive(Clone)]
+#[non_exhaustive]
+pub struct ReadManyOptions {
+    /// General-purpose options that apply to each query.
+    /// See [`OperationOptions`] for available settings and layered resolution behavior.
+    pub operation: OperationOptions,
+
+    /// Session token for session-consistent reads.
+    pub session_token: Option<SessionToken>,
+
+    /// The maximum number of queries in flight at once.
+    ///
+    /// Defaults to 10.
+    pub max_concurrency: NonZeroUsize,
+}
+
+impl Default for ReadManyOptions {
+    fn default() -> Self {
+        Self {
+            operation: OperationOptions::default(),
+            session_token: None,
+            max_concurrency: DEFAULT_MAX_CONCURRENCY,
+        }
+    }
+}
+
+impl ReadManyOptions {
+    /// Sets the [`OperationOptions`] for each query.
+    pub fn with_operation_options(mut self, operation: OperationOptions) -> Self {
+        self.operation = operation;
+        self
+    }
+
+    /// Sets the session token for session-consistent reads.
+    pub fn with_session_token(mut self, session_token: impl Into<SessionToken>) -> Self {
+        self.session_token = Some(session_token.into());
+        self
+    }
+
+    /// Sets the maximum number of queries in flight at once.
+    pub fn with_max_concurrency(mut self, max_concurrency: NonZeroUsize) -> Self {
+        self.max_concurrency = max_concurrency;
+        self
+    }
+}
@@ -0,0 +1,38 @@
+// Copyright (c) Microsoft Corporation. All rights reserved.
+// Licensed under the MIT License.
+
+//! Options for executing stored procedures.
+
+use azure_data_cosmos_driver::models::SessionToken;
+use azure_data_cosmos_driver::options::OperationOptions;
+
+/// Options for stored procedure execution.
+///
+/// Used by [`ContainerClient::execute_stored_procedure()`](crate::clients::ContainerClient::execute_stored_procedure).
+///
+/// General-purpose settings such as custom headers and excluded regions are configured
+/// via the [`with_operation_options`](Self::with_operation_options) setter. See [`OperationOptions`] for details.
+#[derive(Clone, Default)]
+#[non_exhaustive]
+pub struct ExecuteStoredProcedureOptions {
+    /// General-purpose options that apply to this request.
+    /// See [`OperationOptions`] for available settings and layered resolution behavior.
+    pub operation: OperationOptions,
+
+    /// Session token for session-consistent reads made by the stored procedure.
+    pub session_token: Option<SessionToken>,
+}
+
+impl ExecuteStoredProcedureOptions {
+    /// Sets the session token for this request.
+    pub fn with_session_token(mut self, session_token: impl Into<SessionToken>) -> Self {
+        self.session_token = Some(session_token.into());
+        self
+    }
+
+    /// Sets the [`OperationOptions`] for this request.
+    pub fn with_operation_options(mut self, operation: OperationOptions) -> Self {
+        self.operation = operation;
+        self
+    }
+}
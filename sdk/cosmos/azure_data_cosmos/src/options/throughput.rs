@@ -4,20 +4,17 @@
 //! [`ThroughputOptions`] — options for throughput / offer operations.
 
 use azure_data_cosmos_driver::options::OperationOptions;
+use azure_data_cosmos_macros::CosmosSetters;
 
 /// Options to be passed to operations related to Throughput offers.
-#[derive(Clone, Default)]
+#[derive(Clone, Default, CosmosSetters)]
 #[non_exhaustive]
 pub struct ThroughputOptions {
     /// General-purpose options that apply to this request.
     /// See [`OperationOptions`] for available settings and layered resolution behavior.
+    #[setter(
+        name = "with_operation_options",
+        doc = "Sets the [`OperationOptions`] for this request."
+    )]
     pub operation: OperationOptions,
 }
-
-impl ThroughputOptions {
-    /// Sets the [`OperationOptions`] for this request.
-    pub fn with_operation_options(mut self, operation: OperationOptions) -> Self {
-        self.operation = operation;
-        self
-    }
-}
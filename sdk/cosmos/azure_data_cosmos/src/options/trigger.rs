@@ -0,0 +1,102 @@
+// Copyright (c) Microsoft Corporation. All rights reserved.
+// Licensed under the MIT License.
+
+//! Options for trigger CRUD and query operations.
+
+use azure_data_cosmos_driver::models::MaxItemCountHint;
+use azure_data_cosmos_driver::options::OperationOptions;
+use azure_data_cosmos_macros::CosmosSetters;
+
+use crate::{feed::ContinuationToken, options::FeedOptions};
+
+/// Options to be passed to [`ContainerClient::create_trigger()`](crate::clients::ContainerClient::create_trigger()).
+#[derive(Clone, Default, CosmosSetters)]
+#[non_exhaustive]
+pub struct CreateTriggerOptions {
+    /// General-purpose options that apply to this request.
+    /// See [`OperationOptions`] for available settings and layered resolution behavior.
+    #[setter(
+        name = "with_operation_options",
+        doc = "Sets the [`OperationOptions`] for this request."
+    )]
+    pub operation: OperationOptions,
+}
+
+/// Options to be passed to [`ContainerClient::replace_trigger()`](crate::clients::ContainerClient::replace_trigger()).
+#[derive(Clone, Default, CosmosSetters)]
+#[non_exhaustive]
+pub struct ReplaceTriggerOptions {
+    /// General-purpose options that apply to this request.
+    /// See [`OperationOptions`] for available settings and layered resolution behavior.
+    #[setter(
+        name = "with_operation_options",
+        doc = "Sets the [`OperationOptions`] for this request."
+    )]
+    pub operation: OperationOptions,
+}
+
+/// Options to be passed to [`ContainerClient::delete_trigger()`](crate::clients::ContainerClient::delete_trigger()).
+#[derive(Clone, Default, CosmosSetters)]
+#[non_exhaustive]
+pub struct DeleteTriggerOptions {
+    /// General-purpose options that apply to this request.
+    /// See [`OperationOptions`] for available settings and layered resolution behavior.
+    #[setter(
+        name = "with_operation_options",
+        doc = "Sets the [`OperationOptions`] for this request."
+    )]
+    pub operation: OperationOptions,
+}
+
+/// Options to be passed to [`ContainerClient::read_trigger()`](crate::clients::ContainerClient::read_trigger()).
+#[derive(Clone, Default, CosmosSetters)]
+#[non_exhaustive]
+pub struct ReadTriggerOptions {
+    /// General-purpose options that apply to this request.
+    /// See [`OperationOptions`] for available settings and layered resolution behavior.
+    #[setter(
+        name = "with_operation_options",
+        doc = "Sets the [`OperationOptions`] for this request."
+    )]
+    pub operation: OperationOptions,
+}
+
+/// Options to be passed to [`ContainerClient::query_triggers()`](crate::clients::ContainerClient::query_triggers()).
+#[derive(Clone, Default, CosmosSetters)]
+#[non_exhaustive]
+pub struct QueryTriggersOptions {
+    /// General-purpose options that apply to this request.
+    /// See [`OperationOptions`] for available settings and layered resolution behavior.
+    #[setter(
+        name = "with_operation_options",
+        doc = "Sets the [`OperationOptions`] for this request."
+    )]
+    pub operation: OperationOptions,
+
+    /// Paging options (max item count, continuation token) for this query.
+    #[setter(
+        name = "with_feed_options",
+        doc = "Sets the [`FeedOptions`] (paging) for this query."
+    )]
+    pub feed: FeedOptions,
+}
+
+impl QueryTriggersOptions {
+    /// Sets the maximum number of items the service should return per page.
+    ///
+    /// Delegates to [`FeedOptions::with_max_item_count`] on the inner
+    /// [`feed`](Self::feed) options.
+    pub fn with_max_item_count(mut self, max_item_count: MaxItemCountHint) -> Self {
+        self.feed = self.feed.with_max_item_count(max_item_count);
+        self
+    }
+
+    /// Sets a continuation token to resume the query at a previous position.
+    ///
+    /// Delegates to [`FeedOptions::with_continuation_token`] on the inner
+    /// [`feed`](Self::feed) options.
+    pub fn with_continuation_token(mut self, continuation_token: ContinuationToken) -> Self {
+        self.feed = self.feed.with_continuation_token(continuation_token);
+        self
+    }
+}
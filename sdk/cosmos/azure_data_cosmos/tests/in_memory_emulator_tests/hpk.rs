@@ -323,3 +323,40 @@ async fn hpk_query_full_key_equality_excludes_colocated_rows() {
     );
     assert_eq!(items[0].city, "Oakland");
 }
+
+/// Writes that infer the partition key from the item body compute the full
+/// hierarchical key, so the item lands in the same logical partition as one
+/// written with an explicit key.
+#[tokio::test]
+async fn hpk_write_infers_partition_key_from_item() {
+    let container = setup_hpk_container().await;
+    let item = GeoItem::new("USA", "WA", "Spokane");
+
+    container
+        .create_item_infer_partition_key(&item.id, &item, None)
+        .await
+        .unwrap();
+    let read: GeoItem = container
+        .read_item(PartitionKey::from(("USA", "WA", "Spokane")), &item.id, None)
+        .await
+        .unwrap()
+        .into_model()
+        .unwrap();
+    assert_eq!(read, item);
+
+    container
+        .upsert_item_infer_partition_key(&item.id, &item, None)
+        .await
+        .unwrap();
+    container
+        .replace_item_infer_partition_key(&item.id, &item, None)
+        .await
+        .unwrap();
+
+    let items = query_scope(
+        &container,
+        FeedScope::partition(PartitionKey::from(("USA", "WA"))),
+    )
+    .await;
+    assert_eq!(items.len(), 3, "USA/WA should now hold 3 items");
+}
@@ -21,6 +21,7 @@ use crate::{
             partition_key_range_id::PartitionKeyRangeId, session_manager::SessionManager,
             CosmosEndpoint, LocationStateStore,
         },
+        throughput_control::{ThroughputController, ThroughputControllerStore},
         transport::uses_dataplane_pipeline,
     },
     models::{
@@ -284,6 +285,9 @@ pub struct CosmosDriver {
     /// driver construction. The runtime no longer owns its own registry —
     /// throughput-control groups are a driver-level concern.
     throughput_control_groups: crate::options::ThroughputControlGroupRegistry,
+    /// Client-side controllers of the groups with a target throughput, created
+    /// on first use.
+    throughput_controllers: ThroughputControllerStore,
     /// Native FFI query plan provider. Lazily loads the native library on
     /// first use; returns errors if unavailable.
     #[cfg(feature = "__internal_native_query_plan")]
@@ -1426,17 +1430,20 @@ impl CosmosDriver {
                 Some(Arc::new(Http2ConnectivityProbe::new(probe_client)))
             };
 
-        let location_state_store = Arc::new(LocationStateStore::new(
-            runtime.account_metadata_cache().clone(),
-            account_endpoint,
-            default_endpoint,
-            refresh_callback,
-            !runtime.connection_pool().gateway_v2_disabled(),
-            endpoint_unavailability_ttl,
-            options.partition_failover_options().clone(),
-            options.preferred_regions().to_vec(),
-            connectivity_probe,
-        ));
+        let location_state_store = Arc::new(
+            LocationStateStore::new(
+                runtime.account_metadata_cache().clone(),
+                account_endpoint,
+                default_endpoint,
+                refresh_callback,
+                !runtime.connection_pool().gateway_v2_disabled(),
+                endpoint_unavailability_ttl,
+                options.partition_failover_options().clone(),
+                options.preferred_regions().to_vec(),
+                connectivity_probe,
+            )
+            .with_latency_based_read_routing(options.latency_based_read_routing_enabled()),
+        );
 
         // Spawn the background failback loop for partition-level overrides.
         #[cfg(feature = "tokio")]
@@ -1498,6 +1505,7 @@ impl CosmosDriver {
         // The runtime no longer owns one — TCGs are a driver-level concern.
         // Clone the per-driver registry as-is for the request hot path.
         let throughput_control_groups = options.throughput_control_groups().clone();
+        let session_manager = SessionManager::with_max_containers(options.max_session_containers());
 
         Ok(Self {
             runtime,
@@ -1510,13 +1518,14 @@ impl CosmosDriver {
             ))]
             endpoint_probe_fn: TestEndpointProbeFn(endpoint_probe_fn_for_tests),
             pk_range_cache: PartitionKeyRangeCache::new(),
-            session_manager: SessionManager::new(),
+            session_manager,
             initialized: AtomicBool::new(false),
             user_agent,
             http_client_factory,
             #[cfg(feature = "fault_injection")]
             fault_injection_enabled,
             throughput_control_groups,
+            throughput_controllers: ThroughputControllerStore::default(),
             #[cfg(feature = "__internal_native_query_plan")]
             native_query_plan_provider: crate::query_plan_native::NativeQueryPlanProvider::new(),
         })
@@ -1903,6 +1912,27 @@ impl CosmosDriver {
         })
     }
 
+    /// Returns the controller enforcing the client-side target of the
+    /// operation's throughput control group, if any.
+    ///
+    /// The group named in the operation options is used when set; otherwise
+    /// the container's default group applies.
+    fn throughput_controller(
+        &self,
+        effective_options: &OperationOptionsView<'_>,
+        container: &ContainerReference,
+    ) -> Option<Arc<ThroughputController>> {
+        let group = match effective_options.throughput_control().group_name() {
+            Some(name) => self
+                .throughput_control_groups
+                .get_by_container_and_name(container, name)?,
+            None => self
+                .throughput_control_groups
+                .get_default_for_container(container)?,
+        };
+        self.throughput_controllers.get_or_create(group)
+    }
+
     /// Fetches partition key ranges from the service for the given container.
     ///
     /// Builds a GET request to `/dbs/{db_rid}/colls/{container_rid}/pkranges`
@@ -2529,6 +2559,23 @@ impl CosmosDriver {
             None
         };
 
+        // Step 2.1: Wait for capacity in the group's client-side target, if any.
+        // Only item operations count towards the target.
+        let throughput_controller = match operation.container() {
+            Some(container) if operation.resource_type() == ResourceType::Document => {
+                self.throughput_controller(&effective_options, container)
+            }
+            _ => None,
+        };
+        if let Some(controller) = &throughput_controller {
+            let max_wait = effective_options
+                .throttling_retry_options()
+                .max_retry_wait_time()
+                .copied()
+                .unwrap_or(super::pipeline::components::DEFAULT_MAX_THROTTLE_WAIT);
+            super::throughput_control::acquire(self, controller, max_wait).await?;
+        }
+
         // Step 3: Initialize operation activity id
         let activity_id = ActivityId::new_uuid();
 
@@ -2604,7 +2651,7 @@ impl CosmosDriver {
             azure_core::http::headers::HeaderValue::from(self.user_agent.as_str().to_owned());
 
         // Step 8: Execute via the new operation pipeline
-        super::pipeline::operation_pipeline::execute_operation_pipeline(
+        let result = super::pipeline::operation_pipeline::execute_operation_pipeline(
             operation,
             overrides,
             &effective_options,
@@ -2625,7 +2672,21 @@ impl CosmosDriver {
             effective_throughput_control,
             pre_resolved_pk_range_id,
         )
-        .await
+        .await;
+
+        // Step 9: Charge the request against the group's client-side target.
+        if let Some(controller) = &throughput_controller {
+            let request_charge = match &result {
+                Ok(response) => response.diagnostics_ref().total_request_charge(),
+                Err(error) => error
+                    .diagnostics()
+                    .map(|diagnostics| diagnostics.total_request_charge())
+                    .unwrap_or_default(),
+            };
+            controller.record_charge(request_charge.value());
+        }
+
+        result
     }
 
     /// Resolves a container by database and container name.
@@ -2820,7 +2881,29 @@ impl CosmosDriver {
             return Ok(OperationPlan::new(pipeline, operation));
         }
 
-        // 3. Cross-partition query: obtain a query plan and build the fan-out
+        // 3. Bulk batch: route the batch to the partition key range holding
+        //    its target range (no query plan needed).
+        if operation.is_bulk() {
+            let container = operation.container().ok_or_else(|| {
+                crate::error::CosmosError::builder()
+                    .with_status(
+                        crate::error::CosmosStatus::CLIENT_CROSS_PARTITION_QUERY_REQUIRES_CONTAINER_REF,
+                    )
+                    .with_message("bulk batch requires a container reference")
+                    .build()
+            })?;
+            let mut topology = CachedTopologyProvider::new(
+                &self.pk_range_cache,
+                container.clone(),
+                |container, continuation| {
+                    self.fetch_pk_ranges_from_service(container, continuation)
+                },
+            );
+            let pipeline = planner::build_bulk_pipeline(&mut topology, &operation).await?;
+            return Ok(OperationPlan::new(pipeline, operation));
+        }
+
+        // 4. Cross-partition query: obtain a query plan and build the fan-out
         //    pipeline. Try the native FFI provider first (no network call),
         //    falling back to the Gateway if unavailable.
         let container = operation.container().ok_or_else(|| {
@@ -2832,9 +2915,9 @@ impl CosmosDriver {
                 .build()
         })?;
 
-        let query_plan = self
-            .resolve_query_plan(container, &operation, options)
-            .await?;
+        // Boxed, like the planner below, so that fetching the plan through the
+        // operation pipeline doesn't inflate this future and every caller's.
+        let query_plan = Box::pin(self.resolve_query_plan(container, &operation, options)).await?;
 
         // Build the fan-out pipeline using the query plan.
         let container_ref = container.clone();
@@ -2844,9 +2927,15 @@ impl CosmosDriver {
             |container, continuation| self.fetch_pk_ranges_from_service(container, continuation),
         );
 
-        let pipeline =
-            planner::build_sequential_drain(&query_plan, &mut topology, &operation, resume_state)
-                .await?;
+        // Boxed so the planner's future, which grows with every wrapper node
+        // it can build, isn't inlined into this one.
+        let pipeline = Box::pin(planner::build_query_pipeline(
+            &query_plan,
+            &mut topology,
+            &operation,
+            resume_state,
+        ))
+        .await?;
         Ok(OperationPlan::new(pipeline, operation))
     }
 
@@ -2857,10 +2946,10 @@ impl CosmosDriver {
         operation: &CosmosOperation,
         options: &OperationOptions,
     ) -> crate::error::Result<QueryPlan> {
-        // Advertise the SDK's supported query-rewrite features. The default
-        // (`SUPPORTED_QUERY_FEATURES`) is `"None"` until the cross-partition
-        // pipeline gains rewrite support, but the value must be non-empty so
-        // the Gateway V2 thin-client proxy accepts the QueryPlan request.
+        // Advertise the query-rewrite features the cross-partition pipeline
+        // can execute (`SUPPORTED_QUERY_FEATURES`). The value must be
+        // non-empty so the Gateway V2 thin-client proxy accepts the QueryPlan
+        // request.
         let query_plan_operation = CosmosOperation::query_plan(
             container.clone(),
             std::borrow::Cow::Borrowed(crate::query::SUPPORTED_QUERY_FEATURES),
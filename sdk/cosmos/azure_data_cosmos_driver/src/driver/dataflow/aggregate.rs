@@ -0,0 +1,414 @@
+// Copyright (c) Microsoft Corporation. All rights reserved.
+// Licensed under the MIT License.
+
+//! Aggregate node for cross-partition `SELECT VALUE` aggregate queries.
+//!
+//! For `SELECT VALUE COUNT(...)` (and `SUM`, `AVG`, `MIN`, `MAX`) the query
+//! plan's rewritten query makes every partition return its partial aggregate
+//! wrapped as `[{"item": <partial>}]`. [`Aggregate`] reads its source to the
+//! end, combines the partials and emits a single page holding the final value.
+//!
+//! Partials that cannot be combined with plain arithmetic come back as
+//! objects: `AVG` as `{"sum": .., "count": ..}`, and `MIN` / `MAX` as
+//! `{"min": .., "count": ..}` / `{"max": .., "count": ..}`, where a zero
+//! `count` means the partition had no values.
+
+use async_trait::async_trait;
+use serde_json::{Map, Number, Value};
+
+use super::{
+    documents::{compare_values, invalid_query_response, page_documents, PageAccumulator},
+    PageResult, PipelineContext, PipelineNode, PipelineNodeState,
+};
+
+/// The aggregate function of a `SELECT VALUE` aggregate query.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum AggregateKind {
+    Count,
+    Sum,
+    Average,
+    Min,
+    Max,
+}
+
+impl AggregateKind {
+    /// Parses an entry of the query plan's `aggregates` list.
+    pub(crate) fn parse(name: &str) -> Option<Self> {
+        match name {
+            "Count" => Some(Self::Count),
+            "Sum" => Some(Self::Sum),
+            "Average" => Some(Self::Average),
+            "Min" => Some(Self::Min),
+            "Max" => Some(Self::Max),
+            _ => None,
+        }
+    }
+
+    /// Combines the partial aggregates returned by each partition.
+    ///
+    /// Returns `None` when the aggregate is undefined, in which case the query
+    /// has no result (e.g. `AVG` over no values, or `SUM` over a non-number).
+    pub(crate) fn combine(self, partials: &[Value]) -> crate::error::Result<Option<Value>> {
+        let items = partials.iter().map(partial_item);
+        match self {
+            Self::Count => {
+                let mut total = Some(Number::from(0));
+                for item in items {
+                    let count = item
+                        .and_then(Value::as_number)
+                        .ok_or_else(|| invalid_partial(self))?;
+                    total = total.and_then(|total| add_numbers(&total, count));
+                }
+                Ok(total.map(Value::Number))
+            }
+            Self::Sum => {
+                let mut total = Some(Number::from(0));
+                for item in items {
+                    // A partition whose sum is undefined (e.g. it summed a
+                    // string) makes the overall sum undefined.
+                    total = match (total, item.and_then(Value::as_number)) {
+                        (Some(total), Some(sum)) => add_numbers(&total, sum),
+                        _ => None,
+                    };
+                }
+                Ok(total.map(Value::Number))
+            }
+            Self::Average => {
+                let mut sum = Some(Number::from(0));
+                let mut count = 0;
+                for item in items.flatten() {
+                    let (partial_sum, partial_count) = item
+                        .as_object()
+                        .and_then(|item| Some((item.get("sum"), partial_count(item)?)))
+                        .ok_or_else(|| invalid_partial(self))?;
+                    if partial_count == 0 {
+                        continue;
+                    }
+                    count += partial_count;
+                    sum = match (sum, partial_sum.and_then(Value::as_number)) {
+                        (Some(sum), Some(partial_sum)) => add_numbers(&sum, partial_sum),
+                        _ => None,
+                    };
+                }
+                Ok(sum
+                    .filter(|_| count > 0)
+                    .and_then(|sum| Number::from_f64(sum.as_f64()? / count as f64))
+                    .map(Value::Number))
+            }
+            Self::Min | Self::Max => {
+                let key = if self == Self::Min { "min" } else { "max" };
+                let mut best: Option<&Value> = None;
+                for item in items.flatten() {
+                    let value = match item.as_object() {
+                        Some(item) if item.contains_key("count") => {
+                            if partial_count(item) == Some(0) {
+                                continue;
+                            }
+                            item.get(key)
+                        }
+                        _ => Some(item),
+                    };
+                    let Some(value) = value else {
+                        continue;
+                    };
+                    let ordering = compare_values(Some(value), best);
+                    let replaces = best.is_none()
+                        || if self == Self::Min {
+                            ordering.is_lt()
+                        } else {
+                            ordering.is_gt()
+                        };
+                    if replaces {
+                        best = Some(value);
+                    }
+                }
+                Ok(best.cloned())
+            }
+        }
+    }
+}
+
+/// Extracts the partial aggregate from a partition's result document.
+///
+/// Returns `None` when the partial is undefined.
+fn partial_item(document: &Value) -> Option<&Value> {
+    let wrapper = match document {
+        Value::Array(items) => items.first()?,
+        other => other,
+    };
+    wrapper.get("item")
+}
+
+fn partial_count(item: &Map<String, Value>) -> Option<u64> {
+    item.get("count").and_then(Value::as_u64)
+}
+
+/// Adds two JSON numbers, keeping integer results integral.
+///
+/// Returns `None` if the result is not a finite number.
+fn add_numbers(left: &Number, right: &Number) -> Option<Number> {
+    if let (Some(left), Some(right)) = (left.as_i64(), right.as_i64()) {
+        if let Some(sum) = left.checked_add(right) {
+            return Some(sum.into());
+        }
+    }
+    Number::from_f64(left.as_f64()? + right.as_f64()?)
+}
+
+fn invalid_partial(kind: AggregateKind) -> crate::error::CosmosError {
+    invalid_query_response(std::io::Error::other(format!(
+        "query result is not a valid partial {kind:?} aggregate"
+    )))
+}
+
+/// Reduces its source's results to the single value of a `SELECT VALUE`
+/// aggregate.
+///
+/// The first call to `next_page` reads the source to the end and returns one
+/// terminal page containing the combined value (or no documents if the
+/// aggregate is undefined). The page carries the request charge of every
+/// backend page read to produce it.
+pub(crate) struct Aggregate {
+    source: Box<dyn PipelineNode>,
+    kind: AggregateKind,
+    /// The result documents read from the source so far. Each partition
+    /// returns a single partial, so this stays small.
+    partials: Vec<Value>,
+    accumulator: PageAccumulator,
+    emitted: bool,
+}
+
+impl Aggregate {
+    /// Creates an aggregate over the given source.
+    #[cfg(test)]
+    pub(crate) fn new(source: Box<dyn PipelineNode>, kind: AggregateKind) -> Self {
+        Self::resumed(source, kind, Vec::new())
+    }
+
+    /// Creates an aggregate restored from a [`PipelineNodeState::Aggregate`]
+    /// snapshot, with the partials read before the snapshot was taken.
+    pub(crate) fn resumed(
+        source: Box<dyn PipelineNode>,
+        kind: AggregateKind,
+        partials: Vec<Value>,
+    ) -> Self {
+        debug_assert!(
+            !source.topology_can_change(),
+            "aggregate source must handle topology changes itself"
+        );
+        Self {
+            source,
+            kind,
+            partials,
+            accumulator: PageAccumulator::default(),
+            emitted: false,
+        }
+    }
+}
+
+#[async_trait]
+impl PipelineNode for Aggregate {
+    async fn next_page(
+        &mut self,
+        context: &mut PipelineContext<'_>,
+    ) -> crate::error::Result<PageResult> {
+        if self.emitted {
+            return Ok(PageResult::Drained);
+        }
+
+        loop {
+            match self.source.next_page(context).await? {
+                PageResult::Page {
+                    response,
+                    is_terminal,
+                } => {
+                    self.accumulator.record(&response);
+                    self.partials.extend(page_documents(&response)?);
+                    if is_terminal {
+                        break;
+                    }
+                }
+                PageResult::Drained => break,
+                // Defensive: the source handles topology changes itself (see
+                // `resumed`), so this never happens in practice.
+                split @ PageResult::SplitRequired { .. } => return Ok(split),
+            }
+        }
+
+        let value = self.kind.combine(&self.partials)?;
+        self.emitted = true;
+        self.partials.clear();
+        match self.accumulator.take_page(value.into_iter().collect()) {
+            Some(response) => Ok(PageResult::Page {
+                response,
+                is_terminal: true,
+            }),
+            None => Ok(PageResult::Drained),
+        }
+    }
+
+    #[cfg(test)]
+    fn into_children(self) -> Vec<Box<dyn PipelineNode>> {
+        vec![self.source]
+    }
+
+    fn snapshot_state(&self) -> crate::error::Result<PipelineNodeState> {
+        if self.emitted {
+            return Ok(PipelineNodeState::Drained);
+        }
+        Ok(PipelineNodeState::Aggregate {
+            partials: self.partials.clone(),
+            source: Box::new(self.source.snapshot_state()?),
+        })
+    }
+
+    fn topology_can_change(&self) -> bool {
+        false
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::mocks::*;
+    use super::*;
+    use serde_json::json;
+
+    fn partial_page(partials: &[Value], is_terminal: bool) -> crate::error::Result<PageResult> {
+        let documents: Vec<Value> = partials.iter().map(|p| json!([{ "item": p }])).collect();
+        Ok(PageResult::Page {
+            response: response(json!({ "Documents": documents }).to_string().as_bytes()),
+            is_terminal,
+        })
+    }
+
+    fn emitted(result: crate::error::Result<PageResult>) -> Vec<Value> {
+        let response = unwrap_page(result);
+        let body: Value = serde_json::from_slice(response.body_bytes()).unwrap();
+        body["Documents"].as_array().unwrap().clone()
+    }
+
+    fn combine(kind: AggregateKind, partials: &[Value]) -> Option<Value> {
+        let documents: Vec<Value> = partials.iter().map(|p| json!([{ "item": p }])).collect();
+        kind.combine(&documents).unwrap()
+    }
+
+    #[tokio::test]
+    async fn reads_source_to_the_end_and_emits_one_page() {
+        let source = MockLeaf::with_pages(vec![
+            partial_page(&[json!(3)], false),
+            partial_page(&[], false),
+            partial_page(&[json!(4), json!(5)], true),
+        ]);
+        let mut node = Aggregate::new(Box::new(source), AggregateKind::Count);
+        let mut executor = NoopRequestExecutor;
+        let mut context = PipelineContext::new(&mut executor, None);
+
+        assert_eq!(emitted(node.next_page(&mut context).await), vec![json!(12)]);
+        assert_drained(node.next_page(&mut context).await);
+        assert_eq!(node.snapshot_state().unwrap(), PipelineNodeState::Drained);
+    }
+
+    #[test]
+    fn count_and_sum_keep_integers_integral() {
+        assert_eq!(
+            combine(AggregateKind::Count, &[json!(2), json!(3)]),
+            Some(json!(5))
+        );
+        assert_eq!(
+            combine(AggregateKind::Sum, &[json!(2), json!(0.5)]),
+            Some(json!(2.5))
+        );
+        assert_eq!(combine(AggregateKind::Sum, &[]), Some(json!(0)));
+    }
+
+    #[test]
+    fn undefined_partial_sum_makes_sum_undefined() {
+        let documents = vec![json!([{ "item": 2 }]), json!([{}])];
+        assert_eq!(AggregateKind::Sum.combine(&documents).unwrap(), None);
+    }
+
+    #[test]
+    fn average_weights_partials_by_count() {
+        assert_eq!(
+            combine(
+                AggregateKind::Average,
+                &[
+                    json!({"sum": 10, "count": 4}),
+                    json!({"sum": 0, "count": 0}),
+                    json!({"sum": 5, "count": 1}),
+                ]
+            ),
+            Some(json!(3.0))
+        );
+        assert_eq!(
+            combine(AggregateKind::Average, &[json!({"sum": 0, "count": 0})]),
+            None
+        );
+        assert_eq!(
+            combine(AggregateKind::Average, &[json!({"count": 2})]),
+            None
+        );
+    }
+
+    #[test]
+    fn min_and_max_skip_empty_partials() {
+        let partials = [
+            json!({"min": 4, "max": 4, "count": 1}),
+            json!({"count": 0}),
+            json!({"min": "a", "max": "a", "count": 2}),
+        ];
+        assert_eq!(combine(AggregateKind::Min, &partials), Some(json!(4)));
+        assert_eq!(combine(AggregateKind::Max, &partials), Some(json!("a")));
+        assert_eq!(
+            combine(AggregateKind::Min, &[json!(7), json!(null)]),
+            Some(json!(null))
+        );
+        assert_eq!(combine(AggregateKind::Max, &[json!({"count": 0})]), None);
+    }
+
+    #[test]
+    fn rejects_malformed_partials() {
+        assert!(AggregateKind::Count
+            .combine(&[json!([{ "item": "x" }])])
+            .is_err());
+        assert!(AggregateKind::Average
+            .combine(&[json!([{ "item": 1 }])])
+            .is_err());
+    }
+
+    #[tokio::test]
+    async fn snapshot_carries_partials_and_source_state() {
+        let source = MockLeaf::with_pages(vec![]).with_snapshot(PipelineNodeState::Request {
+            server_continuation: Some("t1".to_owned()),
+        });
+        let node = Aggregate::resumed(
+            Box::new(source),
+            AggregateKind::Sum,
+            vec![json!([{"item": 1}])],
+        );
+
+        assert_eq!(
+            node.snapshot_state().unwrap(),
+            PipelineNodeState::Aggregate {
+                partials: vec![json!([{"item": 1}])],
+                source: Box::new(PipelineNodeState::Request {
+                    server_continuation: Some("t1".to_owned()),
+                }),
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn resumed_partials_are_combined_with_new_results() {
+        let source = MockLeaf::with_pages(vec![partial_page(&[json!(2)], true)]);
+        let mut node = Aggregate::resumed(
+            Box::new(source),
+            AggregateKind::Sum,
+            vec![json!([{"item": 40}])],
+        );
+        let mut executor = NoopRequestExecutor;
+        let mut context = PipelineContext::new(&mut executor, None);
+
+        assert_eq!(emitted(node.next_page(&mut context).await), vec![json!(42)]);
+    }
+}
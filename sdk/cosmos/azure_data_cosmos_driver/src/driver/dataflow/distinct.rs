@@ -0,0 +1,260 @@
+// Copyright (c) Microsoft Corporation. All rights reserved.
+// Licensed under the MIT License.
+
+//! Distinct node for cross-partition `SELECT DISTINCT` queries.
+//!
+//! Each partition deduplicates its own results, but the same result can still
+//! come back from several partitions. [`Distinct`] drops those repeats by
+//! remembering the [`hash_value`] of the results it has emitted.
+
+use std::collections::HashSet;
+
+use async_trait::async_trait;
+
+use super::{
+    documents::{hash_value, page_documents, PageAccumulator},
+    PageResult, PipelineContext, PipelineNode, PipelineNodeState,
+};
+
+/// The most distinct results an unordered [`Distinct`] can record in a
+/// continuation token. Each one takes 32 hex digits in the token.
+///
+/// Dropping hashes would let a resumed query return results again, so past
+/// this limit snapshotting fails instead and the query can only be resumed by
+/// running it from the start.
+pub(crate) const MAX_RESUMABLE_UNORDERED_RESULTS: usize = 10_000;
+
+/// How a [`Distinct`] node recognizes repeated results.
+#[derive(Debug)]
+enum Seen {
+    /// The source emits results in sort order, so equal results are adjacent
+    /// and only the last one needs to be remembered.
+    Ordered(Option<u128>),
+    /// Results arrive in any order; every emitted result is remembered.
+    Unordered(HashSet<u128>),
+}
+
+/// Drops results its source already emitted.
+///
+/// Queries with `ORDER BY` use ordered deduplication: the source (an
+/// [`OrderedMerge`](super::OrderedMerge)) emits equal results next to each
+/// other, so a result is a repeat exactly when it matches the previous one.
+/// Other queries use unordered deduplication, which keeps the hash of every
+/// emitted result; its continuation state grows with the number of distinct
+/// results, so it can only be snapshotted until it has emitted
+/// [`MAX_RESUMABLE_UNORDERED_RESULTS`] of them.
+///
+/// Each source page is forwarded as one page holding its new results, so a
+/// page can be empty when all of its results were repeats.
+pub(crate) struct Distinct {
+    source: Box<dyn PipelineNode>,
+    seen: Seen,
+}
+
+impl Distinct {
+    /// Creates an ordered distinct over a source that emits results in sort
+    /// order, restored with the hash of the last emitted result (if any).
+    pub(crate) fn ordered(source: Box<dyn PipelineNode>, last: Option<u128>) -> Self {
+        Self::with_seen(source, Seen::Ordered(last))
+    }
+
+    /// Creates an unordered distinct, restored with the hashes of the results
+    /// emitted before the snapshot was taken.
+    pub(crate) fn unordered(source: Box<dyn PipelineNode>, seen: Vec<u128>) -> Self {
+        Self::with_seen(source, Seen::Unordered(seen.into_iter().collect()))
+    }
+
+    fn with_seen(source: Box<dyn PipelineNode>, seen: Seen) -> Self {
+        debug_assert!(
+            !source.topology_can_change(),
+            "distinct source must handle topology changes itself"
+        );
+        Self { source, seen }
+    }
+
+    /// Records `hash` as emitted, returning `false` if it is a repeat.
+    fn insert(&mut self, hash: u128) -> bool {
+        match &mut self.seen {
+            Seen::Ordered(last) => last.replace(hash) != Some(hash),
+            Seen::Unordered(seen) => seen.insert(hash),
+        }
+    }
+}
+
+#[async_trait]
+impl PipelineNode for Distinct {
+    async fn next_page(
+        &mut self,
+        context: &mut PipelineContext<'_>,
+    ) -> crate::error::Result<PageResult> {
+        match self.source.next_page(context).await? {
+            PageResult::Page {
+                response,
+                is_terminal,
+            } => {
+                let documents = page_documents(&response)?
+                    .into_iter()
+                    .filter(|document| self.insert(hash_value(document)))
+                    .collect();
+                let mut accumulator = PageAccumulator::default();
+                accumulator.record(&response);
+                let response = accumulator
+                    .take_page(documents)
+                    .expect("a response was recorded");
+                Ok(PageResult::Page {
+                    response,
+                    is_terminal,
+                })
+            }
+            other => Ok(other),
+        }
+    }
+
+    #[cfg(test)]
+    fn into_children(self) -> Vec<Box<dyn PipelineNode>> {
+        vec![self.source]
+    }
+
+    fn snapshot_state(&self) -> crate::error::Result<PipelineNodeState> {
+        let source = self.source.snapshot_state()?;
+        if source == PipelineNodeState::Drained {
+            return Ok(PipelineNodeState::Drained);
+        }
+        let seen = match &self.seen {
+            Seen::Ordered(last) => last.iter().copied().collect(),
+            Seen::Unordered(seen) if seen.len() > MAX_RESUMABLE_UNORDERED_RESULTS => {
+                return Err(crate::error::CosmosError::builder()
+                    .with_status(crate::error::CosmosStatus::CLIENT_DISTINCT_CONTINUATION_TOO_LARGE)
+                    .with_message(format!(
+                        "cannot create a continuation token for an unordered DISTINCT query after \
+                         {MAX_RESUMABLE_UNORDERED_RESULTS} distinct results"
+                    ))
+                    .build());
+            }
+            Seen::Unordered(seen) => {
+                let mut seen: Vec<_> = seen.iter().copied().collect();
+                seen.sort_unstable();
+                seen
+            }
+        };
+        Ok(PipelineNodeState::Distinct {
+            seen,
+            source: Box::new(source),
+        })
+    }
+
+    fn topology_can_change(&self) -> bool {
+        false
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::mocks::*;
+    use super::*;
+    use serde_json::{json, Value};
+
+    fn page(documents: &[Value], is_terminal: bool) -> crate::error::Result<PageResult> {
+        Ok(PageResult::Page {
+            response: response(json!({ "Documents": documents }).to_string().as_bytes()),
+            is_terminal,
+        })
+    }
+
+    fn emitted(result: crate::error::Result<PageResult>) -> Vec<Value> {
+        let response = unwrap_page(result);
+        let body: Value = serde_json::from_slice(response.body_bytes()).unwrap();
+        body["Documents"].as_array().unwrap().clone()
+    }
+
+    #[tokio::test]
+    async fn unordered_drops_results_seen_on_any_earlier_page() {
+        let source = MockLeaf::with_pages(vec![
+            page(&[json!({"a": 1}), json!(2)], false),
+            page(&[json!(2.0), json!(3), json!({"a": 1})], true),
+        ]);
+        let mut node = Distinct::unordered(Box::new(source), Vec::new());
+        let mut executor = NoopRequestExecutor;
+        let mut context = PipelineContext::new(&mut executor, None);
+
+        assert_eq!(
+            emitted(node.next_page(&mut context).await),
+            vec![json!({"a": 1}), json!(2)]
+        );
+        assert_eq!(emitted(node.next_page(&mut context).await), vec![json!(3)]);
+    }
+
+    #[tokio::test]
+    async fn ordered_drops_only_adjacent_repeats() {
+        let source = MockLeaf::with_pages(vec![
+            page(&[json!(1), json!(1), json!(2)], false),
+            page(&[json!(2), json!(3)], true),
+        ]);
+        let mut node = Distinct::ordered(Box::new(source), None);
+        let mut executor = NoopRequestExecutor;
+        let mut context = PipelineContext::new(&mut executor, None);
+
+        assert_eq!(
+            emitted(node.next_page(&mut context).await),
+            vec![json!(1), json!(2)]
+        );
+        assert_eq!(emitted(node.next_page(&mut context).await), vec![json!(3)]);
+    }
+
+    #[tokio::test]
+    async fn resumed_hashes_suppress_repeats() {
+        let source = MockLeaf::with_pages(vec![page(&[json!("x"), json!("y")], true)]);
+        let mut node = Distinct::unordered(Box::new(source), vec![hash_value(&json!("x"))]);
+        let mut executor = NoopRequestExecutor;
+        let mut context = PipelineContext::new(&mut executor, None);
+
+        assert_eq!(
+            emitted(node.next_page(&mut context).await),
+            vec![json!("y")]
+        );
+    }
+
+    #[test]
+    fn snapshot_carries_seen_hashes_and_source_state() {
+        let pending = PipelineNodeState::Request {
+            server_continuation: Some("t1".to_owned()),
+        };
+        let source = MockLeaf::with_pages(vec![]).with_snapshot(pending.clone());
+        let node = Distinct::unordered(Box::new(source), vec![7, 3]);
+        assert_eq!(
+            node.snapshot_state().unwrap(),
+            PipelineNodeState::Distinct {
+                seen: vec![3, 7],
+                source: Box::new(pending),
+            }
+        );
+
+        let drained = Distinct::ordered(Box::new(MockLeaf::with_pages(vec![])), Some(1));
+        assert_eq!(
+            drained.snapshot_state().unwrap(),
+            PipelineNodeState::Drained
+        );
+    }
+
+    #[test]
+    fn unordered_snapshot_fails_past_resume_limit() {
+        let pending = PipelineNodeState::Request {
+            server_continuation: None,
+        };
+        let limit = MAX_RESUMABLE_UNORDERED_RESULTS as u128;
+        let at_limit = Distinct::unordered(
+            Box::new(MockLeaf::with_pages(vec![]).with_snapshot(pending.clone())),
+            (0..limit).collect(),
+        );
+        assert!(at_limit.snapshot_state().is_ok());
+
+        let over_limit = Distinct::unordered(
+            Box::new(MockLeaf::with_pages(vec![]).with_snapshot(pending)),
+            (0..=limit).collect(),
+        );
+        assert_eq!(
+            over_limit.snapshot_state().unwrap_err().status(),
+            crate::error::CosmosStatus::CLIENT_DISTINCT_CONTINUATION_TOO_LARGE
+        );
+    }
+}
@@ -0,0 +1,480 @@
+// Copyright (c) Microsoft Corporation. All rights reserved.
+// Licensed under the MIT License.
+
+//! Document-level helpers for pipeline nodes that rewrite query results.
+//!
+//! Most pipeline nodes forward whole backend pages untouched. Nodes that merge
+//! or combine results across partitions (e.g. [`OrderedMerge`](super::OrderedMerge))
+//! instead need to look at individual documents: [`page_documents`] splits a
+//! backend page into documents, [`compare_values`] orders them the way the
+//! service does, [`hash_value`] identifies equal documents, and
+//! [`PageAccumulator`] wraps the documents a node emits back into a page.
+
+use std::{cmp::Ordering, sync::Arc};
+
+use serde::Deserialize;
+use serde_json::Value;
+
+use crate::{
+    diagnostics::DiagnosticsContext,
+    models::{CosmosResponse, RequestCharge, ResponseBody},
+};
+
+/// The `Documents` envelope of a query response page.
+#[derive(Deserialize)]
+struct DocumentsEnvelope {
+    #[serde(rename = "Documents", default)]
+    documents: Vec<Value>,
+}
+
+/// Parses the documents of a backend query page.
+pub(crate) fn page_documents(response: &CosmosResponse) -> crate::error::Result<Vec<Value>> {
+    match response.body() {
+        ResponseBody::NoPayload => Ok(Vec::new()),
+        ResponseBody::Bytes(bytes) if bytes.is_empty() => Ok(Vec::new()),
+        ResponseBody::Bytes(bytes) => serde_json::from_slice::<DocumentsEnvelope>(bytes)
+            .map(|envelope| envelope.documents)
+            .map_err(invalid_query_response),
+        ResponseBody::Items(items) => items
+            .iter()
+            .map(|item| serde_json::from_slice(item).map_err(invalid_query_response))
+            .collect(),
+    }
+}
+
+/// Builds the error returned when a backend page does not have the shape the
+/// query plan promised (e.g. an `ORDER BY` result without `orderByItems`).
+pub(crate) fn invalid_query_response(
+    source: impl std::error::Error + Send + Sync + 'static,
+) -> crate::error::CosmosError {
+    crate::error::CosmosError::builder()
+        .with_status(crate::error::CosmosStatus::SERIALIZATION_RESPONSE_BODY_INVALID)
+        .with_message("query response does not match the shape required by the query plan")
+        .with_source(source)
+        .build()
+}
+
+/// Builds the error returned when a rewritten query result is missing a
+/// required field.
+pub(crate) fn missing_query_field(field: &str) -> crate::error::CosmosError {
+    invalid_query_response(std::io::Error::other(format!(
+        "query result is missing the `{field}` field"
+    )))
+}
+
+/// Compares two optional JSON values using the Cosmos DB cross-type ordering.
+///
+/// `None` stands for `undefined`. Values of different types order as
+/// `undefined < null < boolean < number < string < array < object`; values of
+/// the same type compare naturally. Strings compare by UTF-16 code unit, which
+/// matches the service's ordinal string ordering.
+pub(crate) fn compare_values(left: Option<&Value>, right: Option<&Value>) -> Ordering {
+    match (left, right) {
+        (None, None) => Ordering::Equal,
+        (None, Some(_)) => Ordering::Less,
+        (Some(_), None) => Ordering::Greater,
+        (Some(left), Some(right)) => compare_defined(left, right),
+    }
+}
+
+fn compare_defined(left: &Value, right: &Value) -> Ordering {
+    match (left, right) {
+        (Value::Bool(l), Value::Bool(r)) => l.cmp(r),
+        (Value::Number(l), Value::Number(r)) => {
+            let l = l.as_f64().unwrap_or_default();
+            let r = r.as_f64().unwrap_or_default();
+            l.total_cmp(&r)
+        }
+        (Value::String(l), Value::String(r)) => l.encode_utf16().cmp(r.encode_utf16()),
+        (Value::Array(l), Value::Array(r)) => l
+            .iter()
+            .zip(r)
+            .map(|(l, r)| compare_defined(l, r))
+            .find(|ordering| ordering.is_ne())
+            .unwrap_or_else(|| l.len().cmp(&r.len())),
+        (Value::Object(l), Value::Object(r)) => {
+            let mut l: Vec<_> = l.iter().collect();
+            let mut r: Vec<_> = r.iter().collect();
+            l.sort_by(|a, b| a.0.cmp(b.0));
+            r.sort_by(|a, b| a.0.cmp(b.0));
+            l.iter()
+                .zip(&r)
+                .map(|((lk, lv), (rk, rv))| lk.cmp(rk).then_with(|| compare_defined(lv, rv)))
+                .find(|ordering| ordering.is_ne())
+                .unwrap_or_else(|| l.len().cmp(&r.len()))
+        }
+        _ => type_rank(left).cmp(&type_rank(right)),
+    }
+}
+
+/// FNV-1a 128-bit offset basis.
+const FNV_OFFSET_BASIS: u128 = 0x6c62272e07bb014262b821756295c58d;
+
+/// FNV-1a 128-bit prime.
+const FNV_PRIME: u128 = 0x0000000001000000000000000000013b;
+
+/// Hashes a JSON value so that values the service considers equal hash equal.
+///
+/// The hash is a 128-bit FNV-1a over a canonical encoding of the value:
+/// object keys are sorted and numbers are hashed by their `f64` value, so
+/// `{"a":1,"b":2}` and `{"b":2.0,"a":1}` hash the same. The hash is stable
+/// across processes, which lets it be stored in continuation tokens.
+pub(crate) fn hash_value(value: &Value) -> u128 {
+    let mut hash = FNV_OFFSET_BASIS;
+    hash_into(value, &mut hash);
+    hash
+}
+
+fn hash_into(value: &Value, hash: &mut u128) {
+    fnv_write(hash, &[type_rank(value)]);
+    match value {
+        Value::Null => {}
+        Value::Bool(b) => fnv_write(hash, &[u8::from(*b)]),
+        Value::Number(n) => {
+            // `+ 0.0` folds `-0.0` into `0.0`.
+            let n = n.as_f64().unwrap_or_default() + 0.0;
+            fnv_write(hash, &n.to_bits().to_le_bytes());
+        }
+        Value::String(s) => fnv_write_str(hash, s),
+        Value::Array(items) => {
+            fnv_write(hash, &(items.len() as u64).to_le_bytes());
+            for item in items {
+                hash_into(item, hash);
+            }
+        }
+        Value::Object(map) => {
+            fnv_write(hash, &(map.len() as u64).to_le_bytes());
+            let mut entries: Vec<_> = map.iter().collect();
+            entries.sort_by(|a, b| a.0.cmp(b.0));
+            for (key, value) in entries {
+                fnv_write_str(hash, key);
+                hash_into(value, hash);
+            }
+        }
+    }
+}
+
+fn fnv_write_str(hash: &mut u128, s: &str) {
+    fnv_write(hash, &(s.len() as u64).to_le_bytes());
+    fnv_write(hash, s.as_bytes());
+}
+
+fn fnv_write(hash: &mut u128, bytes: &[u8]) {
+    for byte in bytes {
+        *hash ^= u128::from(*byte);
+        *hash = hash.wrapping_mul(FNV_PRIME);
+    }
+}
+
+fn type_rank(value: &Value) -> u8 {
+    match value {
+        Value::Null => 0,
+        Value::Bool(_) => 1,
+        Value::Number(_) => 2,
+        Value::String(_) => 3,
+        Value::Array(_) => 4,
+        Value::Object(_) => 5,
+    }
+}
+
+/// Collects the backend responses behind the pages a node synthesizes.
+///
+/// Every backend response a node consumes is [`record`](Self::record)ed;
+/// [`take_page`](Self::take_page) then wraps the documents the node emits in a
+/// `Documents` envelope. The page carries the headers of the most recent
+/// backend response, the request charge, query metrics and index utilization
+/// combined across every response recorded since the previous page, and their
+/// aggregated diagnostics. The page never
+/// carries a server continuation: continuing a merged result is the pipeline's
+/// job, not the server's.
+#[derive(Default)]
+pub(crate) struct PageAccumulator {
+    last: Option<CosmosResponse>,
+    request_charge: Option<RequestCharge>,
+    query_metrics: Option<String>,
+    index_metrics: Option<String>,
+    diagnostics: Vec<Arc<DiagnosticsContext>>,
+}
+
+impl PageAccumulator {
+    /// Records a backend response consumed by the node.
+    pub(crate) fn record(&mut self, response: &CosmosResponse) {
+        if let Some(charge) = response.headers().request_charge {
+            self.request_charge = Some(match self.request_charge {
+                Some(total) => total + charge,
+                None => charge,
+            });
+        }
+        if let Some(metrics) = response.headers().query_metrics.as_deref() {
+            self.query_metrics = Some(match self.query_metrics.take() {
+                Some(total) => merge_query_metrics(&total, metrics),
+                None => metrics.to_owned(),
+            });
+        }
+        if let Some(metrics) = response.headers().index_metrics.as_deref() {
+            self.index_metrics = Some(match self.index_metrics.take() {
+                Some(total) => merge_index_metrics(&total, metrics),
+                None => metrics.to_owned(),
+            });
+        }
+        self.diagnostics.push(response.diagnostics());
+        self.last = Some(response.clone());
+    }
+
+    /// Wraps `documents` in a synthesized page.
+    ///
+    /// Returns `None` if no backend response has ever been recorded, in which
+    /// case there is nothing to report a page for.
+    pub(crate) fn take_page(&mut self, documents: Vec<Value>) -> Option<CosmosResponse> {
+        let last = self.last.as_ref()?;
+        let mut headers = last.headers().clone();
+        headers.continuation = None;
+        headers.request_charge = self.request_charge.take().or(Some(RequestCharge::new(0.0)));
+        headers.query_metrics = self.query_metrics.take();
+        headers.index_metrics = self.index_metrics.take();
+        headers.item_count = u32::try_from(documents.len()).ok();
+
+        let diagnostics = std::mem::take(&mut self.diagnostics);
+        let diagnostics = DiagnosticsContext::aggregate_sub_operations(&diagnostics)
+            .map(Arc::new)
+            .unwrap_or_else(|| last.diagnostics());
+
+        let count = documents.len();
+        let body = serde_json::json!({ "Documents": documents, "_count": count });
+        Some(CosmosResponse::new(
+            body.to_string().into_bytes(),
+            headers,
+            last.status(),
+            diagnostics,
+        ))
+    }
+}
+
+/// Combines two `x-ms-documentdb-query-metrics` values.
+///
+/// Counts, sizes and times are summed, and `indexUtilizationRatio` is weighted
+/// by `retrievedDocumentCount`. Keys keep the order they first appeared in.
+/// Returns `other` if either value is malformed.
+fn merge_query_metrics(total: &str, other: &str) -> String {
+    const RATIO: &str = "indexUtilizationRatio";
+    const RETRIEVED: &str = "retrievedDocumentCount";
+
+    fn parse(value: &str) -> Option<Vec<(&str, f64)>> {
+        value
+            .split(';')
+            .filter(|pair| !pair.trim().is_empty())
+            .map(|pair| {
+                let (key, value) = pair.split_once('=')?;
+                Some((key.trim(), value.trim().parse().ok()?))
+            })
+            .collect()
+    }
+    fn get(metrics: &[(&str, f64)], key: &str) -> f64 {
+        metrics
+            .iter()
+            .find_map(|(k, v)| (*k == key).then_some(*v))
+            .unwrap_or_default()
+    }
+
+    let (Some(mut merged), Some(other)) = (parse(total), parse(other)) else {
+        return other.to_owned();
+    };
+    let retrieved = (get(&merged, RETRIEVED), get(&other, RETRIEVED));
+    let hits = get(&merged, RATIO) * retrieved.0 + get(&other, RATIO) * retrieved.1;
+    for (key, value) in &other {
+        match merged.iter_mut().find(|(k, _)| k == key) {
+            Some((_, total)) => *total += value,
+            None => merged.push((*key, *value)),
+        }
+    }
+    if let Some((_, ratio)) = merged.iter_mut().find(|(k, _)| *k == RATIO) {
+        let retrieved = retrieved.0 + retrieved.1;
+        *ratio = if retrieved > 0.0 {
+            hits / retrieved
+        } else {
+            1.0
+        };
+    }
+    merged
+        .iter()
+        .map(|(key, value)| format!("{key}={value}"))
+        .collect::<Vec<_>>()
+        .join(";")
+}
+
+/// Combines two decoded `x-ms-cosmos-index-utilization` values.
+///
+/// Each list of utilized and potential indexes is concatenated, skipping
+/// entries already present. Returns `other` if either value is malformed.
+fn merge_index_metrics(total: &str, other: &str) -> String {
+    let (Ok(Value::Object(mut merged)), Ok(Value::Object(other_metrics))) = (
+        serde_json::from_str::<Value>(total),
+        serde_json::from_str::<Value>(other),
+    ) else {
+        return other.to_owned();
+    };
+    for (key, value) in other_metrics {
+        match (merged.get_mut(&key), value) {
+            (Some(Value::Array(indexes)), Value::Array(more)) => {
+                for index in more {
+                    if !indexes.contains(&index) {
+                        indexes.push(index);
+                    }
+                }
+            }
+            (_, value) => {
+                merged.insert(key, value);
+            }
+        }
+    }
+    Value::Object(merged).to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::mocks::*;
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn compare_values_orders_across_types() {
+        let ordered = [
+            None,
+            Some(json!(null)),
+            Some(json!(false)),
+            Some(json!(true)),
+            Some(json!(-1.5)),
+            Some(json!(2)),
+            Some(json!("")),
+            Some(json!("a")),
+            Some(json!([1])),
+            Some(json!({"a": 1})),
+        ];
+        for pair in ordered.windows(2) {
+            assert_eq!(
+                compare_values(pair[0].as_ref(), pair[1].as_ref()),
+                Ordering::Less,
+                "{:?} should sort before {:?}",
+                pair[0],
+                pair[1],
+            );
+        }
+        assert_eq!(
+            compare_values(Some(&json!(1)), Some(&json!(1.0))),
+            Ordering::Equal
+        );
+    }
+
+    #[test]
+    fn compare_values_orders_strings_by_utf16_code_unit() {
+        // U+FF21 (FULLWIDTH A) is a single code unit above the surrogate
+        // range, while U+1F600 is encoded as a surrogate pair starting at
+        // 0xD83D, so ordinal UTF-16 ordering puts the emoji first.
+        assert_eq!(
+            compare_values(Some(&json!("\u{1F600}")), Some(&json!("\u{FF21}"))),
+            Ordering::Less
+        );
+    }
+
+    #[test]
+    fn hash_value_ignores_key_order_and_number_representation() {
+        assert_eq!(
+            hash_value(&json!({"a": 1, "b": [true, null]})),
+            hash_value(&json!({"b": [true, null], "a": 1.0}))
+        );
+        assert_eq!(hash_value(&json!(-0.0)), hash_value(&json!(0)));
+        assert_ne!(hash_value(&json!("1")), hash_value(&json!(1)));
+        assert_ne!(hash_value(&json!(["a", "b"])), hash_value(&json!(["ab"])));
+        assert_ne!(hash_value(&json!({})), hash_value(&json!([])));
+    }
+
+    #[test]
+    fn page_documents_reads_envelope() {
+        let page = response(br#"{"_rid":"x","Documents":[{"id":"1"},{"id":"2"}],"_count":2}"#);
+        assert_eq!(
+            page_documents(&page).unwrap(),
+            vec![json!({"id": "1"}), json!({"id": "2"})]
+        );
+        assert!(page_documents(&response(b"not json")).is_err());
+    }
+
+    #[test]
+    fn take_page_sums_charge_and_clears_continuation() {
+        let first = response_with_charge(b"{}", 1.5);
+        let second = response_with_charge(b"{}", 2.0);
+
+        let mut accumulator = PageAccumulator::default();
+        assert!(accumulator.take_page(vec![]).is_none());
+        accumulator.record(&first);
+        accumulator.record(&second);
+        let page = accumulator.take_page(vec![json!(1), json!(2)]).unwrap();
+
+        assert_eq!(page.headers().request_charge, Some(RequestCharge::new(3.5)));
+        assert_eq!(page.headers().continuation, None);
+        assert_eq!(page.headers().item_count, Some(2));
+        assert_eq!(
+            serde_json::from_slice::<Value>(page.body_bytes()).unwrap(),
+            json!({"Documents": [1, 2], "_count": 2})
+        );
+
+        // The charge is reset once reported.
+        let page = accumulator.take_page(vec![]).unwrap();
+        assert_eq!(page.headers().request_charge, Some(RequestCharge::new(0.0)));
+    }
+
+    fn response_with_metrics(query_metrics: &str, index_metrics: &str) -> CosmosResponse {
+        let response = response(b"{}");
+        let mut headers = response.headers().clone();
+        headers.query_metrics = Some(query_metrics.to_owned());
+        headers.index_metrics = Some(index_metrics.to_owned());
+        CosmosResponse::new(
+            b"{}".to_vec(),
+            headers,
+            response.status(),
+            response.diagnostics(),
+        )
+    }
+
+    #[test]
+    fn take_page_combines_query_and_index_metrics() {
+        let first = response_with_metrics(
+            "totalExecutionTimeInMs=2.5;retrievedDocumentCount=100;indexUtilizationRatio=0.5",
+            r#"{"UtilizedSingleIndexes":[{"IndexSpec":"/a/?"}],"PotentialSingleIndexes":[]}"#,
+        );
+        let second = response_with_metrics(
+            "totalExecutionTimeInMs=1.5;retrievedDocumentCount=300;indexUtilizationRatio=1;outputDocumentCount=7",
+            r#"{"UtilizedSingleIndexes":[{"IndexSpec":"/a/?"},{"IndexSpec":"/b/?"}],"PotentialSingleIndexes":[]}"#,
+        );
+
+        let mut accumulator = PageAccumulator::default();
+        accumulator.record(&first);
+        accumulator.record(&second);
+        let page = accumulator.take_page(vec![]).unwrap();
+
+        assert_eq!(
+            page.headers().query_metrics.as_deref(),
+            Some("totalExecutionTimeInMs=4;retrievedDocumentCount=400;indexUtilizationRatio=0.875;outputDocumentCount=7")
+        );
+        assert_eq!(
+            serde_json::from_str::<Value>(page.headers().index_metrics.as_deref().unwrap())
+                .unwrap(),
+            json!({
+                "UtilizedSingleIndexes": [{"IndexSpec": "/a/?"}, {"IndexSpec": "/b/?"}],
+                "PotentialSingleIndexes": [],
+            })
+        );
+
+        // The metrics are reset once reported.
+        let page = accumulator.take_page(vec![]).unwrap();
+        assert_eq!(page.headers().query_metrics, None);
+        assert_eq!(page.headers().index_metrics, None);
+    }
+
+    #[test]
+    fn merge_query_metrics_keeps_latest_when_malformed() {
+        assert_eq!(
+            merge_query_metrics("retrievedDocumentCount=abc", "retrievedDocumentCount=1"),
+            "retrievedDocumentCount=1"
+        );
+    }
+}
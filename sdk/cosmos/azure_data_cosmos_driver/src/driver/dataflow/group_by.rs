@@ -0,0 +1,360 @@
+// Copyright (c) Microsoft Corporation. All rights reserved.
+// Licensed under the MIT License.
+
+//! Group-by node for cross-partition `GROUP BY` queries.
+//!
+//! Each partition groups its own results, so the same group can come back
+//! from several partitions. The query plan's rewritten query returns every
+//! partition-local group as
+//! `{"groupByItems": [{"item": <key>}, ...], "payload": <row>}`, where each
+//! aggregate in the row is wrapped as `{"item": <partial>}`. [`GroupBy`] reads
+//! its source to the end, merges the rows of each group and emits the final
+//! rows.
+
+use std::collections::HashMap;
+
+use async_trait::async_trait;
+use serde_json::{Map, Value};
+
+use super::{
+    documents::{hash_value, missing_query_field, page_documents, PageAccumulator},
+    AggregateKind, PageResult, PipelineContext, PipelineNode, PipelineNodeState,
+};
+
+/// How a [`GroupBy`] node turns a group's partition-local rows into its
+/// final row.
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) enum GroupProjection {
+    /// `SELECT VALUE <aggregate>`: each row is the wrapped partial aggregate.
+    ValueAggregate(AggregateKind),
+    /// `SELECT VALUE <expression>`: each row is the grouped value itself.
+    Value,
+    /// `SELECT <expression> AS <alias>, ...`: each row is an object keyed by
+    /// alias. Aliases are listed in output order, each with its aggregate
+    /// function or `None` for a grouped (non-aggregate) expression.
+    Aliases(Vec<(String, Option<AggregateKind>)>),
+}
+
+impl GroupProjection {
+    /// Merges the partition-local rows of one group.
+    ///
+    /// Returns `None` when the group's row is undefined, in which case the
+    /// group produces no result.
+    fn combine(&self, rows: &[Value]) -> crate::error::Result<Option<Value>> {
+        match self {
+            Self::ValueAggregate(kind) => kind.combine(rows),
+            Self::Value => Ok(rows.first().cloned()),
+            Self::Aliases(aliases) => {
+                let mut result = Map::new();
+                for (alias, aggregate) in aliases {
+                    let values = rows.iter().map(|row| row.get(alias));
+                    let value = match aggregate {
+                        Some(kind) => {
+                            let partials: Vec<Value> =
+                                values.map(|v| v.cloned().unwrap_or(Value::Null)).collect();
+                            kind.combine(&partials)?
+                        }
+                        // Every row of a group agrees on its grouped values.
+                        None => values.flatten().next().cloned(),
+                    };
+                    if let Some(value) = value {
+                        result.insert(alias.clone(), value);
+                    }
+                }
+                Ok(Some(Value::Object(result)))
+            }
+        }
+    }
+}
+
+/// Merges the partition-local groups of its source into the final groups.
+///
+/// The first call to `next_page` reads the source to the end and returns one
+/// terminal page holding a row per group, in the order the groups were first
+/// seen. The page carries the request charge of every backend page read to
+/// produce it.
+pub(crate) struct GroupBy {
+    source: Box<dyn PipelineNode>,
+    projection: GroupProjection,
+    /// The rewritten-query results read from the source so far.
+    rows: Vec<Value>,
+    accumulator: PageAccumulator,
+    emitted: bool,
+}
+
+impl GroupBy {
+    /// Creates a group-by over the given source.
+    #[cfg(test)]
+    pub(crate) fn new(source: Box<dyn PipelineNode>, projection: GroupProjection) -> Self {
+        Self::resumed(source, projection, Vec::new())
+    }
+
+    /// Creates a group-by restored from a [`PipelineNodeState::GroupBy`]
+    /// snapshot, with the rows read before the snapshot was taken.
+    pub(crate) fn resumed(
+        source: Box<dyn PipelineNode>,
+        projection: GroupProjection,
+        rows: Vec<Value>,
+    ) -> Self {
+        debug_assert!(
+            !source.topology_can_change(),
+            "group-by source must handle topology changes itself"
+        );
+        Self {
+            source,
+            projection,
+            rows,
+            accumulator: PageAccumulator::default(),
+            emitted: false,
+        }
+    }
+
+    /// Groups the rows read from the source and builds each group's result.
+    fn combine_groups(&self) -> crate::error::Result<Vec<Value>> {
+        let mut groups: Vec<Vec<Value>> = Vec::new();
+        let mut group_indices = HashMap::new();
+        for row in &self.rows {
+            let Some(key) = row.get("groupByItems") else {
+                return Err(missing_query_field("groupByItems"));
+            };
+            let payload = row.get("payload").cloned().unwrap_or(Value::Null);
+            let group = *group_indices.entry(hash_value(key)).or_insert_with(|| {
+                groups.push(Vec::new());
+                groups.len() - 1
+            });
+            groups[group].push(payload);
+        }
+
+        let mut results = Vec::with_capacity(groups.len());
+        for rows in groups {
+            results.extend(self.projection.combine(&rows)?);
+        }
+        Ok(results)
+    }
+}
+
+#[async_trait]
+impl PipelineNode for GroupBy {
+    async fn next_page(
+        &mut self,
+        context: &mut PipelineContext<'_>,
+    ) -> crate::error::Result<PageResult> {
+        if self.emitted {
+            return Ok(PageResult::Drained);
+        }
+
+        loop {
+            match self.source.next_page(context).await? {
+                PageResult::Page {
+                    response,
+                    is_terminal,
+                } => {
+                    self.accumulator.record(&response);
+                    self.rows.extend(page_documents(&response)?);
+                    if is_terminal {
+                        break;
+                    }
+                }
+                PageResult::Drained => break,
+                // Defensive: the source handles topology changes itself (see
+                // `resumed`), so this never happens in practice.
+                split @ PageResult::SplitRequired { .. } => return Ok(split),
+            }
+        }
+
+        let results = self.combine_groups()?;
+        self.emitted = true;
+        self.rows.clear();
+        match self.accumulator.take_page(results) {
+            Some(response) => Ok(PageResult::Page {
+                response,
+                is_terminal: true,
+            }),
+            None => Ok(PageResult::Drained),
+        }
+    }
+
+    #[cfg(test)]
+    fn into_children(self) -> Vec<Box<dyn PipelineNode>> {
+        vec![self.source]
+    }
+
+    fn snapshot_state(&self) -> crate::error::Result<PipelineNodeState> {
+        if self.emitted {
+            return Ok(PipelineNodeState::Drained);
+        }
+        Ok(PipelineNodeState::GroupBy {
+            rows: self.rows.clone(),
+            source: Box::new(self.source.snapshot_state()?),
+        })
+    }
+
+    fn topology_can_change(&self) -> bool {
+        false
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::mocks::*;
+    use super::*;
+    use serde_json::json;
+
+    fn page(rows: &[Value], is_terminal: bool) -> crate::error::Result<PageResult> {
+        Ok(PageResult::Page {
+            response: response(json!({ "Documents": rows }).to_string().as_bytes()),
+            is_terminal,
+        })
+    }
+
+    fn emitted(result: crate::error::Result<PageResult>) -> Vec<Value> {
+        let response = unwrap_page(result);
+        let body: Value = serde_json::from_slice(response.body_bytes()).unwrap();
+        body["Documents"].as_array().unwrap().clone()
+    }
+
+    fn city_row(city: &str, count: i64, avg: (i64, u64)) -> Value {
+        json!({
+            "groupByItems": [{"item": city}],
+            "payload": {
+                "city": city,
+                "n": {"item": count},
+                "avg": {"item": {"sum": avg.0, "count": avg.1}},
+            },
+        })
+    }
+
+    fn city_projection() -> GroupProjection {
+        GroupProjection::Aliases(vec![
+            ("city".to_owned(), None),
+            ("n".to_owned(), Some(AggregateKind::Count)),
+            ("avg".to_owned(), Some(AggregateKind::Average)),
+        ])
+    }
+
+    #[tokio::test]
+    async fn merges_groups_across_partitions_in_first_seen_order() {
+        let source = MockLeaf::with_pages(vec![
+            page(
+                &[city_row("oslo", 2, (10, 2)), city_row("rome", 1, (3, 1))],
+                false,
+            ),
+            page(
+                &[city_row("rome", 3, (9, 3)), city_row("oslo", 1, (2, 1))],
+                true,
+            ),
+        ]);
+        let mut node = GroupBy::new(Box::new(source), city_projection());
+        let mut executor = NoopRequestExecutor;
+        let mut context = PipelineContext::new(&mut executor, None);
+
+        assert_eq!(
+            emitted(node.next_page(&mut context).await),
+            vec![
+                json!({"city": "oslo", "n": 3, "avg": 4.0}),
+                json!({"city": "rome", "n": 4, "avg": 3.0}),
+            ]
+        );
+        assert_drained(node.next_page(&mut context).await);
+        assert_eq!(node.snapshot_state().unwrap(), PipelineNodeState::Drained);
+    }
+
+    #[tokio::test]
+    async fn undefined_values_are_omitted_from_rows() {
+        let rows = [
+            json!({"groupByItems": [{}], "payload": {"n": {"item": 1}, "s": {}}}),
+            json!({"groupByItems": [{}], "payload": {"n": {"item": 2}, "s": {}}}),
+        ];
+        let source = MockLeaf::with_pages(vec![page(&rows, true)]);
+        let projection = GroupProjection::Aliases(vec![
+            ("city".to_owned(), None),
+            ("n".to_owned(), Some(AggregateKind::Count)),
+            ("s".to_owned(), Some(AggregateKind::Sum)),
+        ]);
+        let mut node = GroupBy::new(Box::new(source), projection);
+        let mut executor = NoopRequestExecutor;
+        let mut context = PipelineContext::new(&mut executor, None);
+
+        assert_eq!(
+            emitted(node.next_page(&mut context).await),
+            vec![json!({"n": 3})]
+        );
+    }
+
+    #[tokio::test]
+    async fn select_value_projections() {
+        let rows = [
+            json!({"groupByItems": [{"item": 1}], "payload": {"item": {"max": 5, "count": 1}}}),
+            json!({"groupByItems": [{"item": 2}], "payload": {"item": {"count": 0}}}),
+            json!({"groupByItems": [{"item": 1}], "payload": {"item": {"max": 9, "count": 2}}}),
+        ];
+        let source = MockLeaf::with_pages(vec![page(&rows, true)]);
+        let mut node = GroupBy::new(
+            Box::new(source),
+            GroupProjection::ValueAggregate(AggregateKind::Max),
+        );
+        let mut executor = NoopRequestExecutor;
+        let mut context = PipelineContext::new(&mut executor, None);
+        assert_eq!(emitted(node.next_page(&mut context).await), vec![json!(9)]);
+
+        let rows = [
+            json!({"groupByItems": [{"item": "a"}], "payload": "a"}),
+            json!({"groupByItems": [{"item": "b"}], "payload": "b"}),
+            json!({"groupByItems": [{"item": "a"}], "payload": "a"}),
+        ];
+        let source = MockLeaf::with_pages(vec![page(&rows, true)]);
+        let mut node = GroupBy::new(Box::new(source), GroupProjection::Value);
+        assert_eq!(
+            emitted(node.next_page(&mut context).await),
+            vec![json!("a"), json!("b")]
+        );
+    }
+
+    #[tokio::test]
+    async fn rejects_rows_without_group_by_items() {
+        let source = MockLeaf::with_pages(vec![page(&[json!({"payload": 1})], true)]);
+        let mut node = GroupBy::new(Box::new(source), GroupProjection::Value);
+        let mut executor = NoopRequestExecutor;
+        let mut context = PipelineContext::new(&mut executor, None);
+
+        assert!(node.next_page(&mut context).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn resumed_rows_are_grouped_with_new_results() {
+        let source = MockLeaf::with_pages(vec![page(&[city_row("oslo", 2, (4, 2))], true)]);
+        let mut node = GroupBy::resumed(
+            Box::new(source),
+            city_projection(),
+            vec![city_row("oslo", 1, (1, 1))],
+        );
+        let mut executor = NoopRequestExecutor;
+        let mut context = PipelineContext::new(&mut executor, None);
+
+        assert_eq!(
+            emitted(node.next_page(&mut context).await),
+            vec![json!({"city": "oslo", "n": 3, "avg": 5.0 / 3.0})]
+        );
+    }
+
+    #[test]
+    fn snapshot_carries_rows_and_source_state() {
+        let pending = PipelineNodeState::Request {
+            server_continuation: Some("t1".to_owned()),
+        };
+        let source = MockLeaf::with_pages(vec![]).with_snapshot(pending.clone());
+        let node = GroupBy::resumed(
+            Box::new(source),
+            GroupProjection::Value,
+            vec![json!({"groupByItems": [], "payload": 1})],
+        );
+
+        assert_eq!(
+            node.snapshot_state().unwrap(),
+            PipelineNodeState::GroupBy {
+                rows: vec![json!({"groupByItems": [], "payload": 1})],
+                source: Box::new(pending),
+            }
+        );
+    }
+}
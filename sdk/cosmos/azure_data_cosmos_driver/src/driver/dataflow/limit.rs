@@ -0,0 +1,230 @@
+// Copyright (c) Microsoft Corporation. All rights reserved.
+// Licensed under the MIT License.
+
+//! Limit node for cross-partition `TOP` and `OFFSET ... LIMIT` queries.
+//!
+//! Each partition applies the query's row limit to its own results only (the
+//! plan's rewritten query turns `OFFSET o LIMIT l` into `OFFSET 0 LIMIT o + l`),
+//! so the combined results can still hold too many rows. [`Limit`] skips the
+//! offset and cuts the results off at the limit, and stops reading its source
+//! once the limit is reached so no further backend requests are sent.
+
+use async_trait::async_trait;
+
+use super::{
+    documents::{page_documents, PageAccumulator},
+    PageResult, PipelineContext, PipelineNode, PipelineNodeState,
+};
+
+/// Skips the first `offset` results of its source and emits at most `limit`
+/// of the rest.
+///
+/// Each source page is forwarded as one page holding its remaining results,
+/// so a page can be empty when all of its results were skipped. The page that
+/// reaches the limit is terminal.
+pub(crate) struct Limit {
+    source: Box<dyn PipelineNode>,
+    /// Results still to be skipped.
+    offset: u64,
+    /// Results still to be emitted, or `None` for no limit.
+    limit: Option<u64>,
+}
+
+impl Limit {
+    /// Creates a limit over the given source, with the offset and limit still
+    /// to be applied (restored from a [`PipelineNodeState::Limit`] snapshot on
+    /// resume).
+    pub(crate) fn new(source: Box<dyn PipelineNode>, offset: u64, limit: Option<u64>) -> Self {
+        debug_assert!(
+            !source.topology_can_change(),
+            "limit source must handle topology changes itself"
+        );
+        Self {
+            source,
+            offset,
+            limit,
+        }
+    }
+
+    fn limit_reached(&self) -> bool {
+        self.limit == Some(0)
+    }
+}
+
+fn saturating_usize(value: u64) -> usize {
+    usize::try_from(value).unwrap_or(usize::MAX)
+}
+
+#[async_trait]
+impl PipelineNode for Limit {
+    async fn next_page(
+        &mut self,
+        context: &mut PipelineContext<'_>,
+    ) -> crate::error::Result<PageResult> {
+        if self.limit_reached() {
+            return Ok(PageResult::Drained);
+        }
+
+        match self.source.next_page(context).await? {
+            PageResult::Page {
+                response,
+                is_terminal,
+            } => {
+                let mut documents = page_documents(&response)?;
+                let skipped = documents.len().min(saturating_usize(self.offset));
+                documents.drain(..skipped);
+                self.offset -= skipped as u64;
+                if let Some(limit) = &mut self.limit {
+                    documents.truncate(saturating_usize(*limit));
+                    *limit -= documents.len() as u64;
+                }
+
+                let mut accumulator = PageAccumulator::default();
+                accumulator.record(&response);
+                let response = accumulator
+                    .take_page(documents)
+                    .expect("a response was recorded");
+                Ok(PageResult::Page {
+                    response,
+                    is_terminal: is_terminal || self.limit_reached(),
+                })
+            }
+            other => Ok(other),
+        }
+    }
+
+    #[cfg(test)]
+    fn into_children(self) -> Vec<Box<dyn PipelineNode>> {
+        vec![self.source]
+    }
+
+    fn snapshot_state(&self) -> crate::error::Result<PipelineNodeState> {
+        if self.limit_reached() {
+            return Ok(PipelineNodeState::Drained);
+        }
+        let source = self.source.snapshot_state()?;
+        if source == PipelineNodeState::Drained {
+            return Ok(PipelineNodeState::Drained);
+        }
+        Ok(PipelineNodeState::Limit {
+            offset: self.offset,
+            limit: self.limit,
+            source: Box::new(source),
+        })
+    }
+
+    fn topology_can_change(&self) -> bool {
+        false
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::mocks::*;
+    use super::*;
+    use serde_json::{json, Value};
+
+    fn page(documents: &[Value], is_terminal: bool) -> crate::error::Result<PageResult> {
+        Ok(PageResult::Page {
+            response: response(json!({ "Documents": documents }).to_string().as_bytes()),
+            is_terminal,
+        })
+    }
+
+    /// Returns the documents of the node's next page and whether it is
+    /// terminal.
+    async fn next(node: &mut Limit, context: &mut PipelineContext<'_>) -> (Vec<Value>, bool) {
+        let result = node.next_page(context).await;
+        let is_terminal = matches!(
+            result,
+            Ok(PageResult::Page {
+                is_terminal: true,
+                ..
+            })
+        );
+        let response = unwrap_page(result);
+        let body: Value = serde_json::from_slice(response.body_bytes()).unwrap();
+        (body["Documents"].as_array().unwrap().clone(), is_terminal)
+    }
+
+    #[tokio::test]
+    async fn stops_reading_the_source_once_the_limit_is_reached() {
+        // The mock panics if a third page is ever requested.
+        let source = MockLeaf::with_pages(vec![
+            page(&[json!(1), json!(2)], false),
+            page(&[json!(3), json!(4)], false),
+        ]);
+        let mut node = Limit::new(Box::new(source), 0, Some(3));
+        let mut executor = NoopRequestExecutor;
+        let mut context = PipelineContext::new(&mut executor, None);
+
+        assert_eq!(
+            next(&mut node, &mut context).await,
+            (vec![json!(1), json!(2)], false)
+        );
+        assert_eq!(next(&mut node, &mut context).await, (vec![json!(3)], true));
+        assert_drained(node.next_page(&mut context).await);
+        assert_eq!(node.snapshot_state().unwrap(), PipelineNodeState::Drained);
+    }
+
+    #[tokio::test]
+    async fn skips_the_offset_across_pages() {
+        let source = MockLeaf::with_pages(vec![
+            page(&[json!(1), json!(2)], false),
+            page(&[json!(3), json!(4), json!(5)], false),
+            page(&[json!(6)], true),
+        ]);
+        let mut node = Limit::new(Box::new(source), 3, Some(2));
+        let mut executor = NoopRequestExecutor;
+        let mut context = PipelineContext::new(&mut executor, None);
+
+        assert_eq!(next(&mut node, &mut context).await, (vec![], false));
+        assert_eq!(
+            next(&mut node, &mut context).await,
+            (vec![json!(4), json!(5)], true)
+        );
+    }
+
+    #[tokio::test]
+    async fn zero_limit_sends_no_requests() {
+        let source = MockLeaf::with_pages(vec![page(&[json!(1)], true)]);
+        let mut node = Limit::new(Box::new(source), 0, Some(0));
+        let mut executor = NoopRequestExecutor;
+        let mut context = PipelineContext::new(&mut executor, None);
+
+        assert_drained(node.next_page(&mut context).await);
+    }
+
+    #[tokio::test]
+    async fn source_end_ends_the_results_before_the_limit() {
+        let source = MockLeaf::with_pages(vec![page(&[json!(1)], true)]);
+        let mut node = Limit::new(Box::new(source), 0, Some(5));
+        let mut executor = NoopRequestExecutor;
+        let mut context = PipelineContext::new(&mut executor, None);
+
+        assert_eq!(next(&mut node, &mut context).await, (vec![json!(1)], true));
+    }
+
+    #[test]
+    fn snapshot_carries_remaining_counts_and_source_state() {
+        let pending = PipelineNodeState::Request {
+            server_continuation: Some("t1".to_owned()),
+        };
+        let source = MockLeaf::with_pages(vec![]).with_snapshot(pending.clone());
+        let node = Limit::new(Box::new(source), 2, Some(7));
+        assert_eq!(
+            node.snapshot_state().unwrap(),
+            PipelineNodeState::Limit {
+                offset: 2,
+                limit: Some(7),
+                source: Box::new(pending),
+            }
+        );
+
+        let drained = Limit::new(Box::new(MockLeaf::with_pages(vec![])), 0, Some(1));
+        assert_eq!(
+            drained.snapshot_state().unwrap(),
+            PipelineNodeState::Drained
+        );
+    }
+}
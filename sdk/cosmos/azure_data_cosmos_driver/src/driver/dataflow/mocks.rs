@@ -263,6 +263,19 @@ pub(crate) fn response_with_continuation(
     )
 }
 
+/// Creates a test response with the given body and request charge.
+pub(crate) fn response_with_charge(body: &[u8], request_charge: f64) -> CosmosResponse {
+    let response = response(body);
+    let mut headers = response.headers().clone();
+    headers.request_charge = Some(crate::models::RequestCharge::new(request_charge));
+    CosmosResponse::new(
+        body.to_vec(),
+        headers,
+        response.status(),
+        response.diagnostics(),
+    )
+}
+
 /// Creates a 410 Gone error with a partition topology change substatus.
 pub(crate) fn gone_error() -> crate::error::CosmosError {
     crate::error::CosmosError::builder()
@@ -16,10 +16,18 @@
 //! - Intermediate nodes: [`SequentialDrain`] iterates EPK-ordered children
 //!   left-to-right, draining each before advancing. [`UnorderedMerge`] polls
 //!   children round-robin without evicting them, suitable for change feed.
+//!   [`OrderedMerge`] merges children by `ORDER BY` sort key.
+//! - Result-rewriting nodes: [`Aggregate`] reduces a source subtree to the
+//!   single value of a `SELECT VALUE` aggregate query, [`GroupBy`] merges the
+//!   partition-local groups of a `GROUP BY` query, [`Distinct`] drops
+//!   results its source already emitted, and [`Limit`] applies `TOP` and
+//!   `OFFSET ... LIMIT`.
 //! - Planner: [`planner::build_trivial_pipeline`] handles point reads and
-//!   single-partition operations; [`planner::build_sequential_drain`] handles
+//!   single-partition operations; [`planner::build_query_pipeline`] handles
 //!   cross-partition queries by consuming a backend query plan and resolving
 //!   it against the current topology.
+//! - Document helpers: [`documents`] parses backend pages into documents and
+//!   synthesizes the pages emitted by nodes that rewrite query results.
 //! - Serializable state: [`PipelineNodeState`] (see [`snapshot`]) is the
 //!   in-memory shape of a continuation snapshot; the wire-format token lives
 //!   in [`crate::models::ContinuationToken`].
@@ -31,14 +39,20 @@
 //! pipeline (paged operations, split recovery, continuation tokens, planned
 //! cross-partition strategies).
 
+mod aggregate;
 mod context;
+mod distinct;
+mod documents;
 mod drain;
 mod drained;
+mod group_by;
 #[cfg(test)]
 mod integration_tests;
+mod limit;
 #[cfg(test)]
 pub(crate) mod mocks;
 mod node;
+mod ordered_merge;
 mod pipeline;
 pub(crate) mod planner;
 pub(crate) mod query_plan;
@@ -47,16 +61,21 @@ mod snapshot;
 mod topology;
 mod unordered_merge;
 
+pub(crate) use aggregate::{Aggregate, AggregateKind};
 pub(crate) use context::{
     PartitionRoutingRefresh, PipelineContext, RequestExecutor, ResolvedRange, TopologyProvider,
 };
+pub(crate) use distinct::Distinct;
 pub(crate) use drain::SequentialDrain;
 pub(crate) use drained::DrainedLeaf;
+pub(crate) use group_by::{GroupBy, GroupProjection};
+pub(crate) use limit::Limit;
 pub(crate) use node::{PageResult, PipelineNode};
+pub(crate) use ordered_merge::OrderedMerge;
 pub use pipeline::OperationPlan;
 pub(crate) use pipeline::Pipeline;
 pub(crate) use request::{intersect_feed_ranges, Request, RequestTarget};
-pub(crate) use snapshot::{PipelineNodeState, RangedToken};
+pub(crate) use snapshot::{OrderedMergeCursor, PipelineNodeState, RangedToken};
 pub(crate) use topology::CachedTopologyProvider;
 pub(crate) use unordered_merge::UnorderedMerge;
 
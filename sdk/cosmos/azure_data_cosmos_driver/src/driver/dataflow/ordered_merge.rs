@@ -0,0 +1,621 @@
+// Copyright (c) Microsoft Corporation. All rights reserved.
+// Licensed under the MIT License.
+
+//! Ordered (k-way) merge node for cross-partition `ORDER BY` queries.
+//!
+//! Each child runs the query plan's rewritten query against one EPK range.
+//! The rewritten query returns every result wrapped as
+//! `{"orderByItems": [{"item": <key>}, ...], "payload": <result>}`, already
+//! sorted within the range. [`OrderedMerge`] buffers one page per child and
+//! repeatedly emits the payload with the smallest sort key across all
+//! children, so results stream in global order without reading any partition
+//! to the end first.
+
+use std::{cmp::Ordering, collections::VecDeque};
+
+use async_trait::async_trait;
+use serde_json::Value;
+
+use super::{
+    documents::{compare_values, missing_query_field, page_documents, PageAccumulator},
+    query_plan::SortOrder,
+    snapshot::{ChildSnapshotContribution, OrderedMergeCursor},
+    PageResult, PipelineContext, PipelineNode, PipelineNodeState,
+};
+
+/// Maximum number of consecutive split retries before giving up.
+const MAX_SPLIT_RETRIES: usize = 10;
+
+/// One result of a rewritten `ORDER BY` query.
+struct OrderByResult {
+    /// The sort key values; `None` is an undefined key.
+    sort_key: Vec<Option<Value>>,
+    /// The projected result; `None` when the projection was undefined.
+    payload: Option<Value>,
+}
+
+impl OrderByResult {
+    fn parse(document: Value) -> crate::error::Result<Self> {
+        let Value::Object(mut document) = document else {
+            return Err(missing_query_field("orderByItems"));
+        };
+        let Some(Value::Array(items)) = document.remove("orderByItems") else {
+            return Err(missing_query_field("orderByItems"));
+        };
+        let sort_key = items
+            .into_iter()
+            .map(|item| match item {
+                Value::Object(mut item) => item.remove("item"),
+                _ => None,
+            })
+            .collect();
+        Ok(Self {
+            sort_key,
+            payload: document.remove("payload"),
+        })
+    }
+}
+
+/// A child of an [`OrderedMerge`] together with its buffered page.
+struct MergeChild {
+    node: Box<dyn PipelineNode>,
+    /// Results from the child's current page that have not been emitted yet.
+    buffer: VecDeque<OrderByResult>,
+    /// The server continuation that produced the buffered page.
+    page_continuation: Option<String>,
+    /// How many results of the buffered page were already emitted.
+    consumed: u64,
+    /// How many results to drop from the child's next page (set on resume).
+    pending_skip: u64,
+    /// `true` once the child has produced its last page.
+    exhausted: bool,
+}
+
+/// Merges EPK-ordered children by the `ORDER BY` sort key of each result.
+///
+/// Every call to `next_page` first makes sure each live child has buffered
+/// results, fetching a page from any child whose buffer is empty. It then
+/// emits results in sort order until some child's buffer runs dry — at that
+/// point the next result could come from that child's next page, so the node
+/// returns what it has and refills on the following call. Ties are broken by
+/// child position (EPK order), matching the other Cosmos DB SDKs.
+///
+/// Emitted pages are synthesized by a [`PageAccumulator`]: they contain the
+/// unwrapped payloads and the request charge of every backend page fetched
+/// while producing them.
+pub(crate) struct OrderedMerge {
+    children: Vec<MergeChild>,
+    sort_orders: Vec<SortOrder>,
+    accumulator: PageAccumulator,
+}
+
+impl OrderedMerge {
+    /// Creates an ordered merge over the given children.
+    ///
+    /// Children must be ordered by EPK range from smallest to largest.
+    pub(crate) fn new(children: Vec<Box<dyn PipelineNode>>, sort_orders: Vec<SortOrder>) -> Self {
+        Self::resumed(
+            children.into_iter().map(|node| (node, 0)).collect(),
+            sort_orders,
+        )
+    }
+
+    /// Creates an ordered merge over children restored from a
+    /// [`PipelineNodeState::OrderedMerge`] snapshot.
+    ///
+    /// Each child is paired with the number of results to drop from its first
+    /// page, because they were emitted before the snapshot was taken.
+    pub(crate) fn resumed(
+        children: Vec<(Box<dyn PipelineNode>, u64)>,
+        sort_orders: Vec<SortOrder>,
+    ) -> Self {
+        Self {
+            children: children
+                .into_iter()
+                .map(|(node, skip)| MergeChild {
+                    node,
+                    buffer: VecDeque::new(),
+                    page_continuation: None,
+                    consumed: 0,
+                    pending_skip: skip,
+                    exhausted: false,
+                })
+                .collect(),
+            sort_orders,
+            accumulator: PageAccumulator::default(),
+        }
+    }
+
+    /// Fetches a page for every live child whose buffer is empty and evicts
+    /// children that have nothing left to emit.
+    async fn fill_buffers(
+        &mut self,
+        context: &mut PipelineContext<'_>,
+    ) -> crate::error::Result<()> {
+        let mut split_retries = 0;
+        let mut idx = 0;
+        while idx < self.children.len() {
+            let total = self.children.len();
+            let child = &mut self.children[idx];
+            if !child.buffer.is_empty() {
+                idx += 1;
+                continue;
+            }
+            if child.exhausted {
+                self.children.remove(idx);
+                continue;
+            }
+
+            let page_continuation = match child.node.snapshot_state()?.into_child_contribution(
+                "OrderedMerge",
+                idx,
+                total,
+            )? {
+                ChildSnapshotContribution::Pending {
+                    server_continuation,
+                } => server_continuation,
+                ChildSnapshotContribution::Drained => None,
+            };
+
+            match child.node.next_page(context).await? {
+                PageResult::Page {
+                    response,
+                    is_terminal,
+                } => {
+                    self.accumulator.record(&response);
+                    let mut results = page_documents(&response)?
+                        .into_iter()
+                        .map(OrderByResult::parse)
+                        .collect::<crate::error::Result<VecDeque<_>>>()?;
+                    let skip = child.pending_skip.min(results.len() as u64);
+                    results.drain(..skip as usize);
+                    child.pending_skip -= skip;
+                    child.buffer = results;
+                    child.page_continuation = page_continuation;
+                    child.consumed = skip;
+                    child.exhausted = is_terminal;
+                    // Re-check the same child: an empty non-terminal page
+                    // needs another fetch.
+                }
+                PageResult::Drained => child.exhausted = true,
+                PageResult::SplitRequired { replacement_nodes } => {
+                    split_retries += 1;
+                    if split_retries > MAX_SPLIT_RETRIES {
+                        return Err(crate::error::CosmosError::builder()
+                            .with_status(crate::error::CosmosStatus::CLIENT_SPLIT_RETRIES_EXHAUSTED)
+                            .with_message(format!(
+                                "exceeded maximum split retries ({MAX_SPLIT_RETRIES}) \
+                                 in OrderedMerge"
+                            ))
+                            .build());
+                    }
+                    if child.pending_skip > 0 {
+                        // The skip count refers to one page of the split
+                        // range; it cannot be distributed over the
+                        // replacement ranges without re-reading them.
+                        return Err(crate::error::CosmosError::builder()
+                            .with_status(
+                                crate::error::CosmosStatus::CLIENT_CONTINUATION_TOKEN_SAVED_RANGE_UNHONORED,
+                            )
+                            .with_message(
+                                "the partition a continuation token resumes an ORDER BY query \
+                                 in was split; the query cannot be safely resumed",
+                            )
+                            .build());
+                    }
+
+                    // Only children with an empty buffer are fetched, so the
+                    // replacements can take over without losing results.
+                    self.children.remove(idx);
+                    for (i, node) in replacement_nodes.into_iter().enumerate() {
+                        self.children.insert(
+                            idx + i,
+                            MergeChild {
+                                node,
+                                buffer: VecDeque::new(),
+                                page_continuation: None,
+                                consumed: 0,
+                                pending_skip: 0,
+                                exhausted: false,
+                            },
+                        );
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the index of the child whose next result sorts first.
+    fn min_child(&self) -> Option<usize> {
+        let mut min: Option<usize> = None;
+        for (idx, child) in self.children.iter().enumerate() {
+            let Some(candidate) = child.buffer.front() else {
+                continue;
+            };
+            let is_smaller = match min {
+                None => true,
+                Some(current) => {
+                    let current = self.children[current]
+                        .buffer
+                        .front()
+                        .expect("min child has a buffered result");
+                    self.compare(candidate, current) == Ordering::Less
+                }
+            };
+            if is_smaller {
+                min = Some(idx);
+            }
+        }
+        min
+    }
+
+    fn compare(&self, left: &OrderByResult, right: &OrderByResult) -> Ordering {
+        left.sort_key
+            .iter()
+            .zip(&right.sort_key)
+            .zip(&self.sort_orders)
+            .map(|((l, r), order)| {
+                let ordering = compare_values(l.as_ref(), r.as_ref());
+                match order {
+                    SortOrder::Ascending => ordering,
+                    SortOrder::Descending => ordering.reverse(),
+                }
+            })
+            .find(|ordering| ordering.is_ne())
+            .unwrap_or(Ordering::Equal)
+    }
+}
+
+#[async_trait]
+impl PipelineNode for OrderedMerge {
+    async fn next_page(
+        &mut self,
+        context: &mut PipelineContext<'_>,
+    ) -> crate::error::Result<PageResult> {
+        if self.children.is_empty() {
+            return Ok(PageResult::Drained);
+        }
+
+        self.fill_buffers(context).await?;
+
+        // Every live child now has buffered results. Emit until one of them
+        // runs dry, since its next page may hold the next result in order.
+        let mut documents = Vec::new();
+        while let Some(idx) = self.min_child() {
+            let child = &mut self.children[idx];
+            let result = child
+                .buffer
+                .pop_front()
+                .expect("min child has a buffered result");
+            child.consumed += 1;
+            // An undefined projection produces no result, as it would in a
+            // single-partition query.
+            if let Some(payload) = result.payload {
+                documents.push(payload);
+            }
+            if child.buffer.is_empty() {
+                if !child.exhausted {
+                    break;
+                }
+                self.children.remove(idx);
+            }
+        }
+
+        match self.accumulator.take_page(documents) {
+            Some(response) => Ok(PageResult::Page {
+                response,
+                is_terminal: self.children.is_empty(),
+            }),
+            None => Ok(PageResult::Drained),
+        }
+    }
+
+    #[cfg(test)]
+    fn into_children(self) -> Vec<Box<dyn PipelineNode>> {
+        self.children.into_iter().map(|child| child.node).collect()
+    }
+
+    fn snapshot_state(&self) -> crate::error::Result<PipelineNodeState> {
+        if self.children.is_empty() {
+            return Ok(PipelineNodeState::Drained);
+        }
+
+        let mut cursors = Vec::with_capacity(self.children.len());
+        for (idx, child) in self.children.iter().enumerate() {
+            let Some(range) = child.node.feed_range() else {
+                return Err(crate::error::CosmosError::builder()
+                    .with_status(
+                        crate::error::CosmosStatus::CLIENT_CONTINUATION_TOKEN_UNEXPECTED_NESTED_SHAPE,
+                    )
+                    .with_message(format!(
+                        "OrderedMerge child {idx} of {total} has no feed_range; \
+                         cannot snapshot continuation state safely",
+                        total = self.children.len(),
+                    ))
+                    .build());
+            };
+
+            // A child with buffered results resumes by re-reading the
+            // buffered page and skipping what was already emitted. A child
+            // with an empty buffer resumes from its own state.
+            let (server_continuation, skip) = if !child.buffer.is_empty() {
+                (child.page_continuation.clone(), child.consumed)
+            } else if child.exhausted {
+                continue;
+            } else {
+                match child.node.snapshot_state()?.into_child_contribution(
+                    "OrderedMerge",
+                    idx,
+                    self.children.len(),
+                )? {
+                    ChildSnapshotContribution::Drained => continue,
+                    ChildSnapshotContribution::Pending {
+                        server_continuation,
+                    } => (server_continuation, child.pending_skip),
+                }
+            };
+            cursors.push(OrderedMergeCursor {
+                min_epk: range.min_inclusive().to_hex(),
+                max_epk: range.max_exclusive().to_hex(),
+                server_continuation,
+                skip,
+            });
+        }
+
+        if cursors.is_empty() {
+            return Ok(PipelineNodeState::Drained);
+        }
+        Ok(PipelineNodeState::OrderedMerge { cursors })
+    }
+
+    fn topology_can_change(&self) -> bool {
+        // OrderedMerge handles splits internally by splicing replacement
+        // nodes into its children list.
+        false
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::mocks::*;
+    use super::*;
+    use crate::models::{effective_partition_key::EffectivePartitionKey, FeedRange};
+    use serde_json::json;
+
+    /// Builds a backend page of rewritten `ORDER BY` results keyed by `keys`.
+    fn order_by_page(keys: &[i64]) -> Vec<u8> {
+        let documents: Vec<Value> = keys
+            .iter()
+            .map(|k| json!({"_rid": "r", "orderByItems": [{"item": k}], "payload": {"k": k}}))
+            .collect();
+        json!({ "Documents": documents }).to_string().into_bytes()
+    }
+
+    fn page(keys: &[i64], is_terminal: bool) -> crate::error::Result<PageResult> {
+        Ok(PageResult::Page {
+            response: response(&order_by_page(keys)),
+            is_terminal,
+        })
+    }
+
+    fn emitted_keys(result: crate::error::Result<PageResult>) -> Vec<i64> {
+        let response = unwrap_page(result);
+        let body: Value = serde_json::from_slice(response.body_bytes()).unwrap();
+        body["Documents"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|d| d["k"].as_i64().unwrap())
+            .collect()
+    }
+
+    fn range(min: &str, max: &str) -> FeedRange {
+        FeedRange::new(
+            EffectivePartitionKey::from(min),
+            EffectivePartitionKey::from(max),
+        )
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn merges_children_in_sort_order() {
+        let child_a = MockLeaf::with_pages(vec![page(&[1, 4], false), page(&[6], true)]);
+        let child_b = MockLeaf::with_pages(vec![page(&[2, 3, 5], true)]);
+        let mut merge = OrderedMerge::new(
+            vec![Box::new(child_a), Box::new(child_b)],
+            vec![SortOrder::Ascending],
+        );
+        let mut executor = NoopRequestExecutor;
+        let mut ctx = PipelineContext::new(&mut executor, None);
+
+        // Child A runs dry after 4, so the merge stops to refill it.
+        assert_eq!(emitted_keys(merge.next_page(&mut ctx).await), [1, 2, 3, 4]);
+        // Child B is exhausted after 5, leaving only child A.
+        assert_eq!(emitted_keys(merge.next_page(&mut ctx).await), [5, 6]);
+        assert_drained(merge.next_page(&mut ctx).await);
+    }
+
+    #[tokio::test]
+    async fn descending_order_and_ties_prefer_lower_epk_child() {
+        let tagged = |tag: &str, keys: &[i64]| {
+            let documents: Vec<Value> = keys
+                .iter()
+                .map(|k| json!({"orderByItems": [{"item": k}], "payload": format!("{tag}{k}")}))
+                .collect();
+            Ok(PageResult::Page {
+                response: response(json!({ "Documents": documents }).to_string().as_bytes()),
+                is_terminal: true,
+            })
+        };
+        let child_a = MockLeaf::with_pages(vec![tagged("a", &[3, 1])]);
+        let child_b = MockLeaf::with_pages(vec![tagged("b", &[3, 2])]);
+        let mut merge = OrderedMerge::new(
+            vec![Box::new(child_a), Box::new(child_b)],
+            vec![SortOrder::Descending],
+        );
+        let mut executor = NoopRequestExecutor;
+        let mut ctx = PipelineContext::new(&mut executor, None);
+
+        let response = unwrap_page(merge.next_page(&mut ctx).await);
+        let body: Value = serde_json::from_slice(response.body_bytes()).unwrap();
+        assert_eq!(body["Documents"], json!(["a3", "b3", "b2", "a1"]));
+    }
+
+    #[tokio::test]
+    async fn undefined_keys_sort_first_and_undefined_payloads_are_dropped() {
+        let documents = json!({"Documents": [
+            {"orderByItems": [{}], "payload": "undefined-key"},
+            {"orderByItems": [{"item": null}]},
+            {"orderByItems": [{"item": 1}], "payload": "one"},
+        ]});
+        let child = MockLeaf::with_pages(vec![Ok(PageResult::Page {
+            response: response(documents.to_string().as_bytes()),
+            is_terminal: true,
+        })]);
+        let mut merge = OrderedMerge::new(vec![Box::new(child)], vec![SortOrder::Ascending]);
+        let mut executor = NoopRequestExecutor;
+        let mut ctx = PipelineContext::new(&mut executor, None);
+
+        let response = unwrap_page(merge.next_page(&mut ctx).await);
+        let body: Value = serde_json::from_slice(response.body_bytes()).unwrap();
+        assert_eq!(body["Documents"], json!(["undefined-key", "one"]));
+    }
+
+    #[tokio::test]
+    async fn rejects_results_without_order_by_items() {
+        let child = MockLeaf::with_pages(vec![Ok(PageResult::Page {
+            response: response(br#"{"Documents":[{"id":"1"}]}"#),
+            is_terminal: true,
+        })]);
+        let mut merge = OrderedMerge::new(vec![Box::new(child)], vec![SortOrder::Ascending]);
+        let mut executor = NoopRequestExecutor;
+        let mut ctx = PipelineContext::new(&mut executor, None);
+
+        let err = merge.next_page(&mut ctx).await.unwrap_err();
+        assert!(
+            format!("{err:?}").contains("orderByItems"),
+            "unexpected: {err:?}"
+        );
+    }
+
+    #[tokio::test]
+    async fn sums_request_charge_of_fetched_pages() {
+        let charged = |keys: &[i64], charge: f64| {
+            Ok(PageResult::Page {
+                response: response_with_charge(&order_by_page(keys), charge),
+                is_terminal: true,
+            })
+        };
+        let child_a = MockLeaf::with_pages(vec![charged(&[1], 1.5)]);
+        let child_b = MockLeaf::with_pages(vec![charged(&[2], 2.5)]);
+        let mut merge = OrderedMerge::new(
+            vec![Box::new(child_a), Box::new(child_b)],
+            vec![SortOrder::Ascending],
+        );
+        let mut executor = NoopRequestExecutor;
+        let mut ctx = PipelineContext::new(&mut executor, None);
+
+        let response = unwrap_page(merge.next_page(&mut ctx).await);
+        assert_eq!(
+            response.headers().request_charge,
+            Some(crate::models::RequestCharge::new(4.0))
+        );
+        assert_eq!(response.headers().continuation, None);
+    }
+
+    #[tokio::test]
+    async fn handles_split_required() {
+        let split_child = MockLeaf::with_pages(vec![Ok(PageResult::SplitRequired {
+            replacement_nodes: vec![
+                Box::new(MockLeaf::with_pages(vec![page(&[1, 5], true)])),
+                Box::new(MockLeaf::with_pages(vec![page(&[2], true)])),
+            ],
+        })]);
+        let other = MockLeaf::with_pages(vec![page(&[3], true)]);
+        let mut merge = OrderedMerge::new(
+            vec![Box::new(split_child), Box::new(other)],
+            vec![SortOrder::Ascending],
+        );
+        let mut executor = NoopRequestExecutor;
+        let mut ctx = PipelineContext::new(&mut executor, None);
+
+        assert_eq!(emitted_keys(merge.next_page(&mut ctx).await), [1, 2, 3, 5]);
+        assert_drained(merge.next_page(&mut ctx).await);
+    }
+
+    #[tokio::test]
+    async fn snapshot_records_buffered_page_and_emitted_count() {
+        let child_a = MockLeaf::with_pages(vec![page(&[1, 4], false)])
+            .with_feed_range(range("", "80"))
+            .with_snapshot(PipelineNodeState::Request {
+                server_continuation: Some("a-page-2".to_owned()),
+            });
+        let child_b = MockLeaf::with_pages(vec![page(&[2, 3, 5], false)])
+            .with_feed_range(range("80", "FF"))
+            .with_snapshot(PipelineNodeState::Request {
+                server_continuation: Some("b-page-1".to_owned()),
+            });
+        let mut merge = OrderedMerge::new(
+            vec![Box::new(child_a), Box::new(child_b)],
+            vec![SortOrder::Ascending],
+        );
+        let mut executor = NoopRequestExecutor;
+        let mut ctx = PipelineContext::new(&mut executor, None);
+
+        assert_eq!(emitted_keys(merge.next_page(&mut ctx).await), [1, 2, 3, 4]);
+
+        // Child A's buffer is empty, so it resumes from its own state. Child
+        // B still buffers `5` from the page fetched with `b-page-1` (the mock
+        // reports the same state before and after the fetch), two results of
+        // which were emitted.
+        assert_eq!(
+            merge.snapshot_state().unwrap(),
+            PipelineNodeState::OrderedMerge {
+                cursors: vec![
+                    OrderedMergeCursor {
+                        min_epk: String::new(),
+                        max_epk: "80".to_owned(),
+                        server_continuation: Some("a-page-2".to_owned()),
+                        skip: 0,
+                    },
+                    OrderedMergeCursor {
+                        min_epk: "80".to_owned(),
+                        max_epk: "FF".to_owned(),
+                        server_continuation: Some("b-page-1".to_owned()),
+                        skip: 2,
+                    },
+                ],
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn resumed_children_skip_emitted_results() {
+        let child = MockLeaf::with_pages(vec![page(&[1, 2, 3], true)]);
+        let mut merge =
+            OrderedMerge::resumed(vec![(Box::new(child), 2)], vec![SortOrder::Ascending]);
+        let mut executor = NoopRequestExecutor;
+        let mut ctx = PipelineContext::new(&mut executor, None);
+
+        assert_eq!(emitted_keys(merge.next_page(&mut ctx).await), [3]);
+        assert_drained(merge.next_page(&mut ctx).await);
+    }
+
+    #[tokio::test]
+    async fn split_of_resumed_child_with_pending_skip_fails() {
+        let split_child = MockLeaf::with_pages(vec![Ok(PageResult::SplitRequired {
+            replacement_nodes: vec![Box::new(MockLeaf::with_pages(vec![]))],
+        })]);
+        let mut merge =
+            OrderedMerge::resumed(vec![(Box::new(split_child), 1)], vec![SortOrder::Ascending]);
+        let mut executor = NoopRequestExecutor;
+        let mut ctx = PipelineContext::new(&mut executor, None);
+
+        let err = merge.next_page(&mut ctx).await.unwrap_err();
+        assert_eq!(
+            err.status(),
+            crate::error::CosmosStatus::CLIENT_CONTINUATION_TOKEN_SAVED_RANGE_UNHONORED
+        );
+    }
+}
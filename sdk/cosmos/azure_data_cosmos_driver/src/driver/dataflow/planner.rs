@@ -6,9 +6,11 @@
 //! The planner validates an operation's target against its resource type and
 //! constructs the appropriate dataflow [`Pipeline`].
 //!
-//! For cross-partition queries, [`build_sequential_drain`] consumes a backend
-//! [`QueryPlan`](super::query_plan::QueryPlan) and resolves the query's EPK
-//! ranges against the current topology to produce a fan-out pipeline.
+//! For cross-partition queries, [`build_query_pipeline`] consumes a backend
+//! [`QueryPlan`](super::query_plan::QueryPlan), resolves the query's EPK
+//! ranges against the current topology, and picks the fan-out strategy the
+//! plan requires: a [`SequentialDrain`] for plain queries, or an
+//! [`OrderedMerge`] for `ORDER BY` queries.
 
 use std::sync::Arc;
 
@@ -23,10 +25,17 @@ use crate::{
 use super::{
     intersect_feed_ranges,
     query_plan::{QueryInfo, QueryPlan},
-    DrainedLeaf, PartitionRoutingRefresh, Pipeline, PipelineNode, PipelineNodeState, RangedToken,
-    Request, RequestTarget, ResolvedRange, SequentialDrain, TopologyProvider, UnorderedMerge,
+    Aggregate, AggregateKind, Distinct, DrainedLeaf, GroupBy, GroupProjection, Limit, OrderedMerge,
+    OrderedMergeCursor, PartitionRoutingRefresh, Pipeline, PipelineNode, PipelineNodeState,
+    RangedToken, Request, RequestTarget, ResolvedRange, SequentialDrain, TopologyProvider,
+    UnorderedMerge,
 };
 
+/// Placeholder the backend leaves in a rewritten `ORDER BY` query for the
+/// filter used to resume after a given sort key. The pipeline resumes with
+/// server continuations instead, so the filter is always `true`.
+const ORDER_BY_FILTER_PLACEHOLDER: &str = "{documentdb-formattableorderbyquery-filter}";
+
 /// Builds a single-node [`Pipeline`] for a trivial operation.
 ///
 /// Trivial operations are those that can be satisfied by a single request to
@@ -104,11 +113,54 @@ pub(crate) fn build_trivial_pipeline(
     Ok(Pipeline::new(Box::new(root)))
 }
 
+/// Builds the single-request [`Pipeline`] for a bulk batch.
+///
+/// The request targets the whole partition key range that holds the
+/// operation's target range. A batch body can't be divided between
+/// partitions, so a target that spans several partition key ranges fails
+/// with [`CosmosStatus::PARTITION_KEY_RANGE_GONE`](crate::models::CosmosStatus::PARTITION_KEY_RANGE_GONE)
+/// instead of fanning out; the caller regroups its operations.
+pub(crate) async fn build_bulk_pipeline(
+    topology_provider: &mut dyn TopologyProvider,
+    operation: &Arc<CosmosOperation>,
+) -> crate::error::Result<Pipeline> {
+    debug_assert!(
+        operation.is_bulk(),
+        "build_bulk_pipeline called with a non-bulk operation"
+    );
+    let range = operation.target().cloned().unwrap_or_else(FeedRange::full);
+    let resolved = topology_provider
+        .resolve_ranges(&range, PartitionRoutingRefresh::UseCached)
+        .await?;
+    let [resolved]: [ResolvedRange; 1] = resolved.try_into().map_err(|resolved: Vec<_>| {
+        crate::error::CosmosError::builder()
+            .with_status(crate::error::CosmosStatus::PARTITION_KEY_RANGE_GONE)
+            .with_message(format!(
+                "bulk batch target {} spans {} partition key ranges",
+                render_feed_range_for_error(&range),
+                resolved.len()
+            ))
+            .build()
+    })?;
+
+    let target = RequestTarget::effective_partition_key_range(
+        resolved.range.clone(),
+        resolved.partition_key_range_id,
+        resolved.range,
+    );
+    Ok(Pipeline::new(Box::new(Request::new(
+        Arc::clone(operation),
+        target,
+        None,
+    ))))
+}
+
 /// Builds a fan-out [`Pipeline`] from a backend query plan as a sequential drain.
 ///
 /// Produces a [`SequentialDrain`] over one [`Request`] per resolved range.
-/// Other cross-partition strategies (streaming `ORDER BY`, hybrid search,
-/// read-many, etc.) will live as sibling functions.
+/// Other cross-partition strategies (streaming `ORDER BY` in
+/// [`plan_ordered_merge`]; hybrid search, read-many, etc. in the future)
+/// live as sibling functions, selected by [`build_query_pipeline`].
 ///
 /// `operation` is the underlying logical operation shared across every
 /// resulting [`Request`] node via `Arc::clone`; per-partition differences
@@ -152,12 +204,21 @@ pub(crate) async fn build_sequential_drain(
     resume: Option<PipelineNodeState>,
 ) -> crate::error::Result<Pipeline> {
     validate_query_plan(query_plan)?;
+    let root = plan_sequential_drain(query_plan, topology_provider, operation, resume).await?;
+    Ok(Pipeline::new(root))
+}
 
+/// Builds the [`SequentialDrain`] root of [`build_sequential_drain`] without
+/// validating the plan's query features.
+async fn plan_sequential_drain(
+    query_plan: &QueryPlan,
+    topology_provider: &mut dyn TopologyProvider,
+    operation: &Arc<CosmosOperation>,
+    resume: Option<PipelineNodeState>,
+) -> crate::error::Result<Box<dyn PipelineNode>> {
     let saved_snapshot = match resume {
         None => None,
-        Some(PipelineNodeState::Drained) => {
-            return Ok(Pipeline::new(Box::new(DrainedLeaf)));
-        }
+        Some(PipelineNodeState::Drained) => return Ok(Box::new(DrainedLeaf)),
         Some(PipelineNodeState::SequentialDrain {
             left_most_undrained_epk,
             active_tokens,
@@ -189,21 +250,370 @@ pub(crate) async fn build_sequential_drain(
         // fully drained. Otherwise the plan / topology yielded nothing to
         // query — that's a service contract violation.
         if saved_snapshot.is_some() {
-            return Ok(Pipeline::new(Box::new(DrainedLeaf)));
+            return Ok(Box::new(DrainedLeaf));
         }
-        return Err(crate::error::CosmosError::builder()
-            .with_status(crate::error::CosmosStatus::CLIENT_QUERY_PLAN_PRODUCED_EMPTY_RANGES)
-            .with_message("query plan produced no partition ranges to query")
-            .build());
+        return Err(empty_ranges_error());
     }
 
     // Even when there's only one request node, we still need to wrap it in
     // a SequentialDrain so the pipeline can react to splits by replacing
     // the single Request with multiple Requests.
-    let root = Box::new(SequentialDrain::new(request_nodes));
+    Ok(Box::new(SequentialDrain::new(request_nodes)))
+}
+
+/// Builds the fan-out [`Pipeline`] for a cross-partition query.
+///
+/// Validates that the plan only uses features the pipeline can execute, then
+/// dispatches on the plan's shape: `ORDER BY` queries get an
+/// [`OrderedMerge`] (see [`plan_ordered_merge`]); everything else gets a
+/// [`SequentialDrain`] (see [`build_sequential_drain`]). Every partition runs
+/// the plan's rewritten query, if it has one. The fan-out is then wrapped, from
+/// the inside out, in:
+///
+/// 1. an [`Aggregate`] node that combines per-partition partials, for
+///    `SELECT VALUE` aggregate queries, or a [`GroupBy`] node that merges
+///    per-partition groups, for `GROUP BY` queries;
+/// 2. a [`Distinct`] node that drops repeated results, for `SELECT DISTINCT`
+///    queries;
+/// 3. a [`Limit`] node that skips the offset and stops at the row limit, for
+///    `TOP` and `OFFSET ... LIMIT` queries.
+///
+/// On resume, each wrapper node's state (e.g. `Aggregate { source, .. }`) is
+/// unwrapped in turn and the innermost `source` is handed to the fan-out
+/// planner.
+pub(crate) async fn build_query_pipeline(
+    query_plan: &QueryPlan,
+    topology_provider: &mut dyn TopologyProvider,
+    operation: &Arc<CosmosOperation>,
+    resume: Option<PipelineNodeState>,
+) -> crate::error::Result<Pipeline> {
+    validate_query_engine_plan(query_plan)?;
+
+    let Some(info) = &query_plan.query_info else {
+        return build_sequential_drain(query_plan, topology_provider, operation, resume).await;
+    };
+
+    let aggregate = value_aggregate(info)?;
+    let group_by = group_by_projection(info)?;
+    let distinct = info.distinct_type != DistinctType::None;
+    let limit = info.top.or(info.limit);
+    let limited = limit.is_some() || info.offset.is_some();
+    let wrapped = aggregate.is_some() || group_by.is_some() || distinct || limited;
+    if wrapped && matches!(resume, Some(PipelineNodeState::Drained)) {
+        return Ok(Pipeline::new(Box::new(DrainedLeaf)));
+    }
+
+    // Wrapper states nest their source's state; peel them off from the
+    // outermost wrapper inwards.
+    let (offset, limit, resume) = match resume {
+        Some(PipelineNodeState::Limit {
+            offset,
+            limit,
+            source,
+        }) if limited => (offset, limit, Some(*source)),
+        Some(other) if limited => return Err(resume_shape_mismatch(&other, "TOP or LIMIT")),
+        resume => (info.offset.unwrap_or(0), limit, resume),
+    };
+    let (seen, resume) = match resume {
+        Some(PipelineNodeState::Distinct { seen, source }) if distinct => (seen, Some(*source)),
+        Some(other) if distinct => return Err(resume_shape_mismatch(&other, "DISTINCT")),
+        resume => (Vec::new(), resume),
+    };
+    let (rows, resume) = match resume {
+        Some(PipelineNodeState::GroupBy { rows, source }) if group_by.is_some() => {
+            (rows, Some(*source))
+        }
+        Some(other) if group_by.is_some() => {
+            return Err(resume_shape_mismatch(&other, "GROUP BY"));
+        }
+        resume => (Vec::new(), resume),
+    };
+    let (partials, resume) = match resume {
+        Some(PipelineNodeState::Aggregate { partials, source }) if aggregate.is_some() => {
+            (partials, Some(*source))
+        }
+        Some(other) if aggregate.is_some() => {
+            return Err(resume_shape_mismatch(&other, "aggregate"));
+        }
+        resume => (Vec::new(), resume),
+    };
+
+    let operation = rewrite_query_operation(operation, info)?;
+    let mut root = if info.order_by.is_empty() {
+        plan_sequential_drain(query_plan, topology_provider, &operation, resume).await?
+    } else {
+        plan_ordered_merge(query_plan, info, topology_provider, &operation, resume).await?
+    };
+
+    if let Some(kind) = aggregate {
+        root = Box::new(Aggregate::resumed(root, kind, partials));
+    }
+    if let Some(projection) = group_by {
+        root = Box::new(GroupBy::resumed(root, projection, rows));
+    }
+    if distinct {
+        // Ordered deduplication relies on equal results being adjacent,
+        // which only an ORDER BY merge guarantees.
+        root = if info.distinct_type == DistinctType::Ordered && !info.order_by.is_empty() {
+            Box::new(Distinct::ordered(root, seen.last().copied()))
+        } else {
+            Box::new(Distinct::unordered(root, seen))
+        };
+    }
+    if limited {
+        root = Box::new(Limit::new(root, offset, limit));
+    }
     Ok(Pipeline::new(root))
 }
 
+/// Builds the error returned when a continuation token's state does not
+/// match the wrapper node the query needs.
+fn resume_shape_mismatch(state: &PipelineNodeState, query: &str) -> crate::error::CosmosError {
+    crate::error::CosmosError::builder()
+        .with_status(crate::error::CosmosStatus::CLIENT_CONTINUATION_TOKEN_SHAPE_MISMATCH)
+        .with_message(format!(
+            "continuation token shape {} does not match a cross-partition {query} query",
+            snapshot_kind(state)
+        ))
+        .build()
+}
+
+/// Returns the aggregate function of a `SELECT VALUE` aggregate query, or
+/// `None` if the query has no aggregates or groups them (see
+/// [`group_by_projection`]).
+fn value_aggregate(info: &QueryInfo) -> crate::error::Result<Option<AggregateKind>> {
+    if !info.group_by_expressions.is_empty() {
+        return Ok(None);
+    }
+    match info.aggregates.as_slice() {
+        [] => Ok(None),
+        [name] if info.has_select_value => parse_aggregate(name).map(Some),
+        _ => Err(unsupported_feature(
+            "non-VALUE aggregates in cross-partition queries",
+        )),
+    }
+}
+
+/// Returns how a `GROUP BY` query's groups are projected into results, or
+/// `None` if the query has no `GROUP BY`.
+fn group_by_projection(info: &QueryInfo) -> crate::error::Result<Option<GroupProjection>> {
+    if info.group_by_expressions.is_empty() {
+        return Ok(None);
+    }
+    if info.has_select_value {
+        return match info.aggregates.as_slice() {
+            [] => Ok(Some(GroupProjection::Value)),
+            [name] => Ok(Some(GroupProjection::ValueAggregate(parse_aggregate(
+                name,
+            )?))),
+            _ => Err(unsupported_feature(
+                "multiple aggregates in a SELECT VALUE GROUP BY query",
+            )),
+        };
+    }
+    let aliases = info
+        .group_by_aliases
+        .iter()
+        .map(|alias| {
+            let aggregate = match info.group_by_alias_to_aggregate_type.get(alias) {
+                None | Some(serde_json::Value::Null) => None,
+                Some(serde_json::Value::String(name)) => Some(parse_aggregate(name)?),
+                Some(other) => {
+                    return Err(unsupported_feature(&format!(
+                        "{other} aggregates in cross-partition queries"
+                    )))
+                }
+            };
+            Ok((alias.clone(), aggregate))
+        })
+        .collect::<crate::error::Result<_>>()?;
+    Ok(Some(GroupProjection::Aliases(aliases)))
+}
+
+fn parse_aggregate(name: &str) -> crate::error::Result<AggregateKind> {
+    AggregateKind::parse(name).ok_or_else(|| {
+        unsupported_feature(&format!("{name} aggregates in cross-partition queries"))
+    })
+}
+
+/// Builds an [`OrderedMerge`] root for a cross-partition `ORDER BY` query.
+///
+/// `operation` must carry the plan's rewritten query, which wraps each result
+/// with its sort key so the merge can order results across partitions. It is
+/// shared across the resulting [`Request`] nodes.
+///
+/// `resume` is an optional [`PipelineNodeState`] from a continuation token.
+/// On resume, `OrderedMerge { cursors }` lists every range that still has
+/// results; each is resolved against the current topology and restarted from
+/// its saved server continuation, skipping the results of that page that were
+/// already emitted. A saved range that now spans several partitions can only
+/// be resumed when it has nothing to skip; otherwise the resume fails with a
+/// continuation-token error.
+async fn plan_ordered_merge(
+    query_plan: &QueryPlan,
+    query_info: &QueryInfo,
+    topology_provider: &mut dyn TopologyProvider,
+    operation: &Arc<CosmosOperation>,
+    resume: Option<PipelineNodeState>,
+) -> crate::error::Result<Box<dyn PipelineNode>> {
+    let cursors = match resume {
+        None => None,
+        Some(PipelineNodeState::Drained) => return Ok(Box::new(DrainedLeaf)),
+        Some(PipelineNodeState::OrderedMerge { cursors }) => Some(cursors),
+        Some(other) => {
+            return Err(crate::error::CosmosError::builder()
+                .with_status(crate::error::CosmosStatus::CLIENT_CONTINUATION_TOKEN_SHAPE_MISMATCH)
+                .with_message(format!(
+                    "continuation token shape {} does not match a cross-partition ORDER BY query",
+                    snapshot_kind(&other)
+                ))
+                .build());
+        }
+    };
+
+    let sort_orders = query_info.order_by.clone();
+    let root = match cursors {
+        Some(cursors) => {
+            let children = plan_ordered_merge_resume(topology_provider, operation, cursors).await?;
+            if children.is_empty() {
+                return Err(empty_ranges_error());
+            }
+            OrderedMerge::resumed(children, sort_orders)
+        }
+        None => {
+            let children = plan_fresh(query_plan, topology_provider, operation).await?;
+            if children.is_empty() {
+                return Err(empty_ranges_error());
+            }
+            OrderedMerge::new(children, sort_orders)
+        }
+    };
+    Ok(Box::new(root))
+}
+
+fn empty_ranges_error() -> crate::error::CosmosError {
+    crate::error::CosmosError::builder()
+        .with_status(crate::error::CosmosStatus::CLIENT_QUERY_PLAN_PRODUCED_EMPTY_RANGES)
+        .with_message("query plan produced no partition ranges to query")
+        .build()
+}
+
+/// Builds the children of a resumed [`OrderedMerge`], paired with the number
+/// of results each must skip.
+async fn plan_ordered_merge_resume(
+    topology_provider: &mut dyn TopologyProvider,
+    operation: &Arc<CosmosOperation>,
+    cursors: Vec<OrderedMergeCursor>,
+) -> crate::error::Result<Vec<(Box<dyn PipelineNode>, u64)>> {
+    let mut children: Vec<(Box<dyn PipelineNode>, u64)> = Vec::new();
+    let mut previous: Option<FeedRange> = None;
+    for cursor in cursors {
+        let min = EffectivePartitionKey::from(cursor.min_epk);
+        let max = EffectivePartitionKey::from(cursor.max_epk);
+        if min >= max {
+            return Err(crate::error::CosmosError::builder()
+                .with_status(
+                    crate::error::CosmosStatus::CLIENT_CONTINUATION_TOKEN_INVALID_EPK_RANGE,
+                )
+                .with_message(format!(
+                    "continuation token has invalid cursors entry (min `{}` >= max `{}`)",
+                    min.to_hex(),
+                    max.to_hex(),
+                ))
+                .build());
+        }
+        let range = FeedRange::new(min, max)?;
+        if let Some(prev) = &previous {
+            if range.min_inclusive() < prev.max_exclusive() {
+                return Err(crate::error::CosmosError::builder()
+                    .with_status(
+                        crate::error::CosmosStatus::CLIENT_CONTINUATION_TOKEN_INVALID_EPK_RANGE,
+                    )
+                    .with_message(format!(
+                        "continuation token cursors must be sorted and non-overlapping; \
+                         entry {} overlaps {}",
+                        render_feed_range_for_error(&range),
+                        render_feed_range_for_error(prev),
+                    ))
+                    .build());
+            }
+        }
+
+        let resolved = topology_provider
+            .resolve_ranges(&range, PartitionRoutingRefresh::UseCached)
+            .await?;
+        if resolved.len() > 1 && cursor.skip > 0 {
+            return Err(crate::error::CosmosError::builder()
+                .with_status(
+                    crate::error::CosmosStatus::CLIENT_CONTINUATION_TOKEN_SAVED_RANGE_UNHONORED,
+                )
+                .with_message(format!(
+                    "continuation token cursor {} now spans {} partitions and has {} \
+                     results to skip; the ORDER BY query cannot be safely resumed",
+                    render_feed_range_for_error(&range),
+                    resolved.len(),
+                    cursor.skip,
+                ))
+                .build());
+        }
+        for resolved_range in resolved {
+            let leaf = intersect_feed_ranges(&resolved_range.range, &range).ok_or_else(|| {
+                topology_range_not_overlapping_error(&resolved_range.range, &range)
+            })?;
+            let target = RequestTarget::effective_partition_key_range(
+                leaf,
+                resolved_range.partition_key_range_id,
+                resolved_range.range,
+            );
+            children.push((
+                Box::new(Request::new(
+                    Arc::clone(operation),
+                    target,
+                    cursor.server_continuation.clone(),
+                )),
+                cursor.skip,
+            ));
+        }
+        previous = Some(range);
+    }
+    Ok(children)
+}
+
+/// Returns the operation each partition runs for a query plan: the original
+/// operation, or a copy whose query text is the plan's rewritten query.
+///
+/// Query parameters are kept; only the `query` property of the body changes.
+fn rewrite_query_operation(
+    operation: &Arc<CosmosOperation>,
+    query_info: &QueryInfo,
+) -> crate::error::Result<Arc<CosmosOperation>> {
+    let Some(rewritten) = query_info
+        .rewritten_query
+        .as_deref()
+        .filter(|query| !query.is_empty())
+    else {
+        return Ok(Arc::clone(operation));
+    };
+
+    let invalid_body = || {
+        crate::error::CosmosError::builder()
+            .with_status(crate::error::CosmosStatus::CLIENT_BAD_REQUEST)
+            .with_message("cross-partition query body must be a JSON query specification object")
+            .build()
+    };
+    let mut spec: serde_json::Value =
+        serde_json::from_slice(operation.body().unwrap_or_default()).map_err(|_| invalid_body())?;
+    let spec_object = spec.as_object_mut().ok_or_else(invalid_body)?;
+    spec_object.insert(
+        "query".to_owned(),
+        rewritten
+            .replace(ORDER_BY_FILTER_PLACEHOLDER, "true")
+            .into(),
+    );
+    let body = serde_json::to_vec(&spec).map_err(|_| invalid_body())?;
+    Ok(Arc::new((**operation).clone().with_body(body)))
+}
+
 /// Builds an [`UnorderedMerge`] pipeline for change feed operations.
 ///
 /// Unlike [`build_sequential_drain`], this does not require a query plan.
@@ -809,6 +1219,11 @@ fn snapshot_kind(state: &PipelineNodeState) -> &'static str {
         PipelineNodeState::Request { .. } => "Request",
         PipelineNodeState::SequentialDrain { .. } => "SequentialDrain",
         PipelineNodeState::UnorderedMerge { .. } => "UnorderedMerge",
+        PipelineNodeState::OrderedMerge { .. } => "OrderedMerge",
+        PipelineNodeState::Aggregate { .. } => "Aggregate",
+        PipelineNodeState::GroupBy { .. } => "GroupBy",
+        PipelineNodeState::Distinct { .. } => "Distinct",
+        PipelineNodeState::Limit { .. } => "Limit",
     }
 }
 
@@ -862,7 +1277,8 @@ fn validate_unordered_merge_tokens(
     Ok(parsed)
 }
 
-/// Validates that the query plan does not require features we don't yet support.
+/// Validates that the query plan can be executed by a plain
+/// [`SequentialDrain`], which forwards backend pages untouched.
 fn validate_query_plan(plan: &QueryPlan) -> crate::error::Result<()> {
     if plan.hybrid_search_query_info.is_some() {
         return Err(unsupported_feature("hybrid search queries"));
@@ -875,6 +1291,31 @@ fn validate_query_plan(plan: &QueryPlan) -> crate::error::Result<()> {
     Ok(())
 }
 
+/// Validates that the query plan only uses features [`build_query_pipeline`]
+/// can execute.
+fn validate_query_engine_plan(plan: &QueryPlan) -> crate::error::Result<()> {
+    if plan.hybrid_search_query_info.is_some() {
+        return Err(unsupported_feature("hybrid search queries"));
+    }
+
+    let Some(info) = &plan.query_info else {
+        return Ok(());
+    };
+    // Non-streaming ORDER BY (e.g. `ORDER BY VectorDistance(...)`) returns each
+    // partition's results sorted, like a streaming ORDER BY, so it runs
+    // through the same merge. The Gateway only plans it under TOP or LIMIT.
+    if info.has_non_streaming_order_by
+        && (info.order_by.is_empty() || (info.top.is_none() && info.limit.is_none()))
+    {
+        return Err(unsupported_feature(
+            "non-streaming ORDER BY without TOP or LIMIT in cross-partition queries",
+        ));
+    }
+    value_aggregate(info)?;
+    group_by_projection(info)?;
+    Ok(())
+}
+
 fn validate_query_info(info: &QueryInfo) -> crate::error::Result<()> {
     if info.top.is_some() {
         return Err(unsupported_feature("TOP clause in cross-partition queries"));
@@ -1736,6 +2177,749 @@ mod tests {
         );
     }
 
+    // --- build_bulk_pipeline tests ---
+
+    fn bulk_operation(min: &str, max: &str) -> Arc<CosmosOperation> {
+        let range = FeedRange::new(
+            EffectivePartitionKey::from(min),
+            EffectivePartitionKey::from(max),
+        )
+        .unwrap();
+        Arc::new(CosmosOperation::bulk(test_container(), range).with_body(b"[]".to_vec()))
+    }
+
+    #[tokio::test]
+    async fn bulk_targets_the_whole_partition_key_range_holding_its_range() {
+        let op = bulk_operation("20", "40");
+        assert!(!op.is_trivial());
+        // The partition has merged into a wider one since the caller grouped
+        // its operations.
+        let mut topology = MockTopologyProvider::new(vec![Ok(vec![rr("", "80", "pkrange-0")])]);
+
+        let pipeline = build_bulk_pipeline(&mut topology, &op).await.unwrap();
+
+        let request = pipeline.root().downcast_ref::<Request>().unwrap();
+        assert_eq!(
+            *request.target(),
+            RequestTarget::effective_partition_key_range(
+                rr("", "80", "pkrange-0").range,
+                "pkrange-0".to_owned(),
+                rr("", "80", "pkrange-0").range,
+            )
+        );
+        assert!(!request.topology_can_change());
+    }
+
+    #[tokio::test]
+    async fn bulk_spanning_partition_key_ranges_is_gone() {
+        let op = bulk_operation("", "80");
+        let mut topology = MockTopologyProvider::new(vec![Ok(vec![
+            rr("", "40", "pkrange-left"),
+            rr("40", "80", "pkrange-right"),
+        ])]);
+
+        let Err(error) = build_bulk_pipeline(&mut topology, &op).await else {
+            panic!("expected a bulk batch spanning two partitions to fail");
+        };
+        assert!(error.status().is_partition_key_range_gone());
+    }
+
+    // --- build_query_pipeline tests ---
+
+    fn order_by_plan(ranges: Vec<QueryRange>) -> QueryPlan {
+        use super::super::query_plan::SortOrder;
+        QueryPlan {
+            query_info: Some(QueryInfo {
+                order_by: vec![SortOrder::Ascending],
+                order_by_expressions: vec!["c.n".to_owned()],
+                rewritten_query: Some(
+                    "SELECT c._rid, [{\"item\": c.n}] AS orderByItems, c AS payload \
+                     FROM c WHERE {documentdb-formattableorderbyquery-filter} ORDER BY c.n"
+                        .to_owned(),
+                ),
+                ..Default::default()
+            }),
+            ..plan_with_ranges(ranges)
+        }
+    }
+
+    /// Asserts that the pipeline is an `OrderedMerge` over `Request` nodes
+    /// targeting the given EPK ranges, and returns those requests.
+    fn ordered_merge_requests(pipeline: Pipeline, expected: &[(&str, &str, &str)]) -> Vec<Request> {
+        let merge = pipeline
+            .into_root()
+            .downcast::<OrderedMerge>()
+            .expect("expected OrderedMerge root");
+        let requests: Vec<_> = merge
+            .into_children()
+            .into_iter()
+            .map(|child| {
+                *child
+                    .downcast::<Request>()
+                    .expect("expected Request child node")
+            })
+            .collect();
+        let targets: Vec<_> = requests.iter().map(|r| r.target().clone()).collect();
+        let expected: Vec<_> = expected
+            .iter()
+            .map(|&(min, max, pk_range_id)| {
+                let range = FeedRange::new(
+                    EffectivePartitionKey::from(min),
+                    EffectivePartitionKey::from(max),
+                )
+                .unwrap();
+                RequestTarget::effective_partition_key_range(
+                    range.clone(),
+                    pk_range_id.to_string(),
+                    range,
+                )
+            })
+            .collect();
+        assert_eq!(targets, expected);
+        requests
+    }
+
+    #[tokio::test]
+    async fn query_pipeline_without_order_by_is_sequential_drain() {
+        let plan = plan_with_ranges(vec![qr("", "FF")]);
+        let op = cross_partition_query_operation();
+        let mut topology = MockTopologyProvider::new(vec![Ok(vec![rr("", "FF", "pkrange-0")])]);
+
+        let pipeline = build_query_pipeline(&plan, &mut topology, &Arc::new(op), None)
+            .await
+            .unwrap();
+        assert_drain_requests(pipeline, &[("", "FF", "pkrange-0")]);
+    }
+
+    #[tokio::test]
+    async fn query_pipeline_merges_order_by_with_rewritten_query() {
+        let plan = order_by_plan(vec![qr("", "FF")]);
+        let op = CosmosOperation::query_items(test_container(), Some(FeedRange::full())).with_body(
+            br#"{"query":"SELECT * FROM c ORDER BY c.n","parameters":[{"name":"@p","value":1}]}"#
+                .to_vec(),
+        );
+        let mut topology = MockTopologyProvider::new(vec![Ok(vec![
+            rr("", "80", "pkrange-0"),
+            rr("80", "FF", "pkrange-1"),
+        ])]);
+
+        let pipeline = build_query_pipeline(&plan, &mut topology, &Arc::new(op), None)
+            .await
+            .unwrap();
+        let requests = ordered_merge_requests(
+            pipeline,
+            &[("", "80", "pkrange-0"), ("80", "FF", "pkrange-1")],
+        );
+        for request in requests {
+            let body: serde_json::Value =
+                serde_json::from_slice(request.operation().body().unwrap()).unwrap();
+            assert_eq!(
+                body,
+                serde_json::json!({
+                    "query": "SELECT c._rid, [{\"item\": c.n}] AS orderByItems, c AS payload \
+                              FROM c WHERE true ORDER BY c.n",
+                    "parameters": [{"name": "@p", "value": 1}],
+                })
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn query_pipeline_merges_non_streaming_order_by_under_limit() {
+        let mut plan = order_by_plan(vec![qr("", "FF")]);
+        let info = plan.query_info.as_mut().unwrap();
+        info.has_non_streaming_order_by = true;
+        info.top = Some(10);
+        let op = cross_partition_query_operation();
+        let mut topology = MockTopologyProvider::new(vec![Ok(vec![
+            rr("", "80", "pkrange-0"),
+            rr("80", "FF", "pkrange-1"),
+        ])]);
+
+        let pipeline = build_query_pipeline(&plan, &mut topology, &Arc::new(op), None)
+            .await
+            .unwrap();
+        let PipelineNodeState::Limit { limit, source, .. } = pipeline.snapshot_state().unwrap()
+        else {
+            panic!("expected Limit root");
+        };
+        assert_eq!(Some(10), limit);
+        let PipelineNodeState::OrderedMerge { cursors } = *source else {
+            panic!("expected OrderedMerge under Limit");
+        };
+        assert_eq!(2, cursors.len());
+    }
+
+    #[tokio::test]
+    async fn query_pipeline_rejects_non_streaming_order_by_without_limit() {
+        let mut plan = order_by_plan(vec![qr("", "FF")]);
+        plan.query_info.as_mut().unwrap().has_non_streaming_order_by = true;
+        let op = cross_partition_query_operation();
+        let mut topology = NoopTopologyProvider;
+
+        let err = build_query_pipeline(&plan, &mut topology, &Arc::new(op), None)
+            .await
+            .unwrap_err();
+        assert_eq!(
+            err.status().sub_status(),
+            Some(crate::error::SubStatusCode::CLIENT_UNSUPPORTED_QUERY_FEATURE),
+            "unexpected: {err}",
+        );
+    }
+
+    #[tokio::test]
+    async fn query_pipeline_resumes_order_by_cursors() {
+        let plan = order_by_plan(vec![qr("", "FF")]);
+        let op = cross_partition_query_operation();
+        let mut topology = MockTopologyProvider::new(vec![
+            Ok(vec![rr("", "80", "pkrange-0")]),
+            Ok(vec![rr("80", "FF", "pkrange-1")]),
+        ]);
+        let resume = PipelineNodeState::OrderedMerge {
+            cursors: vec![
+                OrderedMergeCursor {
+                    min_epk: "".to_owned(),
+                    max_epk: "80".to_owned(),
+                    server_continuation: None,
+                    skip: 0,
+                },
+                OrderedMergeCursor {
+                    min_epk: "80".to_owned(),
+                    max_epk: "FF".to_owned(),
+                    server_continuation: Some("t1".to_owned()),
+                    skip: 2,
+                },
+            ],
+        };
+
+        let pipeline = build_query_pipeline(&plan, &mut topology, &Arc::new(op), Some(resume))
+            .await
+            .unwrap();
+        let requests = ordered_merge_requests(
+            pipeline,
+            &[("", "80", "pkrange-0"), ("80", "FF", "pkrange-1")],
+        );
+        assert_eq!(
+            requests[1].snapshot_state().unwrap(),
+            PipelineNodeState::Request {
+                server_continuation: Some("t1".to_owned()),
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn query_pipeline_rejects_order_by_resume_with_skip_across_split() {
+        let plan = order_by_plan(vec![qr("", "FF")]);
+        let op = cross_partition_query_operation();
+        let mut topology = MockTopologyProvider::new(vec![Ok(vec![
+            rr("", "80", "pkrange-0"),
+            rr("80", "FF", "pkrange-1"),
+        ])]);
+        let resume = PipelineNodeState::OrderedMerge {
+            cursors: vec![OrderedMergeCursor {
+                min_epk: "".to_owned(),
+                max_epk: "FF".to_owned(),
+                server_continuation: Some("t1".to_owned()),
+                skip: 1,
+            }],
+        };
+
+        let err = build_query_pipeline(&plan, &mut topology, &Arc::new(op), Some(resume))
+            .await
+            .unwrap_err();
+        assert_eq!(
+            err.status().sub_status(),
+            Some(crate::error::SubStatusCode::CLIENT_CONTINUATION_TOKEN_SAVED_RANGE_UNHONORED),
+            "unexpected: {err}",
+        );
+    }
+
+    #[tokio::test]
+    async fn query_pipeline_rejects_mismatched_resume_shape() {
+        let plan = order_by_plan(vec![qr("", "FF")]);
+        let op = cross_partition_query_operation();
+        let mut topology = NoopTopologyProvider;
+        let resume = PipelineNodeState::Request {
+            server_continuation: Some("t1".to_owned()),
+        };
+
+        let err = build_query_pipeline(&plan, &mut topology, &Arc::new(op), Some(resume))
+            .await
+            .unwrap_err();
+        assert_eq!(
+            err.status().sub_status(),
+            Some(crate::error::SubStatusCode::CLIENT_CONTINUATION_TOKEN_SHAPE_MISMATCH),
+            "unexpected: {err}",
+        );
+    }
+
+    fn value_aggregate_plan(aggregate: &str) -> QueryPlan {
+        QueryPlan {
+            query_info: Some(QueryInfo {
+                aggregates: vec![aggregate.to_owned()],
+                has_select_value: true,
+                rewritten_query: Some("SELECT VALUE [{\"item\": COUNT(1)}] FROM c".to_owned()),
+                ..Default::default()
+            }),
+            ..plan_with_ranges(vec![qr("", "FF")])
+        }
+    }
+
+    #[tokio::test]
+    async fn query_pipeline_wraps_value_aggregate_in_aggregate_node() {
+        let plan = value_aggregate_plan("Count");
+        let op = cross_partition_query_operation();
+        let mut topology = MockTopologyProvider::new(vec![Ok(vec![
+            rr("", "80", "pkrange-0"),
+            rr("80", "FF", "pkrange-1"),
+        ])]);
+
+        let pipeline = build_query_pipeline(&plan, &mut topology, &Arc::new(op), None)
+            .await
+            .unwrap();
+        let aggregate = pipeline
+            .into_root()
+            .downcast::<Aggregate>()
+            .expect("expected Aggregate root");
+        let source = Pipeline::new(aggregate.into_children().pop().unwrap());
+        assert_drain_requests(
+            source,
+            &[("", "80", "pkrange-0"), ("80", "FF", "pkrange-1")],
+        );
+    }
+
+    #[tokio::test]
+    async fn query_pipeline_sends_rewritten_aggregate_query() {
+        let plan = value_aggregate_plan("Count");
+        let op = cross_partition_query_operation();
+        let mut topology = MockTopologyProvider::new(vec![Ok(vec![rr("", "FF", "pkrange-0")])]);
+
+        let pipeline = build_query_pipeline(&plan, &mut topology, &Arc::new(op), None)
+            .await
+            .unwrap();
+        let drain = pipeline
+            .into_root()
+            .downcast::<Aggregate>()
+            .expect("expected Aggregate root")
+            .into_children()
+            .pop()
+            .unwrap()
+            .downcast::<SequentialDrain>()
+            .expect("expected SequentialDrain source");
+        let request = drain
+            .into_children()
+            .pop()
+            .unwrap()
+            .downcast::<Request>()
+            .expect("expected Request child node");
+        let body: serde_json::Value =
+            serde_json::from_slice(request.operation().body().unwrap()).unwrap();
+        assert_eq!(
+            body,
+            serde_json::json!({"query": "SELECT VALUE [{\"item\": COUNT(1)}] FROM c"})
+        );
+    }
+
+    #[tokio::test]
+    async fn query_pipeline_resumes_aggregate_source_and_partials() {
+        let plan = value_aggregate_plan("Sum");
+        let op = cross_partition_query_operation();
+        let mut topology = MockTopologyProvider::new(vec![Ok(vec![
+            rr("", "80", "pkrange-0"),
+            rr("80", "FF", "pkrange-1"),
+        ])]);
+        let resume = PipelineNodeState::Aggregate {
+            partials: vec![serde_json::json!([{"item": 3}])],
+            source: Box::new(PipelineNodeState::SequentialDrain {
+                left_most_undrained_epk: "80".to_owned(),
+                active_tokens: vec![],
+            }),
+        };
+
+        let pipeline = build_query_pipeline(&plan, &mut topology, &Arc::new(op), Some(resume))
+            .await
+            .unwrap();
+        assert_eq!(
+            pipeline.snapshot_state().unwrap(),
+            PipelineNodeState::Aggregate {
+                partials: vec![serde_json::json!([{"item": 3}])],
+                source: Box::new(PipelineNodeState::SequentialDrain {
+                    left_most_undrained_epk: "80".to_owned(),
+                    active_tokens: vec![],
+                }),
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn query_pipeline_rejects_non_aggregate_resume_shape_for_aggregates() {
+        let plan = value_aggregate_plan("Count");
+        let op = cross_partition_query_operation();
+        let mut topology = NoopTopologyProvider;
+        let resume = PipelineNodeState::SequentialDrain {
+            left_most_undrained_epk: String::new(),
+            active_tokens: vec![],
+        };
+
+        let err = build_query_pipeline(&plan, &mut topology, &Arc::new(op), Some(resume))
+            .await
+            .unwrap_err();
+        assert_eq!(
+            err.status().sub_status(),
+            Some(crate::error::SubStatusCode::CLIENT_CONTINUATION_TOKEN_SHAPE_MISMATCH),
+            "unexpected: {err}",
+        );
+    }
+
+    #[tokio::test]
+    async fn query_pipeline_rejects_non_value_aggregates() {
+        let mut plan = value_aggregate_plan("Count");
+        plan.query_info.as_mut().unwrap().has_select_value = false;
+        let op = cross_partition_query_operation();
+        let mut topology = NoopTopologyProvider;
+
+        let err = build_query_pipeline(&plan, &mut topology, &Arc::new(op), None)
+            .await
+            .unwrap_err();
+        assert!(
+            err.to_string().ends_with(
+                "unsupported query feature: non-VALUE aggregates in cross-partition queries"
+            ),
+            "unexpected: {err}"
+        );
+    }
+
+    fn group_by_plan() -> QueryPlan {
+        QueryPlan {
+            query_info: Some(QueryInfo {
+                group_by_expressions: vec!["c.city".to_owned()],
+                group_by_aliases: vec!["city".to_owned(), "n".to_owned()],
+                group_by_alias_to_aggregate_type: [
+                    ("city".to_owned(), serde_json::Value::Null),
+                    ("n".to_owned(), serde_json::json!("Count")),
+                ]
+                .into_iter()
+                .collect(),
+                aggregates: vec!["Count".to_owned()],
+                rewritten_query: Some(
+                    "SELECT [{\"item\": c.city}] AS groupByItems, {\"city\": c.city, \"n\": {\"item\": COUNT(1)}} AS payload FROM c GROUP BY c.city".to_owned(),
+                ),
+                ..Default::default()
+            }),
+            ..plan_with_ranges(vec![qr("", "FF")])
+        }
+    }
+
+    #[test]
+    fn group_by_projection_follows_alias_order_and_aggregate_types() {
+        let plan = group_by_plan();
+        let mut info = plan.query_info.unwrap();
+        assert_eq!(
+            group_by_projection(&info).unwrap(),
+            Some(GroupProjection::Aliases(vec![
+                ("city".to_owned(), None),
+                ("n".to_owned(), Some(AggregateKind::Count)),
+            ]))
+        );
+        assert_eq!(value_aggregate(&info).unwrap(), None);
+
+        info.has_select_value = true;
+        assert_eq!(
+            group_by_projection(&info).unwrap(),
+            Some(GroupProjection::ValueAggregate(AggregateKind::Count))
+        );
+        info.aggregates.clear();
+        assert_eq!(
+            group_by_projection(&info).unwrap(),
+            Some(GroupProjection::Value)
+        );
+
+        info.has_select_value = false;
+        info.group_by_alias_to_aggregate_type
+            .insert("n".to_owned(), serde_json::json!("CountIf"));
+        let err = group_by_projection(&info).unwrap_err();
+        assert!(
+            err.to_string().ends_with(
+                "unsupported query feature: CountIf aggregates in cross-partition queries"
+            ),
+            "unexpected: {err}"
+        );
+    }
+
+    #[tokio::test]
+    async fn query_pipeline_wraps_group_by_in_group_by_node() {
+        let plan = group_by_plan();
+        let op = cross_partition_query_operation();
+        let mut topology = MockTopologyProvider::new(vec![Ok(vec![
+            rr("", "80", "pkrange-0"),
+            rr("80", "FF", "pkrange-1"),
+        ])]);
+
+        let pipeline = build_query_pipeline(&plan, &mut topology, &Arc::new(op), None)
+            .await
+            .unwrap();
+        let group_by = pipeline
+            .into_root()
+            .downcast::<GroupBy>()
+            .expect("expected GroupBy root");
+        let source = Pipeline::new(group_by.into_children().pop().unwrap());
+        assert_drain_requests(
+            source,
+            &[("", "80", "pkrange-0"), ("80", "FF", "pkrange-1")],
+        );
+    }
+
+    #[tokio::test]
+    async fn query_pipeline_resumes_group_by_rows_and_source() {
+        let plan = group_by_plan();
+        let op = cross_partition_query_operation();
+        let mut topology = MockTopologyProvider::new(vec![Ok(vec![
+            rr("", "80", "pkrange-0"),
+            rr("80", "FF", "pkrange-1"),
+        ])]);
+        let resume = PipelineNodeState::GroupBy {
+            rows: vec![serde_json::json!({
+                "groupByItems": [{"item": "oslo"}],
+                "payload": {"city": "oslo", "n": {"item": 2}},
+            })],
+            source: Box::new(PipelineNodeState::SequentialDrain {
+                left_most_undrained_epk: "80".to_owned(),
+                active_tokens: vec![],
+            }),
+        };
+
+        let pipeline =
+            build_query_pipeline(&plan, &mut topology, &Arc::new(op), Some(resume.clone()))
+                .await
+                .unwrap();
+        assert_eq!(pipeline.snapshot_state().unwrap(), resume);
+
+        let err = build_query_pipeline(
+            &plan,
+            &mut NoopTopologyProvider,
+            &Arc::new(cross_partition_query_operation()),
+            Some(PipelineNodeState::Aggregate {
+                partials: vec![],
+                source: Box::new(PipelineNodeState::Drained),
+            }),
+        )
+        .await
+        .unwrap_err();
+        assert_eq!(
+            err.status().sub_status(),
+            Some(crate::error::SubStatusCode::CLIENT_CONTINUATION_TOKEN_SHAPE_MISMATCH),
+            "unexpected: {err}",
+        );
+    }
+
+    fn limit_plan(top: Option<u64>, offset: Option<u64>, limit: Option<u64>) -> QueryPlan {
+        QueryPlan {
+            query_info: Some(QueryInfo {
+                top,
+                offset,
+                limit,
+                ..Default::default()
+            }),
+            ..plan_with_ranges(vec![qr("", "FF")])
+        }
+    }
+
+    #[tokio::test]
+    async fn query_pipeline_wraps_top_and_offset_limit_in_limit_node() {
+        let cases = [
+            (limit_plan(Some(10), None, None), 0, Some(10)),
+            (limit_plan(None, Some(5), Some(20)), 5, Some(20)),
+        ];
+        for (plan, offset, limit) in cases {
+            let op = cross_partition_query_operation();
+            let mut topology = MockTopologyProvider::new(vec![Ok(vec![rr("", "FF", "pkrange-0")])]);
+
+            let pipeline = build_query_pipeline(&plan, &mut topology, &Arc::new(op), None)
+                .await
+                .unwrap();
+            assert_eq!(
+                pipeline.snapshot_state().unwrap(),
+                PipelineNodeState::Limit {
+                    offset,
+                    limit,
+                    source: Box::new(PipelineNodeState::SequentialDrain {
+                        left_most_undrained_epk: String::new(),
+                        active_tokens: vec![],
+                    }),
+                }
+            );
+            pipeline
+                .into_root()
+                .downcast::<Limit>()
+                .expect("expected Limit root");
+        }
+    }
+
+    #[tokio::test]
+    async fn query_pipeline_resumes_remaining_limit_over_distinct() {
+        let mut plan = limit_plan(Some(10), None, None);
+        plan.query_info.as_mut().unwrap().distinct_type = DistinctType::Unordered;
+        let op = cross_partition_query_operation();
+        let mut topology = MockTopologyProvider::new(vec![Ok(vec![rr("", "FF", "pkrange-0")])]);
+        let resume = PipelineNodeState::Limit {
+            offset: 0,
+            limit: Some(4),
+            source: Box::new(PipelineNodeState::Distinct {
+                seen: vec![1, 2],
+                source: Box::new(PipelineNodeState::SequentialDrain {
+                    left_most_undrained_epk: String::new(),
+                    active_tokens: vec![],
+                }),
+            }),
+        };
+
+        let pipeline =
+            build_query_pipeline(&plan, &mut topology, &Arc::new(op), Some(resume.clone()))
+                .await
+                .unwrap();
+        assert_eq!(pipeline.snapshot_state().unwrap(), resume);
+
+        let err = build_query_pipeline(
+            &plan,
+            &mut NoopTopologyProvider,
+            &Arc::new(cross_partition_query_operation()),
+            Some(PipelineNodeState::Distinct {
+                seen: vec![],
+                source: Box::new(PipelineNodeState::Drained),
+            }),
+        )
+        .await
+        .unwrap_err();
+        assert_eq!(
+            err.status().sub_status(),
+            Some(crate::error::SubStatusCode::CLIENT_CONTINUATION_TOKEN_SHAPE_MISMATCH),
+            "unexpected: {err}",
+        );
+    }
+
+    #[tokio::test]
+    async fn query_pipeline_resumes_drained_limit_without_requests() {
+        let plan = limit_plan(Some(10), None, None);
+        let op = cross_partition_query_operation();
+        let mut topology = NoopTopologyProvider;
+
+        let pipeline = build_query_pipeline(
+            &plan,
+            &mut topology,
+            &Arc::new(op),
+            Some(PipelineNodeState::Drained),
+        )
+        .await
+        .unwrap();
+        pipeline
+            .into_root()
+            .downcast::<DrainedLeaf>()
+            .expect("expected DrainedLeaf root");
+    }
+
+    #[tokio::test]
+    async fn query_pipeline_resumes_nested_distinct_and_aggregate_states() {
+        let mut plan = value_aggregate_plan("Count");
+        plan.query_info.as_mut().unwrap().distinct_type = DistinctType::Unordered;
+        let op = cross_partition_query_operation();
+        let mut topology = MockTopologyProvider::new(vec![Ok(vec![rr("", "FF", "pkrange-0")])]);
+        let resume = PipelineNodeState::Distinct {
+            seen: vec![1, 2],
+            source: Box::new(PipelineNodeState::Aggregate {
+                partials: vec![serde_json::json!([{"item": 3}])],
+                source: Box::new(PipelineNodeState::SequentialDrain {
+                    left_most_undrained_epk: String::new(),
+                    active_tokens: vec![],
+                }),
+            }),
+        };
+
+        let pipeline =
+            build_query_pipeline(&plan, &mut topology, &Arc::new(op), Some(resume.clone()))
+                .await
+                .unwrap();
+        assert_eq!(pipeline.snapshot_state().unwrap(), resume);
+    }
+
+    #[tokio::test]
+    async fn query_pipeline_uses_ordered_distinct_only_over_order_by() {
+        let ordered_resume = |source| PipelineNodeState::Distinct {
+            seen: vec![1, 2],
+            source: Box::new(source),
+        };
+
+        // With ORDER BY, only the last emitted hash is kept.
+        let mut plan = order_by_plan(vec![qr("", "FF")]);
+        plan.query_info.as_mut().unwrap().distinct_type = DistinctType::Ordered;
+        let mut topology = MockTopologyProvider::new(vec![Ok(vec![rr("", "FF", "pkrange-0")])]);
+        let cursors = vec![OrderedMergeCursor {
+            min_epk: String::new(),
+            max_epk: "FF".to_owned(),
+            server_continuation: Some("t1".to_owned()),
+            skip: 0,
+        }];
+        let resume = ordered_resume(PipelineNodeState::OrderedMerge {
+            cursors: cursors.clone(),
+        });
+        let pipeline = build_query_pipeline(
+            &plan,
+            &mut topology,
+            &Arc::new(cross_partition_query_operation()),
+            Some(resume),
+        )
+        .await
+        .unwrap();
+        assert_eq!(
+            pipeline.snapshot_state().unwrap(),
+            PipelineNodeState::Distinct {
+                seen: vec![2],
+                source: Box::new(PipelineNodeState::OrderedMerge { cursors }),
+            }
+        );
+
+        // Without ORDER BY, results are not adjacent, so every hash is kept.
+        let mut plan = plan_with_ranges(vec![qr("", "FF")]);
+        plan.query_info = Some(QueryInfo {
+            distinct_type: DistinctType::Ordered,
+            ..Default::default()
+        });
+        let mut topology = MockTopologyProvider::new(vec![Ok(vec![rr("", "FF", "pkrange-0")])]);
+        let source = PipelineNodeState::SequentialDrain {
+            left_most_undrained_epk: String::new(),
+            active_tokens: vec![],
+        };
+        let resume = ordered_resume(source);
+        let pipeline = build_query_pipeline(
+            &plan,
+            &mut topology,
+            &Arc::new(cross_partition_query_operation()),
+            Some(resume.clone()),
+        )
+        .await
+        .unwrap();
+        assert_eq!(pipeline.snapshot_state().unwrap(), resume);
+    }
+
+    #[tokio::test]
+    async fn query_pipeline_resumes_drained_distinct_as_drained() {
+        let mut plan = plan_with_ranges(vec![qr("", "FF")]);
+        plan.query_info = Some(QueryInfo {
+            distinct_type: DistinctType::Unordered,
+            ..Default::default()
+        });
+        let mut topology = NoopTopologyProvider;
+
+        let pipeline = build_query_pipeline(
+            &plan,
+            &mut topology,
+            &Arc::new(cross_partition_query_operation()),
+            Some(PipelineNodeState::Drained),
+        )
+        .await
+        .unwrap();
+        assert!(pipeline.root().downcast_ref::<DrainedLeaf>().is_some());
+    }
+
     // -----------------------------------------------------------------
     // Resume tests
     // -----------------------------------------------------------------
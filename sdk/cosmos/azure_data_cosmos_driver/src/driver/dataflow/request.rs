@@ -206,10 +206,11 @@ impl PipelineNode for Request {
         // A non-partitioned request can't be affected because it doesn't rely on partition routing.
         // A logical partition key request shouldn't be affected because the gateway should route it to the correct partition even if splits have occurred,
         // but in the unlikely event it is, we retry once inline and have no need to split since a given LPK can never span multiple physical partitions.
+        // A bulk batch can't be split either: its body can't be divided between partitions.
         matches!(
             self.target,
             RequestTarget::EffectivePartitionKeyRange { .. }
-        )
+        ) && !self.operation.is_bulk()
     }
 }
 
@@ -329,6 +330,10 @@ impl Request {
                         self.handle_response(response)
                     })
             }
+            // The caller regroups the batch's operations for the new topology.
+            RequestTarget::EffectivePartitionKeyRange { .. } if self.operation.is_bulk() => {
+                Err(error)
+            }
             RequestTarget::EffectivePartitionKeyRange { .. } => {
                 let range = self
                     .target
@@ -850,6 +855,33 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn bulk_topology_change_is_returned_instead_of_splitting() {
+        let account = crate::models::AccountReference::with_master_key(
+            url::Url::parse("https://test.documents.azure.com:443/").unwrap(),
+            "dGVzdA==",
+        );
+        let properties: crate::models::ContainerProperties =
+            serde_json::from_str(r#"{"id":"coll","partitionKey":{"paths":["/pk"]}}"#).unwrap();
+        let container = crate::models::ContainerReference::new(
+            account,
+            "db",
+            "db_rid",
+            "coll",
+            "coll_rid",
+            &properties,
+        );
+        let operation = CosmosOperation::bulk(container, FeedRange::full());
+        let mut request = Request::new(Arc::new(operation), epk_range_target(), None);
+        assert!(!request.topology_can_change());
+        let mut executor = MockRequestExecutor::new(vec![Err(gone_error())]);
+        let mut topology = NoopTopologyProvider;
+        let mut context = PipelineContext::new(&mut executor, Some(&mut topology));
+
+        let err = request.next_page(&mut context).await.unwrap_err();
+        assert!(err.status().is_partition_topology_change());
+    }
+
     #[tokio::test]
     async fn non_partitioned_topology_change_not_retried() {
         let mut request = Request::new(Arc::new(operation()), RequestTarget::NonPartitioned, None);
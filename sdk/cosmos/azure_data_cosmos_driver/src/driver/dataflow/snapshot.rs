@@ -23,7 +23,7 @@ use crate::models::ChangeFeedStartFrom;
 /// The shape is intentionally open to future intermediate node kinds so a
 /// parent does not need to know what type its child is — every node produces
 /// a `PipelineNodeState` from `snapshot_state()`.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(tag = "kind", rename_all = "snake_case")]
 pub(crate) enum PipelineNodeState {
     /// The node has produced all of its pages.
@@ -78,6 +78,73 @@ pub(crate) enum PipelineNodeState {
         #[serde(default, skip_serializing_if = "Option::is_none")]
         start_from: Option<ChangeFeedStartFrom>,
     },
+
+    /// An ordered (k-way) merge over partition children, used for
+    /// cross-partition `ORDER BY` queries.
+    ///
+    /// `cursors` carries one entry per child that still has results to emit,
+    /// sorted ascending by `min_epk`. Ranges with no entry have been fully
+    /// emitted. Because the merge buffers a page per child and emits its
+    /// items incrementally, each cursor records the server continuation that
+    /// produced the buffered page plus how many of that page's items were
+    /// already emitted; on resume the page is re-fetched and those items are
+    /// skipped.
+    OrderedMerge {
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        cursors: Vec<OrderedMergeCursor>,
+    },
+
+    /// An aggregate over a source subtree, used for cross-partition
+    /// `SELECT VALUE` aggregate queries.
+    ///
+    /// `partials` holds the partial-aggregate documents already read from
+    /// the source, and `source` is the source's own state. Once the final
+    /// value has been emitted the node snapshots as `Drained` instead.
+    Aggregate {
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        partials: Vec<serde_json::Value>,
+        source: Box<PipelineNodeState>,
+    },
+
+    /// A group-by over a source subtree, used for cross-partition `GROUP BY`
+    /// queries.
+    ///
+    /// `rows` holds the partition-local group rows already read from the
+    /// source, and `source` is the source's own state. Once the groups have
+    /// been emitted the node snapshots as `Drained` instead.
+    GroupBy {
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        rows: Vec<serde_json::Value>,
+        source: Box<PipelineNodeState>,
+    },
+
+    /// An offset and row limit over a source subtree, used for
+    /// cross-partition `TOP` and `OFFSET ... LIMIT` queries.
+    ///
+    /// `offset` is the number of results still to be skipped, `limit` the
+    /// number still to be emitted (`None` for no limit), and `source` is the
+    /// source's own state. Once the limit is reached or the source is drained
+    /// the node snapshots as `Drained` instead.
+    Limit {
+        #[serde(default, skip_serializing_if = "is_zero")]
+        offset: u64,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        limit: Option<u64>,
+        source: Box<PipelineNodeState>,
+    },
+
+    /// A distinct over a source subtree, used for cross-partition
+    /// `SELECT DISTINCT` queries.
+    ///
+    /// `seen` holds the hashes of the results already emitted (only the last
+    /// one for ordered distinct), serialized as hex strings, and `source` is
+    /// the source's own state. Once the source is drained the node snapshots
+    /// as `Drained` instead.
+    Distinct {
+        #[serde(default, skip_serializing_if = "Vec::is_empty", with = "hex_hashes")]
+        seen: Vec<u128>,
+        source: Box<PipelineNodeState>,
+    },
 }
 
 /// One entry in a [`PipelineNodeState::SequentialDrain`] `active_tokens`
@@ -95,6 +162,47 @@ pub(crate) struct RangedToken {
     pub(crate) server_continuation: String,
 }
 
+/// One entry in a [`PipelineNodeState::OrderedMerge`] `cursors` list.
+///
+/// `server_continuation` is the token that produced the child's buffered page
+/// (`None` when it was the first page), and `skip` is the number of items from
+/// that page that were already emitted.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub(crate) struct OrderedMergeCursor {
+    pub(crate) min_epk: String,
+    pub(crate) max_epk: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) server_continuation: Option<String>,
+    #[serde(default, skip_serializing_if = "is_zero")]
+    pub(crate) skip: u64,
+}
+
+fn is_zero(value: &u64) -> bool {
+    *value == 0
+}
+
+/// Serializes 128-bit hashes as hex strings, which unlike 128-bit JSON
+/// numbers survive any JSON parser.
+mod hex_hashes {
+    use serde::{de::Error, Deserialize, Deserializer, Serializer};
+
+    pub(super) fn serialize<S: Serializer>(
+        hashes: &[u128],
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(hashes.iter().map(|hash| format!("{hash:032x}")))
+    }
+
+    pub(super) fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Vec<u128>, D::Error> {
+        Vec::<String>::deserialize(deserializer)?
+            .iter()
+            .map(|hash| u128::from_str_radix(hash, 16).map_err(D::Error::custom))
+            .collect()
+    }
+}
+
 /// A child's snapshot state as visible to its parent.
 ///
 /// Parents (e.g., [`SequentialDrain`](super::drain::SequentialDrain))
@@ -145,6 +253,11 @@ impl PipelineNodeState {
                         PipelineNodeState::Request { .. } => "Request",
                         PipelineNodeState::SequentialDrain { .. } => "SequentialDrain",
                         PipelineNodeState::UnorderedMerge { .. } => "UnorderedMerge",
+                        PipelineNodeState::OrderedMerge { .. } => "OrderedMerge",
+                        PipelineNodeState::Aggregate { .. } => "Aggregate",
+                        PipelineNodeState::GroupBy { .. } => "GroupBy",
+                        PipelineNodeState::Distinct { .. } => "Distinct",
+                        PipelineNodeState::Limit { .. } => "Limit",
                     },
                 ))
                 .build()),
@@ -382,4 +495,107 @@ mod tests {
             point_in_time
         );
     }
+
+    #[test]
+    fn ordered_merge_round_trips_cursors() {
+        let state = PipelineNodeState::OrderedMerge {
+            cursors: vec![
+                OrderedMergeCursor {
+                    min_epk: String::new(),
+                    max_epk: "80".to_owned(),
+                    server_continuation: None,
+                    skip: 0,
+                },
+                OrderedMergeCursor {
+                    min_epk: "80".to_owned(),
+                    max_epk: "FF".to_owned(),
+                    server_continuation: Some("t1".to_owned()),
+                    skip: 3,
+                },
+            ],
+        };
+        let json = serde_json::to_string(&state).unwrap();
+        assert_eq!(
+            json,
+            r#"{"kind":"ordered_merge","cursors":[{"min_epk":"","max_epk":"80"},{"min_epk":"80","max_epk":"FF","server_continuation":"t1","skip":3}]}"#,
+            "fresh-start cursors must omit the continuation and zero skip count",
+        );
+        let parsed: PipelineNodeState = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed, state);
+    }
+
+    #[test]
+    fn aggregate_round_trips_partials_and_source() {
+        let state = PipelineNodeState::Aggregate {
+            partials: vec![serde_json::json!([{"item": 3}])],
+            source: Box::new(PipelineNodeState::SequentialDrain {
+                left_most_undrained_epk: "80".to_owned(),
+                active_tokens: vec![],
+            }),
+        };
+        let json = serde_json::to_string(&state).unwrap();
+        assert_eq!(
+            json,
+            r#"{"kind":"aggregate","partials":[[{"item":3}]],"source":{"kind":"sequential_drain","left_most_undrained_epk":"80"}}"#,
+        );
+        let parsed: PipelineNodeState = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed, state);
+    }
+
+    #[test]
+    fn group_by_round_trips_rows_and_source() {
+        let state = PipelineNodeState::GroupBy {
+            rows: vec![serde_json::json!({"groupByItems": [{"item": 1}], "payload": 1})],
+            source: Box::new(PipelineNodeState::Request {
+                server_continuation: Some("t1".to_owned()),
+            }),
+        };
+        let json = serde_json::to_string(&state).unwrap();
+        assert_eq!(
+            json,
+            r#"{"kind":"group_by","rows":[{"groupByItems":[{"item":1}],"payload":1}],"source":{"kind":"request","server_continuation":"t1"}}"#,
+        );
+        let parsed: PipelineNodeState = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed, state);
+    }
+
+    #[test]
+    fn limit_round_trips_remaining_counts() {
+        let state = PipelineNodeState::Limit {
+            offset: 0,
+            limit: Some(4),
+            source: Box::new(PipelineNodeState::Request {
+                server_continuation: None,
+            }),
+        };
+        let json = serde_json::to_string(&state).unwrap();
+        assert_eq!(
+            json, r#"{"kind":"limit","limit":4,"source":{"kind":"request"}}"#,
+            "a zero offset must be omitted",
+        );
+        let parsed: PipelineNodeState = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed, state);
+    }
+
+    #[test]
+    fn distinct_round_trips_seen_hashes_as_hex() {
+        let state = PipelineNodeState::Distinct {
+            seen: vec![1, u128::MAX],
+            source: Box::new(PipelineNodeState::Request {
+                server_continuation: None,
+            }),
+        };
+        let json = serde_json::to_string(&state).unwrap();
+        assert_eq!(
+            json,
+            r#"{"kind":"distinct","seen":["00000000000000000000000000000001","ffffffffffffffffffffffffffffffff"],"source":{"kind":"request"}}"#,
+        );
+        let parsed: PipelineNodeState = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed, state);
+
+        assert!(serde_json::from_str::<PipelineNodeState>(
+            r#"{"kind":"distinct","seen":["xyz"],"source":{"kind":"request"}}"#
+        )
+        .is_err());
+    }
 }
@@ -18,6 +18,7 @@ pub(crate) mod jitter;
 pub(crate) mod pipeline;
 pub(crate) mod routing;
 mod runtime;
+pub(crate) mod throughput_control;
 pub(crate) mod transport;
 
 pub use cosmos_driver::CosmosDriver;
@@ -557,6 +557,9 @@ pub(crate) struct ThrottleRetryState {
 /// Hard-coded defaults for throttle retry.
 pub(crate) const DEFAULT_MAX_THROTTLE_ATTEMPTS: u32 = 9;
 pub(crate) const DEFAULT_MAX_THROTTLE_WAIT: Duration = Duration::from_secs(30);
+/// Defaults for local connectivity retry (see `ConnectivityRetryOptions`).
+pub(crate) const DEFAULT_MAX_CONNECTIVITY_RETRIES: u32 = 1;
+pub(crate) const DEFAULT_CONNECTIVITY_RETRY_BACKOFF: Duration = Duration::ZERO;
 const DEFAULT_MAX_PER_RETRY_DELAY: Duration = Duration::from_secs(5);
 const DEFAULT_FALLBACK_BASE_DELAY: Duration = Duration::from_millis(5);
 const DEFAULT_BACKOFF_FACTOR: f64 = 2.0;
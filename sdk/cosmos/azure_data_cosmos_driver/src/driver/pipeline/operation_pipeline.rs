@@ -40,8 +40,8 @@ use crate::{
 use super::{
     components::{
         OperationAction, OperationRetryState, RoutingDecision, TransportMode, TransportOutcome,
-        TransportRequest, TransportResult, DEFAULT_MAX_THROTTLE_ATTEMPTS,
-        DEFAULT_MAX_THROTTLE_WAIT,
+        TransportRequest, TransportResult, DEFAULT_CONNECTIVITY_RETRY_BACKOFF,
+        DEFAULT_MAX_CONNECTIVITY_RETRIES, DEFAULT_MAX_THROTTLE_ATTEMPTS, DEFAULT_MAX_THROTTLE_WAIT,
     },
     hedging_diagnostics::{HedgeDiagnostics, HedgingStrategyConfig},
     hedging_eligibility::evaluate_hedge_eligibility,
@@ -240,6 +240,19 @@ pub(crate) async fn execute_operation_pipeline(
         .copied()
         .unwrap_or(DEFAULT_MAX_THROTTLE_WAIT);
 
+    // Local connectivity retry limits (connect/DNS/reset failures), forwarded
+    // to the transport pipeline with the same per-invocation scope as the
+    // throttle limits above.
+    let connectivity_retry_options = options.connectivity_retry_options();
+    let max_connectivity_retries = connectivity_retry_options
+        .max_retry_count()
+        .copied()
+        .unwrap_or(DEFAULT_MAX_CONNECTIVITY_RETRIES);
+    let connectivity_retry_backoff = connectivity_retry_options
+        .retry_backoff()
+        .copied()
+        .unwrap_or(DEFAULT_CONNECTIVITY_RETRY_BACKOFF);
+
     // Determine if session consistency is active for this operation.
     let session_capturing_disabled = options
         .session_capturing_disabled()
@@ -589,6 +602,7 @@ pub(crate) async fn execute_operation_pipeline(
 
         // ── STAGE 4: Execute via transport pipeline ────────────────────
 
+        let attempt_started = Instant::now();
         let result = execute_transport_pipeline(
             transport_request,
             &TransportPipelineContext {
@@ -603,11 +617,22 @@ pub(crate) async fn execute_operation_pipeline(
                 collection_rid: operation.container().map(|c| c.rid().to_owned()),
                 max_throttle_attempts,
                 max_throttle_wait_time,
+                max_connectivity_retries,
+                connectivity_retry_backoff,
             },
             &mut diagnostics,
         )
         .await;
 
+        // Feed latency-based read routing. Only successful data-plane reads
+        // count, so failures and slow metadata calls don't skew the average.
+        if operation.is_read_only()
+            && pipeline_type.is_data_plane()
+            && matches!(result.outcome, TransportOutcome::Success { .. })
+        {
+            location_state_store.record_read_latency(&routing.endpoint, attempt_started.elapsed());
+        }
+
         // Fallback: capture the partition key range ID from response headers
         // only if pre-resolution (from the request's partition key / EPK range)
         // did not already seed it. Set once, on the first response that carries it.
@@ -1158,14 +1183,23 @@ fn resolve_endpoint(
         route_to_write_endpoints,
         is_distributed_transaction_operation(operation),
     );
-    let selected = try_select_endpoint(
+    let selected = latency_preferred_endpoint(
         operation,
         retry_state,
-        account,
+        location,
         primary,
-        &in_flight_failed,
         endpoint_unavailability_ttl,
-    );
+    )
+    .or_else(|| {
+        try_select_endpoint(
+            operation,
+            retry_state,
+            account,
+            primary,
+            &in_flight_failed,
+            endpoint_unavailability_ttl,
+        )
+    });
 
     // If every region in the primary list has been attempted (or excluded),
     // fall back to the standard selection ignoring the in-flight skip set so
@@ -1444,6 +1478,51 @@ fn preferred_endpoints_for_attempt<'a>(
     }
 }
 
+/// Returns the read endpoint chosen by latency-based read routing for an
+/// operation's first attempt: the lowest-latency eligible endpoint, or
+/// periodically another eligible endpoint to sample its latency.
+///
+/// Only reads routed to the preferred read endpoints are affected. Retries
+/// (failover and session) always follow the preference-list order, so a
+/// failing low-latency endpoint cannot pin an operation.
+fn latency_preferred_endpoint(
+    operation: &CosmosOperation,
+    retry_state: &OperationRetryState,
+    location: &LocationSnapshot,
+    endpoints: &[CosmosEndpoint],
+    endpoint_unavailability_ttl: Duration,
+) -> Option<CosmosEndpoint> {
+    let tracker = location.read_latency.as_ref()?;
+    let account = location.account.as_ref();
+    if !operation.is_read_only()
+        || !std::ptr::eq(endpoints, account.preferred_read_endpoints.as_ref())
+        || retry_state.failover_retry_count > 0
+        || retry_state.session_token_retry_count > 0
+    {
+        return None;
+    }
+    let now = Instant::now();
+    let candidates: Vec<&CosmosEndpoint> = endpoints
+        .iter()
+        .filter(|candidate| {
+            let excluded = candidate
+                .region()
+                .is_some_and(|r| retry_state.excluded_regions.iter().any(|e| e == r));
+            !excluded
+                && endpoint_is_available(
+                    operation,
+                    candidate,
+                    account,
+                    now,
+                    endpoint_unavailability_ttl,
+                )
+        })
+        .collect();
+    let urls: Vec<&url::Url> = candidates.iter().map(|e| e.url()).collect();
+    let selected = tracker.select_read_endpoint(&urls)?;
+    Some(candidates[selected].clone())
+}
+
 /// Walks `endpoints` starting at the retry-state base index and returns the
 /// first endpoint that is not excluded, not in `skip_regions`, and currently
 /// available. Falls back to the first unavailable-but-not-skipped endpoint
@@ -1600,17 +1679,23 @@ fn build_transport_request(
             );
         }
         OperationType::Batch => {
+            // A bulk batch commits each operation independently and continues past failures.
+            let (atomic, continue_on_error) = if operation.is_bulk() {
+                ("False", "True")
+            } else {
+                ("True", "False")
+            };
             headers.insert(
                 HeaderName::from_static(request_header_names::IS_BATCH_REQUEST),
                 HeaderValue::from_static("True"),
             );
             headers.insert(
                 HeaderName::from_static(request_header_names::BATCH_ATOMIC),
-                HeaderValue::from_static("True"),
+                HeaderValue::from_static(atomic),
             );
             headers.insert(
                 HeaderName::from_static(request_header_names::BATCH_CONTINUE_ON_ERROR),
-                HeaderValue::from_static("False"),
+                HeaderValue::from_static(continue_on_error),
             );
         }
         OperationType::Query | OperationType::SqlQuery => {
@@ -2634,6 +2719,15 @@ async fn perform_single_attempt(
         .max_retry_wait_time()
         .copied()
         .unwrap_or(DEFAULT_MAX_THROTTLE_WAIT);
+    let connectivity_retry_options = ctx.options.connectivity_retry_options();
+    let max_connectivity_retries = connectivity_retry_options
+        .max_retry_count()
+        .copied()
+        .unwrap_or(DEFAULT_MAX_CONNECTIVITY_RETRIES);
+    let connectivity_retry_backoff = connectivity_retry_options
+        .retry_backoff()
+        .copied()
+        .unwrap_or(DEFAULT_CONNECTIVITY_RETRY_BACKOFF);
 
     let result = execute_transport_pipeline(
         transport_request,
@@ -2650,6 +2744,8 @@ async fn perform_single_attempt(
             collection_rid: ctx.operation.container().map(|c| c.rid().to_owned()),
             max_throttle_attempts,
             max_throttle_wait_time,
+            max_connectivity_retries,
+            connectivity_retry_backoff,
         },
         diagnostics,
     )
@@ -4462,6 +4558,135 @@ mod tests {
         assert_eq!(routing.endpoint, write_endpoint);
     }
 
+    #[test]
+    fn resolve_endpoint_prefers_low_latency_read_endpoint_on_first_attempt() {
+        let operation = CosmosOperation::read_all_databases(test_account());
+        let east = CosmosEndpoint::regional(
+            "eastus".into(),
+            Url::parse("https://test-eastus.documents.azure.com:443/").unwrap(),
+        );
+        let west = CosmosEndpoint::regional(
+            "westus2".into(),
+            Url::parse("https://test-westus2.documents.azure.com:443/").unwrap(),
+        );
+
+        let mut location = LocationSnapshot::for_tests(Arc::new(AccountEndpointState {
+            generation: 0,
+            preferred_read_endpoints: vec![east.clone(), west.clone()].into(),
+            preferred_write_endpoints: vec![east.clone()].into(),
+            account_write_endpoints: vec![east.clone()].into(),
+            unavailable_endpoints: Default::default(),
+            multiple_write_locations_enabled: false,
+            default_endpoint: east.clone(),
+        }));
+        let tracker = crate::driver::routing::endpoint_latency::EndpointLatencyTracker::default();
+        for _ in 0..5 {
+            tracker.record(west.url(), Duration::from_millis(10));
+        }
+        location.read_latency = Some(Arc::new(tracker));
+
+        let first_attempt = crate::driver::pipeline::components::OperationRetryState::initial(
+            0,
+            false,
+            Vec::new(),
+            3,
+            1,
+        );
+        let routing = super::resolve_endpoint(
+            &operation,
+            &first_attempt,
+            &location,
+            false,
+            true,
+            Duration::from_secs(60),
+        );
+        assert_eq!(routing.endpoint, west);
+
+        // Failover retries follow preferred-region order again.
+        let mut retry = first_attempt.clone();
+        retry.failover_retry_count = 1;
+        let routing = super::resolve_endpoint(
+            &operation,
+            &retry,
+            &location,
+            false,
+            true,
+            Duration::from_secs(60),
+        );
+        assert_eq!(routing.endpoint, east);
+
+        // An excluded region is never chosen by latency routing.
+        let mut excluded = first_attempt;
+        excluded.excluded_regions = vec!["westus2".into()];
+        let routing = super::resolve_endpoint(
+            &operation,
+            &excluded,
+            &location,
+            false,
+            true,
+            Duration::from_secs(60),
+        );
+        assert_eq!(routing.endpoint, east);
+    }
+
+    #[test]
+    fn resolve_endpoint_latency_routing_samples_other_read_endpoints() {
+        let operation = CosmosOperation::read_all_databases(test_account());
+        let east = CosmosEndpoint::regional(
+            "eastus".into(),
+            Url::parse("https://test-eastus.documents.azure.com:443/").unwrap(),
+        );
+        let west = CosmosEndpoint::regional(
+            "westus2".into(),
+            Url::parse("https://test-westus2.documents.azure.com:443/").unwrap(),
+        );
+
+        let mut location = LocationSnapshot::for_tests(Arc::new(AccountEndpointState {
+            generation: 0,
+            preferred_read_endpoints: vec![east.clone(), west.clone()].into(),
+            preferred_write_endpoints: vec![east.clone()].into(),
+            account_write_endpoints: vec![east.clone()].into(),
+            unavailable_endpoints: Default::default(),
+            multiple_write_locations_enabled: false,
+            default_endpoint: east.clone(),
+        }));
+        let tracker =
+            Arc::new(crate::driver::routing::endpoint_latency::EndpointLatencyTracker::default());
+        location.read_latency = Some(tracker.clone());
+        let first_attempt = crate::driver::pipeline::components::OperationRetryState::initial(
+            0,
+            false,
+            Vec::new(),
+            3,
+            1,
+        );
+
+        // Reads start in preference order, so the slower east region is the
+        // first to collect enough samples. Probe reads sample west as well,
+        // and routing moves to it once its average is known.
+        let mut routed = Vec::new();
+        for _ in 0..200 {
+            let routing = super::resolve_endpoint(
+                &operation,
+                &first_attempt,
+                &location,
+                false,
+                true,
+                Duration::from_secs(60),
+            );
+            let latency = if routing.endpoint == east { 100 } else { 20 };
+            tracker.record(routing.endpoint.url(), Duration::from_millis(latency));
+            routed.push(routing.endpoint);
+        }
+
+        assert_eq!(routed[0], east);
+        assert!(routed[..20].contains(&west), "west is never probed");
+        assert!(
+            routed[150..].iter().filter(|e| **e == west).count() > 40,
+            "routing never moved to the faster region"
+        );
+    }
+
     #[cfg(feature = "preview_dtx")]
     #[test]
     fn resolve_endpoint_uses_account_write_region_order_for_read_dtx() {
@@ -7721,6 +7946,54 @@ mod tests {
         );
     }
 
+    #[test]
+    fn build_transport_request_sets_bulk_headers() {
+        let operation =
+            CosmosOperation::bulk(test_container(), FeedRange::full()).with_body(b"[]".to_vec());
+
+        let routing = test_routing();
+        let activity_id = ActivityId::from_string("default-activity".to_string());
+        let ctx = TransportRequestContext {
+            routing: &routing,
+            activity_id: &activity_id,
+            execution_context: ExecutionContext::Initial,
+            deadline: None,
+            resolved_session_token: None,
+            throughput_control: None,
+            effective_consistency: DefaultConsistencyLevel::Session,
+            read_consistency_strategy: crate::options::ReadConsistencyStrategy::Default,
+        };
+        let request =
+            build_transport_request(&operation, &OperationOverrides::default(), None, &ctx)
+                .expect("request should build");
+
+        assert_eq!(
+            request
+                .headers
+                .get_optional_str(&HeaderName::from_static("x-ms-cosmos-is-batch-request")),
+            Some("True")
+        );
+        assert_eq!(
+            request
+                .headers
+                .get_optional_str(&HeaderName::from_static("x-ms-cosmos-batch-atomic")),
+            Some("False")
+        );
+        assert_eq!(
+            request.headers.get_optional_str(&HeaderName::from_static(
+                "x-ms-cosmos-batch-continue-on-error"
+            )),
+            Some("True")
+        );
+        // Each operation in a bulk body carries its own partition key.
+        assert_eq!(
+            request
+                .headers
+                .get_optional_str(&HeaderName::from_static("x-ms-documentdb-partitionkey")),
+            None
+        );
+    }
+
     #[test]
     fn build_transport_request_omits_batch_headers_for_create() {
         let container = test_container();
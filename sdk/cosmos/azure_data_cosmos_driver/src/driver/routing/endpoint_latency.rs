@@ -0,0 +1,245 @@
+// Copyright (c) Microsoft Corporation. All rights reserved.
+// Licensed under the MIT License.
+
+//! Rolling per-endpoint read latency used by latency-aware read routing.
+//!
+//! Reads only measure the endpoint they are sent to, so every
+//! [`PROBE_INTERVAL`]th routed read is sent to an alternative endpoint
+//! instead of the preferred one. This keeps the other endpoints' averages
+//! current, so routing can move to a region that is (or becomes) faster.
+
+use std::{collections::HashMap, sync::Mutex, time::Duration};
+
+use url::Url;
+
+/// Weight of the newest sample in the exponentially weighted moving average.
+const EWMA_WEIGHT: f64 = 0.2;
+
+/// Samples an endpoint needs before it can become the preferred endpoint.
+const MIN_SAMPLES: u32 = 5;
+
+/// A candidate replaces the preferred endpoint only when its average latency
+/// is below this fraction of the preferred endpoint's average. The gap keeps
+/// routing from flapping between endpoints with similar latency.
+const SWITCH_RATIO: f64 = 0.8;
+
+/// Every this many routed reads, one is sent to an alternative endpoint to
+/// sample its latency.
+const PROBE_INTERVAL: u64 = 10;
+
+#[derive(Clone, Copy, Debug)]
+struct EndpointLatency {
+    average: Duration,
+    samples: u32,
+}
+
+#[derive(Debug, Default)]
+struct LatencyState {
+    endpoints: HashMap<Url, EndpointLatency>,
+    preferred: Option<Url>,
+    /// Reads routed by [`EndpointLatencyTracker::select_read_endpoint`].
+    reads: u64,
+    /// Probe reads sent so far, used to rotate through the alternatives.
+    probes: usize,
+}
+
+/// Tracks a rolling average of successful read latency per endpoint and
+/// derives the lowest-latency endpoint, with hysteresis.
+#[derive(Debug, Default)]
+pub(crate) struct EndpointLatencyTracker {
+    state: Mutex<LatencyState>,
+}
+
+impl EndpointLatencyTracker {
+    /// Records the latency of a successful read against `endpoint`.
+    pub fn record(&self, endpoint: &Url, latency: Duration) {
+        let mut state = self.state.lock().unwrap();
+        state
+            .endpoints
+            .entry(endpoint.clone())
+            .and_modify(|e| {
+                e.average = e.average.mul_f64(1.0 - EWMA_WEIGHT) + latency.mul_f64(EWMA_WEIGHT);
+                e.samples = e.samples.saturating_add(1);
+            })
+            .or_insert(EndpointLatency {
+                average: latency,
+                samples: 1,
+            });
+
+        let Some((best_url, best)) = state
+            .endpoints
+            .iter()
+            .filter(|(_, e)| e.samples >= MIN_SAMPLES)
+            .min_by_key(|(_, e)| e.average)
+            .map(|(url, e)| (url.clone(), *e))
+        else {
+            return;
+        };
+
+        let current = state
+            .preferred
+            .as_ref()
+            .and_then(|url| state.endpoints.get(url));
+        let switch = match current {
+            Some(current) => best.average < current.average.mul_f64(SWITCH_RATIO),
+            None => true,
+        };
+        if switch {
+            state.preferred = Some(best_url);
+        }
+    }
+
+    /// Chooses the endpoint for a read among `candidates`, the available
+    /// read endpoints in preference order.
+    ///
+    /// Returns the index of the lowest-latency candidate, or `None` to keep
+    /// preference-list routing while no candidate has enough samples. Every
+    /// [`PROBE_INTERVAL`]th call instead returns one of the other candidates
+    /// in turn, so their latency keeps being sampled.
+    pub fn select_read_endpoint(&self, candidates: &[&Url]) -> Option<usize> {
+        let mut state = self.state.lock().unwrap();
+        state.reads = state.reads.wrapping_add(1);
+        let preferred = state
+            .preferred
+            .as_ref()
+            .and_then(|url| candidates.iter().position(|c| *c == url));
+
+        if state.reads.is_multiple_of(PROBE_INTERVAL) {
+            // Without a preference, reads go to the first candidate.
+            let current = preferred.unwrap_or(0);
+            let alternatives: Vec<usize> =
+                (0..candidates.len()).filter(|&i| i != current).collect();
+            if !alternatives.is_empty() {
+                let probe = alternatives[state.probes % alternatives.len()];
+                state.probes = state.probes.wrapping_add(1);
+                return Some(probe);
+            }
+        }
+        preferred
+    }
+
+    #[cfg(test)]
+    pub fn preferred(&self) -> Option<Url> {
+        self.state.lock().unwrap().preferred.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn url(name: &str) -> Url {
+        Url::parse(&format!("https://test-{name}.documents.azure.com/")).unwrap()
+    }
+
+    fn record_n(tracker: &EndpointLatencyTracker, endpoint: &Url, millis: u64, n: u32) {
+        for _ in 0..n {
+            tracker.record(endpoint, Duration::from_millis(millis));
+        }
+    }
+
+    #[test]
+    fn no_preference_until_min_samples() {
+        let tracker = EndpointLatencyTracker::default();
+        let east = url("eastus");
+
+        record_n(&tracker, &east, 10, MIN_SAMPLES - 1);
+        assert_eq!(tracker.preferred(), None);
+
+        tracker.record(&east, Duration::from_millis(10));
+        assert_eq!(tracker.preferred(), Some(east));
+    }
+
+    #[test]
+    fn prefers_lowest_latency_endpoint() {
+        let tracker = EndpointLatencyTracker::default();
+        let east = url("eastus");
+        let west = url("westus");
+
+        record_n(&tracker, &east, 100, MIN_SAMPLES);
+        assert_eq!(tracker.preferred(), Some(east));
+
+        record_n(&tracker, &west, 20, MIN_SAMPLES);
+        assert_eq!(tracker.preferred(), Some(west));
+    }
+
+    #[test]
+    fn hysteresis_keeps_preference_for_similar_latency() {
+        let tracker = EndpointLatencyTracker::default();
+        let east = url("eastus");
+        let west = url("westus");
+
+        record_n(&tracker, &east, 100, MIN_SAMPLES);
+        // 10% faster is within the hysteresis band.
+        record_n(&tracker, &west, 90, MIN_SAMPLES);
+        assert_eq!(tracker.preferred(), Some(east.clone()));
+
+        // The preferred endpoint slows down past the band, so routing switches.
+        record_n(&tracker, &east, 200, 10);
+        assert_eq!(tracker.preferred(), Some(west));
+    }
+
+    #[test]
+    fn probes_alternatives_in_turn() {
+        let tracker = EndpointLatencyTracker::default();
+        let east = url("eastus");
+        let west = url("westus");
+        let north = url("northeurope");
+        let candidates = [&east, &west, &north];
+
+        // Without a preference, reads follow preference-list routing except
+        // for the periodic probes of the other candidates.
+        let selected: Vec<_> = (0..2 * PROBE_INTERVAL)
+            .map(|_| tracker.select_read_endpoint(&candidates))
+            .collect();
+        let probes: Vec<_> = selected.iter().flatten().copied().collect();
+        assert_eq!(probes, vec![1, 2]);
+        assert_eq!(selected[PROBE_INTERVAL as usize - 1], Some(1));
+
+        // With a preference, probes skip the preferred endpoint.
+        record_n(&tracker, &west, 10, MIN_SAMPLES);
+        let selected: Vec<_> = (0..2 * PROBE_INTERVAL)
+            .map(|_| tracker.select_read_endpoint(&candidates))
+            .collect();
+        assert_eq!(
+            selected.iter().filter(|s| **s == Some(1)).count(),
+            2 * PROBE_INTERVAL as usize - 2
+        );
+        assert!(selected.contains(&Some(0)));
+        assert!(selected.contains(&Some(2)));
+    }
+
+    #[test]
+    fn probe_samples_let_a_faster_endpoint_take_over() {
+        let tracker = EndpointLatencyTracker::default();
+        let east = url("eastus");
+        let west = url("westus");
+        let candidates = [&east, &west];
+
+        // Simulate reads whose latency depends on the endpoint they go to.
+        for _ in 0..20 * PROBE_INTERVAL {
+            let selected = tracker.select_read_endpoint(&candidates).unwrap_or(0);
+            let latency = if selected == 0 { 100 } else { 20 };
+            tracker.record(candidates[selected], Duration::from_millis(latency));
+        }
+
+        assert_eq!(tracker.preferred(), Some(west));
+    }
+
+    #[test]
+    fn average_tracks_recent_samples() {
+        let tracker = EndpointLatencyTracker::default();
+        let east = url("eastus");
+
+        tracker.record(&east, Duration::from_millis(100));
+        tracker.record(&east, Duration::from_millis(200));
+
+        let state = tracker.state.lock().unwrap();
+        let average = state.endpoints[&east].average;
+        assert!(
+            average.abs_diff(Duration::from_millis(120)) < Duration::from_micros(1),
+            "unexpected average {average:?}"
+        );
+        assert_eq!(state.endpoints[&east].samples, 2);
+    }
+}
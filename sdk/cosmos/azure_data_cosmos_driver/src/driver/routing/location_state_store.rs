@@ -29,7 +29,8 @@ use crate::{
 
 use super::{
     advance_hub_region_discovery, build_account_endpoint_state, cache_hub_region,
-    expire_partition_overrides, mark_endpoint_unavailable, mark_partition_unavailable,
+    endpoint_latency::EndpointLatencyTracker, expire_partition_overrides,
+    mark_endpoint_unavailable, mark_partition_unavailable,
     partition_endpoint_state::PartitionEndpointState, partition_key_range_id::PartitionKeyRangeId,
     record_hedge_alternate_win, record_hedge_primary_win, AccountEndpointState, CosmosEndpoint,
     LocationEffect,
@@ -40,6 +41,8 @@ use super::{
 pub(crate) struct LocationSnapshot {
     pub account: Arc<AccountEndpointState>,
     pub partitions: Arc<PartitionEndpointState>,
+    /// Per-endpoint read latency when latency-based read routing is enabled.
+    pub read_latency: Option<Arc<EndpointLatencyTracker>>,
 }
 
 #[cfg(test)]
@@ -48,6 +51,7 @@ impl LocationSnapshot {
         Self {
             account,
             partitions: Arc::new(PartitionEndpointState::default()),
+            read_latency: None,
         }
     }
 
@@ -58,6 +62,7 @@ impl LocationSnapshot {
         Self {
             account,
             partitions,
+            read_latency: None,
         }
     }
 }
@@ -131,6 +136,9 @@ pub(crate) struct LocationStateStore {
     last_synced_properties: std::sync::Mutex<Option<Arc<AccountProperties>>>,
     /// Monotonic version counter bumped on every successful CAS write.
     account_version: AtomicU64,
+    /// Rolling read latency per endpoint. `Some` only when latency-based read
+    /// routing is enabled.
+    read_latency: Option<Arc<EndpointLatencyTracker>>,
     /// Cached snapshot: (version, snapshot). When the version matches
     /// `account_version`, `snapshot()` returns `Arc::clone()` of the cached
     /// arcs (refcount increment only) instead of a full clone.
@@ -195,6 +203,7 @@ impl LocationStateStore {
         let initial_snapshot = LocationSnapshot {
             account: Arc::new(account_state.clone()),
             partitions: Arc::new(partition_state.clone()),
+            read_latency: None,
         };
 
         Self {
@@ -220,12 +229,33 @@ impl LocationStateStore {
             last_synced_etag: std::sync::Mutex::new(String::new()),
             last_synced_properties: std::sync::Mutex::new(None),
             account_version: AtomicU64::new(0),
+            read_latency: None,
             cached_snapshot: std::sync::Mutex::new((0, initial_snapshot)),
             #[cfg(feature = "tokio")]
             background_task_manager: BackgroundTaskManager::new(),
         }
     }
 
+    /// Enables latency-based read routing.
+    ///
+    /// When enabled, successful read latency is tracked per endpoint and
+    /// snapshots carry the tracker, which routing uses to choose the
+    /// lowest-latency read endpoint (or an alternative to probe) on an
+    /// operation's first attempt.
+    pub fn with_latency_based_read_routing(mut self, enabled: bool) -> Self {
+        self.read_latency = enabled.then(Default::default);
+        self
+    }
+
+    /// Records the latency of a successful read against `endpoint`.
+    ///
+    /// No-op unless latency-based read routing is enabled.
+    pub fn record_read_latency(&self, endpoint: &CosmosEndpoint, latency: Duration) {
+        if let Some(tracker) = &self.read_latency {
+            tracker.record(endpoint.url(), latency);
+        }
+    }
+
     /// Returns the default endpoint.
     pub fn default_endpoint(&self) -> &CosmosEndpoint {
         &self.default_endpoint
@@ -250,6 +280,14 @@ impl LocationStateStore {
         AccountEndpoint::new(self.default_endpoint.url().clone()).global_database_account_name()
     }
 
+    /// Returns a snapshot of account and partition state, plus the read
+    /// latency tracker.
+    pub fn snapshot(&self) -> LocationSnapshot {
+        let mut snapshot = self.routing_snapshot();
+        snapshot.read_latency = self.read_latency.clone();
+        snapshot
+    }
+
     /// Returns a snapshot of account and partition state.
     ///
     /// Uses a fast path when the version hasn't changed since the last
@@ -257,7 +295,7 @@ impl LocationStateStore {
     /// epoch-protected pointers. On a version mismatch (slow path),
     /// the current state is loaded under an epoch guard, cloned into
     /// fresh `Arc`s, and cached for subsequent callers.
-    pub fn snapshot(&self) -> LocationSnapshot {
+    fn routing_snapshot(&self) -> LocationSnapshot {
         let current_version = self.account_version.load(Ordering::Acquire);
 
         {
@@ -284,6 +322,7 @@ impl LocationStateStore {
         let snapshot = LocationSnapshot {
             account,
             partitions,
+            read_latency: None,
         };
 
         let mut cached = self.cached_snapshot.lock().unwrap();
@@ -5,6 +5,7 @@
 
 mod account_endpoint_state;
 mod endpoint;
+pub(crate) mod endpoint_latency;
 mod location_effects;
 mod location_state_store;
 pub(crate) mod partition_endpoint_state;
@@ -8,6 +8,8 @@ use crate::models::{
     SessionToken,
 };
 use std::collections::HashMap;
+use std::num::NonZeroUsize;
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::RwLock;
 
 use azure_core::fmt::SafeDebug;
@@ -20,19 +22,36 @@ use azure_core::fmt::SafeDebug;
 ///
 /// Thread-safety is provided via [`std::sync::RwLock`] because the lock is
 /// never held across `.await` points.
+///
+/// When a maximum collection count is configured, storing a token for a new
+/// collection beyond that count evicts the least recently used collection.
 #[derive(SafeDebug)]
 pub(crate) struct SessionContainer {
     inner: RwLock<SessionContainerInner>,
+    /// Maximum number of collections to keep tokens for; `None` is unbounded.
+    max_collections: Option<NonZeroUsize>,
+    /// Monotonic clock stamped on a collection every time it is used.
+    clock: AtomicU64,
 }
 
 #[derive(Debug, Default)]
 struct SessionContainerInner {
     /// `collection_rid → (pk_range_id → SessionTokenValue)`
-    tokens: HashMap<ResourceId, HashMap<String, SessionTokenValue>>,
+    tokens: HashMap<ResourceId, CollectionTokens>,
     /// `collection_name_path → collection_rid` (name path = `dbs/{db}/colls/{coll}`)
     name_to_rid: HashMap<String, ResourceId>,
 }
 
+/// The cached tokens of one collection.
+#[derive(Debug, Default)]
+struct CollectionTokens {
+    /// `pk_range_id → SessionTokenValue`
+    ranges: HashMap<String, SessionTokenValue>,
+    /// [`SessionContainer::clock`] value at the last use, for LRU eviction.
+    /// Atomic so lookups can refresh it under the read lock.
+    last_used: AtomicU64,
+}
+
 /// Returns the `dbs/{db}/colls/{coll}` name path from a [`ContainerReference`],
 /// reusing the pre-computed `name_based_path` by skipping the leading `/`.
 fn name_path(container: &ContainerReference) -> &str {
@@ -41,10 +60,52 @@ fn name_path(container: &ContainerReference) -> &str {
 }
 
 impl SessionContainer {
-    /// Creates a new, empty session container.
+    /// Creates a new, empty session container with no size limit.
+    #[cfg(test)]
     pub(crate) fn new() -> Self {
+        Self::with_max_collections(None)
+    }
+
+    /// Creates a new, empty session container that keeps tokens for at most
+    /// `max_collections` collections.
+    pub(crate) fn with_max_collections(max_collections: Option<NonZeroUsize>) -> Self {
         Self {
             inner: RwLock::new(SessionContainerInner::default()),
+            max_collections,
+            clock: AtomicU64::new(0),
+        }
+    }
+
+    /// Looks up a collection's tokens by RID, marking the collection as recently used.
+    fn touch<'a>(
+        &self,
+        inner: &'a SessionContainerInner,
+        collection_rid: &str,
+    ) -> Option<&'a HashMap<String, SessionTokenValue>> {
+        let entry = inner.tokens.get(collection_rid)?;
+        entry.last_used.store(
+            self.clock.fetch_add(1, Ordering::Relaxed),
+            Ordering::Relaxed,
+        );
+        Some(&entry.ranges)
+    }
+
+    /// Evicts least recently used collections until at most `max_collections` remain.
+    fn evict(&self, inner: &mut SessionContainerInner) {
+        let Some(max_collections) = self.max_collections else {
+            return;
+        };
+        while inner.tokens.len() > max_collections.get() {
+            let Some(oldest) = inner
+                .tokens
+                .iter()
+                .min_by_key(|(_, entry)| entry.last_used.load(Ordering::Relaxed))
+                .map(|(rid, _)| rid.clone())
+            else {
+                return;
+            };
+            inner.tokens.remove(&oldest);
+            inner.name_to_rid.retain(|_, rid| *rid != oldest);
         }
     }
 
@@ -52,10 +113,11 @@ impl SessionContainer {
     ///
     /// Segments are sorted by partition-key-range ID for deterministic output.
     fn build_composite_token(
+        &self,
         inner: &SessionContainerInner,
         collection_rid: &str,
     ) -> Option<SessionToken> {
-        let pk_map = inner.tokens.get(collection_rid)?;
+        let pk_map = self.touch(inner, collection_rid)?;
         if pk_map.is_empty() {
             return None;
         }
@@ -79,14 +141,14 @@ impl SessionContainer {
         let rid = container.rid();
 
         // Try direct RID lookup
-        if let Some(token) = Self::build_composite_token(&guard, rid) {
+        if let Some(token) = self.build_composite_token(&guard, rid) {
             return Some(token);
         }
 
         // Fall back to name → RID → token
         let np = name_path(container);
         if let Some(resolved_rid) = guard.name_to_rid.get(np) {
-            return Self::build_composite_token(&guard, resolved_rid.as_str());
+            return self.build_composite_token(&guard, resolved_rid.as_str());
         }
 
         None
@@ -108,7 +170,7 @@ impl SessionContainer {
         let guard = self.inner.read().unwrap_or_else(|e| e.into_inner());
 
         let lookup = |rid: &str| -> Option<SessionToken> {
-            let vector = guard.tokens.get(rid)?.get(pk_range_id)?;
+            let vector = self.touch(&guard, rid)?.get(pk_range_id)?;
             Some(SessionToken::new(format!("{pk_range_id}:{vector}")))
         };
 
@@ -134,12 +196,12 @@ impl SessionContainer {
         parents: &[String],
     ) -> Option<SessionToken> {
         let guard = self.inner.read().unwrap_or_else(|e| e.into_inner());
-        let pk_map = guard.tokens.get(container.rid()).or_else(|| {
+        let pk_map = self.touch(&guard, container.rid()).or_else(|| {
             let np = name_path(container);
             guard
                 .name_to_rid
                 .get(np)
-                .and_then(|resolved_rid| guard.tokens.get(resolved_rid))
+                .and_then(|resolved_rid| self.touch(&guard, resolved_rid.as_str()))
         })?;
 
         if let Some(token) = pk_map.get(partition_key_range_id) {
@@ -235,16 +297,22 @@ impl SessionContainer {
         }
         guard.name_to_rid.insert(np.to_owned(), rid.clone());
 
-        let pk_map = guard.tokens.entry(rid).or_default();
+        let entry = guard.tokens.entry(rid).or_default();
+        entry.last_used.store(
+            self.clock.fetch_add(1, Ordering::Relaxed),
+            Ordering::Relaxed,
+        );
 
         for (pk_range_id, new_token) in parsed {
-            pk_map
+            entry
+                .ranges
                 .entry(pk_range_id)
                 .and_modify(|existing| {
                     existing.merge(&new_token);
                 })
                 .or_insert(new_token);
         }
+        self.evict(&mut guard);
 
         Ok(())
     }
@@ -400,6 +468,24 @@ mod tests {
         );
     }
 
+    #[test]
+    fn max_collections_evicts_least_recently_used() {
+        let sc = SessionContainer::with_max_collections(NonZeroUsize::new(2));
+        let c1 = test_container("db1", "c1", "rid1");
+        let c2 = test_container("db1", "c2", "rid2");
+        let c3 = test_container("db1", "c3", "rid3");
+        sc.set_session_token(&c1, "0:1#100#1=10");
+        sc.set_session_token(&c2, "0:1#200#1=20");
+
+        // Reading c1 makes c2 the least recently used collection.
+        assert!(sc.resolve_session_token(&c1).is_some());
+        sc.set_session_token(&c3, "0:1#300#1=30");
+
+        assert!(sc.resolve_session_token(&c1).is_some());
+        assert!(sc.resolve_session_token(&c2).is_none());
+        assert!(sc.resolve_session_token(&c3).is_some());
+    }
+
     #[test]
     fn name_based_resolution_fallback() {
         let sc = SessionContainer::new();
@@ -13,6 +13,7 @@ use crate::models::{
 };
 
 use super::session_container::SessionContainer;
+use std::num::NonZeroUsize;
 
 /// Determines whether a given resource type + operation type combination targets
 /// the master partition (metadata), meaning session tokens should NOT be
@@ -51,9 +52,16 @@ pub(crate) struct SessionManager {
 
 impl SessionManager {
     /// Creates a new session manager with an empty cache.
+    #[cfg(test)]
     pub(crate) fn new() -> Self {
+        Self::with_max_containers(None)
+    }
+
+    /// Creates a new session manager whose cache holds tokens for at most
+    /// `max_containers` containers, evicting the least recently used.
+    pub(crate) fn with_max_containers(max_containers: Option<NonZeroUsize>) -> Self {
         Self {
-            container: SessionContainer::new(),
+            container: SessionContainer::with_max_collections(max_containers),
         }
     }
 
@@ -689,6 +689,9 @@ impl CosmosDriverRuntimeBuilder {
                 // overrides for the new group are ignored" at runtime — no
                 // compile-time guard catches it.
                 throttling_retry_options: Some(crate::options::ThrottlingRetryOptions::from_env()),
+                connectivity_retry_options: Some(
+                    crate::options::ConnectivityRetryOptions::from_env(),
+                ),
                 ..OperationOptions::from_env()
             }),
             // Kill-switch layer: only `overridable` fields (read from their
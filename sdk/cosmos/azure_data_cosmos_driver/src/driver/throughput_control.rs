@@ -0,0 +1,573 @@
+// Copyright (c) Microsoft Corporation. All rights reserved.
+// Licensed under the MIT License.
+
+//! Client-side enforcement of throughput control group targets.
+//!
+//! Each group with a client-side target gets a [`ThroughputController`], which
+//! hands out a per-cycle RU budget derived from the group's target. Requests
+//! wait for the next cycle once the budget is spent, and the actual request
+//! charge is deducted after each request completes, so a request that overdraws
+//! the budget delays the ones that follow it.
+//!
+//! Groups with global control split the target between clients in proportion to
+//! their recent demand, which each client publishes as an item in the control
+//! container. Control items are written and read by the driver itself, so this
+//! module deserializes their bodies.
+
+use crate::{
+    driver::CosmosDriver,
+    models::{ContainerReference, CosmosOperation, CosmosResponse, ItemReference, PartitionKey},
+    options::{OperationOptions, ThroughputControlGroupKey, ThroughputControlGroupOptions},
+};
+use futures::future::BoxFuture;
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+
+/// Length of one budget cycle.
+const CONTROL_CYCLE: Duration = Duration::from_secs(1);
+
+/// How long a container's provisioned throughput is used before it is read again.
+const CONTAINER_THROUGHPUT_REFRESH_INTERVAL: Duration = Duration::from_secs(60);
+
+/// Smallest load a client reports, so an idle client keeps a small share and can
+/// start sending without waiting for the next renewal.
+const MIN_LOAD_FACTOR: f64 = 1.0;
+
+/// A client's item in the control container of a globally controlled group.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct ControlItem {
+    pub(crate) id: String,
+    pub(crate) group_id: String,
+    pub(crate) load_factor: f64,
+    #[serde(rename = "ttl", default, skip_serializing_if = "Option::is_none")]
+    pub(crate) ttl: Option<u64>,
+}
+
+#[derive(Debug)]
+struct ControllerState {
+    cycle_start: Option<Instant>,
+    available: f64,
+    average_charge: f64,
+    container_throughput: Option<f64>,
+    container_throughput_checked: Option<Instant>,
+    share: f64,
+    demand: f64,
+    last_renewal: Option<Instant>,
+    renewing: bool,
+}
+
+/// Enforces the client-side target of one throughput control group.
+#[derive(Debug)]
+pub(crate) struct ThroughputController {
+    group: Arc<ThroughputControlGroupOptions>,
+    client_id: String,
+    state: Mutex<ControllerState>,
+}
+
+impl ThroughputController {
+    pub(crate) fn new(group: Arc<ThroughputControlGroupOptions>) -> Self {
+        Self {
+            group,
+            client_id: uuid::Uuid::new_v4().to_string(),
+            state: Mutex::new(ControllerState {
+                cycle_start: None,
+                available: 0.0,
+                average_charge: MIN_LOAD_FACTOR,
+                container_throughput: None,
+                container_throughput_checked: None,
+                share: 1.0,
+                demand: 0.0,
+                last_renewal: None,
+                renewing: false,
+            }),
+        }
+    }
+
+    pub(crate) fn group(&self) -> &ThroughputControlGroupOptions {
+        &self.group
+    }
+
+    /// Partition key value of the group's items in the control container.
+    pub(crate) fn control_group_id(&self) -> String {
+        let container = self.group.container();
+        format!(
+            "{}/{}/{}",
+            container.database_name(),
+            container.name(),
+            self.group.name()
+        )
+    }
+
+    /// Id of this client's item in the control container.
+    pub(crate) fn client_id(&self) -> &str {
+        &self.client_id
+    }
+
+    /// Returns this client's target in RU/s, or `None` if the group has no
+    /// client-side target or the container's throughput is not known yet.
+    fn target(&self, state: &ControllerState) -> Option<f64> {
+        let target = match self.group.target_throughput() {
+            Some(target) => f64::from(target),
+            None => {
+                let threshold = self.group.target_throughput_threshold()?;
+                state.container_throughput? * threshold.clamp(0.0, 1.0)
+            }
+        };
+        Some(target * state.share)
+    }
+
+    /// Returns `true` when the container's throughput must be read before the
+    /// target can be computed, and marks the read as started.
+    pub(crate) fn begin_container_throughput_refresh(&self, now: Instant) -> bool {
+        if self.group.target_throughput().is_some()
+            || self.group.target_throughput_threshold().is_none()
+        {
+            return false;
+        }
+        let mut state = self.state.lock().unwrap();
+        let stale = state.container_throughput_checked.is_none_or(|checked| {
+            now.duration_since(checked) >= CONTAINER_THROUGHPUT_REFRESH_INTERVAL
+        });
+        if stale {
+            state.container_throughput_checked = Some(now);
+        }
+        stale
+    }
+
+    /// Records the container's provisioned throughput in RU/s.
+    pub(crate) fn set_container_throughput(&self, request_units_per_second: f64) {
+        self.state.lock().unwrap().container_throughput = Some(request_units_per_second);
+    }
+
+    /// Returns the load to publish when this client's control item is due for
+    /// renewal, and marks the renewal as started. Returns `None` for groups
+    /// without global control and while a renewal is in progress.
+    pub(crate) fn begin_renewal(&self, now: Instant) -> Option<f64> {
+        let global = self.group.global_control()?;
+        let mut state = self.state.lock().unwrap();
+        let due = state
+            .last_renewal
+            .is_none_or(|last| now.duration_since(last) >= global.renew_interval());
+        if !due || state.renewing {
+            return None;
+        }
+        state.renewing = true;
+        let load = state.demand.max(MIN_LOAD_FACTOR);
+        state.demand = 0.0;
+        Some(load)
+    }
+
+    /// Completes a renewal started by [`begin_renewal`](Self::begin_renewal).
+    ///
+    /// `items` are the group's control items, or `None` if they couldn't be
+    /// read, in which case the current share is kept.
+    pub(crate) fn end_renewal(&self, now: Instant, own_load: f64, items: Option<&[ControlItem]>) {
+        let mut state = self.state.lock().unwrap();
+        state.renewing = false;
+        state.last_renewal = Some(now);
+        if let Some(items) = items {
+            state.share = compute_share(&self.client_id, own_load, items);
+        }
+    }
+
+    /// Reserves capacity for a request.
+    ///
+    /// Returns how long to wait before trying again if the budget for the
+    /// current cycle is spent.
+    pub(crate) fn try_acquire(&self, now: Instant) -> Result<(), Duration> {
+        let mut state = self.state.lock().unwrap();
+        let Some(target) = self.target(&state) else {
+            return Ok(());
+        };
+        let budget = target * CONTROL_CYCLE.as_secs_f64();
+        match state.cycle_start {
+            Some(start) if now.duration_since(start) < CONTROL_CYCLE => {}
+            Some(start) => {
+                // Carry any overdraft into the new cycle, but never bank more
+                // than one cycle's budget.
+                let cycles =
+                    (now.duration_since(start).as_secs_f64() / CONTROL_CYCLE.as_secs_f64()).floor();
+                state.available = (state.available + budget * cycles).min(budget);
+                state.cycle_start = Some(start + CONTROL_CYCLE.mul_f64(cycles));
+            }
+            None => {
+                state.available = budget;
+                state.cycle_start = Some(now);
+            }
+        }
+        if state.available > 0.0 {
+            return Ok(());
+        }
+        // Count the denied request towards this client's demand.
+        state.demand += state.average_charge;
+        let cycle_end = state.cycle_start.unwrap_or(now) + CONTROL_CYCLE;
+        Err(cycle_end
+            .saturating_duration_since(now)
+            .max(Duration::from_millis(1)))
+    }
+
+    /// Deducts the charge of a completed request from the budget.
+    pub(crate) fn record_charge(&self, request_charge: f64) {
+        let mut state = self.state.lock().unwrap();
+        state.available -= request_charge;
+        state.demand += request_charge;
+        state.average_charge = 0.8 * state.average_charge + 0.2 * request_charge;
+    }
+}
+
+/// Returns this client's share of the group target: its load over the total load
+/// of all clients with a live control item.
+fn compute_share(client_id: &str, own_load: f64, items: &[ControlItem]) -> f64 {
+    let others: f64 = items
+        .iter()
+        .filter(|item| item.id != client_id)
+        .map(|item| item.load_factor.max(MIN_LOAD_FACTOR))
+        .sum();
+    let own_load = own_load.max(MIN_LOAD_FACTOR);
+    own_load / (own_load + others)
+}
+
+/// The controllers of a driver's throughput control groups, created on first use.
+#[derive(Debug, Default)]
+pub(crate) struct ThroughputControllerStore {
+    controllers: Mutex<HashMap<ThroughputControlGroupKey, Arc<ThroughputController>>>,
+}
+
+impl ThroughputControllerStore {
+    /// Returns the controller for `group`, or `None` if it has no client-side target.
+    pub(crate) fn get_or_create(
+        &self,
+        group: &Arc<ThroughputControlGroupOptions>,
+    ) -> Option<Arc<ThroughputController>> {
+        if !group.has_client_side_target() {
+            return None;
+        }
+        let mut controllers = self.controllers.lock().unwrap();
+        Some(Arc::clone(controllers.entry(group.key()).or_insert_with(
+            || Arc::new(ThroughputController::new(Arc::clone(group))),
+        )))
+    }
+}
+
+/// Executes an operation issued by the controller itself.
+///
+/// The operation goes back through [`CosmosDriver::execute_operation`], so the
+/// future is boxed as `Send` to break the recursive type.
+fn execute(
+    driver: &CosmosDriver,
+    operation: CosmosOperation,
+) -> BoxFuture<'_, crate::error::Result<Option<CosmosResponse>>> {
+    Box::pin(driver.execute_operation(operation, OperationOptions::default()))
+}
+
+/// Waits until `controller` has capacity for another request.
+///
+/// Refreshes the container throughput and the client's share of a globally
+/// controlled group when they are due. Fails with
+/// [`THROUGHPUT_CONTROL_REQUEST_RATE_TOO_LARGE`](crate::error::CosmosStatus::THROUGHPUT_CONTROL_REQUEST_RATE_TOO_LARGE)
+/// if the request would have to wait longer than `max_wait`.
+pub(crate) async fn acquire(
+    driver: &CosmosDriver,
+    controller: &ThroughputController,
+    max_wait: Duration,
+) -> crate::error::Result<()> {
+    if controller.begin_container_throughput_refresh(Instant::now()) {
+        match read_container_throughput(driver, controller.group().container()).await {
+            Ok(Some(throughput)) => controller.set_container_throughput(throughput),
+            Ok(None) => tracing::warn!(
+                group = %controller.control_group_id(),
+                "no offer found for throughput control group container"
+            ),
+            Err(error) => tracing::warn!(
+                group = %controller.control_group_id(),
+                ?error,
+                "failed to read throughput control group container throughput"
+            ),
+        }
+    }
+
+    if let Some(load) = controller.begin_renewal(Instant::now()) {
+        let items = match renew_control_item(driver, controller, load).await {
+            Ok(items) => Some(items),
+            Err(error) => {
+                tracing::warn!(
+                    group = %controller.control_group_id(),
+                    ?error,
+                    "failed to renew throughput control item"
+                );
+                None
+            }
+        };
+        controller.end_renewal(Instant::now(), load, items.as_deref());
+    }
+
+    let mut waited = Duration::ZERO;
+    while let Err(wait) = controller.try_acquire(Instant::now()) {
+        if waited + wait > max_wait {
+            return Err(crate::error::CosmosError::builder()
+                .with_status(crate::error::CosmosStatus::THROUGHPUT_CONTROL_REQUEST_RATE_TOO_LARGE)
+                .with_message(format!(
+                    "throughput control group '{}' exceeded its target throughput",
+                    controller.control_group_id()
+                ))
+                .build());
+        }
+        if let Ok(duration) = azure_core::time::Duration::try_from(wait) {
+            azure_core::sleep(duration).await;
+        }
+        waited += wait;
+    }
+    Ok(())
+}
+
+/// Reads the provisioned throughput of `container` in RU/s, falling back to
+/// its database's shared throughput.
+///
+/// Autoscale offers report their maximum throughput.
+async fn read_container_throughput(
+    driver: &CosmosDriver,
+    container: &ContainerReference,
+) -> crate::error::Result<Option<f64>> {
+    for resource_id in [container.rid(), container.database_rid()] {
+        let body = serde_json::to_vec(&serde_json::json!({
+            "query": "SELECT * FROM c WHERE c.offerResourceId = @rid",
+            "parameters": [{ "name": "@rid", "value": resource_id }],
+        }))
+        .expect("offer query serializes");
+        let operation = CosmosOperation::query_offers(container.account().clone()).with_body(body);
+        let Some(response) = execute(driver, operation).await? else {
+            continue;
+        };
+        let offers: Vec<serde_json::Value> = response.into_body().into_items()?;
+        let throughput = offers.iter().find_map(|offer| {
+            offer
+                .pointer("/content/offerAutopilotSettings/maxThroughput")
+                .or_else(|| offer.pointer("/content/offerThroughput"))
+                .and_then(serde_json::Value::as_f64)
+        });
+        if throughput.is_some() {
+            return Ok(throughput);
+        }
+    }
+    Ok(None)
+}
+
+/// Publishes this client's load to the control container of a globally
+/// controlled group and returns the group's live control items.
+async fn renew_control_item(
+    driver: &CosmosDriver,
+    controller: &ThroughputController,
+    load: f64,
+) -> crate::error::Result<Vec<ControlItem>> {
+    let Some(global) = controller.group().global_control() else {
+        return Ok(Vec::new());
+    };
+    let control_container = global.control_container();
+    let group_id = controller.control_group_id();
+    let item = ControlItem {
+        id: controller.client_id().to_owned(),
+        group_id: group_id.clone(),
+        load_factor: load,
+        ttl: Some(global.expire_interval().as_secs().max(1)),
+    };
+    let body = serde_json::to_vec(&item).expect("control item serializes");
+    let upsert = CosmosOperation::upsert_item(ItemReference::from_name(
+        control_container,
+        PartitionKey::from(group_id.clone()),
+        item.id.clone(),
+    ))
+    .with_body(body);
+    execute(driver, upsert).await?;
+
+    let read =
+        CosmosOperation::read_all_items(control_container.clone(), PartitionKey::from(group_id));
+    match execute(driver, read).await? {
+        Some(response) => response.into_body().into_items(),
+        None => Ok(Vec::new()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        models::{AccountReference, PartitionKeyDefinition, SystemProperties},
+        options::GlobalThroughputControlOptions,
+    };
+    use url::Url;
+
+    fn test_container(name: &str) -> ContainerReference {
+        let partition_key: PartitionKeyDefinition =
+            serde_json::from_str(r#"{"paths":["/pk"]}"#).unwrap();
+        let account = AccountReference::with_master_key(
+            Url::parse("https://test.documents.azure.com:443/").unwrap(),
+            "test-key",
+        );
+        ContainerReference::new(
+            account,
+            "testdb",
+            "testdb_rid",
+            name.to_owned(),
+            format!("{name}_rid"),
+            &crate::models::ContainerProperties {
+                id: name.to_owned().into(),
+                partition_key,
+                system_properties: SystemProperties::default(),
+            },
+        )
+    }
+
+    fn controller(group: ThroughputControlGroupOptions) -> ThroughputController {
+        ThroughputController::new(Arc::new(group))
+    }
+
+    #[test]
+    fn denies_requests_once_cycle_budget_is_spent() {
+        let controller = controller(
+            ThroughputControlGroupOptions::new("bulk", test_container("c"), false)
+                .with_target_throughput(100),
+        );
+        let start = Instant::now();
+
+        assert!(controller.try_acquire(start).is_ok());
+        controller.record_charge(60.0);
+        assert!(controller.try_acquire(start).is_ok());
+        controller.record_charge(60.0);
+
+        let wait = controller
+            .try_acquire(start + Duration::from_millis(250))
+            .expect_err("budget spent");
+        assert_eq!(wait, Duration::from_millis(750));
+
+        // The 20 RU overdraft is carried into the next cycle.
+        assert!(controller.try_acquire(start + CONTROL_CYCLE).is_ok());
+        controller.record_charge(80.0);
+        assert!(controller.try_acquire(start + CONTROL_CYCLE).is_err());
+    }
+
+    #[test]
+    fn idle_cycles_do_not_bank_more_than_one_budget() {
+        let controller = controller(
+            ThroughputControlGroupOptions::new("bulk", test_container("c"), false)
+                .with_target_throughput(100),
+        );
+        let start = Instant::now();
+        assert!(controller.try_acquire(start).is_ok());
+
+        let later = start + CONTROL_CYCLE * 10;
+        assert!(controller.try_acquire(later).is_ok());
+        controller.record_charge(100.0);
+        assert!(controller.try_acquire(later).is_err());
+    }
+
+    #[test]
+    fn threshold_target_waits_for_container_throughput() {
+        let controller = controller(
+            ThroughputControlGroupOptions::new("bulk", test_container("c"), false)
+                .with_target_throughput_threshold(0.1),
+        );
+        let start = Instant::now();
+
+        assert!(controller.begin_container_throughput_refresh(start));
+        assert!(!controller.begin_container_throughput_refresh(start));
+
+        // Unknown container throughput doesn't throttle.
+        controller.record_charge(1000.0);
+        assert!(controller.try_acquire(start).is_ok());
+
+        // The first cycle starts once the target is known, with a full budget.
+        controller.set_container_throughput(1000.0);
+        assert!(controller.try_acquire(start).is_ok());
+        controller.record_charge(100.0);
+        assert!(controller.try_acquire(start).is_err());
+
+        assert!(controller
+            .begin_container_throughput_refresh(start + CONTAINER_THROUGHPUT_REFRESH_INTERVAL));
+    }
+
+    #[test]
+    fn global_control_splits_target_by_load() {
+        let group = ThroughputControlGroupOptions::new("bulk", test_container("c"), false)
+            .with_target_throughput(100)
+            .with_global_control(GlobalThroughputControlOptions::new(test_container(
+                "control",
+            )));
+        let controller = controller(group);
+        let start = Instant::now();
+
+        controller.record_charge(30.0);
+        let load = controller
+            .begin_renewal(start)
+            .expect("first renewal is due");
+        assert_eq!(load, 30.0);
+        assert!(
+            controller.begin_renewal(start).is_none(),
+            "renewal in progress"
+        );
+
+        let items = [
+            ControlItem {
+                id: controller.client_id().to_owned(),
+                group_id: controller.control_group_id(),
+                load_factor: load,
+                ttl: Some(30),
+            },
+            ControlItem {
+                id: "other".to_owned(),
+                group_id: controller.control_group_id(),
+                load_factor: 90.0,
+                ttl: Some(30),
+            },
+        ];
+        controller.end_renewal(start, load, Some(&items));
+        assert!(controller.begin_renewal(start).is_none(), "not due yet");
+
+        // 30 / (30 + 90) of 100 RU/s.
+        assert!(controller.try_acquire(start).is_ok());
+        controller.record_charge(25.0);
+        assert!(controller.try_acquire(start).is_err());
+
+        assert!(controller
+            .begin_renewal(start + Duration::from_secs(10))
+            .is_some());
+    }
+
+    #[test]
+    fn compute_share_floors_idle_loads() {
+        let item = |id: &str, load_factor| ControlItem {
+            id: id.to_owned(),
+            group_id: "g".to_owned(),
+            load_factor,
+            ttl: None,
+        };
+        assert_eq!(compute_share("me", 0.0, &[]), 1.0);
+        assert_eq!(compute_share("me", 0.0, &[item("other", 0.0)]), 0.5);
+        assert_eq!(
+            compute_share("me", 10.0, &[item("me", 1.0), item("other", 30.0)]),
+            0.25
+        );
+    }
+
+    #[test]
+    fn store_only_creates_controllers_for_client_side_targets() {
+        let store = ThroughputControllerStore::default();
+        let server_side = Arc::new(
+            ThroughputControlGroupOptions::new("bucket", test_container("c"), false)
+                .with_throughput_bucket(1),
+        );
+        assert!(store.get_or_create(&server_side).is_none());
+
+        let client_side = Arc::new(
+            ThroughputControlGroupOptions::new("bulk", test_container("c"), false)
+                .with_target_throughput(100),
+        );
+        let first = store.get_or_create(&client_side).unwrap();
+        let second = store.get_or_create(&client_side).unwrap();
+        assert!(Arc::ptr_eq(&first, &second));
+    }
+}
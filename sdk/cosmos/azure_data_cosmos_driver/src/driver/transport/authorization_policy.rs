@@ -139,6 +139,27 @@ pub(crate) async fn generate_authorization(
             s.push_str(&signature);
             s
         }
+        Credential::ResourceTokens(tokens) => {
+            let link = auth_ctx.resource_link.as_str();
+            let token = tokens.token_for(link).ok_or_else(|| {
+                crate::error::CosmosError::builder()
+                    .with_status(crate::error::CosmosStatus::CLIENT_GENERATED_401)
+                    .with_message(format!(
+                        "no resource token grants access to resource link '{link}'"
+                    ))
+                    .build()
+            })?;
+            // Tokens copied from a permission's `_token` are raw; tokens that
+            // were already URL-encoded are sent as-is.
+            let token = token.secret();
+            if token
+                .get(..7)
+                .is_some_and(|p| p.eq_ignore_ascii_case("type%3d"))
+            {
+                return Ok(token.to_owned());
+            }
+            token.to_owned()
+        }
     };
 
     Ok(url_encode(&token))
@@ -186,6 +207,49 @@ fn url_encode(s: &str) -> String {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::models::ResourceTokens;
+
+    #[tokio::test]
+    async fn resource_token_selected_by_resource_link() {
+        let credential = Credential::ResourceTokens(
+            ResourceTokens::new()
+                .with_token("dbs/db1/colls/c1", "type=resource&ver=1.0&sig=abc/1+=")
+                .with_token(
+                    "dbs/db1/colls/c2",
+                    "type%3Dresource%26ver%3D1.0%26sig%3Dxyz",
+                ),
+        );
+        let date_string = "Mon, 01 Jan 1900 01:00:00 GMT";
+
+        let c1 = AuthorizationContext::new(
+            Method::Get,
+            ResourceType::Document,
+            "dbs/db1/colls/c1/docs/item1",
+        );
+        assert_eq!(
+            generate_authorization(&credential, &c1, date_string)
+                .await
+                .unwrap(),
+            "type%3Dresource%26ver%3D1.0%26sig%3Dabc%2F1%2B%3D"
+        );
+
+        let c2 = AuthorizationContext::new(Method::Get, ResourceType::Document, "dbs/db1/colls/c2");
+        assert_eq!(
+            generate_authorization(&credential, &c2, date_string)
+                .await
+                .unwrap(),
+            "type%3Dresource%26ver%3D1.0%26sig%3Dxyz"
+        );
+
+        let other = AuthorizationContext::new(Method::Get, ResourceType::Document, "dbs/db2");
+        let err = generate_authorization(&credential, &other, date_string)
+            .await
+            .unwrap_err();
+        assert_eq!(
+            err.status(),
+            crate::error::CosmosStatus::CLIENT_GENERATED_401
+        );
+    }
 
     #[test]
     fn build_string_to_sign_format() {
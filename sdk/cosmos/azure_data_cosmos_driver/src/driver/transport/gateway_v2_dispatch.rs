@@ -49,6 +49,10 @@ const X_MS_SESSION_TOKEN: HeaderName = HeaderName::from_static(request_header_na
 const X_MS_MAX_ITEM_COUNT: HeaderName =
     HeaderName::from_static(request_header_names::MAX_ITEM_COUNT);
 const IF_MATCH: HeaderName = HeaderName::from_static(request_header_names::IF_MATCH);
+const X_MS_PRE_TRIGGER_INCLUDE: HeaderName =
+    HeaderName::from_static(request_header_names::PRE_TRIGGER_INCLUDE);
+const X_MS_POST_TRIGGER_INCLUDE: HeaderName =
+    HeaderName::from_static(request_header_names::POST_TRIGGER_INCLUDE);
 const IF_NONE_MATCH: HeaderName = HeaderName::from_static(request_header_names::IF_NONE_MATCH);
 const X_MS_VERSION: HeaderName = HeaderName::from_static(request_header_names::VERSION);
 const CACHE_CONTROL: HeaderName = HeaderName::from_static(request_header_names::CACHE_CONTROL);
@@ -284,6 +288,23 @@ pub(crate) fn wrap_request_for_gateway_v2(
         metadata.push(Token::session_token(session_token.to_owned()));
     }
 
+    // Pre-/post-trigger includes (0x0009/0x000A) name the triggers to run for an
+    // item write, forwarded verbatim from their comma-separated HTTP headers.
+    if let Some(triggers) = request
+        .headers
+        .get_optional_str(&X_MS_PRE_TRIGGER_INCLUDE)
+        .filter(|s| !s.is_empty())
+    {
+        metadata.push(Token::pre_trigger_include(triggers.to_owned()));
+    }
+    if let Some(triggers) = request
+        .headers
+        .get_optional_str(&X_MS_POST_TRIGGER_INCLUDE)
+        .filter(|s| !s.is_empty())
+    {
+        metadata.push(Token::post_trigger_include(triggers.to_owned()));
+    }
+
     // Page size (0x0004) carries the requested max item count for query and
     // read-feed pages, parsed from the `x-ms-max-item-count` header. A negative
     // (unbounded) request is encoded as 0xFFFFFFFF. Empty or unparseable values
@@ -1252,6 +1273,42 @@ mod tests {
         );
     }
 
+    #[test]
+    fn wrap_emits_trigger_includes_when_http_headers_present() {
+        let mut request = signed_request(None);
+        request.headers.insert(
+            HeaderName::from_static(request_header_names::PRE_TRIGGER_INCLUDE),
+            "validate,stamp",
+        );
+        request.headers.insert(
+            HeaderName::from_static(request_header_names::POST_TRIGGER_INCLUDE),
+            "audit",
+        );
+        let auth_context = AuthorizationContext::new(
+            Method::Post,
+            ResourceType::Document,
+            "dbs/db1/colls/coll1/docs",
+        );
+
+        let wrapped = wrap_request_for_gateway_v2(
+            &request,
+            &wrap_inputs(&auth_context, OperationType::Create, None),
+        )
+        .unwrap();
+        let parsed = parse_wrapped_request(&wrapped, 0);
+
+        assert_eq!(
+            parsed.tokens[&0x0009],
+            ParsedTokenValue::String("validate,stamp".into()),
+            "PreTriggerInclude (0x0009) must be emitted from the HTTP header"
+        );
+        assert_eq!(
+            parsed.tokens[&0x000A],
+            ParsedTokenValue::String("audit".into()),
+            "PostTriggerInclude (0x000A) must be emitted from the HTTP header"
+        );
+    }
+
     #[test]
     fn wrap_omits_session_token_when_http_header_absent_or_empty() {
         // No header => no token. An empty value is also skipped so the proxy
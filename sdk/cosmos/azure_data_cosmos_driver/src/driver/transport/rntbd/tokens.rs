@@ -324,6 +324,24 @@ impl Token {
         Self::new(RntbdRequestToken::Match, TokenValue::String(value))
     }
 
+    /// Pre-trigger include (id `0x0009`, `String`) naming the pre-triggers to run
+    /// for an item write. Populated from the `x-ms-documentdb-pre-trigger-include` header.
+    pub(crate) fn pre_trigger_include(value: String) -> Self {
+        Self::new(
+            RntbdRequestToken::PreTriggerInclude,
+            TokenValue::String(value),
+        )
+    }
+
+    /// Post-trigger include (id `0x000A`, `String`) naming the post-triggers to run
+    /// for an item write. Populated from the `x-ms-documentdb-post-trigger-include` header.
+    pub(crate) fn post_trigger_include(value: String) -> Self {
+        Self::new(
+            RntbdRequestToken::PostTriggerInclude,
+            TokenValue::String(value),
+        )
+    }
+
     pub(crate) fn consistency_level(value: DefaultConsistencyLevel) -> Self {
         Self::new(
             RntbdRequestToken::ConsistencyLevel,
@@ -547,6 +565,8 @@ pub(crate) enum RntbdRequestToken {
     SessionToken,
     ContinuationToken,
     Match,
+    PreTriggerInclude,
+    PostTriggerInclude,
     ConsistencyLevel,
     DatabaseName,
     CollectionName,
@@ -580,6 +600,8 @@ impl TryFrom<u16> for RntbdRequestToken {
             0x0005 => Ok(Self::SessionToken),
             0x0006 => Ok(Self::ContinuationToken),
             0x0008 => Ok(Self::Match),
+            0x0009 => Ok(Self::PreTriggerInclude),
+            0x000A => Ok(Self::PostTriggerInclude),
             0x0010 => Ok(Self::ConsistencyLevel),
             0x0015 => Ok(Self::DatabaseName),
             0x0016 => Ok(Self::CollectionName),
@@ -614,6 +636,8 @@ impl From<RntbdRequestToken> for u16 {
             RntbdRequestToken::SessionToken => 0x0005,
             RntbdRequestToken::ContinuationToken => 0x0006,
             RntbdRequestToken::Match => 0x0008,
+            RntbdRequestToken::PreTriggerInclude => 0x0009,
+            RntbdRequestToken::PostTriggerInclude => 0x000A,
             RntbdRequestToken::ConsistencyLevel => 0x0010,
             RntbdRequestToken::DatabaseName => 0x0015,
             RntbdRequestToken::CollectionName => 0x0016,
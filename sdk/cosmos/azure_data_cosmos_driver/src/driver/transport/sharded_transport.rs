@@ -26,6 +26,7 @@ use tracing::trace;
 use url::Url;
 
 use crate::diagnostics::TransportShardDiagnostics;
+use crate::models::SubStatusCode;
 use crate::options::ConnectionPoolOptions;
 
 #[cfg(feature = "tokio")]
@@ -110,6 +111,10 @@ impl ShardedHttpTransport {
         guard.finish(&result);
         let shard_diagnostics = Some(shard.transport_diagnostics());
 
+        if pool.record_connection_outcome(&result) {
+            self.refresh_endpoint(endpoint_key, &pool);
+        }
+
         TransportDispatch {
             result,
             shard_id: Some(shard_id),
@@ -152,6 +157,32 @@ impl ShardedHttpTransport {
             .map(|shard| shard.id)
     }
 
+    /// Drops the endpoint's shard pool so the next request builds fresh
+    /// clients. New clients open new connections, which re-resolves the host
+    /// name and picks up DNS changes (e.g. a regional failover).
+    ///
+    /// In-flight requests keep the old pool alive until they complete. The
+    /// pool is only removed if it is still the current one, so concurrent
+    /// refreshes for the same endpoint don't discard a pool that was already
+    /// rebuilt.
+    fn refresh_endpoint(&self, endpoint_key: &EndpointKey, pool: &Arc<EndpointShardPool>) {
+        // Safe to ignore poisoning: the critical section only performs
+        // HashMap::get/remove + Arc::ptr_eq which cannot panic.
+        let mut pools = self.pools.lock().unwrap_or_else(|e| e.into_inner());
+        if pools
+            .get(endpoint_key)
+            .is_some_and(|current| Arc::ptr_eq(current, pool))
+        {
+            pools.remove(endpoint_key);
+            tracing::debug!(
+                endpoint = %endpoint_key.0,
+                threshold = self.connection_pool.endpoint_refresh_failure_threshold(),
+                "Flushing endpoint connections after consecutive connection failures; \
+                 the host name is re-resolved on the next request"
+            );
+        }
+    }
+
     fn get_or_create_pool(
         &self,
         endpoint_key: EndpointKey,
@@ -268,6 +299,9 @@ struct EndpointShardPool {
     /// acquire this lock — they use `shards.load()` directly.
     write_lock: Mutex<()>,
     next_shard_id: AtomicU64,
+    /// Connection-layer failures since the last request to this endpoint
+    /// that reached the service, across all shards.
+    consecutive_connection_failures: AtomicU32,
 }
 
 impl EndpointShardPool {
@@ -285,6 +319,7 @@ impl EndpointShardPool {
             shards: ArcSwap::from_pointee(Vec::new()),
             write_lock: Mutex::new(()),
             next_shard_id: AtomicU64::new(1),
+            consecutive_connection_failures: AtomicU32::new(0),
         };
 
         // Best-effort eager shard creation. If a transient TLS/DNS issue
@@ -359,6 +394,28 @@ impl EndpointShardPool {
             })
     }
 
+    /// Tracks connection-layer failures for the endpoint and returns `true`
+    /// exactly once, when the consecutive count reaches the refresh threshold.
+    ///
+    /// Any result that is not a connection-layer failure — including service
+    /// error responses, which prove the connection works — resets the count.
+    fn record_connection_outcome(&self, result: &Result<HttpResponse, TransportError>) -> bool {
+        match result {
+            Err(error) if is_connection_failure(&error.error) => {
+                let failures = self
+                    .consecutive_connection_failures
+                    .fetch_add(1, Ordering::Relaxed)
+                    + 1;
+                failures == self.connection_pool.endpoint_refresh_failure_threshold()
+            }
+            _ => {
+                self.consecutive_connection_failures
+                    .store(0, Ordering::Relaxed);
+                false
+            }
+        }
+    }
+
     fn can_select_different_shard(&self, excluded_shard_id: u64) -> bool {
         let shards = self.shards.load();
         shards
@@ -552,6 +609,18 @@ impl fmt::Debug for EndpointShardPool {
     }
 }
 
+/// Returns `true` for failures at the connection layer — connect, DNS, or
+/// socket I/O — where a stale address or dead connection is a likely cause.
+fn is_connection_failure(error: &crate::error::CosmosError) -> bool {
+    !error.is_from_wire()
+        && matches!(
+            error.status().sub_status(),
+            Some(SubStatusCode::TRANSPORT_CONNECTION_FAILED)
+                | Some(SubStatusCode::TRANSPORT_DNS_FAILED)
+                | Some(SubStatusCode::TRANSPORT_IO_FAILED)
+        )
+}
+
 /// Sentinel value for `AtomicU64` timestamp fields meaning "no value" (like `None`).
 const TIMESTAMP_NONE: u64 = u64::MAX;
 
@@ -1252,4 +1321,94 @@ mod tests {
             "failed shard {second_id} should have been evicted by background sweep, remaining: {shard_ids:?}"
         );
     }
+
+    #[derive(Debug, Default)]
+    struct CountingFactory {
+        builds: std::sync::atomic::AtomicUsize,
+    }
+
+    impl HttpClientFactory for CountingFactory {
+        fn build(
+            &self,
+            _connection_pool: &ConnectionPoolOptions,
+            _config: HttpClientConfig,
+        ) -> crate::error::Result<Arc<dyn TransportClient>> {
+            self.builds.fetch_add(1, Ordering::Relaxed);
+            Ok(Arc::new(NoopTransportClient))
+        }
+    }
+
+    fn connection_failed() -> Result<HttpResponse, TransportError> {
+        Err(TransportError::new(
+            crate::error::CosmosError::builder()
+                .with_status(crate::error::CosmosStatus::TRANSPORT_CONNECTION_FAILED)
+                .with_message("connection refused")
+                .build(),
+            crate::diagnostics::RequestSentStatus::NotSent,
+        ))
+    }
+
+    fn refresh_test_transport() -> (ShardedHttpTransport, Arc<CountingFactory>) {
+        let pool_opts = ConnectionPoolOptions::builder()
+            .with_min_http2_connections_per_endpoint(1)
+            .with_endpoint_refresh_failure_threshold(2)
+            .build()
+            .unwrap();
+        let config = HttpClientConfig::dataplane_gateway(
+            &pool_opts,
+            crate::diagnostics::TransportHttpVersion::Http2,
+        );
+        let factory = Arc::new(CountingFactory::default());
+        let transport = ShardedHttpTransport::new(pool_opts, factory.clone(), config);
+        (transport, factory)
+    }
+
+    #[test]
+    fn connection_failures_at_threshold_signal_refresh_once() {
+        let (transport, _) = refresh_test_transport();
+        let endpoint_key = EndpointKey(Arc::from("refresh-test.documents.azure.com:443"));
+        let pool = transport.get_or_create_pool(endpoint_key).unwrap();
+
+        assert!(!pool.record_connection_outcome(&connection_failed()));
+        assert!(pool.record_connection_outcome(&connection_failed()));
+        // Past the threshold, the refresh is not signalled again.
+        assert!(!pool.record_connection_outcome(&connection_failed()));
+    }
+
+    #[test]
+    fn non_connection_outcomes_reset_refresh_count() {
+        let (transport, _) = refresh_test_transport();
+        let endpoint_key = EndpointKey(Arc::from("refresh-test.documents.azure.com:443"));
+        let pool = transport.get_or_create_pool(endpoint_key).unwrap();
+
+        assert!(!pool.record_connection_outcome(&connection_failed()));
+        assert!(!pool.record_connection_outcome(&Err(synthetic_transport_error())));
+        assert!(!pool.record_connection_outcome(&connection_failed()));
+        assert!(!pool.record_connection_outcome(&Ok(HttpResponse {
+            status: 503,
+            headers: azure_core::http::headers::Headers::new(),
+            body: Vec::new(),
+        })));
+        assert!(!pool.record_connection_outcome(&connection_failed()));
+        assert!(pool.record_connection_outcome(&connection_failed()));
+    }
+
+    #[test]
+    fn refresh_endpoint_rebuilds_clients_on_next_request() {
+        let (transport, factory) = refresh_test_transport();
+        let endpoint_key = EndpointKey(Arc::from("refresh-test.documents.azure.com:443"));
+        let stale = transport.get_or_create_pool(endpoint_key.clone()).unwrap();
+        let builds_before = factory.builds.load(Ordering::Relaxed);
+
+        transport.refresh_endpoint(&endpoint_key, &stale);
+
+        let fresh = transport.get_or_create_pool(endpoint_key.clone()).unwrap();
+        assert!(!Arc::ptr_eq(&stale, &fresh));
+        assert!(factory.builds.load(Ordering::Relaxed) > builds_before);
+
+        // A late refresh for the stale pool must not discard the rebuilt one.
+        transport.refresh_endpoint(&endpoint_key, &stale);
+        let current = transport.get_or_create_pool(endpoint_key).unwrap();
+        assert!(Arc::ptr_eq(&fresh, &current));
+    }
 }
@@ -51,13 +51,18 @@ const DEADLINE_RETRY_SAFETY_MARGIN: Duration = Duration::from_millis(100);
 // This is intentionally lower than public option validation to avoid
 // collapsing near-deadline retries to an entire second.
 const MIN_REMAINING_REQUEST_TIMEOUT: Duration = Duration::from_millis(1);
-const MAX_LOCAL_CONNECTIVITY_RETRIES: u32 = 1;
 
 fn deadline_capped_delay(requested_delay: Duration, remaining: Duration) -> Duration {
     let budget_for_delay = remaining.saturating_sub(DEADLINE_RETRY_SAFETY_MARGIN);
     requested_delay.min(budget_for_delay)
 }
 
+/// Backoff before a local connectivity retry: `backoff` doubled for every
+/// retry already attempted.
+fn connectivity_retry_delay(backoff: Duration, retry_count: u32) -> Duration {
+    backoff.saturating_mul(2_u32.saturating_pow(retry_count))
+}
+
 fn remaining_request_timeout(deadline: Option<Instant>) -> Option<Duration> {
     deadline.map(|deadline| {
         deadline
@@ -200,6 +205,18 @@ pub(crate) struct TransportPipelineContext<'a> {
     /// (defaulting to 30 seconds). Same per-invocation scope note as
     /// [`max_throttle_attempts`](Self::max_throttle_attempts).
     pub max_throttle_wait_time: Duration,
+    /// Maximum number of local retries, on a different shard, after a
+    /// connectivity failure.
+    ///
+    /// Resolved by the operation pipeline from the effective
+    /// [`ConnectivityRetryOptionsView::max_retry_count`](crate::options::ConnectivityRetryOptionsView::max_retry_count)
+    /// (defaulting to `1`). `0` disables local connectivity retries. Same
+    /// per-invocation scope note as
+    /// [`max_throttle_attempts`](Self::max_throttle_attempts).
+    pub max_connectivity_retries: u32,
+    /// Delay before the first local connectivity retry; doubled for each
+    /// subsequent retry and capped by the request deadline.
+    pub connectivity_retry_backoff: Duration,
 }
 
 /// Executes a single transport attempt.
@@ -384,7 +401,7 @@ pub(crate) async fn execute_transport_pipeline(
         );
 
         if result.shard_id.is_some_and(|failed_shard_id| {
-            local_connectivity_retry_count < MAX_LOCAL_CONNECTIVITY_RETRIES
+            local_connectivity_retry_count < ctx.max_connectivity_retries
                 && should_retry_connectivity_failure(&result.result, ctx.allow_sent_transport_retry)
                 && ctx
                     .transport
@@ -393,8 +410,27 @@ pub(crate) async fn execute_transport_pipeline(
             if let Some(failed_transport_shard) = failed_transport_shard(&result) {
                 prior_failed_transport_shards.push(failed_transport_shard);
             }
+            let delay = connectivity_retry_delay(
+                ctx.connectivity_retry_backoff,
+                local_connectivity_retry_count,
+            );
             local_connectivity_retry_count += 1;
             excluded_shard_id = result.shard_id;
+
+            if !delay.is_zero() {
+                let effective_delay = match request.deadline {
+                    Some(deadline) => deadline_capped_delay(
+                        delay,
+                        deadline.saturating_duration_since(Instant::now()),
+                    ),
+                    None => delay,
+                };
+                azure_core::sleep(
+                    azure_core::time::Duration::try_from(effective_delay)
+                        .unwrap_or(azure_core::time::Duration::ZERO),
+                )
+                .await;
+            }
             continue;
         }
 
@@ -922,6 +958,7 @@ mod tests {
     use crate::{
         diagnostics::{DiagnosticsContextBuilder, RequestSentStatus},
         driver::{
+            pipeline::components::DEFAULT_MAX_CONNECTIVITY_RETRIES,
             routing::CosmosEndpoint,
             transport::{
                 adaptive_transport::AdaptiveTransport,
@@ -1305,6 +1342,8 @@ mod tests {
                 collection_rid: None,
                 max_throttle_attempts: 9,
                 max_throttle_wait_time: Duration::from_secs(30),
+                max_connectivity_retries: DEFAULT_MAX_CONNECTIVITY_RETRIES,
+                connectivity_retry_backoff: Duration::ZERO,
             },
             &mut diagnostics,
         )
@@ -1380,6 +1419,8 @@ mod tests {
                 collection_rid: None,
                 max_throttle_attempts: 0,
                 max_throttle_wait_time: Duration::from_secs(30),
+                max_connectivity_retries: DEFAULT_MAX_CONNECTIVITY_RETRIES,
+                connectivity_retry_backoff: Duration::ZERO,
             },
             &mut diagnostics,
         )
@@ -1443,6 +1484,8 @@ mod tests {
                 collection_rid: None,
                 max_throttle_attempts: 9,
                 max_throttle_wait_time: Duration::from_millis(1),
+                max_connectivity_retries: DEFAULT_MAX_CONNECTIVITY_RETRIES,
+                connectivity_retry_backoff: Duration::ZERO,
             },
             &mut diagnostics,
         )
@@ -1515,6 +1558,8 @@ mod tests {
                     // Generous budget so the cumulative-wait cap is never the
                     // limiter for these small attempt counts.
                     max_throttle_wait_time: Duration::from_secs(300),
+                    max_connectivity_retries: DEFAULT_MAX_CONNECTIVITY_RETRIES,
+                    connectivity_retry_backoff: Duration::ZERO,
                 },
                 &mut diagnostics,
             )
@@ -1789,6 +1834,8 @@ mod tests {
                 collection_rid: None,
                 max_throttle_attempts: 9,
                 max_throttle_wait_time: Duration::from_secs(30),
+                max_connectivity_retries: DEFAULT_MAX_CONNECTIVITY_RETRIES,
+                connectivity_retry_backoff: Duration::ZERO,
             },
             &mut diagnostics,
         )
@@ -1813,6 +1860,93 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn execute_transport_pipeline_honors_connectivity_retry_options() {
+        let credential = Credential::from(azure_core::credentials::Secret::new("dGVzdA=="));
+        let user_agent = azure_core::http::headers::HeaderValue::from_static("test-agent");
+        let context = |transport, max_connectivity_retries, connectivity_retry_backoff| {
+            TransportPipelineContext {
+                transport,
+                allow_sent_transport_retry: false,
+                credential: &credential,
+                user_agent: &user_agent,
+                pipeline_type: PipelineType::DataPlane,
+                transport_security: TransportSecurity::Secure,
+                endpoint_key: test_endpoint_key(),
+                account_name: None,
+                collection_rid: None,
+                max_throttle_attempts: 9,
+                max_throttle_wait_time: Duration::from_secs(30),
+                max_connectivity_retries,
+                connectivity_retry_backoff,
+            }
+        };
+
+        // A zero retry count surfaces the first connectivity failure.
+        let client = scripted_transport(
+            CosmosStatus::TRANSPORT_DNS_FAILED,
+            "first shard failed",
+            CosmosStatus::TRANSPORT_DNS_FAILED,
+            "second shard failed",
+        );
+        let mut diagnostics = DiagnosticsContextBuilder::new(
+            ActivityId::from_string("transport-retry-disabled".to_owned()),
+            Arc::new(DiagnosticsOptions::default()),
+        );
+        let result = execute_transport_pipeline(
+            test_request(Some(Instant::now() + Duration::from_secs(2))),
+            &context(&client, 0, Duration::ZERO),
+            &mut diagnostics,
+        )
+        .await;
+        match result.outcome {
+            TransportOutcome::TransportError { error, .. } => {
+                assert!(error.to_string().contains("first shard failed"));
+            }
+            other => panic!("expected transport error, got {other:?}"),
+        }
+        assert_eq!(diagnostics.complete().requests().len(), 1);
+
+        // The backoff delays the retry.
+        let client = scripted_transport(
+            CosmosStatus::TRANSPORT_DNS_FAILED,
+            "first shard failed",
+            CosmosStatus::TRANSPORT_DNS_FAILED,
+            "second shard failed",
+        );
+        let mut diagnostics = DiagnosticsContextBuilder::new(
+            ActivityId::from_string("transport-retry-backoff".to_owned()),
+            Arc::new(DiagnosticsOptions::default()),
+        );
+        let started = Instant::now();
+        let result = execute_transport_pipeline(
+            test_request(Some(Instant::now() + Duration::from_secs(2))),
+            &context(&client, 1, Duration::from_millis(50)),
+            &mut diagnostics,
+        )
+        .await;
+        assert!(started.elapsed() >= Duration::from_millis(50));
+        match result.outcome {
+            TransportOutcome::TransportError { error, .. } => {
+                assert!(error.to_string().contains("second shard failed"));
+            }
+            other => panic!("expected transport error, got {other:?}"),
+        }
+        assert_eq!(diagnostics.complete().requests().len(), 2);
+    }
+
+    #[test]
+    fn connectivity_retry_delay_doubles() {
+        let backoff = Duration::from_millis(100);
+        assert_eq!(connectivity_retry_delay(backoff, 0), backoff);
+        assert_eq!(
+            connectivity_retry_delay(backoff, 2),
+            Duration::from_millis(400)
+        );
+        assert_eq!(connectivity_retry_delay(Duration::ZERO, 5), Duration::ZERO);
+        assert_eq!(connectivity_retry_delay(Duration::MAX, 1), Duration::MAX);
+    }
+
     #[tokio::test]
     async fn execute_transport_pipeline_only_retries_unknown_connectivity_error_when_allowed() {
         let credential = Credential::from(azure_core::credentials::Secret::new("dGVzdA=="));
@@ -1842,6 +1976,8 @@ mod tests {
                 collection_rid: None,
                 max_throttle_attempts: 9,
                 max_throttle_wait_time: Duration::from_secs(30),
+                max_connectivity_retries: DEFAULT_MAX_CONNECTIVITY_RETRIES,
+                connectivity_retry_backoff: Duration::ZERO,
             },
             &mut diagnostics,
         )
@@ -1883,6 +2019,8 @@ mod tests {
                 collection_rid: None,
                 max_throttle_attempts: 9,
                 max_throttle_wait_time: Duration::from_secs(30),
+                max_connectivity_retries: DEFAULT_MAX_CONNECTIVITY_RETRIES,
+                connectivity_retry_backoff: Duration::ZERO,
             },
             &mut diagnostics,
         )
@@ -1922,6 +2060,8 @@ mod tests {
                 collection_rid: None,
                 max_throttle_attempts: 9,
                 max_throttle_wait_time: Duration::from_secs(30),
+                max_connectivity_retries: DEFAULT_MAX_CONNECTIVITY_RETRIES,
+                connectivity_retry_backoff: Duration::ZERO,
             },
             &mut diagnostics,
         )
@@ -2035,6 +2175,8 @@ mod tests {
             collection_rid: None,
             max_throttle_attempts: 9,
             max_throttle_wait_time: Duration::from_secs(30),
+            max_connectivity_retries: DEFAULT_MAX_CONNECTIVITY_RETRIES,
+            connectivity_retry_backoff: Duration::ZERO,
         }
     }
 
@@ -493,6 +493,8 @@ impl SubStatusCode {
             20115 => Some("ClientQueryPlanComplexProjectionUnsupported"),
             20116 => Some("ClientOpaqueTokenInvalidForCrossPartitionQuery"),
             20117 => Some("ClientContinuationTokenNonQueryOperation"),
+            20118 => Some("ClientPartitionKeyNotExtractable"),
+            20119 => Some("ClientDistinctContinuationTooLarge"),
             20150 => Some("ClientDuplicateFaultInjectionRuleId"),
             20151 => Some("ClientThroughputControlGroupRegistrationFailed"),
             20152 => Some("ClientThroughputControlGroupNotRegistered"),
@@ -1321,6 +1323,16 @@ impl SubStatusCode {
     /// operations.
     pub const CLIENT_CONTINUATION_TOKEN_NON_QUERY_OPERATION: SubStatusCode = SubStatusCode(20117);
 
+    /// The partition key could not be extracted from an item body because
+    /// the body is not a JSON object or a partition key path resolves to an
+    /// object or array (20118).
+    pub const CLIENT_PARTITION_KEY_NOT_EXTRACTABLE: SubStatusCode = SubStatusCode(20118);
+
+    /// A continuation token was requested for an unordered cross-partition
+    /// `SELECT DISTINCT` query that has returned too many distinct results
+    /// to record in the token (20119).
+    pub const CLIENT_DISTINCT_CONTINUATION_TOO_LARGE: SubStatusCode = SubStatusCode(20119);
+
     // ----- 20150-20199: SDK configuration / setup errors -----
 
     /// Two fault-injection rules registered with the same id (20150).
@@ -2006,6 +2018,12 @@ impl CosmosStatus {
         sub_status: Some(SubStatusCode::RU_BUDGET_EXCEEDED),
     };
 
+    /// Client-side throughput control budget exhausted (HTTP 429, sub-status 10003).
+    pub const THROUGHPUT_CONTROL_REQUEST_RATE_TOO_LARGE: CosmosStatus = CosmosStatus {
+        status_code: StatusCode::TooManyRequests,
+        sub_status: Some(SubStatusCode::THROUGHPUT_CONTROL_REQUEST_RATE_TOO_LARGE),
+    };
+
     // ----- Client SDK–synthesized statuses (20100-20349) -----
     //
     // Convenience constants pairing each `CLIENT_*` `SubStatusCode` with
@@ -2132,6 +2150,20 @@ impl CosmosStatus {
         sub_status: Some(SubStatusCode::CLIENT_CONTINUATION_TOKEN_NON_QUERY_OPERATION),
     };
 
+    /// 400 / 20118 — partition key could not be extracted from an item
+    /// body.
+    pub const CLIENT_PARTITION_KEY_NOT_EXTRACTABLE: CosmosStatus = CosmosStatus {
+        status_code: StatusCode::BadRequest,
+        sub_status: Some(SubStatusCode::CLIENT_PARTITION_KEY_NOT_EXTRACTABLE),
+    };
+
+    /// 400 / 20119 — unordered `SELECT DISTINCT` query returned too many
+    /// distinct results to resume from a continuation token.
+    pub const CLIENT_DISTINCT_CONTINUATION_TOO_LARGE: CosmosStatus = CosmosStatus {
+        status_code: StatusCode::BadRequest,
+        sub_status: Some(SubStatusCode::CLIENT_DISTINCT_CONTINUATION_TOO_LARGE),
+    };
+
     // Configuration / setup (HTTP 400, sub-status 20150-20199)
 
     /// 400 / 20150 — duplicate fault-injection rule id.
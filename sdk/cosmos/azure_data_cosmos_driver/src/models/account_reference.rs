@@ -131,14 +131,17 @@ impl<'de> serde::Deserialize<'de> for AccountEndpoint {
 
 /// Authentication options for connecting to a Cosmos DB account.
 ///
-/// Either key-based authentication using a master key, or token-based
-/// authentication using an Azure credential (e.g., managed identity, service principal).
+/// Either key-based authentication using a master key, token-based
+/// authentication using an Azure credential (e.g., managed identity, service principal),
+/// or scoped access using resource tokens.
 #[derive(Clone)]
 pub enum Credential {
     /// Key-based authentication using the account's primary or secondary master key.
     MasterKey(Secret),
     /// Token-based authentication using an Azure credential.
     TokenCredential(Arc<dyn TokenCredential>),
+    /// Scoped authentication using resource tokens minted from permissions.
+    ResourceTokens(ResourceTokens),
 }
 
 impl std::fmt::Debug for Credential {
@@ -146,10 +149,115 @@ impl std::fmt::Debug for Credential {
         match self {
             Self::MasterKey(_) => f.debug_tuple("MasterKey").field(&"***").finish(),
             Self::TokenCredential(_) => f.debug_tuple("TokenCredential").field(&"...").finish(),
+            Self::ResourceTokens(tokens) => f.debug_tuple("ResourceTokens").field(tokens).finish(),
         }
     }
 }
 
+impl From<ResourceTokens> for Credential {
+    fn from(tokens: ResourceTokens) -> Self {
+        Self::ResourceTokens(tokens)
+    }
+}
+
+/// A set of resource tokens, each granting access to one resource and its children.
+///
+/// Resource tokens are short-lived tokens the service returns in a permission's
+/// `_token` property. An application holding a master key mints them for a
+/// user, and hands them to an untrusted client that may only access the
+/// permitted resources.
+///
+/// Each token is registered under the name-based link of the resource its
+/// permission covers, such as `dbs/mydb/colls/mycontainer`. A request is signed
+/// with the token of its resource or closest ancestor. Account and container
+/// metadata reads, which target a parent of every permitted resource, use a
+/// token for any resource beneath them.
+///
+/// # Examples
+///
+/// ```
+/// use azure_data_cosmos_driver::models::{AccountReference, ResourceTokens};
+/// use url::Url;
+///
+/// let tokens = ResourceTokens::new()
+///     .with_token("dbs/mydb/colls/orders", "type=resource&ver=1.0&sig=...")
+///     .with_token("dbs/mydb/colls/customers", "type=resource&ver=1.0&sig=...");
+/// let account = AccountReference::with_resource_tokens(
+///     Url::parse("https://myaccount.documents.azure.com:443/").unwrap(),
+///     tokens,
+/// );
+/// ```
+#[derive(Clone, Default)]
+pub struct ResourceTokens {
+    /// `(resource_link, token)`, with links stored without leading or trailing `/`.
+    ///
+    /// Kept behind an `Arc` so that this variant doesn't grow [`Credential`], and with it every
+    /// future that holds an [`AccountReference`].
+    tokens: Arc<Vec<(String, Secret)>>,
+}
+
+impl ResourceTokens {
+    /// Creates an empty set of resource tokens.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds the token granting access to the resource at `resource_link`.
+    ///
+    /// `resource_link` is the name-based link of the permitted resource, such as
+    /// `dbs/mydb/colls/mycontainer`. A token added for a link that is already
+    /// present replaces the previous one.
+    pub fn with_token(mut self, resource_link: impl AsRef<str>, token: impl Into<Secret>) -> Self {
+        let resource_link = resource_link.as_ref().trim_matches('/').to_owned();
+        let token = token.into();
+        let tokens = Arc::make_mut(&mut self.tokens);
+        match tokens.iter_mut().find(|(link, _)| *link == resource_link) {
+            Some((_, existing)) => *existing = token,
+            None => tokens.push((resource_link, token)),
+        }
+        self
+    }
+
+    /// Returns `true` if no tokens have been added.
+    pub fn is_empty(&self) -> bool {
+        self.tokens.is_empty()
+    }
+
+    /// Returns the token used to sign a request for `resource_link`.
+    ///
+    /// Prefers the token for the resource itself or its closest ancestor. If
+    /// there is none, falls back to a token for any resource beneath
+    /// `resource_link`, which lets account and container metadata reads succeed.
+    pub(crate) fn token_for(&self, resource_link: &str) -> Option<&Secret> {
+        let resource_link = resource_link.trim_matches('/');
+        let is_below = |link: &str, ancestor: &str| {
+            ancestor.is_empty()
+                || link
+                    .strip_prefix(ancestor)
+                    .is_some_and(|rest| rest.is_empty() || rest.starts_with('/'))
+        };
+
+        self.tokens
+            .iter()
+            .filter(|(link, _)| is_below(resource_link, link))
+            .max_by_key(|(link, _)| link.len())
+            .or_else(|| {
+                self.tokens
+                    .iter()
+                    .find(|(link, _)| is_below(link, resource_link))
+            })
+            .map(|(_, token)| token)
+    }
+}
+
+impl std::fmt::Debug for ResourceTokens {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_list()
+            .entries(self.tokens.iter().map(|(link, _)| link))
+            .finish()
+    }
+}
+
 impl From<Secret> for Credential {
     fn from(key: Secret) -> Self {
         Self::MasterKey(key)
@@ -248,6 +356,17 @@ impl AccountReference {
         }
     }
 
+    /// Creates a new account reference with resource token authentication.
+    ///
+    /// Requests are limited to the resources the tokens grant access to.
+    pub fn with_resource_tokens(endpoint: Url, tokens: ResourceTokens) -> Self {
+        Self {
+            endpoint: AccountEndpoint::from(endpoint),
+            credential: Credential::ResourceTokens(tokens),
+            backup_endpoints: Vec::new(),
+        }
+    }
+
     /// Returns the service endpoint URL.
     pub fn endpoint(&self) -> &Url {
         self.endpoint.url()
@@ -332,6 +451,12 @@ impl AccountReferenceBuilder {
         self
     }
 
+    /// Sets resource token authentication.
+    pub fn resource_tokens(mut self, tokens: ResourceTokens) -> Self {
+        self.credential = Some(Credential::ResourceTokens(tokens));
+        self
+    }
+
     /// Sets authentication options directly.
     pub fn auth(mut self, credential: Credential) -> Self {
         self.credential = Some(credential);
@@ -478,6 +603,70 @@ mod tests {
         }
     }
 
+    #[test]
+    fn shorthand_with_resource_tokens() {
+        let account = AccountReference::with_resource_tokens(
+            Url::parse("https://test.documents.azure.com:443/").unwrap(),
+            ResourceTokens::new().with_token("/dbs/db1/colls/c1/", "token1"),
+        );
+
+        match account.auth() {
+            Credential::ResourceTokens(tokens) => {
+                assert_eq!(
+                    tokens.token_for("dbs/db1/colls/c1").unwrap().secret(),
+                    "token1"
+                );
+            }
+            _ => panic!("Expected ResourceTokens auth"),
+        }
+    }
+
+    #[test]
+    fn resource_tokens_select_closest_ancestor() {
+        let tokens = ResourceTokens::new()
+            .with_token("dbs/db1/colls/c1", "container")
+            .with_token("dbs/db1/colls/c1/docs/item1", "item")
+            .with_token("dbs/db1/colls/c2", "other");
+        let token_for = |link| tokens.token_for(link).map(|t| t.secret().to_owned());
+
+        assert_eq!(
+            token_for("dbs/db1/colls/c1/docs/item1").as_deref(),
+            Some("item")
+        );
+        assert_eq!(
+            token_for("dbs/db1/colls/c1/docs/item2").as_deref(),
+            Some("container")
+        );
+        assert_eq!(token_for("dbs/db1/colls/c1").as_deref(), Some("container"));
+        assert_eq!(
+            token_for("dbs/db1/colls/c2/docs/x").as_deref(),
+            Some("other")
+        );
+        // Prefix matching is segment-aware.
+        assert_eq!(token_for("dbs/db1/colls/c10").as_deref(), None);
+    }
+
+    #[test]
+    fn resource_tokens_fall_back_to_descendant_for_metadata_reads() {
+        let tokens = ResourceTokens::new().with_token("dbs/db1/colls/c1/docs/item1", "item");
+        let token_for = |link| tokens.token_for(link).map(|t| t.secret().to_owned());
+
+        assert_eq!(token_for("").as_deref(), Some("item"));
+        assert_eq!(token_for("dbs/db1/colls/c1").as_deref(), Some("item"));
+        assert_eq!(token_for("dbs/db2").as_deref(), None);
+    }
+
+    #[test]
+    fn resource_tokens_replace_duplicate_link_and_redact_debug() {
+        let tokens = ResourceTokens::new()
+            .with_token("dbs/db1", "first")
+            .with_token("dbs/db1/", "second");
+
+        assert_eq!(tokens.token_for("dbs/db1").unwrap().secret(), "second");
+        let rendered = format!("{tokens:?}");
+        assert_eq!(rendered, r#"["dbs/db1"]"#);
+    }
+
     #[test]
     fn account_endpoint_deserialize_valid_url() {
         let endpoint: AccountEndpoint =
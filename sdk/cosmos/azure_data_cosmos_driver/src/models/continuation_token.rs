@@ -160,7 +160,7 @@ impl TokenOperation {
 }
 
 /// The decoded state of a continuation token that can be used to resume an operation.
-#[derive(Serialize, Deserialize, PartialEq, Eq, Debug)]
+#[derive(Serialize, Deserialize, PartialEq, Debug)]
 pub struct TokenState {
     /// The kind of operation this token is for (e.g. query, read feed, etc.). This is used to ensure the token is only used with a compatible operation.
     #[serde(rename = "op")]
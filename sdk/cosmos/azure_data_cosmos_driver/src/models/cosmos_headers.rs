@@ -45,6 +45,8 @@ pub(crate) mod request_header_names {
     pub const SUPPORTED_QUERY_FEATURES: &str = "x-ms-cosmos-supported-query-features";
     pub const QUERY_VERSION: &str = "x-ms-cosmos-query-version";
     pub const IS_UPSERT: &str = "x-ms-documentdb-is-upsert";
+    pub const PRE_TRIGGER_INCLUDE: &str = "x-ms-documentdb-pre-trigger-include";
+    pub const POST_TRIGGER_INCLUDE: &str = "x-ms-documentdb-post-trigger-include";
     pub const MAX_ITEM_COUNT: &str = "x-ms-max-item-count";
     /// Change-feed indicator ("Incremental Feed"). HTTP standard name `a-im`.
     pub const A_IM: &str = "a-im";
@@ -62,6 +64,9 @@ pub(crate) mod request_header_names {
     pub const CONTINUATION: &str = "x-ms-continuation";
     pub const OFFER_THROUGHPUT: &str = "x-ms-offer-throughput";
     pub const OFFER_AUTOPILOT_SETTINGS: &str = "x-ms-cosmos-offer-autopilot-settings";
+    pub const MIGRATE_OFFER_TO_AUTOPILOT: &str = "x-ms-cosmos-migrate-offer-to-autopilot";
+    pub const MIGRATE_OFFER_TO_MANUAL_THROUGHPUT: &str =
+        "x-ms-cosmos-migrate-offer-to-manual-throughput";
     pub const PRIORITY_LEVEL: &str = "x-ms-cosmos-priority-level";
     pub const THROUGHPUT_BUCKET: &str = "x-ms-cosmos-throughput-bucket";
     pub const START_EPK: &str = "x-ms-start-epk";
@@ -216,6 +221,18 @@ pub struct CosmosRequestHeaders {
     /// Precondition for optimistic concurrency (`if-match` / `if-none-match`).
     pub precondition: Option<Precondition>,
 
+    /// Names of the pre-triggers to run for an item write
+    /// (`x-ms-documentdb-pre-trigger-include`).
+    ///
+    /// Sent as a comma-separated list; the header is omitted when empty.
+    pub pre_triggers: Vec<String>,
+
+    /// Names of the post-triggers to run for an item write
+    /// (`x-ms-documentdb-post-trigger-include`).
+    ///
+    /// Sent as a comma-separated list; the header is omitted when empty.
+    pub post_triggers: Vec<String>,
+
     /// Manual throughput in RU/s (`x-ms-offer-throughput`).
     pub offer_throughput: Option<usize>,
 
@@ -224,6 +241,11 @@ pub struct CosmosRequestHeaders {
     /// The driver serializes this to JSON for the header value.
     pub offer_autopilot_settings: Option<OfferAutoscaleSettings>,
 
+    /// Requests that an offer replace migrates the offer between manual and autoscale
+    /// throughput (`x-ms-cosmos-migrate-offer-to-autopilot` /
+    /// `x-ms-cosmos-migrate-offer-to-manual-throughput`).
+    pub offer_migration: Option<OfferMigration>,
+
     /// Maximum number of items to return per page (`x-ms-max-item-count`).
     ///
     /// Used by feed/query/changefeed reads. See [`MaxItemCountHint`] for the two
@@ -315,6 +337,18 @@ impl CosmosRequestHeaders {
                 ),
             }
         }
+        if !self.pre_triggers.is_empty() {
+            headers.insert(
+                request_header_names::PRE_TRIGGER_INCLUDE,
+                HeaderValue::from(self.pre_triggers.join(",")),
+            );
+        }
+        if !self.post_triggers.is_empty() {
+            headers.insert(
+                request_header_names::POST_TRIGGER_INCLUDE,
+                HeaderValue::from(self.post_triggers.join(",")),
+            );
+        }
         if let Some(throughput) = self.offer_throughput {
             headers.insert(
                 request_header_names::OFFER_THROUGHPUT,
@@ -329,6 +363,15 @@ impl CosmosRequestHeaders {
                 );
             }
         }
+        if let Some(migration) = self.offer_migration {
+            let name = match migration {
+                OfferMigration::ToAutoscale => request_header_names::MIGRATE_OFFER_TO_AUTOPILOT,
+                OfferMigration::ToManual => {
+                    request_header_names::MIGRATE_OFFER_TO_MANUAL_THROUGHPUT
+                }
+            };
+            headers.insert(name, HeaderValue::from_static("true"));
+        }
         if let Some(count) = self.max_item_count {
             let wire = match count {
                 MaxItemCountHint::ServerDecides => "-1".to_string(),
@@ -420,6 +463,19 @@ impl OfferAutoscaleSettings {
     }
 }
 
+/// The direction of a throughput offer migration, sent with an offer replace.
+///
+/// The service picks the new throughput: migrating to autoscale derives the maximum from the
+/// current manual throughput, and migrating to manual uses the current autoscale maximum.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum OfferMigration {
+    /// Migrates a manual throughput offer to autoscale.
+    ToAutoscale,
+    /// Migrates an autoscale offer to manual throughput.
+    ToManual,
+}
+
 /// Auto-upgrade policy for autoscale throughput.
 #[derive(Clone, Debug, Default, Serialize)]
 #[serde(rename_all = "camelCase")]
@@ -1215,6 +1271,63 @@ mod tests {
         );
     }
 
+    #[test]
+    fn write_to_headers_offer_migration() {
+        for (migration, expected, other) in [
+            (
+                OfferMigration::ToAutoscale,
+                "x-ms-cosmos-migrate-offer-to-autopilot",
+                "x-ms-cosmos-migrate-offer-to-manual-throughput",
+            ),
+            (
+                OfferMigration::ToManual,
+                "x-ms-cosmos-migrate-offer-to-manual-throughput",
+                "x-ms-cosmos-migrate-offer-to-autopilot",
+            ),
+        ] {
+            let cosmos_headers = CosmosRequestHeaders {
+                offer_migration: Some(migration),
+                ..Default::default()
+            };
+            let mut headers = Headers::new();
+
+            cosmos_headers.write_to_headers(&mut headers);
+
+            assert_eq!(
+                headers.get_optional_str(&HeaderName::from_static(expected)),
+                Some("true")
+            );
+            assert_eq!(
+                headers.get_optional_str(&HeaderName::from_static(other)),
+                None
+            );
+        }
+    }
+
+    #[test]
+    fn write_to_headers_trigger_includes() {
+        let cosmos_headers = CosmosRequestHeaders {
+            pre_triggers: vec!["validate".to_string(), "stamp".to_string()],
+            ..Default::default()
+        };
+        let mut headers = Headers::new();
+
+        cosmos_headers.write_to_headers(&mut headers);
+
+        assert_eq!(
+            headers.get_optional_str(&HeaderName::from_static(
+                "x-ms-documentdb-pre-trigger-include"
+            )),
+            Some("validate,stamp")
+        );
+        assert_eq!(
+            headers.get_optional_str(&HeaderName::from_static(
+                "x-ms-documentdb-post-trigger-include"
+            )),
+            None
+        );
+    }
+
     #[test]
     fn write_to_headers_precondition_if_match() {
         let cosmos_headers = CosmosRequestHeaders {
@@ -6,7 +6,7 @@
 use crate::models::{
     AccountReference, ContainerReference, CosmosRequestHeaders, CosmosResourceReference,
     DatabaseReference, FeedRange, ItemReference, OperationType, PartitionKey, Precondition,
-    ResourceType,
+    ResourceType, StoredProcedureReference, TriggerReference,
 };
 use azure_core::http::Etag;
 use serde::{Deserialize, Serialize};
@@ -132,6 +132,9 @@ pub struct CosmosOperation {
     /// [`change_feed`](Self::change_feed) rather than inferred from a header,
     /// so future change feed modes can be added without ambiguity.
     is_change_feed: bool,
+    /// `true` when this is a non-atomic batch built by [`bulk`](Self::bulk).
+    /// Only consulted when `operation_type == OperationType::Batch`.
+    is_bulk: bool,
     /// The original change feed start position, persisted into the continuation
     /// token so never-polled partitions can re-apply it on resume. `None` for
     /// non-change-feed operations.
@@ -197,6 +200,13 @@ impl CosmosOperation {
         self.is_change_feed
     }
 
+    /// Returns `true` if this is a non-atomic bulk batch.
+    ///
+    /// Set explicitly by [`bulk`](Self::bulk).
+    pub fn is_bulk(&self) -> bool {
+        self.is_bulk
+    }
+
     /// Returns the request headers.
     pub fn request_headers(&self) -> &CosmosRequestHeaders {
         &self.request_headers
@@ -222,6 +232,31 @@ impl CosmosOperation {
         self
     }
 
+    /// Requests that an offer replace migrates the offer between manual and autoscale throughput.
+    ///
+    /// Only meaningful for [`replace_offer`](Self::replace_offer) operations, whose body should
+    /// be the current offer unchanged.
+    pub fn with_offer_migration(mut self, migration: crate::models::OfferMigration) -> Self {
+        self.request_headers.offer_migration = Some(migration);
+        self
+    }
+
+    /// Sets the names of the pre-triggers to run for the operation.
+    ///
+    /// Only meaningful for item writes.
+    pub fn with_pre_triggers(mut self, triggers: impl IntoIterator<Item = String>) -> Self {
+        self.request_headers.pre_triggers = triggers.into_iter().collect();
+        self
+    }
+
+    /// Sets the names of the post-triggers to run for the operation.
+    ///
+    /// Only meaningful for item writes.
+    pub fn with_post_triggers(mut self, triggers: impl IntoIterator<Item = String>) -> Self {
+        self.request_headers.post_triggers = triggers.into_iter().collect();
+        self
+    }
+
     /// Sets the activity ID request header for the operation.
     pub fn with_activity_id(mut self, activity_id: crate::models::ActivityId) -> Self {
         self.request_headers.activity_id = Some(activity_id);
@@ -345,6 +380,7 @@ impl CosmosOperation {
             body: None,
             patch_max_attempts: None,
             is_change_feed: false,
+            is_bulk: false,
             change_feed_start: None,
         }
     }
@@ -654,6 +690,81 @@ impl CosmosOperation {
         Self::new(OperationType::Batch, resource_ref, Some(range))
     }
 
+    /// Executes a non-atomic batch of operations against one partition key range.
+    ///
+    /// Unlike a [`batch`](Self::batch), the operations may target any partition
+    /// keys within `range`, and each one carries its own `partitionKey` in the
+    /// body. Each operation commits or fails on its own and the service
+    /// continues past failed operations. The response reports a status per
+    /// operation. Use `with_body()` to provide the JSON-encoded array of batch
+    /// operations.
+    ///
+    /// The request is sent to the partition key range that holds `range`. A
+    /// batch body can't be divided between partitions, so if `range` no longer
+    /// falls within a single partition key range (after a split), the operation
+    /// fails with [`CosmosStatus::PARTITION_KEY_RANGE_GONE`](crate::models::CosmosStatus::PARTITION_KEY_RANGE_GONE)
+    /// and the caller regroups its operations.
+    pub fn bulk(container: ContainerReference, range: FeedRange) -> Self {
+        let resource_ref: CosmosResourceReference = CosmosResourceReference::from(container)
+            .with_resource_type(ResourceType::Document)
+            .into_feed_reference();
+        let mut operation = Self::new(OperationType::Batch, resource_ref, Some(range));
+        operation.is_bulk = true;
+        operation
+    }
+
+    /// Executes a stored procedure against a single partition.
+    ///
+    /// The stored procedure runs in the scope of `partition_key`. Use
+    /// `with_body()` to provide the JSON array of parameters.
+    pub fn execute_stored_procedure(
+        stored_procedure: StoredProcedureReference,
+        partition_key: PartitionKey,
+    ) -> Self {
+        let range = FeedRange::for_partition(
+            partition_key,
+            stored_procedure.container().partition_key_definition(),
+        );
+        Self::new(OperationType::Execute, stored_procedure, Some(range))
+    }
+
+    /// Creates a trigger in a container.
+    ///
+    /// Use `with_body()` to provide the trigger definition JSON.
+    pub fn create_trigger(container: ContainerReference) -> Self {
+        let resource_ref: CosmosResourceReference = CosmosResourceReference::from(container)
+            .with_resource_type(ResourceType::Trigger)
+            .into_feed_reference();
+        Self::new(OperationType::Create, resource_ref, None)
+    }
+
+    /// Reads a trigger's definition.
+    pub fn read_trigger(trigger: TriggerReference) -> Self {
+        Self::new(OperationType::Read, trigger, None)
+    }
+
+    /// Replaces a trigger's definition.
+    ///
+    /// Use `with_body()` to provide the updated trigger definition JSON.
+    pub fn replace_trigger(trigger: TriggerReference) -> Self {
+        Self::new(OperationType::Replace, trigger, None)
+    }
+
+    /// Deletes a trigger.
+    pub fn delete_trigger(trigger: TriggerReference) -> Self {
+        Self::new(OperationType::Delete, trigger, None)
+    }
+
+    /// Queries the triggers in a container.
+    ///
+    /// Use `with_body()` to provide the query JSON.
+    pub fn query_triggers(container: ContainerReference) -> Self {
+        let resource_ref: CosmosResourceReference = CosmosResourceReference::from(container)
+            .with_resource_type(ResourceType::Trigger)
+            .into_feed_reference();
+        Self::new(OperationType::Query, resource_ref, None)
+    }
+
     /// Upserts (creates or replaces) an item (document) in a container.
     ///
     /// The `ItemReference` contains the container, partition key, and item identifier,
@@ -846,8 +957,12 @@ impl CosmosOperation {
     ///
     /// Cross-partition queries (those targeting a [`FeedRange`](crate::models::FeedRange))
     /// are **not** trivial and require a backend query plan to determine the
-    /// fan-out strategy.
+    /// fan-out strategy. Neither are [`bulk`](Self::bulk) batches, whose target
+    /// range must be resolved to a partition key range.
     pub fn is_trivial(&self) -> bool {
+        if self.is_bulk() {
+            return false;
+        }
         if self.operation_type != OperationType::Query {
             // Change feed is trivial only when targeting a specific logical partition key.
             // Full-container (target=None) and EPK range targets require fan-out.
@@ -1012,6 +1127,80 @@ mod tests {
         assert!(op.is_idempotent());
     }
 
+    #[test]
+    fn execute_stored_procedure_targets_partition() {
+        let sproc = StoredProcedureReference::from_name(&test_container(), "mysproc");
+        let op = CosmosOperation::execute_stored_procedure(sproc, PartitionKey::from("pk1"));
+
+        assert_eq!(op.operation_type(), OperationType::Execute);
+        assert_eq!(op.resource_type(), ResourceType::StoredProcedure);
+        assert_eq!(op.partition_key(), Some(&PartitionKey::from("pk1")));
+        assert!(!op.is_read_only());
+        assert!(!op.is_idempotent());
+        assert_eq!(
+            op.compute_resource_paths().signing_link(),
+            "dbs/testdb/colls/testcontainer/sprocs/mysproc"
+        );
+    }
+
+    #[test]
+    fn trigger_feed_operations_target_container() {
+        let create = CosmosOperation::create_trigger(test_container());
+        let query = CosmosOperation::query_triggers(test_container());
+
+        assert_eq!(create.operation_type(), OperationType::Create);
+        assert_eq!(query.operation_type(), OperationType::Query);
+        for op in [create, query] {
+            assert_eq!(op.resource_type(), ResourceType::Trigger);
+            assert!(op.partition_key().is_none());
+            let paths = op.compute_resource_paths();
+            assert_eq!(
+                paths.request_path(),
+                "/dbs/testdb/colls/testcontainer/triggers"
+            );
+            assert_eq!(paths.signing_link(), "dbs/testdb/colls/testcontainer");
+        }
+    }
+
+    #[test]
+    fn trigger_operations_target_trigger() {
+        let trigger = || TriggerReference::from_name(&test_container(), "mytrigger");
+
+        for (op, operation_type) in [
+            (
+                CosmosOperation::read_trigger(trigger()),
+                OperationType::Read,
+            ),
+            (
+                CosmosOperation::replace_trigger(trigger()),
+                OperationType::Replace,
+            ),
+            (
+                CosmosOperation::delete_trigger(trigger()),
+                OperationType::Delete,
+            ),
+        ] {
+            assert_eq!(op.operation_type(), operation_type);
+            assert_eq!(op.resource_type(), ResourceType::Trigger);
+            assert_eq!(
+                op.compute_resource_paths().signing_link(),
+                "dbs/testdb/colls/testcontainer/triggers/mytrigger"
+            );
+        }
+    }
+
+    #[test]
+    fn with_triggers_sets_request_headers() {
+        let item_ref =
+            ItemReference::from_name(&test_container(), PartitionKey::from("pk1"), "doc1");
+        let op = CosmosOperation::create_item(item_ref)
+            .with_pre_triggers(["validate".to_string()])
+            .with_post_triggers(["audit".to_string(), "notify".to_string()]);
+
+        assert_eq!(op.request_headers().pre_triggers, vec!["validate"]);
+        assert_eq!(op.request_headers().post_triggers, vec!["audit", "notify"]);
+    }
+
     #[test]
     fn upsert_is_not_idempotent() {
         let pk = PartitionKey::from("pk1");
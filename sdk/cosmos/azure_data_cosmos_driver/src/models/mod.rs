@@ -43,7 +43,9 @@ pub mod partition_key_range;
 #[allow(dead_code)]
 pub(crate) mod range;
 
-pub use account_reference::{AccountReference, AccountReferenceBuilder, Credential};
+pub use account_reference::{
+    AccountReference, AccountReferenceBuilder, Credential, ResourceTokens,
+};
 pub use activity_id::ActivityId;
 pub use connection_string::ConnectionString;
 pub(crate) use consistency_level::DefaultConsistencyLevel;
@@ -51,7 +53,7 @@ pub use continuation_token::ContinuationToken;
 pub(crate) use continuation_token::ResolvedToken;
 pub use cosmos_headers::{
     AutoscaleAutoUpgradePolicy, AutoscaleThroughputPolicy, CosmosRequestHeaders,
-    CosmosResponseHeaders, MaxItemCountHint, OfferAutoscaleSettings,
+    CosmosResponseHeaders, MaxItemCountHint, OfferAutoscaleSettings, OfferMigration,
 };
 pub use cosmos_operation::{ChangeFeedStartFrom, CosmosOperation};
 pub use cosmos_resource_reference::CosmosResourceReference;
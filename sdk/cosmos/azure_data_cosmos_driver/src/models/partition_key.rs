@@ -4,7 +4,10 @@
 
 //! Partition key types for Cosmos DB operations.
 
-use crate::models::FiniteF64;
+use crate::{
+    error::{CosmosError, CosmosStatus},
+    models::{FiniteF64, PartitionKeyDefinition},
+};
 use azure_core::http::headers::{AsHeaders, HeaderName, HeaderValue};
 use std::{borrow::Cow, hash::Hash};
 
@@ -322,6 +325,120 @@ impl PartitionKey {
     pub fn values(&self) -> &[PartitionKeyValue] {
         &self.0
     }
+
+    /// Extracts the partition key of `item` using the paths of a container's
+    /// [`PartitionKeyDefinition`].
+    ///
+    /// Each path (for example `/tenantId` or `/address/city`) is resolved
+    /// against the item body, producing one component per path for
+    /// hierarchical partition keys. A property that is missing from the item
+    /// yields [`PartitionKeyValue::UNDEFINED`] and a JSON `null` yields
+    /// [`PartitionKeyValue::NULL`], matching how the service evaluates the
+    /// partition key of a stored document.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error with status
+    /// [`CosmosStatus::CLIENT_PARTITION_KEY_NOT_EXTRACTABLE`] if `item` is not
+    /// a JSON object or a path resolves to an object or array.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use azure_data_cosmos_driver::models::{PartitionKey, PartitionKeyDefinition};
+    /// use std::borrow::Cow;
+    ///
+    /// let definition = PartitionKeyDefinition::new(vec![
+    ///     Cow::Borrowed("/tenantId"),
+    ///     Cow::Borrowed("/address/city"),
+    /// ]);
+    /// let item = serde_json::json!({
+    ///     "id": "1",
+    ///     "tenantId": "contoso",
+    ///     "address": { "city": "Redmond" },
+    /// });
+    ///
+    /// let pk = PartitionKey::from_item(&definition, &item)?;
+    /// assert_eq!(pk, PartitionKey::from(("contoso", "Redmond")));
+    /// # Ok::<(), azure_data_cosmos_driver::error::CosmosError>(())
+    /// ```
+    pub fn from_item(
+        definition: &PartitionKeyDefinition,
+        item: &serde_json::Value,
+    ) -> crate::error::Result<Self> {
+        if !item.is_object() {
+            return Err(not_extractable(
+                "cannot extract a partition key from an item that is not a JSON object",
+            ));
+        }
+
+        let mut values = Vec::with_capacity(definition.paths().len());
+        for path in definition.paths() {
+            let mut current = Some(item);
+            for segment in path_segments(path) {
+                current = current.and_then(|value| value.get(segment.as_str()));
+            }
+            let value = match current {
+                None => PartitionKeyValue::UNDEFINED,
+                Some(serde_json::Value::Null) => PartitionKeyValue::NULL,
+                Some(serde_json::Value::Bool(b)) => (*b).into(),
+                Some(serde_json::Value::String(s)) => s.clone().into(),
+                Some(serde_json::Value::Number(n)) => match n.as_f64() {
+                    Some(n) => n.into(),
+                    None => {
+                        return Err(not_extractable(format!(
+                        "partition key path '{path}' has a number that is not representable as f64"
+                    )))
+                    }
+                },
+                Some(_) => {
+                    return Err(not_extractable(format!(
+                        "partition key path '{path}' resolves to an object or array"
+                    )))
+                }
+            };
+            values.push(value);
+        }
+        Ok(Self(values))
+    }
+}
+
+fn not_extractable(message: impl Into<String>) -> CosmosError {
+    CosmosError::builder()
+        .with_status(CosmosStatus::CLIENT_PARTITION_KEY_NOT_EXTRACTABLE)
+        .with_message(message.into())
+        .build()
+}
+
+/// Splits a partition key path such as `/a/b` or `/"a/b"/c` into its property
+/// names. Segments wrapped in double quotes may contain `/`.
+fn path_segments(path: &str) -> Vec<String> {
+    let mut segments = Vec::new();
+    let mut chars = path.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '/' => continue,
+            '"' => {
+                let mut segment = String::new();
+                while let Some(c) = chars.next() {
+                    match c {
+                        '\\' => segment.extend(chars.next()),
+                        '"' => break,
+                        c => segment.push(c),
+                    }
+                }
+                segments.push(segment);
+            }
+            c => {
+                let mut segment = String::from(c);
+                while let Some(c) = chars.next_if(|c| *c != '/') {
+                    segment.push(c);
+                }
+                segments.push(segment);
+            }
+        }
+    }
+    segments
 }
 
 impl AsHeaders for PartitionKey {
@@ -489,6 +606,60 @@ mod tests {
         assert_eq!(pk3.len(), 1);
     }
 
+    #[test]
+    fn from_item_extracts_hierarchical_paths() {
+        let definition = PartitionKeyDefinition::new(vec![
+            Cow::Borrowed("/tenantId"),
+            Cow::Borrowed("/user/id"),
+            Cow::Borrowed("/\"session/key\""),
+        ]);
+        let item = serde_json::json!({
+            "tenantId": "contoso",
+            "user": { "id": 42 },
+            "session/key": true,
+        });
+
+        let pk = PartitionKey::from_item(&definition, &item).unwrap();
+        assert_eq!(pk, PartitionKey::from(("contoso", 42, true)));
+    }
+
+    #[test]
+    fn from_item_missing_and_null_values() {
+        let definition =
+            PartitionKeyDefinition::new(vec![Cow::Borrowed("/a"), Cow::Borrowed("/b/c")]);
+        let item = serde_json::json!({ "a": null });
+
+        let pk = PartitionKey::from_item(&definition, &item).unwrap();
+        assert_eq!(
+            pk.values(),
+            &[PartitionKeyValue::NULL, PartitionKeyValue::UNDEFINED]
+        );
+    }
+
+    #[test]
+    fn from_item_rejects_non_primitive_values() {
+        let definition = PartitionKeyDefinition::from("/a");
+
+        for item in [
+            serde_json::json!({ "a": { "b": 1 } }),
+            serde_json::json!({ "a": [1] }),
+            serde_json::json!("not an object"),
+        ] {
+            let err = PartitionKey::from_item(&definition, &item).unwrap_err();
+            assert_eq!(
+                err.status(),
+                CosmosStatus::CLIENT_PARTITION_KEY_NOT_EXTRACTABLE
+            );
+        }
+    }
+
+    #[test]
+    fn path_segments_handles_quotes() {
+        assert_eq!(path_segments("/a/b"), ["a", "b"]);
+        assert_eq!(path_segments("/\"a/b\"/c"), ["a/b", "c"]);
+        assert_eq!(path_segments("/\"a\\\"b\""), ["a\"b"]);
+    }
+
     #[test]
     fn hierarchical_partition_key() {
         let pk = PartitionKey::from(("tenant", "user", 42));
@@ -59,6 +59,7 @@ pub struct ConnectionPoolOptions {
     http2_health_check_interval: Duration,
     http2_consecutive_failure_threshold: u32,
     http2_eviction_grace_period: Duration,
+    endpoint_refresh_failure_threshold: u32,
     http2_keep_alive_interval: Duration,
     http2_keep_alive_timeout: Duration,
     tcp_keepalive_time: Option<Duration>,
@@ -175,6 +176,12 @@ impl ConnectionPoolOptions {
         self.http2_eviction_grace_period
     }
 
+    /// Returns the consecutive connection-failure count that makes the driver
+    /// flush an endpoint's cached connections and re-resolve its host name.
+    pub fn endpoint_refresh_failure_threshold(&self) -> u32 {
+        self.endpoint_refresh_failure_threshold
+    }
+
     /// Returns the HTTP/2 keep-alive ping interval.
     pub fn http2_keep_alive_interval(&self) -> Duration {
         self.http2_keep_alive_interval
@@ -268,6 +275,7 @@ impl ConnectionPoolOptions {
 /// - `AZURE_COSMOS_CONNECTION_POOL_HTTP2_HEALTH_CHECK_INTERVAL_MS`: Background HTTP/2 health-sweep interval in milliseconds (default: `10_000`, min: `100`)
 /// - `AZURE_COSMOS_CONNECTION_POOL_HTTP2_CONSECUTIVE_FAILURE_THRESHOLD`: Consecutive failure count before a shard becomes unhealthy (default: `5`, min: `1`, max: `255`)
 /// - `AZURE_COSMOS_CONNECTION_POOL_HTTP2_EVICTION_GRACE_PERIOD_MS`: Minimum time since the last successful request before an unhealthy shard can be evicted (default: `2_000`, min: `100`)
+/// - `AZURE_COSMOS_CONNECTION_POOL_ENDPOINT_REFRESH_FAILURE_THRESHOLD`: Consecutive connection-layer failures to an endpoint before its cached connections are flushed and its host name re-resolved (default: `3`, min: `1`, max: `255`)
 /// - `AZURE_COSMOS_CONNECTION_POOL_HTTP2_KEEP_ALIVE_INTERVAL_MS`: HTTP/2 keep-alive ping interval in milliseconds (default: `1_000`, min: `100`)
 /// - `AZURE_COSMOS_CONNECTION_POOL_HTTP2_KEEP_ALIVE_TIMEOUT_MS`: HTTP/2 keep-alive ping timeout in milliseconds (default: `2_000`, min: `100`)
 /// - `AZURE_COSMOS_CONNECTION_POOL_TCP_KEEPALIVE_TIME_MS`: TCP keepalive time in milliseconds (default: `1_000`, min: `1_000` when set)
@@ -394,6 +402,8 @@ pub struct ConnectionPoolOptionsBuilder {
         parser = parse_env_duration_millis
     )]
     http2_eviction_grace_period: Option<Duration>,
+    #[option(env = "AZURE_COSMOS_CONNECTION_POOL_ENDPOINT_REFRESH_FAILURE_THRESHOLD")]
+    endpoint_refresh_failure_threshold: Option<u32>,
     #[option(
         env = "AZURE_COSMOS_CONNECTION_POOL_HTTP2_KEEP_ALIVE_INTERVAL_MS",
         parser = parse_env_duration_millis
@@ -578,6 +588,20 @@ impl ConnectionPoolOptionsBuilder {
         self
     }
 
+    /// Sets how many consecutive connection-layer failures (connect, DNS, or
+    /// socket I/O errors) an endpoint may return before the driver drops its
+    /// cached connections and re-resolves the host name on the next request.
+    ///
+    /// Re-resolving lets the client follow regional DNS failovers instead of
+    /// reusing connections to an address that no longer serves the endpoint.
+    ///
+    /// Must be between 1 and 255 inclusive.
+    /// Default: 3.
+    pub fn with_endpoint_refresh_failure_threshold(mut self, value: u32) -> Self {
+        self.endpoint_refresh_failure_threshold = Some(value);
+        self
+    }
+
     /// Sets the HTTP/2 keep-alive ping interval.
     ///
     /// Must be at least 100 milliseconds.
@@ -854,6 +878,14 @@ impl ConnectionPoolOptionsBuilder {
             u64::MAX,
         )?;
 
+        let endpoint_refresh_failure_threshold = resolve_from_env(
+            self.endpoint_refresh_failure_threshold,
+            env.endpoint_refresh_failure_threshold,
+            "AZURE_COSMOS_CONNECTION_POOL_ENDPOINT_REFRESH_FAILURE_THRESHOLD",
+            3_u32,
+            ValidationBounds::range(1_u32, 255_u32),
+        )?;
+
         let http2_keep_alive_interval = resolve_duration_ms(
             self.http2_keep_alive_interval
                 .or(env.http2_keep_alive_interval),
@@ -928,6 +960,7 @@ impl ConnectionPoolOptionsBuilder {
             http2_health_check_interval,
             http2_consecutive_failure_threshold,
             http2_eviction_grace_period,
+            endpoint_refresh_failure_threshold,
             http2_keep_alive_interval,
             http2_keep_alive_timeout,
             tcp_keepalive_time,
@@ -1061,6 +1094,7 @@ mod tests {
             options.http2_eviction_grace_period(),
             Duration::from_secs(2)
         );
+        assert_eq!(options.endpoint_refresh_failure_threshold(), 3);
         assert_eq!(options.http2_keep_alive_interval(), Duration::from_secs(1));
         assert_eq!(options.http2_keep_alive_timeout(), Duration::from_secs(2));
         assert_eq!(options.tcp_keepalive_time(), Some(Duration::from_secs(1)));
@@ -1093,6 +1127,7 @@ mod tests {
             .with_http2_health_check_interval(Duration::from_millis(15_000))
             .with_http2_consecutive_failure_threshold(8)
             .with_http2_eviction_grace_period(Duration::from_millis(4_000))
+            .with_endpoint_refresh_failure_threshold(6)
             .with_http2_keep_alive_interval(Duration::from_millis(1_500))
             .with_http2_keep_alive_timeout(Duration::from_millis(2_500))
             .with_tcp_keepalive_time(Duration::from_millis(30_000))
@@ -1143,6 +1178,7 @@ mod tests {
             options.http2_eviction_grace_period(),
             Duration::from_millis(4_000)
         );
+        assert_eq!(options.endpoint_refresh_failure_threshold(), 6);
         assert_eq!(
             options.http2_keep_alive_interval(),
             Duration::from_millis(1_500)
@@ -1432,6 +1468,19 @@ mod tests {
             .contains("http2_consecutive_failure_threshold must be at least 1"));
     }
 
+    #[test]
+    fn endpoint_refresh_failure_threshold_too_small() {
+        let result = ConnectionPoolOptionsBuilder::new()
+            .with_endpoint_refresh_failure_threshold(0)
+            .build();
+
+        assert!(result.is_err());
+        assert!(result
+            .unwrap_err()
+            .to_string()
+            .contains("endpoint_refresh_failure_threshold must be at least 1"));
+    }
+
     #[test]
     fn tcp_keepalive_retries_too_small() {
         let result = ConnectionPoolOptionsBuilder::new()
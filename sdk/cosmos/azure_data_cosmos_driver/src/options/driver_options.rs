@@ -3,7 +3,7 @@
 
 //! Driver-level configuration options.
 
-use std::sync::Arc;
+use std::{num::NonZeroUsize, sync::Arc};
 
 use crate::{
     models::AccountReference,
@@ -94,6 +94,18 @@ pub struct DriverOptions {
     /// behavior for the lifetime of the driver. They are independent of
     /// per-operation [`OperationOptions`].
     partition_failover_options: PartitionFailoverOptions,
+    /// Whether reads prefer the lowest-latency preferred read endpoint.
+    ///
+    /// When enabled, the driver keeps a rolling average of successful read
+    /// latency per endpoint and routes first-attempt reads to the fastest
+    /// available endpoint instead of strictly following preferred-region order.
+    /// Retries still follow preferred-region order.
+    latency_based_read_routing_enabled: bool,
+    /// Maximum number of containers whose session tokens are cached.
+    ///
+    /// When `Some`, caching a token for a new container beyond this count
+    /// evicts the least recently used container's tokens. `None` is unbounded.
+    max_session_containers: Option<NonZeroUsize>,
 }
 
 impl DriverOptions {
@@ -146,6 +158,16 @@ impl DriverOptions {
     pub fn partition_failover_options(&self) -> &PartitionFailoverOptions {
         &self.partition_failover_options
     }
+
+    /// Returns whether latency-based read routing is enabled.
+    pub fn latency_based_read_routing_enabled(&self) -> bool {
+        self.latency_based_read_routing_enabled
+    }
+
+    /// Returns the maximum number of containers whose session tokens are cached, if limited.
+    pub fn max_session_containers(&self) -> Option<NonZeroUsize> {
+        self.max_session_containers
+    }
 }
 
 /// Builder for creating [`DriverOptions`].
@@ -163,6 +185,8 @@ pub struct DriverOptionsBuilder {
     fault_injection_rules: Option<Vec<Arc<FaultInjectionRule>>>,
     throughput_control_groups: ThroughputControlGroupRegistry,
     partition_failover_options: Option<PartitionFailoverOptions>,
+    latency_based_read_routing_enabled: bool,
+    max_session_containers: Option<NonZeroUsize>,
 }
 
 impl DriverOptionsBuilder {
@@ -177,6 +201,8 @@ impl DriverOptionsBuilder {
             fault_injection_rules: None,
             throughput_control_groups: ThroughputControlGroupRegistry::new(),
             partition_failover_options: None,
+            latency_based_read_routing_enabled: false,
+            max_session_containers: None,
         }
     }
 
@@ -293,6 +319,31 @@ impl DriverOptionsBuilder {
         self
     }
 
+    /// Enables or disables latency-based read routing (disabled by default).
+    ///
+    /// When enabled, first-attempt reads go to the available preferred read
+    /// endpoint with the lowest observed latency. An endpoint must be
+    /// noticeably faster than the current choice before routing switches, so
+    /// similar regions don't cause requests to flap between them. One in every
+    /// ten routed reads goes to another available preferred read endpoint, so
+    /// the latency of every endpoint keeps being sampled.
+    pub fn with_latency_based_read_routing(mut self, enabled: bool) -> Self {
+        self.latency_based_read_routing_enabled = enabled;
+        self
+    }
+
+    /// Limits the number of containers whose session tokens are cached (unbounded by default).
+    ///
+    /// Processes that touch many containers over their lifetime (for example,
+    /// one container per tenant) can set this to bound memory use. Caching a
+    /// token for a new container beyond the limit evicts the least recently
+    /// used container's tokens; the next request to that container is sent
+    /// without a cached session token.
+    pub fn with_max_session_containers(mut self, max_containers: NonZeroUsize) -> Self {
+        self.max_session_containers = Some(max_containers);
+        self
+    }
+
     /// Builds the [`DriverOptions`].
     ///
     /// When [`with_partition_failover_options`](Self::with_partition_failover_options)
@@ -338,6 +389,8 @@ impl DriverOptionsBuilder {
             fault_injection_rules: self.fault_injection_rules.filter(|r| !r.is_empty()),
             throughput_control_groups: self.throughput_control_groups,
             partition_failover_options,
+            latency_based_read_routing_enabled: self.latency_based_read_routing_enabled,
+            max_session_containers: self.max_session_containers,
         }
     }
 }
@@ -376,6 +429,17 @@ mod tests {
             .is_none());
     }
 
+    #[test]
+    fn builder_sets_max_session_containers() {
+        let options = DriverOptionsBuilder::new(test_account()).build();
+        assert_eq!(options.max_session_containers(), None);
+
+        let options = DriverOptionsBuilder::new(test_account())
+            .with_max_session_containers(NonZeroUsize::new(100).unwrap())
+            .build();
+        assert_eq!(options.max_session_containers(), NonZeroUsize::new(100));
+    }
+
     #[test]
     fn builder_sets_operation_options() {
         let operation = OperationOptionsBuilder::new()
@@ -415,6 +479,16 @@ mod tests {
             .read_consistency_strategy
             .is_none());
         assert!(options.preferred_regions().is_empty());
+        assert!(!options.latency_based_read_routing_enabled());
+    }
+
+    #[test]
+    fn builder_sets_latency_based_read_routing() {
+        let options = DriverOptionsBuilder::new(test_account())
+            .with_latency_based_read_routing(true)
+            .build();
+
+        assert!(options.latency_based_read_routing_enabled());
     }
 
     #[test]
@@ -38,6 +38,7 @@ pub use driver_options::{DriverOptions, DriverOptionsBuilder};
 pub(crate) use env_parsing::parse_duration_millis_from_env;
 pub use identity::{CorrelationId, UserAgentSuffix, WorkloadId};
 pub use operation_options::{
+    ConnectivityRetryOptions, ConnectivityRetryOptionsBuilder, ConnectivityRetryOptionsView,
     OperationOptions, OperationOptionsBuilder, OperationOptionsView, ThrottlingRetryOptions,
     ThrottlingRetryOptionsBuilder, ThrottlingRetryOptionsView, ThroughputControlOptions,
     ThroughputControlOptionsBuilder, ThroughputControlOptionsView,
@@ -51,7 +52,8 @@ pub use priority::PriorityLevel;
 pub(crate) use read_consistency::resolve_effective_consistency;
 pub use read_consistency::ReadConsistencyStrategy;
 pub use region::Region;
-pub use throughput_control::ThroughputControlGroupOptions;
+pub use throughput_control::{GlobalThroughputControlOptions, ThroughputControlGroupOptions};
 pub(crate) use throughput_control::{
-    ResolvedThroughputControl, ThroughputControlGroupRegistry, ThroughputControlGroupSnapshot,
+    ResolvedThroughputControl, ThroughputControlGroupKey, ThroughputControlGroupRegistry,
+    ThroughputControlGroupSnapshot,
 };
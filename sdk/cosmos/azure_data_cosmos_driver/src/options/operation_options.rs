@@ -100,6 +100,17 @@ pub struct OperationOptions {
     /// etc.), configure [`end_to_end_latency_policy`](Self::end_to_end_latency_policy).
     #[option(nested)]
     pub throttling_retry_options: Option<ThrottlingRetryOptions>,
+
+    /// Retry behavior for connection-level transport failures (connect
+    /// timeouts, connection resets, DNS failures), distinct from the
+    /// status-code-based retries above.
+    ///
+    /// See [`ConnectivityRetryOptions`] for the individual settings
+    /// ([`max_retry_count`](ConnectivityRetryOptions::max_retry_count) and
+    /// [`retry_backoff`](ConnectivityRetryOptions::retry_backoff)).
+    #[option(nested)]
+    pub connectivity_retry_options: Option<ConnectivityRetryOptions>,
+
     /// Master switch that enables or disables cross-region read hedging.
     ///
     /// **Default**: `None`, which the driver treats as **enabled** — eligible
@@ -201,6 +212,47 @@ pub struct ThrottlingRetryOptions {
     pub max_retry_wait_time: Option<Duration>,
 }
 
+/// Retry behavior for connection-level transport failures.
+///
+/// A connectivity failure is one where no HTTP response was received: the
+/// connection could not be established (connect timeout, DNS failure), was
+/// reset, or failed while reading the response. These failures are retried
+/// locally on a different connection to the same endpoint before the
+/// operation pipeline considers a regional failover. HTTP status codes
+/// (such as 429 or 503 returned by the service) are never retried here; see
+/// [`ThrottlingRetryOptions`] and
+/// [`OperationOptions::max_failover_retry_count`] for those.
+///
+/// A request that may already have been sent is only retried when the
+/// operation is a read or is idempotent.
+///
+/// # Scope
+///
+/// Like [`ThrottlingRetryOptions`], the budget applies *per
+/// transport-pipeline invocation*. Use
+/// [`OperationOptions::end_to_end_latency_policy`] to bound the total time
+/// an operation spends on retries; connectivity retry delays never sleep
+/// past that deadline.
+#[derive(CosmosOptions, Clone, Debug)]
+#[options(layers(runtime, account, operation))]
+#[non_exhaustive]
+pub struct ConnectivityRetryOptions {
+    /// Maximum number of local retries after a connectivity failure.
+    ///
+    /// **Default**: `1`. A value of `0` disables local connectivity retries,
+    /// leaving the failure to the operation pipeline's regional failover.
+    /// A retry is only attempted when another connection to the endpoint is
+    /// available.
+    #[option(env = "AZURE_COSMOS_MAX_CONNECTIVITY_RETRY_COUNT")]
+    pub max_retry_count: Option<u32>,
+
+    /// Delay before the first connectivity retry.
+    ///
+    /// Each subsequent retry doubles the previous delay. **Default**: zero,
+    /// meaning retries are attempted immediately.
+    pub retry_backoff: Option<Duration>,
+}
+
 /// Throughput-control tuning for an individual request (or layer default).
 ///
 /// Mirrors the [`ThrottlingRetryOptions`] pattern: three independently
@@ -427,6 +479,18 @@ mod tests {
         assert!(throttling.max_retry_wait_time.is_none());
     }
 
+    #[test]
+    fn connectivity_retry_options_from_env() {
+        let connectivity = ConnectivityRetryOptions::from_env_vars(|key| match key {
+            "AZURE_COSMOS_MAX_CONNECTIVITY_RETRY_COUNT" => Ok("3".to_string()),
+            _ => Err(std::env::VarError::NotPresent),
+        });
+
+        assert_eq!(connectivity.max_retry_count, Some(3));
+        // `retry_backoff` has no env var, so it stays None.
+        assert!(connectivity.retry_backoff.is_none());
+    }
+
     #[test]
     fn from_env_vars_returns_none_for_missing_vars() {
         let options = OperationOptions::from_env_vars(|_| Err(std::env::VarError::NotPresent));
@@ -245,19 +245,46 @@ impl Region {
         }
     }
 
-    /// Gets the internal region ID used for session token tracking.
+    /// Gets the numeric region ID the service uses for this region in vector session tokens.
     ///
-    /// Returns `None` for regions not in the known mapping table.
+    /// Vector session tokens (`<version>#<global_lsn>#<region_id>=<region_lsn>…`)
+    /// report per-region progress by this ID. Returns `None` for regions not in
+    /// the known mapping table.
     ///
-    /// **Note**: This is an internal implementation detail and may change.
-    /// The returned ID should be treated as an opaque value.
-    #[cfg(test)]
-    pub(crate) fn id(&self) -> Option<u8> {
+    /// # Examples
+    ///
+    /// ```
+    /// use azure_data_cosmos_driver::options::Region;
+    ///
+    /// assert_eq!(Region::WEST_US_2.region_id(), Some(8));
+    /// assert_eq!(Region::new("East US 9").region_id(), None);
+    /// ```
+    pub fn region_id(&self) -> Option<u8> {
         REGION_ID_MAPPING
             .iter()
             .find(|(normalized, _)| *normalized == self.normalized.as_ref())
             .map(|(_, id)| *id)
     }
+
+    /// Looks up the region with the given vector session token region ID.
+    ///
+    /// This is the inverse of [`region_id()`](Self::region_id). Returns `None`
+    /// for IDs not in the known mapping table.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use azure_data_cosmos_driver::options::Region;
+    ///
+    /// let region = Region::from_region_id(8).unwrap();
+    /// assert_eq!(region.display_name(), "West US 2");
+    /// ```
+    pub fn from_region_id(id: u8) -> Option<Region> {
+        REGION_ID_MAPPING
+            .iter()
+            .find(|(_, region_id)| *region_id == id)
+            .map(|(normalized, _)| Region::new_const(normalized))
+    }
 }
 
 impl From<&'static str> for Region {
@@ -296,9 +323,8 @@ fn normalize_region_name(name: impl AsRef<str>) -> Cow<'static, str> {
 }
 
 // ===== Region ID Mapping =====
-// Maps normalized region names to internal IDs for session token tracking.
+// Maps normalized region names to the region IDs used in vector session tokens.
 // Source: RegionNameToRegionIdMap.java from Azure SDK for Java
-#[cfg(test)]
 const REGION_ID_MAPPING: &[(&str, u8)] = &[
     ("eastus", 1),
     ("eastus2", 2),
@@ -533,13 +559,22 @@ mod tests {
 
     #[test]
     fn region_ids() {
-        assert_eq!(Region::WEST_US_2.id(), Some(8));
-        assert_eq!(Region::EAST_US.id(), Some(1));
-        assert_eq!(Region::USSEC_WEST_CENTRAL.id(), Some(113));
+        assert_eq!(Region::WEST_US_2.region_id(), Some(8));
+        assert_eq!(Region::EAST_US.region_id(), Some(1));
+        assert_eq!(Region::USSEC_WEST_CENTRAL.region_id(), Some(113));
 
         // Unknown region should return None
         let custom = Region::new("East US 9");
-        assert_eq!(custom.id(), None);
+        assert_eq!(custom.region_id(), None);
+    }
+
+    #[test]
+    fn from_region_id_round_trips() {
+        for (_, id) in REGION_ID_MAPPING {
+            assert_eq!(Region::from_region_id(*id).unwrap().region_id(), Some(*id));
+        }
+        assert_eq!(Region::from_region_id(8), Some(Region::WEST_US_2));
+        assert_eq!(Region::from_region_id(0), None);
     }
 
     #[test]
@@ -9,6 +9,10 @@
 //!
 //! Each group is uniquely identified by the combination of container reference and group name.
 //! At most one group per container can be marked as `is_default = true`.
+//!
+//! Besides the server-side settings (throughput bucket, priority level), a group can carry a
+//! client-side RU/s target which the driver enforces itself, optionally coordinated across
+//! clients through a control container (see [`GlobalThroughputControlOptions`]).
 
 use crate::{
     models::{ContainerReference, ThroughputControlGroupName},
@@ -17,6 +21,7 @@ use crate::{
 use std::{
     collections::HashMap,
     sync::{Arc, RwLock},
+    time::Duration,
 };
 
 /// Runtime settings for a throughput control group.
@@ -26,25 +31,99 @@ use std::{
 struct ThroughputControlSettings {
     throughput_bucket: Option<u32>,
     priority_level: Option<PriorityLevel>,
+    target_throughput: Option<u32>,
+    target_throughput_threshold: Option<f64>,
+}
+
+/// Default interval at which a client renews its item in the control container.
+const DEFAULT_CONTROL_ITEM_RENEW_INTERVAL: Duration = Duration::from_secs(10);
+
+/// Default time after which a client's item in the control container expires.
+const DEFAULT_CONTROL_ITEM_EXPIRE_INTERVAL: Duration = Duration::from_secs(30);
+
+/// Coordinates a throughput control group's client-side target across clients.
+///
+/// Without global control, every client enforces the group's full target on its own.
+/// With it, each client periodically writes its recent RU demand to an item in the
+/// control container and takes a share of the target proportional to its demand, so the
+/// clients together stay within the target.
+///
+/// The control container must be partitioned by `/groupId` and have a default TTL set
+/// (for example `-1`), so items of clients that stop renewing them expire.
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub struct GlobalThroughputControlOptions {
+    control_container: ContainerReference,
+    renew_interval: Duration,
+    expire_interval: Duration,
+}
+
+impl GlobalThroughputControlOptions {
+    /// Creates global control options which coordinate through `control_container`.
+    pub fn new(control_container: ContainerReference) -> Self {
+        Self {
+            control_container,
+            renew_interval: DEFAULT_CONTROL_ITEM_RENEW_INTERVAL,
+            expire_interval: DEFAULT_CONTROL_ITEM_EXPIRE_INTERVAL,
+        }
+    }
+
+    /// Sets how often the client renews its item and recomputes its share.
+    ///
+    /// Default: 10 seconds.
+    pub fn with_renew_interval(mut self, interval: Duration) -> Self {
+        self.renew_interval = interval;
+        self
+    }
+
+    /// Sets how long a client's item lives without being renewed.
+    ///
+    /// Must be greater than the renew interval. Default: 30 seconds.
+    pub fn with_expire_interval(mut self, interval: Duration) -> Self {
+        self.expire_interval = interval;
+        self
+    }
+
+    /// Returns the control container.
+    pub fn control_container(&self) -> &ContainerReference {
+        &self.control_container
+    }
+
+    /// Returns the renew interval.
+    pub fn renew_interval(&self) -> Duration {
+        self.renew_interval
+    }
+
+    /// Returns the expire interval.
+    pub fn expire_interval(&self) -> Duration {
+        self.expire_interval
+    }
 }
 
 /// Configuration for a throughput control group.
 ///
 /// Registered at the runtime level and associated with a container.
-/// Throughput control is enforced server-side.
 ///
-/// A group can have a throughput bucket, a priority level, or both.
+/// A group can have a throughput bucket, a priority level, or both; these are
+/// enforced server-side. A group can also have a client-side target, either an
+/// absolute RU/s value or a fraction of the container's provisioned throughput,
+/// which the driver enforces by delaying requests once the group has used its
+/// budget for the current second. Client-side control applies to requests that
+/// name the group, and to requests that name no group when the group is the
+/// container's default.
 ///
 /// # Immutability
 ///
-/// Once registered, the group's name, container, and `is_default` flag are immutable.
-/// Only the target values (priority level, bucket) can be modified at runtime.
+/// Once registered, the group's name, container, `is_default` flag, and global
+/// control options are immutable. Only the target values (priority level, bucket,
+/// client-side targets) can be modified at runtime.
 #[derive(Clone, Debug)]
 #[non_exhaustive]
 pub struct ThroughputControlGroupOptions {
     name: ThroughputControlGroupName,
     container: ContainerReference,
     is_default: bool,
+    global_control: Option<GlobalThroughputControlOptions>,
     mutable: Arc<RwLock<ThroughputControlSettings>>,
 }
 
@@ -59,10 +138,34 @@ impl ThroughputControlGroupOptions {
             name: name.into(),
             container,
             is_default,
+            global_control: None,
             mutable: Arc::new(RwLock::new(ThroughputControlSettings::default())),
         }
     }
 
+    /// Sets the initial client-side target in RU/s.
+    ///
+    /// Takes precedence over a target throughput threshold.
+    pub fn with_target_throughput(self, request_units_per_second: u32) -> Self {
+        self.mutable.write().unwrap().target_throughput = Some(request_units_per_second);
+        self
+    }
+
+    /// Sets the initial client-side target as a fraction of the container's provisioned
+    /// throughput (the maximum throughput for autoscale containers).
+    ///
+    /// Must be greater than 0 and at most 1.
+    pub fn with_target_throughput_threshold(self, threshold: f64) -> Self {
+        self.mutable.write().unwrap().target_throughput_threshold = Some(threshold);
+        self
+    }
+
+    /// Coordinates the client-side target across clients through a control container.
+    pub fn with_global_control(mut self, global_control: GlobalThroughputControlOptions) -> Self {
+        self.global_control = Some(global_control);
+        self
+    }
+
     /// Sets the initial throughput bucket value.
     pub fn with_throughput_bucket(self, bucket: u32) -> Self {
         self.mutable.write().unwrap().throughput_bucket = Some(bucket);
@@ -90,6 +193,12 @@ impl ThroughputControlGroupOptions {
         self.is_default
     }
 
+    /// Returns the global control options, if the group's client-side target is
+    /// coordinated across clients.
+    pub fn global_control(&self) -> Option<&GlobalThroughputControlOptions> {
+        self.global_control.as_ref()
+    }
+
     /// Returns the registry key for this group.
     pub(crate) fn key(&self) -> ThroughputControlGroupKey {
         ThroughputControlGroupKey {
@@ -117,6 +226,34 @@ impl ThroughputControlGroupOptions {
     pub fn set_priority_level(&self, level: PriorityLevel) {
         self.mutable.write().unwrap().priority_level = Some(level);
     }
+
+    /// Returns the current client-side target in RU/s, if set.
+    pub fn target_throughput(&self) -> Option<u32> {
+        self.mutable.read().unwrap().target_throughput
+    }
+
+    /// Sets the client-side target in RU/s.
+    pub fn set_target_throughput(&self, request_units_per_second: u32) {
+        self.mutable.write().unwrap().target_throughput = Some(request_units_per_second);
+    }
+
+    /// Returns the current client-side target threshold, if set.
+    pub fn target_throughput_threshold(&self) -> Option<f64> {
+        self.mutable.read().unwrap().target_throughput_threshold
+    }
+
+    /// Sets the client-side target as a fraction of the container's provisioned throughput.
+    ///
+    /// Values outside `(0, 1]` are clamped into that range when the target is enforced.
+    pub fn set_target_throughput_threshold(&self, threshold: f64) {
+        self.mutable.write().unwrap().target_throughput_threshold = Some(threshold);
+    }
+
+    /// Returns whether the group has a client-side target the driver enforces.
+    pub(crate) fn has_client_side_target(&self) -> bool {
+        let mutable = self.mutable.read().unwrap();
+        mutable.target_throughput.is_some() || mutable.target_throughput_threshold.is_some()
+    }
 }
 
 /// Composite key for identifying a throughput control group.
@@ -280,6 +417,13 @@ pub(crate) enum ThroughputControlGroupRegistrationError {
         container: ContainerReference,
         existing_default: ThroughputControlGroupName,
     },
+    /// The target throughput threshold is not in `(0, 1]`.
+    InvalidTargetThroughputThreshold(f64),
+    /// The control item expire interval is not greater than its renew interval.
+    InvalidGlobalControlIntervals {
+        renew_interval: Duration,
+        expire_interval: Duration,
+    },
 }
 
 impl std::fmt::Display for ThroughputControlGroupRegistrationError {
@@ -301,6 +445,23 @@ impl std::fmt::Display for ThroughputControlGroupRegistrationError {
                     existing_default
                 )
             }
+            Self::InvalidTargetThroughputThreshold(threshold) => {
+                write!(
+                    f,
+                    "Target throughput threshold must be greater than 0 and at most 1, got {}",
+                    threshold
+                )
+            }
+            Self::InvalidGlobalControlIntervals {
+                renew_interval,
+                expire_interval,
+            } => {
+                write!(
+                    f,
+                    "Control item expire interval ({:?}) must be greater than its renew interval ({:?})",
+                    expire_interval, renew_interval
+                )
+            }
         }
     }
 }
@@ -335,6 +496,8 @@ impl ThroughputControlGroupRegistry {
     /// Returns an error if:
     /// - A group with the same (container, name) key already exists
     /// - Another group is already marked as default for the same container
+    /// - The target throughput threshold is not in `(0, 1]`
+    /// - The global control expire interval is not greater than the renew interval
     #[allow(clippy::result_large_err)]
     pub(crate) fn register(
         &mut self,
@@ -342,6 +505,26 @@ impl ThroughputControlGroupRegistry {
     ) -> Result<(), ThroughputControlGroupRegistrationError> {
         let key = group.key();
 
+        if let Some(threshold) = group.target_throughput_threshold() {
+            if !(threshold > 0.0 && threshold <= 1.0) {
+                return Err(
+                    ThroughputControlGroupRegistrationError::InvalidTargetThroughputThreshold(
+                        threshold,
+                    ),
+                );
+            }
+        }
+        if let Some(global) = group.global_control() {
+            if global.expire_interval() <= global.renew_interval() {
+                return Err(
+                    ThroughputControlGroupRegistrationError::InvalidGlobalControlIntervals {
+                        renew_interval: global.renew_interval(),
+                        expire_interval: global.expire_interval(),
+                    },
+                );
+            }
+        }
+
         // Check for duplicate key
         if self.groups.contains_key(&key) {
             return Err(ThroughputControlGroupRegistrationError::DuplicateGroup(key));
@@ -706,4 +889,64 @@ mod tests {
         assert_eq!(snapshot.throughput_bucket(), Some(200));
         assert_eq!(snapshot.priority_level(), Some(PriorityLevel::High));
     }
+
+    #[test]
+    fn client_side_targets() {
+        let group = ThroughputControlGroupOptions::new("bulk", test_container(), false);
+        assert!(!group.has_client_side_target());
+
+        let group = group
+            .with_target_throughput(500)
+            .with_target_throughput_threshold(0.3)
+            .with_global_control(
+                GlobalThroughputControlOptions::new(test_container_2())
+                    .with_renew_interval(Duration::from_secs(5)),
+            );
+        assert!(group.has_client_side_target());
+        assert_eq!(group.target_throughput(), Some(500));
+        assert_eq!(group.target_throughput_threshold(), Some(0.3));
+
+        let global = group.global_control().unwrap();
+        assert_eq!(global.control_container(), &test_container_2());
+        assert_eq!(global.renew_interval(), Duration::from_secs(5));
+        assert_eq!(
+            global.expire_interval(),
+            DEFAULT_CONTROL_ITEM_EXPIRE_INTERVAL
+        );
+
+        group.set_target_throughput(1000);
+        group.set_target_throughput_threshold(0.5);
+        assert_eq!(group.target_throughput(), Some(1000));
+        assert_eq!(group.target_throughput_threshold(), Some(0.5));
+    }
+
+    #[test]
+    fn registry_rejects_invalid_target_throughput_threshold() {
+        let mut registry = ThroughputControlGroupRegistry::new();
+        for threshold in [0.0, -0.5, 1.5, f64::NAN] {
+            let group = ThroughputControlGroupOptions::new("bulk", test_container(), false)
+                .with_target_throughput_threshold(threshold);
+            assert!(matches!(
+                registry.register(group),
+                Err(ThroughputControlGroupRegistrationError::InvalidTargetThroughputThreshold(_))
+            ));
+        }
+        assert!(registry.is_empty());
+    }
+
+    #[test]
+    fn registry_rejects_expire_interval_not_after_renew_interval() {
+        let mut registry = ThroughputControlGroupRegistry::new();
+        let group = ThroughputControlGroupOptions::new("bulk", test_container(), false)
+            .with_target_throughput(100)
+            .with_global_control(
+                GlobalThroughputControlOptions::new(test_container_2())
+                    .with_renew_interval(Duration::from_secs(10))
+                    .with_expire_interval(Duration::from_secs(10)),
+            );
+        assert!(matches!(
+            registry.register(group),
+            Err(ThroughputControlGroupRegistrationError::InvalidGlobalControlIntervals { .. })
+        ));
+    }
 }
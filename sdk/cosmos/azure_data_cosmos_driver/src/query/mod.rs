@@ -27,14 +27,19 @@ pub(crate) use parser::parse;
 /// advertises to the Cosmos DB Gateway via
 /// `x-ms-cosmos-supported-query-features`.
 ///
-/// Advertised as `"None"`: the cross-partition
-/// query pipeline does not yet support any of the advanced rewrite shapes the
-/// Gateway can plan (Aggregate, CompositeAggregate, CountIf, DCount, Distinct,
-/// GroupBy, HybridSearch, MultipleAggregates, MultipleOrderBy,
-/// NonStreamingOrderBy, NonValueAggregate, OffsetAndLimit, OrderBy, Top,
-/// WeightedRankFusion); advertising any of them in production would cause the
-/// Gateway to return a plan we cannot execute. Add a feature here only after
-/// the local pipeline gains support for the corresponding rewrite shape.
+/// Lists only the rewrite shapes the cross-partition query pipeline can
+/// execute: `ORDER BY` over one or more sort keys (OrderBy,
+/// MultipleOrderBy), including non-streaming `ORDER BY`, such as vector
+/// search, under `TOP` or `LIMIT` (NonStreamingOrderBy), `SELECT VALUE`
+/// aggregates, including `AVG`, `MIN` and `MAX`, whose partials are composite
+/// objects (Aggregate, CompositeAggregate), `DISTINCT` (Distinct), `GROUP BY`
+/// (GroupBy), `TOP` (Top) and `OFFSET ... LIMIT` (OffsetAndLimit). The
+/// remaining shapes the Gateway can plan (CountIf, DCount, HybridSearch,
+/// MultipleAggregates, NonValueAggregate, WeightedRankFusion) are not
+/// advertised; doing so in
+/// production would cause the Gateway to return a plan we cannot execute. Add
+/// a feature here only after the local pipeline gains support for the
+/// corresponding rewrite shape.
 ///
 /// The value must be non-empty: the Gateway V2 thin-client proxy rejects
 /// QueryPlan requests where the `x-ms-cosmos-supported-query-features` header
@@ -43,7 +48,8 @@ pub(crate) use parser::parse;
 /// Tests use [`__TEST_ONLY_SUPPORTED_QUERY_FEATURES`] (broad, matches what
 /// Java/.NET advertise) so plan-shape parity against the live Gateway is
 /// validated end-to-end across the full feature surface.
-pub(crate) const SUPPORTED_QUERY_FEATURES: &str = "None";
+pub(crate) const SUPPORTED_QUERY_FEATURES: &str =
+    "Aggregate,CompositeAggregate,Distinct,GroupBy,NonStreamingOrderBy,OffsetAndLimit,OrderBy,MultipleOrderBy,Top";
 
 /// Broad supported-features list used by cross-crate gateway-comparison
 /// tests. Matches what the Java and .NET SDKs send today so the Gateway
@@ -9,6 +9,7 @@
 mod builder;
 mod env;
 mod parse;
+mod setters;
 mod view;
 
 use parse::OptionsInput;
@@ -71,6 +72,48 @@ pub fn derive_cosmos_options(input: proc_macro::TokenStream) -> proc_macro::Toke
     }
 }
 
+/// Derives `builder()`, `with_{field}` setters, and `build()` for Cosmos DB request options structs.
+///
+/// The struct is its own builder: `{Name}::builder()` returns
+/// `{Name}::default()`, so the struct must implement [`Default`], and
+/// `build()` returns the struct, e.g.
+/// `CreateContainerOptions::builder().with_throughput(throughput).build()`.
+///
+/// Each setter consumes and returns `self` so calls can be chained, e.g.
+/// `CreateContainerOptions::default().with_throughput(throughput)`.
+/// Setters for `Option<T>` fields take a `T` and store `Some(value)`.
+/// Setters have the visibility of the struct, so `pub(crate)` fields can
+/// still be set by callers. The field's doc comments are reused as the
+/// setter's documentation.
+///
+/// # Field-Level Attributes
+///
+/// - `#[setter(skip)]` — does not generate a setter for the field.
+/// - `#[setter(name = "with_...")]` — overrides the setter name.
+/// - `#[setter(doc = "...")]` — overrides the setter documentation.
+/// - `#[setter(into)]` — the setter accepts `impl Into<T>`.
+///
+/// # Example
+///
+/// ```ignore
+/// #[derive(Clone, Default, CosmosSetters)]
+/// pub struct CreateContainerOptions {
+///     #[setter(name = "with_operation_options", doc = "Sets the [`OperationOptions`] for this request.")]
+///     pub operation: OperationOptions,
+///
+///     #[setter(doc = "Sets the throughput properties for the new container.")]
+///     pub(crate) throughput: Option<ThroughputProperties>,
+/// }
+/// ```
+#[proc_macro_derive(CosmosSetters, attributes(setter))]
+pub fn derive_cosmos_setters(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let ast = parse_macro_input!(input as DeriveInput);
+    match setters::generate_setters(&ast) {
+        Ok(tokens) => tokens.into(),
+        Err(e) => e.to_compile_error().into(),
+    }
+}
+
 fn derive_cosmos_options_impl(ast: DeriveInput) -> Result<proc_macro2::TokenStream> {
     let input = OptionsInput::from_derive_input(&ast)?;
 
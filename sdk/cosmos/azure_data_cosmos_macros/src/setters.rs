@@ -0,0 +1,218 @@
+// Copyright (c) Microsoft Corporation. All rights reserved.
+// Licensed under the MIT License.
+
+//! Code generation for `#[derive(CosmosSetters)]`.
+
+use crate::Result;
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{
+    spanned::Spanned, Attribute, Data, DataStruct, DeriveInput, Error, Fields, GenericArgument,
+    Ident, LitStr, PathArguments, Type,
+};
+
+/// Generates `with_{field}` setters for every named field of an options struct, bracketed by
+/// `builder()` and `build()` so the struct serves as its own builder.
+pub fn generate_setters(ast: &DeriveInput) -> Result<TokenStream> {
+    let name = &ast.ident;
+    let vis = &ast.vis;
+    let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
+
+    let fields = match &ast.data {
+        Data::Struct(DataStruct {
+            fields: Fields::Named(named),
+            ..
+        }) => &named.named,
+        _ => {
+            return Err(Error::new(
+                ast.ident.span(),
+                "CosmosSetters can only be derived for structs with named fields",
+            ))
+        }
+    };
+
+    let mut setters = Vec::new();
+    for field in fields {
+        let attrs = parse_setter_attrs(&field.attrs)?;
+        if attrs.skip {
+            continue;
+        }
+
+        let field_name = field.ident.as_ref().expect("named field");
+        let setter_name = attrs
+            .name
+            .unwrap_or_else(|| format_ident!("with_{}", field_name));
+        let doc = match attrs.doc {
+            Some(doc) => quote! { #[doc = #doc] },
+            None => {
+                let docs: Vec<&Attribute> = field
+                    .attrs
+                    .iter()
+                    .filter(|a| a.path().is_ident("doc"))
+                    .collect();
+                if docs.is_empty() {
+                    let doc = format!("Sets `{field_name}`.");
+                    quote! { #[doc = #doc] }
+                } else {
+                    quote! { #(#docs)* }
+                }
+            }
+        };
+
+        // `Option<T>` fields take a `T` and are wrapped in `Some`.
+        let (value_type, assign) = match extract_option_inner_type(&field.ty) {
+            Some(inner) => (inner, quote! { ::std::option::Option::Some(value) }),
+            None => (&field.ty, quote! { value }),
+        };
+        let (param_type, convert) = if attrs.into {
+            (
+                quote! { impl ::std::convert::Into<#value_type> },
+                quote! { let value = value.into(); },
+            )
+        } else {
+            (quote! { #value_type }, quote! {})
+        };
+        setters.push(quote! {
+            #doc
+            #vis fn #setter_name(mut self, value: #param_type) -> Self {
+                #convert
+                self.#field_name = #assign;
+                self
+            }
+        });
+    }
+
+    let builder_doc =
+        format!("Creates a default `{name}` to set options on with the `with_*` setters.");
+    let build_doc = format!("Returns the `{name}` the setters were applied to.");
+
+    Ok(quote! {
+        #[automatically_derived]
+        impl #impl_generics #name #ty_generics #where_clause {
+            #[doc = #builder_doc]
+            #vis fn builder() -> Self {
+                ::std::default::Default::default()
+            }
+
+            #(#setters)*
+
+            #[doc = #build_doc]
+            #[must_use]
+            #vis fn build(self) -> Self {
+                self
+            }
+        }
+    })
+}
+
+#[derive(Default)]
+struct SetterAttrs {
+    skip: bool,
+    into: bool,
+    name: Option<Ident>,
+    doc: Option<String>,
+}
+
+fn parse_setter_attrs(attrs: &[Attribute]) -> Result<SetterAttrs> {
+    let mut result = SetterAttrs::default();
+    for attr in attrs.iter().filter(|a| a.path().is_ident("setter")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("skip") {
+                result.skip = true;
+            } else if meta.path.is_ident("into") {
+                result.into = true;
+            } else if meta.path.is_ident("name") {
+                let name: LitStr = meta.value()?.parse()?;
+                result.name = Some(name.parse()?);
+            } else if meta.path.is_ident("doc") {
+                let doc: LitStr = meta.value()?.parse()?;
+                result.doc = Some(doc.value());
+            } else {
+                return Err(
+                    meta.error("unknown setter attribute, expected one of: skip, into, name, doc")
+                );
+            }
+            Ok(())
+        })?;
+    }
+
+    if result.skip && (result.into || result.name.is_some() || result.doc.is_some()) {
+        return Err(Error::new(
+            attrs
+                .iter()
+                .find(|a| a.path().is_ident("setter"))
+                .map_or_else(proc_macro2::Span::call_site, |a| a.span()),
+            "`skip` cannot be combined with other setter attributes",
+        ));
+    }
+    Ok(result)
+}
+
+/// Extracts the inner type `T` from `Option<T>`.
+fn extract_option_inner_type(ty: &Type) -> Option<&Type> {
+    let Type::Path(type_path) = ty else {
+        return None;
+    };
+    let segment = type_path.path.segments.last()?;
+    if segment.ident != "Option" {
+        return None;
+    }
+    let PathArguments::AngleBracketed(args) = &segment.arguments else {
+        return None;
+    };
+    match args.args.first()? {
+        GenericArgument::Type(inner) => Some(inner),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn option_fields_take_inner_type() {
+        let ast: DeriveInput = syn::parse_quote! {
+            pub struct TestOptions {
+                pub(crate) throughput: Option<u32>,
+            }
+        };
+        let tokens = generate_setters(&ast).unwrap().to_string();
+        assert!(tokens.contains("fn with_throughput (mut self , value : u32)"));
+        assert!(tokens.contains("Some (value)"));
+    }
+
+    #[test]
+    fn builder_generated() {
+        let ast: DeriveInput = syn::parse_quote! {
+            pub struct TestOptions {
+                #[setter(into)]
+                pub(crate) session_token: Option<String>,
+            }
+        };
+        let tokens = generate_setters(&ast).unwrap().to_string();
+        assert!(tokens.contains("pub fn builder () -> Self"));
+        assert!(tokens.contains("pub fn build (self) -> Self { self }"));
+        assert!(!tokens.contains("TestOptionsBuilder"));
+    }
+
+    #[test]
+    fn skip_conflicts_with_name() {
+        let ast: DeriveInput = syn::parse_quote! {
+            pub struct TestOptions {
+                #[setter(skip, name = "with_value")]
+                value: Option<u32>,
+            }
+        };
+        let err = generate_setters(&ast).err().expect("expected error");
+        assert!(err.to_string().contains("cannot be combined"));
+    }
+
+    #[test]
+    fn rejects_tuple_structs() {
+        let ast: DeriveInput = syn::parse_quote! {
+            pub struct TestOptions(Option<u32>);
+        };
+        assert!(generate_setters(&ast).is_err());
+    }
+}
@@ -0,0 +1,65 @@
+// Copyright (c) Microsoft Corporation. All rights reserved.
+// Licensed under the MIT License.
+
+//! Integration tests for `#[derive(CosmosSetters)]`.
+
+use azure_data_cosmos_macros::CosmosSetters;
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct OperationOptions {
+    pub priority: Option<u8>,
+}
+
+#[derive(Clone, Debug, Default, CosmosSetters)]
+pub struct ItemOptions {
+    /// Sets the [`OperationOptions`] for this request.
+    #[setter(name = "with_operation_options")]
+    pub operation: OperationOptions,
+
+    pub(crate) if_match: Option<String>,
+
+    #[setter(into)]
+    pub(crate) session_token: Option<String>,
+
+    pub(crate) content_response_on_write: bool,
+
+    #[setter(skip)]
+    pub(crate) internal: Option<u32>,
+}
+
+#[test]
+fn setters_chain() {
+    let options = ItemOptions::default()
+        .with_operation_options(OperationOptions { priority: Some(1) })
+        .with_if_match("\"etag\"".to_string())
+        .with_session_token("0:1#2")
+        .with_content_response_on_write(true);
+
+    assert_eq!(OperationOptions { priority: Some(1) }, options.operation);
+    assert_eq!(Some("\"etag\""), options.if_match.as_deref());
+    assert_eq!(Some("0:1#2"), options.session_token.as_deref());
+    assert!(options.content_response_on_write);
+    assert_eq!(None, options.internal);
+}
+
+#[test]
+fn builder_builds_options() {
+    let options = ItemOptions::builder()
+        .with_operation_options(OperationOptions { priority: Some(1) })
+        .with_session_token("0:1#2")
+        .with_content_response_on_write(true)
+        .build();
+
+    assert_eq!(OperationOptions { priority: Some(1) }, options.operation);
+    assert_eq!(None, options.if_match);
+    assert_eq!(Some("0:1#2"), options.session_token.as_deref());
+    assert!(options.content_response_on_write);
+}
+
+#[test]
+fn later_setter_overwrites() {
+    let options = ItemOptions::default()
+        .with_session_token("first")
+        .with_session_token("second");
+    assert_eq!(Some("second"), options.session_token.as_deref());
+}
@@ -586,7 +586,7 @@ mod tests {
         let connection_manager = RecoverableConnection::new(
             url,
             None,
-            None,
+            Default::default(),
             mock_credential.clone(),
             Default::default(),
             None,
@@ -652,7 +652,7 @@ mod tests {
         let connection_manager = RecoverableConnection::new(
             url,
             None,
-            None,
+            Default::default(),
             mock_credential.clone(),
             Default::default(),
             None,
@@ -722,7 +722,7 @@ mod tests {
         let recoverable_connection = Arc::new(RecoverableConnection::new(
             host.clone(),
             None,
-            None,
+            Default::default(),
             mock_credential.clone(),
             Default::default(),
             None,
@@ -853,7 +853,7 @@ mod tests {
         let connection = RecoverableConnection::new(
             url.clone(),
             None,
-            None,
+            Default::default(),
             credential.clone(),
             Default::default(),
             None,
@@ -949,7 +949,7 @@ mod tests {
         let connection = RecoverableConnection::new(
             url.clone(),
             None,
-            None,
+            Default::default(),
             credential.clone(),
             Default::default(),
             None,
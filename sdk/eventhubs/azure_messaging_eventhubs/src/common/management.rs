@@ -1,6 +1,8 @@
 // Copyright (c) Microsoft Corporation. All Rights reserved
 // Licensed under the MIT license.
 
+// cspell:ignore georeplication
+
 use super::recoverable::RecoverableConnection;
 use crate::{
     error::{ErrorKind, EventHubsError, Result},
@@ -36,6 +38,7 @@ const EVENTHUB_PROPERTY_PARTITION_IDS: &str = "partition_ids";
 const EVENTHUB_PROPERTY_NAME: &str = "name";
 const EVENTHUB_PROPERTY_PARTITION: &str = "partition";
 const EVENTHUB_PROPERTY_CREATED_AT: &str = "created_at";
+const EVENTHUB_PROPERTY_GEO_REPLICATION_FACTOR: &str = "georeplication_factor";
 
 const EVENTHUB_PARTITION_PROPERTIES_TYPE: &str = "type";
 const EVENTHUB_PARTITION_PROPERTIES_LAST_ENQUEUED_SEQUENCE_NUMBER_EPOCH: &str =
@@ -137,10 +140,19 @@ impl ManagementInstance {
                 return Err(EventHubsError::from(ErrorKind::InvalidManagementResponse));
             }
         };
+        // Only geo-replicated namespaces report a replication factor.
+        let geo_replication_factor = match response.get(EVENTHUB_PROPERTY_GEO_REPLICATION_FACTOR) {
+            Some(AmqpValue::Int(factor)) => i64::from(*factor),
+            Some(AmqpValue::Long(factor)) => *factor,
+            Some(AmqpValue::UInt(factor)) => i64::from(*factor),
+            _ => 0,
+        };
+
         Ok(EventHubProperties {
             name,
             created_on: created_at,
             partition_ids,
+            geo_replication_enabled: geo_replication_factor > 1,
         })
     }
 
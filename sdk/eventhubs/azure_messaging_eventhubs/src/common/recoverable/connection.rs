@@ -10,7 +10,7 @@ use super::{
 use crate::{
     common::{
         authorizer::Authorizer,
-        retry::ErrorRecoveryAction,
+        retry::{ErrorRecoveryAction, RedirectTarget},
         user_agent::{get_package_name, get_package_version, get_platform_info, get_user_agent},
     },
     error::Result,
@@ -23,15 +23,13 @@ use azure_core::{credentials::TokenCredential, http::Url, time::Duration, Uuid};
 use azure_core_amqp::{
     error::{AmqpErrorCondition, AmqpErrorKind},
     AmqpClaimsBasedSecurity, AmqpConnection, AmqpConnectionApis, AmqpConnectionOptions, AmqpError,
-    AmqpManagement, AmqpManagementApis, AmqpReceiver, AmqpReceiverApis, AmqpReceiverOptions,
-    AmqpSender, AmqpSenderApis, AmqpSession, AmqpSessionApis, AmqpSessionOptions, AmqpSource,
-    AmqpSymbol,
+    AmqpManagement, AmqpManagementApis, AmqpOrderedMap, AmqpReceiver, AmqpReceiverApis,
+    AmqpReceiverOptions, AmqpSender, AmqpSenderApis, AmqpSession, AmqpSessionApis,
+    AmqpSessionOptions, AmqpSource, AmqpSymbol, AmqpTransportType,
 };
-#[cfg(test)]
-use std::sync::Mutex;
 use std::{
     collections::HashMap,
-    sync::{Arc, Weak},
+    sync::{Arc, Mutex, Weak},
 };
 use tracing::{debug, info, instrument, trace, warn};
 
@@ -41,6 +39,43 @@ use tracing::{debug, info, instrument, trace, warn};
 /// scenarios for high availability.
 const GEODR_REPLICATION_CAPABILITY: &str = "com.microsoft.georeplication";
 
+/// Keys of the `info` map of an `amqp:connection:redirect` or `amqp:link:redirect` error
+/// which identify the host the client should connect to instead.
+const REDIRECT_NETWORK_HOST: &str = "network-host";
+const REDIRECT_HOSTNAME: &str = "hostname";
+const REDIRECT_PORT: &str = "port";
+
+/// Settings which control how the AMQP connection reaches the Event Hubs service.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct TransportOptions {
+    /// Endpoint to connect to instead of the namespace, such as a local AMQP proxy.
+    pub custom_endpoint: Option<Url>,
+    /// Transport used to carry AMQP traffic.
+    pub transport_type: AmqpTransportType,
+    /// HTTP proxy used when the transport is [`AmqpTransportType::AmqpWebSockets`].
+    pub proxy: Option<Url>,
+}
+
+impl TransportOptions {
+    /// Parses the endpoint and proxy strings collected by the client builders.
+    pub(crate) fn parse(
+        custom_endpoint: Option<String>,
+        transport_type: Option<AmqpTransportType>,
+        proxy: Option<String>,
+    ) -> Result<Self> {
+        let parse = |url: Option<String>| -> Result<Option<Url>> {
+            url.map(|url| Url::parse(&url).map_err(azure_core::Error::from))
+                .transpose()
+                .map_err(Into::into)
+        };
+        Ok(Self {
+            custom_endpoint: parse(custom_endpoint)?,
+            transport_type: transport_type.unwrap_or_default(),
+            proxy: parse(proxy)?,
+        })
+    }
+}
+
 /// The recoverable connection is responsible for managing the connection to the Event Hubs service.
 /// It also handles authorization and connection recovery.
 ///
@@ -74,7 +109,7 @@ const GEODR_REPLICATION_CAPABILITY: &str = "com.microsoft.georeplication";
 pub(crate) struct RecoverableConnection {
     pub(super) url: Url,
     application_id: Option<String>,
-    custom_endpoint: Option<Url>,
+    transport: TransportOptions,
     mgmt_client: AsyncMutex<Option<Arc<AmqpManagement>>>,
     // The sender, session, and receiver caches are keyed by path. Each entry is
     // an independently-initialized `OnceCell`, so concurrent operations on
@@ -86,6 +121,10 @@ pub(crate) struct RecoverableConnection {
     receiver_instances: RwLock<HashMap<Url, Arc<OnceCell<Arc<AmqpReceiver>>>>>,
     pub(super) authorizer: Arc<Authorizer>,
     connections: AsyncMutex<Option<Arc<AmqpConnection>>>,
+    // The endpoint the service redirected the connection to, such as the promoted
+    // secondary of a geo-replicated namespace. Used instead of the configured
+    // endpoint until the service redirects the connection again.
+    redirect: Mutex<Option<RedirectTarget>>,
     connection_name: String,
     pub(super) retry_options: RetryOptions,
 
@@ -138,7 +177,8 @@ impl RecoveryPlan {
     /// should never reach `recover_from_error`).
     fn for_action(action: &ErrorRecoveryAction) -> Option<Self> {
         match action {
-            ErrorRecoveryAction::ReconnectConnection => Some(Self {
+            ErrorRecoveryAction::ReconnectConnection
+            | ErrorRecoveryAction::RedirectConnection(_) => Some(Self {
                 drop_connection: true,
                 clear_authorizer: true,
                 clear_sessions: true,
@@ -174,7 +214,7 @@ impl RecoverableConnection {
     pub fn new(
         url: Url,
         application_id: Option<String>,
-        custom_endpoint: Option<Url>,
+        transport: TransportOptions,
         credential: Arc<dyn TokenCredential>,
         retry_options: RetryOptions,
         cbs_token_type: Option<&'static str>,
@@ -190,9 +230,10 @@ impl RecoverableConnection {
                 url,
                 application_id,
                 connection_name,
-                custom_endpoint,
+                transport,
                 retry_options,
                 connections: AsyncMutex::new(None),
+                redirect: Mutex::new(None),
                 session_instances: RwLock::new(HashMap::new()),
                 sender_instances: RwLock::new(HashMap::new()),
                 receiver_instances: RwLock::new(HashMap::new()),
@@ -240,6 +281,52 @@ impl RecoverableConnection {
         &self.connection_name
     }
 
+    /// Returns the target of the latest redirect, if the service redirected the connection.
+    fn redirect(&self) -> Option<RedirectTarget> {
+        self.redirect
+            .lock()
+            .expect("Redirect lock is poisoned")
+            .clone()
+    }
+
+    /// Builds the target named by the `info` map of a redirect error.
+    ///
+    /// `hostname` names the host the connection is opened against, used for the URL, TLS server
+    /// name and AMQP hostname. `network-host` optionally names a different address to connect
+    /// to, and is used as the host when `hostname` is missing. `port` optionally names the port
+    /// of both.
+    fn redirect_target(info: &AmqpOrderedMap<AmqpSymbol, AmqpValue>) -> Option<RedirectTarget> {
+        let host = |key: &str| match info.get(key) {
+            Some(AmqpValue::String(host)) if !host.is_empty() => Some(host.as_str()),
+            Some(AmqpValue::Symbol(host)) if !host.0.is_empty() => Some(host.0.as_str()),
+            _ => None,
+        };
+        let port = match info.get(REDIRECT_PORT) {
+            Some(AmqpValue::UShort(port)) => Some(*port),
+            Some(AmqpValue::UInt(port)) => u16::try_from(*port).ok(),
+            Some(AmqpValue::Int(port)) => u16::try_from(*port).ok(),
+            _ => None,
+        };
+        let url = |host: &str| {
+            let endpoint = match port {
+                Some(port) => format!("amqps://{host}:{port}"),
+                None => format!("amqps://{host}"),
+            };
+            Url::parse(&endpoint).ok()
+        };
+
+        let hostname = host(REDIRECT_HOSTNAME);
+        let network_host = host(REDIRECT_NETWORK_HOST);
+        let endpoint = url(hostname.or(network_host)?)?;
+        let network_endpoint = network_host
+            .filter(|network_host| hostname.is_some_and(|hostname| hostname != *network_host))
+            .and_then(url);
+        Some(RedirectTarget {
+            endpoint,
+            network_endpoint,
+        })
+    }
+
     /// Closes the connection to the Event Hubs service.
     ///
     /// # Notes
@@ -534,10 +621,20 @@ impl RecoverableConnection {
         );
         let connection = Arc::new(AmqpConnection::new());
 
+        // After a redirect, connect to the redirect target instead of the namespace, bypassing
+        // any configured custom endpoint.
+        let (url, custom_endpoint, network_endpoint) = match self.redirect() {
+            Some(redirect) => (redirect.endpoint, None, redirect.network_endpoint),
+            None => (
+                self.url.clone(),
+                self.transport.custom_endpoint.clone(),
+                None,
+            ),
+        };
         connection
             .open(
                 self.connection_name.clone(),
-                self.url.clone(),
+                url,
                 Some(AmqpConnectionOptions {
                     properties: Some(
                         vec![
@@ -551,7 +648,10 @@ impl RecoverableConnection {
                         .collect(),
                     ),
                     desired_capabilities: Some(vec![GEODR_REPLICATION_CAPABILITY.into()]),
-                    custom_endpoint: self.custom_endpoint.clone(),
+                    custom_endpoint,
+                    network_endpoint,
+                    transport_type: Some(self.transport.transport_type),
+                    proxy: self.transport.proxy.clone(),
                     ..Default::default()
                 }),
             )
@@ -777,6 +877,19 @@ impl RecoverableConnection {
             )));
         };
 
+        if let ErrorRecoveryAction::RedirectConnection(redirect) = &reason {
+            info!(
+                connection_id = %connection_id,
+                endpoint = %redirect.endpoint,
+                network_endpoint = ?redirect.network_endpoint,
+                "Service redirected the connection to a new endpoint."
+            );
+            *connection
+                .redirect
+                .lock()
+                .expect("Redirect lock is poisoned") = Some(redirect.clone());
+        }
+
         debug!(
             connection_id = %connection_id,
             reason = ?reason,
@@ -920,6 +1033,30 @@ impl RecoverableConnection {
                         "AMQP described error requires reconnect."
                     );
                     ErrorRecoveryAction::ReconnectConnection
+                } else if matches!(
+                    described_error.condition,
+                    AmqpErrorCondition::ConnectionRedirect | AmqpErrorCondition::LinkRedirect
+                ) {
+                    // The namespace moved, for instance when the secondary of a geo-replicated
+                    // namespace is promoted. Reconnect to the host named by the redirect, or to
+                    // the configured endpoint again if the service didn't name one.
+                    match Self::redirect_target(&described_error.info) {
+                        Some(redirect) => {
+                            info!(
+                                condition = ?described_error.condition,
+                                endpoint = %redirect.endpoint,
+                                "AMQP redirect, will reconnect to the redirected endpoint."
+                            );
+                            ErrorRecoveryAction::RedirectConnection(redirect)
+                        }
+                        None => {
+                            warn!(
+                                condition = ?described_error.condition,
+                                "AMQP redirect without a target host, will reconnect."
+                            );
+                            ErrorRecoveryAction::ReconnectConnection
+                        }
+                    }
                 } else if matches!(
                     described_error.condition,
                     AmqpErrorCondition::UnauthorizedAccess
@@ -1074,7 +1211,7 @@ mod tests {
         let connection_manager = RecoverableConnection::new(
             url,
             None,
-            None,
+            Default::default(),
             Arc::new(MockCredential),
             Default::default(),
             None,
@@ -1097,7 +1234,7 @@ mod tests {
         let connection_manager = RecoverableConnection::new(
             url,
             Some(app_id.clone()),
-            None,
+            Default::default(),
             Arc::new(MockCredential),
             Default::default(),
             None,
@@ -1117,7 +1254,7 @@ mod tests {
         let connection_manager = Arc::new(RecoverableConnection::new(
             url.clone(),
             None,
-            None,
+            Default::default(),
             Arc::new(MockCredential),
             Default::default(),
             None,
@@ -1137,7 +1274,7 @@ mod tests {
         let connection = RecoverableConnection::new(
             url,
             None,
-            None,
+            Default::default(),
             Arc::new(MockCredential),
             Default::default(),
             None,
@@ -1185,13 +1322,41 @@ mod tests {
         let connection_manager = RecoverableConnection::new(
             url,
             None,
-            Some(custom_endpoint.clone()),
+            TransportOptions {
+                custom_endpoint: Some(custom_endpoint.clone()),
+                ..Default::default()
+            },
             Arc::new(MockCredential),
             Default::default(),
             None,
         );
 
-        assert_eq!(connection_manager.custom_endpoint, Some(custom_endpoint));
+        assert_eq!(
+            connection_manager.transport.custom_endpoint,
+            Some(custom_endpoint)
+        );
+        assert_eq!(
+            connection_manager.transport.transport_type,
+            AmqpTransportType::AmqpTcp
+        );
+    }
+
+    #[test]
+    fn transport_options_parse() {
+        let transport = TransportOptions::parse(
+            None,
+            Some(AmqpTransportType::AmqpWebSockets),
+            Some("http://proxy:3128".to_string()),
+        )
+        .unwrap();
+        assert_eq!(transport.custom_endpoint, None);
+        assert_eq!(transport.transport_type, AmqpTransportType::AmqpWebSockets);
+        assert_eq!(
+            transport.proxy,
+            Some(Url::parse("http://proxy:3128").unwrap())
+        );
+
+        assert!(TransportOptions::parse(None, None, Some("not a url".to_string())).is_err());
     }
 
     #[test]
@@ -1509,4 +1674,118 @@ mod tests {
         assert!(RecoveryPlan::for_action(&ErrorRecoveryAction::RetryAction).is_none());
         assert!(RecoveryPlan::for_action(&ErrorRecoveryAction::ReturnError).is_none());
     }
+
+    fn redirect_error(condition: AmqpErrorCondition, info: Vec<(&str, AmqpValue)>) -> AmqpError {
+        AmqpError::from(AmqpErrorKind::AmqpDescribedError(
+            azure_core_amqp::AmqpDescribedError::new(
+                condition,
+                None,
+                info.into_iter()
+                    .map(|(k, v)| (AmqpSymbol::from(k), v))
+                    .collect(),
+            ),
+        ))
+    }
+
+    #[test]
+    fn redirect_reconnects_to_redirected_endpoint() {
+        let err = redirect_error(
+            AmqpErrorCondition::ConnectionRedirect,
+            vec![
+                (
+                    "hostname",
+                    AmqpValue::from("secondary.servicebus.windows.net"),
+                ),
+                ("network-host", AmqpValue::from("10.0.0.4")),
+                ("port", AmqpValue::UShort(5671)),
+            ],
+        );
+        // The hostname is the URL, TLS server name and AMQP hostname; the network host is
+        // only the address connected to.
+        assert_eq!(
+            RecoverableConnection::should_retry_amqp_error(&err),
+            ErrorRecoveryAction::RedirectConnection(RedirectTarget {
+                endpoint: Url::parse("amqps://secondary.servicebus.windows.net:5671").unwrap(),
+                network_endpoint: Some(Url::parse("amqps://10.0.0.4:5671").unwrap()),
+            })
+        );
+
+        let err = redirect_error(
+            AmqpErrorCondition::LinkRedirect,
+            vec![(
+                "hostname",
+                AmqpValue::from("secondary.servicebus.windows.net"),
+            )],
+        );
+        assert_eq!(
+            RecoverableConnection::should_retry_amqp_error(&err),
+            ErrorRecoveryAction::RedirectConnection(RedirectTarget {
+                endpoint: Url::parse("amqps://secondary.servicebus.windows.net").unwrap(),
+                network_endpoint: None,
+            })
+        );
+
+        // A network host that matches the hostname, or names the only host, is connected to
+        // directly.
+        for info in [
+            vec![
+                ("hostname", AmqpValue::from("secondary.example.com")),
+                ("network-host", AmqpValue::from("secondary.example.com")),
+            ],
+            vec![("network-host", AmqpValue::from("secondary.example.com"))],
+        ] {
+            let err = redirect_error(AmqpErrorCondition::ConnectionRedirect, info);
+            assert_eq!(
+                RecoverableConnection::should_retry_amqp_error(&err),
+                ErrorRecoveryAction::RedirectConnection(RedirectTarget {
+                    endpoint: Url::parse("amqps://secondary.example.com").unwrap(),
+                    network_endpoint: None,
+                })
+            );
+        }
+
+        // Without a target host, reconnect to the configured endpoint.
+        let err = redirect_error(AmqpErrorCondition::ConnectionRedirect, Vec::new());
+        assert_eq!(
+            RecoverableConnection::should_retry_amqp_error(&err),
+            ErrorRecoveryAction::ReconnectConnection
+        );
+    }
+
+    #[tokio::test]
+    async fn recover_from_redirect_replaces_endpoint() {
+        let custom_endpoint = Url::parse("amqps://proxy.example.com").unwrap();
+        let connection = RecoverableConnection::new(
+            Url::parse("amqps://example.com").unwrap(),
+            None,
+            TransportOptions {
+                custom_endpoint: Some(custom_endpoint.clone()),
+                ..Default::default()
+            },
+            Arc::new(MockCredential),
+            Default::default(),
+            None,
+        );
+        assert_eq!(connection.redirect(), None);
+
+        let redirect = RedirectTarget {
+            endpoint: Url::parse("amqps://secondary.example.com:5671").unwrap(),
+            network_endpoint: Some(Url::parse("amqps://10.0.0.4:5671").unwrap()),
+        };
+        let plan =
+            RecoveryPlan::for_action(&ErrorRecoveryAction::RedirectConnection(redirect.clone()))
+                .expect("RedirectConnection has a recovery plan");
+        assert_eq!(
+            Some(plan),
+            RecoveryPlan::for_action(&ErrorRecoveryAction::ReconnectConnection)
+        );
+
+        RecoverableConnection::recover_from_error(
+            Arc::downgrade(&connection),
+            ErrorRecoveryAction::RedirectConnection(redirect.clone()),
+        )
+        .await
+        .expect("recovery succeeds");
+        assert_eq!(connection.redirect(), Some(redirect));
+    }
 }
@@ -94,17 +94,19 @@ impl RecoverableManagementClient {
     }
 }
 
-#[async_trait::async_trait]
-impl AmqpManagementApis for RecoverableManagementClient {
-    async fn call(
+impl RecoverableManagementClient {
+    /// Sends a management request, recovering the connection and retrying on failure.
+    async fn call_recoverable(
         &self,
         operation_type: String,
         application_properties: AmqpOrderedMap<String, AmqpSimpleValue>,
+        body: Option<AmqpOrderedMap<String, AmqpValue>>,
     ) -> Result<AmqpOrderedMap<String, AmqpValue>> {
         let result = recover_azure_operation(
             || {
                 let operation_type = operation_type.clone();
                 let application_properties = application_properties.clone();
+                let body = body.clone();
 
                 async move {
                     let connection = self
@@ -115,16 +117,23 @@ impl AmqpManagementApis for RecoverableManagementClient {
                     #[cfg(test)]
                     connection.get_forced_error()?;
 
-                    let result = connection
-                        .ensure_amqp_management()
-                        .await?
-                        .call(operation_type, application_properties)
-                        .await;
+                    let management = connection.ensure_amqp_management().await?;
+                    let result = match body {
+                        Some(body) => {
+                            management
+                                .call_with_body(operation_type, application_properties, body)
+                                .await
+                        }
+                        None => {
+                            management
+                                .call(operation_type, application_properties)
+                                .await
+                        }
+                    };
                     if let Err(ref e) = result {
                         trace!("Management call error: {:?}", e);
                     }
                     result
-                    //                    Ok(result)
                 }
             },
             &self
@@ -143,6 +152,28 @@ impl AmqpManagementApis for RecoverableManagementClient {
         .await?;
         Ok(result)
     }
+}
+
+#[async_trait::async_trait]
+impl AmqpManagementApis for RecoverableManagementClient {
+    async fn call(
+        &self,
+        operation_type: String,
+        application_properties: AmqpOrderedMap<String, AmqpSimpleValue>,
+    ) -> Result<AmqpOrderedMap<String, AmqpValue>> {
+        self.call_recoverable(operation_type, application_properties, None)
+            .await
+    }
+
+    async fn call_with_body(
+        &self,
+        operation_type: String,
+        application_properties: AmqpOrderedMap<String, AmqpSimpleValue>,
+        body: AmqpOrderedMap<String, AmqpValue>,
+    ) -> Result<AmqpOrderedMap<String, AmqpValue>> {
+        self.call_recoverable(operation_type, application_properties, Some(body))
+            .await
+    }
 
     async fn attach(&self) -> Result<()> {
         unimplemented!("AmqpManagementClient does not support attach operation");
@@ -7,5 +7,5 @@ mod management;
 mod receiver;
 mod sender;
 
-pub(crate) use connection::RecoverableConnection;
+pub(crate) use connection::{RecoverableConnection, TransportOptions};
 pub(crate) use sender::RecoverableSender;
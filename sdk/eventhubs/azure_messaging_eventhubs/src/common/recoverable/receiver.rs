@@ -7,6 +7,7 @@ use crate::common::retry::ErrorRecoveryAction;
 use azure_core::{error::ErrorKind as AzureErrorKind, http::Url, time::Duration};
 use azure_core_amqp::{
     error::Result, AmqpError, AmqpReceiverApis, AmqpReceiverOptions, AmqpSession, AmqpSource,
+    ReceiverCreditMode,
 };
 use futures::{select, FutureExt};
 use std::sync::Weak;
@@ -71,6 +72,10 @@ impl AmqpReceiverApis for RecoverableReceiver {
         unimplemented!("AmqpReceiverClient does not support credit_mode operation");
     }
 
+    async fn set_credit(&self, _credit: u32) -> Result<()> {
+        unimplemented!("AmqpReceiverClient does not support set_credit operation");
+    }
+
     // Hot per-event path: trace level and no `err` attribute to avoid per-delivery
     // error spam; carry only the partition source URL for correlation.
     #[instrument(level = "trace", skip_all, fields(source_url = %self.source_url))]
@@ -106,6 +111,14 @@ impl AmqpReceiverApis for RecoverableReceiver {
                             AmqpError::with_message(format!("Failed to ensure receiver: {e}"))
                         })?
                 };
+                // Manual-credit receivers request one event at a time. This runs on every
+                // attempt so that a link re-attached during recovery is credited as well.
+                if matches!(
+                    self.receiver_options.credit_mode,
+                    Some(ReceiverCreditMode::Manual)
+                ) {
+                    receiver.set_credit(1).await?;
+                }
                 if let Some(delivery_timeout) = self.timeout {
                     select! {
                         delivery = receiver.receive_delivery().fuse() => Ok(delivery),
@@ -3,7 +3,7 @@
 
 // cspell: ignore retryable backoff
 
-use azure_core::{sleep, time::Duration};
+use azure_core::{http::Url, sleep, time::Duration};
 use rand::random;
 use std::{fmt::Debug, pin::Pin};
 use tracing::{debug, info, warn};
@@ -23,6 +23,9 @@ pub(crate) enum ErrorRecoveryAction {
     RetryAction,
     /// Error requires reconnecting the Connection, Session and Link.
     ReconnectConnection,
+    /// The service redirected the Connection to another endpoint; reconnect the
+    /// Connection, Session and Link against that endpoint.
+    RedirectConnection(RedirectTarget),
     /// Error requires reconnecting the Session and Link
     ReconnectSession,
     /// Error requires reconnecting the Link
@@ -31,6 +34,17 @@ pub(crate) enum ErrorRecoveryAction {
     ReturnError,
 }
 
+/// The endpoint named by an AMQP redirect.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub(crate) struct RedirectTarget {
+    /// The endpoint to open the Connection against. Its host, the redirect's `hostname`, is the
+    /// TLS server name and the AMQP hostname.
+    pub endpoint: Url,
+    /// The address to connect to, from the redirect's `network-host`, when it differs from
+    /// `endpoint`.
+    pub network_endpoint: Option<Url>,
+}
+
 /// Options for configuring exponential backoff retry behavior.
 #[derive(Debug, Clone)]
 pub struct RetryOptions {
@@ -5,6 +5,7 @@ use crate::{
     common::recoverable::RecoverableConnection,
     error::{ErrorKind, EventHubsError, Result},
     models::ReceivedEventData,
+    StartLocation, StartPosition,
 };
 use async_stream::try_stream;
 use azure_core::{http::Url, time::Duration};
@@ -15,7 +16,7 @@ use azure_core_amqp::{
 use futures::Stream;
 use std::sync::{
     atomic::{AtomicBool, Ordering},
-    Arc,
+    Arc, Mutex,
 };
 use tracing::{debug, trace, warn, Instrument};
 
@@ -106,6 +107,10 @@ pub struct EventReceiver {
     source_url: Url,
     partition_id: String,
     timeout: Option<Duration>,
+    // Sequence number of the last event yielded by `stream_events()`. If the link has to be
+    // re-attached, the receiver resumes after this event rather than at the original start
+    // position, so recovery neither replays nor skips events.
+    last_sequence_number: Mutex<Option<i64>>,
     // Set by `request_close()` to terminate `stream_events()` even if
     // `close_receiver` could not detach by-value because an in-flight
     // receive holds a strong Arc on the AMQP receiver.
@@ -128,10 +133,30 @@ impl EventReceiver {
             message_source,
             partition_id,
             timeout,
+            last_sequence_number: Mutex::new(None),
             closed: AtomicBool::new(false),
         }
     }
 
+    /// The source used to (re-)attach the receiver link: the original start position until an
+    /// event has been received, and just after the last received event from then on.
+    fn message_source(&self) -> AmqpSource {
+        let last_sequence_number = *self
+            .last_sequence_number
+            .lock()
+            .unwrap_or_else(|e| e.into_inner());
+        match last_sequence_number {
+            Some(sequence_number) => StartPosition::message_source(
+                &self.source_url,
+                &Some(StartPosition {
+                    location: StartLocation::SequenceNumber(sequence_number),
+                    inclusive: false,
+                }),
+            ),
+            None => self.message_source.clone(),
+        }
+    }
+
     /// Returns the partition ID of the receiver.
     pub fn partition_id(&self) -> &str {
         &self.partition_id
@@ -200,7 +225,7 @@ impl EventReceiver {
                 // receive loop is parented under it on every poll (see the span
                 // construction above for why this is not a fn-level attribute).
                 let receiver = self.connection.get_receiver(&self.source_url,
-                    self.message_source.clone(),
+                    self.message_source(),
                     self.receiver_options.clone(),
                     self.timeout
                 ).instrument(span.clone()).await?;
@@ -214,6 +239,12 @@ impl EventReceiver {
                 // Now that we have a delivery, we can process it.
                 let message = delivery.into_message();
                 let message = ReceivedEventData::from(message);
+                if let Some(sequence_number) = message.sequence_number() {
+                    *self
+                        .last_sequence_number
+                        .lock()
+                        .unwrap_or_else(|e| e.into_inner()) = Some(sequence_number);
+                }
                 // SENSITIVE-DATA: `{:?}` on a ReceivedEventData dumps the
                 // raw AMQP message, including the customer payload body and any PII in
                 // application properties. This is redacted by the SafeDebug derive ONLY
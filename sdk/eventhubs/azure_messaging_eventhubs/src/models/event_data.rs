@@ -3,7 +3,10 @@
 
 use crate::models::{AmqpMessage, AmqpSimpleValue, AmqpValue, MessageId};
 use azure_core::fmt::SafeDebug;
-use azure_core_amqp::message::{AmqpAnnotationKey, AmqpMessageBody, AmqpMessageProperties};
+use azure_core_amqp::{
+    message::{AmqpAnnotationKey, AmqpMessageBody, AmqpMessageProperties},
+    FromAmqpSimpleValue,
+};
 use std::{
     collections::HashMap,
     fmt::{Debug, Formatter},
@@ -63,6 +66,27 @@ impl EventData {
         self.properties.as_ref()
     }
 
+    /// The value of the application property `name`, converted to `T`.
+    ///
+    /// Returns `None` if the event has no such property, or if the property holds a value of a
+    /// different AMQP type (for instance, an `i32` property read as `i64`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use azure_messaging_eventhubs::models::EventData;
+    ///
+    /// let event_data = EventData::builder()
+    ///     .add_property("count".to_string(), 3i32)
+    ///     .build();
+    ///
+    /// assert_eq!(event_data.property::<i32>("count"), Some(3));
+    /// assert_eq!(event_data.property::<String>("count"), None);
+    /// ```
+    pub fn property<T: FromAmqpSimpleValue>(&self, name: &str) -> Option<T> {
+        self.properties.as_ref()?.get(name)?.get()
+    }
+
     /// The body of the event.
     pub fn body(&self) -> Option<&[u8]> {
         self.body.as_deref()
@@ -448,6 +472,25 @@ mod tests {
         assert_eq!(event_data.body().unwrap(), &body);
     }
 
+    #[test]
+    fn test_event_data_typed_property() {
+        let event_data = EventData::builder()
+            .add_property("name".to_string(), "value")
+            .add_property("count".to_string(), 42u64)
+            .add_property("enabled".to_string(), true)
+            .build();
+
+        assert_eq!(
+            event_data.property::<String>("name"),
+            Some("value".to_string())
+        );
+        assert_eq!(event_data.property::<u64>("count"), Some(42));
+        assert_eq!(event_data.property::<bool>("enabled"), Some(true));
+        assert_eq!(event_data.property::<i32>("count"), None);
+        assert_eq!(event_data.property::<bool>("missing"), None);
+        assert_eq!(EventData::default().property::<bool>("enabled"), None);
+    }
+
     #[test]
     fn test_event_data_builder_with_content_type() {
         let content_type = "application/json".to_string();
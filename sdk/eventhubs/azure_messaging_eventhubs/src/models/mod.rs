@@ -17,6 +17,13 @@ pub use azure_core_amqp::AmqpValue;
 /// the `Map`, `List`, `Array`, and `Described` types).
 pub use azure_core_amqp::AmqpSimpleValue;
 
+/// A type which can be read from an [`AmqpSimpleValue`], such as an application property of an
+/// event. See [`EventData::property`].
+pub use azure_core_amqp::FromAmqpSimpleValue;
+
+/// The transport used to carry AMQP traffic to and from the Event Hubs service.
+pub use azure_core_amqp::AmqpTransportType;
+
 /// An event received from an Event Hub.
 pub use event_data::ReceivedEventData;
 
@@ -43,6 +50,7 @@ use std::time::SystemTime;
 /// - `name`: A `String` representing the name of the Event Hubs instance.
 /// - `created_on`: An `Option<std::time::SystemTime>` representing the UTC time when the Event Hubs instance was created.
 /// - `partition_ids`: A `Vec<String>` containing the unique identifiers of the partitions in the Event Hubs instance.
+/// - `geo_replication_enabled`: A `bool` indicating whether the namespace replicates the Event Hubs instance to other regions.
 ///
 /// # Examples
 ///
@@ -74,6 +82,12 @@ pub struct EventHubProperties {
 
     /// The unique identifiers of the partitions in the Event Hub.
     pub partition_ids: Vec<String>,
+
+    /// Indicates whether the namespace replicates the Event Hub to other regions.
+    ///
+    /// When it does, the clients follow the namespace to its promoted secondary after a
+    /// failover without being recreated.
+    pub geo_replication_enabled: bool,
 }
 
 /// Represents the properties of an Event Hubs partition.
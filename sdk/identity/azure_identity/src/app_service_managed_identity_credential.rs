@@ -49,11 +49,13 @@ impl AppServiceManagedIdentityCredential {
             credential: ImdsManagedIdentityCredential::new(
                 endpoint,
                 API_VERSION,
+                "mi_res_id",
                 SECRET_HEADER,
                 SECRET_ENV,
                 id,
                 client_options,
                 None,
+                None,
                 env,
             ),
         }))
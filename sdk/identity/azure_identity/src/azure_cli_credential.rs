@@ -5,7 +5,7 @@ use crate::{
     authentication_error,
     env::Env,
     process::{new_executor, shell_exec, Executor, OutputProcessor},
-    validate_scope, validate_subscription, validate_tenant_id,
+    resolve_tenant_id, validate_scope, validate_subscription, validate_tenant_id,
 };
 use azure_core::{
     credentials::{AccessToken, Secret, TokenCredential, TokenRequestOptions},
@@ -69,6 +69,7 @@ pub struct AzureCliCredential {
     executor: Arc<dyn Executor>,
     subscription: Option<String>,
     tenant_id: Option<String>,
+    additionally_allowed_tenants: Vec<String>,
 }
 
 impl fmt::Debug for AzureCliCredential {
@@ -92,6 +93,10 @@ pub struct AzureCliCredentialOptions {
     /// Defaults to the CLI's default tenant, which is typically the home tenant of the logged in user.
     pub tenant_id: Option<String>,
 
+    /// Tenants, in addition to the configured tenant, for which the credential may acquire tokens.
+    /// See [`ALL_TENANTS`](crate::ALL_TENANTS) for how to request them.
+    pub additionally_allowed_tenants: Vec<String>,
+
     /// An implementation of [`Executor`] to run commands asynchronously.
     ///
     /// If `None`, one is created using [`new_executor`]; alternatively,
@@ -130,6 +135,7 @@ impl AzureCliCredential {
             executor: options.executor.unwrap_or(new_executor()),
             subscription: options.subscription,
             tenant_id: options.tenant_id,
+            additionally_allowed_tenants: options.additionally_allowed_tenants,
         }))
     }
 }
@@ -140,7 +146,7 @@ impl TokenCredential for AzureCliCredential {
     async fn get_token(
         &self,
         scopes: &[&str],
-        _: Option<TokenRequestOptions<'_>>,
+        options: Option<TokenRequestOptions<'_>>,
     ) -> azure_core::Result<AccessToken> {
         if scopes.is_empty() {
             return Err(Error::new(
@@ -149,10 +155,16 @@ impl TokenCredential for AzureCliCredential {
             ));
         }
         validate_scope(scopes[0])?;
+        let tenant_id = resolve_tenant_id(
+            self.tenant_id.as_deref(),
+            options.as_ref(),
+            &self.additionally_allowed_tenants,
+        )?
+        .or(self.tenant_id.as_deref());
 
         let mut command = OsString::from("az account get-access-token -o json --scope ");
         command.push(scopes[0]);
-        if let Some(ref tenant_id) = self.tenant_id {
+        if let Some(tenant_id) = tenant_id {
             command.push(" --tenant ");
             command.push(tenant_id);
         }
@@ -173,7 +185,9 @@ impl TokenCredential for AzureCliCredential {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::tests::{MockExecutor, FAKE_TENANT_ID, FAKE_TOKEN, LIVE_TEST_SCOPES};
+    use crate::tests::{
+        with_tenant_id, MockExecutor, FAKE_TENANT_ID, FAKE_TOKEN, LIVE_TEST_SCOPES,
+    };
     use std::ffi::OsStr;
     use time::{format_description::well_known::Rfc3339, UtcOffset};
 
@@ -229,6 +243,7 @@ mod tests {
                 )),
                 tenant_id,
                 subscription,
+                ..Default::default()
             };
         let cred = AzureCliCredential::new(Some(options))?;
         return cred.get_token(LIVE_TEST_SCOPES, None).await;
@@ -339,4 +354,36 @@ mod tests {
         assert_eq!(UtcOffset::UTC, token.expires_on.offset());
         assert_eq!(expires_on, token.expires_on);
     }
+
+    #[tokio::test]
+    async fn requested_tenant_id() {
+        let stdout = format!(
+            r#"{{"accessToken":"{FAKE_TOKEN}","expires_on":2147483647,"tokenType":"Bearer"}}"#
+        );
+        let credential = AzureCliCredential::new(Some(AzureCliCredentialOptions {
+            env: Some(Env::from(&[("SYSTEMROOT", "/dev/null")][..])),
+            executor: Some(MockExecutor::with_output(
+                0,
+                &stdout,
+                "",
+                Some(Arc::new(|_: &OsStr, args: &[&OsStr]| {
+                    assert!(args[1]
+                        .to_string_lossy()
+                        .contains(&format!(" --tenant {FAKE_TENANT_ID}")));
+                })),
+            )),
+            additionally_allowed_tenants: vec![FAKE_TENANT_ID.to_string()],
+            ..Default::default()
+        }))
+        .expect("valid credential");
+
+        credential
+            .get_token(LIVE_TEST_SCOPES, Some(with_tenant_id(FAKE_TENANT_ID)))
+            .await
+            .expect("token");
+        credential
+            .get_token(LIVE_TEST_SCOPES, Some(with_tenant_id("other-tenant")))
+            .await
+            .expect_err("tenant isn't allowed");
+    }
 }
@@ -5,7 +5,7 @@ use crate::{
     authentication_error,
     env::Env,
     process::{new_executor, shell_exec, Executor, OutputProcessor},
-    validate_scope, validate_tenant_id,
+    resolve_tenant_id, validate_scope, validate_tenant_id,
 };
 use azure_core::{
     credentials::{AccessToken, Secret, TokenCredential, TokenRequestOptions},
@@ -73,6 +73,7 @@ pub struct AzureDeveloperCliCredential {
     env: Env,
     executor: Arc<dyn Executor>,
     tenant_id: Option<String>,
+    additionally_allowed_tenants: Vec<String>,
 }
 
 impl fmt::Debug for AzureDeveloperCliCredential {
@@ -97,6 +98,10 @@ pub struct AzureDeveloperCliCredentialOptions {
     /// Defaults to the azd environment, which is the tenant of the selected Azure subscription.
     pub tenant_id: Option<String>,
 
+    /// Tenants, in addition to the configured tenant, for which the credential may acquire tokens.
+    /// See [`ALL_TENANTS`](crate::ALL_TENANTS) for how to request them.
+    pub additionally_allowed_tenants: Vec<String>,
+
     #[cfg(test)]
     pub(crate) env: Option<Env>,
 }
@@ -127,6 +132,7 @@ impl AzureDeveloperCliCredential {
             env,
             executor,
             tenant_id: options.tenant_id,
+            additionally_allowed_tenants: options.additionally_allowed_tenants,
         }))
     }
 }
@@ -136,7 +142,7 @@ impl TokenCredential for AzureDeveloperCliCredential {
     async fn get_token(
         &self,
         scopes: &[&str],
-        _: Option<TokenRequestOptions<'_>>,
+        options: Option<TokenRequestOptions<'_>>,
     ) -> azure_core::Result<AccessToken> {
         if scopes.is_empty() {
             return Err(Error::new(
@@ -144,13 +150,19 @@ impl TokenCredential for AzureDeveloperCliCredential {
                 "at least one scope required",
             ));
         }
+        let tenant_id = resolve_tenant_id(
+            self.tenant_id.as_deref(),
+            options.as_ref(),
+            &self.additionally_allowed_tenants,
+        )?
+        .or(self.tenant_id.as_deref());
         let mut command = OsString::from("azd auth token -o json --no-prompt");
         for scope in scopes {
             validate_scope(scope)?;
             command.push(" --scope ");
             command.push(scope);
         }
-        if let Some(ref tenant_id) = self.tenant_id {
+        if let Some(tenant_id) = tenant_id {
             command.push(" --tenant-id ");
             command.push(tenant_id);
         }
@@ -210,6 +222,7 @@ mod tests {
                 })),
             )),
             tenant_id,
+            ..Default::default()
         };
         let cred = AzureDeveloperCliCredential::new(Some(options))?;
         return cred.get_token(LIVE_TEST_SCOPES, None).await;
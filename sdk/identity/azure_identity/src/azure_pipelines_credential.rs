@@ -299,6 +299,7 @@ mod tests {
                     transport: Some(Transport::new(Arc::new(mock_client))),
                     ..Default::default()
                 },
+                ..Default::default()
             },
             env: Some(Env::from(
                 &[(OIDC_VARIABLE_NAME, "http://localhost/get_token")][..],
@@ -381,6 +382,7 @@ To troubleshoot, visit https://aka.ms/azsdk/rust/identity/troubleshoot#apc"#,
                     transport: Some(Transport::new(Arc::new(mock_client))),
                     ..Default::default()
                 },
+                ..Default::default()
             },
             env: Some(Env::from(
                 &[(OIDC_VARIABLE_NAME, "http://localhost/get_token")][..],
@@ -1,24 +1,78 @@
 // Copyright (c) Microsoft Corporation. All rights reserved.
 // Licensed under the MIT License.
 
-use async_lock::RwLock;
+use async_lock::{Mutex, RwLock};
 use azure_core::credentials::{AccessToken, TokenRequestOptions};
-use azure_core::time::{Duration, OffsetDateTime};
+use azure_core::random;
+use azure_core::time::{self, Duration, OffsetDateTime};
 use std::collections::HashMap;
 use std::future::Future;
-use tracing::trace;
+use std::sync::Arc;
+use tracing::{debug, trace};
 
-#[derive(Debug)]
-pub(crate) struct TokenCache(RwLock<HashMap<Vec<String>, AccessToken>>);
+/// How long before a token expires the cache starts refreshing it.
+const REFRESH_WINDOW: Duration = Duration::minutes(5);
+
+/// The maximum random delay added to the start of the refresh window, so that many clients
+/// sharing an identity don't all refresh at the same moment.
+const MAX_REFRESH_JITTER_SECONDS: u64 = 30;
+
+/// Caches tokens per set of scopes and tenant.
+///
+/// Tokens are returned from the cache until they enter the refresh window before they expire.
+/// A token in that window is refreshed by one caller while concurrent callers continue to get
+/// the cached token, and if that refresh fails, callers get the cached token until it expires.
+/// Concurrent callers missing a token for the same key share a single request for a new one.
+#[derive(Debug, Default)]
+pub(crate) struct TokenCache(std::sync::Mutex<HashMap<CacheKey, Arc<CacheEntry>>>);
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct CacheKey {
+    scopes: Vec<String>,
+    tenant_id: Option<String>,
+}
+
+#[derive(Debug, Default)]
+struct CacheEntry {
+    token: RwLock<Option<CachedToken>>,
+    /// Held by the caller requesting a new token so concurrent callers don't do the same.
+    refresh: Mutex<()>,
+}
+
+#[derive(Debug, Clone)]
+struct CachedToken {
+    token: AccessToken,
+    refresh_on: OffsetDateTime,
+}
+
+impl CachedToken {
+    fn new(token: AccessToken) -> Self {
+        let jitter = random::next_u64() % (MAX_REFRESH_JITTER_SECONDS + 1);
+        let refresh_on = token.expires_on - REFRESH_WINDOW - Duration::seconds(jitter as i64);
+        Self { token, refresh_on }
+    }
+
+    fn is_fresh(&self, now: OffsetDateTime) -> bool {
+        now < self.refresh_on
+    }
+
+    fn is_expired(&self, now: OffsetDateTime) -> bool {
+        now >= self.token.expires_on
+    }
+}
 
 impl TokenCache {
     pub(crate) fn new() -> Self {
-        Self(RwLock::new(HashMap::new()))
+        Self::default()
     }
 
+    /// Gets a token for `scopes` in `tenant_id` from the cache, calling `callback` to request one when necessary.
+    ///
+    /// `tenant_id` is `None` when the token is for the credential's configured tenant.
     pub(crate) async fn get_token<'a, C, F>(
         &self,
         scopes: &'a [&'a str],
+        tenant_id: Option<&str>,
         options: Option<TokenRequestOptions<'a>>,
         callback: C,
     ) -> azure_core::Result<AccessToken>
@@ -26,52 +80,72 @@ impl TokenCache {
         C: FnOnce(&'a [&'a str], Option<TokenRequestOptions<'a>>) -> F + Send,
         F: Future<Output = azure_core::Result<AccessToken>> + Send,
     {
-        let token_cache = self.0.read().await;
-        let scopes_owned = scopes.iter().map(ToString::to_string).collect::<Vec<_>>();
-        if let Some(token) = token_cache.get(&scopes_owned) {
-            if !should_refresh(token) {
+        let entry = self.entry(CacheKey {
+            scopes: scopes.iter().map(ToString::to_string).collect(),
+            tenant_id: tenant_id.map(ToString::to_string),
+        });
+
+        let cached = entry.token.read().await.clone();
+        let now = time::now_utc();
+        let _refresh = match cached {
+            Some(cached) if cached.is_fresh(now) => {
                 trace!("returning cached token");
-                return Ok(token.clone());
+                return Ok(cached.token);
             }
-        }
-
-        // otherwise, drop the read lock and get a write lock to refresh the token
-        drop(token_cache);
-        let mut token_cache = self.0.write().await;
+            Some(cached) if !cached.is_expired(now) => {
+                // The token is still valid, so if another caller is refreshing it, return it
+                // rather than waiting; otherwise, try to refresh it but fall back to it on failure.
+                let Some(refresh) = entry.refresh.try_lock() else {
+                    trace!("returning cached token while another caller refreshes it");
+                    return Ok(cached.token);
+                };
+                trace!("refreshing token before it expires");
+                return match callback(scopes, options).await {
+                    Ok(token) => {
+                        *entry.token.write().await = Some(CachedToken::new(token.clone()));
+                        drop(refresh);
+                        Ok(token)
+                    }
+                    Err(err) => {
+                        debug!("returning cached token after failing to refresh it: {err}");
+                        Ok(cached.token)
+                    }
+                };
+            }
+            _ => entry.refresh.lock().await,
+        };
 
-        // check again in case another thread refreshed the token while we were
-        // waiting on the write lock
-        if let Some(token) = token_cache.get(&scopes_owned) {
-            if !should_refresh(token) {
-                trace!("returning token that was updated while waiting on write lock");
-                return Ok(token.clone());
+        // check again in case another caller got a token while we were waiting on the lock
+        if let Some(cached) = entry.token.read().await.as_ref() {
+            if cached.is_fresh(time::now_utc()) {
+                trace!("returning token that was updated while waiting on refresh lock");
+                return Ok(cached.token.clone());
             }
         }
 
         trace!("token cache miss");
         let token = callback(scopes, options).await?;
-        token_cache.insert(scopes_owned, token.clone());
+        *entry.token.write().await = Some(CachedToken::new(token.clone()));
         Ok(token)
     }
-}
 
-impl Default for TokenCache {
-    fn default() -> Self {
-        TokenCache::new()
+    fn entry(&self, key: CacheKey) -> Arc<CacheEntry> {
+        let mut entries = self
+            .0
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        entries.entry(key).or_default().clone()
     }
 }
 
-fn should_refresh(token: &AccessToken) -> bool {
-    token.expires_on <= OffsetDateTime::now_utc() + Duration::seconds(300)
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
     use async_lock::Mutex;
     use azure_core::{
         credentials::Secret,
-        time::{Duration, OffsetDateTime},
+        random::{with_random_source, RandomSource},
+        time::{with_clock, Clock, Duration, OffsetDateTime},
     };
 
     #[derive(Debug)]
@@ -125,10 +199,14 @@ mod tests {
 
         // Test that querying a token for the same resource twice returns the same (cached) token on the second call
         let token1 = cache
-            .get_token(resource1, None, |s, o| mock_credential.get_token(s, o))
+            .get_token(resource1, None, None, |s, o| {
+                mock_credential.get_token(s, o)
+            })
             .await?;
         let token2 = cache
-            .get_token(resource1, None, |s, o| mock_credential.get_token(s, o))
+            .get_token(resource1, None, None, |s, o| {
+                mock_credential.get_token(s, o)
+            })
             .await?;
 
         let expected_token = format!("{}-{}:1", resource1.join(" "), secret_string);
@@ -138,10 +216,14 @@ mod tests {
         // Test that querying a token for a second resource returns a different token, as the cache is per-resource.
         // Also test that the same token is the returned (cached) on a second call.
         let token3 = cache
-            .get_token(resource2, None, |s, o| mock_credential.get_token(s, o))
+            .get_token(resource2, None, None, |s, o| {
+                mock_credential.get_token(s, o)
+            })
             .await?;
         let token4 = cache
-            .get_token(resource2, None, |s, o| mock_credential.get_token(s, o))
+            .get_token(resource2, None, None, |s, o| {
+                mock_credential.get_token(s, o)
+            })
             .await?;
         let expected_token = format!("{}-{}:2", resource2.join(" "), secret_string);
         assert_eq!(token3.token.secret(), expected_token);
@@ -164,7 +246,7 @@ mod tests {
         // Test that querying an expired token returns a new token
         for i in 1..5 {
             let token = cache
-                .get_token(resource, None, |s, o| mock_credential.get_token(s, o))
+                .get_token(resource, None, None, |s, o| mock_credential.get_token(s, o))
                 .await?;
             assert_eq!(
                 token.token.secret(),
@@ -174,4 +256,199 @@ mod tests {
 
         Ok(())
     }
+
+    #[tokio::test]
+    async fn tokens_are_cached_per_tenant() -> azure_core::Result<()> {
+        let resource = &[STORAGE_TOKEN_SCOPE];
+        let expires_on = OffsetDateTime::now_utc() + Duration::seconds(3600);
+        let mock_credential = MockCredential::new(AccessToken::new("test-token", expires_on));
+        let cache = TokenCache::new();
+
+        let default_tenant = cache
+            .get_token(resource, None, None, |s, o| mock_credential.get_token(s, o))
+            .await?;
+        let other_tenant = cache
+            .get_token(resource, Some("other"), None, |s, o| {
+                mock_credential.get_token(s, o)
+            })
+            .await?;
+        let cached = cache
+            .get_token(resource, Some("other"), None, |s, o| {
+                mock_credential.get_token(s, o)
+            })
+            .await?;
+
+        assert_ne!(default_tenant.token.secret(), other_tenant.token.secret());
+        assert_eq!(other_tenant.token.secret(), cached.token.secret());
+        assert_eq!(2, *mock_credential.get_token_call_count.lock().await);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn concurrent_requests_share_one_refresh() -> azure_core::Result<()> {
+        let resource = &[STORAGE_TOKEN_SCOPE];
+        let expires_on = OffsetDateTime::now_utc() + Duration::seconds(3600);
+        let mock_credential = MockCredential::new(AccessToken::new("test-token", expires_on));
+        let cache = TokenCache::new();
+
+        let requests = (0..10).map(|_| {
+            cache.get_token(resource, None, None, |s, o| async {
+                tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+                mock_credential.get_token(s, o).await
+            })
+        });
+        let tokens = futures::future::try_join_all(requests).await?;
+
+        assert_eq!(1, *mock_credential.get_token_call_count.lock().await);
+        assert!(tokens
+            .iter()
+            .all(|token| token.token.secret() == tokens[0].token.secret()));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn refresh_before_expiry() -> azure_core::Result<()> {
+        let resource = &[STORAGE_TOKEN_SCOPE];
+        // the token is valid but inside the refresh window
+        let expires_on = OffsetDateTime::now_utc() + Duration::seconds(60);
+        let mock_credential = MockCredential::new(AccessToken::new("test-token", expires_on));
+        let cache = TokenCache::new();
+
+        for i in 1..3 {
+            let token = cache
+                .get_token(resource, None, None, |s, o| mock_credential.get_token(s, o))
+                .await?;
+            assert_eq!(
+                format!("{}-test-token:{i}", resource.join(" ")),
+                token.token.secret()
+            );
+        }
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn refresh_failure_returns_valid_token() -> azure_core::Result<()> {
+        let resource = &[STORAGE_TOKEN_SCOPE];
+        let expires_on = OffsetDateTime::now_utc() + Duration::seconds(60);
+        let mock_credential = MockCredential::new(AccessToken::new("test-token", expires_on));
+        let cache = TokenCache::new();
+
+        let token = cache
+            .get_token(resource, None, None, |s, o| mock_credential.get_token(s, o))
+            .await?;
+        let cached = cache
+            .get_token(resource, None, None, |_, _| async {
+                Err(azure_core::Error::with_message(
+                    azure_core::error::ErrorKind::Credential,
+                    "refresh failed",
+                ))
+            })
+            .await?;
+
+        assert_eq!(token.token.secret(), cached.token.secret());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn refresh_in_progress_returns_valid_token() -> azure_core::Result<()> {
+        let resource = &[STORAGE_TOKEN_SCOPE];
+        let expires_on = OffsetDateTime::now_utc() + Duration::seconds(60);
+        let mock_credential = MockCredential::new(AccessToken::new("test-token", expires_on));
+        let cache = TokenCache::new();
+
+        let token = cache
+            .get_token(resource, None, None, |s, o| mock_credential.get_token(s, o))
+            .await?;
+
+        // simulate another caller refreshing the token
+        let entry = cache.entry(CacheKey {
+            scopes: vec![STORAGE_TOKEN_SCOPE.to_string()],
+            tenant_id: None,
+        });
+        let _refresh = entry.refresh.lock().await;
+
+        let cached = cache
+            .get_token(resource, None, None, |_, _| async {
+                panic!("the token shouldn't be refreshed while another caller refreshes it")
+            })
+            .await?;
+        assert_eq!(token.token.secret(), cached.token.secret());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn expired_token_refresh_failure_is_returned() {
+        let resource = &[STORAGE_TOKEN_SCOPE];
+        let mock_credential =
+            MockCredential::new(AccessToken::new("test-token", OffsetDateTime::now_utc()));
+        let cache = TokenCache::new();
+
+        cache
+            .get_token(resource, None, None, |s, o| mock_credential.get_token(s, o))
+            .await
+            .expect("token");
+        cache
+            .get_token(resource, None, None, |_, _| async {
+                Err(azure_core::Error::with_message(
+                    azure_core::error::ErrorKind::Credential,
+                    "refresh failed",
+                ))
+            })
+            .await
+            .expect_err("expired token shouldn't be returned");
+    }
+
+    #[derive(Debug)]
+    struct MockClock(std::sync::Mutex<OffsetDateTime>);
+
+    impl Clock for MockClock {
+        fn now_utc(&self) -> OffsetDateTime {
+            *self.0.lock().unwrap()
+        }
+    }
+
+    #[derive(Debug)]
+    struct MockRandomSource(u64);
+
+    impl RandomSource for MockRandomSource {
+        fn next_u64(&self) -> u64 {
+            self.0
+        }
+    }
+
+    #[tokio::test]
+    async fn refresh_window_uses_current_clock_and_random_source() -> azure_core::Result<()> {
+        let resource = &[STORAGE_TOKEN_SCOPE];
+        let expires_on = OffsetDateTime::UNIX_EPOCH + Duration::hours(1);
+        let mock_credential = MockCredential::new(AccessToken::new("test-token", expires_on));
+        let cache = TokenCache::new();
+        let clock = Arc::new(MockClock(std::sync::Mutex::new(OffsetDateTime::UNIX_EPOCH)));
+        // The maximum jitter starts the refresh window 5 minutes 30 seconds before the token expires.
+        let random_source = Arc::new(MockRandomSource(MAX_REFRESH_JITTER_SECONDS));
+
+        let get_token = || {
+            let future =
+                cache.get_token(resource, None, None, |s, o| mock_credential.get_token(s, o));
+            with_clock(
+                clock.clone(),
+                with_random_source(random_source.clone(), future),
+            )
+        };
+
+        get_token().await?;
+        *clock.0.lock().unwrap() = expires_on - Duration::seconds(331);
+        let token = get_token().await?;
+        assert!(token.token.secret().ends_with(":1"));
+
+        *clock.0.lock().unwrap() = expires_on - Duration::seconds(330);
+        let token = get_token().await?;
+        assert!(token.token.secret().ends_with(":2"));
+
+        Ok(())
+    }
 }
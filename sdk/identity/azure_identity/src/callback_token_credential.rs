@@ -0,0 +1,157 @@
+// Copyright (c) Microsoft Corporation. All rights reserved.
+// Licensed under the MIT License.
+
+use crate::{authentication_error, TokenCache};
+use azure_core::{
+    credentials::{AccessToken, TokenCredential, TokenRequestOptions},
+    error::{Error, ErrorKind},
+    http::ClientMethodOptions,
+};
+use std::{any::type_name, fmt, future::Future, sync::Arc};
+
+/// Gets tokens from an asynchronous callback, such as a client for an external token broker.
+///
+/// The credential caches the tokens the callback returns per scopes and tenant, and calls it again
+/// shortly before a token expires. The callback receives the requested scopes and the request's options,
+/// which include any tenant requested with [`TokenRequestOptions::tenant_id`].
+///
+/// # Examples
+///
+/// ```
+/// use azure_core::{credentials::AccessToken, time::{Duration, OffsetDateTime}};
+/// use azure_identity::CallbackTokenCredential;
+///
+/// let credential = CallbackTokenCredential::new(|scopes, _options| async move {
+///     // request a token for `scopes` from the token broker
+///     Ok(AccessToken::new("token", OffsetDateTime::now_utc() + Duration::hours(1)))
+/// });
+/// ```
+pub struct CallbackTokenCredential<F> {
+    callback: F,
+    cache: TokenCache,
+}
+
+impl<F> fmt::Debug for CallbackTokenCredential<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct(type_name::<Self>()).finish_non_exhaustive()
+    }
+}
+
+impl<F, Fut> CallbackTokenCredential<F>
+where
+    F: Fn(Vec<String>, TokenRequestOptions<'static>) -> Fut + Send + Sync + 'static,
+    Fut: Future<Output = azure_core::Result<AccessToken>> + Send + 'static,
+{
+    /// Create a new `CallbackTokenCredential`.
+    ///
+    /// # Arguments
+    /// - `callback`: Gets a token for the given scopes and options.
+    pub fn new(callback: F) -> Arc<Self> {
+        Arc::new(Self {
+            callback,
+            cache: TokenCache::new(),
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl<F, Fut> TokenCredential for CallbackTokenCredential<F>
+where
+    F: Fn(Vec<String>, TokenRequestOptions<'static>) -> Fut + Send + Sync + 'static,
+    Fut: Future<Output = azure_core::Result<AccessToken>> + Send + 'static,
+{
+    async fn get_token(
+        &self,
+        scopes: &[&str],
+        options: Option<TokenRequestOptions<'_>>,
+    ) -> azure_core::Result<AccessToken> {
+        if scopes.is_empty() {
+            return Err(Error::with_message(
+                ErrorKind::Credential,
+                "no scopes specified",
+            ));
+        }
+        let tenant_id = options
+            .as_ref()
+            .and_then(|options| options.tenant_id.clone());
+        self.cache
+            .get_token(scopes, tenant_id.as_deref(), options, |s, o| {
+                let options = o.unwrap_or_default();
+                (self.callback)(
+                    s.iter().map(ToString::to_string).collect(),
+                    TokenRequestOptions {
+                        method_options: ClientMethodOptions {
+                            context: options.method_options.context.into_owned(),
+                        },
+                        tenant_id: options.tenant_id,
+                    },
+                )
+            })
+            .await
+            .map_err(|err| authentication_error(stringify!(CallbackTokenCredential), err))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::{with_tenant_id, FAKE_TENANT_ID, FAKE_TOKEN, LIVE_TEST_SCOPES};
+    use azure_core::time::{Duration, OffsetDateTime};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    #[tokio::test]
+    async fn caches_tokens() {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let credential = CallbackTokenCredential::new({
+            let calls = calls.clone();
+            move |scopes: Vec<String>, options: TokenRequestOptions<'static>| {
+                let calls = calls.clone();
+                async move {
+                    assert_eq!(LIVE_TEST_SCOPES, scopes.as_slice());
+                    let tenant = options
+                        .tenant_id
+                        .as_deref()
+                        .unwrap_or("default")
+                        .to_string();
+                    let call = calls.fetch_add(1, Ordering::SeqCst);
+                    Ok(AccessToken::new(
+                        format!("{FAKE_TOKEN}-{tenant}-{call}"),
+                        OffsetDateTime::now_utc() + Duration::hours(1),
+                    ))
+                }
+            }
+        });
+
+        for _ in 0..2 {
+            let token = credential
+                .get_token(LIVE_TEST_SCOPES, None)
+                .await
+                .expect("token");
+            assert_eq!(format!("{FAKE_TOKEN}-default-0"), token.token.secret());
+        }
+
+        let token = credential
+            .get_token(LIVE_TEST_SCOPES, Some(with_tenant_id(FAKE_TENANT_ID)))
+            .await
+            .expect("token");
+        assert_eq!(
+            format!("{FAKE_TOKEN}-{FAKE_TENANT_ID}-1"),
+            token.token.secret()
+        );
+        assert_eq!(2, calls.load(Ordering::SeqCst));
+    }
+
+    #[tokio::test]
+    async fn callback_error() {
+        let credential = CallbackTokenCredential::new(|_, _| async {
+            Err(Error::with_message(ErrorKind::Other, "broker unavailable"))
+        });
+
+        let err = credential
+            .get_token(LIVE_TEST_SCOPES, None)
+            .await
+            .expect_err("callback error");
+        assert!(matches!(err.kind(), ErrorKind::Credential));
+        assert!(err.to_string().contains("broker unavailable"), "{err}");
+    }
+}
@@ -1,10 +1,12 @@
 // Copyright (c) Microsoft Corporation. All rights reserved.
 // Licensed under the MIT License.
 
-use crate::{get_authority_host, validate_not_empty, validate_tenant_id, TokenCache};
+use crate::{
+    get_authority_host, resolve_tenant_id, token_endpoint, validate_not_empty, validate_tenant_id,
+    TokenCache,
+};
 use azure_core::{
     credentials::{AccessToken, TokenCredential, TokenRequestOptions},
-    error::{ErrorKind, ResultExt},
     http::{
         headers::{self, content_type},
         ClientMethodOptions, ClientOptions, Method, Pipeline, PipelineSendOptions, Request, Url,
@@ -26,7 +28,9 @@ const ASSERTION_TYPE: &str = "urn:ietf:params:oauth:client-assertion-type:jwt-be
 pub struct ClientAssertionCredential<C> {
     name: &'static str,
     client_id: String,
-    endpoint: Url,
+    tenant_id: String,
+    additionally_allowed_tenants: Vec<String>,
+    authority_host: Url,
     assertion: C,
     cache: TokenCache,
     pipeline: Pipeline,
@@ -36,7 +40,8 @@ impl<C> fmt::Debug for ClientAssertionCredential<C> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_struct(type_name::<Self>())
             .field("client_id", &self.client_id)
-            .field("endpoint", &self.endpoint)
+            .field("tenant_id", &self.tenant_id)
+            .field("authority_host", &self.authority_host)
             .finish_non_exhaustive()
     }
 }
@@ -46,6 +51,10 @@ impl<C> fmt::Debug for ClientAssertionCredential<C> {
 pub struct ClientAssertionCredentialOptions {
     /// Options for the credential's HTTP pipeline.
     pub client_options: ClientOptions,
+
+    /// Tenants, in addition to the configured tenant, for which the credential may acquire tokens.
+    /// See [`ALL_TENANTS`](crate::ALL_TENANTS) for how to request them.
+    pub additionally_allowed_tenants: Vec<String>,
 }
 
 impl fmt::Debug for ClientAssertionCredentialOptions {
@@ -99,11 +108,8 @@ impl<C: ClientAssertion> ClientAssertionCredential<C> {
         validate_not_empty(&client_id, "no client ID specified")?;
         let options = options.unwrap_or_default();
         let authority_host = get_authority_host(None, options.client_options.cloud.as_deref())?;
-        let endpoint = authority_host
-            .join(&format!("/{tenant_id}/oauth2/v2.0/token"))
-            .with_context_fn(ErrorKind::DataConversion, || {
-                format!("tenant_id {tenant_id} could not be URL encoded")
-            })?;
+        // validate the configured tenant's endpoint now rather than on the first token request
+        token_endpoint(&authority_host, &tenant_id)?;
         let pipeline = Pipeline::new(
             option_env!("CARGO_PKG_NAME"),
             option_env!("CARGO_PKG_VERSION"),
@@ -115,8 +121,10 @@ impl<C: ClientAssertion> ClientAssertionCredential<C> {
         Ok(Self {
             name,
             client_id,
+            tenant_id,
+            additionally_allowed_tenants: options.additionally_allowed_tenants,
+            authority_host,
             assertion,
-            endpoint,
             cache: TokenCache::new(),
             pipeline,
         })
@@ -124,10 +132,12 @@ impl<C: ClientAssertion> ClientAssertionCredential<C> {
 
     async fn get_token_impl(
         &self,
+        tenant_id: &str,
         scopes: &[&str],
         options: Option<TokenRequestOptions<'_>>,
     ) -> azure_core::Result<AccessToken> {
-        let mut req = Request::new(self.endpoint.clone(), Method::Post);
+        let endpoint = token_endpoint(&self.authority_host, tenant_id)?;
+        let mut req = Request::new(endpoint, Method::Post);
         req.insert_header(
             headers::CONTENT_TYPE,
             content_type::APPLICATION_X_WWW_FORM_URLENCODED,
@@ -170,8 +180,17 @@ impl<C: ClientAssertion> TokenCredential for ClientAssertionCredential<C> {
         scopes: &[&str],
         options: Option<TokenRequestOptions<'_>>,
     ) -> azure_core::Result<AccessToken> {
+        let requested_tenant_id = resolve_tenant_id(
+            Some(&self.tenant_id),
+            options.as_ref(),
+            &self.additionally_allowed_tenants,
+        )?
+        .map(ToString::to_string);
+        let tenant_id = requested_tenant_id.as_deref().unwrap_or(&self.tenant_id);
         self.cache
-            .get_token(scopes, options, |s, o| self.get_token_impl(s, o))
+            .get_token(scopes, requested_tenant_id.as_deref(), options, |s, o| {
+                self.get_token_impl(tenant_id, s, o)
+            })
             .await
             .map_err(|err| crate::authentication_error(self.name, err))
     }
@@ -182,6 +201,7 @@ pub(crate) mod tests {
     use super::*;
     use crate::tests::*;
     use azure_core::{
+        error::ErrorKind,
         http::{
             headers::{self, content_type, Headers},
             AsyncRawResponse, Body, Method, RawResponse, Request, StatusCode, Transport,
@@ -279,6 +299,7 @@ pub(crate) mod tests {
                     transport: Some(Transport::new(Arc::new(mock))),
                     ..Default::default()
                 },
+                ..Default::default()
             }),
         )
         .expect("valid credential");
@@ -328,6 +349,7 @@ pub(crate) mod tests {
                     transport: Some(Transport::new(Arc::new(mock))),
                     ..Default::default()
                 },
+                ..Default::default()
             }),
         )
         .expect("valid credential");
@@ -369,6 +391,7 @@ pub(crate) mod tests {
                         cloud: Some(Arc::new(cloud)),
                         ..Default::default()
                     },
+                    ..Default::default()
                 }),
             )
             .expect("valid credential");
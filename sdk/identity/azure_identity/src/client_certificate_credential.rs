@@ -2,8 +2,8 @@
 // Licensed under the MIT License.
 
 use crate::{
-    authentication_error, env::Env, get_authority_host, validate_not_empty, validate_tenant_id,
-    TokenCache,
+    authentication_error, env::Env, get_authority_host, resolve_tenant_id, token_endpoint,
+    validate_not_empty, validate_tenant_id, TokenCache,
 };
 use azure_core::{
     base64,
@@ -14,8 +14,7 @@ use azure_core::{
         request::Request,
         ClientOptions, Method, Pipeline, PipelineSendOptions, Url,
     },
-    time::OffsetDateTime,
-    Uuid,
+    random, time,
 };
 
 // cspell:ignore pkey
@@ -41,8 +40,21 @@ pub struct ClientCertificateCredentialOptions {
     pub client_options: ClientOptions,
 
     /// The password for the certificate.
+    ///
+    /// For PEM certificates, this decrypts an encrypted private key.
     pub password: Option<Secret>,
 
+    /// Whether to send the certificate chain in the `x5c` header of each client assertion.
+    ///
+    /// Set this to enable subject name/issuer (SNI) authentication, which lets Microsoft Entra ID
+    /// validate the certificate by its subject and issuer instead of a registered thumbprint.
+    /// This is also enabled when the `AZURE_CLIENT_SEND_CERTIFICATE_CHAIN` environment variable is "1" or "true".
+    pub send_certificate_chain: bool,
+
+    /// Tenants, in addition to the configured tenant, for which the credential may acquire tokens.
+    /// See [`ALL_TENANTS`](crate::ALL_TENANTS) for how to request them.
+    pub additionally_allowed_tenants: Vec<String>,
+
     #[cfg(test)]
     pub(crate) env: Option<Env>,
 }
@@ -57,7 +69,9 @@ impl fmt::Debug for ClientCertificateCredentialOptions {
 pub struct ClientCertificateCredential {
     client_id: String,
     key: PKey<Private>,
-    endpoint: Url,
+    tenant_id: String,
+    additionally_allowed_tenants: Vec<String>,
+    authority_host: Url,
     pipeline: Pipeline,
     header: String,
     cache: TokenCache,
@@ -67,7 +81,8 @@ impl fmt::Debug for ClientCertificateCredential {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_struct(type_name::<Self>())
             .field("client_id", &self.client_id)
-            .field("endpoint", &self.endpoint)
+            .field("tenant_id", &self.tenant_id)
+            .field("authority_host", &self.authority_host)
             .finish_non_exhaustive()
     }
 }
@@ -78,7 +93,8 @@ impl ClientCertificateCredential {
     /// # Arguments
     /// - `tenant_id`: The tenant (directory) ID of the service principal.
     /// - `client_id`: The client (application) ID of the service principal.
-    /// - `certificate`: The PKCS12 certificate bytes with its RSA private key.
+    /// - `certificate`: The certificate with its RSA private key, either as PKCS12 bytes or as PEM
+    ///   containing the private key followed by the certificate and any CA certificates.
     /// - `options`: Options for configuring the credential. If `None`, the credential uses its default options.
     ///
     pub fn new(
@@ -105,10 +121,11 @@ impl ClientCertificateCredential {
         #[cfg(not(test))]
         let env = Env::default();
 
-        let send_x5c = env
-            .var(AZURE_CLIENT_SEND_CERTIFICATE_CHAIN_ENV_KEY)
-            .map(|s| s == "1" || s.eq_ignore_ascii_case("true"))
-            .unwrap_or(false);
+        let send_x5c = options.send_certificate_chain
+            || env
+                .var(AZURE_CLIENT_SEND_CERTIFICATE_CHAIN_ENV_KEY)
+                .map(|s| s == "1" || s.eq_ignore_ascii_case("true"))
+                .unwrap_or(false);
         let header = if send_x5c {
             let base_signature = get_encoded_cert(&cert)?;
             let x5c = match &ca_chain {
@@ -128,11 +145,8 @@ impl ClientCertificateCredential {
         };
 
         let authority_host = get_authority_host(None, options.client_options.cloud.as_deref())?;
-        let endpoint = authority_host
-            .join(&format!("/{tenant_id}/oauth2/v2.0/token"))
-            .with_context_fn(ErrorKind::DataConversion, || {
-                format!("tenant_id '{tenant_id}' could not be URL encoded")
-            })?;
+        // validate the configured tenant's endpoint now rather than on the first token request
+        token_endpoint(&authority_host, &tenant_id)?;
 
         let pipeline = Pipeline::new(
             option_env!("CARGO_PKG_NAME"),
@@ -146,7 +160,9 @@ impl ClientCertificateCredential {
         Ok(Arc::new(ClientCertificateCredential {
             client_id,
             key,
-            endpoint,
+            tenant_id,
+            additionally_allowed_tenants: options.additionally_allowed_tenants,
+            authority_host,
             pipeline,
             header: ClientCertificateCredential::as_jwt_part(header.as_bytes()),
             cache: TokenCache::new(),
@@ -165,15 +181,17 @@ impl ClientCertificateCredential {
 
     async fn get_token_impl(
         &self,
+        tenant_id: &str,
         scopes: &[&str],
         options: Option<TokenRequestOptions<'_>>,
     ) -> azure_core::Result<AccessToken> {
-        let uuid = Uuid::new_v4();
-        let current_time = OffsetDateTime::now_utc().unix_timestamp();
+        let endpoint = token_endpoint(&self.authority_host, tenant_id)?;
+        let uuid = random::new_uuid();
+        let current_time = time::now_utc().unix_timestamp();
         let expiry_time = current_time + DEFAULT_ASSERTION_LIFETIME;
         let payload = format!(
             r#"{{"aud":"{}","exp":{},"iss": "{}", "jti": "{}", "nbf": {}, "sub": "{}"}}"#,
-            self.endpoint, expiry_time, self.client_id, uuid, current_time, self.client_id
+            endpoint, expiry_time, self.client_id, uuid, current_time, self.client_id
         );
         let payload = ClientCertificateCredential::as_jwt_part(payload.as_bytes());
 
@@ -197,7 +215,7 @@ impl ClientCertificateCredential {
             encoded.finish()
         };
 
-        let mut req = Request::new(self.endpoint.clone(), Method::Post);
+        let mut req = Request::new(endpoint, Method::Post);
         req.insert_header(
             headers::CONTENT_TYPE,
             content_type::APPLICATION_X_WWW_FORM_URLENCODED,
@@ -222,10 +240,29 @@ impl ClientCertificateCredential {
     }
 }
 
-/// Parse a PKCS12 certificate into key, certificate, and optional CA chain.
+/// Parse a PKCS12 or PEM certificate into key, certificate, and optional CA chain.
 fn parse_certificate(
     cert_bytes: &[u8],
     password: Option<&Secret>,
+) -> azure_core::Result<(PKey<Private>, X509, Option<Vec<X509>>)> {
+    let (key, cert, ca_chain) = if cert_bytes.trim_ascii_start().starts_with(b"-----BEGIN") {
+        parse_pem(cert_bytes, password)?
+    } else {
+        parse_pkcs12(cert_bytes, password)?
+    };
+    if key.id() != Id::RSA {
+        return Err(Error::with_message(
+            ErrorKind::Credential,
+            "only RSA private keys are supported",
+        ));
+    }
+
+    Ok((key, cert, ca_chain))
+}
+
+fn parse_pkcs12(
+    cert_bytes: &[u8],
+    password: Option<&Secret>,
 ) -> azure_core::Result<(PKey<Private>, X509, Option<Vec<X509>>)> {
     let pkcs12 = Pkcs12::from_der(cert_bytes).with_context(
         ErrorKind::Credential,
@@ -240,12 +277,6 @@ fn parse_certificate(
             "PKCS12 bundle contains no private key",
         )
     })?;
-    if key.id() != Id::RSA {
-        return Err(Error::with_message(
-            ErrorKind::Credential,
-            "only RSA private keys are supported",
-        ));
-    }
     let cert = parsed.cert.ok_or_else(|| {
         Error::with_message(
             ErrorKind::Credential,
@@ -264,6 +295,40 @@ fn parse_certificate(
     Ok((key, cert, ca_chain))
 }
 
+/// Parse PEM containing a private key, the certificate, and any CA certificates after it.
+fn parse_pem(
+    cert_bytes: &[u8],
+    password: Option<&Secret>,
+) -> azure_core::Result<(PKey<Private>, X509, Option<Vec<X509>>)> {
+    let key = match password {
+        Some(password) => {
+            PKey::private_key_from_pem_passphrase(cert_bytes, password.secret().as_bytes())
+        }
+        // Don't let OpenSSL prompt for a passphrase when the key is encrypted.
+        None => PKey::private_key_from_pem_callback(cert_bytes, |_| Ok(0)),
+    }
+    .with_context(ErrorKind::Credential, "PEM contains no valid private key")?;
+    let mut certs = X509::stack_from_pem(cert_bytes)
+        .with_context(ErrorKind::Credential, "PEM certificate parsing failed")?
+        .into_iter();
+    let cert = certs
+        .next()
+        .ok_or_else(|| Error::with_message(ErrorKind::Credential, "PEM contains no certificate"))?;
+    let matches = cert
+        .public_key()
+        .map(|public_key| public_key.public_eq(&key))
+        .unwrap_or(false);
+    if !matches {
+        return Err(Error::with_message(
+            ErrorKind::Credential,
+            "the first certificate in the PEM doesn't match its private key",
+        ));
+    }
+    let ca_chain: Vec<X509> = certs.collect();
+
+    Ok((key, cert, (!ca_chain.is_empty()).then_some(ca_chain)))
+}
+
 fn get_encoded_cert(cert: &X509) -> azure_core::Result<String> {
     Ok(format!(
         "\"{}\"",
@@ -287,8 +352,17 @@ impl TokenCredential for ClientCertificateCredential {
                 "no scopes specified",
             ));
         }
+        let requested_tenant_id = resolve_tenant_id(
+            Some(&self.tenant_id),
+            options.as_ref(),
+            &self.additionally_allowed_tenants,
+        )?
+        .map(ToString::to_string);
+        let tenant_id = requested_tenant_id.as_deref().unwrap_or(&self.tenant_id);
         self.cache
-            .get_token(scopes, options, |s, o| self.get_token_impl(s, o))
+            .get_token(scopes, requested_tenant_id.as_deref(), options, |s, o| {
+                self.get_token_impl(tenant_id, s, o)
+            })
             .await
             .map_err(|err| authentication_error(stringify!(ClientCertificateCredential), err))
     }
@@ -305,6 +379,7 @@ mod tests {
             policies::{Policy, PolicyResult},
             AsyncRawResponse, Context, RawResponse, StatusCode, Transport,
         },
+        time::OffsetDateTime,
         Bytes,
     };
     use openssl::{pkey::Public, sign::Verifier};
@@ -321,6 +396,14 @@ mod tests {
         .expect("failed to read test certificate")
     });
 
+    /// [`TEST_CERT`] as PEM, with the private key followed by the certificate.
+    static TEST_CERT_PEM: LazyLock<Vec<u8>> = LazyLock::new(|| {
+        let (key, cert, _) = parse_certificate(&TEST_CERT, None).expect("valid certificate");
+        let mut pem = key.private_key_to_pem_pkcs8().expect("key PEM");
+        pem.extend(cert.to_pem().expect("certificate PEM"));
+        pem
+    });
+
     #[derive(Debug, Clone)]
     struct VerifyAssertionPolicy {
         public_key: PKey<Public>,
@@ -630,4 +713,130 @@ mod tests {
 
         assert_eq!(FAKE_TOKEN, token.token.secret());
     }
+
+    #[tokio::test]
+    async fn pem() {
+        let sts = MockSts::new(
+            vec![token_response()],
+            Some(Arc::new(is_valid_request(
+                FAKE_PUBLIC_CLOUD_AUTHORITY.to_string(),
+                None,
+            ))),
+        );
+        let credential = ClientCertificateCredential::new(
+            FAKE_TENANT_ID.to_string(),
+            FAKE_CLIENT_ID.to_string(),
+            TEST_CERT_PEM.as_slice().into(),
+            Some(ClientCertificateCredentialOptions {
+                client_options: ClientOptions {
+                    transport: Some(Transport::new(Arc::new(sts))),
+                    per_try_policies: vec![Arc::new(VerifyAssertionPolicy::new(&TEST_CERT, false))],
+                    ..Default::default()
+                },
+                ..Default::default()
+            }),
+        )
+        .expect("valid credential");
+
+        let token = credential
+            .get_token(LIVE_TEST_SCOPES, None)
+            .await
+            .expect("token");
+
+        assert_eq!(FAKE_TOKEN, token.token.secret());
+    }
+
+    #[test]
+    fn pem_encrypted_key() {
+        let (key, cert, _) = parse_certificate(&TEST_CERT, None).expect("valid certificate");
+        let mut pem = key
+            .private_key_to_pem_pkcs8_passphrase(openssl::symm::Cipher::aes_256_cbc(), b"password")
+            .expect("encrypted key PEM");
+        pem.extend(cert.to_pem().expect("certificate PEM"));
+
+        ClientCertificateCredential::new(
+            FAKE_TENANT_ID.to_string(),
+            FAKE_CLIENT_ID.to_string(),
+            pem.as_slice().into(),
+            None,
+        )
+        .expect_err("missing password");
+
+        ClientCertificateCredential::new(
+            FAKE_TENANT_ID.to_string(),
+            FAKE_CLIENT_ID.to_string(),
+            pem.as_slice().into(),
+            Some(ClientCertificateCredentialOptions {
+                password: Some(Secret::from("password")),
+                ..Default::default()
+            }),
+        )
+        .expect("valid credential");
+    }
+
+    #[test]
+    fn pem_mismatched_key() {
+        let key = PKey::from_rsa(openssl::rsa::Rsa::generate(2048).expect("RSA key"))
+            .expect("private key");
+        let (_, cert, _) = parse_certificate(&TEST_CERT, None).expect("valid certificate");
+        let mut pem = key.private_key_to_pem_pkcs8().expect("key PEM");
+        pem.extend(cert.to_pem().expect("certificate PEM"));
+
+        let err = ClientCertificateCredential::new(
+            FAKE_TENANT_ID.to_string(),
+            FAKE_CLIENT_ID.to_string(),
+            pem.as_slice().into(),
+            None,
+        )
+        .expect_err("mismatched key");
+        assert!(matches!(err.kind(), ErrorKind::Credential));
+    }
+
+    #[test]
+    fn pem_without_certificate() {
+        let (key, _, _) = parse_certificate(&TEST_CERT, None).expect("valid certificate");
+        let pem = key.private_key_to_pem_pkcs8().expect("key PEM");
+
+        ClientCertificateCredential::new(
+            FAKE_TENANT_ID.to_string(),
+            FAKE_CLIENT_ID.to_string(),
+            pem.as_slice().into(),
+            None,
+        )
+        .expect_err("no certificate");
+    }
+
+    #[tokio::test]
+    async fn send_certificate_chain() {
+        let sts = MockSts::new(
+            vec![token_response()],
+            Some(Arc::new(is_valid_request(
+                FAKE_PUBLIC_CLOUD_AUTHORITY.to_string(),
+                None,
+            ))),
+        );
+        let credential = ClientCertificateCredential::new(
+            FAKE_TENANT_ID.to_string(),
+            FAKE_CLIENT_ID.to_string(),
+            TEST_CERT_PEM.as_slice().into(),
+            Some(ClientCertificateCredentialOptions {
+                client_options: ClientOptions {
+                    transport: Some(Transport::new(Arc::new(sts))),
+                    per_try_policies: vec![Arc::new(VerifyAssertionPolicy::new(&TEST_CERT, true))],
+                    ..Default::default()
+                },
+                send_certificate_chain: true,
+                env: Some(Env::from(&[][..])),
+                ..Default::default()
+            }),
+        )
+        .expect("credential");
+
+        let token = credential
+            .get_token(LIVE_TEST_SCOPES, None)
+            .await
+            .expect("token");
+
+        assert_eq!(FAKE_TOKEN, token.token.secret());
+    }
 }
@@ -1,13 +1,15 @@
 // Copyright (c) Microsoft Corporation. All rights reserved.
 // Licensed under the MIT License.
 
-use crate::{authentication_error, get_authority_host, TokenCache};
+use crate::{
+    authentication_error, get_authority_host, resolve_tenant_id, token_endpoint, TokenCache,
+};
 use azure_core::credentials::TokenRequestOptions;
 use azure_core::http::PipelineSendOptions;
 use azure_core::Result;
 use azure_core::{
     credentials::{AccessToken, Secret, TokenCredential},
-    error::{ErrorKind, ResultExt},
+    error::ErrorKind,
     http::{
         headers::{self, content_type},
         ClientOptions, Method, Pipeline, Request, Url,
@@ -22,6 +24,10 @@ use url::form_urlencoded;
 pub struct ClientSecretCredentialOptions {
     /// Options for the credential's HTTP pipeline.
     pub client_options: ClientOptions,
+
+    /// Tenants, in addition to the configured tenant, for which the credential may acquire tokens.
+    /// See [`ALL_TENANTS`](crate::ALL_TENANTS) for how to request them.
+    pub additionally_allowed_tenants: Vec<String>,
 }
 
 impl fmt::Debug for ClientSecretCredentialOptions {
@@ -34,7 +40,9 @@ impl fmt::Debug for ClientSecretCredentialOptions {
 pub struct ClientSecretCredential {
     cache: TokenCache,
     client_id: String,
-    endpoint: Url,
+    tenant_id: String,
+    additionally_allowed_tenants: Vec<String>,
+    authority_host: Url,
     pipeline: Pipeline,
     secret: Secret,
 }
@@ -43,7 +51,8 @@ impl fmt::Debug for ClientSecretCredential {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_struct(type_name::<Self>())
             .field("client_id", &self.client_id)
-            .field("endpoint", &self.endpoint)
+            .field("tenant_id", &self.tenant_id)
+            .field("authority_host", &self.authority_host)
             .finish_non_exhaustive()
     }
 }
@@ -69,11 +78,8 @@ impl ClientSecretCredential {
 
         let options = options.unwrap_or_default();
         let authority_host = get_authority_host(None, options.client_options.cloud.as_deref())?;
-        let endpoint = authority_host
-            .join(&format!("/{tenant_id}/oauth2/v2.0/token"))
-            .with_context_fn(ErrorKind::DataConversion, || {
-                format!("tenant_id '{tenant_id}' could not be URL encoded")
-            })?;
+        // validate the configured tenant's endpoint now rather than on the first token request
+        token_endpoint(&authority_host, tenant_id)?;
 
         let pipeline = Pipeline::new(
             option_env!("CARGO_PKG_NAME"),
@@ -87,7 +93,9 @@ impl ClientSecretCredential {
         Ok(Arc::new(Self {
             cache: TokenCache::new(),
             client_id,
-            endpoint,
+            tenant_id: tenant_id.to_string(),
+            additionally_allowed_tenants: options.additionally_allowed_tenants,
+            authority_host,
             pipeline,
             secret,
         }))
@@ -95,10 +103,12 @@ impl ClientSecretCredential {
 
     async fn get_token_impl(
         &self,
+        tenant_id: &str,
         scopes: &[&str],
         options: Option<TokenRequestOptions<'_>>,
     ) -> Result<AccessToken> {
-        let mut req = Request::new(self.endpoint.clone(), Method::Post);
+        let endpoint = token_endpoint(&self.authority_host, tenant_id)?;
+        let mut req = Request::new(endpoint, Method::Post);
         req.insert_header(
             headers::CONTENT_TYPE,
             content_type::APPLICATION_X_WWW_FORM_URLENCODED,
@@ -142,8 +152,17 @@ impl TokenCredential for ClientSecretCredential {
                 "no scopes specified",
             ));
         }
+        let requested_tenant_id = resolve_tenant_id(
+            Some(&self.tenant_id),
+            options.as_ref(),
+            &self.additionally_allowed_tenants,
+        )?
+        .map(ToString::to_string);
+        let tenant_id = requested_tenant_id.as_deref().unwrap_or(&self.tenant_id);
         self.cache
-            .get_token(scopes, options, |s, o| self.get_token_impl(s, o))
+            .get_token(scopes, requested_tenant_id.as_deref(), options, |s, o| {
+                self.get_token_impl(tenant_id, s, o)
+            })
             .await
             .map_err(|err| authentication_error(stringify!(ClientSecretCredential), err))
     }
@@ -192,6 +211,7 @@ mod tests {
                         cloud: Some(Arc::new(cloud)),
                         ..Default::default()
                     },
+                    ..Default::default()
                 }),
             )
             .expect("valid credential");
@@ -228,6 +248,7 @@ mod tests {
                     transport: Some(Transport::new(Arc::new(sts))),
                     ..Default::default()
                 },
+                ..Default::default()
             }),
         )
         .expect("valid credential");
@@ -277,6 +298,7 @@ mod tests {
                     transport: Some(Transport::new(Arc::new(sts))),
                     ..Default::default()
                 },
+                ..Default::default()
             }),
         )
         .expect("valid credential");
@@ -329,4 +351,39 @@ mod tests {
         .await
         .expect_err("no scopes specified");
     }
+
+    #[tokio::test]
+    async fn additionally_allowed_tenants() {
+        let sts = MockSts::new(
+            vec![token_response()],
+            Some(Arc::new(is_valid_request(
+                "https://login.microsoftonline.com/other-tenant".to_string(),
+            ))),
+        );
+        let credential = ClientSecretCredential::new(
+            FAKE_TENANT_ID,
+            FAKE_CLIENT_ID.to_string(),
+            FAKE_SECRET.into(),
+            Some(ClientSecretCredentialOptions {
+                client_options: ClientOptions {
+                    transport: Some(Transport::new(Arc::new(sts))),
+                    ..Default::default()
+                },
+                additionally_allowed_tenants: vec!["other-tenant".to_string()],
+            }),
+        )
+        .expect("valid credential");
+
+        let err = credential
+            .get_token(LIVE_TEST_SCOPES, Some(with_tenant_id("another-tenant")))
+            .await
+            .expect_err("tenant isn't allowed");
+        assert!(matches!(err.kind(), ErrorKind::Credential));
+
+        let token = credential
+            .get_token(LIVE_TEST_SCOPES, Some(with_tenant_id("other-tenant")))
+            .await
+            .expect("token");
+        assert_eq!(FAKE_TOKEN, token.token.secret());
+    }
 }
@@ -26,6 +26,10 @@ pub struct DeveloperToolsCredentialOptions {
     /// If `None`, one is created using [`crate::process::new_executor`]; alternatively,
     /// you can supply your own implementation using a different asynchronous runtime.
     pub executor: Option<Arc<dyn Executor>>,
+
+    /// Tenants, in addition to each developer tool's default tenant, for which the credential may acquire tokens.
+    /// See [`ALL_TENANTS`](crate::ALL_TENANTS) for how to request them.
+    pub additionally_allowed_tenants: Vec<String>,
 }
 
 impl fmt::Debug for DeveloperToolsCredentialOptions {
@@ -60,10 +64,12 @@ impl DeveloperToolsCredential {
         let sources: Vec<Arc<dyn TokenCredential>> = vec![
             AzureCliCredential::new(Some(AzureCliCredentialOptions {
                 executor: options.executor.clone(),
+                additionally_allowed_tenants: options.additionally_allowed_tenants.clone(),
                 ..Default::default()
             }))?,
             AzureDeveloperCliCredential::new(Some(AzureDeveloperCliCredentialOptions {
                 executor: options.executor,
+                additionally_allowed_tenants: options.additionally_allowed_tenants,
                 ..Default::default()
             }))?,
         ];
@@ -247,6 +253,7 @@ mod tests {
         let executor = MockExecutor::with_error(err);
         let options = DeveloperToolsCredentialOptions {
             executor: Some(executor.clone()),
+            ..Default::default()
         };
         let err = DeveloperToolsCredential::new(Some(options))
             .expect("valid credential")
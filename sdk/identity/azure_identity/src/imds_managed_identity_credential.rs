@@ -3,20 +3,26 @@
 
 use crate::{env::Env, TokenCache, UserAssignedId};
 use azure_core::{
+    async_runtime::get_async_runtime,
     credentials::{AccessToken, Secret, TokenCredential, TokenRequestOptions},
     error::{Error, ErrorKind},
     http::{
-        headers::HeaderName, request::Request, ClientOptions, Method, Pipeline, PipelineOptions,
-        PipelineSendOptions, StatusCode, Url,
+        headers::HeaderName, request::Request, ClientOptions, Context, Method, Pipeline,
+        PipelineOptions, PipelineSendOptions, RawResponse, RetryOptions, StatusCode, Url,
     },
     json::from_json,
-    time::OffsetDateTime,
+    time::{Duration, OffsetDateTime},
 };
+use futures::future::{select, Either};
 use serde::{
     de::{self, Deserializer},
     Deserialize,
 };
-use std::{any::type_name, fmt, str};
+use std::{
+    any::type_name,
+    fmt, pin, str,
+    sync::atomic::{AtomicBool, Ordering},
+};
 
 /// An identifier for the Azure Instance Metadata Service (IMDS).
 ///
@@ -34,6 +40,25 @@ pub enum ImdsId {
     MsiResId(String),
 }
 
+/// Sends the first token request without retries to detect whether the endpoint exists.
+///
+/// When the endpoint doesn't respond at all, e.g. on a machine outside Azure, retrying with the
+/// recommended IMDS backoff would take over a minute before failing.
+pub(crate) struct ImdsProbe {
+    /// How long to wait for the endpoint to respond.
+    pub timeout: Duration,
+    /// Status codes that should be retried after the probe receives a response.
+    pub retry_status_codes: Vec<StatusCode>,
+}
+
+struct Probe {
+    pipeline: Pipeline,
+    timeout: Duration,
+    retry_status_codes: Vec<StatusCode>,
+    // set after the endpoint has responded once; subsequent requests skip the probe
+    responded: AtomicBool,
+}
+
 impl From<UserAssignedId> for ImdsId {
     fn from(user_assigned_id: UserAssignedId) -> Self {
         match user_assigned_id {
@@ -51,8 +76,10 @@ impl From<UserAssignedId> for ImdsId {
 /// Built up from docs at [https://learn.microsoft.com/azure/app-service/overview-managed-identity#using-the-rest-protocol](https://learn.microsoft.com/azure/app-service/overview-managed-identity#using-the-rest-protocol)
 pub(crate) struct ImdsManagedIdentityCredential {
     pipeline: Pipeline,
+    probe: Option<Probe>,
     endpoint: Url,
     api_version: String,
+    resource_id_param: &'static str,
     secret_header: HeaderName,
     secret_env: String,
     id: ImdsId,
@@ -73,13 +100,31 @@ impl ImdsManagedIdentityCredential {
     pub fn new(
         endpoint: Url,
         api_version: &str,
+        resource_id_param: &'static str,
         secret_header: HeaderName,
         secret_env: &str,
         id: ImdsId,
         client_options: ClientOptions,
         pipeline_options: Option<PipelineOptions>,
+        probe: Option<ImdsProbe>,
         env: Env,
     ) -> Self {
+        let probe = probe.map(|probe| Probe {
+            pipeline: Pipeline::new(
+                option_env!("CARGO_PKG_NAME"),
+                option_env!("CARGO_PKG_VERSION"),
+                ClientOptions {
+                    retry: RetryOptions::none(),
+                    ..client_options.clone()
+                },
+                Vec::default(),
+                Vec::default(),
+                None,
+            ),
+            timeout: probe.timeout,
+            retry_status_codes: probe.retry_status_codes,
+            responded: AtomicBool::new(false),
+        });
         let pipeline = Pipeline::new(
             option_env!("CARGO_PKG_NAME"),
             option_env!("CARGO_PKG_VERSION"),
@@ -90,8 +135,10 @@ impl ImdsManagedIdentityCredential {
         );
         Self {
             pipeline,
+            probe,
             endpoint,
             api_version: api_version.to_owned(),
+            resource_id_param,
             secret_header: secret_header.to_owned(),
             secret_env: secret_env.to_owned(),
             id,
@@ -116,7 +163,9 @@ impl ImdsManagedIdentityCredential {
             ImdsId::SystemAssigned => (),
             ImdsId::ClientId(ref client_id) => query_items.push(("client_id", client_id)),
             ImdsId::ObjectId(ref object_id) => query_items.push(("object_id", object_id)),
-            ImdsId::MsiResId(ref msi_res_id) => query_items.push(("msi_res_id", msi_res_id)),
+            ImdsId::MsiResId(ref msi_res_id) => {
+                query_items.push((self.resource_id_param, msi_res_id))
+            }
         }
 
         let mut url = self.endpoint.clone();
@@ -133,17 +182,18 @@ impl ImdsManagedIdentityCredential {
 
         let options = options.unwrap_or_default();
         let ctx = options.method_options.context.to_borrowed();
-        let rsp = self
-            .pipeline
-            .send(
-                &ctx,
-                &mut req,
-                Some(PipelineSendOptions {
-                    skip_checks: true,
-                    ..Default::default()
-                }),
-            )
-            .await?;
+        let rsp = match &self.probe {
+            Some(probe) if !probe.responded.load(Ordering::Relaxed) => {
+                let rsp = send_probe(probe, &ctx, &mut req).await?;
+                probe.responded.store(true, Ordering::Relaxed);
+                if probe.retry_status_codes.contains(&rsp.status()) {
+                    self.send(&ctx, &mut req).await?
+                } else {
+                    rsp
+                }
+            }
+            _ => self.send(&ctx, &mut req).await?,
+        };
 
         let status = rsp.status();
         if !status.is_success() {
@@ -177,6 +227,51 @@ impl ImdsManagedIdentityCredential {
     }
 }
 
+impl ImdsManagedIdentityCredential {
+    async fn send(&self, ctx: &Context<'_>, req: &mut Request) -> azure_core::Result<RawResponse> {
+        self.pipeline
+            .send(
+                ctx,
+                req,
+                Some(PipelineSendOptions {
+                    skip_checks: true,
+                    ..Default::default()
+                }),
+            )
+            .await
+    }
+}
+
+/// Sends `req` once, failing fast if the endpoint doesn't respond within the probe's timeout.
+async fn send_probe(
+    probe: &Probe,
+    ctx: &Context<'_>,
+    req: &mut Request,
+) -> azure_core::Result<RawResponse> {
+    let send = pin::pin!(probe.pipeline.send(
+        ctx,
+        req,
+        Some(PipelineSendOptions {
+            skip_checks: true,
+            ..Default::default()
+        }),
+    ));
+    match select(send, get_async_runtime().sleep(probe.timeout)).await {
+        Either::Left((Ok(rsp), _)) => Ok(rsp),
+        Either::Left((Err(err), _)) => Err(Error::with_error(
+            ErrorKind::Credential,
+            err,
+            "The managed identity endpoint is unavailable",
+        )),
+        Either::Right(_) => Err(Error::with_message_fn(ErrorKind::Credential, || {
+            format!(
+                "The managed identity endpoint didn't respond within {} ms",
+                probe.timeout.whole_milliseconds()
+            )
+        })),
+    }
+}
+
 #[async_trait::async_trait]
 impl TokenCredential for ImdsManagedIdentityCredential {
     async fn get_token(
@@ -185,7 +280,7 @@ impl TokenCredential for ImdsManagedIdentityCredential {
         options: Option<TokenRequestOptions<'_>>,
     ) -> azure_core::Result<AccessToken> {
         self.cache
-            .get_token(scopes, options, |s, o| self.get_token(s, o))
+            .get_token(scopes, None, options, |s, o| self.get_token(s, o))
             .await
     }
 }
@@ -10,6 +10,7 @@ mod azure_cli_credential;
 mod azure_developer_cli_credential;
 mod azure_pipelines_credential;
 mod cache;
+mod callback_token_credential;
 mod client_assertion_credential;
 #[cfg(feature = "client_certificate")]
 mod client_certificate_credential;
@@ -18,25 +19,33 @@ mod developer_tools_credential;
 mod env;
 mod imds_managed_identity_credential;
 mod managed_identity_credential;
+mod on_behalf_of_credential;
 mod process;
+mod static_token_credential;
+mod tenant;
 mod virtual_machine_managed_identity_credential;
 mod workload_identity_credential;
 
 pub use azure_cli_credential::*;
 pub use azure_developer_cli_credential::*;
 pub use azure_pipelines_credential::*;
+pub use callback_token_credential::*;
 pub use client_assertion_credential::*;
 #[cfg(feature = "client_certificate")]
 pub use client_certificate_credential::*;
 pub use client_secret_credential::*;
 pub use developer_tools_credential::*;
 pub use managed_identity_credential::*;
+pub use on_behalf_of_credential::*;
 pub use process::{new_executor, Executor};
+pub use static_token_credential::*;
+pub use tenant::ALL_TENANTS;
 pub use workload_identity_credential::*;
 
 pub(crate) use app_service_managed_identity_credential::*;
 pub(crate) use cache::TokenCache;
 pub(crate) use imds_managed_identity_credential::*;
+pub(crate) use tenant::{resolve_tenant_id, token_endpoint};
 pub(crate) use virtual_machine_managed_identity_credential::*;
 
 use crate::env::Env;
@@ -45,7 +54,7 @@ use azure_core::{
     credentials::AccessToken,
     error::ErrorKind,
     http::{RawResponse, Url},
-    time::{Duration, OffsetDateTime},
+    time::{self, Duration},
     Error, Result,
 };
 use serde::Deserialize;
@@ -82,7 +91,7 @@ fn handle_entra_response(response: RawResponse) -> Result<AccessToken> {
         let token_response: EntraIdTokenResponse = deserialize(&response)?;
         return Ok(AccessToken::new(
             token_response.access_token,
-            OffsetDateTime::now_utc() + Duration::seconds(token_response.expires_in),
+            time::now_utc() + Duration::seconds(token_response.expires_in),
         ));
     }
 
@@ -266,6 +275,7 @@ mod tests {
     use async_trait::async_trait;
     use azure_core::{
         cloud::{CloudConfiguration, CustomConfiguration},
+        credentials::TokenRequestOptions,
         error::ErrorKind,
         http::{headers::Headers, AsyncRawResponse, RawResponse, Request, StatusCode},
         Bytes, Error, Result,
@@ -286,6 +296,13 @@ mod tests {
     pub const LIVE_TEST_RESOURCE: &str = "https://management.azure.com";
     pub const LIVE_TEST_SCOPES: &[&str] = &["https://management.azure.com/.default"];
 
+    pub fn with_tenant_id(tenant_id: &str) -> TokenRequestOptions<'static> {
+        TokenRequestOptions {
+            tenant_id: Some(tenant_id.to_string()),
+            ..Default::default()
+        }
+    }
+
     pub type RunCallback = Arc<dyn Fn(&OsStr, &[&OsStr]) + Send + Sync>;
 
     #[derive(Default)]
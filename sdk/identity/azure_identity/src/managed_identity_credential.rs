@@ -7,6 +7,7 @@ use crate::{
 };
 use azure_core::credentials::{AccessToken, TokenCredential, TokenRequestOptions};
 use azure_core::http::ClientOptions;
+use azure_core::time::Duration;
 use std::{any::type_name, fmt, sync::Arc};
 use tracing::info;
 
@@ -43,6 +44,14 @@ pub struct ManagedIdentityCredentialOptions {
     /// The [`ClientOptions`] to use for the credential's pipeline.
     pub client_options: ClientOptions,
 
+    /// How long to wait for the Azure Instance Metadata Service (IMDS) to respond to the credential's first request.
+    ///
+    /// When set, the first IMDS request is sent without retries, and the credential fails if IMDS doesn't respond
+    /// within this time. Set this when the credential is one of several tried in turn, so it fails quickly on a
+    /// machine without IMDS, e.g. outside Azure. When `None`, every request is retried following the IMDS retry
+    /// guidance, which can take over a minute to fail. Ignored by other managed identity sources.
+    pub imds_probe_timeout: Option<Duration>,
+
     #[cfg(test)]
     pub(crate) env: Env,
 }
@@ -74,21 +83,14 @@ impl ManagedIdentityCredential {
 
         let credential: Arc<dyn TokenCredential> = match source {
             ManagedIdentitySource::AppService => {
-                // App Service does accept resource IDs, however this crate's current implementation sends
-                // them in the wrong query parameter: https://github.com/Azure/azure-sdk-for-rust/issues/2407
-                if let ImdsId::MsiResId(_) = id {
-                    return Err(azure_core::Error::with_message_fn(
-                        azure_core::error::ErrorKind::Credential,
-                        || {
-                            "User-assigned resource IDs aren't supported for App Service. Use a client or object ID instead.".to_string()
-                        },
-                    ));
-                }
                 AppServiceManagedIdentityCredential::new(id, options.client_options, env)?
             }
-            ManagedIdentitySource::Imds => {
-                VirtualMachineManagedIdentityCredential::new(id, options.client_options, env)?
-            }
+            ManagedIdentitySource::Imds => VirtualMachineManagedIdentityCredential::new(
+                id,
+                options.client_options,
+                options.imds_probe_timeout,
+                env,
+            )?,
             _ => {
                 return Err(azure_core::Error::with_message_fn(
                     azure_core::error::ErrorKind::Credential,
@@ -467,17 +469,13 @@ mod tests {
 
     #[tokio::test]
     async fn app_service_resource_id() {
-        let result = ManagedIdentityCredential::new(Some(ManagedIdentityCredentialOptions {
-            env: Env::from(&[(IDENTITY_ENDPOINT, "..."), (IDENTITY_HEADER, "x-id-header")][..]),
+        run_app_service_test(Some(ManagedIdentityCredentialOptions {
             user_assigned_id: Some(UserAssignedId::ResourceId(
                 "expected resource ID".to_string(),
             )),
             ..Default::default()
-        }));
-        assert!(
-            matches!(result, Err(ref e) if *e.kind() == azure_core::error::ErrorKind::Credential),
-            "Expected constructor error"
-        );
+        }))
+        .await;
     }
 
     #[test]
@@ -583,6 +581,81 @@ mod tests {
         run_error_response_test(ManagedIdentitySource::Imds).await
     }
 
+    #[tokio::test]
+    async fn imds_unavailable() {
+        let requests = Arc::new(AtomicUsize::new(0));
+        let mock_client = {
+            let requests = requests.clone();
+            MockHttpClient::new(move |_| {
+                requests.fetch_add(1, Ordering::SeqCst);
+                async move {
+                    Err(azure_core::Error::with_message(
+                        ErrorKind::Io,
+                        "connection refused",
+                    ))
+                }
+                .boxed()
+            })
+        };
+        let credential = ManagedIdentityCredential::new(Some(ManagedIdentityCredentialOptions {
+            client_options: ClientOptions {
+                transport: Some(Transport::new(Arc::new(mock_client))),
+                ..Default::default()
+            },
+            imds_probe_timeout: Some(Duration::seconds(1)),
+            env: Env::from(&[][..]),
+            ..Default::default()
+        }))
+        .expect("credential");
+
+        let err = credential
+            .get_token(LIVE_TEST_SCOPES, None)
+            .await
+            .expect_err("expected error");
+        assert!(matches!(err.kind(), ErrorKind::Credential));
+        assert!(
+            err.to_string()
+                .contains("The managed identity endpoint is unavailable"),
+            "{err}"
+        );
+        // the probe isn't retried
+        assert_eq!(1, requests.load(Ordering::SeqCst));
+    }
+
+    #[tokio::test]
+    async fn imds_probe_timeout() {
+        let requests = Arc::new(AtomicUsize::new(0));
+        let mock_client = {
+            let requests = requests.clone();
+            MockHttpClient::new(move |_| {
+                requests.fetch_add(1, Ordering::SeqCst);
+                futures::future::pending().boxed()
+            })
+        };
+        let credential = ManagedIdentityCredential::new(Some(ManagedIdentityCredentialOptions {
+            client_options: ClientOptions {
+                transport: Some(Transport::new(Arc::new(mock_client))),
+                ..Default::default()
+            },
+            imds_probe_timeout: Some(Duration::milliseconds(10)),
+            env: Env::from(&[][..]),
+            ..Default::default()
+        }))
+        .expect("credential");
+
+        let err = credential
+            .get_token(LIVE_TEST_SCOPES, None)
+            .await
+            .expect_err("expected error");
+        assert!(matches!(err.kind(), ErrorKind::Credential));
+        assert!(
+            err.to_string()
+                .contains("The managed identity endpoint didn't respond within 10 ms"),
+            "{err}"
+        );
+        assert_eq!(1, requests.load(Ordering::SeqCst));
+    }
+
     #[tokio::test]
     async fn imds_object_id() {
         run_imds_test(Some(ManagedIdentityCredentialOptions {
@@ -0,0 +1,326 @@
+// Copyright (c) Microsoft Corporation. All rights reserved.
+// Licensed under the MIT License.
+
+use crate::{
+    authentication_error, get_authority_host, resolve_tenant_id, token_endpoint, TokenCache,
+};
+use azure_core::credentials::TokenRequestOptions;
+use azure_core::http::PipelineSendOptions;
+use azure_core::Result;
+use azure_core::{
+    credentials::{AccessToken, Secret, TokenCredential},
+    error::ErrorKind,
+    http::{
+        headers::{self, content_type},
+        ClientOptions, Method, Pipeline, Request, Url,
+    },
+    Error,
+};
+use std::{any::type_name, fmt, str, sync::Arc};
+use url::form_urlencoded;
+
+const JWT_BEARER_GRANT_TYPE: &str = "urn:ietf:params:oauth:grant-type:jwt-bearer";
+
+/// Options for constructing a new [`OnBehalfOfCredential`].
+#[derive(Default)]
+pub struct OnBehalfOfCredentialOptions {
+    /// Options for the credential's HTTP pipeline.
+    pub client_options: ClientOptions,
+
+    /// Tenants, in addition to the configured tenant, for which the credential may acquire tokens.
+    /// See [`ALL_TENANTS`](crate::ALL_TENANTS) for how to request them.
+    pub additionally_allowed_tenants: Vec<String>,
+}
+
+impl fmt::Debug for OnBehalfOfCredentialOptions {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct(type_name::<Self>()).finish_non_exhaustive()
+    }
+}
+
+/// Authenticates a middle-tier service on behalf of a user with the [on-behalf-of flow].
+///
+/// The service authenticates as itself with a client secret and exchanges an access token it received
+/// from a user (the user assertion) for a token to a downstream resource. Each credential is bound to
+/// one user assertion and caches tokens for that user only, so create a credential per incoming request.
+///
+/// [on-behalf-of flow]: https://learn.microsoft.com/entra/identity-platform/v2-oauth2-on-behalf-of-flow
+pub struct OnBehalfOfCredential {
+    cache: TokenCache,
+    client_id: String,
+    tenant_id: String,
+    additionally_allowed_tenants: Vec<String>,
+    authority_host: Url,
+    pipeline: Pipeline,
+    secret: Secret,
+    user_assertion: Secret,
+}
+
+impl fmt::Debug for OnBehalfOfCredential {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct(type_name::<Self>())
+            .field("client_id", &self.client_id)
+            .field("tenant_id", &self.tenant_id)
+            .field("authority_host", &self.authority_host)
+            .finish_non_exhaustive()
+    }
+}
+
+impl OnBehalfOfCredential {
+    /// Create a new `OnBehalfOfCredential`.
+    ///
+    /// # Arguments
+    /// - `tenant_id`: The tenant (directory) ID of the service principal.
+    /// - `client_id`: The client (application) ID of the service principal.
+    /// - `secret`: The client secret that was generated for the service principal.
+    /// - `user_assertion`: The access token the service received from the user, issued for the service.
+    /// - `options`: Options for configuring the credential. If `None`, the credential uses its default options.
+    ///
+    pub fn new(
+        tenant_id: &str,
+        client_id: String,
+        secret: Secret,
+        user_assertion: Secret,
+        options: Option<OnBehalfOfCredentialOptions>,
+    ) -> Result<Arc<Self>> {
+        crate::validate_tenant_id(tenant_id)?;
+        crate::validate_not_empty(&client_id, "no client ID specified")?;
+        crate::validate_not_empty(secret.secret(), "no secret specified")?;
+        crate::validate_not_empty(user_assertion.secret(), "no user assertion specified")?;
+
+        let options = options.unwrap_or_default();
+        let authority_host = get_authority_host(None, options.client_options.cloud.as_deref())?;
+        // validate the configured tenant's endpoint now rather than on the first token request
+        token_endpoint(&authority_host, tenant_id)?;
+
+        let pipeline = Pipeline::new(
+            option_env!("CARGO_PKG_NAME"),
+            option_env!("CARGO_PKG_VERSION"),
+            options.client_options,
+            Vec::default(),
+            Vec::default(),
+            None,
+        );
+
+        Ok(Arc::new(Self {
+            cache: TokenCache::new(),
+            client_id,
+            tenant_id: tenant_id.to_string(),
+            additionally_allowed_tenants: options.additionally_allowed_tenants,
+            authority_host,
+            pipeline,
+            secret,
+            user_assertion,
+        }))
+    }
+
+    async fn get_token_impl(
+        &self,
+        tenant_id: &str,
+        scopes: &[&str],
+        options: Option<TokenRequestOptions<'_>>,
+    ) -> Result<AccessToken> {
+        let endpoint = token_endpoint(&self.authority_host, tenant_id)?;
+        let mut req = Request::new(endpoint, Method::Post);
+        req.insert_header(
+            headers::CONTENT_TYPE,
+            content_type::APPLICATION_X_WWW_FORM_URLENCODED,
+        );
+        let body = form_urlencoded::Serializer::new(String::new())
+            .append_pair("assertion", self.user_assertion.secret())
+            .append_pair("client_id", &self.client_id)
+            .append_pair("client_secret", self.secret.secret())
+            .append_pair("grant_type", JWT_BEARER_GRANT_TYPE)
+            .append_pair("requested_token_use", "on_behalf_of")
+            .append_pair("scope", &scopes.join(" "))
+            .finish();
+        req.set_body(body);
+
+        let options = options.unwrap_or_default();
+        let ctx = options.method_options.context.to_borrowed();
+        let res = self
+            .pipeline
+            .send(
+                &ctx,
+                &mut req,
+                Some(PipelineSendOptions {
+                    skip_checks: true,
+                    ..Default::default()
+                }),
+            )
+            .await?;
+
+        crate::handle_entra_response(res)
+    }
+}
+
+#[async_trait::async_trait]
+impl TokenCredential for OnBehalfOfCredential {
+    async fn get_token(
+        &self,
+        scopes: &[&str],
+        options: Option<TokenRequestOptions<'_>>,
+    ) -> Result<AccessToken> {
+        if scopes.is_empty() {
+            return Err(Error::with_message(
+                ErrorKind::Credential,
+                "no scopes specified",
+            ));
+        }
+        let requested_tenant_id = resolve_tenant_id(
+            Some(&self.tenant_id),
+            options.as_ref(),
+            &self.additionally_allowed_tenants,
+        )?
+        .map(ToString::to_string);
+        let tenant_id = requested_tenant_id.as_deref().unwrap_or(&self.tenant_id);
+        self.cache
+            .get_token(scopes, requested_tenant_id.as_deref(), options, |s, o| {
+                self.get_token_impl(tenant_id, s, o)
+            })
+            .await
+            .map_err(|err| authentication_error(stringify!(OnBehalfOfCredential), err))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::*;
+    use azure_core::{
+        http::{headers::Headers, AsyncRawResponse, Body, StatusCode, Transport},
+        Bytes,
+    };
+    use std::collections::HashMap;
+
+    const FAKE_SECRET: &str = "fake secret";
+    const FAKE_USER_ASSERTION: &str = "fake user assertion";
+
+    fn is_valid_request(expected_authority: String) -> impl Fn(&Request) -> Result<()> {
+        let expected_url = format!("{expected_authority}/oauth2/v2.0/token");
+        move |req: &Request| {
+            assert_eq!(Method::Post, req.method());
+            assert_eq!(expected_url, req.url().to_string());
+            assert_eq!(
+                content_type::APPLICATION_X_WWW_FORM_URLENCODED.as_str(),
+                req.headers().get_str(&headers::CONTENT_TYPE).unwrap()
+            );
+            let body = match req.body() {
+                Body::Bytes(bytes) => str::from_utf8(bytes).unwrap(),
+                _ => panic!("unexpected body type"),
+            };
+            let actual_params: HashMap<String, String> = form_urlencoded::parse(body.as_bytes())
+                .map(|(k, v)| (k.to_string(), v.to_string()))
+                .collect();
+            let expected_params = [
+                ("assertion", FAKE_USER_ASSERTION),
+                ("client_id", FAKE_CLIENT_ID),
+                ("client_secret", FAKE_SECRET),
+                ("grant_type", JWT_BEARER_GRANT_TYPE),
+                ("requested_token_use", "on_behalf_of"),
+                ("scope", &LIVE_TEST_SCOPES.join(" ")),
+            ];
+            for (key, value) in expected_params.iter() {
+                assert_eq!(
+                    *value,
+                    actual_params
+                        .get(*key)
+                        .unwrap_or_else(|| panic!("no {} in request body", key))
+                );
+            }
+            Ok(())
+        }
+    }
+
+    fn new_credential(sts: MockSts) -> Arc<OnBehalfOfCredential> {
+        OnBehalfOfCredential::new(
+            FAKE_TENANT_ID,
+            FAKE_CLIENT_ID.to_string(),
+            FAKE_SECRET.into(),
+            FAKE_USER_ASSERTION.into(),
+            Some(OnBehalfOfCredentialOptions {
+                client_options: ClientOptions {
+                    transport: Some(Transport::new(Arc::new(sts))),
+                    ..Default::default()
+                },
+                ..Default::default()
+            }),
+        )
+        .expect("valid credential")
+    }
+
+    #[tokio::test]
+    async fn get_token_error() {
+        let body = Bytes::from(
+            r#"{"error":"invalid_grant","error_description":"AADSTS50013: Assertion failed signature validation.","error_codes":[50013],"timestamp":"2025-04-04 21:10:04Z","trace_id":"...","correlation_id":"..."}"#,
+        );
+        let sts = MockSts::new(
+            vec![AsyncRawResponse::from_bytes(
+                StatusCode::BadRequest,
+                Headers::default(),
+                body,
+            )],
+            Some(Arc::new(is_valid_request(
+                FAKE_PUBLIC_CLOUD_AUTHORITY.to_string(),
+            ))),
+        );
+
+        let err = new_credential(sts)
+            .get_token(LIVE_TEST_SCOPES, None)
+            .await
+            .expect_err("expected error");
+        assert!(matches!(err.kind(), ErrorKind::Credential));
+        assert_eq!(
+            "OnBehalfOfCredential authentication failed. AADSTS50013: Assertion failed signature validation.",
+            err.to_string(),
+        );
+    }
+
+    #[tokio::test]
+    async fn get_token_success() {
+        let sts = MockSts::new(
+            vec![token_response()],
+            Some(Arc::new(is_valid_request(
+                FAKE_PUBLIC_CLOUD_AUTHORITY.to_string(),
+            ))),
+        );
+        let cred = new_credential(sts);
+        let token = cred.get_token(LIVE_TEST_SCOPES, None).await.expect("token");
+        assert_eq!(FAKE_TOKEN, token.token.secret());
+
+        // sts will return an error if the credential sends another request
+        let cached_token = cred
+            .get_token(LIVE_TEST_SCOPES, None)
+            .await
+            .expect("cached token");
+        assert_eq!(token.token.secret(), cached_token.token.secret());
+        assert_eq!(token.expires_on, cached_token.expires_on);
+    }
+
+    #[test]
+    fn no_user_assertion() {
+        OnBehalfOfCredential::new(
+            FAKE_TENANT_ID,
+            FAKE_CLIENT_ID.to_string(),
+            FAKE_SECRET.into(),
+            "".into(),
+            None,
+        )
+        .expect_err("no user assertion");
+    }
+
+    #[tokio::test]
+    async fn no_scopes() {
+        OnBehalfOfCredential::new(
+            FAKE_TENANT_ID,
+            FAKE_CLIENT_ID.to_string(),
+            FAKE_SECRET.into(),
+            FAKE_USER_ASSERTION.into(),
+            None,
+        )
+        .expect("valid credential")
+        .get_token(&[], None)
+        .await
+        .expect_err("no scopes specified");
+    }
+}
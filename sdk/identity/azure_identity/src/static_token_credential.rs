@@ -0,0 +1,90 @@
+// Copyright (c) Microsoft Corporation. All rights reserved.
+// Licensed under the MIT License.
+
+use azure_core::{
+    credentials::{AccessToken, TokenCredential, TokenRequestOptions},
+    error::{Error, ErrorKind},
+    time,
+};
+use std::{any::type_name, fmt, sync::Arc};
+
+/// Provides a token the application acquired elsewhere.
+///
+/// The credential returns the same token for every request, regardless of scopes, until the token expires.
+/// It then returns an error, because it can't acquire another token. Use [`CallbackTokenCredential`](crate::CallbackTokenCredential)
+/// to get new tokens from an external token broker.
+pub struct StaticTokenCredential {
+    token: AccessToken,
+}
+
+impl fmt::Debug for StaticTokenCredential {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct(type_name::<Self>())
+            .field("expires_on", &self.token.expires_on)
+            .finish_non_exhaustive()
+    }
+}
+
+impl StaticTokenCredential {
+    /// Create a new `StaticTokenCredential`.
+    ///
+    /// # Arguments
+    /// - `token`: The token to provide for every request.
+    pub fn new(token: AccessToken) -> Arc<Self> {
+        Arc::new(Self { token })
+    }
+}
+
+#[async_trait::async_trait]
+impl TokenCredential for StaticTokenCredential {
+    async fn get_token(
+        &self,
+        _: &[&str],
+        _: Option<TokenRequestOptions<'_>>,
+    ) -> azure_core::Result<AccessToken> {
+        if self.token.expires_on <= time::now_utc() {
+            return Err(Error::with_message_fn(ErrorKind::Credential, || {
+                format!(
+                    "StaticTokenCredential's token expired at {}",
+                    self.token.expires_on
+                )
+            }));
+        }
+        Ok(self.token.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::{FAKE_TOKEN, LIVE_TEST_SCOPES};
+    use azure_core::time::{Duration, OffsetDateTime};
+
+    #[tokio::test]
+    async fn returns_token() {
+        let expires_on = OffsetDateTime::now_utc() + Duration::hours(1);
+        let credential = StaticTokenCredential::new(AccessToken::new(FAKE_TOKEN, expires_on));
+
+        let token = credential
+            .get_token(LIVE_TEST_SCOPES, None)
+            .await
+            .expect("token");
+
+        assert_eq!(FAKE_TOKEN, token.token.secret());
+        assert_eq!(expires_on, token.expires_on);
+    }
+
+    #[tokio::test]
+    async fn expired_token() {
+        let credential = StaticTokenCredential::new(AccessToken::new(
+            FAKE_TOKEN,
+            OffsetDateTime::now_utc() - Duration::seconds(1),
+        ));
+
+        let err = credential
+            .get_token(LIVE_TEST_SCOPES, None)
+            .await
+            .expect_err("expired token");
+        assert!(matches!(err.kind(), ErrorKind::Credential));
+    }
+}
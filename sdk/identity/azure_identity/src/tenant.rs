@@ -0,0 +1,141 @@
+// Copyright (c) Microsoft Corporation. All rights reserved.
+// Licensed under the MIT License.
+
+use crate::validate_tenant_id;
+use azure_core::{
+    credentials::TokenRequestOptions,
+    error::{ErrorKind, ResultExt},
+    http::Url,
+    Error, Result,
+};
+
+/// Allows a credential to acquire tokens for any tenant when included in `additionally_allowed_tenants`.
+///
+/// Credentials acquire tokens from their configured tenant unless a token request sets
+/// [`TokenRequestOptions::tenant_id`]. They return an error for any other tenant unless their
+/// `additionally_allowed_tenants` option includes it, or includes `ALL_TENANTS` to allow any tenant the identity can access.
+///
+/// ```no_run
+/// use azure_core::credentials::{TokenCredential, TokenRequestOptions};
+/// use azure_identity::{ClientSecretCredential, ClientSecretCredentialOptions, ALL_TENANTS};
+///
+/// # async fn example() -> azure_core::Result<()> {
+/// let credential = ClientSecretCredential::new(
+///     "tenant ID",
+///     "client ID".to_string(),
+///     "client secret".to_string().into(),
+///     Some(ClientSecretCredentialOptions {
+///         additionally_allowed_tenants: vec![ALL_TENANTS.to_string()],
+///         ..Default::default()
+///     }),
+/// )?;
+/// let options = TokenRequestOptions {
+///     tenant_id: Some("other tenant ID".to_string()),
+///     ..Default::default()
+/// };
+/// let token = credential
+///     .get_token(&["https://management.azure.com/.default"], Some(options))
+///     .await?;
+/// # Ok(()) }
+/// ```
+pub const ALL_TENANTS: &str = "*";
+
+/// Gets the tenant other than the configured `tenant_id` that `options` requests a token from.
+///
+/// Returns `None` when `options` doesn't request a tenant or requests `tenant_id`, so tokens for the configured tenant
+/// share a single cache key, or an error when the credential doesn't allow the requested tenant.
+pub(crate) fn resolve_tenant_id<'a>(
+    tenant_id: Option<&str>,
+    options: Option<&'a TokenRequestOptions<'_>>,
+    additionally_allowed_tenants: &[String],
+) -> Result<Option<&'a str>> {
+    let Some(requested) = options.and_then(|options| options.tenant_id.as_deref()) else {
+        return Ok(None);
+    };
+    if tenant_id.is_some_and(|tenant_id| tenant_id.eq_ignore_ascii_case(requested)) {
+        return Ok(None);
+    }
+    validate_tenant_id(requested)?;
+    if additionally_allowed_tenants
+        .iter()
+        .any(|allowed| allowed == ALL_TENANTS || allowed.eq_ignore_ascii_case(requested))
+    {
+        return Ok(Some(requested));
+    }
+
+    Err(Error::with_message(
+        ErrorKind::Credential,
+        format!("the credential isn't configured to acquire tokens for tenant {requested}. To allow it, add the tenant to additionally_allowed_tenants in the credential's options, or add \"{ALL_TENANTS}\" to allow any tenant"),
+    ))
+}
+
+/// Gets the Microsoft Entra ID token endpoint for `tenant_id`.
+pub(crate) fn token_endpoint(authority_host: &Url, tenant_id: &str) -> Result<Url> {
+    authority_host
+        .join(&format!("/{tenant_id}/oauth2/v2.0/token"))
+        .with_context_fn(ErrorKind::DataConversion, || {
+            format!("tenant_id '{tenant_id}' could not be URL encoded")
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::with_tenant_id;
+
+    const TENANT: &str = "configured";
+
+    fn resolve(
+        requested: Option<&str>,
+        additionally_allowed_tenants: &[&str],
+    ) -> Result<Option<String>> {
+        let options = requested.map(with_tenant_id);
+        let allowed: Vec<String> = additionally_allowed_tenants
+            .iter()
+            .map(ToString::to_string)
+            .collect();
+        resolve_tenant_id(Some(TENANT), options.as_ref(), &allowed).map(|t| t.map(String::from))
+    }
+
+    #[test]
+    fn configured_tenant_by_default() {
+        assert_eq!(None, resolve(None, &[]).unwrap());
+        assert_eq!(None, resolve(Some(TENANT), &[]).unwrap());
+        assert_eq!(None, resolve(Some("CONFIGURED"), &[]).unwrap());
+    }
+
+    #[test]
+    fn additionally_allowed_tenant() {
+        assert_eq!(
+            Some("other".to_string()),
+            resolve(Some("other"), &["Other"]).unwrap()
+        );
+        assert_eq!(
+            Some("other".to_string()),
+            resolve(Some("other"), &[ALL_TENANTS]).unwrap()
+        );
+    }
+
+    #[test]
+    fn disallowed_tenant() {
+        let err = resolve(Some("other"), &["another"]).expect_err("disallowed tenant");
+        assert!(matches!(err.kind(), ErrorKind::Credential));
+        assert!(err.to_string().contains("tenant other"), "{err}");
+    }
+
+    #[test]
+    fn invalid_tenant() {
+        resolve(Some("not/a/tenant"), &[ALL_TENANTS]).expect_err("invalid tenant");
+    }
+
+    #[test]
+    fn no_configured_tenant() {
+        let options = with_tenant_id("other");
+        assert_eq!(None, resolve_tenant_id(None, None, &[]).unwrap());
+        resolve_tenant_id(None, Some(&options), &[]).expect_err("disallowed tenant");
+        assert_eq!(
+            Some("other"),
+            resolve_tenant_id(None, Some(&options), &["other".to_string()]).unwrap()
+        );
+    }
+}
@@ -2,7 +2,7 @@
 // Licensed under the MIT License.
 
 use crate::env::Env;
-use crate::{ImdsId, ImdsManagedIdentityCredential};
+use crate::{ImdsId, ImdsManagedIdentityCredential, ImdsProbe};
 use azure_core::{
     credentials::{AccessToken, TokenCredential, TokenRequestOptions},
     http::{
@@ -32,27 +32,30 @@ impl VirtualMachineManagedIdentityCredential {
     pub fn new(
         id: ImdsId,
         client_options: ClientOptions,
+        probe_timeout: Option<Duration>,
         env: Env,
     ) -> azure_core::Result<Arc<Self>> {
         let endpoint = Url::parse(ENDPOINT).unwrap(); // valid url constant
+
+        // https://learn.microsoft.com/entra/identity/managed-identities-azure-resources/how-to-use-vm-token#error-handling
+        let retry_status_codes = Vec::from([
+            StatusCode::NotFound,
+            StatusCode::Gone,
+            StatusCode::TooManyRequests,
+            StatusCode::InternalServerError,
+            StatusCode::NotImplemented,
+            StatusCode::BadGateway,
+            StatusCode::ServiceUnavailable,
+            StatusCode::GatewayTimeout,
+            StatusCode::HttpVersionNotSupported,
+            StatusCode::VariantAlsoNegotiates,
+            StatusCode::InsufficientStorage,
+            StatusCode::LoopDetected,
+            StatusCode::NotExtended,
+            StatusCode::NetworkAuthenticationRequired,
+        ]);
         let pipeline_options = Some(PipelineOptions {
-            // https://learn.microsoft.com/entra/identity/managed-identities-azure-resources/how-to-use-vm-token#error-handling
-            retry_status_codes: Vec::from([
-                StatusCode::NotFound,
-                StatusCode::Gone,
-                StatusCode::TooManyRequests,
-                StatusCode::InternalServerError,
-                StatusCode::NotImplemented,
-                StatusCode::BadGateway,
-                StatusCode::ServiceUnavailable,
-                StatusCode::GatewayTimeout,
-                StatusCode::HttpVersionNotSupported,
-                StatusCode::VariantAlsoNegotiates,
-                StatusCode::InsufficientStorage,
-                StatusCode::LoopDetected,
-                StatusCode::NotExtended,
-                StatusCode::NetworkAuthenticationRequired,
-            ]),
+            retry_status_codes: retry_status_codes.clone(),
             ..Default::default()
         });
         // these settings approximate the recommendations at
@@ -70,11 +73,17 @@ impl VirtualMachineManagedIdentityCredential {
             credential: ImdsManagedIdentityCredential::new(
                 endpoint,
                 API_VERSION,
+                "msi_res_id",
                 SECRET_HEADER,
                 SECRET_ENV,
                 id,
                 client_options,
                 pipeline_options,
+                // when requested, fail fast rather than retrying for over a minute when IMDS doesn't exist, e.g. outside Azure
+                probe_timeout.map(|timeout| ImdsProbe {
+                    timeout,
+                    retry_status_codes,
+                }),
                 env,
             ),
         }))
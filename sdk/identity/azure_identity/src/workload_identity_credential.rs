@@ -264,6 +264,7 @@ mod tests {
                     transport: Some(Transport::new(Arc::new(mock))),
                     ..Default::default()
                 },
+                ..Default::default()
             },
             env: Env::from(
                 &[
@@ -306,6 +307,7 @@ mod tests {
                     transport: Some(Transport::new(Arc::new(mock))),
                     ..Default::default()
                 },
+                ..Default::default()
             },
             env: Env::from(
                 &[
@@ -434,6 +436,7 @@ mod tests {
                     transport: Some(Transport::new(Arc::new(mock))),
                     ..Default::default()
                 },
+                ..Default::default()
             },
             env: Env::from(
                 &[
@@ -98,6 +98,7 @@ impl OnRequest for KeyVaultAuthorizer {
                         method_options: azure_core::http::ClientMethodOptions {
                             context: ctx.to_owned(),
                         },
+                        ..Default::default()
                     },
                 )
                 .await
@@ -168,6 +169,7 @@ impl OnChallenge for KeyVaultAuthorizer {
             method_options: azure_core::http::ClientMethodOptions {
                 context: context.to_owned(),
             },
+            ..Default::default()
         };
         authorizer
             .authorize(request, &[scope.as_str()], options)
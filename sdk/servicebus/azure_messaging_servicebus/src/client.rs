@@ -303,6 +303,79 @@ impl ServiceBusClient {
         .await
     }
 
+    /// Accepts a message session of a session-enabled queue.
+    ///
+    /// The returned receiver holds an exclusive lock on the session and only receives
+    /// messages whose `session_id` matches. Use
+    /// [`Receiver::renew_session_lock`] to keep the lock, and
+    /// [`Receiver::get_session_state`] and [`Receiver::set_session_state`] to
+    /// record progress through the session.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use azure_identity::DeveloperToolsCredential;
+    /// use azure_messaging_servicebus::ServiceBusClient;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let credential = DeveloperToolsCredential::new(None)?;
+    ///     let client = ServiceBusClient::builder()
+    ///         .open("my-servicebus.servicebus.windows.net", credential.clone()).await?;
+    ///
+    ///     let receiver = client.accept_session("my-queue", "order-42", None).await?;
+    ///     let messages = receiver.receive_messages(10, None).await?;
+    ///     for message in &messages {
+    ///         receiver.complete_message(message, None).await?;
+    ///     }
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn accept_session(
+        &self,
+        queue_name: &str,
+        session_id: &str,
+        options: Option<CreateReceiverOptions>,
+    ) -> Result<Receiver> {
+        let receiver = self.create_receiver(queue_name, options).await?;
+        Ok(receiver.with_session_id(session_id.to_string()))
+    }
+
+    /// Accepts a message session of a session-enabled topic subscription.
+    ///
+    /// See [`accept_session`](ServiceBusClient::accept_session) for details.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use azure_identity::DeveloperToolsCredential;
+    /// use azure_messaging_servicebus::ServiceBusClient;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let credential = DeveloperToolsCredential::new(None)?;
+    ///     let client = ServiceBusClient::builder()
+    ///         .open("my-servicebus.servicebus.windows.net", credential.clone()).await?;
+    ///
+    ///     let receiver = client
+    ///         .accept_session_for_subscription("my-topic", "my-subscription", "order-42", None)
+    ///         .await?;
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn accept_session_for_subscription(
+        &self,
+        topic_name: &str,
+        subscription_name: &str,
+        session_id: &str,
+        options: Option<CreateReceiverOptions>,
+    ) -> Result<Receiver> {
+        let receiver = self
+            .create_receiver_for_subscription(topic_name, subscription_name, options)
+            .await?;
+        Ok(receiver.with_session_id(session_id.to_string()))
+    }
+
     /// Gets the fully qualified namespace.
     pub fn fully_qualified_namespace(&self) -> &str {
         &self.namespace
@@ -107,12 +107,16 @@ use azure_core::{fmt::SafeDebug, time::Duration, time::OffsetDateTime, Uuid};
 use azure_core_amqp::{
     message::{AmqpMessageBody, AmqpMessageId},
     AmqpConnection, AmqpDelivery, AmqpDeliveryApis, AmqpManagementApis, AmqpReceiver,
-    AmqpReceiverApis, AmqpSession, AmqpSessionApis, AmqpSimpleValue, AmqpSource, AmqpValue,
+    AmqpReceiverApis, AmqpSession, AmqpSessionApis, AmqpSimpleValue, AmqpSource, AmqpSymbol,
+    AmqpValue,
 };
 use futures::{select, FutureExt};
 use std::{collections::HashMap, sync::Arc};
 use tracing::{debug, trace, warn};
 
+/// Source filter that binds a receiver link to a single message session.
+const SESSION_FILTER: &str = "com.microsoft:session-filter";
+
 /// Represents the lock style to use for a receiver - either `PeekLock` or `ReceiveAndDelete`.
 ///
 /// This enum controls when a message is deleted from Service Bus and determines how message
@@ -535,6 +539,7 @@ pub struct Receiver {
     entity_name: String,
     subscription_name: Option<String>,
     receive_mode: ReceiveMode,
+    session_id: Option<String>,
     _options: ServiceBusClientOptions,
     // Cached session and receiver to avoid creating new ones on each call
     session: OnceCell<Arc<AmqpSession>>,
@@ -586,6 +591,7 @@ impl Receiver {
             entity_name,
             subscription_name,
             receive_mode,
+            session_id: None,
             _options: options,
             session: OnceCell::new(),
             amqp_receiver: OnceCell::new(),
@@ -593,6 +599,15 @@ impl Receiver {
         })
     }
 
+    /// Binds the receiver to the message session `session_id` of a session-enabled entity.
+    ///
+    /// Used by [`crate::ServiceBusClient::accept_session`] and
+    /// [`crate::ServiceBusClient::accept_session_for_subscription`].
+    pub(crate) fn with_session_id(mut self, session_id: String) -> Self {
+        self.session_id = Some(session_id);
+        self
+    }
+
     /// Receives a single message from the Service Bus entity.
     ///
     /// This is a convenience method that calls [`receive_messages`](Receiver::receive_messages)
@@ -767,7 +782,14 @@ impl Receiver {
 
                 // Create AMQP receiver
                 let entity_path = self.get_entity_path();
-                let amqp_source = AmqpSource::builder().with_address(entity_path).build();
+                let mut amqp_source = AmqpSource::builder().with_address(entity_path);
+                if let Some(session_id) = &self.session_id {
+                    amqp_source = amqp_source.add_to_filter(
+                        AmqpSymbol::from(SESSION_FILTER),
+                        AmqpValue::String(session_id.clone()),
+                    );
+                }
+                let amqp_source = amqp_source.build();
                 let amqp_receiver = AmqpReceiver::new();
                 amqp_receiver
                     .attach(&session, amqp_source, None)
@@ -1730,35 +1752,7 @@ impl Receiver {
                 )
             })?;
 
-        // Extract the new expiration time from the response
-        let locked_until = response
-            .get("expiration")
-            .or_else(|| response.get("locked-until-utc"))
-            .ok_or_else(|| {
-                ServiceBusError::new(
-                    ErrorKind::InvalidRequest,
-                    "Management response did not contain expiration time",
-                )
-            })?;
-
-        // Convert the response value to an OffsetDateTime
-        let locked_until = match locked_until {
-            azure_core_amqp::AmqpValue::TimeStamp(timestamp) => {
-                let timestamp: azure_core_amqp::AmqpTimestamp = timestamp.clone();
-                if let Some(system_time) = timestamp.0 {
-                    OffsetDateTime::from(system_time)
-                } else {
-                    OffsetDateTime::now_utc() + Duration::seconds(60)
-                }
-            }
-            _ => {
-                // Fallback to a default if we can't parse the timestamp
-                warn!(
-                    "Could not parse expiration timestamp from management response, using default"
-                );
-                OffsetDateTime::now_utc() + Duration::seconds(60)
-            }
-        };
+        let locked_until = locked_until_from_response(&response)?;
 
         trace!(
             "Message lock renewed successfully with lock token: {}, new expiration: {}",
@@ -1837,6 +1831,129 @@ impl Receiver {
         self.receive_mode.clone()
     }
 
+    /// Gets the ID of the message session this receiver is bound to.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Some(session_id)` for receivers created with
+    /// [`crate::ServiceBusClient::accept_session`], or `None` otherwise.
+    pub fn session_id(&self) -> Option<&str> {
+        self.session_id.as_deref()
+    }
+
+    /// Gets the state of the message session this receiver is bound to.
+    ///
+    /// # Returns
+    ///
+    /// Returns the state last set with [`set_session_state`](Receiver::set_session_state),
+    /// or `None` if the session has no state.
+    ///
+    /// # Errors
+    ///
+    /// This method will return an error if:
+    /// - The receiver is not bound to a session
+    /// - The session lock has been lost
+    /// - A network or service error occurs
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # async fn example(receiver: azure_messaging_servicebus::Receiver) -> Result<(), Box<dyn std::error::Error>> {
+    /// if let Some(state) = receiver.get_session_state().await? {
+    ///     println!("Resuming session from state: {:?}", String::from_utf8_lossy(&state));
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_session_state(&self) -> Result<Option<Vec<u8>>> {
+        let session_id = self.require_session_id()?;
+        debug!("Getting session state for session: {}", session_id);
+
+        let management_client = self.ensure_management_client().await?;
+        get_session_state(&management_client, session_id).await
+    }
+
+    /// Sets the state of the message session this receiver is bound to.
+    ///
+    /// Session state lets a receiver record its progress through a session so that
+    /// the next receiver to accept the session can resume from it. Pass `None` to
+    /// clear the state.
+    ///
+    /// # Errors
+    ///
+    /// This method will return an error if:
+    /// - The receiver is not bound to a session
+    /// - The session lock has been lost
+    /// - A network or service error occurs
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # async fn example(receiver: azure_messaging_servicebus::Receiver) -> Result<(), Box<dyn std::error::Error>> {
+    /// receiver.set_session_state(Some(b"step-2".to_vec())).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn set_session_state(&self, state: Option<Vec<u8>>) -> Result<()> {
+        let session_id = self.require_session_id()?;
+        debug!("Setting session state for session: {}", session_id);
+
+        let management_client = self.ensure_management_client().await?;
+        set_session_state(&management_client, session_id, state).await?;
+
+        trace!("Session state set for session: {}", session_id);
+        Ok(())
+    }
+
+    /// Renews the lock on the message session this receiver is bound to.
+    ///
+    /// A session receiver holds an exclusive lock on its session. Renew the lock
+    /// before it expires to keep receiving from the session.
+    ///
+    /// # Returns
+    ///
+    /// Returns the new expiration time of the session lock.
+    ///
+    /// # Errors
+    ///
+    /// This method will return an error if:
+    /// - The receiver is not bound to a session
+    /// - The session lock has already been lost
+    /// - A network or service error occurs
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # async fn example(receiver: azure_messaging_servicebus::Receiver) -> Result<(), Box<dyn std::error::Error>> {
+    /// let locked_until = receiver.renew_session_lock().await?;
+    /// println!("Session locked until: {:?}", locked_until);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn renew_session_lock(&self) -> Result<OffsetDateTime> {
+        let session_id = self.require_session_id()?;
+        debug!("Renewing session lock for session: {}", session_id);
+
+        let management_client = self.ensure_management_client().await?;
+        let locked_until = renew_session_lock(&management_client, session_id).await?;
+        trace!(
+            "Session lock renewed for session: {}, new expiration: {}",
+            session_id,
+            locked_until
+        );
+        Ok(locked_until)
+    }
+
+    /// Returns the session ID, or an error if the receiver is not bound to a session.
+    fn require_session_id(&self) -> Result<&str> {
+        self.session_id.as_deref().ok_or_else(|| {
+            ServiceBusError::new(
+                ErrorKind::InvalidRequest,
+                "Session operations are only supported on session receivers",
+            )
+        })
+    }
+
     /// Peeks at messages in the queue or subscription without removing them.
     ///
     /// This operation allows you to browse messages without affecting their state or
@@ -2236,6 +2353,131 @@ impl Receiver {
     }
 }
 
+/// Builds the body of a session management request.
+///
+/// Service Bus reads the session ID of session operations from the request body, not from
+/// the application properties.
+fn session_request_body(session_id: &str) -> azure_core_amqp::AmqpOrderedMap<String, AmqpValue> {
+    let mut body = azure_core_amqp::AmqpOrderedMap::new();
+    body.insert("session-id".to_string(), session_id.to_string().into());
+    body
+}
+
+/// Reads the state of a session through the management link.
+async fn get_session_state(
+    management_client: &impl AmqpManagementApis,
+    session_id: &str,
+) -> Result<Option<Vec<u8>>> {
+    let response = management_client
+        .call_with_body(
+            "com.microsoft:get-session-state".to_string(),
+            azure_core_amqp::AmqpOrderedMap::new(),
+            session_request_body(session_id),
+        )
+        .await
+        .map_err(|e| {
+            ServiceBusError::new(
+                ErrorKind::Amqp,
+                format!("Failed to get session state: {:?}", e),
+            )
+        })?;
+
+    match response.get("session-state") {
+        Some(AmqpValue::Binary(state)) => Ok(Some(state.clone())),
+        Some(AmqpValue::Null) | None => Ok(None),
+        Some(other) => Err(ServiceBusError::new(
+            ErrorKind::InvalidRequest,
+            format!(
+                "Unexpected session state in management response: {:?}",
+                other
+            ),
+        )),
+    }
+}
+
+/// Writes the state of a session through the management link.
+async fn set_session_state(
+    management_client: &impl AmqpManagementApis,
+    session_id: &str,
+    state: Option<Vec<u8>>,
+) -> Result<()> {
+    let mut body = session_request_body(session_id);
+    body.insert(
+        "session-state".to_string(),
+        state.map_or(AmqpValue::Null, AmqpValue::Binary),
+    );
+
+    management_client
+        .call_with_body(
+            "com.microsoft:set-session-state".to_string(),
+            azure_core_amqp::AmqpOrderedMap::new(),
+            body,
+        )
+        .await
+        .map_err(|e| {
+            ServiceBusError::new(
+                ErrorKind::Amqp,
+                format!("Failed to set session state: {:?}", e),
+            )
+        })?;
+    Ok(())
+}
+
+/// Renews the lock on a session through the management link.
+async fn renew_session_lock(
+    management_client: &impl AmqpManagementApis,
+    session_id: &str,
+) -> Result<OffsetDateTime> {
+    let response = management_client
+        .call_with_body(
+            "com.microsoft:renew-session-lock".to_string(),
+            azure_core_amqp::AmqpOrderedMap::new(),
+            session_request_body(session_id),
+        )
+        .await
+        .map_err(|e| {
+            ServiceBusError::new(
+                ErrorKind::SessionLockLost,
+                format!("Failed to renew session lock: {:?}", e),
+            )
+        })?;
+
+    locked_until_from_response(&response)
+}
+
+/// Extracts the lock expiration time from a `renew-lock` or `renew-session-lock` management response.
+fn locked_until_from_response(
+    response: &azure_core_amqp::AmqpOrderedMap<String, AmqpValue>,
+) -> Result<OffsetDateTime> {
+    let locked_until = response
+        .get("expiration")
+        .or_else(|| response.get("locked-until-utc"))
+        .ok_or_else(|| {
+            ServiceBusError::new(
+                ErrorKind::InvalidRequest,
+                "Management response did not contain expiration time",
+            )
+        })?;
+
+    // Convert the response value to an OffsetDateTime
+    let locked_until = match locked_until {
+        AmqpValue::TimeStamp(timestamp) => {
+            let timestamp: azure_core_amqp::AmqpTimestamp = timestamp.clone();
+            if let Some(system_time) = timestamp.0 {
+                OffsetDateTime::from(system_time)
+            } else {
+                OffsetDateTime::now_utc() + Duration::seconds(60)
+            }
+        }
+        _ => {
+            // Fallback to a default if we can't parse the timestamp
+            warn!("Could not parse expiration timestamp from management response, using default");
+            OffsetDateTime::now_utc() + Duration::seconds(60)
+        }
+    };
+    Ok(locked_until)
+}
+
 impl Drop for Receiver {
     fn drop(&mut self) {
         let entity_path = self.get_entity_path();
@@ -2305,6 +2547,152 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn test_receiver_session_id() -> Result<()> {
+        let receiver = Receiver::new(
+            create_test_connection(),
+            "test-queue".to_string(),
+            None,
+            ReceiveMode::PeekLock,
+            create_test_options(),
+        )
+        .await?;
+        assert_eq!(receiver.session_id(), None);
+
+        let receiver = receiver.with_session_id("session-1".to_string());
+        assert_eq!(receiver.session_id(), Some("session-1"));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_session_operations_require_session() -> Result<()> {
+        let receiver = Receiver::new(
+            create_test_connection(),
+            "test-queue".to_string(),
+            None,
+            ReceiveMode::PeekLock,
+            create_test_options(),
+        )
+        .await?;
+
+        let error = receiver.get_session_state().await.unwrap_err();
+        assert_eq!(error.kind(), &ErrorKind::InvalidRequest);
+        let error = receiver.set_session_state(None).await.unwrap_err();
+        assert_eq!(error.kind(), &ErrorKind::InvalidRequest);
+        let error = receiver.renew_session_lock().await.unwrap_err();
+        assert_eq!(error.kind(), &ErrorKind::InvalidRequest);
+        Ok(())
+    }
+
+    /// The operation, application properties, and body of a management request.
+    type ManagementRequest = (
+        String,
+        azure_core_amqp::AmqpOrderedMap<String, AmqpSimpleValue>,
+        azure_core_amqp::AmqpOrderedMap<String, AmqpValue>,
+    );
+
+    /// A management client that records each request and replies with a canned response.
+    struct MockManagement {
+        response: azure_core_amqp::AmqpOrderedMap<String, AmqpValue>,
+        requests: std::sync::Mutex<Vec<ManagementRequest>>,
+    }
+
+    impl MockManagement {
+        fn new(response: azure_core_amqp::AmqpOrderedMap<String, AmqpValue>) -> Self {
+            Self {
+                response,
+                requests: std::sync::Mutex::new(Vec::new()),
+            }
+        }
+    }
+
+    #[async_trait::async_trait]
+    impl AmqpManagementApis for MockManagement {
+        async fn attach(&self) -> azure_core_amqp::error::Result<()> {
+            Ok(())
+        }
+
+        async fn detach(self) -> azure_core_amqp::error::Result<()> {
+            Ok(())
+        }
+
+        async fn call(
+            &self,
+            operation_type: String,
+            application_properties: azure_core_amqp::AmqpOrderedMap<String, AmqpSimpleValue>,
+        ) -> azure_core_amqp::error::Result<azure_core_amqp::AmqpOrderedMap<String, AmqpValue>>
+        {
+            self.call_with_body(
+                operation_type,
+                application_properties,
+                azure_core_amqp::AmqpOrderedMap::new(),
+            )
+            .await
+        }
+
+        async fn call_with_body(
+            &self,
+            operation_type: String,
+            application_properties: azure_core_amqp::AmqpOrderedMap<String, AmqpSimpleValue>,
+            body: azure_core_amqp::AmqpOrderedMap<String, AmqpValue>,
+        ) -> azure_core_amqp::error::Result<azure_core_amqp::AmqpOrderedMap<String, AmqpValue>>
+        {
+            self.requests
+                .lock()
+                .unwrap()
+                .push((operation_type, application_properties, body));
+            Ok(self.response.clone())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_session_operations_send_session_id_in_body() -> Result<()> {
+        let mut response = azure_core_amqp::AmqpOrderedMap::new();
+        response.insert(
+            "session-state".to_string(),
+            AmqpValue::Binary(b"step-2".to_vec()),
+        );
+        let locked_until = OffsetDateTime::now_utc() + Duration::seconds(30);
+        response.insert(
+            "expiration".to_string(),
+            AmqpValue::TimeStamp(azure_core_amqp::AmqpTimestamp(Some(locked_until.into()))),
+        );
+        let management = MockManagement::new(response);
+
+        assert_eq!(
+            get_session_state(&management, "session-1").await?,
+            Some(b"step-2".to_vec())
+        );
+        set_session_state(&management, "session-1", Some(b"step-3".to_vec())).await?;
+        assert_eq!(
+            renew_session_lock(&management, "session-1").await?,
+            locked_until
+        );
+
+        let requests = management.requests.lock().unwrap();
+        let operations: Vec<_> = requests.iter().map(|(op, _, _)| op.as_str()).collect();
+        assert_eq!(
+            operations,
+            [
+                "com.microsoft:get-session-state",
+                "com.microsoft:set-session-state",
+                "com.microsoft:renew-session-lock",
+            ]
+        );
+        for (_, application_properties, body) in requests.iter() {
+            assert!(application_properties.get("session-id").is_none());
+            assert_eq!(
+                body.get("session-id"),
+                Some(&AmqpValue::String("session-1".to_string()))
+            );
+        }
+        assert_eq!(
+            requests[1].2.get("session-state"),
+            Some(&AmqpValue::Binary(b"step-3".to_vec()))
+        );
+        Ok(())
+    }
+
     #[test]
     fn test_receive_mode_equality() {
         assert_eq!(ReceiveMode::PeekLock, ReceiveMode::PeekLock);
@@ -2341,6 +2729,7 @@ mod tests {
             entity_name: entity_name.clone(),
             subscription_name: None,
             receive_mode: ReceiveMode::PeekLock,
+            session_id: None,
             _options: options,
             session: OnceCell::new(),
             amqp_receiver: OnceCell::new(),
@@ -2362,6 +2751,7 @@ mod tests {
             entity_name: entity_name.clone(),
             subscription_name: Some(subscription_name.clone()),
             receive_mode: ReceiveMode::PeekLock,
+            session_id: None,
             _options: options,
             session: OnceCell::new(),
             amqp_receiver: OnceCell::new(),
@@ -2403,6 +2793,7 @@ mod tests {
             entity_name,
             subscription_name: None,
             receive_mode: ReceiveMode::PeekLock,
+            session_id: None,
             _options: options,
             session: OnceCell::new(),
             amqp_receiver: OnceCell::new(),
@@ -8,24 +8,99 @@ use crate::{
         clients::BlobClient as GeneratedBlobClient, models::BlobClientDownloadInternalOptions,
     },
     models::{
-        BlobClientDownloadIntoResult, BlobClientDownloadOptions, BlobClientDownloadResult,
-        BlobClientUploadOptions, BlobClientUploadResult, BlobDownloadProperties, HttpRange,
-        StorageErrorCode,
+        BlobClientAbortCopyFromUrlOptions, BlobClientCopyFromUrlOptions,
+        BlobClientCopyFromUrlResult, BlobClientDownloadIntoResult, BlobClientDownloadOptions,
+        BlobClientDownloadResult, BlobClientGetPropertiesOptions,
+        BlobClientStartCopyFromUrlOptions, BlobClientUploadOptions, BlobClientUploadResult,
+        BlobCopyStatus, BlobDownloadProperties, ChecksumAlgorithm, HttpRange, StorageErrorCode,
     },
     partitioned_transfer::{self, PartitionedDownloadBehavior},
     AppendBlobClient, BlockBlobClient, PageBlobClient,
 };
 use async_trait::async_trait;
 use azure_core::{
+    base64,
     credentials::TokenCredential,
-    error::ErrorKind,
+    error::{CheckSuccessOptions, ErrorKind},
     http::{
+        headers::{HeaderName, Headers},
         policies::{auth::BearerTokenAuthorizationPolicy, Policy},
-        AsyncRawResponse, Etag, NoFormat, Pipeline, RequestContent, StatusCode, Url, UrlExt,
+        poller::{
+            Poller, PollerContinuation, PollerResult, PollerState, PollerStatus, StatusMonitor,
+        },
+        AppendToUrlQuery, AsyncRawResponse, ClientMethodOptions, Etag, Method, NoFormat, Pipeline,
+        PipelineSendOptions, RawResponse, Request, RequestContent, Response, StatusCode, Url,
+        UrlExt,
     },
-    tracing, Bytes, Result,
+    json,
+    time::to_rfc7231,
+    tracing, Bytes, Error, Result,
+};
+use azure_storage_common::checksum::{validate_checksum, ValidatingStream};
+use futures::{io::AsyncWrite, AsyncWriteExt, TryStreamExt};
+use std::{
+    num::NonZero,
+    ops::Range,
+    sync::{Arc, OnceLock},
 };
-use std::{ops::Range, sync::Arc};
+
+/// Inserts the headers shared by [`BlobClient::start_copy_from_url()`] and [`BlobClient::copy_from_url()`]
+/// into `$request`, reading them from the fields of the same name in `$options`.
+macro_rules! insert_copy_headers {
+    ($request:ident, $options:ident, $copy_source:ident) => {
+        $request.insert_header("x-ms-copy-source", $copy_source.to_string());
+        if let Some(if_match) = $options.if_match.as_ref() {
+            $request.insert_header("if-match", if_match.to_string());
+        }
+        if let Some(if_modified_since) = $options.if_modified_since {
+            $request.insert_header("if-modified-since", to_rfc7231(&if_modified_since));
+        }
+        if let Some(if_none_match) = $options.if_none_match.as_ref() {
+            $request.insert_header("if-none-match", if_none_match.to_string());
+        }
+        if let Some(if_unmodified_since) = $options.if_unmodified_since {
+            $request.insert_header("if-unmodified-since", to_rfc7231(&if_unmodified_since));
+        }
+        if let Some(tier) = $options.tier.as_ref() {
+            $request.insert_header("x-ms-access-tier", tier.to_string());
+        }
+        if let Some(if_tags) = $options.if_tags.as_ref() {
+            $request.insert_header("x-ms-if-tags", if_tags);
+        }
+        if let Some(lease_id) = $options.lease_id.as_ref() {
+            $request.insert_header("x-ms-lease-id", lease_id);
+        }
+        if let Some(metadata) = $options.metadata.as_ref() {
+            for (k, v) in metadata {
+                $request.insert_header(format!("x-ms-meta-{k}"), v);
+            }
+        }
+        if let Some(source_if_match) = $options.source_if_match.as_ref() {
+            $request.insert_header("x-ms-source-if-match", source_if_match.to_string());
+        }
+        if let Some(source_if_modified_since) = $options.source_if_modified_since {
+            $request.insert_header(
+                "x-ms-source-if-modified-since",
+                to_rfc7231(&source_if_modified_since),
+            );
+        }
+        if let Some(source_if_none_match) = $options.source_if_none_match.as_ref() {
+            $request.insert_header(
+                "x-ms-source-if-none-match",
+                source_if_none_match.to_string(),
+            );
+        }
+        if let Some(source_if_unmodified_since) = $options.source_if_unmodified_since {
+            $request.insert_header(
+                "x-ms-source-if-unmodified-since",
+                to_rfc7231(&source_if_unmodified_since),
+            );
+        }
+        if let Some(blob_tags_string) = $options.blob_tags_string.as_ref() {
+            $request.insert_header("x-ms-tags", blob_tags_string);
+        }
+    };
+}
 
 impl BlobClient {
     /// Creates a new BlobClient from a blob URL.
@@ -191,20 +266,43 @@ impl BlobClient {
         let parallel = options
             .parallel
             .unwrap_or_else(crate::partitioned_transfer::defaults::default_concurrency);
-        let partition_size = options
-            .partition_size
-            .unwrap_or(crate::partitioned_transfer::defaults::DEFAULT_DOWNLOAD_PARTITION_SIZE);
+        let partition_size = download_partition_size(&options);
         let range = options.range.clone();
+        let progress = options.progress.clone();
+        let transfer_validation = options.transfer_validation;
         let inner_client = GeneratedBlobClient {
             endpoint: self.endpoint.clone(),
             pipeline: self.pipeline.clone(),
             version: self.version.clone(),
             tracer: self.tracer.clone(),
         };
-        let behavior = BlobClientDownloadBehavior::new(inner_client, options.into());
+        let behavior =
+            BlobClientDownloadBehavior::new(inner_client, options.into(), transfer_validation);
+        let response = partitioned_transfer::download(
+            range.clone(),
+            parallel,
+            partition_size,
+            Arc::new(behavior),
+            progress,
+        )
+        .await?;
+        // Each range was validated as it was read. Also validate the whole blob against its stored MD5.
         let response =
-            partitioned_transfer::download(range, parallel, partition_size, Arc::new(behavior))
-                .await?;
+            match blob_content_md5(transfer_validation, range.as_ref(), response.headers())? {
+                Some(expected) => {
+                    let (status, headers, body) = response.deconstruct();
+                    AsyncRawResponse::new(
+                        status,
+                        headers,
+                        Box::pin(ValidatingStream::new(
+                            Box::pin(body),
+                            ChecksumAlgorithm::Md5,
+                            expected,
+                        )),
+                    )
+                }
+                None => response,
+            };
         BlobClientDownloadResult::from_headers(response)
     }
 
@@ -235,25 +333,35 @@ impl BlobClient {
         let parallel = options
             .parallel
             .unwrap_or_else(crate::partitioned_transfer::defaults::default_concurrency);
-        let partition_size = options
-            .partition_size
-            .unwrap_or(crate::partitioned_transfer::defaults::DEFAULT_DOWNLOAD_PARTITION_SIZE);
+        let partition_size = download_partition_size(&options);
         let range = options.range.clone();
+        let progress = options.progress.clone();
+        let transfer_validation = options.transfer_validation;
         let inner_client = GeneratedBlobClient {
             endpoint: self.endpoint.clone(),
             pipeline: self.pipeline.clone(),
             version: self.version.clone(),
             tracer: self.tracer.clone(),
         };
-        let behavior = BlobClientDownloadBehavior::new(inner_client, options.into());
+        let behavior =
+            BlobClientDownloadBehavior::new(inner_client, options.into(), transfer_validation);
         let (_, headers, len) = partitioned_transfer::download_into(
             buffer,
-            range,
+            range.clone(),
             parallel,
             partition_size,
             Arc::new(behavior),
+            progress,
         )
         .await?;
+        // Each range was validated as it was read. Also validate the whole blob against its stored MD5.
+        if let Some(expected) = blob_content_md5(transfer_validation, range.as_ref(), &headers)? {
+            validate_checksum(
+                ChecksumAlgorithm::Md5,
+                &expected,
+                ChecksumAlgorithm::Md5.compute(&buffer[..len]),
+            )?;
+        }
         Ok(BlobClientDownloadIntoResult {
             len,
             properties: BlobDownloadProperties::from_headers(&headers)?,
@@ -261,6 +369,54 @@ impl BlobClient {
         })
     }
 
+    /// Downloads a blob and writes its contents to `writer`, such as a file.
+    ///
+    /// This operation performs the same managed (multi-part) download as [`BlobClient::download`],
+    /// and writes each range to `writer` in order as soon as it and all preceding ranges are
+    /// downloaded. Ranges whose connection fails are requested again from where they left off,
+    /// and every range must come from the same version of the blob as the initial response.
+    ///
+    /// To write to a [`tokio::fs::File`](https://docs.rs/tokio/latest/tokio/fs/struct.File.html),
+    /// wrap it with `tokio_util::compat::TokioAsyncWriteCompatExt::compat_write()`.
+    ///
+    /// # Arguments
+    ///
+    /// * `writer` - Destination to write the downloaded blob data to.
+    /// * `options` - Optional configuration for the request.
+    ///
+    /// # Notes
+    ///
+    /// By default, storage clients create their HTTP transport via
+    /// [`azure_core::http::new_http_client()`] with automatic decompression disabled.
+    /// If you set a custom transport in [`BlobClientOptions`] without also disabling
+    /// automatic decompression, partitioned downloads may not succeed.
+    #[tracing::function("Storage.Blob.Blob.download_to")]
+    pub async fn download_to<W>(
+        &self,
+        writer: &mut W,
+        options: Option<BlobClientDownloadOptions<'_>>,
+    ) -> Result<BlobClientDownloadIntoResult>
+    where
+        W: AsyncWrite + Unpin + Send + ?Sized,
+    {
+        let BlobClientDownloadResult {
+            mut body,
+            properties,
+            headers,
+        } = self.download(options).await?;
+        let mut len = 0;
+        while let Some(bytes) = body.try_next().await? {
+            writer.write_all(&bytes).await?;
+            len += bytes.len();
+        }
+        writer.flush().await?;
+        Ok(BlobClientDownloadIntoResult {
+            len,
+            properties,
+            headers,
+        })
+    }
+
     /// Uploads content to a block blob, overwriting any existing blob by default.
     ///
     /// Updating an existing block blob overwrites any existing metadata on the blob. Use [`BlobClientUploadOptions::if_not_exists()`] to fail instead of overwriting.
@@ -278,6 +434,209 @@ impl BlobClient {
         self.block_blob_client().upload(content, options).await
     }
 
+    /// Starts an asynchronous copy of a blob or file to this blob.
+    ///
+    /// The service copies the source in the background. The returned [`Poller`] checks the copy's status until it succeeds,
+    /// fails, or is aborted, yielding a [`BlobCopyStatus`] parsed from the blob's `x-ms-copy-*` headers for each check.
+    /// Awaiting the poller returns the properties of the destination blob once the copy succeeds.
+    /// To abort a pending copy, pass its [`BlobCopyStatus::copy_id`] to [`BlobClient::abort_copy_from_url()`].
+    ///
+    /// # Arguments
+    ///
+    /// * `copy_source` - The URL of the source blob or file. It must be public or include a SAS token.
+    /// * `options` - Optional parameters for the request.
+    #[tracing::function("Storage.Blob.Blob.start_copy_from_url")]
+    pub fn start_copy_from_url(
+        &self,
+        copy_source: &Url,
+        options: Option<BlobClientStartCopyFromUrlOptions<'_>>,
+    ) -> Result<Poller<BlobCopyStatus>> {
+        let options = options.unwrap_or_default().into_owned();
+        let client = Arc::new(GeneratedBlobClient {
+            endpoint: self.endpoint.clone(),
+            pipeline: self.pipeline.clone(),
+            version: self.version.clone(),
+            tracer: self.tracer.clone(),
+        });
+
+        let mut url = self.endpoint.clone();
+        if let Some(timeout) = options.timeout {
+            let mut query_builder = url.query_builder();
+            query_builder.set_pair("timeout", timeout.to_string());
+            query_builder.build();
+        }
+        let mut request = Request::new(url, Method::Put);
+        insert_copy_headers!(request, options, copy_source);
+        request.insert_header("x-ms-version", &self.version);
+
+        // The ID of the copy this poller started, to detect another copy replacing it.
+        let copy_id = Arc::new(OnceLock::<String>::new());
+        Ok(Poller::new(
+            move |poller_state: PollerState, poller_options| {
+                let client = client.clone();
+                let mut request = request.clone();
+                let copy_id = copy_id.clone();
+                Box::pin(async move {
+                    let ctx = poller_options.context.clone();
+                    let rsp = match poller_state {
+                        PollerState::Initial => {
+                            client
+                                .pipeline
+                                .send(
+                                    &ctx,
+                                    &mut request,
+                                    Some(PipelineSendOptions {
+                                        check_success: CheckSuccessOptions {
+                                            success_codes: &[202],
+                                        },
+                                        ..Default::default()
+                                    }),
+                                )
+                                .await?
+                        }
+                        PollerState::More(_) => client
+                            .get_properties(Some(BlobClientGetPropertiesOptions {
+                                method_options: ClientMethodOptions {
+                                    context: ctx.clone(),
+                                },
+                                ..Default::default()
+                            }))
+                            .await?
+                            .to_raw_response(),
+                    };
+                    let (status, headers, _) = rsp.deconstruct();
+                    let res = BlobCopyStatus::from_headers(&headers)?;
+                    match (copy_id.get(), res.copy_id.as_ref()) {
+                        (None, Some(id)) => {
+                            _ = copy_id.set(id.clone());
+                        }
+                        (Some(expected), actual) if Some(expected) != actual => {
+                            return Err(Error::with_message_fn(ErrorKind::Other, || {
+                                format!(
+                                    "copy {expected} was replaced by copy {}",
+                                    actual.map_or("<none>", String::as_str)
+                                )
+                            }));
+                        }
+                        _ => {}
+                    }
+                    let rsp = RawResponse::from_bytes(status, headers, json::to_json(&res)?).into();
+
+                    Ok(match res.status() {
+                        PollerStatus::InProgress => PollerResult::InProgress {
+                            response: rsp,
+                            retry_after: poller_options.frequency,
+                            continuation: PollerContinuation::Links {
+                                next_link: client.endpoint.clone(),
+                                final_link: None,
+                            },
+                        },
+                        PollerStatus::Succeeded => PollerResult::Succeeded {
+                            response: rsp,
+                            target: Box::new(move || {
+                                Box::pin(async move {
+                                    client
+                                        .get_properties(Some(BlobClientGetPropertiesOptions {
+                                            method_options: ClientMethodOptions { context: ctx },
+                                            ..Default::default()
+                                        }))
+                                        .await
+                                })
+                            }),
+                        },
+                        _ => PollerResult::Done { response: rsp },
+                    })
+                })
+            },
+            Some(options.method_options),
+        ))
+    }
+
+    /// Copies a blob or file to this blob, returning once the copy has completed.
+    ///
+    /// The service copies the source before responding, so the source must be a block blob of at most 256 MiB.
+    /// Use [`BlobClient::start_copy_from_url()`] to copy larger blobs.
+    ///
+    /// # Arguments
+    ///
+    /// * `copy_source` - The URL of the source blob or file. It must be public, include a SAS token, or be authorized
+    ///   with [`BlobClientCopyFromUrlOptions::copy_source_authorization`].
+    /// * `options` - Optional parameters for the request.
+    #[tracing::function("Storage.Blob.Blob.copy_from_url")]
+    pub async fn copy_from_url(
+        &self,
+        copy_source: &Url,
+        options: Option<BlobClientCopyFromUrlOptions<'_>>,
+    ) -> Result<BlobClientCopyFromUrlResult> {
+        let options = options.unwrap_or_default();
+        let mut url = self.endpoint.clone();
+        if let Some(timeout) = options.timeout {
+            let mut query_builder = url.query_builder();
+            query_builder.set_pair("timeout", timeout.to_string());
+            query_builder.build();
+        }
+        let mut request = Request::new(url, Method::Put);
+        insert_copy_headers!(request, options, copy_source);
+        if let Some(copy_source_authorization) = options.copy_source_authorization.as_ref() {
+            request.insert_header("x-ms-copy-source-authorization", copy_source_authorization);
+        }
+        request.insert_header("x-ms-requires-sync", "true");
+        request.insert_header("x-ms-version", &self.version);
+        let rsp = self
+            .pipeline
+            .send(
+                &options.method_options.context,
+                &mut request,
+                Some(PipelineSendOptions {
+                    check_success: CheckSuccessOptions {
+                        success_codes: &[202],
+                    },
+                    ..Default::default()
+                }),
+            )
+            .await?;
+        BlobClientCopyFromUrlResult::from_response(rsp)
+    }
+
+    /// Aborts a pending copy started by [`BlobClient::start_copy_from_url()`], leaving this blob with zero length and full metadata.
+    ///
+    /// # Arguments
+    ///
+    /// * `copy_id` - The ID of the copy to abort, from [`BlobCopyStatus::copy_id`].
+    /// * `options` - Optional parameters for the request.
+    #[tracing::function("Storage.Blob.Blob.abort_copy_from_url")]
+    pub async fn abort_copy_from_url(
+        &self,
+        copy_id: &str,
+        options: Option<BlobClientAbortCopyFromUrlOptions<'_>>,
+    ) -> Result<Response<(), NoFormat>> {
+        let options = options.unwrap_or_default();
+        let mut url = self.endpoint.clone();
+        let mut query_builder = url.query_builder();
+        query_builder.append_pair("comp", "copy");
+        query_builder.set_pair("copyid", copy_id);
+        query_builder.build();
+        options.append_to_url_query(&mut url);
+        let mut request = Request::new(url, Method::Put);
+        request.insert_header("x-ms-copy-action", "abort");
+        request.insert_headers(&options)?;
+        request.insert_header("x-ms-version", &self.version);
+        let rsp = self
+            .pipeline
+            .send(
+                &options.method_options.context,
+                &mut request,
+                Some(PipelineSendOptions {
+                    check_success: CheckSuccessOptions {
+                        success_codes: &[204],
+                    },
+                    ..Default::default()
+                }),
+            )
+            .await?;
+        Ok(rsp.into())
+    }
+
     /// Checks if the blob exists.
     ///
     /// Returns `true` if the blob exists, `false` if the blob does not exist, and propagates all other errors.
@@ -301,14 +660,57 @@ impl BlobClient {
     }
 }
 
+/// Returns the partition size for a download, limited to the largest range the service returns a
+/// checksum for if the download is validated.
+fn download_partition_size(options: &BlobClientDownloadOptions) -> NonZero<usize> {
+    let partition_size = options
+        .partition_size
+        .unwrap_or(crate::partitioned_transfer::defaults::DEFAULT_DOWNLOAD_PARTITION_SIZE);
+    match options.transfer_validation {
+        Some(_) => {
+            partition_size.min(crate::partitioned_transfer::defaults::MAX_CHECKSUM_RANGE_SIZE)
+        }
+        None => partition_size,
+    }
+}
+
+/// Returns the stored MD5 of the blob to validate a download against, if the whole blob was
+/// downloaded with MD5 validation and the blob has a stored MD5.
+fn blob_content_md5(
+    transfer_validation: Option<ChecksumAlgorithm>,
+    range: Option<&HttpRange>,
+    headers: &Headers,
+) -> Result<Option<Vec<u8>>> {
+    if transfer_validation != Some(ChecksumAlgorithm::Md5) || range.is_some() {
+        return Ok(None);
+    }
+    headers
+        .get_optional_str(&BLOB_CONTENT_MD5)
+        .map(base64::decode)
+        .transpose()
+}
+
+const CONTENT_MD5: HeaderName = HeaderName::from_static("content-md5");
+const CONTENT_CRC64: HeaderName = HeaderName::from_static("x-ms-content-crc64");
+const BLOB_CONTENT_MD5: HeaderName = HeaderName::from_static("x-ms-blob-content-md5");
+
 struct BlobClientDownloadBehavior<'a> {
     client: GeneratedBlobClient,
     options: BlobClientDownloadInternalOptions<'a>,
+    transfer_validation: Option<ChecksumAlgorithm>,
 }
 
 impl<'a> BlobClientDownloadBehavior<'a> {
-    fn new(client: GeneratedBlobClient, options: BlobClientDownloadInternalOptions<'a>) -> Self {
-        Self { client, options }
+    fn new(
+        client: GeneratedBlobClient,
+        options: BlobClientDownloadInternalOptions<'a>,
+        transfer_validation: Option<ChecksumAlgorithm>,
+    ) -> Self {
+        Self {
+            client,
+            options,
+            transfer_validation,
+        }
     }
 }
 
@@ -320,6 +722,12 @@ impl PartitionedDownloadBehavior for BlobClientDownloadBehavior<'_> {
         etag_lock: Option<Etag>,
     ) -> Result<AsyncRawResponse> {
         let mut opt = self.options.clone();
+        // The service only returns a transactional checksum for ranged requests if asked.
+        match (self.transfer_validation, &range) {
+            (Some(ChecksumAlgorithm::Md5), Some(_)) => opt.range_get_content_md5 = Some(true),
+            (Some(ChecksumAlgorithm::Crc64), Some(_)) => opt.range_get_content_crc64 = Some(true),
+            _ => {}
+        }
         opt.range = range.map(HttpRange::from);
         if let Some(etag) = etag_lock {
             opt.if_match = Some(etag);
@@ -328,9 +736,373 @@ impl PartitionedDownloadBehavior for BlobClientDownloadBehavior<'_> {
             opt.if_unmodified_since = None;
             opt.if_tags = None;
         }
-        self.client
-            .download_internal(Some(opt))
+        let response = AsyncRawResponse::from(self.client.download_internal(Some(opt)).await?);
+
+        let Some(algorithm) = self.transfer_validation else {
+            return Ok(response);
+        };
+        let header_name = match algorithm {
+            ChecksumAlgorithm::Md5 => CONTENT_MD5,
+            ChecksumAlgorithm::Crc64 => CONTENT_CRC64,
+        };
+        let Some(expected) = response
+            .headers()
+            .get_optional_str(&header_name)
+            .map(base64::decode)
+            .transpose()?
+        else {
+            return Ok(response);
+        };
+        let (status, headers, body) = response.deconstruct();
+        Ok(AsyncRawResponse::new(
+            status,
+            headers,
+            Box::pin(ValidatingStream::new(Box::pin(body), algorithm, expected)),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{BlobClientGetPropertiesResultHeaders, ChecksumMismatchError, CopyStatus};
+    use azure_core::{
+        http::{headers::Headers, ClientOptions, HttpClient, Transport},
+        time::Duration,
+    };
+    use azure_core_test::http::MockHttpClient;
+    use futures::{FutureExt as _, StreamExt as _};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    const SOURCE_URL: &str = "https://source.blob.core.windows.net/container/source";
+
+    fn blob_client(mock_client: impl HttpClient + 'static) -> Result<BlobClient> {
+        BlobClient::new(
+            Url::parse("https://example.blob.core.windows.net/container/blob").unwrap(),
+            None,
+            Some(BlobClientOptions {
+                client_options: ClientOptions {
+                    transport: Some(Transport::new(Arc::new(mock_client))),
+                    ..Default::default()
+                },
+                ..Default::default()
+            }),
+        )
+    }
+
+    fn copy_headers(copy_id: &str, copy_status: &str) -> Headers {
+        let mut headers = Headers::new();
+        headers.insert("x-ms-copy-id", copy_id.to_string());
+        headers.insert("x-ms-copy-status", copy_status.to_string());
+        headers
+    }
+
+    fn poller_options() -> BlobClientStartCopyFromUrlOptions<'static> {
+        BlobClientStartCopyFromUrlOptions {
+            method_options: azure_core::http::poller::PollerOptions {
+                frequency: Duration::seconds(1),
+                ..Default::default()
+            },
+            ..Default::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn start_copy_from_url_polls_until_success() -> Result<()> {
+        let requests = Arc::new(AtomicUsize::new(0));
+        let client = blob_client(MockHttpClient::new({
+            let requests = requests.clone();
+            move |req| {
+                let request = requests.fetch_add(1, Ordering::SeqCst);
+                let rsp = if request == 0 {
+                    assert_eq!(Method::Put, req.method());
+                    assert_eq!(
+                        Some(SOURCE_URL),
+                        req.headers().get_optional_str(&"x-ms-copy-source".into())
+                    );
+                    AsyncRawResponse::from_bytes(
+                        StatusCode::Accepted,
+                        copy_headers("copy-1", "pending"),
+                        Vec::new(),
+                    )
+                } else {
+                    assert_eq!(Method::Head, req.method());
+                    AsyncRawResponse::from_bytes(
+                        StatusCode::Ok,
+                        copy_headers("copy-1", "success"),
+                        Vec::new(),
+                    )
+                };
+                async move { Ok(rsp) }.boxed()
+            }
+        }))?;
+
+        let mut poller =
+            client.start_copy_from_url(&SOURCE_URL.parse().unwrap(), Some(poller_options()))?;
+        let status = poller.next().await.expect("status")?.into_model()?;
+        assert_eq!(Some("copy-1"), status.copy_id.as_deref());
+        assert_eq!(Some(CopyStatus::Pending), status.copy_status);
+
+        let properties = poller.await?;
+        assert_eq!(Some(CopyStatus::Success), properties.copy_status()?);
+        // copy, status, and final properties
+        assert_eq!(3, requests.load(Ordering::SeqCst));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn start_copy_from_url_fails_when_replaced() -> Result<()> {
+        let requests = Arc::new(AtomicUsize::new(0));
+        let client = blob_client(MockHttpClient::new({
+            let requests = requests.clone();
+            move |_| {
+                let (status, copy_id) = match requests.fetch_add(1, Ordering::SeqCst) {
+                    0 => (StatusCode::Accepted, "copy-1"),
+                    _ => (StatusCode::Ok, "copy-2"),
+                };
+                async move {
+                    Ok(AsyncRawResponse::from_bytes(
+                        status,
+                        copy_headers(copy_id, "pending"),
+                        Vec::new(),
+                    ))
+                }
+                .boxed()
+            }
+        }))?;
+
+        let err = client
+            .start_copy_from_url(&SOURCE_URL.parse().unwrap(), Some(poller_options()))?
+            .await
+            .expect_err("replaced copy");
+        assert!(err.to_string().contains("copy-2"), "{err}");
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn copy_from_url_is_synchronous() -> Result<()> {
+        let client = blob_client(MockHttpClient::new(|req| {
+            assert_eq!(Method::Put, req.method());
+            assert_eq!(
+                Some("true"),
+                req.headers().get_optional_str(&"x-ms-requires-sync".into())
+            );
+            assert_eq!(
+                Some("Bearer token"),
+                req.headers()
+                    .get_optional_str(&"x-ms-copy-source-authorization".into())
+            );
+            async {
+                Ok(AsyncRawResponse::from_bytes(
+                    StatusCode::Accepted,
+                    copy_headers("copy-1", "success"),
+                    Vec::new(),
+                ))
+            }
+            .boxed()
+        }))?;
+
+        let result = client
+            .copy_from_url(
+                &SOURCE_URL.parse().unwrap(),
+                Some(BlobClientCopyFromUrlOptions {
+                    copy_source_authorization: Some("Bearer token".into()),
+                    ..Default::default()
+                }),
+            )
+            .await?;
+        assert_eq!(Some("copy-1"), result.copy_id.as_deref());
+        assert_eq!(Some(CopyStatus::Success), result.copy_status);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn abort_copy_from_url() -> Result<()> {
+        let client = blob_client(MockHttpClient::new(|req| {
+            assert_eq!(Method::Put, req.method());
+            let query = req.url().query().unwrap_or_default();
+            assert!(query.contains("comp=copy"), "{query}");
+            assert!(query.contains("copyid=copy-1"), "{query}");
+            assert!(query.contains("timeout=30"), "{query}");
+            assert_eq!(
+                Some("abort"),
+                req.headers().get_optional_str(&"x-ms-copy-action".into())
+            );
+            assert_eq!(
+                Some("lease-1"),
+                req.headers().get_optional_str(&"x-ms-lease-id".into())
+            );
+            async {
+                Ok(AsyncRawResponse::from_bytes(
+                    StatusCode::NoContent,
+                    Headers::new(),
+                    Vec::new(),
+                ))
+            }
+            .boxed()
+        }))?;
+
+        client
+            .abort_copy_from_url(
+                "copy-1",
+                Some(BlobClientAbortCopyFromUrlOptions {
+                    lease_id: Some("lease-1".to_string()),
+                    timeout: Some(30),
+                    ..Default::default()
+                }),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    const DATA: &[u8] = b"hello world";
+
+    /// Responds to a ranged download of [`DATA`] with the given checksum headers.
+    fn download_client(
+        checksum_headers: impl Fn(&Request) -> Headers + Send + Sync + 'static,
+    ) -> Result<BlobClient> {
+        blob_client(MockHttpClient::new(move |req| {
+            assert_eq!(Method::Get, req.method());
+            let mut headers = checksum_headers(req);
+            headers.insert("content-length", DATA.len().to_string());
+            headers.insert(
+                "content-range",
+                format!("bytes 0-{}/{}", DATA.len() - 1, DATA.len()),
+            );
+            headers.insert("etag", "\"0x1\"");
+            async move {
+                Ok(AsyncRawResponse::from_bytes(
+                    StatusCode::PartialContent,
+                    headers,
+                    DATA,
+                ))
+            }
+            .boxed()
+        }))
+    }
+
+    fn validated(
+        transfer_validation: ChecksumAlgorithm,
+    ) -> Option<BlobClientDownloadOptions<'static>> {
+        Some(BlobClientDownloadOptions {
+            transfer_validation: Some(transfer_validation),
+            ..Default::default()
+        })
+    }
+
+    #[tokio::test]
+    async fn download_validates_range_checksum() -> Result<()> {
+        let client = download_client(|req| {
+            assert_eq!(
+                Some("true"),
+                req.headers()
+                    .get_optional_str(&"x-ms-range-get-content-crc64".into())
+            );
+            let mut headers = Headers::new();
+            headers.insert(
+                CONTENT_CRC64,
+                base64::encode(ChecksumAlgorithm::Crc64.compute(DATA)),
+            );
+            headers
+        })?;
+
+        let body = client
+            .download(validated(ChecksumAlgorithm::Crc64))
+            .await?
+            .body
+            .collect()
+            .await?;
+        assert_eq!(DATA, &body[..]);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn download_fails_on_range_checksum_mismatch() -> Result<()> {
+        let client = download_client(|_| {
+            let mut headers = Headers::new();
+            headers.insert(
+                CONTENT_MD5,
+                base64::encode(ChecksumAlgorithm::Md5.compute(b"hello w0rld")),
+            );
+            headers
+        })?;
+
+        let err = client
+            .download_into(&mut [0; 64], validated(ChecksumAlgorithm::Md5))
             .await
-            .map(AsyncRawResponse::from)
+            .expect_err("checksum mismatch");
+        assert_eq!(&ErrorKind::DataConversion, err.kind());
+        let mismatch = err.downcast_ref::<ChecksumMismatchError>().unwrap();
+        assert_eq!(ChecksumAlgorithm::Md5, mismatch.algorithm);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn download_validates_blob_content_md5() -> Result<()> {
+        for (blob_content_md5, valid) in [(DATA, true), (&b"hello w0rld"[..], false)] {
+            let client = download_client(move |_| {
+                let mut headers = Headers::new();
+                headers.insert(
+                    CONTENT_MD5,
+                    base64::encode(ChecksumAlgorithm::Md5.compute(DATA)),
+                );
+                headers.insert(
+                    BLOB_CONTENT_MD5,
+                    base64::encode(ChecksumAlgorithm::Md5.compute(blob_content_md5)),
+                );
+                headers
+            })?;
+
+            let result = client
+                .download(validated(ChecksumAlgorithm::Md5))
+                .await?
+                .body
+                .collect()
+                .await;
+            assert_eq!(valid, result.is_ok(), "{result:?}");
+            let result = client
+                .download_into(&mut [0; 64], validated(ChecksumAlgorithm::Md5))
+                .await;
+            assert_eq!(valid, result.is_ok(), "{result:?}");
+        }
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn upload_sends_checksum() -> Result<()> {
+        let client = blob_client(MockHttpClient::new(|req| {
+            assert_eq!(Method::Put, req.method());
+            assert_eq!(
+                Some(base64::encode(ChecksumAlgorithm::Md5.compute(DATA)).as_str()),
+                req.headers().get_optional_str(&CONTENT_MD5)
+            );
+            async {
+                Ok(AsyncRawResponse::from_bytes(
+                    StatusCode::Created,
+                    Headers::new(),
+                    Vec::new(),
+                ))
+            }
+            .boxed()
+        }))?;
+
+        client
+            .upload(
+                RequestContent::from(DATA.to_vec()),
+                Some(BlobClientUploadOptions {
+                    transfer_validation: Some(ChecksumAlgorithm::Md5),
+                    ..Default::default()
+                }),
+            )
+            .await?;
+
+        Ok(())
     }
 }
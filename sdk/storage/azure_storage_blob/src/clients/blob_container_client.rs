@@ -119,10 +119,12 @@ impl BlobContainerClient {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::StorageSharedKeyCredential;
     use azure_core::{
         http::{
-            headers::Headers, pager::PagerContinuation, AsyncRawResponse, ClientOptions,
-            StatusCode, Transport,
+            headers::{self, HeaderName, Headers},
+            pager::PagerContinuation,
+            AsyncRawResponse, ClientOptions, StatusCode, Transport,
         },
         Bytes,
     };
@@ -210,4 +212,46 @@ mod tests {
 
         Ok(())
     }
+
+    #[tokio::test]
+    async fn shared_key_credential_signs_requests() -> Result<()> {
+        let mock_client = Arc::new(MockHttpClient::new(|req| {
+            assert!(req
+                .headers()
+                .get_optional_str(&HeaderName::from_static("x-ms-date"))
+                .is_some());
+            assert!(req
+                .headers()
+                .get_optional_str(&headers::AUTHORIZATION)
+                .is_some_and(|authorization| authorization.starts_with("SharedKey example:")));
+            async move {
+                Ok(AsyncRawResponse::from_bytes(
+                    StatusCode::Ok,
+                    Headers::new(),
+                    Bytes::new(),
+                ))
+            }
+            .boxed()
+        }));
+        let credential = StorageSharedKeyCredential::new(
+            "example",
+            "FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFF",
+        );
+        let client = BlobContainerClient::new(
+            Url::parse("https://example.blob.core.windows.net/container").unwrap(),
+            None,
+            Some(BlobContainerClientOptions {
+                client_options: ClientOptions {
+                    per_try_policies: vec![credential.authorization_policy()],
+                    transport: Some(Transport::new(mock_client)),
+                    ..Default::default()
+                },
+                ..Default::default()
+            }),
+        )?;
+
+        assert!(client.exists().await?);
+
+        Ok(())
+    }
 }
@@ -3,12 +3,14 @@
 
 pub use crate::generated::clients::{BlobServiceClient, BlobServiceClientOptions};
 
-use crate::{BlobClient, BlobContainerClient};
+use crate::{models::BlobServiceClientUndeleteContainerOptions, BlobClient, BlobContainerClient};
 use azure_core::{
     credentials::TokenCredential,
+    error::CheckSuccessOptions,
     http::{
         policies::{auth::BearerTokenAuthorizationPolicy, Policy},
-        Pipeline, Url,
+        AppendToUrlQuery, Method, NoFormat, Pipeline, PipelineSendOptions, Request, Response, Url,
+        UrlExt,
     },
     tracing, Result,
 };
@@ -116,4 +118,151 @@ impl BlobServiceClient {
     pub fn url(&self) -> &Url {
         &self.endpoint
     }
+
+    /// Restores a soft-deleted container, along with its blobs and metadata, under its original name.
+    ///
+    /// Soft-deleted containers and their versions are listed by [`BlobServiceClient::list_containers()`] when
+    /// [`ListContainersIncludeType::Deleted`](crate::models::ListContainersIncludeType::Deleted) is included.
+    /// The restore fails if a container with the same name already exists.
+    ///
+    /// # Arguments
+    ///
+    /// * `deleted_container_name` - The name of the soft-deleted container.
+    /// * `deleted_container_version` - The version of the soft-deleted container, from [`ContainerItem::version`](crate::models::ContainerItem::version).
+    /// * `options` - Optional parameters for the request.
+    #[tracing::function("Storage.Blob.Service.undelete_container")]
+    pub async fn undelete_container(
+        &self,
+        deleted_container_name: &str,
+        deleted_container_version: &str,
+        options: Option<BlobServiceClientUndeleteContainerOptions<'_>>,
+    ) -> Result<Response<(), NoFormat>> {
+        let options = options.unwrap_or_default();
+        let mut url = self.blob_container_client(deleted_container_name).endpoint;
+        let mut query_builder = url.query_builder();
+        query_builder
+            .append_pair("comp", "undelete")
+            .append_pair("restype", "container");
+        query_builder.build();
+        options.append_to_url_query(&mut url);
+        let mut request = Request::new(url, Method::Put);
+        request.insert_header(
+            "x-ms-deleted-container-name",
+            deleted_container_name.to_string(),
+        );
+        request.insert_header(
+            "x-ms-deleted-container-version",
+            deleted_container_version.to_string(),
+        );
+        request.insert_header("x-ms-version", &self.version);
+        let rsp = self
+            .pipeline
+            .send(
+                &options.method_options.context,
+                &mut request,
+                Some(PipelineSendOptions {
+                    check_success: CheckSuccessOptions {
+                        success_codes: &[201],
+                    },
+                    ..Default::default()
+                }),
+            )
+            .await?;
+        Ok(rsp.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{BlobServiceClientListContainersOptions, ListContainersIncludeType};
+    use azure_core::{
+        http::{headers::Headers, AsyncRawResponse, ClientOptions, StatusCode, Transport},
+        Bytes,
+    };
+    use azure_core_test::http::MockHttpClient;
+    use futures::{FutureExt as _, TryStreamExt as _};
+
+    const LIST_DELETED_CONTAINERS_PAGE: &[u8] = br#"<?xml version="1.0" encoding="utf-8"?>
+<EnumerationResults ServiceEndpoint="https://example.blob.core.windows.net/">
+  <Containers>
+    <Container>
+      <Name>container</Name>
+      <Deleted>true</Deleted>
+      <Version>01D60F8BB59A4652</Version>
+      <Properties>
+        <Last-Modified>Wed, 01 Jan 2025 00:00:00 GMT</Last-Modified>
+        <Etag>"0x1"</Etag>
+      </Properties>
+    </Container>
+  </Containers>
+  <NextMarker />
+</EnumerationResults>"#;
+
+    #[tokio::test]
+    async fn undelete_listed_container() -> Result<()> {
+        let mock_client = Arc::new(MockHttpClient::new(|req| {
+            let query = req.url().query().unwrap_or_default().to_string();
+            let rsp = if query.contains("comp=list") {
+                assert!(query.contains("include=deleted"), "{query}");
+                AsyncRawResponse::from_bytes(
+                    StatusCode::Ok,
+                    Headers::new(),
+                    Bytes::from_static(LIST_DELETED_CONTAINERS_PAGE),
+                )
+            } else {
+                assert_eq!(Method::Put, req.method());
+                assert_eq!("/container", req.url().path());
+                assert!(query.contains("comp=undelete"), "{query}");
+                assert!(query.contains("restype=container"), "{query}");
+                assert!(query.contains("timeout=30"), "{query}");
+                assert_eq!(
+                    Some("container"),
+                    req.headers()
+                        .get_optional_str(&"x-ms-deleted-container-name".into())
+                );
+                assert_eq!(
+                    Some("01D60F8BB59A4652"),
+                    req.headers()
+                        .get_optional_str(&"x-ms-deleted-container-version".into())
+                );
+                AsyncRawResponse::from_bytes(StatusCode::Created, Headers::new(), Vec::new())
+            };
+            async move { Ok(rsp) }.boxed()
+        }));
+        let client = BlobServiceClient::new(
+            Url::parse("https://example.blob.core.windows.net/").unwrap(),
+            None,
+            Some(BlobServiceClientOptions {
+                client_options: ClientOptions {
+                    transport: Some(Transport::new(mock_client)),
+                    ..Default::default()
+                },
+                ..Default::default()
+            }),
+        )?;
+
+        let deleted = client
+            .list_containers(Some(BlobServiceClientListContainersOptions {
+                include: Some(vec![ListContainersIncludeType::Deleted]),
+                ..Default::default()
+            }))?
+            .try_next()
+            .await?
+            .expect("expected a deleted container");
+        assert_eq!(Some(true), deleted.deleted);
+
+        client
+            .undelete_container(
+                deleted.name.as_deref().unwrap(),
+                deleted.version.as_deref().unwrap(),
+                Some(BlobServiceClientUndeleteContainerOptions {
+                    timeout: Some(30),
+                    ..Default::default()
+                }),
+            )
+            .await?;
+
+        Ok(())
+    }
 }
@@ -11,6 +11,7 @@ use crate::{
     models::{
         BlockBlobClientCommitBlockListOptions, BlockBlobClientStageBlockOptions,
         BlockBlobClientUploadOptions, BlockBlobClientUploadResult, BlockLookupList,
+        ChecksumAlgorithm,
     },
     partitioned_transfer::{self, PartitionedUploadBehavior},
 };
@@ -23,6 +24,7 @@ use azure_core::{
     },
     tracing, Bytes, Result, Uuid,
 };
+use azure_storage_common::checksum::compute_body_checksum;
 use futures::lock::Mutex;
 use std::sync::Arc;
 
@@ -109,6 +111,13 @@ impl BlockBlobClient {
         let partition_size = options
             .partition_size
             .unwrap_or(crate::partitioned_transfer::defaults::DEFAULT_UPLOAD_PARTITION_SIZE);
+        let progress = options.progress.clone();
+        let transfer_validation = options.transfer_validation;
+        // Only MD5 can be stored for the whole blob. A single-shot upload stores its transactional MD5.
+        let content_checksum = match (transfer_validation, &options.blob_content_md5) {
+            (Some(ChecksumAlgorithm::Md5), None) => Some(ChecksumAlgorithm::Md5),
+            _ => None,
+        };
         // Construct exhaustively to catch new options.
         let oneshot_options = BlockBlobClientUploadInternalOptions {
             blob_cache_control: options.blob_cache_control.clone(),
@@ -184,8 +193,17 @@ impl BlockBlobClient {
             oneshot_options,
             stage_block_options,
             commit_block_list_options,
+            transfer_validation,
         );
-        partitioned_transfer::upload(content.into(), parallel, partition_size, &behavior).await?;
+        partitioned_transfer::upload(
+            content.into(),
+            parallel,
+            partition_size,
+            &behavior,
+            progress,
+            content_checksum,
+        )
+        .await?;
         behavior.result.into_inner().ok_or_else(|| {
             azure_core::Error::with_message(
                 azure_core::error::ErrorKind::Other,
@@ -205,6 +223,7 @@ struct BlockBlobClientUploadBehavior<'c, 'opt> {
     oneshot_options: BlockBlobClientUploadInternalOptions<'opt>,
     stage_block_options: BlockBlobClientStageBlockOptions<'opt>,
     commit_block_list_options: BlockBlobClientCommitBlockListOptions<'opt>,
+    transfer_validation: Option<ChecksumAlgorithm>,
     blocks: Mutex<Vec<BlockInfo>>,
     result: Mutex<Option<BlockBlobClientUploadResult>>,
 }
@@ -215,21 +234,41 @@ impl<'c, 'opt> BlockBlobClientUploadBehavior<'c, 'opt> {
         oneshot_options: BlockBlobClientUploadInternalOptions<'opt>,
         stage_block_options: BlockBlobClientStageBlockOptions<'opt>,
         commit_block_list_options: BlockBlobClientCommitBlockListOptions<'opt>,
+        transfer_validation: Option<ChecksumAlgorithm>,
     ) -> Self {
         Self {
             client,
             oneshot_options,
             stage_block_options,
             commit_block_list_options,
+            transfer_validation,
             blocks: Mutex::new(vec![]),
             result: Mutex::new(None),
         }
     }
+
+    /// Computes the `Content-MD5` or `x-ms-content-crc64` checksum of `content`, if transfer validation is enabled.
+    async fn transactional_checksums(
+        &self,
+        content: &mut Body,
+    ) -> Result<(Option<Vec<u8>>, Option<Vec<u8>>)> {
+        Ok(match self.transfer_validation {
+            Some(ChecksumAlgorithm::Md5) => (
+                Some(compute_body_checksum(content, ChecksumAlgorithm::Md5).await?),
+                None,
+            ),
+            Some(ChecksumAlgorithm::Crc64) => (
+                None,
+                Some(compute_body_checksum(content, ChecksumAlgorithm::Crc64).await?),
+            ),
+            None => (None, None),
+        })
+    }
 }
 
 #[async_trait]
 impl PartitionedUploadBehavior for BlockBlobClientUploadBehavior<'_, '_> {
-    async fn transfer_oneshot(&self, content: Body) -> Result<()> {
+    async fn transfer_oneshot(&self, mut content: Body) -> Result<()> {
         // This should only ever be called by a managed uploader when the length is known.
         // Otherwise, we can only buffer or error.
         // Buffering strategy must be left to the caller, so we must error.
@@ -239,13 +278,14 @@ impl PartitionedUploadBehavior for BlockBlobClientUploadBehavior<'_, '_> {
                 "length unknown",
             ));
         };
+        let mut options = self.oneshot_options.clone();
+        (
+            options.transactional_content_md5,
+            options.transactional_content_crc64,
+        ) = self.transactional_checksums(&mut content).await?;
         let rsp = self
             .client
-            .upload_internal(
-                content.into(),
-                content_len,
-                Some(self.oneshot_options.clone()),
-            )
+            .upload_internal(content.into(), content_len, Some(options))
             .await?;
         *self.result.lock().await = Some(BlockBlobClientUploadResult {
             content_md5: rsp.content_md5()?,
@@ -261,7 +301,7 @@ impl PartitionedUploadBehavior for BlockBlobClientUploadBehavior<'_, '_> {
         Ok(())
     }
 
-    async fn transfer_partition(&self, offset: u64, content: Body) -> Result<()> {
+    async fn transfer_partition(&self, offset: u64, mut content: Body) -> Result<()> {
         // This should only ever be called by a managed uploader when the length is known.
         // Otherwise, we can only buffer or error.
         // Buffering strategy must be left to the caller, so we must error.
@@ -278,12 +318,17 @@ impl PartitionedUploadBehavior for BlockBlobClientUploadBehavior<'_, '_> {
                 .await
                 .push(BlockInfo { offset, block_id });
         }
+        let mut options = self.stage_block_options.clone();
+        (
+            options.transactional_content_md5,
+            options.transactional_content_crc64,
+        ) = self.transactional_checksums(&mut content).await?;
         self.client
             .stage_block(
                 block_id.as_bytes(),
                 content_len,
                 content.into(),
-                Some(self.stage_block_options.clone()),
+                Some(options),
             )
             .await?;
         Ok(())
@@ -293,7 +338,7 @@ impl PartitionedUploadBehavior for BlockBlobClientUploadBehavior<'_, '_> {
         Ok(())
     }
 
-    async fn finalize(&self) -> Result<()> {
+    async fn finalize(&self, content_checksum: Option<Vec<u8>>) -> Result<()> {
         let mut blocks = self.blocks.lock().await;
         blocks.sort_by_key(|left| left.offset);
         let blocklist = BlockLookupList {
@@ -305,12 +350,13 @@ impl PartitionedUploadBehavior for BlockBlobClientUploadBehavior<'_, '_> {
             ),
             ..Default::default()
         };
+        let mut options = self.commit_block_list_options.clone();
+        if content_checksum.is_some() {
+            options.blob_content_md5 = content_checksum;
+        }
         let rsp = self
             .client
-            .commit_block_list(
-                blocklist.try_into()?,
-                Some(self.commit_block_list_options.clone()),
-            )
+            .commit_block_list(blocklist.try_into()?, Some(options))
             .await?;
         *self.result.lock().await = Some(BlockBlobClientUploadResult {
             content_md5: rsp.content_md5()?,
@@ -21,6 +21,8 @@ pub use blob_service_client::{BlobServiceClient, BlobServiceClientOptions};
 pub use block_blob_client::{BlockBlobClient, BlockBlobClientOptions};
 pub use page_blob_client::{PageBlobClient, PageBlobClientOptions};
 
+pub use azure_storage_common::shared_key::StorageSharedKeyCredential;
+
 #[allow(clippy::needless_update)]
 fn apply_client_defaults(options: &mut ClientOptions) {
     if options.transport.is_none() {
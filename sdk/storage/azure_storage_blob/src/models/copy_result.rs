@@ -0,0 +1,108 @@
+// Copyright (c) Microsoft Corporation. All rights reserved.
+// Licensed under the MIT License.
+
+use azure_core::{
+    fmt::SafeDebug,
+    http::{
+        headers::{HeaderName, Headers},
+        poller::{PollerStatus, StatusMonitor},
+        Etag, NoFormat, RawResponse,
+    },
+    time::parse_rfc7231,
+};
+use serde::{Deserialize, Serialize};
+use time::OffsetDateTime;
+
+use crate::generated::models::{BlobClientGetPropertiesResult, CopyStatus};
+
+const COPY_ID: HeaderName = HeaderName::from_static("x-ms-copy-id");
+const COPY_PROGRESS: HeaderName = HeaderName::from_static("x-ms-copy-progress");
+const COPY_STATUS: HeaderName = HeaderName::from_static("x-ms-copy-status");
+const COPY_STATUS_DESCRIPTION: HeaderName = HeaderName::from_static("x-ms-copy-status-description");
+
+/// Status of a copy started by `BlobClient::start_copy_from_url()`.
+///
+/// Each response from the [`Poller`](azure_core::http::poller::Poller) contains the status parsed
+/// from the `x-ms-copy-*` headers of the destination blob.
+#[derive(Clone, Default, Deserialize, SafeDebug, Serialize)]
+#[non_exhaustive]
+pub struct BlobCopyStatus {
+    /// Identifier of the copy operation (`x-ms-copy-id` header).
+    ///
+    /// Pass this to `BlobClient::abort_copy_from_url()` to abort a pending copy.
+    pub copy_id: Option<String>,
+
+    /// Number of bytes copied and the total bytes in the source, as `copied/total`
+    /// (`x-ms-copy-progress` header).
+    pub copy_progress: Option<String>,
+
+    /// State of the copy operation (`x-ms-copy-status` header).
+    pub copy_status: Option<CopyStatus>,
+
+    /// Description of a failed copy operation (`x-ms-copy-status-description` header).
+    pub copy_status_description: Option<String>,
+}
+
+impl BlobCopyStatus {
+    pub(crate) fn from_headers(headers: &Headers) -> azure_core::Result<Self> {
+        Ok(Self {
+            copy_id: headers.get_optional_as(&COPY_ID)?,
+            copy_progress: headers.get_optional_as(&COPY_PROGRESS)?,
+            copy_status: headers.get_optional_as(&COPY_STATUS)?,
+            copy_status_description: headers.get_optional_as(&COPY_STATUS_DESCRIPTION)?,
+        })
+    }
+}
+
+impl StatusMonitor for BlobCopyStatus {
+    type Output = BlobClientGetPropertiesResult;
+    type Format = NoFormat;
+
+    fn status(&self) -> PollerStatus {
+        match self.copy_status {
+            Some(CopyStatus::Success) => PollerStatus::Succeeded,
+            Some(CopyStatus::Failed) => PollerStatus::Failed,
+            Some(CopyStatus::Aborted) => PollerStatus::Canceled,
+            Some(CopyStatus::Pending) | None => PollerStatus::InProgress,
+        }
+    }
+}
+
+/// Result of a `BlobClient::copy_from_url()` operation.
+#[derive(SafeDebug)]
+pub struct BlobClientCopyFromUrlResult {
+    /// Identifier of the copy operation.
+    pub copy_id: Option<String>,
+
+    /// State of the copy operation. A synchronous copy that returns successfully is always `Success`.
+    pub copy_status: Option<CopyStatus>,
+
+    /// The destination blob's ETag.
+    pub etag: Option<Etag>,
+
+    /// The date/time the destination blob was last modified.
+    pub last_modified: Option<OffsetDateTime>,
+
+    /// The version ID of the destination blob, if versioning is enabled on the storage account.
+    pub version_id: Option<String>,
+
+    /// The raw HTTP response.
+    pub raw_response: RawResponse,
+}
+
+impl BlobClientCopyFromUrlResult {
+    pub(crate) fn from_response(raw_response: RawResponse) -> azure_core::Result<Self> {
+        let headers = raw_response.headers();
+        Ok(Self {
+            copy_id: headers.get_optional_as(&COPY_ID)?,
+            copy_status: headers.get_optional_as(&COPY_STATUS)?,
+            etag: headers.get_optional_as(&HeaderName::from_static("etag"))?,
+            last_modified: headers
+                .get_optional_with(&HeaderName::from_static("last-modified"), |h| {
+                    parse_rfc7231(h.as_str())
+                })?,
+            version_id: headers.get_optional_as(&HeaderName::from_static("x-ms-version-id"))?,
+            raw_response,
+        })
+    }
+}
@@ -38,10 +38,10 @@ pub struct BlobClientDownloadResult {
     pub headers: Headers,
 }
 
-/// Result of a `BlobClient::download_into()` operation.
+/// Result of a `BlobClient::download_into()` or `BlobClient::download_to()` operation.
 #[derive(SafeDebug)]
 pub struct BlobClientDownloadIntoResult {
-    /// The length of data written to the provided buffer.
+    /// The length of data written to the provided buffer or writer.
     pub len: usize,
 
     /// Blob properties parsed from the initial response.
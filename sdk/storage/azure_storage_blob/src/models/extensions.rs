@@ -2,9 +2,10 @@
 // Licensed under the MIT License.
 
 use crate::models::{
-    AccessPolicy, AppendBlobClientCreateOptions, BlobTag, BlobTags,
-    BlockBlobClientCommitBlockListOptions, BlockBlobClientUploadBlobFromUrlOptions,
-    BlockBlobClientUploadOptions, PageBlobClientCreateOptions, SignedIdentifier, SignedIdentifiers,
+    AccessPolicy, AppendBlobClientCreateOptions, BlobClientCopyFromUrlOptions,
+    BlobClientStartCopyFromUrlOptions, BlobTag, BlobTags, BlockBlobClientCommitBlockListOptions,
+    BlockBlobClientUploadBlobFromUrlOptions, BlockBlobClientUploadOptions,
+    PageBlobClientCreateOptions, SignedIdentifier, SignedIdentifiers,
 };
 use percent_encoding::{percent_encode, NON_ALPHANUMERIC};
 use std::collections::HashMap;
@@ -126,6 +127,20 @@ impl BlockBlobClientCommitBlockListOptions<'_> {
     }
 }
 
+impl BlobClientStartCopyFromUrlOptions<'_> {
+    pub fn with_tags(mut self, tags: impl Into<BlobTags>) -> Self {
+        self.blob_tags_string = encode_tags(&tags.into());
+        self
+    }
+}
+
+impl BlobClientCopyFromUrlOptions<'_> {
+    pub fn with_tags(mut self, tags: impl Into<BlobTags>) -> Self {
+        self.blob_tags_string = encode_tags(&tags.into());
+        self
+    }
+}
+
 /// Converts a `BlobTags` struct into `HashMap<String, String>`.
 impl From<BlobTags> for HashMap<String, String> {
     fn from(blob_tags: BlobTags) -> Self {
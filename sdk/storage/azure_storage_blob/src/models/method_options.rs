@@ -5,13 +5,13 @@ use std::{collections::HashMap, num::NonZero};
 
 use azure_core::{
     fmt::SafeDebug,
-    http::{ClientMethodOptions, Etag},
+    http::{headers::AsHeaders, poller::PollerOptions, ClientMethodOptions, Etag, QueryParams},
 };
 use time::OffsetDateTime;
 
 use crate::models::{
-    AccessTier, BlobClientDownloadInternalOptions, EncryptionAlgorithmType, HttpRange,
-    ImmutabilityPolicyMode,
+    AccessTier, BlobClientDownloadInternalOptions, ChecksumAlgorithm, EncryptionAlgorithmType,
+    HttpRange, ImmutabilityPolicyMode, ProgressHandler,
 };
 
 /// Options to be passed to `BlobClient::download()`
@@ -55,6 +55,9 @@ pub struct BlobClientDownloadOptions<'a> {
     /// A default value will be chosen if none is provided.
     pub partition_size: Option<NonZero<usize>>,
 
+    /// Optional. Receives the number of bytes downloaded so far as the blob's contents are read.
+    pub progress: Option<ProgressHandler>,
+
     /// Optional range of the blob to download.
     ///
     /// Accepts an [`HttpRange`] value. You can convert from standard Rust range types
@@ -77,6 +80,14 @@ pub struct BlobClientDownloadOptions<'a> {
     /// The timeout parameter is expressed in seconds. For more information, see [Setting Timeouts for Blob Service Operations.](https://docs.microsoft.com/en-us/rest/api/storageservices/fileservices/setting-timeouts-for-blob-service-operations)
     pub timeout: Option<i32>,
 
+    /// Optional. Validates the downloaded content using checksums of the given algorithm.
+    ///
+    /// Each ranged request asks the service for a checksum of that range, which limits partitions to 4 MiB.
+    /// When the whole blob is downloaded using [`ChecksumAlgorithm::Md5`] and the blob has a stored `Content-MD5`,
+    /// the whole blob is validated against it as well. A mismatch fails the download with an error wrapping a
+    /// [`ChecksumMismatchError`](crate::models::ChecksumMismatchError).
+    pub transfer_validation: Option<ChecksumAlgorithm>,
+
     /// Specifies the version ID of the blob.
     pub version_id: Option<String>,
 }
@@ -195,6 +206,179 @@ pub struct BlockBlobClientUploadOptions<'a> {
     /// [Setting Timeouts for Blob Service Operations.](https://docs.microsoft.com/rest/api/storageservices/fileservices/setting-timeouts-for-blob-service-operations)
     pub per_request_timeout: Option<i32>,
 
+    /// Optional. Receives the number of bytes uploaded so far as each partition completes.
+    pub progress: Option<ProgressHandler>,
+
     /// The tier to be set on the blob.
     pub tier: Option<AccessTier>,
+
+    /// Optional. Sends a checksum of the given algorithm with each request, so the service rejects content that was
+    /// corrupted in transit.
+    ///
+    /// Using [`ChecksumAlgorithm::Md5`], the MD5 of the whole blob is also stored as its `Content-MD5` unless
+    /// `blob_content_md5` is set.
+    pub transfer_validation: Option<ChecksumAlgorithm>,
+}
+
+/// Options to be passed to `BlobClient::start_copy_from_url()`
+#[derive(Clone, Default, SafeDebug)]
+pub struct BlobClientStartCopyFromUrlOptions<'a> {
+    /// The blob tags.
+    ///
+    /// This is the percent-encoded `x-ms-tags` header value (`key=value&key2=value2`).
+    /// Use [`Self::with_tags`] to set this from a `HashMap<String, String>` or `BlobTags`.
+    pub blob_tags_string: Option<String>,
+
+    /// Specify this value to operate only on a blob with a matching Etag value.
+    pub if_match: Option<Etag>,
+
+    /// Specify this value to operate only on a blob if it has been modified since the specified date-time.
+    pub if_modified_since: Option<OffsetDateTime>,
+
+    /// Specify this value to operate only on a blob with a non-matching Etag value.
+    pub if_none_match: Option<Etag>,
+
+    /// Specifies a SQL-like where clause on blob tags to operate only on a blob with matching tags.
+    pub if_tags: Option<String>,
+
+    /// Specify this value to operate only on a blob if it has not been modified since the specified date-time.
+    pub if_unmodified_since: Option<OffsetDateTime>,
+
+    /// If specified, the operation only succeeds if the resource's lease is active and matches this ID.
+    pub lease_id: Option<String>,
+
+    /// The metadata headers. If not specified, the metadata of the source blob is copied to the destination blob.
+    pub metadata: Option<HashMap<String, String>>,
+
+    /// Allows customization of the [`Poller`](azure_core::http::poller::Poller).
+    pub method_options: PollerOptions<'a>,
+
+    /// Specify this value to copy the source only if its Etag matches the value specified.
+    pub source_if_match: Option<Etag>,
+
+    /// Specify this value to copy the source only if it has been modified since the specified date-time.
+    pub source_if_modified_since: Option<OffsetDateTime>,
+
+    /// Specify this value to copy the source only if its Etag does not match the value specified.
+    pub source_if_none_match: Option<Etag>,
+
+    /// Specify this value to copy the source only if it has not been modified since the specified date-time.
+    pub source_if_unmodified_since: Option<OffsetDateTime>,
+
+    /// The tier to be set on the destination blob.
+    pub tier: Option<AccessTier>,
+
+    /// The timeout parameter is expressed in seconds. For more information, see [Setting Timeouts for Blob Service Operations.](https://docs.microsoft.com/en-us/rest/api/storageservices/fileservices/setting-timeouts-for-blob-service-operations)
+    pub timeout: Option<i32>,
+}
+
+impl BlobClientStartCopyFromUrlOptions<'_> {
+    /// Converts these options into an owned form so they can be used in `'static` contexts.
+    #[must_use]
+    pub fn into_owned(self) -> BlobClientStartCopyFromUrlOptions<'static> {
+        BlobClientStartCopyFromUrlOptions {
+            blob_tags_string: self.blob_tags_string,
+            if_match: self.if_match,
+            if_modified_since: self.if_modified_since,
+            if_none_match: self.if_none_match,
+            if_tags: self.if_tags,
+            if_unmodified_since: self.if_unmodified_since,
+            lease_id: self.lease_id,
+            metadata: self.metadata,
+            method_options: self.method_options.into_owned(),
+            source_if_match: self.source_if_match,
+            source_if_modified_since: self.source_if_modified_since,
+            source_if_none_match: self.source_if_none_match,
+            source_if_unmodified_since: self.source_if_unmodified_since,
+            tier: self.tier,
+            timeout: self.timeout,
+        }
+    }
+}
+
+/// Options to be passed to `BlobClient::copy_from_url()`
+#[derive(Clone, Default, SafeDebug)]
+pub struct BlobClientCopyFromUrlOptions<'a> {
+    /// The blob tags.
+    ///
+    /// This is the percent-encoded `x-ms-tags` header value (`key=value&key2=value2`).
+    /// Use [`Self::with_tags`] to set this from a `HashMap<String, String>` or `BlobTags`.
+    pub blob_tags_string: Option<String>,
+
+    /// Authorization for the copy source, as an OAuth access token in the form `Bearer <token>`.
+    ///
+    /// Not needed if the source is public or `copy_source` includes a SAS token.
+    pub copy_source_authorization: Option<String>,
+
+    /// Specify this value to operate only on a blob with a matching Etag value.
+    pub if_match: Option<Etag>,
+
+    /// Specify this value to operate only on a blob if it has been modified since the specified date-time.
+    pub if_modified_since: Option<OffsetDateTime>,
+
+    /// Specify this value to operate only on a blob with a non-matching Etag value.
+    pub if_none_match: Option<Etag>,
+
+    /// Specifies a SQL-like where clause on blob tags to operate only on a blob with matching tags.
+    pub if_tags: Option<String>,
+
+    /// Specify this value to operate only on a blob if it has not been modified since the specified date-time.
+    pub if_unmodified_since: Option<OffsetDateTime>,
+
+    /// If specified, the operation only succeeds if the resource's lease is active and matches this ID.
+    pub lease_id: Option<String>,
+
+    /// The metadata headers. If not specified, the metadata of the source blob is copied to the destination blob.
+    pub metadata: Option<HashMap<String, String>>,
+
+    /// Allows customization of the method call.
+    pub method_options: ClientMethodOptions<'a>,
+
+    /// Specify this value to copy the source only if its Etag matches the value specified.
+    pub source_if_match: Option<Etag>,
+
+    /// Specify this value to copy the source only if it has been modified since the specified date-time.
+    pub source_if_modified_since: Option<OffsetDateTime>,
+
+    /// Specify this value to copy the source only if its Etag does not match the value specified.
+    pub source_if_none_match: Option<Etag>,
+
+    /// Specify this value to copy the source only if it has not been modified since the specified date-time.
+    pub source_if_unmodified_since: Option<OffsetDateTime>,
+
+    /// The tier to be set on the destination blob.
+    pub tier: Option<AccessTier>,
+
+    /// The timeout parameter is expressed in seconds. For more information, see [Setting Timeouts for Blob Service Operations.](https://docs.microsoft.com/en-us/rest/api/storageservices/fileservices/setting-timeouts-for-blob-service-operations)
+    pub timeout: Option<i32>,
+}
+
+/// Options to be passed to `BlobClient::abort_copy_from_url()`
+#[derive(AsHeaders, Clone, Default, QueryParams, SafeDebug)]
+#[header(crate = "azure_core")]
+#[query(crate = "azure_core")]
+pub struct BlobClientAbortCopyFromUrlOptions<'a> {
+    /// If specified, the operation only succeeds if the resource's lease is active and matches this ID.
+    #[header("x-ms-lease-id")]
+    #[query(skip)]
+    pub lease_id: Option<String>,
+
+    /// Allows customization of the method call.
+    #[query(skip)]
+    pub method_options: ClientMethodOptions<'a>,
+
+    /// The timeout parameter is expressed in seconds. For more information, see [Setting Timeouts for Blob Service Operations.](https://docs.microsoft.com/en-us/rest/api/storageservices/fileservices/setting-timeouts-for-blob-service-operations)
+    pub timeout: Option<i32>,
+}
+
+/// Options to be passed to `BlobServiceClient::undelete_container()`
+#[derive(Clone, Default, QueryParams, SafeDebug)]
+#[query(crate = "azure_core")]
+pub struct BlobServiceClientUndeleteContainerOptions<'a> {
+    /// Allows customization of the method call.
+    #[query(skip)]
+    pub method_options: ClientMethodOptions<'a>,
+
+    /// The timeout parameter is expressed in seconds. For more information, see [Setting Timeouts for Blob Service Operations.](https://docs.microsoft.com/en-us/rest/api/storageservices/fileservices/setting-timeouts-for-blob-service-operations)
+    pub timeout: Option<i32>,
 }
@@ -3,24 +3,32 @@
 
 //! Model types for Azure Blob Storage.
 
+mod copy_result;
 mod download_result;
 pub(crate) mod drains;
 pub(crate) mod error;
 pub(crate) mod extensions;
 pub(crate) mod http_ranges;
 mod method_options;
+mod progress;
 
 pub use http_ranges::HttpRange;
 pub(crate) mod response_ext;
 mod upload_result;
 
 pub use crate::generated::models::*;
+pub use azure_storage_common::checksum::{ChecksumAlgorithm, ChecksumMismatchError};
+pub use copy_result::{BlobClientCopyFromUrlResult, BlobCopyStatus};
 pub use download_result::{
     BlobClientDownloadIntoResult, BlobClientDownloadResult, BlobDownloadProperties,
 };
-pub use method_options::BlobClientDownloadOptions;
 pub use method_options::BlockBlobClientUploadOptions;
 pub use method_options::BlockBlobClientUploadOptions as BlobClientUploadOptions;
+pub use method_options::{
+    BlobClientAbortCopyFromUrlOptions, BlobClientCopyFromUrlOptions, BlobClientDownloadOptions,
+    BlobClientStartCopyFromUrlOptions, BlobServiceClientUndeleteContainerOptions,
+};
+pub use progress::ProgressHandler;
 pub use upload_result::BlockBlobClientUploadResult;
 pub use upload_result::BlockBlobClientUploadResult as BlobClientUploadResult;
 
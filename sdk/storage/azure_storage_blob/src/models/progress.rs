@@ -0,0 +1,43 @@
+// Copyright (c) Microsoft Corporation. All rights reserved.
+// Licensed under the MIT License.
+
+use std::{fmt, sync::Arc};
+
+/// Receives progress updates for a blob upload or download.
+///
+/// The callback is passed the total number of bytes transferred so far and, if known, the total
+/// number of bytes in the transfer. Partitions may be transferred in parallel, but the callback
+/// is never called concurrently and the number of bytes transferred never decreases.
+///
+/// # Examples
+///
+/// ```
+/// use azure_storage_blob::models::{BlobClientDownloadOptions, ProgressHandler};
+///
+/// let options = BlobClientDownloadOptions {
+///     progress: Some(ProgressHandler::new(|transferred, total| {
+///         println!("downloaded {transferred} of {total:?} bytes");
+///     })),
+///     ..Default::default()
+/// };
+/// ```
+#[derive(Clone)]
+pub struct ProgressHandler(Arc<dyn Fn(u64, Option<u64>) + Send + Sync>);
+
+impl ProgressHandler {
+    /// Creates a new `ProgressHandler` that calls `callback` with the bytes transferred and the
+    /// total bytes, if known.
+    pub fn new(callback: impl Fn(u64, Option<u64>) + Send + Sync + 'static) -> Self {
+        Self(Arc::new(callback))
+    }
+
+    pub(crate) fn report(&self, transferred: u64, total: Option<u64>) {
+        (self.0)(transferred, total)
+    }
+}
+
+impl fmt::Debug for ProgressHandler {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ProgressHandler").finish_non_exhaustive()
+    }
+}
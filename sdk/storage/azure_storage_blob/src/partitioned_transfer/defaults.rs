@@ -13,6 +13,14 @@ pub(crate) const DEFAULT_DOWNLOAD_PARTITION_SIZE: NonZero<usize> =
 pub(crate) const DEFAULT_UPLOAD_PARTITION_SIZE: NonZero<u64> =
     NonZero::new(4 * 1024 * 1024).unwrap();
 
+/// Largest range the service returns a transactional checksum for (4 MiB).
+// unwrap evaluated at compile time
+pub(crate) const MAX_CHECKSUM_RANGE_SIZE: NonZero<usize> = NonZero::new(4 * 1024 * 1024).unwrap();
+
+/// Number of times a partitioned download requests the rest of a range again after its
+/// connection fails.
+pub(crate) const DOWNLOAD_RANGE_RETRIES: usize = 3;
+
 /// Returns the default concurrency for partitioned uploads and downloads.
 ///
 /// Formula: `min(max(available_parallelism, 8), 96)`
@@ -11,22 +11,24 @@ use std::{
     },
 };
 
-use crate::models::HttpRange;
+use crate::models::{HttpRange, ProgressHandler};
 
 use async_trait::async_trait;
 use azure_core::{
     async_runtime::{get_async_runtime, SpawnedTask},
     error::ErrorKind,
-    http::{headers::Headers, AsyncRawResponse, AsyncResponseBody, Etag, StatusCode},
+    http::{headers::Headers, AsyncRawResponse, Etag, StatusCode},
     Error,
 };
 use bytes::Bytes;
 use futures::{
     channel::mpsc::{self, UnboundedReceiver, UnboundedSender},
     future::{self, Either},
-    SinkExt, StreamExt,
+    SinkExt, Stream, StreamExt,
 };
 
+use super::{defaults::DOWNLOAD_RANGE_RETRIES, ProgressTracker};
+
 use crate::models::{drains::SequentialBoundedDrain, http_ranges::ContentRange};
 
 use super::*;
@@ -50,11 +52,14 @@ pub(crate) trait PartitionedDownloadBehavior {
 /// resource while not wasting a roundtrip just for a HEAD request. It then determines the
 /// correct set of additional ranges to download and queues them up. The returned `Stream`
 /// executes these downloads, maintaining limits for parallel downloads and buffer count.
+///
+/// If `progress` is provided, it receives the number of bytes read from the returned stream.
 pub(crate) async fn download<Behavior>(
     range: Option<HttpRange>,
     parallel: NonZero<usize>,
     partition_size: NonZero<usize>,
     client: Arc<Behavior>,
+    progress: Option<ProgressHandler>,
 ) -> AzureResult<AsyncRawResponse>
 where
     Behavior: PartitionedDownloadBehavior + Send + Sync + 'static,
@@ -77,17 +82,28 @@ where
     let status = initial_response.status();
     let headers = initial_response.headers().clone();
     let etag_lock = headers.get_optional_str(&"etag".into()).map(Etag::from);
-
-    let mut remaining_ranges = stats
-        .map(|s| s.remaining_download_ranges)
-        .unwrap_or_default();
-    if remaining_ranges.is_empty() {
+    let progress = progress.map(|handler| {
+        let total = match &stats {
+            Some(stats) => Some(stats.overall_download_range.len() as u64),
+            None => headers.get_optional_as(&"content-length".into())?,
+        };
+        AzureResult::Ok(Arc::new(ProgressTracker::new(handler, total)))
+    });
+    let progress = progress.transpose()?;
+
+    let Some(InitialResponseAnalysis {
+        initial_download_range,
+        remaining_download_ranges: mut remaining_ranges,
+        resource_len,
+        ..
+    }) = stats.filter(|s| !s.remaining_download_ranges.is_empty())
+    else {
         return Ok(AsyncRawResponse::new(
             status,
             headers,
-            Box::pin(initial_response.into_body()),
+            Box::pin(with_progress(initial_response.into_body(), progress)),
         ));
-    }
+    };
     let total_chunks = remaining_ranges.len() + 1;
 
     // channel for download workers to send results to their coordinator.
@@ -96,11 +112,15 @@ where
     // start with one initial download task at index 0
     let active_tasks_counter = Arc::new(AtomicUsize::new(1));
     let mut next_task_index = 1;
-    let mut task_bucket = vec![start_initial_download_task_buffer(
-        initial_response,
+    let mut task_bucket = vec![start_download_task_buffer(
+        client.clone(),
+        Some(initial_response),
+        initial_download_range,
+        etag_lock.clone(),
+        resource_len,
         tx.clone(),
         active_tasks_counter.clone(),
-        partition_size,
+        0,
     )];
 
     let mut drain = SequentialBoundedDrain::new(max_buffers);
@@ -122,7 +142,7 @@ where
                         next_task_index += 1;
                         active_tasks_counter.fetch_add(1, Ordering::Relaxed);
                         let t = tx_opt.as_ref().ok_or_else(||Error::with_message(ErrorKind::Other, "Channel closed unexpectedly."))?.clone();
-                        task_bucket.push(start_download_task_buffer(client.clone(), range, etag_lock.clone(), t, active_tasks_counter.clone(), i));
+                        task_bucket.push(start_download_task_buffer(client.clone(), None, range, etag_lock.clone(), resource_len, t, active_tasks_counter.clone(), i));
                     }
                     None => {
                         // if ranges are finished, we'll never need to clone the transmitter again.
@@ -146,7 +166,11 @@ where
         }
     };
 
-    Ok(AsyncRawResponse::new(status, headers, Box::pin(stream)))
+    Ok(AsyncRawResponse::new(
+        status,
+        headers,
+        Box::pin(with_progress(stream, progress)),
+    ))
 }
 
 pub(crate) async fn download_into<Behavior>(
@@ -155,6 +179,7 @@ pub(crate) async fn download_into<Behavior>(
     parallel: NonZero<usize>,
     partition_size: NonZero<usize>,
     client: Arc<Behavior>,
+    progress: Option<ProgressHandler>,
 ) -> AzureResult<(StatusCode, Headers, usize)>
 where
     Behavior: PartitionedDownloadBehavior + Send + Sync + 'static,
@@ -187,13 +212,25 @@ where
     let headers = initial_response.headers().clone();
     let etag_lock = headers.get_optional_str(&"etag".into()).map(Etag::from);
 
+    let progress = progress.map(|handler| {
+        let total = match &response_analysis {
+            Some(analysis) => Some(analysis.overall_download_range.len() as u64),
+            None => headers.get_optional_as(&"content-length".into())?,
+        };
+        AzureResult::Ok(ProgressTracker::new(handler, total))
+    });
+    let progress = progress.transpose()?;
+    let report_progress = |len: usize| {
+        if let Some(progress) = &progress {
+            progress.add(len as u64);
+        }
+    };
+
     // if no response analysis, no subsequent gets, therefore just copy to buffer and return
     let Some(mut response_analysis) = response_analysis else {
-        return initial_response
-            .into_body()
-            .collect_into(buffer)
-            .await
-            .map(|read| (status, headers, read));
+        let read = initial_response.into_body().collect_into(buffer).await?;
+        report_progress(read);
+        return Ok((status, headers, read));
     };
 
     // fail fast for buffer overflow
@@ -201,16 +238,37 @@ where
         return Err(insufficient_buffer_err());
     }
 
+    // the starting offset of the overall download range
+    // bytes at this position are written to position 0 of `buffer`
+    let src_offset = response_analysis.overall_download_range.start;
+    let resource_len = response_analysis.resource_len;
+
     // if no real parallelism, just sequentially go through the ranges and write to buffer
     if parallel == 1 {
-        let mut total_read = initial_response.into_body().collect_into(buffer).await?;
-        for range in response_analysis.remaining_download_ranges {
-            total_read += client
-                .transfer_range(Some(range), etag_lock.clone())
-                .await?
-                .into_body()
-                .collect_into(&mut buffer[total_read..])
-                .await?;
+        let mut total_read = 0;
+        let mut response = Some(initial_response);
+        let ranges = std::iter::once(response_analysis.initial_download_range.clone())
+            .chain(response_analysis.remaining_download_ranges.drain(..));
+        for range in ranges {
+            let destination_offset = range.start - src_offset;
+            read_range(
+                client.as_ref(),
+                response.take(),
+                range,
+                etag_lock.as_ref(),
+                resource_len,
+                |offset, bytes| {
+                    let write_offset = destination_offset + offset;
+                    buffer
+                        .get_mut(write_offset..write_offset + bytes.len())
+                        .ok_or_else(insufficient_buffer_err)?
+                        .copy_from_slice(&bytes);
+                    total_read += bytes.len();
+                    report_progress(bytes.len());
+                    Ok(())
+                },
+            )
+            .await?;
         }
         let expected_total_read = response_analysis.overall_download_range.len();
         if expected_total_read != total_read {
@@ -228,16 +286,17 @@ where
      * be no significant buildup of memory in this channel.
      */
     let (mut tx, mut rx) = mpsc::unbounded();
+    let expected_total_read = response_analysis.overall_download_range.len();
     // worker that does nothing but monitor active download workers and spawn new ones
     let downloads_manager_handle = get_async_runtime().spawn(Box::pin(async move {
-        // the starting offset of the overall download range
-        // bytes at this position are written to position 0 of `buffer`
-        let src_offset = response_analysis.overall_download_range.start;
-
         let mut download_workers = Vec::with_capacity(parallel);
-        download_workers.push(start_initial_download_task_channel(
-            initial_response,
+        download_workers.push(start_download_task_channel(
+            client.clone(),
+            Some(initial_response),
+            response_analysis.initial_download_range.clone(),
             0,
+            etag_lock.clone(),
+            resource_len,
             tx.clone(),
         ));
 
@@ -247,9 +306,11 @@ where
             }
             download_workers.push(start_download_task_channel(
                 client.clone(),
+                None,
                 range.clone(),
                 range.start - src_offset,
                 etag_lock.clone(),
+                resource_len,
                 tx.clone(),
             ));
         }
@@ -270,9 +331,9 @@ where
         }
         buffer[write_offset..write_offset + bytes.len()].copy_from_slice(&bytes);
         total_read += bytes.len();
+        report_progress(bytes.len());
     }
 
-    let expected_total_read = response_analysis.overall_download_range.len();
     if expected_total_read != total_read {
         return Err(missing_bytes_err(expected_total_read, total_read));
     }
@@ -318,6 +379,21 @@ where
     Ok((initial_response, stats))
 }
 
+/// Reports the length of each chunk read from `body` to `progress`, if any.
+fn with_progress<S>(
+    body: S,
+    progress: Option<Arc<ProgressTracker>>,
+) -> impl Stream<Item = AzureResult<Bytes>>
+where
+    S: Stream<Item = AzureResult<Bytes>>,
+{
+    body.inspect(move |bytes| {
+        if let (Some(progress), Ok(bytes)) = (&progress, bytes) {
+            progress.add(bytes.len() as u64);
+        }
+    })
+}
+
 /// Race awaiting a message vs checking if tasks have completed successfully,
 /// until either message is received or a task failure is found.
 ///
@@ -355,130 +431,189 @@ async fn await_message_while_joining_workers<T>(
     Ok((message_fut.await.map_err(on_recv_err)?, task_bucket))
 }
 
-/// Spawns a worker to take the given raw response and stream it into a buffer.
-/// That buffer result is then sent through sender with chunk index 0.
-fn start_initial_download_task_buffer(
-    initial_response: AsyncRawResponse,
-    mut sender: UnboundedSender<Result<(usize, Bytes), Error>>,
-    active_tasks_counter: Arc<AtomicUsize>,
-    partition_size: usize,
-) -> SpawnedTask {
-    get_async_runtime().spawn(Box::pin(async move {
-        let mut dst = vec![0u8; partition_size];
-        let res = initial_response
-            .into_body()
-            .collect_into(&mut dst)
-            .await
-            // this is the initial download task, it's chunk index is 0
-            .map(|_| (0usize, dst.into()));
-        active_tasks_counter.fetch_sub(1, Ordering::Relaxed);
-        let _send_res = sender.send(res).await;
-    }))
-}
-
-/// Spawns a worker to request the given range and stream it into a buffer.
+/// Spawns a worker to stream the given range into a buffer, requesting it unless `response` is
+/// the response already received for it.
 /// That buffer result is then sent through sender with the given chunk index.
+#[allow(clippy::too_many_arguments)]
 fn start_download_task_buffer<Behavior: PartitionedDownloadBehavior + Send + Sync + 'static>(
     client: Arc<Behavior>,
+    response: Option<AsyncRawResponse>,
     range: Range<usize>,
     etag_lock: Option<Etag>,
+    resource_len: usize,
     mut sender: UnboundedSender<Result<(usize, Bytes), Error>>,
     active_tasks_counter: Arc<AtomicUsize>,
     chunk_idx: usize,
 ) -> SpawnedTask {
     get_async_runtime().spawn(Box::pin(async move {
-        let mut dst = vec![0u8; range.len()];
-        let res = async {
-            client
-                .transfer_range(Some(range), etag_lock)
-                .await?
-                .into_body()
-                .collect_into(&mut dst)
-                .await
-        }
+        let mut dst = Vec::with_capacity(range.len());
+        let res = read_range(
+            client.as_ref(),
+            response,
+            range,
+            etag_lock.as_ref(),
+            resource_len,
+            |_, bytes| {
+                dst.extend_from_slice(&bytes);
+                Ok(())
+            },
+        )
         .await;
-        if let Ok(count) = res {
-            dst.truncate(count);
-        }
         active_tasks_counter.fetch_sub(1, Ordering::Relaxed);
         let _send_res = sender.send(res.map(|_| (chunk_idx, dst.into()))).await;
     }))
 }
 
-/// Spawns a worker to take the given raw response and stream it through a channel.
-/// Worker terminates gracefully on download error or channel send error.
-///
-/// # Arguments
-///
-/// - initial_response: the initial download response to stream into a channel.
-/// - destination_offset: offset in the final destination buffer that this response is meant to be
-///   written into.
-/// - sender: channel to send network Bytes through along with the destination buffer offset to
-///   write those exact bytes to.
-fn start_initial_download_task_channel(
-    initial_response: AsyncRawResponse,
-    destination_offset: usize,
-    sender: UnboundedSender<Result<(usize, Bytes), Error>>,
-) -> SpawnedTask {
-    get_async_runtime().spawn(Box::pin(async move {
-        _ = body_to_channel(initial_response.into_body(), destination_offset, sender).await;
-    }))
-}
-
-/// Spawns a worker to request the given range and stream it through a channel.
+/// Spawns a worker to stream the given range through a channel, requesting it unless `response`
+/// is the response already received for it.
 /// Worker terminates gracefully on download error or channel send error.
 ///
 /// # Arguments
 ///
 /// - client: Client to request a range from.
+/// - response: the response already received for `range`, if any.
 /// - range: The range to request.
 /// - destination_offset: offset in the final destination buffer that the response is meant to be
 ///   written into.
 /// - etag_lock: etag to lock on for this ranged request.
+/// - resource_len: total length of the remote resource.
 /// - sender: channel to send network Bytes through along with the destination buffer offset to
 ///   write those exact bytes to.
 fn start_download_task_channel<Behavior: PartitionedDownloadBehavior + Send + Sync + 'static>(
     client: Arc<Behavior>,
+    response: Option<AsyncRawResponse>,
     range: Range<usize>,
     destination_offset: usize,
     etag_lock: Option<Etag>,
-    mut sender: UnboundedSender<Result<(usize, Bytes), Error>>,
+    resource_len: usize,
+    sender: UnboundedSender<Result<(usize, Bytes), Error>>,
 ) -> SpawnedTask {
     get_async_runtime().spawn(Box::pin(async move {
-        _ = match client.transfer_range(Some(range), etag_lock).await {
-            Ok(response) => body_to_channel(response.into_body(), destination_offset, sender).await,
-            Err(err) => sender.send(Err(err)).await,
-        };
+        let res = read_range(
+            client.as_ref(),
+            response,
+            range,
+            etag_lock.as_ref(),
+            resource_len,
+            |offset, bytes| {
+                sender
+                    .unbounded_send(Ok((destination_offset + offset, bytes)))
+                    .map_err(|err| Error::new(ErrorKind::Other, err.into_send_error()))
+            },
+        )
+        .await;
+        if let Err(err) = res {
+            _ = sender.unbounded_send(Err(err));
+        }
     }))
 }
 
-/// Takes an AsyncResponseBody and streams it through a channel, using the given `destination_offset`
-/// to calculate the offset for each individual Bytes message.
-/// When receiving an error from `body`, sends that error through the channel and terminates.
-/// Immediately returns on error sending a message through the channel.
-async fn body_to_channel(
-    mut body: AsyncResponseBody,
-    mut destination_offset: usize,
-    mut sender: UnboundedSender<Result<(usize, Bytes), Error>>,
-) -> std::result::Result<(), mpsc::SendError> {
-    const CHANNEL_BATCH_LEN: usize = 8;
-    let mut batch_counter = BatchCounter::<CHANNEL_BATCH_LEN>::new();
-    while let Some(result) = body.next().await {
-        match result {
-            Ok(bytes) => {
-                let bytes_len = bytes.len();
-                sender.feed(Ok((destination_offset, bytes))).await?;
-                destination_offset += bytes_len;
-                if batch_counter.eval() {
-                    sender.flush().await?;
+/// Streams the given range of the resource to `on_bytes`, along with the offset of those bytes
+/// within the range.
+///
+/// Uses `response` for the first attempt if provided, otherwise requests the range. If the
+/// connection fails before the whole range is received, requests the rest of the range again,
+/// up to [`DOWNLOAD_RANGE_RETRIES`] times. Each requested range must match the requested offset,
+/// `resource_len`, and `etag_lock`, so bytes from a different version of the resource are never
+/// mixed in.
+async fn read_range<Behavior>(
+    client: &Behavior,
+    mut response: Option<AsyncRawResponse>,
+    range: Range<usize>,
+    etag_lock: Option<&Etag>,
+    resource_len: usize,
+    mut on_bytes: impl FnMut(usize, Bytes) -> AzureResult<()>,
+) -> AzureResult<()>
+where
+    Behavior: PartitionedDownloadBehavior + Send + Sync,
+{
+    let mut received = 0;
+    let mut retries = 0;
+    loop {
+        let result = async {
+            let response = match response.take() {
+                Some(response) => response,
+                None => {
+                    let start = range.start + received;
+                    let response = client
+                        .transfer_range(Some(start..range.end), etag_lock.cloned())
+                        .await?;
+                    validate_range_response(&response, start, resource_len, etag_lock)?;
+                    response
                 }
+            };
+            let mut body = response.into_body();
+            while let Some(bytes) = body.next().await {
+                let bytes = bytes?;
+                let len = bytes.len();
+                if received + len > range.len() {
+                    return Err(Error::with_message_fn(ErrorKind::Other, || {
+                        format!(
+                            "Download failed. Received more than the {} bytes requested for range {range:?}.",
+                            range.len()
+                        )
+                    }));
+                }
+                on_bytes(received, bytes)?;
+                received += len;
             }
-            Err(err) => {
-                return sender.send(Err(err)).await;
+            if received < range.len() {
+                return Err(Error::with_message_fn(ErrorKind::Io, || {
+                    format!(
+                        "Connection closed after receiving {received} of {} bytes for range {range:?}.",
+                        range.len()
+                    )
+                }));
             }
-        };
+            Ok(())
+        }
+        .await;
+
+        match result {
+            Err(err) if matches!(err.kind(), ErrorKind::Io) && retries < DOWNLOAD_RANGE_RETRIES => {
+                retries += 1;
+            }
+            result => return result,
+        }
     }
-    sender.flush().await
+}
+
+/// Checks that a ranged response starts at the requested offset of the same resource, with the
+/// same length and ETag as the initial response.
+fn validate_range_response(
+    response: &AsyncRawResponse,
+    range_start: usize,
+    resource_len: usize,
+    etag_lock: Option<&Etag>,
+) -> AzureResult<()> {
+    let content_range = response
+        .headers()
+        .get_optional_as::<ContentRange, _>(&"content-range".into())?;
+    match content_range {
+        Some(ContentRange {
+            range: Some((start, _)),
+            total_len: Some(total_len),
+        }) if start == range_start && total_len == resource_len => {}
+        content_range => {
+            return Err(Error::with_message_fn(ErrorKind::Other, || {
+                format!(
+                    "Download failed. Expected a response starting at byte {range_start} of {resource_len} bytes, but received Content-Range {}.",
+                    content_range.map_or_else(|| "<none>".to_string(), |r| r.to_string())
+                )
+            }));
+        }
+    }
+    if let (Some(etag_lock), Some(etag)) = (
+        etag_lock,
+        response.headers().get_optional_str(&"etag".into()),
+    ) {
+        if Etag::from(etag) != *etag_lock {
+            return Err(Error::with_message_fn(ErrorKind::Other, || {
+                format!("Download failed. The resource changed from ETag {etag_lock} to {etag} during the download.")
+            }));
+        }
+    }
+    Ok(())
 }
 
 /// Performs a `transfer_range()` call with the given range. If this results in a
@@ -510,6 +645,8 @@ struct InitialResponseAnalysis {
     overall_download_range: Range<usize>,
     initial_download_range: Range<usize>,
     remaining_download_ranges: VecDeque<Range<usize>>,
+    /// Total length of the remote resource. Every ranged response must report the same length.
+    resource_len: usize,
 }
 /// Reads over the response headers of the initial download response and compiles all relevant
 /// information to perform the remaining downloads and arrange all resulting bytes.
@@ -542,6 +679,7 @@ fn analyze_initial_response(
                     .step_by(partition_len)
                     .map(|i| i..min(i.saturating_add(partition_len), remainder_end))
                     .collect(),
+                resource_len,
             }));
         }
     }
@@ -552,21 +690,6 @@ fn map_spawned_task_error(err: Box<dyn std::error::Error + Send>) -> Error {
     Error::with_message(ErrorKind::Other, err.to_string())
 }
 
-/// Counter which evaluates to true exactly every n evaluations.
-struct BatchCounter<const LEN: usize> {
-    count: usize,
-}
-impl<const LEN: usize> BatchCounter<LEN> {
-    fn new() -> Self {
-        Self { count: 0 }
-    }
-    /// Evaluates to true every n evaluations, otherwise false.
-    fn eval(&mut self) -> bool {
-        self.count = (self.count + 1) % LEN;
-        self.count == 0
-    }
-}
-
 trait DownloadRangeFuture: Future + Send {}
 impl<T: Future + Send> DownloadRangeFuture for T {}
 
@@ -604,6 +727,7 @@ mod tests {
         pub data: Bytes,
         pub delay_millis: Option<Range<u64>>,
         pub etag: Mutex<Option<Etag>>,
+        pub body_failures: AtomicUsize,
     }
 
     #[derive(Clone, Default)]
@@ -613,6 +737,9 @@ mod tests {
 
         /// Sets the initial ETag to match against and return in responses.
         etag: Option<Etag>,
+
+        /// Number of ranged responses whose body fails with an I/O error halfway through.
+        body_failures: usize,
     }
 
     impl MockPartitionedDownloadBehavior {
@@ -622,6 +749,7 @@ mod tests {
                 data: data.into(),
                 delay_millis: options.clone().and_then(|o| o.delay_millis_range),
                 etag: Mutex::new(options.clone().and_then(|o| o.etag)),
+                body_failures: AtomicUsize::new(options.map_or(0, |o| o.body_failures)),
             }
         }
     }
@@ -687,7 +815,24 @@ mod tests {
                         })?
                     };
                     headers.add(ContentLength(range.len()))?;
-                    let range = range.start..range.end;
+                    let fail_body = range.len() > 1
+                        && self
+                            .body_failures
+                            .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |n| {
+                                n.checked_sub(1)
+                            })
+                            .is_ok();
+                    if fail_body {
+                        let half = self.data.slice(range.start..range.start + range.len() / 2);
+                        return Ok(AsyncRawResponse::new(
+                            StatusCode::PartialContent,
+                            headers,
+                            Box::pin(futures::stream::iter([
+                                Ok(half),
+                                Err(Error::with_message(ErrorKind::Io, "Connection reset")),
+                            ])),
+                        ));
+                    }
                     Ok(AsyncRawResponse::new(
                         StatusCode::PartialContent,
                         headers,
@@ -794,6 +939,7 @@ mod tests {
                 PARALLEL.try_into().unwrap(),
                 args.partition_len.try_into().unwrap(),
                 mock.clone(),
+                None,
             )
             .await?
             .into_body();
@@ -838,6 +984,7 @@ mod tests {
                 PARALLEL.try_into().unwrap(),
                 partition_len.try_into().unwrap(),
                 mock.clone(),
+                None,
             )
             .await?;
 
@@ -905,6 +1052,7 @@ mod tests {
                 args.parallel.try_into().unwrap(),
                 args.partition_len.try_into().unwrap(),
                 mock.clone(),
+                None,
             )
             .await?
             .into_body();
@@ -969,6 +1117,7 @@ mod tests {
                 parallel.try_into().unwrap(),
                 partition_len.try_into().unwrap(),
                 mock.clone(),
+                None,
             )
             .await?;
 
@@ -1013,7 +1162,7 @@ mod tests {
             }),
         ));
 
-        let mut body = download(None, parallel, partition_size, mock.clone())
+        let mut body = download(None, parallel, partition_size, mock.clone(), None)
             .await?
             .into_body();
         let downloaded_data = body.buffer_all().await?;
@@ -1024,6 +1173,70 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn download_reports_progress() -> AzureResult<()> {
+        const DATA_LEN: usize = 1024;
+        let data = get_random_data(DATA_LEN);
+        let parallel = NonZero::new(4).unwrap();
+        let partition_len = NonZero::new(100).unwrap();
+
+        for download_range in [None, Some(100usize..900)] {
+            let expected_len = download_range.as_ref().map_or(DATA_LEN, |r| r.len());
+            let (progress, reports) = recording_progress();
+            let mock = Arc::new(MockPartitionedDownloadBehavior::new(data.clone(), None));
+            download(
+                download_range.clone().map(Into::into),
+                parallel,
+                partition_len,
+                mock.clone(),
+                Some(progress),
+            )
+            .await?
+            .into_body()
+            .buffer_all()
+            .await?;
+            assert_progress(&reports.lock().unwrap(), expected_len);
+
+            let (progress, reports) = recording_progress();
+            download_into(
+                &mut [0; DATA_LEN],
+                download_range.clone().map(Into::into),
+                parallel,
+                partition_len,
+                mock.clone(),
+                Some(progress),
+            )
+            .await?;
+            assert_progress(&reports.lock().unwrap(), expected_len);
+        }
+
+        Ok(())
+    }
+
+    type ProgressReports = Arc<std::sync::Mutex<Vec<(u64, Option<u64>)>>>;
+
+    fn recording_progress() -> (ProgressHandler, ProgressReports) {
+        let reports = ProgressReports::default();
+        let progress = ProgressHandler::new({
+            let reports = reports.clone();
+            move |transferred, total| reports.lock().unwrap().push((transferred, total))
+        });
+        (progress, reports)
+    }
+
+    fn assert_progress(reports: &[(u64, Option<u64>)], expected_len: usize) {
+        let expected_len = expected_len as u64;
+        assert!(!reports.is_empty());
+        assert!(reports.windows(2).all(|w| w[0].0 <= w[1].0), "{reports:?}");
+        assert!(
+            reports
+                .iter()
+                .all(|(_, total)| *total == Some(expected_len)),
+            "{reports:?}"
+        );
+        assert_eq!(reports.last().unwrap().0, expected_len);
+    }
+
     #[tokio::test]
     async fn download_empty_resource() -> AzureResult<()> {
         let parallel = NonZero::new(1).unwrap();
@@ -1031,7 +1244,7 @@ mod tests {
         let data = get_random_data(0);
         let mock = Arc::new(MockPartitionedDownloadBehavior::new(data.clone(), None));
 
-        let mut body = download(None, parallel, partition_len, mock.clone())
+        let mut body = download(None, parallel, partition_len, mock.clone(), None)
             .await?
             .into_body();
         let downloaded_data = body.buffer_all().await?;
@@ -1048,8 +1261,15 @@ mod tests {
         let data = get_random_data(0);
         let mock = Arc::new(MockPartitionedDownloadBehavior::new(data.clone(), None));
 
-        let (_, _, copied) =
-            download_into(&mut [0; 1024], None, parallel, partition_len, mock.clone()).await?;
+        let (_, _, copied) = download_into(
+            &mut [0; 1024],
+            None,
+            parallel,
+            partition_len,
+            mock.clone(),
+            None,
+        )
+        .await?;
 
         assert_eq!(copied, 0);
 
@@ -1072,7 +1292,7 @@ mod tests {
             }),
         ));
 
-        download(None, parallel, partition_len, mock.clone())
+        download(None, parallel, partition_len, mock.clone(), None)
             .await?
             .into_body()
             .collect()
@@ -1120,6 +1340,7 @@ mod tests {
             parallel,
             partition_len,
             mock.clone(),
+            None,
         )
         .await?;
 
@@ -1163,12 +1384,13 @@ mod tests {
                 delay_millis_range: Some(
                     individual_request_delay_ms..individual_request_delay_ms + 1,
                 ),
+                ..Default::default()
             }),
         ));
 
         let (download_result, _) = futures::future::join(
             async {
-                download(None, parallel, partition_len, mock.clone())
+                download(None, parallel, partition_len, mock.clone(), None)
                     .await?
                     .into_body()
                     .collect()
@@ -1206,6 +1428,7 @@ mod tests {
                 delay_millis_range: Some(
                     individual_request_delay_ms..individual_request_delay_ms + 1,
                 ),
+                ..Default::default()
             }),
         ));
 
@@ -1216,6 +1439,7 @@ mod tests {
                 parallel,
                 partition_len,
                 mock.clone(),
+                None,
             ),
             async {
                 sleep(Duration::from_millis(etag_edit_delay_ms)).await;
@@ -1252,6 +1476,7 @@ mod tests {
                         NonZero::new(parallel).unwrap(),
                         NonZero::new(partition_len).unwrap(),
                         Arc::new(MockPartitionedDownloadBehavior::new(data.clone(), None)),
+                        None,
                     )
                     .await;
                     assert!(download_result.is_err(), "Expected error for buffer_len: {}, range: {:?}, parallel: {}, partition_len: {}", buffer_len, http_range, parallel, partition_len);
@@ -1262,6 +1487,168 @@ mod tests {
         Ok(())
     }
 
+    /// Serves every range after the first from one byte later than requested.
+    struct ShiftedRangeBehavior(MockPartitionedDownloadBehavior);
+
+    #[async_trait::async_trait]
+    impl PartitionedDownloadBehavior for ShiftedRangeBehavior {
+        async fn transfer_range(
+            &self,
+            range: Option<Range<usize>>,
+            etag_lock: Option<Etag>,
+        ) -> AzureResult<AsyncRawResponse> {
+            let range = range.map(|r| match r.start {
+                0 => r,
+                start => start + 1..r.end,
+            });
+            self.0.transfer_range(range, etag_lock).await
+        }
+    }
+
+    #[tokio::test]
+    async fn download_resumes_failed_ranges() -> AzureResult<()> {
+        const DATA_LEN: usize = 1024;
+        let data = get_random_data(DATA_LEN);
+        for parallel in [1, 4] {
+            let mock = Arc::new(MockPartitionedDownloadBehavior::new(
+                data.clone(),
+                Some(MockOptions {
+                    body_failures: DOWNLOAD_RANGE_RETRIES,
+                    ..Default::default()
+                }),
+            ));
+            let downloaded_data = download(
+                None,
+                NonZero::new(parallel).unwrap(),
+                NonZero::new(DATA_LEN / 8).unwrap(),
+                mock.clone(),
+                None,
+            )
+            .await?
+            .into_body()
+            .buffer_all()
+            .await?;
+
+            assert_eq!(downloaded_data, data);
+            // 8 ranges plus one request for the rest of each failed range
+            assert_eq!(
+                mock.invocations.lock().await.len(),
+                8 + DOWNLOAD_RANGE_RETRIES
+            );
+        }
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn download_into_resumes_failed_ranges() -> AzureResult<()> {
+        const DATA_LEN: usize = 1024;
+        let data = get_random_data(DATA_LEN);
+        for parallel in [1, 4] {
+            let mock = Arc::new(MockPartitionedDownloadBehavior::new(
+                data.clone(),
+                Some(MockOptions {
+                    body_failures: DOWNLOAD_RANGE_RETRIES,
+                    ..Default::default()
+                }),
+            ));
+            let mut buffer = vec![0; DATA_LEN];
+            let (_, _, len) = download_into(
+                &mut buffer,
+                Some((100..DATA_LEN).into()),
+                NonZero::new(parallel).unwrap(),
+                NonZero::new(DATA_LEN / 8).unwrap(),
+                mock.clone(),
+                None,
+            )
+            .await?;
+
+            assert_eq!(len, DATA_LEN - 100);
+            assert_eq!(&buffer[..len], &data[100..]);
+        }
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn download_fails_after_range_retries() -> AzureResult<()> {
+        const DATA_LEN: usize = 1024;
+        let data = get_random_data(DATA_LEN);
+        for parallel in [1, 4] {
+            let mock = || {
+                Arc::new(MockPartitionedDownloadBehavior::new(
+                    data.clone(),
+                    Some(MockOptions {
+                        body_failures: usize::MAX,
+                        ..Default::default()
+                    }),
+                ))
+            };
+            let parallel = NonZero::new(parallel).unwrap();
+            let partition_len = NonZero::new(DATA_LEN / 8).unwrap();
+
+            let err = download(None, parallel, partition_len, mock(), None)
+                .await?
+                .into_body()
+                .buffer_all()
+                .await
+                .expect_err("download should fail");
+            assert!(matches!(err.kind(), ErrorKind::Io), "{err}");
+
+            let err = download_into(
+                &mut [0; DATA_LEN],
+                None,
+                parallel,
+                partition_len,
+                mock(),
+                None,
+            )
+            .await
+            .expect_err("download_into should fail");
+            assert!(matches!(err.kind(), ErrorKind::Io), "{err}");
+        }
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn download_fails_on_mismatched_range() -> AzureResult<()> {
+        const DATA_LEN: usize = 1024;
+        let data = get_random_data(DATA_LEN);
+        for parallel in [1, 4] {
+            let mock = || {
+                Arc::new(ShiftedRangeBehavior(MockPartitionedDownloadBehavior::new(
+                    data.clone(),
+                    None,
+                )))
+            };
+            let parallel = NonZero::new(parallel).unwrap();
+            let partition_len = NonZero::new(DATA_LEN / 8).unwrap();
+
+            let err = download(None, parallel, partition_len, mock(), None)
+                .await?
+                .into_body()
+                .buffer_all()
+                .await
+                .expect_err("download should fail");
+            assert!(err.to_string().contains("Content-Range"), "{err}");
+
+            let err = download_into(
+                &mut [0; DATA_LEN],
+                None,
+                parallel,
+                partition_len,
+                mock(),
+                None,
+            )
+            .await
+            .expect_err("download_into should fail");
+            assert!(err.to_string().contains("Content-Range"), "{err}");
+        }
+
+        Ok(())
+    }
+
     trait BytesTryStreamExt {
         async fn buffer_all(&mut self) -> AzureResult<Vec<u8>>;
     }
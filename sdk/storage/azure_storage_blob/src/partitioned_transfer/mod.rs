@@ -3,9 +3,11 @@
 
 pub(crate) mod defaults;
 mod download;
+mod progress;
 mod upload;
 
 pub(crate) use download::*;
+pub(crate) use progress::ProgressTracker;
 pub(crate) use upload::*;
 
 use std::{cmp::max, future::Future, num::NonZero, pin::Pin};
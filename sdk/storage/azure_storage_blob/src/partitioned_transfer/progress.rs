@@ -0,0 +1,60 @@
+// Copyright (c) Microsoft Corporation. All rights reserved.
+// Licensed under the MIT License.
+
+use std::sync::Mutex;
+
+use crate::models::ProgressHandler;
+
+/// Accumulates bytes transferred by concurrent partitions and reports the running total to a
+/// [`ProgressHandler`].
+pub(crate) struct ProgressTracker {
+    handler: ProgressHandler,
+    total: Option<u64>,
+    // Held while reporting so concurrent partitions report in increasing order.
+    transferred: Mutex<u64>,
+}
+
+impl ProgressTracker {
+    pub(crate) fn new(handler: ProgressHandler, total: Option<u64>) -> Self {
+        Self {
+            handler,
+            total,
+            transferred: Mutex::new(0),
+        }
+    }
+
+    /// Adds `len` bytes to the running total and reports it.
+    pub(crate) fn add(&self, len: u64) {
+        let mut transferred = self
+            .transferred
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        *transferred += len;
+        self.handler.report(*transferred, self.total);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use super::*;
+
+    #[test]
+    fn reports_running_total() {
+        let reports = Arc::new(Mutex::new(Vec::new()));
+        let tracker = ProgressTracker::new(
+            ProgressHandler::new({
+                let reports = reports.clone();
+                move |transferred, total| reports.lock().unwrap().push((transferred, total))
+            }),
+            Some(10),
+        );
+        tracker.add(4);
+        tracker.add(6);
+        assert_eq!(
+            vec![(4, Some(10)), (10, Some(10))],
+            *reports.lock().unwrap()
+        );
+    }
+}
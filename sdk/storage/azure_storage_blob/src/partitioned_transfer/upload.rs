@@ -4,6 +4,9 @@
 use azure_core::http::Body;
 use bytes::Bytes;
 
+use crate::models::{ChecksumAlgorithm, ProgressHandler};
+use azure_storage_common::checksum::Checksum;
+
 use async_trait::async_trait;
 use azure_core::stream::SeekableStream;
 use futures::StreamExt;
@@ -20,34 +23,59 @@ pub(crate) trait PartitionedUploadBehavior {
     async fn transfer_oneshot(&self, content: Body) -> AzureResult<()>;
     async fn transfer_partition(&self, offset: u64, content: Body) -> AzureResult<()>;
     async fn initialize(&self, content_len: Option<u64>) -> AzureResult<()>;
-    async fn finalize(&self) -> AzureResult<()>;
+    async fn finalize(&self, content_checksum: Option<Vec<u8>>) -> AzureResult<()>;
 }
 
+/// Uploads `content` in a single request if it fits in one partition, otherwise in
+/// partitions of `partition_size` with up to `parallel` partitions in flight.
+///
+/// If `progress` is provided, it receives the number of bytes uploaded as each request completes.
+/// If `content_checksum` is provided and the content is partitioned, the checksum of the whole
+/// content is computed as partitions are read and passed to `finalize()`.
 pub(crate) async fn upload(
     content: Body,
     parallel: NonZero<usize>,
     partition_size: NonZero<u64>,
     client: &impl PartitionedUploadBehavior,
+    progress: Option<ProgressHandler>,
+    content_checksum: Option<ChecksumAlgorithm>,
 ) -> AzureResult<()> {
+    let progress = progress.map(|handler| ProgressTracker::new(handler, content.len()));
+    let progress = progress.as_ref();
     if let Some(content_len) = content.len() {
         if content_len <= partition_size.get() {
             client.transfer_oneshot(content).await?;
+            if let Some(progress) = progress {
+                progress.add(content_len);
+            }
             return Ok(());
         }
     };
 
     client.initialize(content.len()).await?;
 
-    match content {
+    let content_checksum = match content {
         Body::Bytes(bytes) => {
-            upload_bytes_partitions(bytes, parallel, partition_size, client).await?;
+            let content_checksum = content_checksum.map(|algorithm| algorithm.compute(&bytes));
+            upload_bytes_partitions(bytes, parallel, partition_size, client, progress).await?;
+            content_checksum
         }
         Body::SeekableStream(seekable_stream) => {
-            upload_stream_partitions(seekable_stream, parallel, partition_size, client).await?;
+            let mut checksum = content_checksum.map(|algorithm| algorithm.checksum());
+            upload_stream_partitions(
+                seekable_stream,
+                parallel,
+                partition_size,
+                client,
+                progress,
+                checksum.as_mut(),
+            )
+            .await?;
+            checksum.map(Checksum::finalize)
         }
-    }
+    };
 
-    client.finalize().await?;
+    client.finalize(content_checksum).await?;
 
     Ok(())
 }
@@ -57,6 +85,7 @@ async fn upload_bytes_partitions(
     parallel: NonZero<usize>,
     partition_size: NonZero<u64>,
     client: &impl PartitionedUploadBehavior,
+    progress: Option<&ProgressTracker>,
 ) -> AzureResult<()> {
     let partition_size: usize = partition_size.get().try_into().unwrap_or(usize::MAX);
     let partitions = (0..content.len()).step_by(partition_size).map(|offset| {
@@ -64,7 +93,7 @@ async fn upload_bytes_partitions(
         (offset, content.slice(range))
     });
     let ops = partitions.map(|(offset, bytes)| {
-        Ok(move || client.transfer_partition(offset as u64, Body::Bytes(bytes)))
+        Ok(move || transfer_partition(client, offset as u64, Body::Bytes(bytes), progress))
     });
     run_all_with_concurrency_limit(futures::stream::iter(ops), parallel).await?;
     Ok(())
@@ -75,8 +104,10 @@ async fn upload_stream_partitions(
     parallel: NonZero<usize>,
     partition_size: NonZero<u64>,
     client: &impl PartitionedUploadBehavior,
+    progress: Option<&ProgressTracker>,
+    mut checksum: Option<&mut Checksum>,
 ) -> AzureResult<()> {
-    type PartsStream = Pin<Box<dyn Stream<Item = AzureResult<(u64, Body)>> + Send>>;
+    type PartsStream<'a> = Pin<Box<dyn Stream<Item = AzureResult<(u64, Body)>> + Send + 'a>>;
     let partitions = match TryInto::<usize>::try_into(partition_size.get())
         .map_err(|_| ())
         .and_then(|part_usize| {
@@ -92,6 +123,11 @@ async fn upload_stream_partitions(
                 // SAFETY: this value comes out of an existing NonZero. We've only safely converted the bit size.
                 unsafe { NonZero::new_unchecked(partition_size_usize) },
             )
+            .inspect_ok(|bytes| {
+                if let Some(checksum) = checksum.as_mut() {
+                    checksum.update(bytes);
+                }
+            })
             .scan(0u64, |enumerated_bytes, result| match result {
                 Ok(bytes) => {
                     let offset = *enumerated_bytes;
@@ -103,9 +139,13 @@ async fn upload_stream_partitions(
             Box::pin(stream) as PartsStream
         }
         Err(_) => {
-            let stream = stream_multi_buffer_partitions(content, partition_size).scan(
-                0u64,
-                |enumerated_bytes, result| match result {
+            let stream = stream_multi_buffer_partitions(content, partition_size)
+                .inspect_ok(|vec_bytes| {
+                    if let Some(checksum) = checksum.as_mut() {
+                        vec_bytes.iter().for_each(|bytes| checksum.update(bytes));
+                    }
+                })
+                .scan(0u64, |enumerated_bytes, result| match result {
                     Ok(vec_bytes) => {
                         let offset = *enumerated_bytes;
                         *enumerated_bytes += vec_bytes
@@ -122,19 +162,34 @@ async fn upload_stream_partitions(
                         }
                     }
                     Err(e) => future::ready(Some(Err(e))),
-                },
-            );
+                });
             Box::pin(stream) as PartsStream
         }
     };
-    let ops = partitions.map_ok(|(offset, body)| move || client.transfer_partition(offset, body));
+    let ops = partitions
+        .map_ok(|(offset, body)| move || transfer_partition(client, offset, body, progress));
     run_all_with_concurrency_limit(ops, parallel).await?;
     Ok(())
 }
 
+/// Transfers a partition and then reports its length to `progress`, if any.
+async fn transfer_partition(
+    client: &impl PartitionedUploadBehavior,
+    offset: u64,
+    content: Body,
+    progress: Option<&ProgressTracker>,
+) -> AzureResult<()> {
+    let len = content.len();
+    client.transfer_partition(offset, content).await?;
+    if let (Some(progress), Some(len)) = (progress, len) {
+        progress.add(len);
+    }
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
-    use std::{mem::discriminant, slice};
+    use std::{mem::discriminant, slice, sync::Arc};
 
     use async_trait::async_trait;
     use azure_core::{http::Body, stream::BytesStream};
@@ -217,7 +272,7 @@ mod tests {
         Initialize(Option<u64>),
         TransferOneshot(Bytes, BodyType),
         TransferPartition(u64, Bytes, BodyType),
-        Finalize(),
+        Finalize(Option<Vec<u8>>),
     }
 
     /// Mock of a PartitionedUploadBehavior. Keeps a record of all calls made to it.
@@ -268,11 +323,13 @@ mod tests {
             Ok(())
         }
 
-        async fn finalize(&self) -> AzureResult<()> {
+        async fn finalize(&self, content_checksum: Option<Vec<u8>>) -> AzureResult<()> {
             self.invocations
                 .lock()
                 .await
-                .push(MockPartitionedUploadBehaviorInvocation::Finalize());
+                .push(MockPartitionedUploadBehaviorInvocation::Finalize(
+                    content_checksum,
+                ));
             Ok(())
         }
     }
@@ -291,6 +348,8 @@ mod tests {
             NonZero::new(concurrency).unwrap(),
             NonZero::new(partition_size).unwrap(),
             &mock,
+            None,
+            None,
         )
         .await?;
 
@@ -313,6 +372,8 @@ mod tests {
             NonZero::new(concurrency).unwrap(),
             NonZero::new(partition_size).unwrap(),
             &mock,
+            None,
+            None,
         )
         .await?;
 
@@ -341,6 +402,8 @@ mod tests {
             NonZero::new(concurrency).unwrap(),
             NonZero::new(partition_size).unwrap(),
             &mock,
+            None,
+            None,
         )
         .await?;
 
@@ -363,6 +426,8 @@ mod tests {
             NonZero::new(concurrency).unwrap(),
             NonZero::new(partition_size).unwrap(),
             &mock,
+            None,
+            None,
         )
         .await?;
 
@@ -377,6 +442,75 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn partition_stream_reports_progress() -> AzureResult<()> {
+        let data_size: usize = 1024;
+        let partition_size: u64 = 50;
+        let concurrency: usize = 4;
+
+        let mock = MockPartitionedUploadBehavior::new();
+        let src_data = get_random_data(data_size);
+        let reports = Arc::new(std::sync::Mutex::new(Vec::new()));
+
+        upload(
+            Body::SeekableStream(Box::new(BytesStream::new(Bytes::from(src_data.clone())))),
+            NonZero::new(concurrency).unwrap(),
+            NonZero::new(partition_size).unwrap(),
+            &mock,
+            Some(ProgressHandler::new({
+                let reports = reports.clone();
+                move |transferred, total| reports.lock().unwrap().push((transferred, total))
+            })),
+            None,
+        )
+        .await?;
+
+        let reports = reports.lock().unwrap();
+        assert_eq!(
+            reports.len(),
+            (data_size as u64).div_ceil(partition_size) as usize
+        );
+        assert!(reports.windows(2).all(|w| w[0].0 < w[1].0));
+        assert!(reports
+            .iter()
+            .all(|(_, total)| *total == Some(data_size as u64)));
+        assert_eq!(reports.last().unwrap().0, data_size as u64);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn partitions_compute_content_checksum() -> AzureResult<()> {
+        let data_size: usize = 1024;
+        let partition_size: u64 = 50;
+        let src_data = get_random_data(data_size);
+
+        for content in [
+            Body::Bytes(Bytes::from(src_data.clone())),
+            Body::SeekableStream(Box::new(BytesStream::new(Bytes::from(src_data.clone())))),
+        ] {
+            let mock = MockPartitionedUploadBehavior::new();
+            upload(
+                content,
+                NonZero::new(4).unwrap(),
+                NonZero::new(partition_size).unwrap(),
+                &mock,
+                None,
+                Some(ChecksumAlgorithm::Md5),
+            )
+            .await?;
+
+            let invocations = mock.invocations.lock().await;
+            assert!(matches!(
+                invocations.last(),
+                Some(MockPartitionedUploadBehaviorInvocation::Finalize(Some(checksum)))
+                    if *checksum == ChecksumAlgorithm::Md5.compute(&src_data)
+            ));
+        }
+
+        Ok(())
+    }
+
     async fn assert_upload_oneshot_invocations(
         mock: &MockPartitionedUploadBehavior,
         original_data: &[u8],
@@ -407,7 +541,7 @@ mod tests {
         ));
         assert!(matches!(
             &invocations[invocations.len() - 1],
-            MockPartitionedUploadBehaviorInvocation::Finalize()
+            MockPartitionedUploadBehaviorInvocation::Finalize(_)
         ));
 
         let mut sorted_transfer_partition_invocations: Vec<_> = invocations
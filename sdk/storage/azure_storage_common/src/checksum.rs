@@ -0,0 +1,374 @@
+// Copyright (c) Microsoft Corporation. All rights reserved.
+// Licensed under the MIT License.
+
+//! Transactional checksums used to validate the integrity of data sent to and received from Azure Storage.
+
+use azure_core::{
+    error::ErrorKind,
+    http::{headers::HeaderName, response::PinnedStream, Body},
+    Bytes,
+};
+use futures::{io::AsyncReadExt as _, Stream};
+use md5::{Digest as _, Md5};
+use std::{
+    error::Error,
+    fmt,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+/// The `Content-MD5` header.
+pub const CONTENT_MD5: HeaderName = HeaderName::from_static("content-md5");
+
+/// The `x-ms-content-crc64` header.
+pub const CONTENT_CRC64: HeaderName = HeaderName::from_static("x-ms-content-crc64");
+
+/// The algorithm used to compute a transactional checksum.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum ChecksumAlgorithm {
+    /// MD5, sent and returned in the `Content-MD5` header.
+    Md5,
+
+    /// The Azure Storage CRC-64 polynomial, sent and returned in the `x-ms-content-crc64` header.
+    #[default]
+    Crc64,
+}
+
+impl ChecksumAlgorithm {
+    /// The header used to transmit a checksum of this algorithm.
+    pub fn header_name(&self) -> HeaderName {
+        match self {
+            ChecksumAlgorithm::Md5 => CONTENT_MD5,
+            ChecksumAlgorithm::Crc64 => CONTENT_CRC64,
+        }
+    }
+
+    /// Creates a new [`Checksum`] calculator for this algorithm.
+    pub fn checksum(&self) -> Checksum {
+        match self {
+            ChecksumAlgorithm::Md5 => Checksum::Md5(Md5::new()),
+            ChecksumAlgorithm::Crc64 => Checksum::Crc64(Crc64::new()),
+        }
+    }
+
+    /// Computes the checksum of `data` in a single call.
+    pub fn compute(&self, data: impl AsRef<[u8]>) -> Vec<u8> {
+        let mut checksum = self.checksum();
+        checksum.update(data);
+        checksum.finalize()
+    }
+}
+
+impl fmt::Display for ChecksumAlgorithm {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ChecksumAlgorithm::Md5 => f.write_str("MD5"),
+            ChecksumAlgorithm::Crc64 => f.write_str("CRC64"),
+        }
+    }
+}
+
+/// An incremental checksum calculator.
+///
+/// Data can be fed in any number of chunks; the result is identical to computing the checksum over the concatenated data.
+#[derive(Clone)]
+pub enum Checksum {
+    /// An MD5 calculator.
+    Md5(Md5),
+
+    /// An Azure Storage CRC-64 calculator.
+    Crc64(Crc64),
+}
+
+impl Checksum {
+    /// Gets the [`ChecksumAlgorithm`] of this calculator.
+    pub fn algorithm(&self) -> ChecksumAlgorithm {
+        match self {
+            Checksum::Md5(_) => ChecksumAlgorithm::Md5,
+            Checksum::Crc64(_) => ChecksumAlgorithm::Crc64,
+        }
+    }
+
+    /// Feeds `data` into the calculator.
+    pub fn update(&mut self, data: impl AsRef<[u8]>) {
+        match self {
+            Checksum::Md5(md5) => md5.update(data),
+            Checksum::Crc64(crc) => crc.update(data.as_ref()),
+        }
+    }
+
+    /// Consumes the calculator and returns the checksum bytes as they appear, base64-decoded, on the wire.
+    pub fn finalize(self) -> Vec<u8> {
+        match self {
+            Checksum::Md5(md5) => md5.finalize().to_vec(),
+            Checksum::Crc64(crc) => crc.finalize().to_le_bytes().to_vec(),
+        }
+    }
+}
+
+impl fmt::Debug for Checksum {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Checksum").field(&self.algorithm()).finish()
+    }
+}
+
+/// Reflected form of the polynomial Azure Storage uses for CRC-64 checksums.
+const CRC64_POLYNOMIAL: u64 = 0x9A6C_9329_AC4B_C9B5;
+
+const CRC64_TABLE: [u64; 256] = {
+    let mut table = [0u64; 256];
+    let mut i = 0;
+    while i < 256 {
+        let mut crc = i as u64;
+        let mut bit = 0;
+        while bit < 8 {
+            crc = if crc & 1 == 1 {
+                (crc >> 1) ^ CRC64_POLYNOMIAL
+            } else {
+                crc >> 1
+            };
+            bit += 1;
+        }
+        table[i] = crc;
+        i += 1;
+    }
+    table
+};
+
+/// An incremental calculator for the CRC-64 checksum used by Azure Storage in the `x-ms-content-crc64` header.
+#[derive(Clone, Debug)]
+pub struct Crc64 {
+    state: u64,
+}
+
+impl Crc64 {
+    /// Creates a new calculator.
+    pub fn new() -> Self {
+        Self { state: !0 }
+    }
+
+    /// Feeds `data` into the calculator.
+    pub fn update(&mut self, data: &[u8]) {
+        let mut crc = self.state;
+        for byte in data {
+            crc = CRC64_TABLE[((crc ^ u64::from(*byte)) & 0xff) as usize] ^ (crc >> 8);
+        }
+        self.state = crc;
+    }
+
+    /// Returns the checksum of all data fed into the calculator so far.
+    pub fn finalize(&self) -> u64 {
+        !self.state
+    }
+}
+
+impl Default for Crc64 {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// The checksum returned by the service did not match the checksum computed over the transferred content.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ChecksumMismatchError {
+    /// The algorithm used to compute both checksums.
+    pub algorithm: ChecksumAlgorithm,
+
+    /// The checksum reported by the service.
+    pub expected: Vec<u8>,
+
+    /// The checksum computed over the content actually received.
+    pub actual: Vec<u8>,
+}
+
+impl fmt::Display for ChecksumMismatchError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} checksum mismatch: expected {}, computed {}",
+            self.algorithm,
+            azure_core::base64::encode(&self.expected),
+            azure_core::base64::encode(&self.actual),
+        )
+    }
+}
+
+impl Error for ChecksumMismatchError {}
+
+/// Computes the checksum of a request `body`. Seekable streams are read once and then reset.
+pub async fn compute_body_checksum(
+    body: &mut Body,
+    algorithm: ChecksumAlgorithm,
+) -> azure_core::Result<Vec<u8>> {
+    match body {
+        Body::Bytes(bytes) => Ok(algorithm.compute(bytes)),
+        Body::SeekableStream(stream) => {
+            let mut checksum = algorithm.checksum();
+            let mut buffer = vec![0u8; stream.buffer_size()];
+            loop {
+                let read = stream.read(&mut buffer).await?;
+                if read == 0 {
+                    break;
+                }
+                checksum.update(&buffer[..read]);
+            }
+            stream.reset().await?;
+            Ok(checksum.finalize())
+        }
+    }
+}
+
+/// Returns an error of kind [`ErrorKind::DataConversion`] wrapping a [`ChecksumMismatchError`]
+/// if `actual` does not match `expected`.
+pub fn validate_checksum(
+    algorithm: ChecksumAlgorithm,
+    expected: &[u8],
+    actual: Vec<u8>,
+) -> azure_core::Result<()> {
+    if actual == expected {
+        return Ok(());
+    }
+    Err(azure_core::Error::new(
+        ErrorKind::DataConversion,
+        ChecksumMismatchError {
+            algorithm,
+            expected: expected.to_vec(),
+            actual,
+        },
+    ))
+}
+
+/// A response body stream that hashes each chunk as it is yielded and, at the end of the stream,
+/// yields the error from [`validate_checksum`] if the checksum doesn't match `expected`.
+pub struct ValidatingStream {
+    inner: PinnedStream,
+    checksum: Option<Checksum>,
+    expected: Vec<u8>,
+}
+
+impl ValidatingStream {
+    /// Validates `inner` against the `expected` checksum computed with `algorithm`.
+    pub fn new(inner: PinnedStream, algorithm: ChecksumAlgorithm, expected: Vec<u8>) -> Self {
+        Self {
+            inner,
+            checksum: Some(algorithm.checksum()),
+            expected,
+        }
+    }
+}
+
+impl fmt::Debug for ValidatingStream {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ValidatingStream")
+            .field("checksum", &self.checksum)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Stream for ValidatingStream {
+    type Item = azure_core::Result<Bytes>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        match self.inner.as_mut().poll_next(cx) {
+            Poll::Ready(Some(Ok(chunk))) => {
+                if let Some(checksum) = self.checksum.as_mut() {
+                    checksum.update(&chunk);
+                }
+                Poll::Ready(Some(Ok(chunk)))
+            }
+            Poll::Ready(None) => {
+                let Some(checksum) = self.checksum.take() else {
+                    return Poll::Ready(None);
+                };
+                match validate_checksum(checksum.algorithm(), &self.expected, checksum.finalize()) {
+                    Ok(()) => Poll::Ready(None),
+                    Err(err) => Poll::Ready(Some(Err(err))),
+                }
+            }
+            poll => poll,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use azure_core::stream::BytesStream;
+    use futures::TryStreamExt as _;
+
+    #[test]
+    fn crc64_check_value() {
+        let mut crc = Crc64::new();
+        crc.update(b"123456789");
+        assert_eq!(0xAE8B_1486_0A79_9888, crc.finalize());
+    }
+
+    #[test]
+    fn crc64_empty() {
+        assert_eq!(0, Crc64::new().finalize());
+    }
+
+    #[test]
+    fn chunked_matches_whole() {
+        for algorithm in [ChecksumAlgorithm::Md5, ChecksumAlgorithm::Crc64] {
+            let data = b"The quick brown fox jumps over the lazy dog";
+            let mut checksum = algorithm.checksum();
+            for chunk in data.chunks(5) {
+                checksum.update(chunk);
+            }
+            assert_eq!(algorithm.compute(data), checksum.finalize());
+        }
+    }
+
+    #[test]
+    fn md5_value() {
+        assert_eq!(
+            "XrY7u+Ae7tCTyyK7j1rNww==",
+            azure_core::base64::encode(ChecksumAlgorithm::Md5.compute(b"hello world")),
+        );
+    }
+
+    fn chunks(data: &'static [u8]) -> PinnedStream {
+        Box::pin(futures::stream::iter(
+            data.chunks(4).map(|chunk| Ok(Bytes::from_static(chunk))),
+        ))
+    }
+
+    #[tokio::test]
+    async fn validating_stream_passes_matching_content() -> azure_core::Result<()> {
+        let expected = ChecksumAlgorithm::Crc64.compute(b"hello world");
+        let body: Vec<Bytes> =
+            ValidatingStream::new(chunks(b"hello world"), ChecksumAlgorithm::Crc64, expected)
+                .try_collect()
+                .await?;
+        assert_eq!(b"hello world", &body.concat()[..]);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn validating_stream_fails_on_mismatch() {
+        let expected = ChecksumAlgorithm::Md5.compute(b"hello world");
+        let err = ValidatingStream::new(chunks(b"hello w0rld"), ChecksumAlgorithm::Md5, expected)
+            .try_collect::<Vec<_>>()
+            .await
+            .unwrap_err();
+        assert_eq!(&ErrorKind::DataConversion, err.kind());
+        let mismatch = err.downcast_ref::<ChecksumMismatchError>().unwrap();
+        assert_eq!(ChecksumAlgorithm::Md5, mismatch.algorithm);
+    }
+
+    #[tokio::test]
+    async fn checksum_of_stream_body_resets_stream() -> azure_core::Result<()> {
+        let mut body =
+            Body::SeekableStream(Box::new(BytesStream::new(Bytes::from_static(b"123456789"))));
+        assert_eq!(
+            0xAE8B_1486_0A79_9888u64.to_le_bytes().to_vec(),
+            compute_body_checksum(&mut body, ChecksumAlgorithm::Crc64).await?
+        );
+        assert_eq!(
+            ChecksumAlgorithm::Md5.compute(b"123456789"),
+            compute_body_checksum(&mut body, ChecksumAlgorithm::Md5).await?
+        );
+        Ok(())
+    }
+}
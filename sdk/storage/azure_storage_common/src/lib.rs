@@ -3,7 +3,10 @@
 
 #![doc = include_str!("../README.md")]
 
+pub mod checksum;
 pub mod models;
+#[cfg(any(feature = "hmac_rust", feature = "hmac_openssl"))]
+pub mod shared_key;
 
 #[doc(hidden)]
 pub mod rfc3339;
@@ -0,0 +1,225 @@
+// Copyright (c) Microsoft Corporation. All rights reserved.
+// Licensed under the MIT License.
+
+//! Shared Key authorization for Azure Storage services.
+
+use azure_core::{
+    credentials::Secret,
+    http::{
+        headers::{HeaderName, CONTENT_LENGTH, CONTENT_TYPE, IF_MATCH},
+        policies::{
+            auth::{canonicalized_headers, SharedKeyAuthorizationPolicy, SharedKeyCanonicalizer},
+            Policy,
+        },
+        Request,
+    },
+    Result,
+};
+use std::{collections::BTreeMap, fmt, sync::Arc};
+
+const CONTENT_ENCODING: HeaderName = HeaderName::from_static("content-encoding");
+const CONTENT_LANGUAGE: HeaderName = HeaderName::from_static("content-language");
+const CONTENT_MD5: HeaderName = HeaderName::from_static("content-md5");
+const IF_MODIFIED_SINCE: HeaderName = HeaderName::from_static("if-modified-since");
+const IF_NONE_MATCH: HeaderName = HeaderName::from_static("if-none-match");
+const IF_UNMODIFIED_SINCE: HeaderName = HeaderName::from_static("if-unmodified-since");
+const RANGE: HeaderName = HeaderName::from_static("range");
+
+/// The name and access key of a Storage account, used to authorize requests with
+/// [Shared Key](https://learn.microsoft.com/rest/api/storageservices/authorize-with-shared-key).
+///
+/// Add the [`authorization_policy()`](StorageSharedKeyCredential::authorization_policy) to a client's
+/// `per_try_policies` and create the client without a token credential:
+///
+/// ```
+/// use azure_core::http::ClientOptions;
+/// use azure_storage_common::shared_key::StorageSharedKeyCredential;
+///
+/// let credential = StorageSharedKeyCredential::new("myaccount", "<account key>");
+/// let mut client_options = ClientOptions::default();
+/// client_options
+///     .per_try_policies
+///     .push(credential.authorization_policy());
+/// ```
+#[derive(Clone)]
+pub struct StorageSharedKeyCredential {
+    account_name: String,
+    account_key: Secret,
+}
+
+impl StorageSharedKeyCredential {
+    /// Creates a new `StorageSharedKeyCredential`.
+    ///
+    /// # Arguments
+    /// * `account_name` - The name of the Storage account.
+    /// * `account_key` - The base64-encoded account access key.
+    pub fn new(account_name: impl Into<String>, account_key: impl Into<Secret>) -> Self {
+        Self {
+            account_name: account_name.into(),
+            account_key: account_key.into(),
+        }
+    }
+
+    /// The name of the Storage account.
+    pub fn account_name(&self) -> &str {
+        &self.account_name
+    }
+
+    /// Returns a policy that signs each request with the account key.
+    ///
+    /// The policy must run after any policy that changes the signed headers, so add it last.
+    pub fn authorization_policy(&self) -> Arc<dyn Policy> {
+        Arc::new(SharedKeyAuthorizationPolicy::new(
+            self.account_key.clone(),
+            Arc::new(StorageSharedKeyCanonicalizer {
+                account_name: self.account_name.clone(),
+            }),
+        ))
+    }
+}
+
+impl fmt::Debug for StorageSharedKeyCredential {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("StorageSharedKeyCredential")
+            .field("account_name", &self.account_name)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Builds the Blob, Queue, and File service Shared Key string to sign.
+#[derive(Debug)]
+struct StorageSharedKeyCanonicalizer {
+    account_name: String,
+}
+
+impl StorageSharedKeyCanonicalizer {
+    fn canonicalized_resource(&self, request: &Request) -> String {
+        let url = request.url();
+        let mut resource = format!("/{}{}", self.account_name, url.path());
+
+        let mut parameters: BTreeMap<String, Vec<String>> = BTreeMap::new();
+        for (name, value) in url.query_pairs() {
+            parameters
+                .entry(name.to_lowercase())
+                .or_default()
+                .push(value.into_owned());
+        }
+        for (name, mut values) in parameters {
+            values.sort_unstable();
+            resource.push('\n');
+            resource.push_str(&name);
+            resource.push(':');
+            resource.push_str(&values.join(","));
+        }
+        resource
+    }
+}
+
+impl SharedKeyCanonicalizer for StorageSharedKeyCanonicalizer {
+    fn string_to_sign(&self, request: &Request, _date: &str) -> Result<String> {
+        let headers = request.headers();
+        let header = |name: &HeaderName| headers.get_optional_str(name).unwrap_or_default();
+
+        // A zero Content-Length is signed as an empty string.
+        let content_length = headers
+            .get_optional_str(&CONTENT_LENGTH)
+            .map(str::to_owned)
+            .or_else(|| request.body().len().map(|len| len.to_string()))
+            .filter(|len| len != "0")
+            .unwrap_or_default();
+
+        // The date is signed in the x-ms-date header, so the Date line is empty.
+        Ok(format!(
+            "{}\n{}\n{}\n{}\n{}\n{}\n\n{}\n{}\n{}\n{}\n{}\n{}{}",
+            request.method().as_ref(),
+            header(&CONTENT_ENCODING),
+            header(&CONTENT_LANGUAGE),
+            content_length,
+            header(&CONTENT_MD5),
+            header(&CONTENT_TYPE),
+            header(&IF_MODIFIED_SINCE),
+            header(&IF_MATCH),
+            header(&IF_NONE_MATCH),
+            header(&IF_UNMODIFIED_SINCE),
+            header(&RANGE),
+            canonicalized_headers(headers, "x-ms-"),
+            self.canonicalized_resource(request),
+        ))
+    }
+
+    fn authorization(&self, signature: &str) -> Result<String> {
+        Ok(format!("SharedKey {}:{}", self.account_name, signature))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use azure_core::http::{headers::HeaderValue, Method};
+
+    fn canonicalizer() -> StorageSharedKeyCanonicalizer {
+        StorageSharedKeyCanonicalizer {
+            account_name: "myaccount".to_string(),
+        }
+    }
+
+    #[test]
+    fn string_to_sign() {
+        let mut request = Request::new(
+            "https://myaccount.blob.core.windows.net/container/blob?comp=block&blockid=YQ%3D%3D&Timeout=30"
+                .parse()
+                .unwrap(),
+            Method::Put,
+        );
+        request.insert_header("x-ms-version", "2026-04-06");
+        request.insert_header("x-ms-date", "Fri, 16 Oct 2026 12:00:00 GMT");
+        request.insert_header("content-type", "application/octet-stream");
+        request.insert_header("range", HeaderValue::from_static("bytes=0-9"));
+        request.set_body("0123456789");
+
+        assert_eq!(
+            canonicalizer()
+                .string_to_sign(&request, "Fri, 16 Oct 2026 12:00:00 GMT")
+                .unwrap(),
+            "PUT\n\n\n10\n\napplication/octet-stream\n\n\n\n\n\nbytes=0-9\n\
+             x-ms-date:Fri, 16 Oct 2026 12:00:00 GMT\nx-ms-version:2026-04-06\n\
+             /myaccount/container/blob\nblockid:YQ==\ncomp:block\ntimeout:30"
+        );
+    }
+
+    #[test]
+    fn empty_body_signs_empty_content_length() {
+        let mut request = Request::new(
+            "https://myaccount.queue.core.windows.net/?comp=list&include=metadata&include=acl"
+                .parse()
+                .unwrap(),
+            Method::Get,
+        );
+        request.insert_header("x-ms-date", "Fri, 16 Oct 2026 12:00:00 GMT");
+
+        assert_eq!(
+            canonicalizer()
+                .string_to_sign(&request, "Fri, 16 Oct 2026 12:00:00 GMT")
+                .unwrap(),
+            "GET\n\n\n\n\n\n\n\n\n\n\n\n\
+             x-ms-date:Fri, 16 Oct 2026 12:00:00 GMT\n\
+             /myaccount/\ncomp:list\ninclude:acl,metadata"
+        );
+    }
+
+    #[test]
+    fn authorization() {
+        assert_eq!(
+            canonicalizer().authorization("c2lnbmF0dXJl").unwrap(),
+            "SharedKey myaccount:c2lnbmF0dXJl"
+        );
+    }
+
+    #[test]
+    fn debug_redacts_key() {
+        let credential = StorageSharedKeyCredential::new("myaccount", "super-secret-key");
+        let rendered = format!("{credential:?}");
+        assert!(rendered.contains("myaccount"));
+        assert!(!rendered.contains("super-secret-key"));
+    }
+}
@@ -8,3 +8,5 @@ pub use queue_client::{QueueClient, QueueClientOptions};
 
 mod queue_service_client;
 pub use queue_service_client::{QueueServiceClient, QueueServiceClientOptions};
+
+pub use azure_storage_common::shared_key::StorageSharedKeyCredential;